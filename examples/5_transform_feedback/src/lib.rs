@@ -6,6 +6,7 @@
 #![feature(
 const_fn_trait_bound,
     const_maybe_uninit_as_ptr,
+    const_panic,
     const_ptr_offset_from,
     const_raw_ptr_deref
 )]
@@ -7,6 +7,7 @@
 #![feature(
 const_fn_trait_bound,
     const_maybe_uninit_as_ptr,
+    const_panic,
     const_ptr_offset_from,
     const_raw_ptr_deref
 )]
@@ -143,11 +144,7 @@ pub fn start() {
     // We'll use an index list to reuse our vertices multiple times. We've only defined 8 vertices
     // but we want to draw 12 triangles, which each require 3 vertices. We'll use `u16` indices to
     // reference each of our vertices 4 times.
-    //
-    // TODO: we have to use a `Vec` for now, as Borrow<[u16]> is only implemented for arrays up to
-    // length 32 for the time being. I expect this will change as const generics get stabilized,
-    // switch to an array when that happens.
-    let index_data: Vec<u16> = vec![
+    let index_data: [u16; 36] = [
         0, 2, 1, // Back
         1, 2, 3, //
         0, 6, 2, // Left
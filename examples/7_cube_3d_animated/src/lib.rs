@@ -4,42 +4,36 @@
 // many times, on every animation frame the browser can provide. On each frame we'll update the
 // contents of our uniform buffer to make the cube spin.
 //
-// So far we`ve been using plain `web_sys` to interact with the browser, but now we'll use the
-// `arwa` crate to help us request animation frames. We'll also need the `futures` and
-// `wasm_bindgen_futures` crates. Finally, we need the `fn_traits`, `unboxed_closures` to create a
-// stateful self-referential function that we can call in a "loop" on each animation frame.
+// We'll use `RenderingContext::run_animation_frames` to run our rendering code on each animation
+// frame: it requests animation frames from the browser in a loop, calls our closure on each frame
+// and submits the task our closure returns.
 
 #![feature(
 const_fn_trait_bound,
     const_maybe_uninit_as_ptr,
+    const_panic,
     const_ptr_offset_from,
-    const_raw_ptr_deref,
-    fn_traits,
-    unboxed_closures
+    const_raw_ptr_deref
 )]
 
 use std::convert::TryInto;
 use std::f32::consts::PI;
 
 use arwa::html::HtmlCanvasElement;
-use arwa::{window, AnimationFrameCancelled, Document, Window};
+use arwa::{window, Document};
 
 use cgmath::{Matrix4, PerspectiveFov, Rad, SquareMatrix, Vector3};
 use cgmath_std140::AsStd140;
 
-use futures::FutureExt;
-
 use wasm_bindgen::prelude::*;
-use wasm_bindgen_futures::spawn_local;
 
 use web_glitz::buffer::{Buffer, UsageHint};
 use web_glitz::pipeline::graphics::{
     CullingMode, DepthTest, GraphicsPipelineDescriptor, PrimitiveAssembly, WindingOrder,
 };
 use web_glitz::pipeline::resources::BindGroup;
-use web_glitz::runtime::single_threaded::SingleThreadedContext;
-use web_glitz::runtime::{single_threaded, Connection, ContextOptions, RenderingContext};
-use web_glitz::task::{sequence, GpuTask};
+use web_glitz::runtime::{single_threaded, ContextOptions, FrameContext, RenderingContext};
+use web_glitz::task::sequence;
 
 #[derive(web_glitz::derive::Vertex, Clone, Copy)]
 struct Vertex {
@@ -63,60 +57,6 @@ struct Resources<'a> {
     uniforms: &'a Buffer<Uniforms>,
 }
 
-// Our animation frame loop. We implement the `FnOnce` trait for this type so that we can use it as
-// a callback for an animation frame, while we hold onto (and can update, if necessary) the state
-// that's relevant to our rendering code inside this struct.
-struct AnimationLoop<T> {
-    rendering_context: SingleThreadedContext,
-    uniform_buffer: Buffer<Uniforms>,
-    render_pass: T,
-    view: std140::mat4x4,
-    projection: std140::mat4x4,
-    frame_provider: Window,
-}
-
-impl<T> FnOnce<(Result<f64, AnimationFrameCancelled>,)> for AnimationLoop<T>
-where
-    T: GpuTask<Connection> + Clone + 'static,
-{
-    type Output = ();
-
-    extern "rust-call" fn call_once(
-        self,
-        args: (Result<f64, AnimationFrameCancelled>,),
-    ) -> Self::Output {
-        // Only render if the frame has not been cancelled.
-        if let Ok(time) = args.0 {
-            let time = time as f32;
-
-            // Compute the new values for our uniforms
-            let rotate_x = Matrix4::from_angle_x(Rad(time / 1000.0));
-            let rotate_y = Matrix4::from_angle_y(Rad(time / 1000.0));
-            let model = rotate_y * rotate_x;
-            let uniforms = Uniforms {
-                model: model.as_std140(),
-                view: self.view,
-                projection: self.projection,
-            };
-
-            // Create a command that updates the uniform buffer.
-            let update_command = self.uniform_buffer.upload_command(uniforms);
-
-            // Submit a task that sequences the update command with our render pass. Note that we've
-            // preconstructed our render pass task and that for each frame we submit a clone,
-            // without having to reconstruct our render pass task every time.
-            self.rendering_context
-                .submit(sequence(update_command, self.render_pass.clone()));
-
-            // Create a request for the next frame.
-            let next = self.frame_provider.request_animation_frame();
-
-            // Dispatch the request with another call to this function chained onto it.
-            spawn_local(next.map(self));
-        }
-    }
-}
-
 #[wasm_bindgen(start)]
 pub fn start() {
     let window = window().unwrap();
@@ -197,7 +137,7 @@ pub fn start() {
 
     let vertex_buffer = context.create_buffer(vertex_data, UsageHint::StreamDraw);
 
-    let index_data: Vec<u16> = vec![
+    let index_data: [u16; 36] = [
         0, 2, 1, // Back
         1, 2, 3, //
         0, 6, 2, // Left
@@ -249,21 +189,33 @@ pub fn start() {
         })
     });
 
-    // Instantiate our animation loop.
-    let animation_loop = AnimationLoop {
-        rendering_context: context,
-        uniform_buffer,
-        render_pass,
-        view,
-        projection,
-        frame_provider: window.clone(),
-    };
-
-    // Create the initial animation frame request.
-    let request = window.request_animation_frame();
+    // Start our animation loop. The closure is called on every animation frame the browser
+    // provides and the task it returns is submitted to the rendering context.
+    let animation = context.run_animation_frames(move |frame: FrameContext| {
+        let time = frame.timestamp as f32;
+
+        // Compute the new values for our uniforms
+        let rotate_x = Matrix4::from_angle_x(Rad(time / 1000.0));
+        let rotate_y = Matrix4::from_angle_y(Rad(time / 1000.0));
+        let model = rotate_y * rotate_x;
+        let uniforms = Uniforms {
+            model: model.as_std140(),
+            view,
+            projection,
+        };
+
+        // Create a command that updates the uniform buffer.
+        let update_command = uniform_buffer.upload_command(uniforms);
+
+        // Return a task that sequences the update command with our render pass. Note that we've
+        // preconstructed our render pass task and that for each frame we submit a clone, without
+        // having to reconstruct our render pass task every time.
+        sequence(update_command, render_pass.clone())
+    });
 
-    // Dispatch our request with the initial call to our animation loop chained onto it.
-    spawn_local(request.map(animation_loop));
+    // The animation loop only runs for as long as the handle returned by `run_animation_frames` is
+    // alive. We want our loop to run for as long as the page is open, so we "forget" the handle.
+    std::mem::forget(animation);
 
     // We should now see a spinning cube on the canvas!
 }
@@ -7,7 +7,7 @@
 //! pipeline):
 //!
 //! ```
-//! # #![feature(const_fn, const_loop, const_if_match, const_ptr_offset_from, const_transmute, ptr_offset_from)]
+//! # #![feature(const_fn, const_loop, const_if_match, const_panic, const_ptr_offset_from, const_transmute, ptr_offset_from)]
 //! # use web_glitz::runtime::RenderingContext;
 //! # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
 //! use web_glitz::buffer::UsageHint;
@@ -81,20 +81,27 @@
 //! [Rc]: std::rc::Rc
 //! [Arc]: std::sync::Arc
 use std::borrow::Borrow;
-use std::cell::UnsafeCell;
+use std::cell::{Cell, RefCell, UnsafeCell};
+use std::future::Future;
 use std::marker;
 use std::mem;
 use std::ops::{Deref, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use std::pin::Pin;
 use std::slice;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::task::{Context, Poll};
 
+use fnv::FnvHashMap;
 use wasm_bindgen::JsCast;
 use web_sys::{WebGl2RenderingContext as GL, WebGlBuffer};
 
+use crate::runtime::single_threaded::ObjectIdGen;
 use crate::runtime::state::ContextUpdate;
-use crate::runtime::{Connection, RenderingContext};
+use crate::runtime::{Connection, DeletionQueue, Execution, RenderingContext};
 use crate::task::{ContextId, GpuTask, Progress};
-use crate::util::JsId;
+use crate::util::{DebugLabel, JsId};
+use std::fmt;
 use std::hash::{Hash, Hasher};
 use wasm_bindgen::__rt::core::mem::MaybeUninit;
 
@@ -133,7 +140,37 @@ where
     ///
     /// See [UsageHint] for details.
     pub fn usage_hint(&self) -> UsageHint {
-        self.data.usage_hint
+        self.data.usage_hint.get()
+    }
+
+    /// Assigns a debug `label` to this [Buffer].
+    ///
+    /// The label is included in the [Debug](std::fmt::Debug) output for the buffer and in the
+    /// messages of errors that concern the buffer, which makes it easier to identify the buffer
+    /// when debugging. Note that WebGL does not expose a debug marker extension, so the label is
+    /// not visible to external GPU capture tools.
+    pub fn set_label(&self, label: &str) {
+        self.data.label.set(label);
+    }
+
+    /// Returns the debug label that was assigned to this [Buffer] with [set_label], or `None` if no
+    /// label was assigned.
+    pub fn label(&self) -> Option<String> {
+        self.data.label.get()
+    }
+}
+
+impl<T> fmt::Debug for Buffer<T>
+where
+    T: ?Sized,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Buffer")
+            .field("object_id", &self.object_id)
+            .field("label", &self.data.label)
+            .field("len", &self.data.len.get())
+            .field("usage_hint", &self.data.usage_hint.get())
+            .finish()
     }
 }
 
@@ -152,9 +189,10 @@ where
         let data = Arc::new(BufferData {
             id: UnsafeCell::new(None),
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
-            usage_hint,
-            len: 1,
+            dropper: Box::new(context.deletion_queue()),
+            usage_hint: Cell::new(usage_hint),
+            len: Cell::new(1),
+            label: DebugLabel::default(),
         });
 
         let marker: marker::PhantomData<T> = marker::PhantomData;
@@ -188,9 +226,10 @@ where
         let data = Arc::new(BufferData {
             id: UnsafeCell::new(None),
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
-            usage_hint,
-            len,
+            dropper: Box::new(context.deletion_queue()),
+            usage_hint: Cell::new(usage_hint),
+            len: Cell::new(len),
+            label: DebugLabel::default(),
         });
 
         let marker: marker::PhantomData<[T]> = marker::PhantomData;
@@ -227,6 +266,31 @@ where
         }
     }
 
+    /// Returns a command which, when executed will re-specify this [Buffer]'s data store with the
+    /// given `data` and `usage_hint`.
+    ///
+    /// Unlike an [upload_command](Buffer::upload_command), this allocates a new data store for the
+    /// buffer (by calling `bufferData` rather than `bufferSubData`). This may be used to change the
+    /// [UsageHint] of a buffer when its access pattern changes, without creating a new [Buffer]:
+    /// the buffer keeps its identity and any bind groups that reference it remain valid. The value
+    /// returned by [usage_hint](Buffer::usage_hint) changes when the command is executed.
+    ///
+    /// Note that re-specifying a buffer invalidates its old contents: any tasks that read from the
+    /// buffer and that are still in progress when this command executes (for example, a
+    /// [download_command](Buffer::download_command) that is waiting on a fence), may observe the
+    /// new data rather than the old data.
+    pub fn respecify_command<D>(&self, data: D, usage_hint: UsageHint) -> RespecifyCommand<T, D>
+    where
+        D: Borrow<T> + Send + Sync + 'static,
+    {
+        RespecifyCommand {
+            buffer_data: self.data.clone(),
+            data,
+            usage_hint,
+            _marker: marker::PhantomData,
+        }
+    }
+
     /// Returns a command which, when executed will copy the data contained in this [Buffer] into a
     /// [Box].
     ///
@@ -258,10 +322,91 @@ impl<T> Buffer<MaybeUninit<T>> {
     }
 }
 
+impl<T> Buffer<T> {
+    /// Returns the size in bytes of the data contained in this [Buffer].
+    pub fn size_in_bytes(&self) -> usize {
+        mem::size_of::<T>()
+    }
+
+    /// Returns a [BufferView] that interprets the memory of this [Buffer] as a slice of `U`
+    /// elements.
+    ///
+    /// The slice contains as many `U` elements as fit in the memory of a `T` value; any remaining
+    /// bytes are not part of the view. The view may for example be used to bind the memory of a
+    /// buffer that stores a single struct as an array of elements in a different pipeline. When
+    /// the view (or a view on an element of the slice) is bound to a pipeline as a resource, its
+    /// memory layout is validated against the resource slot like any other buffer resource.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![feature(const_fn, const_loop, const_if_match, const_panic, const_ptr_offset_from, const_transmute, ptr_offset_from)]
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::buffer::UsageHint;
+    ///
+    /// #[std140::repr_std140]
+    /// #[derive(web_glitz::derive::InterfaceBlock, Clone, Copy)]
+    /// struct Light {
+    ///     position: std140::vec4,
+    ///     color: std140::vec4,
+    /// }
+    ///
+    /// #[std140::repr_std140]
+    /// #[derive(web_glitz::derive::InterfaceBlock, Clone, Copy)]
+    /// struct Lights {
+    ///     lights: std140::array<Light, 4>,
+    /// }
+    ///
+    /// # let lights: Lights = unimplemented!();
+    /// let buffer = context.create_buffer(lights, UsageHint::StaticDraw);
+    ///
+    /// // View the memory as individual lights, to bind a single light in another pipeline.
+    /// let view = unsafe { buffer.as_slice_view::<Light>() };
+    ///
+    /// assert_eq!(view.len(), 4);
+    /// # }
+    /// ```
+    ///
+    /// # Unsafe
+    ///
+    /// Any bit pattern in the memory of a `T` value must also be valid for the `U` elements (e.g.
+    /// both types should be composed of plain numeric values), otherwise reading the view's data
+    /// back into client memory (e.g. with [BufferView::download_command]) may produce invalid
+    /// values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `U` is a zero-sized type.
+    pub unsafe fn as_slice_view<U>(&self) -> BufferView<[U]> {
+        let element_size = mem::size_of::<U>();
+
+        if element_size == 0 {
+            panic!("Cannot view a buffer as a slice of zero-sized elements.");
+        }
+
+        BufferView {
+            buffer: mem::transmute(self),
+            offset_in_bytes: 0,
+            len: mem::size_of::<T>() / element_size,
+        }
+    }
+}
+
 impl<T> Buffer<[T]> {
     /// Returns the number of elements contained in this [Buffer].
     pub fn len(&self) -> usize {
-        self.data.len
+        self.data.len.get()
+    }
+
+    /// Returns `true` if this [Buffer] contains no elements, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the size in bytes of the elements contained in this [Buffer].
+    pub fn size_in_bytes(&self) -> usize {
+        self.len() * mem::size_of::<T>()
     }
 
     /// Returns a [BufferView] on an element or a slice of the elements this [Buffer], depending
@@ -324,6 +469,52 @@ impl<T> Buffer<[T]> {
     {
         index.get_unchecked(self)
     }
+
+    /// Returns a [BufferView] that interprets the start of the memory of this [Buffer] as a
+    /// single `U` value, or `None` if the buffer is too small to contain a `U` value.
+    ///
+    /// The view may for example be used to bind the memory of a buffer that stores an array of
+    /// elements as a single struct (e.g. a header) in a different pipeline. When the view is bound
+    /// to a pipeline as a resource, its memory layout is validated against the resource slot like
+    /// any other buffer resource.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #![feature(const_fn, const_loop, const_if_match, const_panic, const_ptr_offset_from, const_transmute, ptr_offset_from)]
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::buffer::{Buffer, UsageHint};
+    ///
+    /// #[std140::repr_std140]
+    /// #[derive(web_glitz::derive::InterfaceBlock, Clone, Copy)]
+    /// struct Header {
+    ///     count: std140::uint,
+    /// }
+    ///
+    /// let buffer: Buffer<[[f32; 4]]> = context.create_buffer([[0.0; 4]; 16], UsageHint::StaticDraw);
+    ///
+    /// let header_view = unsafe { buffer.as_struct_view::<Header>() }.unwrap();
+    /// # }
+    /// ```
+    ///
+    /// # Unsafe
+    ///
+    /// Any bit pattern in the memory of the `T` elements must also be valid for a `U` value (e.g.
+    /// both types should be composed of plain numeric values), otherwise reading the view's data
+    /// back into client memory (e.g. with [BufferView::download_command]) may produce an invalid
+    /// value.
+    pub unsafe fn as_struct_view<U>(&self) -> Option<BufferView<U>> {
+        if self.len() * mem::size_of::<T>() < mem::size_of::<U>() {
+            return None;
+        }
+
+        Some(BufferView {
+            buffer: mem::transmute(self),
+            offset_in_bytes: 0,
+            len: 1,
+        })
+    }
 }
 
 impl<T> Buffer<[T]>
@@ -347,7 +538,83 @@ where
             buffer_data: self.data.clone(),
             data,
             offset_in_bytes: 0,
-            len: self.data.len,
+            len: self.data.len.get(),
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns a command which, when executed will re-specify this [Buffer]'s data store with the
+    /// elements in the given `data` and the given `usage_hint`.
+    ///
+    /// Unlike an [upload_command](Buffer::upload_command), this allocates a new data store for the
+    /// buffer (by calling `bufferData` rather than `bufferSubData`). This may be used to change the
+    /// [UsageHint] of a buffer when its access pattern changes, without creating a new [Buffer]:
+    /// the buffer keeps its identity and any bind groups that reference it remain valid.
+    ///
+    /// The `data` may contain a different number of elements than this [Buffer] currently holds, in
+    /// which case the buffer is effectively resized. The values returned by [len](Buffer::len) and
+    /// [usage_hint](Buffer::usage_hint) change when the command is executed; [BufferView]s obtained
+    /// before that time keep the old length.
+    ///
+    /// Note that re-specifying a buffer invalidates its old contents: any tasks that read from the
+    /// buffer and that are still in progress when this command executes (for example, a
+    /// [download_command](Buffer::download_command) that is waiting on a fence), may observe the
+    /// new data rather than the old data.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # use web_glitz::buffer::Buffer;
+    /// # fn wrapper<Rc>(context: &Rc, buffer: Buffer<[f32]>) where Rc: RenderingContext {
+    /// use web_glitz::buffer::UsageHint;
+    ///
+    /// // The buffer was created with `UsageHint::StaticDraw`, but will now be updated frequently.
+    /// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
+    ///
+    /// context.submit(buffer.respecify_command(data, UsageHint::DynamicDraw));
+    /// # }
+    /// ```
+    pub fn respecify_command<D>(&self, data: D, usage_hint: UsageHint) -> RespecifyCommand<[T], D>
+    where
+        D: Borrow<[T]> + Send + Sync + 'static,
+    {
+        RespecifyCommand {
+            buffer_data: self.data.clone(),
+            data,
+            usage_hint,
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns a command which, when executed will resize this [Buffer] to hold `new_len`
+    /// elements, while preserving its existing contents.
+    ///
+    /// If `new_len` is greater than the current length of the buffer, then all current elements
+    /// are preserved and the new elements at the end of the buffer are zeroed. If `new_len` is
+    /// smaller than the current length of the buffer, then only the first `new_len` elements are
+    /// preserved and the remaining elements are discarded.
+    ///
+    /// The buffer keeps its identity: any bind groups that reference the buffer remain valid. The
+    /// value returned by [len](Buffer::len) changes when the command is executed; [BufferView]s
+    /// obtained before that time keep the old length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # use web_glitz::buffer::Buffer;
+    /// # #[derive(Clone, Copy)]
+    /// # struct Particle;
+    /// # fn wrapper<Rc>(context: &Rc, particles: Buffer<[Particle]>) where Rc: RenderingContext {
+    /// // Double the capacity of the particle pool.
+    /// context.submit(particles.resize_command(particles.len() * 2));
+    /// # }
+    /// ```
+    pub fn resize_command(&self, new_len: usize) -> ResizeCommand<T> {
+        ResizeCommand {
+            buffer_data: self.data.clone(),
+            new_len,
             _marker: marker::PhantomData,
         }
     }
@@ -361,7 +628,7 @@ where
             data: self.data.clone(),
             state: DownloadState::Initial,
             offset_in_bytes: 0,
-            len: self.data.len,
+            len: self.data.len.get(),
             _marker: marker::PhantomData,
         }
     }
@@ -409,7 +676,7 @@ where
         BufferView {
             buffer,
             offset_in_bytes: 0,
-            len: buffer.data.len,
+            len: buffer.data.len.get(),
         }
     }
 }
@@ -422,7 +689,7 @@ where
         BufferView {
             buffer,
             offset_in_bytes: 0,
-            len: buffer.data.len,
+            len: buffer.data.len.get(),
         }
     }
 }
@@ -434,7 +701,7 @@ impl<'a, T, const LEN: usize> From<&'a Buffer<[T; LEN]>> for BufferView<'a, [T]>
         BufferView {
             buffer: unsafe { mem::transmute(buffer) },
             offset_in_bytes: 0,
-            len: buffer.data.len,
+            len: buffer.data.len.get(),
         }
     }
 }
@@ -442,7 +709,7 @@ impl<'a, T, const LEN: usize> From<&'a Buffer<[T; LEN]>> for BufferView<'a, [T]>
 impl<'a, T, const LEN: usize> From<&'a mut Buffer<[T; LEN]>> for BufferView<'a, [T]> {
     fn from(buffer: &'a mut Buffer<[T; LEN]>) -> BufferView<'a, [T]> {
         BufferView {
-            len: buffer.data.len,
+            len: buffer.data.len.get(),
             buffer: unsafe { mem::transmute(buffer) },
             offset_in_bytes: 0,
         }
@@ -458,7 +725,7 @@ where
             inner: BufferView {
                 buffer,
                 offset_in_bytes: 0,
-                len: buffer.data.len,
+                len: buffer.data.len.get(),
             },
             _marker: marker::PhantomData,
         }
@@ -471,7 +738,7 @@ impl<'a, T, const LEN: usize> From<&'a mut Buffer<[T; LEN]>> for BufferViewMut<'
     fn from(buffer: &'a mut Buffer<[T; LEN]>) -> BufferViewMut<'a, [T]> {
         BufferViewMut {
             inner: BufferView {
-                len: buffer.data.len,
+                len: buffer.data.len.get(),
                 buffer: unsafe { mem::transmute(buffer) },
                 offset_in_bytes: 0,
             },
@@ -515,7 +782,7 @@ where
     ///
     /// See [UsageHint] for details.
     pub fn usage_hint(&self) -> UsageHint {
-        self.buffer.data.usage_hint
+        self.buffer.data.usage_hint.get()
     }
 }
 
@@ -597,6 +864,16 @@ impl<'a, T> BufferView<'a, [T]> {
         self.len
     }
 
+    /// Returns `true` if the viewed buffer region contains no elements, `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The size in bytes of the viewed buffer region.
+    pub fn size_in_bytes(&self) -> usize {
+        self.len * mem::size_of::<T>()
+    }
+
     /// Returns a [BufferView] on an element or a sub-slice of the elements this [Buffer], depending
     /// on the type of `index`.
     ///
@@ -862,9 +1139,10 @@ where
         let data = Arc::new(BufferData {
             id: UnsafeCell::new(None),
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
-            usage_hint,
-            len: 1,
+            dropper: Box::new(context.deletion_queue()),
+            usage_hint: Cell::new(usage_hint),
+            len: Cell::new(1),
+            label: DebugLabel::default(),
         });
 
         context.submit(AllocateCommand {
@@ -899,9 +1177,10 @@ where
         let data = Arc::new(BufferData {
             id: UnsafeCell::new(None),
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
-            usage_hint,
-            len,
+            dropper: Box::new(context.deletion_queue()),
+            usage_hint: Cell::new(usage_hint),
+            len: Cell::new(len),
+            label: DebugLabel::default(),
         });
 
         context.submit(AllocateCommand::<D, [T]> {
@@ -918,6 +1197,136 @@ where
     }
 }
 
+/// Allocates transient [Buffer]s from a pool of buffer objects that are recycled.
+///
+/// Creating a new [Buffer] for short-lived data (e.g. geometry or uniform values that are
+/// regenerated every frame) creates a new buffer object, which is deleted again when the [Buffer]
+/// is dropped. A [FrameAllocator] instead keeps buffer objects that are no longer in use in a
+/// pool. Buffers allocated with a [FrameAllocator] behave like any other [Buffer], but when they
+/// are dropped their buffer object is returned to the pool, rather than being deleted. Later
+/// allocations of the same size (in bytes) with the same [UsageHint] will reuse these buffer
+/// objects.
+///
+/// A buffer object is only returned to the pool after the GPU has finished executing all commands
+/// that were submitted before the [Buffer] was dropped; this ensures a buffer object is never
+/// reused while the GPU may still be reading from it.
+///
+/// A [FrameAllocator] may be obtained with [RenderingContext::frame_allocator]. All
+/// [FrameAllocator]s obtained from the same context share the same pool.
+///
+/// # Example
+///
+/// ```rust
+/// # use web_glitz::runtime::RenderingContext;
+/// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+/// use web_glitz::buffer::{Buffer, UsageHint};
+///
+/// let allocator = context.frame_allocator();
+///
+/// // Every frame:
+/// let vertex_data: Buffer<[f32]> = allocator.buffer([0.0, 0.5, -0.5, -0.5, 0.5, -0.5], UsageHint::StreamDraw);
+///
+/// // ...use the buffer in this frame's tasks, then drop it...
+/// # }
+/// ```
+///
+/// Buffer objects remain in the pool until they are reused, or until [purge] is called.
+///
+/// [purge]: FrameAllocator::purge
+pub struct FrameAllocator<Rc> {
+    context: Rc,
+    object_id_gen: ObjectIdGen,
+    pool: std::rc::Rc<RefCell<BufferPool>>,
+}
+
+impl<Rc> FrameAllocator<Rc>
+where
+    Rc: RenderingContext + Clone + 'static,
+{
+    pub(crate) fn new(context: Rc, shared_pool: SharedBufferPool) -> Self {
+        FrameAllocator {
+            context,
+            object_id_gen: shared_pool.object_id_gen,
+            pool: shared_pool.pool,
+        }
+    }
+
+    /// Allocates a [Buffer] that contains the elements in the given `data`, reusing a pooled
+    /// buffer object if one of the same size and with the same `usage_hint` is available.
+    ///
+    /// See also [value_buffer] for allocating a buffer that contains a single value.
+    ///
+    /// [value_buffer]: FrameAllocator::value_buffer
+    pub fn buffer<D, T>(&self, data: D, usage_hint: UsageHint) -> Buffer<[T]>
+    where
+        D: Borrow<[T]> + 'static,
+        T: Copy + 'static,
+    {
+        let len = data.borrow().len();
+        let buffer_data = self.allocate_data(len, usage_hint);
+
+        self.context.submit(PooledAllocateCommand::<D, [T]> {
+            data: buffer_data.clone(),
+            initial: data,
+            pool: self.pool.clone(),
+            _marker: marker::PhantomData,
+        });
+
+        Buffer {
+            object_id: self.object_id_gen.next(),
+            data: buffer_data,
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Allocates a [Buffer] that contains the given `value`, reusing a pooled buffer object if
+    /// one of the same size and with the same `usage_hint` is available.
+    pub fn value_buffer<D, T>(&self, value: D, usage_hint: UsageHint) -> Buffer<T>
+    where
+        D: Borrow<T> + 'static,
+        T: Copy + 'static,
+    {
+        let buffer_data = self.allocate_data(1, usage_hint);
+
+        self.context.submit(PooledAllocateCommand::<D, T> {
+            data: buffer_data.clone(),
+            initial: value,
+            pool: self.pool.clone(),
+            _marker: marker::PhantomData,
+        });
+
+        Buffer {
+            object_id: self.object_id_gen.next(),
+            data: buffer_data,
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Deletes all buffer objects that are currently held in the pool.
+    ///
+    /// Buffer objects that are still in use are unaffected; they will be returned to the pool when
+    /// the [Buffer] that uses them is dropped.
+    pub fn purge(&self) {
+        self.context.submit(PurgePoolCommand {
+            pool: self.pool.clone(),
+        });
+    }
+
+    fn allocate_data(&self, len: usize, usage_hint: UsageHint) -> Arc<BufferData> {
+        Arc::new(BufferData {
+            id: UnsafeCell::new(None),
+            context_id: self.context.id(),
+            dropper: Box::new(PooledBufferDropper {
+                deletion_queue: self.context.deletion_queue(),
+                pool: self.pool.clone(),
+            }),
+            usage_hint: Cell::new(usage_hint),
+            len: Cell::new(len),
+            label: DebugLabel::default(),
+        })
+    }
+}
+
 /// Enumerates the available usage hint for [Buffer]s.
 ///
 /// A usage hint may be used to indicate to the GPU driver how you intend to use the data in the
@@ -1005,7 +1414,7 @@ impl<T> BufferSliceIndex<T> for usize {
     type Output = T;
 
     fn get(self, buffer: &Buffer<[T]>) -> Option<BufferView<Self::Output>> {
-        if self < buffer.data.len {
+        if self < buffer.data.len.get() {
             Some(BufferView {
                 buffer: unsafe { mem::transmute(buffer) },
                 offset_in_bytes: self * mem::size_of::<T>(),
@@ -1032,7 +1441,7 @@ impl<T> BufferSliceIndex<T> for RangeFull {
         Some(BufferView {
             buffer,
             offset_in_bytes: 0,
-            len: buffer.data.len,
+            len: buffer.data.len.get(),
         })
     }
 
@@ -1040,7 +1449,7 @@ impl<T> BufferSliceIndex<T> for RangeFull {
         BufferView {
             buffer,
             offset_in_bytes: 0,
-            len: buffer.data.len,
+            len: buffer.data.len.get(),
         }
     }
 }
@@ -1051,7 +1460,7 @@ impl<T> BufferSliceIndex<T> for Range<usize> {
     fn get(self, buffer: &Buffer<[T]>) -> Option<BufferView<Self::Output>> {
         let Range { start, end } = self;
 
-        if start > end || end > buffer.data.len {
+        if start > end || end > buffer.data.len.get() {
             None
         } else {
             Some(BufferView {
@@ -1091,11 +1500,11 @@ impl<T> BufferSliceIndex<T> for RangeFrom<usize> {
     type Output = [T];
 
     fn get(self, buffer: &Buffer<[T]>) -> Option<BufferView<Self::Output>> {
-        buffer.get(self.start..buffer.data.len)
+        buffer.get(self.start..buffer.data.len.get())
     }
 
     unsafe fn get_unchecked(self, buffer: &Buffer<[T]>) -> BufferView<Self::Output> {
-        buffer.get_unchecked(self.start..buffer.data.len)
+        buffer.get_unchecked(self.start..buffer.data.len.get())
     }
 }
 
@@ -1348,6 +1757,8 @@ where
                 self.offset_in_bytes as i32,
                 data,
             );
+
+            state.record_buffer_upload();
         };
 
         Progress::Finished(())
@@ -1395,72 +1806,693 @@ where
                 self.offset_in_bytes as i32,
                 data,
             );
+
+            state.record_buffer_upload();
         };
 
         Progress::Finished(())
     }
 }
 
-/// Command for downloading data from a [Buffer] or a sub-section of a buffer as viewed by a
-/// [BufferView].
+/// Command for re-specifying the data store of a [Buffer].
 ///
-/// See [Buffer::download_command] and [BufferView::download_command] for details.
-pub struct DownloadCommand<T>
+/// See [Buffer::respecify_command] for details.
+pub struct RespecifyCommand<T, D>
 where
     T: ?Sized,
 {
-    data: Arc<BufferData>,
-    state: DownloadState,
-    offset_in_bytes: usize,
-    len: usize,
+    buffer_data: Arc<BufferData>,
+    data: D,
+    usage_hint: UsageHint,
     _marker: marker::PhantomData<T>,
 }
 
-enum DownloadState {
-    Initial,
-    Copied(Option<WebGlBuffer>),
-}
-
-unsafe impl<T> GpuTask<Connection> for DownloadCommand<T> {
-    type Output = Box<T>;
+unsafe impl<T, D> GpuTask<Connection> for RespecifyCommand<T, D>
+where
+    D: Borrow<T>,
+{
+    type Output = ();
 
     fn context_id(&self) -> ContextId {
-        ContextId::Id(self.data.context_id)
+        ContextId::Id(self.buffer_data.context_id)
     }
 
     fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
-        match self.state {
-            DownloadState::Initial => {
-                let (gl, state) = unsafe { connection.unpack_mut() };
-                let read_buffer = GL::create_buffer(&gl).unwrap();
-                let size_in_bytes = mem::size_of::<T>();
+        let (gl, state) = unsafe { connection.unpack_mut() };
 
-                state
-                    .bind_copy_write_buffer(Some(&read_buffer))
-                    .apply(gl)
-                    .unwrap();
+        unsafe {
+            self.buffer_data
+                .id()
+                .unwrap()
+                .with_value_unchecked(|buffer_object| {
+                    state
+                        .bind_copy_write_buffer(Some(&buffer_object))
+                        .apply(gl)
+                        .unwrap();
+                });
+        }
 
-                gl.buffer_data_with_i32(
-                    GL::COPY_WRITE_BUFFER,
-                    size_in_bytes as i32,
-                    GL::STREAM_READ,
-                );
+        unsafe {
+            let data = slice::from_raw_parts(
+                self.data.borrow() as *const _ as *const u8,
+                mem::size_of::<T>(),
+            );
 
-                unsafe {
-                    self.data
-                        .id()
-                        .unwrap()
-                        .with_value_unchecked(|buffer_object| {
-                            state
-                                .bind_copy_read_buffer(Some(&buffer_object))
-                                .apply(gl)
-                                .unwrap();
-                        });
-                }
+            gl.buffer_data_with_u8_array(GL::COPY_WRITE_BUFFER, data, self.usage_hint.gl_id());
 
-                gl.copy_buffer_sub_data_with_i32_and_i32_and_i32(
-                    GL::COPY_READ_BUFFER,
-                    GL::COPY_WRITE_BUFFER,
+            state.record_buffer_upload();
+        }
+
+        self.buffer_data.usage_hint.set(self.usage_hint);
+
+        Progress::Finished(())
+    }
+}
+
+unsafe impl<T, D> GpuTask<Connection> for RespecifyCommand<[T], D>
+where
+    D: Borrow<[T]>,
+{
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.buffer_data.context_id)
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        let (gl, state) = unsafe { connection.unpack_mut() };
+
+        unsafe {
+            self.buffer_data
+                .id()
+                .unwrap()
+                .with_value_unchecked(|buffer_object| {
+                    state
+                        .bind_copy_write_buffer(Some(&buffer_object))
+                        .apply(gl)
+                        .unwrap();
+                });
+        }
+
+        let data = self.data.borrow();
+        let len = data.len();
+
+        unsafe {
+            let data =
+                slice::from_raw_parts(data as *const _ as *const u8, len * mem::size_of::<T>());
+
+            gl.buffer_data_with_u8_array(GL::COPY_WRITE_BUFFER, data, self.usage_hint.gl_id());
+
+            state.record_buffer_upload();
+        }
+
+        self.buffer_data.len.set(len);
+        self.buffer_data.usage_hint.set(self.usage_hint);
+
+        Progress::Finished(())
+    }
+}
+
+/// Command for resizing a [Buffer] while preserving its contents.
+///
+/// See [Buffer::resize_command] for details.
+pub struct ResizeCommand<T> {
+    buffer_data: Arc<BufferData>,
+    new_len: usize,
+    _marker: marker::PhantomData<T>,
+}
+
+unsafe impl<T> GpuTask<Connection> for ResizeCommand<T> {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.buffer_data.context_id)
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        let (gl, state) = unsafe { connection.unpack_mut() };
+        let data = &self.buffer_data;
+        let old_id = data.id().unwrap();
+        let old_len = data.len.get();
+
+        let buffer_object = GL::create_buffer(&gl).unwrap();
+
+        state
+            .bind_copy_write_buffer(Some(&buffer_object))
+            .apply(gl)
+            .unwrap();
+
+        // Note that WebGL zero-initializes newly allocated data stores, which takes care of
+        // zeroing any new elements when growing the buffer.
+        gl.buffer_data_with_i32(
+            GL::COPY_WRITE_BUFFER,
+            (self.new_len * mem::size_of::<T>()) as i32,
+            data.usage_hint.get().gl_id(),
+        );
+
+        let copy_size = old_len.min(self.new_len) * mem::size_of::<T>();
+
+        if copy_size > 0 {
+            unsafe {
+                old_id.with_value_unchecked(|old_buffer_object| {
+                    state
+                        .bind_copy_read_buffer(Some(&old_buffer_object))
+                        .apply(gl)
+                        .unwrap();
+                });
+            }
+
+            gl.copy_buffer_sub_data_with_i32_and_i32_and_i32(
+                GL::COPY_READ_BUFFER,
+                GL::COPY_WRITE_BUFFER,
+                0,
+                0,
+                copy_size as i32,
+            );
+        }
+
+        // Release the old buffer object in the same way the DropCommand does.
+        state
+            .vertex_array_cache_mut()
+            .remove_buffer_dependents(old_id, gl);
+
+        let old_buffer_object = unsafe { JsId::into_value(old_id).unchecked_into() };
+
+        state.unref_buffer(&old_buffer_object);
+        gl.delete_buffer(Some(&old_buffer_object));
+
+        unsafe {
+            *data.id.get() = Some(JsId::from_value(buffer_object.into()));
+        }
+
+        data.len.set(self.new_len);
+
+        Progress::Finished(())
+    }
+}
+
+/// Command for uploading data to many (small) regions of one or more [Buffer]s at once.
+///
+/// Uploads that target the same [Buffer] are coalesced: adjacent or overlapping regions are merged
+/// such that the data is transferred with the minimal number of `bufferSubData` calls. When
+/// uploads overlap, the data for the upload that was added last takes precedence.
+///
+/// # Example
+///
+/// ```
+/// # use web_glitz::buffer::Buffer;
+/// # fn wrapper(uniforms: &Buffer<[[f32; 4]]>, transforms: &[[f32; 4]]) {
+/// use web_glitz::buffer::BatchUpload;
+///
+/// let mut batch = BatchUpload::new();
+///
+/// for (i, transform) in transforms.iter().enumerate() {
+///     batch.push(uniforms.get(i).unwrap(), *transform);
+/// }
+/// # }
+/// ```
+///
+/// Here `uniforms` is a [Buffer] that stores an array of `[f32; 4]` values and `transforms` is a
+/// slice of `[f32; 4]` values. Because the regions targeted by the uploads are adjacent, the
+/// `batch` command will upload all values with a single `bufferSubData` call.
+///
+/// # Panics
+///
+/// Adding an upload to a [BatchUpload] panics if the [Buffer] targeted by the upload belongs to a
+/// different context than a [Buffer] targeted by an upload that was added previously.
+pub struct BatchUpload {
+    context_id: ContextId,
+    entries: Vec<BatchUploadEntry>,
+}
+
+struct BatchUploadEntry {
+    buffer_data: Arc<BufferData>,
+    offset_in_bytes: usize,
+    data: Vec<u8>,
+}
+
+impl BatchUpload {
+    /// Creates a new empty [BatchUpload].
+    pub fn new() -> Self {
+        BatchUpload {
+            context_id: ContextId::Any,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Creates a new [BatchUpload] that uploads each of the `uploads` pairs of a [BufferView] and
+    /// the data that is to replace the data viewed by the [BufferView].
+    pub fn from_uploads<T, D>(uploads: &[(BufferView<T>, D)]) -> Self
+    where
+        T: Copy,
+        D: Borrow<T>,
+    {
+        let mut batch = BatchUpload::new();
+
+        for (view, data) in uploads {
+            batch.push(*view, data.borrow());
+        }
+
+        batch
+    }
+
+    /// Adds an upload that will replace the data viewed by the `view` with the given `data`.
+    pub fn push<T, D>(&mut self, view: BufferView<T>, data: D)
+    where
+        T: Copy,
+        D: Borrow<T>,
+    {
+        let bytes = unsafe {
+            slice::from_raw_parts(data.borrow() as *const _ as *const u8, mem::size_of::<T>())
+        };
+
+        self.push_bytes(view.buffer_data(), view.offset_in_bytes(), bytes);
+    }
+
+    /// Adds an upload that will replace the data viewed by the `view` with the given `data`.
+    ///
+    /// If the `data` contains more elements than the `view`, then only the first `view.len()`
+    /// elements are uploaded.
+    pub fn push_slice<T, D>(&mut self, view: BufferView<[T]>, data: D)
+    where
+        T: Copy,
+        D: Borrow<[T]>,
+    {
+        let data = data.borrow();
+        let len = data.len().min(view.len());
+
+        let bytes = unsafe {
+            slice::from_raw_parts(data as *const _ as *const u8, len * mem::size_of::<T>())
+        };
+
+        self.push_bytes(view.buffer_data(), view.offset_in_bytes(), bytes);
+    }
+
+    fn push_bytes(&mut self, buffer_data: &Arc<BufferData>, offset_in_bytes: usize, bytes: &[u8]) {
+        let context_id = ContextId::Id(buffer_data.context_id());
+
+        if self.context_id == ContextId::Any {
+            self.context_id = context_id;
+        } else if self.context_id != context_id {
+            panic!(
+                "The buffer belongs to a different context than the other buffers in the batch."
+            );
+        }
+
+        self.entries.push(BatchUploadEntry {
+            buffer_data: buffer_data.clone(),
+            offset_in_bytes,
+            data: bytes.to_vec(),
+        });
+    }
+}
+
+impl Default for BatchUpload {
+    fn default() -> Self {
+        BatchUpload::new()
+    }
+}
+
+unsafe impl GpuTask<Connection> for BatchUpload {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        self.context_id
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        let (gl, state) = unsafe { connection.unpack_mut() };
+
+        // Group the entries by the buffer they target, retaining the order in which the entries
+        // were added within each group.
+        let mut groups: Vec<(&Arc<BufferData>, Vec<&BatchUploadEntry>)> = Vec::new();
+
+        for entry in self.entries.iter() {
+            let group = groups
+                .iter_mut()
+                .find(|(buffer_data, _)| Arc::ptr_eq(buffer_data, &entry.buffer_data));
+
+            if let Some((_, entries)) = group {
+                entries.push(entry);
+            } else {
+                groups.push((&entry.buffer_data, vec![entry]));
+            }
+        }
+
+        for (buffer_data, entries) in groups {
+            unsafe {
+                buffer_data
+                    .id()
+                    .unwrap()
+                    .with_value_unchecked(|buffer_object| {
+                        state
+                            .bind_copy_write_buffer(Some(&buffer_object))
+                            .apply(gl)
+                            .unwrap();
+                    });
+            }
+
+            let ranges = entries
+                .iter()
+                .map(|entry| entry.offset_in_bytes..entry.offset_in_bytes + entry.data.len());
+
+            for span in coalesce_ranges(ranges) {
+                let mut data = vec![0; span.end - span.start];
+
+                for entry in entries.iter() {
+                    let start = entry.offset_in_bytes;
+                    let end = start + entry.data.len();
+
+                    if start >= span.start && end <= span.end && start < end {
+                        data[start - span.start..end - span.start].copy_from_slice(&entry.data);
+                    }
+                }
+
+                gl.buffer_sub_data_with_i32_and_u8_array(
+                    GL::COPY_WRITE_BUFFER,
+                    span.start as i32,
+                    &data,
+                );
+
+                state.record_buffer_upload();
+            }
+        }
+
+        Progress::Finished(())
+    }
+}
+
+/// Merges the `ranges` into the minimal set of non-overlapping, non-adjacent ranges that cover the
+/// same bytes, sorted by their start. Empty ranges are ignored.
+fn coalesce_ranges<I>(ranges: I) -> Vec<Range<usize>>
+where
+    I: IntoIterator<Item = Range<usize>>,
+{
+    let mut ranges: Vec<Range<usize>> = ranges
+        .into_iter()
+        .filter(|range| range.start < range.end)
+        .collect();
+
+    ranges.sort_by_key(|range| range.start);
+
+    let mut coalesced: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+
+    for range in ranges {
+        if let Some(last) = coalesced.last_mut() {
+            if range.start <= last.end {
+                last.end = last.end.max(range.end);
+
+                continue;
+            }
+        }
+
+        coalesced.push(range);
+    }
+
+    coalesced
+}
+
+const STAGING_RING_SEGMENTS: usize = 3;
+
+/// A ring buffer over a single [Buffer] that hands out views on sub-regions of the buffer for
+/// streaming data that changes every frame (e.g. dynamic vertex data or uniform data).
+///
+/// The ring is divided into 3 equally sized segments (triple-buffering). Data pushed onto the ring
+/// (see [push]) is uploaded to the next unused region in the current segment; when the data does
+/// not fit in the remainder of the current segment, the ring advances to the next segment (wrapping
+/// around to the first segment after the last). Whenever the ring leaves a segment, a fence is
+/// inserted into the GPU command stream. The ring will only re-enter a segment after the GPU has
+/// finished all commands that were submitted before the ring left the segment that follows it. A
+/// [BufferView] returned by [push] therefore remains valid for any tasks that are submitted before
+/// the ring advances two segments past the segment that contains the view (in practice: before
+/// roughly another third of the ring's capacity has been pushed). If the GPU has not yet finished
+/// reading the next segment when the ring needs to advance, [push] returns a [StagingRingFull]
+/// error rather than overwriting data that may still be in use.
+///
+/// # Example
+///
+/// ```
+/// # use web_glitz::runtime::RenderingContext;
+/// # fn wrapper<Rc>(context: &Rc, particles: Vec<[f32; 4]>)
+/// # where
+/// #     Rc: RenderingContext + Clone + 'static,
+/// # {
+/// use web_glitz::buffer::{StagingRing, UsageHint};
+///
+/// let ring = StagingRing::new(context, 3 * 1024, UsageHint::StreamDraw);
+///
+/// // Each frame:
+/// let particle_view = ring.push(particles).unwrap();
+/// # }
+/// ```
+///
+/// Here `context` is a [RenderingContext] and `particles` is a [Vec] of `[f32; 4]` values that is
+/// updated every frame.
+pub struct StagingRing<T, Rc> {
+    context: Rc,
+    buffer: Buffer<[T]>,
+    state: RefCell<StagingRingState>,
+}
+
+impl<T, Rc> StagingRing<T, Rc>
+where
+    T: Copy + 'static,
+    Rc: RenderingContext + Clone + 'static,
+{
+    /// Creates a new [StagingRing] with a capacity of `len` elements of type `T` on the given
+    /// `context`.
+    ///
+    /// See [UsageHint] for details on the `usage_hint`; typically [UsageHint::StreamDraw] is
+    /// appropriate for a ring that streams data that is used only once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is smaller than the number of segments in the ring (3).
+    pub fn new(context: &Rc, len: usize, usage_hint: UsageHint) -> Self {
+        if len < STAGING_RING_SEGMENTS {
+            panic!("A staging ring must have a capacity of at least 3 elements.");
+        }
+
+        // The data in the buffer is never observed before it was initialized: views are only
+        // handed out for regions that have been uploaded to.
+        let buffer = unsafe {
+            context
+                .create_buffer_slice_uninit::<T>(len, usage_hint)
+                .assume_init()
+        };
+
+        StagingRing {
+            context: context.clone(),
+            buffer,
+            state: RefCell::new(StagingRingState::new(len / STAGING_RING_SEGMENTS)),
+        }
+    }
+
+    /// Returns the total number of elements the ring can hold.
+    pub fn capacity(&self) -> usize {
+        self.state.borrow().segment_len * STAGING_RING_SEGMENTS
+    }
+
+    /// Uploads the `data` to the next unused region of the ring and returns a [BufferView] on
+    /// that region.
+    ///
+    /// The upload is submitted to the ring's context immediately. Returns a [StagingRingFull] error
+    /// if the ring needs to advance to its next segment, but the GPU has not yet finished executing
+    /// commands that may still read from that segment. See [StagingRing] for details on how long
+    /// the returned view remains valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `data` contains more elements than fit in a single segment of the ring (a
+    /// third of the ring's capacity).
+    pub fn push<D>(&self, data: D) -> Result<BufferView<[T]>, StagingRingFull>
+    where
+        D: Borrow<[T]> + Send + Sync + 'static,
+    {
+        let len = data.borrow().len();
+        let (start, fence) = self.state.borrow_mut().reserve(len)?;
+
+        if let Some(signalled) = fence {
+            self.context.submit(SegmentFenceCommand {
+                context_id: self.context.id(),
+                signalled,
+                fenced: false,
+            });
+        }
+
+        let view = self.buffer.get(start..start + len).unwrap();
+
+        self.context.submit(view.upload_command(data));
+
+        Ok(view)
+    }
+}
+
+// Tracks which regions of a [StagingRing] are in use, independently of its buffer and context.
+struct StagingRingState {
+    segment_len: usize,
+    current_segment: usize,
+    head: usize,
+    wrapped: bool,
+    segment_fences: [Option<Arc<AtomicBool>>; STAGING_RING_SEGMENTS],
+}
+
+impl StagingRingState {
+    fn new(segment_len: usize) -> Self {
+        StagingRingState {
+            segment_len,
+            current_segment: 0,
+            head: 0,
+            wrapped: false,
+            segment_fences: Default::default(),
+        }
+    }
+
+    // Reserves the next `len` elements and returns the index of the first element. If the ring
+    // left a segment, also returns the flag for a new fence, which the caller must submit (as a
+    // `SegmentFenceCommand`) before submitting any commands that use the reserved region.
+    fn reserve(&mut self, len: usize) -> Result<(usize, Option<Arc<AtomicBool>>), StagingRingFull> {
+        if len > self.segment_len {
+            panic!("The data does not fit in a single segment of the staging ring.");
+        }
+
+        let segment = self.current_segment;
+        let segment_end = (segment + 1) * self.segment_len;
+        let mut fence = None;
+
+        if self.head + len > segment_end {
+            let next_segment = (segment + 1) % STAGING_RING_SEGMENTS;
+
+            // Data in the next segment may be read by any task that was submitted before the ring
+            // left the segment that follows it; that moment is marked by the fence stored for the
+            // next segment.
+            if let Some(signalled) = &self.segment_fences[next_segment] {
+                if !signalled.load(Ordering::Acquire) {
+                    return Err(StagingRingFull);
+                }
+            }
+
+            self.segment_fences[next_segment] = None;
+
+            // Leaving the current segment marks the end of the period in which the data in the
+            // previous segment may be used, unless the ring has not yet used the previous segment.
+            if segment != 0 || self.wrapped {
+                let signalled = Arc::new(AtomicBool::new(false));
+                let previous_segment =
+                    (segment + STAGING_RING_SEGMENTS - 1) % STAGING_RING_SEGMENTS;
+
+                self.segment_fences[previous_segment] = Some(signalled.clone());
+
+                fence = Some(signalled);
+            }
+
+            if next_segment == 0 {
+                self.wrapped = true;
+            }
+
+            self.current_segment = next_segment;
+            self.head = next_segment * self.segment_len;
+        }
+
+        let start = self.head;
+
+        self.head = start + len;
+
+        Ok((start, fence))
+    }
+}
+
+/// Error returned by [StagingRing::push] when the ring cannot advance to its next segment, because
+/// the GPU may still be reading from that segment.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct StagingRingFull;
+
+struct SegmentFenceCommand {
+    context_id: u64,
+    signalled: Arc<AtomicBool>,
+    fenced: bool,
+}
+
+unsafe impl GpuTask<Connection> for SegmentFenceCommand {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.context_id)
+    }
+
+    fn progress(&mut self, _connection: &mut Connection) -> Progress<Self::Output> {
+        if self.fenced {
+            self.signalled.store(true, Ordering::Release);
+
+            Progress::Finished(())
+        } else {
+            self.fenced = true;
+
+            Progress::ContinueFenced
+        }
+    }
+}
+
+/// Command for downloading data from a [Buffer] or a sub-section of a buffer as viewed by a
+/// [BufferView].
+///
+/// See [Buffer::download_command] and [BufferView::download_command] for details.
+pub struct DownloadCommand<T>
+where
+    T: ?Sized,
+{
+    data: Arc<BufferData>,
+    state: DownloadState,
+    offset_in_bytes: usize,
+    len: usize,
+    _marker: marker::PhantomData<T>,
+}
+
+enum DownloadState {
+    Initial,
+    Copied(Option<WebGlBuffer>),
+}
+
+unsafe impl<T> GpuTask<Connection> for DownloadCommand<T> {
+    type Output = Box<T>;
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.data.context_id)
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        match self.state {
+            DownloadState::Initial => {
+                let (gl, state) = unsafe { connection.unpack_mut() };
+                let read_buffer = GL::create_buffer(&gl).unwrap();
+                let size_in_bytes = mem::size_of::<T>();
+
+                state
+                    .bind_copy_write_buffer(Some(&read_buffer))
+                    .apply(gl)
+                    .unwrap();
+
+                gl.buffer_data_with_i32(
+                    GL::COPY_WRITE_BUFFER,
+                    size_in_bytes as i32,
+                    GL::STREAM_READ,
+                );
+
+                unsafe {
+                    self.data
+                        .id()
+                        .unwrap()
+                        .with_value_unchecked(|buffer_object| {
+                            state
+                                .bind_copy_read_buffer(Some(&buffer_object))
+                                .apply(gl)
+                                .unwrap();
+                        });
+                }
+
+                gl.copy_buffer_sub_data_with_i32_and_i32_and_i32(
+                    GL::COPY_READ_BUFFER,
+                    GL::COPY_WRITE_BUFFER,
                     self.offset_in_bytes as i32,
                     0,
                     size_in_bytes as i32,
@@ -1579,16 +2611,146 @@ unsafe impl<T> GpuTask<Connection> for DownloadCommand<[T]> {
     }
 }
 
-trait BufferObjectDropper {
-    fn drop_buffer_object(&self, id: JsId);
+/// Returned from [RenderingContext::map_read], a [Future] that resolves to a [MappedRead] view on
+/// the contents of a buffer.
+///
+/// See [RenderingContext::map_read] for details.
+pub struct MapRead<'a, T>
+where
+    T: ?Sized,
+{
+    execution: Execution<Box<T>>,
+    _marker: marker::PhantomData<&'a T>,
+}
+
+impl<'a, T> MapRead<'a, T>
+where
+    T: ?Sized,
+{
+    pub(crate) fn new(execution: Execution<Box<T>>) -> Self {
+        MapRead {
+            execution,
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Future for MapRead<'a, T>
+where
+    T: ?Sized,
+{
+    type Output = MappedRead<'a, T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let execution = unsafe { &mut self.get_unchecked_mut().execution };
+
+        match unsafe { Pin::new_unchecked(execution) }.poll(cx) {
+            Poll::Ready(data) => Poll::Ready(MappedRead {
+                data,
+                _marker: marker::PhantomData,
+            }),
+            Poll::Pending => Poll::Pending,
+        }
+    }
 }
 
-impl<T> BufferObjectDropper for T
+/// A read-only view on a copy of the contents of a buffer, as obtained with
+/// [RenderingContext::map_read].
+///
+/// Dereferences to the copied contents. The view borrows the buffer it was obtained from for as
+/// long as it is held; the contents reflect the state of the buffer at the time the read was
+/// executed.
+pub struct MappedRead<'a, T>
+where
+    T: ?Sized,
+{
+    data: Box<T>,
+    _marker: marker::PhantomData<&'a T>,
+}
+
+impl<'a, T> Deref for MappedRead<'a, T>
 where
-    T: RenderingContext,
+    T: ?Sized,
 {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.data
+    }
+}
+
+trait BufferObjectDropper {
+    fn drop_buffer_object(&self, id: JsId);
+}
+
+impl BufferObjectDropper for DeletionQueue {
     fn drop_buffer_object(&self, id: JsId) {
-        self.submit(DropCommand { id });
+        self.schedule(DropCommand { id });
+    }
+}
+
+struct PooledBufferDropper {
+    deletion_queue: DeletionQueue,
+    pool: std::rc::Rc<RefCell<BufferPool>>,
+}
+
+impl BufferObjectDropper for PooledBufferDropper {
+    fn drop_buffer_object(&self, id: JsId) {
+        self.deletion_queue.schedule(ReleaseToPoolCommand {
+            id,
+            pool: self.pool.clone(),
+            fenced: false,
+        });
+    }
+}
+
+// Not part of the public API. The state shared by all frame allocators of a context: the generator
+// for the IDs of the buffers they allocate and the pool of buffer objects they recycle, see
+// `RenderingContext::frame_allocator`.
+#[doc(hidden)]
+#[derive(Clone)]
+pub struct SharedBufferPool {
+    object_id_gen: ObjectIdGen,
+    pool: std::rc::Rc<RefCell<BufferPool>>,
+}
+
+impl SharedBufferPool {
+    pub(crate) fn new(object_id_gen: ObjectIdGen) -> Self {
+        SharedBufferPool {
+            object_id_gen,
+            pool: std::rc::Rc::new(RefCell::new(BufferPool::new())),
+        }
+    }
+}
+
+/// Buffer objects that are available for reuse by a [FrameAllocator], keyed by their size in bytes
+/// and their usage hint.
+pub(crate) struct BufferPool {
+    free: FnvHashMap<(usize, u32), Vec<JsId>>,
+}
+
+impl BufferPool {
+    fn new() -> Self {
+        BufferPool {
+            free: FnvHashMap::default(),
+        }
+    }
+
+    fn take(&mut self, size_in_bytes: usize, usage: u32) -> Option<JsId> {
+        self.free
+            .get_mut(&(size_in_bytes, usage))
+            .and_then(|ids| ids.pop())
+    }
+
+    fn release(&mut self, size_in_bytes: usize, usage: u32, id: JsId) {
+        self.free
+            .entry((size_in_bytes, usage))
+            .or_insert_with(Vec::new)
+            .push(id);
+    }
+
+    fn drain(&mut self) -> impl Iterator<Item = JsId> + '_ {
+        self.free.drain().flat_map(|(_, ids)| ids)
     }
 }
 
@@ -1596,8 +2758,9 @@ pub(crate) struct BufferData {
     id: UnsafeCell<Option<JsId>>,
     context_id: u64,
     dropper: Box<dyn BufferObjectDropper>,
-    len: usize,
-    usage_hint: UsageHint,
+    len: Cell<usize>,
+    usage_hint: Cell<UsageHint>,
+    label: DebugLabel,
 }
 
 impl BufferData {
@@ -1608,6 +2771,10 @@ impl BufferData {
     pub(crate) fn context_id(&self) -> u64 {
         self.context_id
     }
+
+    pub(crate) fn label(&self) -> &DebugLabel {
+        &self.label
+    }
 }
 
 impl Drop for BufferData {
@@ -1646,7 +2813,11 @@ unsafe impl<T> GpuTask<Connection> for AllocateUninitCommand<T> {
 
         let size = mem::size_of::<T>();
 
-        gl.buffer_data_with_i32(GL::COPY_WRITE_BUFFER, size as i32, data.usage_hint.gl_id());
+        gl.buffer_data_with_i32(
+            GL::COPY_WRITE_BUFFER,
+            size as i32,
+            data.usage_hint.get().gl_id(),
+        );
 
         unsafe {
             *data.id.get() = Some(JsId::from_value(buffer_object.into()));
@@ -1674,9 +2845,13 @@ unsafe impl<T> GpuTask<Connection> for AllocateUninitCommand<[T]> {
             .apply(gl)
             .unwrap();
 
-        let size = mem::size_of::<T>() * data.len;
+        let size = mem::size_of::<T>() * data.len.get();
 
-        gl.buffer_data_with_i32(GL::COPY_WRITE_BUFFER, size as i32, data.usage_hint.gl_id());
+        gl.buffer_data_with_i32(
+            GL::COPY_WRITE_BUFFER,
+            size as i32,
+            data.usage_hint.get().gl_id(),
+        );
 
         unsafe {
             *data.id.get() = Some(JsId::from_value(buffer_object.into()));
@@ -1722,7 +2897,13 @@ where
                 mem::size_of::<T>(),
             );
 
-            gl.buffer_data_with_u8_array(GL::COPY_WRITE_BUFFER, initial, data.usage_hint.gl_id());
+            gl.buffer_data_with_u8_array(
+                GL::COPY_WRITE_BUFFER,
+                initial,
+                data.usage_hint.get().gl_id(),
+            );
+
+            state.record_buffer_upload();
         }
 
         unsafe {
@@ -1759,7 +2940,13 @@ where
             let size = initial.len() * mem::size_of::<T>();
             let initial = slice::from_raw_parts(initial as *const _ as *const u8, size);
 
-            gl.buffer_data_with_u8_array(GL::COPY_WRITE_BUFFER, initial, data.usage_hint.gl_id());
+            gl.buffer_data_with_u8_array(
+                GL::COPY_WRITE_BUFFER,
+                initial,
+                data.usage_hint.get().gl_id(),
+            );
+
+            state.record_buffer_upload();
         }
 
         unsafe {
@@ -1770,6 +2957,196 @@ where
     }
 }
 
+struct PooledAllocateCommand<D, T>
+where
+    T: ?Sized,
+{
+    data: Arc<BufferData>,
+    initial: D,
+    pool: std::rc::Rc<RefCell<BufferPool>>,
+    _marker: marker::PhantomData<T>,
+}
+
+impl<D, T> PooledAllocateCommand<D, T>
+where
+    T: ?Sized,
+{
+    fn allocate(&self, connection: &mut Connection, initial: &[u8]) {
+        let (gl, state) = unsafe { connection.unpack_mut() };
+        let data = &self.data;
+        let usage = data.usage_hint.get().gl_id();
+        let pooled = self.pool.borrow_mut().take(initial.len(), usage);
+
+        if let Some(id) = pooled {
+            unsafe {
+                id.with_value_unchecked(|buffer_object| {
+                    state
+                        .bind_copy_write_buffer(Some(&buffer_object))
+                        .apply(gl)
+                        .unwrap();
+                });
+            }
+
+            gl.buffer_sub_data_with_i32_and_u8_array(GL::COPY_WRITE_BUFFER, 0, initial);
+
+            state.record_buffer_upload();
+
+            unsafe {
+                *data.id.get() = Some(id);
+            }
+        } else {
+            let buffer_object = GL::create_buffer(&gl).unwrap();
+
+            state
+                .bind_copy_write_buffer(Some(&buffer_object))
+                .apply(gl)
+                .unwrap();
+
+            gl.buffer_data_with_u8_array(GL::COPY_WRITE_BUFFER, initial, usage);
+
+            state.record_buffer_upload();
+
+            unsafe {
+                *data.id.get() = Some(JsId::from_value(buffer_object.into()));
+            }
+        }
+    }
+}
+
+unsafe impl<D, T> GpuTask<Connection> for PooledAllocateCommand<D, T>
+where
+    D: Borrow<T>,
+{
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.data.context_id)
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        let initial = unsafe {
+            slice::from_raw_parts(
+                self.initial.borrow() as *const _ as *const u8,
+                mem::size_of::<T>(),
+            )
+        };
+
+        self.allocate(connection, initial);
+
+        Progress::Finished(())
+    }
+}
+
+unsafe impl<D, T> GpuTask<Connection> for PooledAllocateCommand<D, [T]>
+where
+    D: Borrow<[T]>,
+{
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.data.context_id)
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        let initial = self.initial.borrow();
+        let size = initial.len() * mem::size_of::<T>();
+        let initial = unsafe { slice::from_raw_parts(initial as *const _ as *const u8, size) };
+
+        self.allocate(connection, initial);
+
+        Progress::Finished(())
+    }
+}
+
+struct ReleaseToPoolCommand {
+    id: JsId,
+    pool: std::rc::Rc<RefCell<BufferPool>>,
+    fenced: bool,
+}
+
+unsafe impl GpuTask<Connection> for ReleaseToPoolCommand {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Any
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        // Wait for a fence first, so that the buffer object is only returned to the pool once the
+        // GPU has finished executing any commands that may still read from it.
+        if !self.fenced {
+            self.fenced = true;
+
+            return Progress::ContinueFenced;
+        }
+
+        let (gl, state) = unsafe { connection.unpack_mut() };
+
+        unsafe {
+            self.id.with_value_unchecked(|buffer_object| {
+                state
+                    .bind_copy_write_buffer(Some(&buffer_object))
+                    .apply(gl)
+                    .unwrap();
+            });
+        }
+
+        // The size and usage are queried rather than tracked, as the buffer's data store may have
+        // been re-specified since it was allocated.
+        let size_in_bytes = gl
+            .get_buffer_parameter(GL::COPY_WRITE_BUFFER, GL::BUFFER_SIZE)
+            .as_f64()
+            .unwrap() as usize;
+        let usage = gl
+            .get_buffer_parameter(GL::COPY_WRITE_BUFFER, GL::BUFFER_USAGE)
+            .as_f64()
+            .unwrap() as u32;
+
+        self.pool
+            .borrow_mut()
+            .release(size_in_bytes, usage, self.id);
+
+        Progress::Finished(())
+    }
+}
+
+pub(crate) struct PurgePoolCommand {
+    pool: std::rc::Rc<RefCell<BufferPool>>,
+}
+
+impl PurgePoolCommand {
+    pub(crate) fn new(shared_pool: &SharedBufferPool) -> Self {
+        PurgePoolCommand {
+            pool: shared_pool.pool.clone(),
+        }
+    }
+}
+
+unsafe impl GpuTask<Connection> for PurgePoolCommand {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Any
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        let (gl, state) = unsafe { connection.unpack_mut() };
+
+        for id in self.pool.borrow_mut().drain() {
+            state
+                .vertex_array_cache_mut()
+                .remove_buffer_dependents(id, gl);
+
+            let value = unsafe { JsId::into_value(id).unchecked_into() };
+
+            state.unref_buffer(&value);
+            gl.delete_buffer(Some(&value));
+        }
+
+        Progress::Finished(())
+    }
+}
+
 struct DropCommand {
     id: JsId,
 }
@@ -1796,3 +3173,172 @@ unsafe impl GpuTask<Connection> for DropCommand {
         Progress::Finished(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NoopDropper;
+
+    impl BufferObjectDropper for NoopDropper {
+        fn drop_buffer_object(&self, _id: JsId) {}
+    }
+
+    fn buffer<T>(len: usize) -> Buffer<T>
+    where
+        T: ?Sized,
+    {
+        Buffer {
+            object_id: 0,
+            data: Arc::new(BufferData {
+                id: UnsafeCell::new(None),
+                context_id: 0,
+                dropper: Box::new(NoopDropper),
+                len: Cell::new(len),
+                usage_hint: Cell::new(UsageHint::StaticDraw),
+                label: DebugLabel::default(),
+            }),
+            _marker: marker::PhantomData,
+        }
+    }
+
+    #[test]
+    fn test_buffer_size_in_bytes() {
+        let buffer: Buffer<[f32; 4]> = buffer(1);
+
+        assert_eq!(buffer.size_in_bytes(), 16);
+    }
+
+    #[test]
+    fn test_buffer_slice_len_and_size_in_bytes() {
+        let buffer: Buffer<[[f32; 4]]> = buffer(3);
+
+        assert_eq!(buffer.len(), 3);
+        assert!(!buffer.is_empty());
+        assert_eq!(buffer.size_in_bytes(), 48);
+
+        let view = buffer.get(1..).unwrap();
+
+        assert_eq!(view.len(), 2);
+        assert!(!view.is_empty());
+        assert_eq!(view.size_in_bytes(), 32);
+
+        let empty = buffer.get(3..).unwrap();
+
+        assert!(empty.is_empty());
+        assert_eq!(empty.size_in_bytes(), 0);
+    }
+
+    #[test]
+    fn test_empty_buffer_slice() {
+        let buffer: Buffer<[u32]> = buffer(0);
+
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.size_in_bytes(), 0);
+    }
+
+    #[test]
+    fn test_coalesce_ranges_disjoint() {
+        assert_eq!(coalesce_ranges(vec![8..12, 0..4]), vec![0..4, 8..12]);
+    }
+
+    #[test]
+    fn test_coalesce_ranges_adjacent() {
+        assert_eq!(coalesce_ranges(vec![0..4, 8..12, 4..8]), vec![0..12]);
+    }
+
+    #[test]
+    fn test_coalesce_ranges_overlapping() {
+        assert_eq!(
+            coalesce_ranges(vec![0..6, 4..10, 16..20, 2..3]),
+            vec![0..10, 16..20]
+        );
+    }
+
+    #[test]
+    fn test_coalesce_ranges_contained() {
+        assert_eq!(coalesce_ranges(vec![4..8, 0..16, 12..14]), vec![0..16]);
+    }
+
+    #[test]
+    fn test_coalesce_ranges_empty() {
+        assert_eq!(coalesce_ranges(Vec::new()), Vec::<Range<usize>>::new());
+        assert_eq!(coalesce_ranges(vec![4..4, 8..12, 12..12]), vec![8..12]);
+    }
+
+    #[test]
+    fn test_staging_ring_state_fills_segments_in_order() {
+        let mut state = StagingRingState::new(4);
+
+        assert_eq!(state.reserve(3).unwrap().0, 0);
+        assert_eq!(state.reserve(1).unwrap().0, 3);
+
+        // Does not fit in the remainder of segment 0; the ring has not yet used the previous
+        // segment, so no fence is needed.
+        let (start, fence) = state.reserve(2).unwrap();
+
+        assert_eq!(start, 4);
+        assert!(fence.is_none());
+
+        // Leaving segment 1 fences segment 0.
+        let (start, fence) = state.reserve(3).unwrap();
+
+        assert_eq!(start, 8);
+        assert!(fence.is_some());
+    }
+
+    #[test]
+    fn test_staging_ring_state_wrap_around() {
+        let mut state = StagingRingState::new(4);
+
+        assert_eq!(state.reserve(4).unwrap().0, 0);
+        assert_eq!(state.reserve(4).unwrap().0, 4);
+
+        let (_, fence_0) = state.reserve(4).unwrap();
+
+        fence_0.unwrap().store(true, Ordering::Release);
+
+        // Wraps around to segment 0, which was released by the fence set when leaving segment 1.
+        let (start, fence_1) = state.reserve(4).unwrap();
+
+        assert_eq!(start, 0);
+        assert!(fence_1.is_some());
+
+        // After wrapping, leaving segment 0 fences the last segment.
+        fence_1.unwrap().store(true, Ordering::Release);
+
+        let (start, fence_2) = state.reserve(4).unwrap();
+
+        assert_eq!(start, 4);
+        assert!(fence_2.is_some());
+    }
+
+    #[test]
+    fn test_staging_ring_state_full() {
+        let mut state = StagingRingState::new(4);
+
+        state.reserve(4).unwrap();
+        state.reserve(4).unwrap();
+
+        let (_, fence) = state.reserve(4).unwrap();
+        let fence = fence.unwrap();
+
+        // The GPU has not yet passed the fence for segment 0.
+        assert_eq!(state.reserve(1).err(), Some(StagingRingFull));
+
+        // A failed reservation does not advance the ring.
+        assert_eq!(state.reserve(1).err(), Some(StagingRingFull));
+
+        fence.store(true, Ordering::Release);
+
+        assert_eq!(state.reserve(1).unwrap().0, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_staging_ring_state_data_larger_than_segment() {
+        let mut state = StagingRingState::new(4);
+
+        state.reserve(5).unwrap();
+    }
+}
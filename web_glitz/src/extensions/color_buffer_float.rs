@@ -67,6 +67,10 @@ pub struct Extension {
 }
 
 impl Extension {
+    pub(crate) fn context_id(&self) -> u64 {
+        self.context_id
+    }
+
     /// Wraps an attachable floating point `image` in a type that can be attached to a
     /// [RenderTargetDescriptor] without causing a type error.
     ///
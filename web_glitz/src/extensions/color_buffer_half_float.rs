@@ -0,0 +1,234 @@
+//! Allows images that use a half-precision floating point internal format to be attached to render
+//! targets.
+//!
+//! Allows images that use the following internal formats to be attached to a
+//! [RenderTargetDescriptor] or [MultisampleRenderTargetDescriptor]:
+//!
+//! - [R16F]
+//! - [RG16F]
+//! - [RGBA16F]
+//!
+//! Many devices (notably mobile devices) that cannot render to 32-bit floating point formats can
+//! still render to 16-bit floating point formats. Where the [color_buffer_float] extension enables
+//! rendering to both, this extension only enables rendering to the 16-bit formats and may therefore
+//! be available on a wider range of devices. A renderer that only needs 16-bit floating point
+//! attachments (e.g. for HDR rendering to [RGBA16F] images) should prefer this extension.
+//!
+//! This extension uses an [Extended] wrapper type to act as a type proof for the availability of
+//! this extension without requiring additional runtime checks when attaching extended images.
+//!
+//! # Example
+//!
+//! ```
+//! # use web_glitz::runtime::RenderingContext;
+//! # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
+//! use web_glitz::extensions::color_buffer_half_float::Extension as ColorBufferHalfFloatExtension;
+//! use web_glitz::image::MipmapLevels;
+//! use web_glitz::image::format::RGBA16F;
+//! use web_glitz::image::texture_2d::Texture2DDescriptor;
+//! use web_glitz::rendering::{RenderTargetDescriptor, LoadOp, StoreOp};
+//!
+//! let extension: Option<ColorBufferHalfFloatExtension> = context.get_extension();
+//!
+//! if let Some(extension) = extension {
+//!     let mut texture = context.try_create_texture_2d(&Texture2DDescriptor{
+//!         format: RGBA16F,
+//!         width: 500,
+//!         height: 500,
+//!         levels: MipmapLevels::Partial(1)
+//!     }).unwrap();
+//!
+//!     let render_target_descriptor = RenderTargetDescriptor::new()
+//!         .attach_color_float(
+//!             extension.extend(texture.base_level_mut()), // Extend the image reference
+//!             LoadOp::Load,
+//!             StoreOp::Store
+//!         );
+//!
+//!     let render_target = context.create_render_target(render_target_descriptor);
+//! }
+//! # }
+//! ```
+//!
+//! Here `context` is a [RenderingContext].
+//!
+//! The one- and two-channel formats are useful for packing data into a compact render target,
+//! e.g. for a velocity buffer that is later sampled by a motion blur pass:
+//!
+//! ```
+//! # use web_glitz::runtime::RenderingContext;
+//! # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
+//! use web_glitz::extensions::color_buffer_half_float::Extension as ColorBufferHalfFloatExtension;
+//! use web_glitz::image::MipmapLevels;
+//! use web_glitz::image::format::RG16F;
+//! use web_glitz::image::sampler::SamplerDescriptor;
+//! use web_glitz::image::texture_2d::Texture2DDescriptor;
+//! use web_glitz::rendering::{RenderTargetDescriptor, LoadOp, StoreOp};
+//!
+//! let extension: Option<ColorBufferHalfFloatExtension> = context.get_extension();
+//!
+//! if let Some(extension) = extension {
+//!     let mut velocity = context.try_create_texture_2d(&Texture2DDescriptor{
+//!         format: RG16F,
+//!         width: 500,
+//!         height: 500,
+//!         levels: MipmapLevels::Partial(1)
+//!     }).unwrap();
+//!
+//!     {
+//!         let render_target = context.create_render_target(
+//!             RenderTargetDescriptor::new().attach_color_float(
+//!                 extension.extend(velocity.base_level_mut()),
+//!                 LoadOp::Clear([0.0; 4]),
+//!                 StoreOp::Store
+//!             )
+//!         );
+//!
+//!         // Create and submit a render pass that writes velocities to the render target...
+//!     }
+//!
+//!     // Sample the velocities in the motion blur pass.
+//!     let sampler = context.create_sampler(&SamplerDescriptor::default());
+//!     let sampled_velocity = velocity.float_sampled(&sampler);
+//! }
+//! # }
+//! ```
+//!
+//! [color_buffer_float]: crate::extensions::color_buffer_float
+use std::ops::{Deref, DerefMut};
+
+use crate::image::format::{FloatRenderable as BaseFloatRenderable, R16F, RG16F, RGBA16F};
+use crate::rendering::render_target::{AttachColorFloat, AttachMultisampleColorFloat};
+use crate::rendering::{AsAttachment, AsMultisampleAttachment, Attachment, MultisampleAttachment};
+use crate::runtime::Connection;
+
+/// Extension object for the [color_buffer_half_float] extension.
+///
+/// See the [color_buffer_half_float] module documentation for details.
+#[derive(Clone, Debug)]
+pub struct Extension {
+    context_id: u64,
+}
+
+impl Extension {
+    pub(crate) fn context_id(&self) -> u64 {
+        self.context_id
+    }
+
+    /// Wraps an attachable half-precision floating point `image` in a type that can be attached to
+    /// a [RenderTargetDescriptor] without causing a type error.
+    ///
+    /// The image's internal format must implement [FloatRenderable].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the image belongs to a different context than the extension.
+    pub fn extend<I>(&self, mut image: I) -> Extended<I>
+    where
+        I: AsAttachment,
+        I::Format: FloatRenderable,
+    {
+        if image.as_attachment().into_data().context_id != self.context_id {
+            panic!("Attachment image belongs to a different context than this extension.");
+        }
+
+        Extended { image }
+    }
+
+    /// Wraps an attachable multisample half-precision floating point `image` in a type that can be
+    /// attached to a [MultisampleRenderTargetDescriptor] without causing a type error.
+    ///
+    /// The image's internal format must implement [FloatRenderable].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the image belongs to a different context than the extension.
+    pub fn extend_multisample<I>(&self, mut image: I) -> Extended<I>
+    where
+        I: AsMultisampleAttachment,
+        I::SampleFormat: FloatRenderable,
+    {
+        if image.as_multisample_attachment().into_data().context_id != self.context_id {
+            panic!("Attachment image belongs to a different context than this extension.");
+        }
+
+        Extended { image }
+    }
+}
+
+impl super::Extension for Extension {
+    fn try_init(connection: &mut Connection, context_id: u64) -> Option<Self> {
+        let (gl, _) = unsafe { connection.unpack() };
+
+        gl.get_extension("EXT_color_buffer_half_float")
+            .ok()
+            .flatten()
+            .map(|_| Extension { context_id })
+    }
+}
+
+/// Marker trait for internal image format types for which this extension enables rendering.
+pub unsafe trait FloatRenderable {}
+
+unsafe impl<F> FloatRenderable for F where F: BaseFloatRenderable {}
+
+unsafe impl FloatRenderable for R16F {}
+unsafe impl FloatRenderable for RG16F {}
+unsafe impl FloatRenderable for RGBA16F {}
+
+/// Wrapper type for attachable images that acts as a type proof for the availability of this
+/// extension, allowing the attachment of images that use a half-precision floating point internal
+/// format.
+pub struct Extended<I> {
+    image: I,
+}
+
+impl<I> Deref for Extended<I> {
+    type Target = I;
+
+    fn deref(&self) -> &Self::Target {
+        &self.image
+    }
+}
+
+impl<I> DerefMut for Extended<I> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.image
+    }
+}
+
+impl<I> AsAttachment for Extended<I>
+where
+    I: AsAttachment,
+{
+    type Format = I::Format;
+
+    fn as_attachment(&mut self) -> Attachment<Self::Format> {
+        self.image.as_attachment()
+    }
+}
+
+impl<I> AsMultisampleAttachment for Extended<I>
+where
+    I: AsMultisampleAttachment,
+{
+    type SampleFormat = I::SampleFormat;
+
+    fn as_multisample_attachment(&mut self) -> MultisampleAttachment<Self::SampleFormat> {
+        self.image.as_multisample_attachment()
+    }
+}
+
+unsafe impl<I> AttachColorFloat for Extended<I>
+where
+    I: AsAttachment,
+    I::Format: FloatRenderable,
+{
+}
+
+unsafe impl<I> AttachMultisampleColorFloat for Extended<I>
+where
+    I: AsMultisampleAttachment,
+    I::SampleFormat: FloatRenderable,
+{
+}
@@ -21,6 +21,8 @@
 use crate::runtime::Connection;
 
 pub mod color_buffer_float;
+pub mod color_buffer_half_float;
+pub mod multi_draw;
 pub mod texture_float_linear;
 
 /// Trait implemented for extension objects, used by [RenderingContext::get_extension] to
@@ -0,0 +1,72 @@
+//! Allows multiple draws of the same graphics pipeline to be submitted with a single call.
+//!
+//! When this extension is available, the [GraphicsPipelineTaskBuilder::multi_draw_arrays] and
+//! [GraphicsPipelineTaskBuilder::multi_draw_elements] commands may be added to a pipeline task.
+//! These commands require a reference to the extension object, which acts as a type proof for the
+//! availability of this extension; the commands can therefore not be added to a task unless the
+//! extension is available.
+//!
+//! Note that [GraphicsPipelineTaskBuilder::draw_each] and
+//! [GraphicsPipelineTaskBuilder::draw_indexed_each] will also use this extension if it is
+//! available, but fall back to a sequence of individual draw calls when it is not.
+//!
+//! # Example
+//!
+//! ```
+//! # use web_glitz::runtime::RenderingContext;
+//! # use web_glitz::rendering::DefaultRGBBuffer;
+//! # use web_glitz::rendering::DefaultRenderTarget;
+//! # use web_glitz::buffer::BufferView;
+//! # use web_glitz::pipeline::graphics::{GraphicsPipeline, Vertex};
+//! # fn wrapper<Rc, V>(
+//! #     context: &Rc,
+//! #     mut render_target: DefaultRenderTarget<DefaultRGBBuffer, ()>,
+//! #     vertex_buffers: BufferView<[V]>,
+//! #     graphics_pipeline: GraphicsPipeline<V, (), ()>
+//! # )
+//! # where
+//! #     Rc: RenderingContext,
+//! #     V: Vertex,
+//! # {
+//! use web_glitz::extensions::multi_draw::Extension as MultiDrawExtension;
+//!
+//! let extension: Option<MultiDrawExtension> = context.get_extension();
+//!
+//! if let Some(extension) = extension {
+//!     let render_pass = render_target.create_render_pass(|framebuffer| {
+//!         framebuffer.pipeline_task(&graphics_pipeline, |active_pipeline| {
+//!             active_pipeline.task_builder()
+//!                 .bind_vertex_buffers(vertex_buffers)
+//!                 .bind_resources(())
+//!                 .multi_draw_arrays(&extension, &[0, 36, 60], &[36, 24, 12])
+//!                 .finish()
+//!         })
+//!     });
+//! }
+//! # }
+//! ```
+//!
+//! Here `context` is a [RenderingContext].
+use crate::runtime::Connection;
+
+/// Extension object for the [multi_draw] extension.
+///
+/// See the [multi_draw] module documentation for details.
+#[derive(Clone, Debug)]
+pub struct Extension {
+    context_id: u64,
+}
+
+impl Extension {
+    pub(crate) fn context_id(&self) -> u64 {
+        self.context_id
+    }
+}
+
+impl super::Extension for Extension {
+    fn try_init(connection: &mut Connection, context_id: u64) -> Option<Self> {
+        connection
+            .multi_draw_extension()
+            .map(|_| Extension { context_id })
+    }
+}
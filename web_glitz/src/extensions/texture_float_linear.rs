@@ -69,6 +69,10 @@ pub struct Extension {
 }
 
 impl Extension {
+    pub(crate) fn context_id(&self) -> u64 {
+        self.context_id
+    }
+
     /// Wraps a sampler in a type that can be combined with a texture that uses a floating point
     /// internal format without causing a type error.
     ///
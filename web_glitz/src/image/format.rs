@@ -120,6 +120,15 @@ unsafe impl ShadowSamplable for Depth32FStencil8 {}
 
 /// Marker trait for formats that can be used as a color attachment for a [RenderTarget] for a
 /// color out that outputs floating point values.
+///
+/// Note that the floating point formats (e.g. [R16F], [RG16F], [R32F]) are not color-renderable in
+/// WebGL 2.0 without an extension. Images with these formats may be attached to a render target
+/// when the [color_buffer_float] extension (or, for the 16-bit formats only, the
+/// [color_buffer_half_float] extension) is available; see the documentation for these extensions
+/// for details.
+///
+/// [color_buffer_float]: crate::extensions::color_buffer_float
+/// [color_buffer_half_float]: crate::extensions::color_buffer_half_float
 pub unsafe trait FloatRenderable: InternalFormat {}
 
 unsafe impl FloatRenderable for R8 {}
@@ -179,6 +188,110 @@ pub unsafe trait StencilRenderable: InternalFormat {}
 
 unsafe impl StencilRenderable for StencilIndex8 {}
 
+/// Trait implemented for types that represent a value to which an image with a certain
+/// [InternalFormat] can be cleared.
+///
+/// Implemented for:
+///
+/// - `[f32; 4]` for [FloatRenderable] formats.
+/// - `[i32; 4]` for [IntegerRenderable] formats.
+/// - `[u32; 4]` for [UnsignedIntegerRenderable] formats.
+/// - `f32` for [DepthRenderable] formats.
+/// - `i32` for [StencilRenderable] formats.
+/// - `(f32, i32)` for [DepthStencilRenderable] formats, where the first value is the depth value
+///   and the second value is the stencil value.
+///
+/// See [Level::clear_command] and [Renderbuffer::clear_command].
+///
+/// # Unsafe
+///
+/// The [ATTACHMENT] must be a framebuffer attachment point that accepts images with format `T` and
+/// [clear_buffer] must only clear the image attached at that attachment point.
+///
+/// [Level::clear_command]: crate::image::texture_2d::Level::clear_command
+/// [Renderbuffer::clear_command]: crate::image::renderbuffer::Renderbuffer::clear_command
+/// [ATTACHMENT]: ClearValue::ATTACHMENT
+/// [clear_buffer]: ClearValue::clear_buffer
+pub unsafe trait ClearValue<T>: Copy
+where
+    T: InternalFormat,
+{
+    /// Identifier for the associated OpenGl framebuffer attachment point.
+    const ATTACHMENT: u32;
+
+    /// Clears the image attached at the [ATTACHMENT] point of the currently bound draw framebuffer
+    /// to this value.
+    ///
+    /// [ATTACHMENT]: ClearValue::ATTACHMENT
+    fn clear_buffer(&self, gl: &Gl);
+}
+
+unsafe impl<T> ClearValue<T> for [f32; 4]
+where
+    T: FloatRenderable,
+{
+    const ATTACHMENT: u32 = Gl::COLOR_ATTACHMENT0;
+
+    fn clear_buffer(&self, gl: &Gl) {
+        gl.clear_bufferfv_with_f32_array(Gl::COLOR, 0, self);
+    }
+}
+
+unsafe impl<T> ClearValue<T> for [i32; 4]
+where
+    T: IntegerRenderable,
+{
+    const ATTACHMENT: u32 = Gl::COLOR_ATTACHMENT0;
+
+    fn clear_buffer(&self, gl: &Gl) {
+        gl.clear_bufferiv_with_i32_array(Gl::COLOR, 0, self);
+    }
+}
+
+unsafe impl<T> ClearValue<T> for [u32; 4]
+where
+    T: UnsignedIntegerRenderable,
+{
+    const ATTACHMENT: u32 = Gl::COLOR_ATTACHMENT0;
+
+    fn clear_buffer(&self, gl: &Gl) {
+        gl.clear_bufferuiv_with_u32_array(Gl::COLOR, 0, self);
+    }
+}
+
+unsafe impl<T> ClearValue<T> for f32
+where
+    T: DepthRenderable,
+{
+    const ATTACHMENT: u32 = Gl::DEPTH_ATTACHMENT;
+
+    fn clear_buffer(&self, gl: &Gl) {
+        gl.clear_bufferfv_with_f32_array(Gl::DEPTH, 0, &[*self]);
+    }
+}
+
+unsafe impl<T> ClearValue<T> for i32
+where
+    T: StencilRenderable,
+{
+    const ATTACHMENT: u32 = Gl::STENCIL_ATTACHMENT;
+
+    fn clear_buffer(&self, gl: &Gl) {
+        gl.clear_bufferiv_with_i32_array(Gl::STENCIL, 0, &[*self]);
+    }
+}
+
+unsafe impl<T> ClearValue<T> for (f32, i32)
+where
+    T: DepthStencilRenderable,
+{
+    const ATTACHMENT: u32 = Gl::DEPTH_STENCIL_ATTACHMENT;
+
+    fn clear_buffer(&self, gl: &Gl) {
+        gl.clear_bufferfi(Gl::DEPTH_STENCIL, 0, self.0, self.1);
+    }
+}
+
 /// Marker trait for formats that support linear filtering.
 pub unsafe trait Filterable {}
 
@@ -330,6 +443,12 @@ unsafe impl PixelUnpack<R16F> for f32 {
     const TYPE_ID: u32 = Gl::FLOAT;
 }
 
+unsafe impl PixelPack<R16F> for f32 {
+    const FORMAT_ID: u32 = Gl::RED;
+
+    const TYPE_ID: u32 = Gl::FLOAT;
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct R32F;
 
@@ -507,12 +626,24 @@ unsafe impl PixelUnpack<RG16F> for [f32; 2] {
     const TYPE_ID: u32 = Gl::FLOAT;
 }
 
+unsafe impl PixelPack<RG16F> for [f32; 2] {
+    const FORMAT_ID: u32 = Gl::RG;
+
+    const TYPE_ID: u32 = Gl::FLOAT;
+}
+
 unsafe impl PixelUnpack<RG16F> for (f32, f32) {
     const FORMAT_ID: u32 = Gl::RG;
 
     const TYPE_ID: u32 = Gl::FLOAT;
 }
 
+unsafe impl PixelPack<RG16F> for (f32, f32) {
+    const FORMAT_ID: u32 = Gl::RG;
+
+    const TYPE_ID: u32 = Gl::FLOAT;
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct RG32F;
 
@@ -1535,6 +1666,13 @@ unsafe impl InternalFormat for StencilIndex8 {
     const ID: u32 = Gl::STENCIL_INDEX8;
 }
 
+/// A combined depth-stencil format with a 24-bit depth aspect and an 8-bit stencil aspect.
+///
+/// When a texture with this format is sampled (see [FloatSamplable] and [ShadowSamplable]), only
+/// the depth aspect is sampled. WebGL 2.0 does not support `DEPTH_STENCIL_TEXTURE_MODE`, so the
+/// stencil aspect of a depth-stencil texture cannot be sampled by a shader. If a shader needs to
+/// read a stencil mask, the mask must also be written to a separate color attachment (e.g. with
+/// the [R8UI] format) in the render pass that produces it.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct Depth24Stencil8;
 
@@ -1548,6 +1686,10 @@ unsafe impl PixelUnpack<Depth24Stencil8> for u32 {
     const TYPE_ID: u32 = Gl::UNSIGNED_INT_24_8;
 }
 
+/// A combined depth-stencil format with a 32-bit floating point depth aspect and an 8-bit stencil
+/// aspect.
+///
+/// As with [Depth24Stencil8], only the depth aspect of a texture with this format can be sampled.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct Depth32FStencil8;
 
@@ -1615,6 +1757,7 @@ unsafe impl PixelUnpack<Alpha> for u8 {
 /// ```
 ///
 /// Note that the base format must be [Multisamplable].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct Multisample<F>(pub F, pub u8)
 where
     F: Multisamplable;
@@ -1,10 +1,14 @@
 use std::borrow::Borrow;
 use std::marker;
 use std::mem;
+use std::slice;
 
+use js_sys::{ArrayBuffer, Object, Uint8Array};
 use wasm_bindgen::JsCast;
 use web_sys::{window, CanvasRenderingContext2d, HtmlCanvasElement, HtmlImageElement};
 
+use crate::image::util::{texture_bytes_as_js_buffer, typed_array_as_js_buffer};
+
 /// Encapsulates data that may be uploaded to a 2D texture (sub-)image.
 ///
 /// # Example
@@ -41,6 +45,7 @@ pub struct Image2DSource<D, T> {
 pub(crate) enum Image2DSourceInternal<D> {
     PixelData {
         data: D,
+        as_js_buffer: fn(&D, u32, usize) -> Object,
         row_length: u32,
         image_height: u32,
         alignment: Alignment,
@@ -84,6 +89,142 @@ where
         Ok(Image2DSource {
             internal: Image2DSourceInternal::PixelData {
                 data: pixels,
+                as_js_buffer: pixels_as_js_buffer::<D, T>,
+                row_length: width,
+                image_height: height,
+                alignment,
+            },
+            _marker: marker::PhantomData,
+        })
+    }
+}
+
+impl<D, T> Image2DSource<D, T>
+where
+    D: Borrow<[u8]>,
+{
+    /// Creates a new [Image2DSource] from raw `bytes` for an image with the given `width` and the
+    /// given `height`, where each pixel is stored as a `T`.
+    ///
+    /// Each row of pixels must start at a multiple of the `alignment`. The stride between rows
+    /// (in bytes) is the size of a row of `width` pixels of type `T`, rounded up to the next
+    /// multiple of the `alignment`. This matches the layout of images produced by many image
+    /// decoders, which avoids copying the decoded data into a typed buffer first.
+    ///
+    /// Returns [FromBytesError::NotEnoughBytes] if the `bytes` do not contain at least the row
+    /// stride times `height` bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use web_glitz::image::{Alignment, Image2DSource};
+    ///
+    /// // An RGB image that is 3 pixels wide; each row is padded to 12 bytes.
+    /// let bytes: Vec<u8> = vec![255; 12 * 2];
+    /// let image_source =
+    ///     Image2DSource::<_, [u8; 3]>::from_bytes(bytes, 3, 2, Alignment::Byte4).unwrap();
+    /// ```
+    pub fn from_bytes(
+        bytes: D,
+        width: u32,
+        height: u32,
+        alignment: Alignment,
+    ) -> Result<Self, FromBytesError> {
+        let len = bytes.borrow().len();
+        let expected_len =
+            row_stride_in_bytes(width, mem::size_of::<T>(), alignment) * height as usize;
+
+        if len < expected_len {
+            return Err(FromBytesError::NotEnoughBytes(len, expected_len));
+        }
+
+        Ok(Image2DSource {
+            internal: Image2DSourceInternal::PixelData {
+                data: bytes,
+                as_js_buffer: bytes_as_js_buffer::<D>,
+                row_length: width,
+                image_height: height,
+                alignment,
+            },
+            _marker: marker::PhantomData,
+        })
+    }
+}
+
+impl<T> Image2DSource<Uint8Array, T> {
+    /// Creates a new [Image2DSource] that uploads directly from the memory viewed by the JS typed
+    /// array `view`, for an image with the given `width` and the given `height`, where each pixel
+    /// is stored as a `T`.
+    ///
+    /// The `view` may be any typed array or `DataView`, including a view of a `SharedArrayBuffer`
+    /// (e.g. the memory of another WASM module); its elements are reinterpreted as the texture data
+    /// type when the image is uploaded. Unlike [from_pixels] and [from_bytes], the data is never
+    /// copied into WASM memory: uploads read straight from the viewed JS memory. The image data
+    /// must therefore not be modified until the upload command has been executed.
+    ///
+    /// The layout of the rows is the same as for [from_bytes]: each row of pixels must start at a
+    /// multiple of the `alignment`.
+    ///
+    /// Returns [FromJsTypedArrayError::NotAView] if the `view` is not a typed array or `DataView`,
+    /// [FromJsTypedArrayError::UnalignedByteOffset] if the byte offset of the `view` is not a
+    /// multiple of the alignment of `T`, or [FromJsTypedArrayError::NotEnoughBytes] if the `view`
+    /// does not contain at least the row stride times `height` bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # fn wrapper(view: js_sys::Float32Array) {
+    /// use web_glitz::image::{Alignment, Image2DSource};
+    ///
+    /// // `view` holds 256 by 256 RGBA pixels with 32-bit float components.
+    /// let image_source =
+    ///     Image2DSource::<_, [f32; 4]>::from_js_typed_array(&view, 256, 256, Alignment::Byte4)
+    ///         .unwrap();
+    /// # }
+    /// ```
+    ///
+    /// [from_pixels]: Image2DSource::from_pixels
+    /// [from_bytes]: Image2DSource::from_bytes
+    pub fn from_js_typed_array(
+        view: &Object,
+        width: u32,
+        height: u32,
+        alignment: Alignment,
+    ) -> Result<Self, FromJsTypedArrayError> {
+        if !ArrayBuffer::is_view(view) {
+            return Err(FromJsTypedArrayError::NotAView);
+        }
+
+        // The `buffer`, `byteOffset` and `byteLength` accessors are shared by all typed arrays and
+        // `DataView`s, so the view can be treated as a byte view without copying.
+        let view: &Uint8Array = view.unchecked_ref();
+        let view = Uint8Array::new_with_byte_offset_and_length(
+            &view.buffer(),
+            view.byte_offset(),
+            view.byte_length(),
+        );
+
+        let byte_offset = view.byte_offset();
+
+        if byte_offset as usize % mem::align_of::<T>() != 0 {
+            return Err(FromJsTypedArrayError::UnalignedByteOffset(
+                byte_offset,
+                mem::align_of::<T>(),
+            ));
+        }
+
+        let len = view.byte_length() as usize;
+        let expected_len =
+            row_stride_in_bytes(width, mem::size_of::<T>(), alignment) * height as usize;
+
+        if len < expected_len {
+            return Err(FromJsTypedArrayError::NotEnoughBytes(len, expected_len));
+        }
+
+        Ok(Image2DSource {
+            internal: Image2DSourceInternal::PixelData {
+                data: view,
+                as_js_buffer: typed_array_as_js_buffer,
                 row_length: width,
                 image_height: height,
                 alignment,
@@ -93,12 +234,31 @@ where
     }
 }
 
+impl<D, T> Image2DSource<D, T> {
+    /// Returns the `(width, height)` of the image, in pixels.
+    pub(crate) fn dimensions(&self) -> (u32, u32) {
+        match &self.internal {
+            Image2DSourceInternal::PixelData {
+                row_length,
+                image_height,
+                ..
+            } => (*row_length, *image_height),
+        }
+    }
+}
+
 impl Image2DSource<Vec<[u8; 4]>, [u8; 4]> {
     /// Creates a new [Image2DSource] for the `image_element`.
     ///
     /// The width will be equal to the [HtmlImageElement::natural_width] of the image element and
     /// the height will be equal the [HtmlImageElement::natural_height] of the image element.
     ///
+    /// The pixel data is obtained by drawing the image onto a 2D canvas. The browser may apply
+    /// colorspace conversion (e.g. an embedded color profile or gamma correction) when it decodes
+    /// the image for the canvas, so the pixel data may differ from the values stored in the image
+    /// file. For images that store data rather than colors (e.g. normal maps or look-up tables),
+    /// decode the image file separately and use [Image2DSource::from_pixels] instead.
+    ///
     /// # Panics
     ///
     /// Panics if the image element is not yet "complete" (see [HtmlImageElement::complete]).
@@ -154,6 +314,7 @@ impl Image2DSource<Vec<[u8; 4]>, [u8; 4]> {
         Image2DSource {
             internal: Image2DSourceInternal::PixelData {
                 data: pixels,
+                as_js_buffer: pixels_as_js_buffer::<Vec<[u8; 4]>, [u8; 4]>,
                 row_length: width,
                 image_height: height,
                 alignment: Alignment::Byte4,
@@ -274,11 +435,119 @@ pub enum FromPixelsError {
     UnsupportedAlignment(usize),
 }
 
+/// Options that control how the data in an [Image2DSource] is interpreted when it is uploaded to a
+/// texture image.
+///
+/// The default options leave the data unmodified.
+///
+/// # Example
+///
+/// Uploading a rectangle from a larger image held in memory, e.g. when building a texture atlas:
+///
+/// ```
+/// # use web_glitz::image::texture_2d::Texture2D;
+/// # use web_glitz::image::format::RGBA8;
+/// # use web_glitz::runtime::RenderingContext;
+/// # fn wrapper<Rc>(context: &Rc, atlas: Texture2D<RGBA8>, pixels: Vec<[u8; 4]>) where Rc: RenderingContext {
+/// use web_glitz::image::{Image2DSource, Region2D, UploadOptions};
+///
+/// // The full 1024 by 1024 source image:
+/// let source = Image2DSource::from_pixels(pixels, 1024, 1024).unwrap();
+///
+/// // Upload the 64 by 32 rectangle at (128, 256) in the source image to (0, 0) in the atlas:
+/// let options = UploadOptions {
+///     skip_pixels: 128,
+///     skip_rows: 256,
+///     ..Default::default()
+/// };
+/// let region = Region2D::Area((0, 0), 64, 32);
+/// let base_level = atlas.base_level();
+///
+/// context.submit(base_level.sub_image(region).upload_command_with_options(source, options));
+/// # }
+/// ```
+///
+/// See for example [texture_2d::Level::upload_command_with_options].
+///
+/// [texture_2d::Level::upload_command_with_options]: crate::image::texture_2d::Level::upload_command_with_options
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+pub struct UploadOptions {
+    /// Whether or not the rows of the image should be uploaded in reverse order.
+    ///
+    /// The first row in the data of an [Image2DSource] is uploaded to the bottom row of the
+    /// texture image (the row at the origin). Images obtained from the DOM or decoded from image
+    /// files typically store their top row first; setting this option to `true` makes such images
+    /// appear the right side up when sampled with the conventional texture coordinates.
+    pub flip_y: bool,
+
+    /// Whether or not the color components of the image should be multiplied by the alpha
+    /// component during the upload.
+    pub premultiply_alpha: bool,
+
+    /// The number of pixels at the start of each row of the [Image2DSource] that are skipped.
+    ///
+    /// Together with [skip_rows], this allows uploading a sub-rectangle of a larger source image
+    /// without first copying that rectangle into a separate contiguous buffer. The row length
+    /// (the stride between rows) is always the width of the [Image2DSource], so the source image
+    /// should be created with its full width and height. If the upload region is wider than the
+    /// remaining `width - skip_pixels` pixels per row, only the first `width - skip_pixels` columns
+    /// of the region are updated.
+    ///
+    /// [skip_rows]: UploadOptions::skip_rows
+    pub skip_pixels: u32,
+
+    /// The number of rows at the start of the [Image2DSource] that are skipped.
+    ///
+    /// If the upload region is taller than the remaining `height - skip_rows` rows of the source
+    /// image, only the first `height - skip_rows` rows of the region are updated. See also
+    /// [skip_pixels].
+    ///
+    /// [skip_pixels]: UploadOptions::skip_pixels
+    pub skip_rows: u32,
+}
+
+/// Error returned by [Image2DSource::from_bytes].
+///
+/// See [Image2DSource::from_bytes] for details.
+#[derive(Debug)]
+pub enum FromBytesError {
+    /// Variant returned when the data does not contain enough bytes to describe an image of the
+    /// required dimensions; contains the actual number of bytes and the required number of bytes.
+    NotEnoughBytes(usize, usize),
+}
+
+/// Error returned by [Image2DSource::from_js_typed_array].
+///
+/// See [Image2DSource::from_js_typed_array] for details.
+#[derive(Debug)]
+pub enum FromJsTypedArrayError {
+    /// Variant returned when the object is not a typed array or `DataView`.
+    NotAView,
+
+    /// Variant returned when the byte offset of the view is not a multiple of the alignment of the
+    /// pixel type; contains the byte offset and the alignment.
+    UnalignedByteOffset(u32, usize),
+
+    /// Variant returned when the view does not contain enough bytes to describe an image of the
+    /// required dimensions; contains the actual number of bytes and the required number of bytes.
+    NotEnoughBytes(usize, usize),
+}
+
+/// The alignment of the start of each row of pixels in image data.
+///
+/// See [Image2DSource::from_bytes].
 #[derive(Clone, Copy, PartialEq, Debug)]
-pub(crate) enum Alignment {
+pub enum Alignment {
+    /// Rows start at any byte.
     Byte,
+
+    /// Rows start at a multiple of 2 bytes.
     Byte2,
+
+    /// Rows start at a multiple of 4 bytes.
     Byte4,
+
+    /// Rows start at a multiple of 8 bytes.
     Byte8,
 }
 
@@ -292,3 +561,48 @@ impl Into<i32> for Alignment {
         }
     }
 }
+
+fn pixels_as_js_buffer<D, T>(pixels: &D, type_id: u32, max_len_in_bytes: usize) -> Object
+where
+    D: Borrow<[T]>,
+{
+    let pixels = pixels.borrow();
+    let bytes =
+        unsafe { slice::from_raw_parts(pixels as *const _ as *const u8, mem::size_of_val(pixels)) };
+
+    texture_bytes_as_js_buffer(bytes, type_id, max_len_in_bytes)
+}
+
+fn bytes_as_js_buffer<D>(bytes: &D, type_id: u32, max_len_in_bytes: usize) -> Object
+where
+    D: Borrow<[u8]>,
+{
+    texture_bytes_as_js_buffer(bytes.borrow(), type_id, max_len_in_bytes)
+}
+
+/// Returns the stride in bytes between rows of `row_length` pixels of `pixel_size` bytes, where
+/// each row starts at a multiple of the `alignment`.
+pub(crate) fn row_stride_in_bytes(
+    row_length: u32,
+    pixel_size: usize,
+    alignment: Alignment,
+) -> usize {
+    let alignment: i32 = alignment.into();
+    let alignment = alignment as usize;
+    let row_size = row_length as usize * pixel_size;
+
+    (row_size + alignment - 1) / alignment * alignment
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_stride_in_bytes() {
+        assert_eq!(row_stride_in_bytes(3, 3, Alignment::Byte), 9);
+        assert_eq!(row_stride_in_bytes(3, 3, Alignment::Byte4), 12);
+        assert_eq!(row_stride_in_bytes(2, 3, Alignment::Byte8), 8);
+        assert_eq!(row_stride_in_bytes(4, 4, Alignment::Byte4), 16);
+    }
+}
@@ -85,13 +85,34 @@
 //!
 //! The main application for mipmapping is minification filtering, in which case each level in the
 //! chain is typically obtained by linear minification filtering of the preceding level (see
-//! [MinificationFilter] for details). If the texture format implements [Filterable], then the image
-//! data for such a chain can be generated from the base level by the driver (see
-//! [Texture2D::generate_mipmap], [Texture3D::generate_mipmap], [Texture2DArray::generate_mipmap],
-//! [TextureCube::generate_mipmap]).
+//! [MinificationFilter] for details). If the texture format implements both [Filterable] and
+//! [FloatRenderable], then the image data for such a chain can be generated from the base level by
+//! the driver (see [Texture2D::generate_mipmap_command], [Texture3D::generate_mipmap_command],
+//! [Texture2DArray::generate_mipmap_command], [TextureCube::generate_mipmap_command]).
+//!
+//! # Unsampled textures
+//!
+//! A texture is typically bound to a pipeline together with a sampler object (see [sampler] and
+//! e.g. [Texture2D::float_sampled]), which determines how the texture is filtered and wrapped when
+//! it is sampled. Shaders that only read a texture with `texelFetch` do not use these sampling
+//! parameters, but the texture must still be "complete". A [Texture2D], [Texture2DArray] or
+//! [Texture3D] may therefore also be bound without a sampler object (see e.g.
+//! [Texture2D::float_unsampled]), in which case the texture's own sampling parameters are used.
+//! These are set to nearest filtering and clamp-to-edge wrapping when the texture is allocated;
+//! nearest filtering keeps textures with an integer format complete. An unsampled texture binds to
+//! the same resource slots as a sampled texture, and is validated against a pipeline's resource
+//! bindings layout in the same way.
+//!
+//! Cube map textures cannot be bound without a sampler, as GLSL ES 3.0 does not provide
+//! `texelFetch` for cube map samplers.
+
+use std::cmp;
 
 pub(crate) mod image_source;
-pub use self::image_source::{FromPixelsError, Image2DSource, LayeredImageSource};
+pub use self::image_source::{
+    Alignment, FromBytesError, FromJsTypedArrayError, FromPixelsError, Image2DSource,
+    LayeredImageSource, UploadOptions,
+};
 
 pub mod format;
 pub mod renderbuffer;
@@ -102,7 +123,7 @@ pub mod texture_3d;
 pub mod texture_cube;
 
 mod texture_object_dropper;
-mod util;
+pub(crate) mod util;
 
 /// Represents a region of a 2-dimensional image.
 #[derive(Clone, Copy, PartialEq, Hash, Debug)]
@@ -143,9 +164,69 @@ impl Into<Region2D> for Region3D {
     }
 }
 
+/// Options that control the memory layout of image data that is packed into a buffer.
+///
+/// The default options produce tightly packed rows: each row starts immediately after the last
+/// pixel of the previous row, without any padding.
+///
+/// # Example
+///
+/// Packing a region of a texture into a larger image held in a buffer, where each row of the
+/// buffer image is 8-byte aligned:
+///
+/// ```
+/// # use web_glitz::runtime::RenderingContext;
+/// # use web_glitz::image::texture_2d::Texture2D;
+/// # use web_glitz::image::format::RGBA8;
+/// # fn wrapper<Rc>(context: &Rc, texture: Texture2D<RGBA8>) where Rc: RenderingContext {
+/// use web_glitz::buffer::{Buffer, BufferView, UsageHint};
+/// use web_glitz::image::{Alignment, PackOptions, Region2D};
+///
+/// let buffer: Buffer<[[u8; 4]]> =
+///     context.create_buffer(vec![[0; 4]; 1024 * 64], UsageHint::StreamRead);
+///
+/// let options = PackOptions {
+///     alignment: Alignment::Byte8,
+///     row_length: 1024,
+/// };
+///
+/// let base_level = texture.base_level();
+/// let sub_image = base_level.sub_image(Region2D::Area((0, 0), 256, 64));
+///
+/// context.submit(
+///     sub_image.pack_to_buffer_command_with_options(BufferView::from(&buffer), options)
+/// );
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct PackOptions {
+    /// The alignment of the start of each row of pixels in the buffer.
+    ///
+    /// Defaults to [Alignment::Byte], which yields tightly packed rows for any pixel type. Note
+    /// that this differs from the OpenGL default of 4 bytes.
+    pub alignment: Alignment,
+
+    /// The number of pixels in a row of the image in the buffer, or `0` if the row length is the
+    /// width of the image that is packed.
+    ///
+    /// Defaults to `0`. When not `0`, the row length must not be smaller than the width of the
+    /// packed image.
+    pub row_length: u32,
+}
+
+impl Default for PackOptions {
+    fn default() -> Self {
+        PackOptions {
+            alignment: Alignment::Byte,
+            row_length: 0,
+        }
+    }
+}
+
 /// Describes the number of mipmap levels that are to be allocated for a texture.
 ///
 /// See the module documentation for [web_glitz::image] for details on mipmap storage.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum MipmapLevels {
     /// Variant that will allocate storage for all mipmap levels in the complete mipmap chain for
     /// an image of the relevant width and height.
@@ -167,3 +248,116 @@ pub struct MaxMipmapLevelsExceeded {
     /// dimensions.
     pub max: usize,
 }
+
+/// Returns the number of levels in the complete mipmap chain for a 2-dimensional image with the
+/// given `width` and `height`.
+///
+/// This is the number of levels that is allocated for a texture created with
+/// [MipmapLevels::Complete]: `floor(log2(max(width, height))) + 1`. An image with a width or height
+/// of `0` is treated as an image of `1` by `1` pixels.
+///
+/// # Example
+///
+/// ```
+/// use web_glitz::image::complete_mipmap_levels;
+///
+/// assert_eq!(complete_mipmap_levels(256, 256), 9);
+/// assert_eq!(complete_mipmap_levels(300, 100), 9);
+/// assert_eq!(complete_mipmap_levels(1, 1), 1);
+/// ```
+pub fn complete_mipmap_levels(width: u32, height: u32) -> usize {
+    let max = cmp::max(cmp::max(width, height), 1);
+
+    // The index of the highest set bit is `floor(log2(max))`.
+    (32 - max.leading_zeros()) as usize
+}
+
+/// Returns the number of levels in the complete mipmap chain for a 3-dimensional image with the
+/// given `width`, `height` and `depth`.
+///
+/// This is the number of levels that is allocated for a [Texture3D] created with
+/// [MipmapLevels::Complete]: `floor(log2(max(width, height, depth))) + 1`. An image with a width,
+/// height or depth of `0` is treated as an image of `1` by `1` by `1` pixels.
+///
+/// # Example
+///
+/// ```
+/// use web_glitz::image::complete_mipmap_levels_3d;
+///
+/// assert_eq!(complete_mipmap_levels_3d(64, 64, 256), 9);
+/// assert_eq!(complete_mipmap_levels_3d(16, 16, 16), 5);
+/// ```
+///
+/// [Texture3D]: crate::image::texture_3d::Texture3D
+pub fn complete_mipmap_levels_3d(width: u32, height: u32, depth: u32) -> usize {
+    complete_mipmap_levels(cmp::max(width, height), depth)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    use crate::image::format::{Multisample, RGBA8};
+    use crate::image::renderbuffer::RenderbufferDescriptor;
+    use crate::image::texture_2d::Texture2DDescriptor;
+
+    #[test]
+    fn test_complete_mipmap_levels() {
+        assert_eq!(complete_mipmap_levels(0, 0), 1);
+        assert_eq!(complete_mipmap_levels(1, 1), 1);
+        assert_eq!(complete_mipmap_levels(2, 1), 2);
+        assert_eq!(complete_mipmap_levels(3, 3), 2);
+        assert_eq!(complete_mipmap_levels(255, 255), 8);
+        assert_eq!(complete_mipmap_levels(256, 255), 9);
+        assert_eq!(complete_mipmap_levels(1, 257), 9);
+        assert_eq!(complete_mipmap_levels(u32::MAX, 1), 32);
+    }
+
+    #[test]
+    fn test_descriptor_dedup() {
+        let texture_descriptor = Texture2DDescriptor {
+            format: RGBA8,
+            width: 256,
+            height: 256,
+            levels: MipmapLevels::Complete,
+        };
+
+        let mut texture_descriptors = HashSet::new();
+
+        texture_descriptors.insert(texture_descriptor.clone());
+        texture_descriptors.insert(texture_descriptor.clone());
+        texture_descriptors.insert(Texture2DDescriptor {
+            levels: MipmapLevels::Partial(1),
+            ..texture_descriptor
+        });
+
+        assert_eq!(texture_descriptors.len(), 2);
+
+        let renderbuffer_descriptor = RenderbufferDescriptor {
+            format: Multisample(RGBA8, 4),
+            width: 256,
+            height: 256,
+        };
+
+        let mut renderbuffer_descriptors = HashSet::new();
+
+        renderbuffer_descriptors.insert(renderbuffer_descriptor);
+        renderbuffer_descriptors.insert(renderbuffer_descriptor);
+        renderbuffer_descriptors.insert(RenderbufferDescriptor {
+            format: Multisample(RGBA8, 2),
+            ..renderbuffer_descriptor
+        });
+
+        assert_eq!(renderbuffer_descriptors.len(), 2);
+    }
+
+    #[test]
+    fn test_complete_mipmap_levels_3d() {
+        assert_eq!(complete_mipmap_levels_3d(1, 1, 1), 1);
+        assert_eq!(complete_mipmap_levels_3d(4, 4, 64), 7);
+        assert_eq!(complete_mipmap_levels_3d(64, 4, 4), 7);
+        assert_eq!(complete_mipmap_levels_3d(4, 64, 0), 7);
+    }
+}
@@ -1,14 +1,20 @@
 use std::cell::UnsafeCell;
 use std::hash::{Hash, Hasher};
 use std::marker;
+use std::mem;
 use std::sync::Arc;
 
 use wasm_bindgen::JsCast;
 use web_sys::WebGl2RenderingContext as Gl;
 
-use crate::image::format::{Multisamplable, Multisample, RenderbufferFormat};
+use crate::buffer::{BufferData, BufferView};
+use crate::image::format::{
+    ClearValue, Multisamplable, Multisample, PixelPack, RenderbufferFormat,
+};
+use crate::image::util::pack_size_in_bytes;
+use crate::image::PackOptions;
 use crate::runtime::state::ContextUpdate;
-use crate::runtime::{Connection, RenderingContext, UnsupportedSampleCount};
+use crate::runtime::{Connection, DeletionQueue, RenderingContext, UnsupportedSampleCount};
 use crate::task::{ContextId, GpuTask, Progress};
 use crate::util::JsId;
 
@@ -16,6 +22,7 @@ use crate::util::JsId;
 ///
 /// See [RenderingContext::try_create_renderbuffer] and
 /// [RenderingContext::try_create_multisample_renderbuffer] for details.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub struct RenderbufferDescriptor<F> {
     /// The format type the [Renderbuffer] will use to store its image data.
     pub format: F,
@@ -95,6 +102,200 @@ impl<F> Renderbuffer<F> {
     }
 }
 
+impl<F> Renderbuffer<F>
+where
+    F: RenderbufferFormat,
+{
+    /// Returns a command which, when executed, copies the image data stored in this [Renderbuffer]
+    /// into the `buffer`.
+    ///
+    /// The pixel data is stored in the `buffer` row by row, starting with the bottom row (the row
+    /// at the origin). Rows are tightly packed, the `buffer` therefore needs to hold exactly
+    /// `width * height` pixels. See [pack_to_buffer_command_with_options] for control over the
+    /// layout of the rows in the `buffer`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `buffer` belongs to a different context than this [Renderbuffer].
+    ///
+    /// Panics if the `buffer` is too small to hold the image data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::buffer::{Buffer, BufferView, UsageHint};
+    /// use web_glitz::image::format::RGBA8;
+    /// use web_glitz::image::renderbuffer::RenderbufferDescriptor;
+    ///
+    /// let renderbuffer = context.create_renderbuffer(&RenderbufferDescriptor {
+    ///     format: RGBA8,
+    ///     width: 256,
+    ///     height: 256
+    /// });
+    ///
+    /// let buffer: Buffer<[[u8; 4]]> =
+    ///     context.create_buffer(vec![[0; 4]; 256 * 256], UsageHint::StreamRead);
+    ///
+    /// let task = renderbuffer.pack_to_buffer_command(BufferView::from(&buffer));
+    ///
+    /// context.submit(task);
+    /// # }
+    /// ```
+    ///
+    /// [pack_to_buffer_command_with_options]: Renderbuffer::pack_to_buffer_command_with_options
+    pub fn pack_to_buffer_command<P>(&self, buffer: BufferView<[P]>) -> PackToBufferCommand<F, P>
+    where
+        P: PixelPack<F>,
+    {
+        self.pack_to_buffer_command_with_options(buffer, PackOptions::default())
+    }
+
+    /// Returns a command which, when executed, copies the image data stored in this [Renderbuffer]
+    /// into the `buffer`, where the layout of the rows in the `buffer` is controlled by the
+    /// `options`.
+    ///
+    /// See [PackOptions] for details on the available options.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `buffer` belongs to a different context than this [Renderbuffer].
+    ///
+    /// Panics if the `buffer` is too small to hold the image data.
+    ///
+    /// Panics if the `options` specify a row length other than `0` that is smaller than the width
+    /// of this [Renderbuffer].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::buffer::{Buffer, BufferView, UsageHint};
+    /// use web_glitz::image::{Alignment, PackOptions};
+    /// use web_glitz::image::format::RGB8;
+    /// use web_glitz::image::renderbuffer::RenderbufferDescriptor;
+    ///
+    /// let renderbuffer = context.create_renderbuffer(&RenderbufferDescriptor {
+    ///     format: RGB8,
+    ///     width: 255,
+    ///     height: 255
+    /// });
+    ///
+    /// // Pad each row to a multiple of 4 bytes: 255 pixels of 3 bytes are padded to 768 bytes.
+    /// let buffer: Buffer<[u8]> =
+    ///     context.create_buffer(vec![0; 768 * 255], UsageHint::StreamRead);
+    ///
+    /// let task = renderbuffer.pack_to_buffer_command_with_options(
+    ///     BufferView::from(&buffer),
+    ///     PackOptions {
+    ///         alignment: Alignment::Byte4,
+    ///         row_length: 0,
+    ///     }
+    /// );
+    ///
+    /// context.submit(task);
+    /// # }
+    /// ```
+    pub fn pack_to_buffer_command_with_options<P>(
+        &self,
+        buffer: BufferView<[P]>,
+        options: PackOptions,
+    ) -> PackToBufferCommand<F, P>
+    where
+        P: PixelPack<F>,
+    {
+        let buffer_data = buffer.buffer_data();
+
+        if buffer_data.context_id() != self.data.context_id {
+            panic!("Buffer belongs to a different context");
+        }
+
+        if options.row_length != 0 && options.row_length < self.data.width {
+            panic!(
+                "The row length ({}) must not be smaller than the width of the renderbuffer ({}).",
+                options.row_length, self.data.width
+            );
+        }
+
+        let required_size = pack_size_in_bytes(
+            self.data.width,
+            self.data.height,
+            mem::size_of::<P>(),
+            &options,
+        );
+        let buffer_size = buffer.len() * mem::size_of::<P>();
+
+        if buffer_size < required_size {
+            panic!(
+                "Buffer is too small: the renderbuffer's image data requires {} bytes, but the \
+                buffer only holds {} bytes.",
+                required_size, buffer_size
+            );
+        }
+
+        PackToBufferCommand {
+            renderbuffer_data: self.data.clone(),
+            buffer_data: buffer_data.clone(),
+            offset: buffer.offset_in_bytes(),
+            options,
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns a command which, when executed, clears the image stored in this [Renderbuffer] to
+    /// the `value`.
+    ///
+    /// The type of the `value` must match the format of the renderbuffer: a `[f32; 4]` for formats
+    /// that store floating point values, a `[i32; 4]` or a `[u32; 4]` for formats that store
+    /// integer or unsigned integer values respectively, a `f32` for depth formats, an `i32` for
+    /// stencil formats, or a `(f32, i32)` for depth-stencil formats (see [ClearValue]). Only
+    /// formats that are renderable without extensions may be cleared this way; multisample
+    /// renderbuffers cannot be cleared with this command.
+    ///
+    /// This does not require a render target: the renderbuffer is attached to a framebuffer that
+    /// is used only for clearing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::image::format::{Depth24Stencil8, RGBA8};
+    /// use web_glitz::image::renderbuffer::RenderbufferDescriptor;
+    /// use web_glitz::task::sequence;
+    ///
+    /// let color = context.create_renderbuffer(&RenderbufferDescriptor {
+    ///     format: RGBA8,
+    ///     width: 256,
+    ///     height: 256
+    /// });
+    ///
+    /// let depth_stencil = context.create_renderbuffer(&RenderbufferDescriptor {
+    ///     format: Depth24Stencil8,
+    ///     width: 256,
+    ///     height: 256
+    /// });
+    ///
+    /// context.submit(sequence(
+    ///     color.clear_command([0.0, 0.0, 0.0, 1.0]),
+    ///     depth_stencil.clear_command((1.0, 0)),
+    /// ));
+    /// # }
+    /// ```
+    pub fn clear_command<V>(&self, value: V) -> ClearCommand<F, V>
+    where
+        V: ClearValue<F>,
+    {
+        ClearCommand {
+            renderbuffer_data: self.data.clone(),
+            value,
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
 impl<F> Renderbuffer<Multisample<F>>
 where
     F: Multisamplable,
@@ -120,7 +321,7 @@ where
         let data = Arc::new(RenderbufferData {
             id: UnsafeCell::new(None),
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
+            dropper: Box::new(context.deletion_queue()),
             width: descriptor.width,
             height: descriptor.height,
             samples: None,
@@ -166,7 +367,7 @@ where
         let data = Arc::new(RenderbufferData {
             id: UnsafeCell::new(None),
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
+            dropper: Box::new(context.deletion_queue()),
             width: descriptor.width,
             height: descriptor.height,
             samples: Some(descriptor.format.samples()),
@@ -202,12 +403,9 @@ trait RenderbufferObjectDropper {
     fn drop_renderbuffer_object(&self, id: JsId);
 }
 
-impl<T> RenderbufferObjectDropper for T
-where
-    T: RenderingContext,
-{
+impl RenderbufferObjectDropper for DeletionQueue {
     fn drop_renderbuffer_object(&self, id: JsId) {
-        self.submit(RenderbufferDropCommand { id });
+        self.schedule(RenderbufferDropCommand { id });
     }
 }
 
@@ -350,3 +548,155 @@ unsafe impl GpuTask<Connection> for RenderbufferDropCommand {
         Progress::Finished(())
     }
 }
+
+/// Clears the image of a [Renderbuffer] to a value.
+///
+/// See [Renderbuffer::clear_command] for details.
+pub struct ClearCommand<F, V> {
+    renderbuffer_data: Arc<RenderbufferData>,
+    value: V,
+    _marker: marker::PhantomData<Box<[F]>>,
+}
+
+unsafe impl<F, V> GpuTask<Connection> for ClearCommand<F, V>
+where
+    F: RenderbufferFormat,
+    V: ClearValue<F>,
+{
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.renderbuffer_data.context_id)
+    }
+
+    fn progress(&mut self, context: &mut Connection) -> Progress<Self::Output> {
+        if self.renderbuffer_data.width == 0 || self.renderbuffer_data.height == 0 {
+            return Progress::Finished(());
+        }
+
+        let (gl, state) = unsafe { context.unpack_mut() };
+
+        state.bind_clear_framebuffer(gl);
+
+        unsafe {
+            self.renderbuffer_data
+                .id()
+                .unwrap()
+                .with_value_unchecked(|renderbuffer_object| {
+                    gl.framebuffer_renderbuffer(
+                        Gl::DRAW_FRAMEBUFFER,
+                        V::ATTACHMENT,
+                        Gl::RENDERBUFFER,
+                        Some(&renderbuffer_object),
+                    );
+                });
+        }
+
+        state.set_scissor_test_enabled(false).apply(gl).unwrap();
+        state.set_color_mask([true; 4]).apply(gl).unwrap();
+        state.set_depth_mask(true).apply(gl).unwrap();
+        state
+            .set_stencil_write_mask_front(0xffffffff)
+            .apply(gl)
+            .unwrap();
+        state
+            .set_stencil_write_mask_back(0xffffffff)
+            .apply(gl)
+            .unwrap();
+
+        self.value.clear_buffer(gl);
+
+        // Detach the renderbuffer again, so that it cannot affect the completeness of the
+        // framebuffer when it is used to clear an image of a different size or at a different
+        // attachment point.
+        gl.framebuffer_renderbuffer(Gl::DRAW_FRAMEBUFFER, V::ATTACHMENT, Gl::RENDERBUFFER, None);
+
+        Progress::Finished(())
+    }
+}
+
+/// Copies the image data of a [Renderbuffer] into a [Buffer].
+///
+/// See [Renderbuffer::pack_to_buffer_command] for details.
+pub struct PackToBufferCommand<F, P> {
+    renderbuffer_data: Arc<RenderbufferData>,
+    buffer_data: Arc<BufferData>,
+    offset: usize,
+    options: PackOptions,
+    _marker: marker::PhantomData<(Box<[F]>, Box<[P]>)>,
+}
+
+unsafe impl<F, P> GpuTask<Connection> for PackToBufferCommand<F, P>
+where
+    F: RenderbufferFormat,
+    P: PixelPack<F>,
+{
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.renderbuffer_data.context_id)
+    }
+
+    fn progress(&mut self, context: &mut Connection) -> Progress<Self::Output> {
+        let width = self.renderbuffer_data.width;
+        let height = self.renderbuffer_data.height;
+
+        if width == 0 || height == 0 {
+            return Progress::Finished(());
+        }
+
+        let (gl, state) = unsafe { context.unpack_mut() };
+
+        state.bind_default_read_framebuffer(gl);
+
+        unsafe {
+            self.renderbuffer_data
+                .id()
+                .unwrap()
+                .with_value_unchecked(|renderbuffer_object| {
+                    gl.framebuffer_renderbuffer(
+                        Gl::READ_FRAMEBUFFER,
+                        Gl::COLOR_ATTACHMENT0,
+                        Gl::RENDERBUFFER,
+                        Some(&renderbuffer_object),
+                    );
+                });
+
+            self.buffer_data
+                .id()
+                .unwrap()
+                .with_value_unchecked(|buffer_object| {
+                    state
+                        .bind_pixel_pack_buffer(Some(buffer_object))
+                        .apply(gl)
+                        .unwrap();
+                })
+        }
+
+        state
+            .set_pixel_pack_alignment(self.options.alignment.into())
+            .apply(gl)
+            .unwrap();
+        state
+            .set_pixel_pack_row_length(self.options.row_length as i32)
+            .apply(gl)
+            .unwrap();
+
+        gl.read_pixels_with_i32(
+            0,
+            0,
+            width as i32,
+            height as i32,
+            P::FORMAT_ID,
+            P::TYPE_ID,
+            self.offset as i32,
+        )
+        .unwrap();
+
+        // Restore the defaults, so they don't affect other pack operations.
+        state.set_pixel_pack_alignment(4).apply(gl).unwrap();
+        state.set_pixel_pack_row_length(0).apply(gl).unwrap();
+
+        Progress::Finished(())
+    }
+}
@@ -1,11 +1,13 @@
 use std::cell::UnsafeCell;
-use std::sync::Arc;
+use std::sync::{Arc, Weak};
+
+use fnv::FnvHashMap;
 
 use wasm_bindgen::JsCast;
 use web_sys::WebGl2RenderingContext as Gl;
 
 use crate::image::format::{Filterable, TextureFormat};
-use crate::runtime::{Connection, RenderingContext};
+use crate::runtime::{Connection, DeletionQueue, RenderingContext};
 use crate::task::Progress;
 use crate::task::{ContextId, GpuTask};
 use crate::util::JsId;
@@ -164,7 +166,7 @@ unsafe impl<F> CompatibleFilter<F> for LinearMipmapLinear where F: TextureFormat
 /// Separate wrapping methods can be used for each texture space coordinate component (typically
 /// referred to as the `S`, `T`, `R` coordinates or "width", "height", "depth" respectively), see
 /// [SamplerDescriptor] and [ShadowSamplerDescriptor].
-#[derive(PartialEq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum Wrap {
     /// If the coordinate value is smaller than `0.0`, then `0.0` is used as the coordinate value;
     /// if the coordinate value is greater than `1.0`, then `1.0` is used as the coordinate value.
@@ -184,12 +186,33 @@ pub enum Wrap {
     MirroredRepeat = Gl::MIRRORED_REPEAT as isize,
 }
 
-#[derive(PartialEq, Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct LODRange {
     min: f32,
     max: f32,
 }
 
+impl LODRange {
+    fn key(&self) -> (u32, u32) {
+        // Adding `0.0` normalizes `-0.0` to `0.0`, such that equal ranges have equal keys.
+        ((self.min + 0.0).to_bits(), (self.max + 0.0).to_bits())
+    }
+}
+
+impl PartialEq for LODRange {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for LODRange {}
+
+impl Hash for LODRange {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
 impl Default for LODRange {
     fn default() -> Self {
         LODRange {
@@ -219,7 +242,11 @@ impl Default for LODRange {
 ///     wrap_r: Wrap::Repeat,
 /// });
 /// ```
-#[derive(Clone, PartialEq, Debug)]
+///
+/// Note that WebGL 2.0 always samples cube maps seamlessly: a filtered lookup near the edge of a
+/// cube map face blends in texels from the adjacent face, rather than wrapping or clamping within
+/// the face. This cannot be disabled.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct SamplerDescriptor<Min, Mag> {
     /// The [MinificationFilter] that a sampler created from this descriptor will use.
     ///
@@ -258,6 +285,23 @@ pub struct SamplerDescriptor<Min, Mag> {
     pub wrap_r: Wrap,
 }
 
+impl<Min, Mag> SamplerDescriptor<Min, Mag>
+where
+    Min: MinificationFilter,
+    Mag: MagnificationFilter,
+{
+    pub(crate) fn key(&self) -> SamplerKey {
+        SamplerKey::Sampler {
+            minification_filter: Min::ID,
+            magnification_filter: Mag::ID,
+            lod_range: self.lod_range,
+            wrap_s: self.wrap_s,
+            wrap_t: self.wrap_t,
+            wrap_r: self.wrap_r,
+        }
+    }
+}
+
 impl SamplerDescriptor<NearestMipmapLinear, Linear> {
     // TODO: the specialization feature might be able to replace this by simply marking the default
     // implemention below this this filter combo as the `default` implementation.
@@ -315,6 +359,10 @@ impl<Min, Mag> Sampler<Min, Mag> {
     pub(crate) fn data(&self) -> &Arc<SamplerData> {
         &self.data
     }
+
+    pub(crate) fn object_id(&self) -> u64 {
+        self.object_id
+    }
 }
 
 impl<Min, Mag> Sampler<Min, Mag>
@@ -333,7 +381,7 @@ where
         let data = Arc::new(SamplerData {
             id: UnsafeCell::new(None),
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
+            dropper: Box::new(context.deletion_queue()),
         });
 
         context.submit(SamplerAllocateCommand {
@@ -348,6 +396,18 @@ where
         }
     }
 
+    pub(crate) fn shared(
+        object_id: u64,
+        data: Arc<SamplerData>,
+        descriptor: &SamplerDescriptor<Min, Mag>,
+    ) -> Self {
+        Sampler {
+            object_id,
+            data,
+            descriptor: descriptor.clone(),
+        }
+    }
+
     /// The [MinificationFilter] used by this [Sampler].
     ///
     /// See [MinificationFilter] for details.
@@ -462,7 +522,7 @@ where
 /// Enumerates the compare functions available for a [ShadowSampler].
 ///
 /// See [ShadowSampler] for details.
-#[derive(PartialEq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
 pub enum CompareFunction {
     /// The function passes if the texel value is equal to the reference value.
     Equal = Gl::EQUAL as isize,
@@ -507,7 +567,7 @@ pub enum CompareFunction {
 ///     wrap_r: Wrap::Repeat,
 /// });
 /// ```
-#[derive(PartialEq, Clone, Debug)]
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
 pub struct ShadowSamplerDescriptor {
     /// The [CompareFunction] that a [ShadowSampler] created from this descriptor will use.
     ///
@@ -536,6 +596,48 @@ pub struct ShadowSamplerDescriptor {
     pub wrap_r: Wrap,
 }
 
+impl ShadowSamplerDescriptor {
+    /// Returns a [ShadowSamplerDescriptor] with the [CompareFunction::LessOrEqual] compare function
+    /// that clamps texture coordinates to the edge of the texture in all directions.
+    ///
+    /// WebGL 2.0 does not support a "clamp to border" wrapping method. Shadow maps sampled with the
+    /// default [Wrap::Repeat] wrapping method will sample the opposite edge of the shadow map for
+    /// coordinates just outside the map, which results in artifacts along the edge of the map. This
+    /// preset avoids wrapping around, and is intended to be combined with a border guard in the
+    /// shader code that returns a constant value for coordinates outside of the map, see
+    /// [shadow_border_guard_glsl].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use web_glitz::image::sampler::{ShadowSamplerDescriptor, CompareFunction, Wrap};
+    ///
+    /// assert_eq!(ShadowSamplerDescriptor::clamp_to_edge(), ShadowSamplerDescriptor {
+    ///     compare: CompareFunction::LessOrEqual,
+    ///     wrap_s: Wrap::ClampToEdge,
+    ///     wrap_t: Wrap::ClampToEdge,
+    ///     wrap_r: Wrap::ClampToEdge,
+    /// });
+    /// ```
+    pub fn clamp_to_edge() -> Self {
+        ShadowSamplerDescriptor {
+            compare: CompareFunction::LessOrEqual,
+            wrap_s: Wrap::ClampToEdge,
+            wrap_t: Wrap::ClampToEdge,
+            wrap_r: Wrap::ClampToEdge,
+        }
+    }
+
+    pub(crate) fn key(&self) -> SamplerKey {
+        SamplerKey::Shadow {
+            compare: self.compare,
+            wrap_s: self.wrap_s,
+            wrap_t: self.wrap_t,
+            wrap_r: self.wrap_r,
+        }
+    }
+}
+
 impl Default for ShadowSamplerDescriptor {
     fn default() -> Self {
         ShadowSamplerDescriptor {
@@ -581,7 +683,7 @@ impl ShadowSampler {
         let data = Arc::new(SamplerData {
             id: UnsafeCell::new(None),
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
+            dropper: Box::new(context.deletion_queue()),
         });
 
         context.submit(ShadowSamplerAllocateCommand {
@@ -596,10 +698,26 @@ impl ShadowSampler {
         }
     }
 
+    pub(crate) fn shared(
+        object_id: u64,
+        data: Arc<SamplerData>,
+        descriptor: &ShadowSamplerDescriptor,
+    ) -> Self {
+        ShadowSampler {
+            object_id,
+            data,
+            descriptor: descriptor.clone(),
+        }
+    }
+
     pub(crate) fn data(&self) -> &Arc<SamplerData> {
         &self.data
     }
 
+    pub(crate) fn object_id(&self) -> u64 {
+        self.object_id
+    }
+
     /// The [CompareFunction] used by this[ShadowSampler].
     ///
     /// See type documentation for [ShadowSampler] and the documentation for [CompareFunction] for
@@ -645,16 +763,206 @@ impl Hash for ShadowSampler {
     }
 }
 
+/// Returns the GLSL source code for a function named `function_name` that samples a `sampler2D`
+/// and returns the `border_color` for texture coordinates outside of the range `0.0..=1.0`.
+///
+/// WebGL 2.0 does not support a "clamp to border" wrapping method (see [Wrap]). This emulates it
+/// in shader code. The generated function has the following signature:
+///
+/// ```glsl
+/// vec4 function_name(sampler2D s, vec2 coord);
+/// ```
+///
+/// The generated source may be inserted into the shader source before the function is used. The
+/// sampler should use [Wrap::ClampToEdge] so that texels along the edge of the texture are not
+/// blended with texels from the opposite edge.
+///
+/// See also [shadow_border_guard_glsl].
+///
+/// # Example
+///
+/// ```rust
+/// use web_glitz::image::sampler::border_guard_glsl;
+///
+/// let guard = border_guard_glsl("sampleWithBorder", [0.0, 0.0, 0.0, 1.0]);
+///
+/// let fragment_shader_source = format!("\
+/// #version 300 es
+/// precision mediump float;
+///
+/// uniform sampler2D u_texture;
+///
+/// in vec2 v_texcoord;
+///
+/// out vec4 out_color;
+///
+/// {}
+///
+/// void main() {{
+///     out_color = sampleWithBorder(u_texture, v_texcoord);
+/// }}
+/// ", guard);
+/// ```
+///
+/// # Panics
+///
+/// Panics if any of the components of the `border_color` is not finite.
+pub fn border_guard_glsl(function_name: &str, border_color: [f32; 4]) -> String {
+    let [r, g, b, a] = border_color;
+
+    format!(
+        "vec4 {}(sampler2D s, vec2 coord) {{
+    if (any(lessThan(coord, vec2(0.0))) || any(greaterThan(coord, vec2(1.0)))) {{
+        return vec4({}, {}, {}, {});
+    }}
+
+    return texture(s, coord);
+}}
+",
+        function_name,
+        glsl_float(r),
+        glsl_float(g),
+        glsl_float(b),
+        glsl_float(a)
+    )
+}
+
+/// Returns the GLSL source code for a function named `function_name` that samples a
+/// `sampler2DShadow` and returns the `border_value` for texture coordinates outside of the range
+/// `0.0..=1.0`.
+///
+/// WebGL 2.0 does not support a "clamp to border" wrapping method (see [Wrap]). For a directional
+/// shadow map, fragments that project outside of the shadow map are typically not in shadow; a
+/// `border_value` of `1.0` (all comparisons pass) emulates this. The generated function has the
+/// following signature:
+///
+/// ```glsl
+/// float function_name(sampler2DShadow s, vec3 coord);
+/// ```
+///
+/// Here `coord.xy` are the shadow map coordinates and `coord.z` is the reference depth value; only
+/// `coord.xy` are checked against the range `0.0..=1.0`. The [ShadowSampler] should be created with
+/// [ShadowSamplerDescriptor::clamp_to_edge].
+///
+/// See also [border_guard_glsl].
+///
+/// # Example
+///
+/// ```rust
+/// use web_glitz::image::sampler::shadow_border_guard_glsl;
+///
+/// let guard = shadow_border_guard_glsl("sampleShadow", 1.0);
+///
+/// let fragment_shader_source = format!("\
+/// #version 300 es
+/// precision mediump float;
+///
+/// uniform mediump sampler2DShadow u_shadow_map;
+///
+/// in vec3 v_shadow_coord;
+///
+/// out vec4 out_color;
+///
+/// {}
+///
+/// void main() {{
+///     float lit = sampleShadow(u_shadow_map, v_shadow_coord);
+///
+///     out_color = vec4(vec3(lit), 1.0);
+/// }}
+/// ", guard);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the `border_value` is not finite.
+pub fn shadow_border_guard_glsl(function_name: &str, border_value: f32) -> String {
+    format!(
+        "float {}(sampler2DShadow s, vec3 coord) {{
+    if (any(lessThan(coord.xy, vec2(0.0))) || any(greaterThan(coord.xy, vec2(1.0)))) {{
+        return {};
+    }}
+
+    return texture(s, coord);
+}}
+",
+        function_name,
+        glsl_float(border_value)
+    )
+}
+
+fn glsl_float(value: f32) -> String {
+    if !value.is_finite() {
+        panic!("Border values must be finite, found `{}`.", value);
+    }
+
+    // The `Debug` formatting of an `f32` always includes a decimal point or an exponent, which
+    // makes it a valid GLSL floating point literal (the `Display` formatting would print `1.0` as
+    // `1`, which GLSL parses as an integer literal).
+    format!("{:?}", value)
+}
+
+/// Identifies the sampling parameters of a sampler object, independent of the marker types used to
+/// specify its filters.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub(crate) enum SamplerKey {
+    Sampler {
+        minification_filter: u32,
+        magnification_filter: u32,
+        lod_range: LODRange,
+        wrap_s: Wrap,
+        wrap_t: Wrap,
+        wrap_r: Wrap,
+    },
+    Shadow {
+        compare: CompareFunction,
+        wrap_s: Wrap,
+        wrap_t: Wrap,
+        wrap_r: Wrap,
+    },
+}
+
+/// Interns sampler objects by their [SamplerKey], such that samplers created from identical
+/// descriptors share the same sampler object.
+///
+/// The cache only holds weak references: when all samplers that share a sampler object are
+/// dropped, the sampler object is released as usual and a later request for the same key will
+/// allocate a new sampler object.
+pub(crate) struct SamplerCache {
+    entries: FnvHashMap<SamplerKey, (u64, Weak<SamplerData>)>,
+}
+
+impl SamplerCache {
+    pub(crate) fn new() -> Self {
+        SamplerCache {
+            entries: FnvHashMap::default(),
+        }
+    }
+
+    /// Returns the object ID and the sampler data for a live sampler with the given `key`, or
+    /// `None` if no such sampler exists.
+    pub(crate) fn get(&self, key: &SamplerKey) -> Option<(u64, Arc<SamplerData>)> {
+        self.entries
+            .get(key)
+            .and_then(|(object_id, data)| data.upgrade().map(|data| (*object_id, data)))
+    }
+
+    /// Registers the sampler `data` for the given `key`.
+    pub(crate) fn insert(&mut self, key: SamplerKey, object_id: u64, data: &Arc<SamplerData>) {
+        // Prune entries for samplers that no longer exist, so that the cache does not grow
+        // unboundedly when many different short-lived samplers are created.
+        self.entries.retain(|_, (_, data)| data.strong_count() > 0);
+        self.entries.insert(key, (object_id, Arc::downgrade(data)));
+    }
+}
+
 trait SamplerObjectDropper {
     fn drop_sampler_object(&self, id: JsId);
 }
 
-impl<T> SamplerObjectDropper for T
-where
-    T: RenderingContext,
-{
+impl SamplerObjectDropper for DeletionQueue {
     fn drop_sampler_object(&self, id: JsId) {
-        self.submit(SamplerDropCommand { id });
+        self.schedule(SamplerDropCommand { id });
     }
 }
 
@@ -803,3 +1111,92 @@ unsafe impl GpuTask<Connection> for SamplerDropCommand {
         Progress::Finished(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct NoopDropper;
+
+    impl SamplerObjectDropper for NoopDropper {
+        fn drop_sampler_object(&self, _id: JsId) {}
+    }
+
+    fn sampler_data(id: u32) -> Arc<SamplerData> {
+        Arc::new(SamplerData {
+            id: UnsafeCell::new(Some(JsId::from_abi(id))),
+            context_id: 0,
+            dropper: Box::new(NoopDropper),
+        })
+    }
+
+    #[test]
+    fn test_sampler_cache_identical_descriptors_share_sampler_object() {
+        let mut cache = SamplerCache::new();
+        let descriptor: SamplerDescriptor<Linear, Linear> = Default::default();
+        let data = sampler_data(1);
+
+        assert!(cache.get(&descriptor.key()).is_none());
+
+        cache.insert(descriptor.key(), 7, &data);
+
+        let (object_id, shared_data) = cache.get(&descriptor.clone().key()).unwrap();
+
+        assert_eq!(object_id, 7);
+        assert_eq!(shared_data.id(), Some(JsId::from_abi(1)));
+    }
+
+    #[test]
+    fn test_sampler_cache_different_descriptors() {
+        let mut cache = SamplerCache::new();
+        let descriptor: SamplerDescriptor<Linear, Linear> = Default::default();
+        let other_descriptor = SamplerDescriptor {
+            wrap_s: Wrap::ClampToEdge,
+            ..descriptor.clone()
+        };
+        let data = sampler_data(1);
+
+        cache.insert(descriptor.key(), 7, &data);
+
+        assert!(cache.get(&other_descriptor.key()).is_none());
+        assert!(cache
+            .get(&ShadowSamplerDescriptor::default().key())
+            .is_none());
+    }
+
+    #[test]
+    fn test_sampler_cache_dropped_sampler() {
+        let mut cache = SamplerCache::new();
+        let descriptor: SamplerDescriptor<Linear, Linear> = Default::default();
+        let data = sampler_data(1);
+
+        cache.insert(descriptor.key(), 7, &data);
+
+        drop(data);
+
+        assert!(cache.get(&descriptor.key()).is_none());
+    }
+
+    #[test]
+    fn test_border_guard_glsl() {
+        let source = border_guard_glsl("sampleWithBorder", [1.0, 0.5, 0.0, 1.0]);
+
+        assert!(source.starts_with("vec4 sampleWithBorder(sampler2D s, vec2 coord) {"));
+        assert!(source.contains("return vec4(1.0, 0.5, 0.0, 1.0);"));
+    }
+
+    #[test]
+    fn test_shadow_border_guard_glsl() {
+        let source = shadow_border_guard_glsl("sampleShadow", 1.0);
+
+        assert!(source.starts_with("float sampleShadow(sampler2DShadow s, vec3 coord) {"));
+        assert!(source.contains("any(lessThan(coord.xy, vec2(0.0)))"));
+        assert!(source.contains("return 1.0;"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_shadow_border_guard_glsl_not_finite() {
+        shadow_border_guard_glsl("sampleShadow", f32::NAN);
+    }
+}
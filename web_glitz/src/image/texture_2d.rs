@@ -1,33 +1,40 @@
-use std::borrow::Borrow;
-use std::cell::UnsafeCell;
+use std::cell::{Cell, UnsafeCell};
+use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::marker;
+use std::mem;
 use std::ops::{Deref, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+use std::rc::Rc;
 use std::sync::Arc;
 
 use web_sys::WebGl2RenderingContext as Gl;
 
 use crate::buffer::{BufferData, BufferView};
+use crate::extensions::{color_buffer_float, texture_float_linear};
 use crate::image::format::{
-    Filterable, FloatSamplable, IntegerSamplable, PixelPack, PixelUnpack, ShadowSamplable,
-    TextureFormat, UnsignedIntegerSamplable,
+    ClearValue, Filterable, FloatRenderable, FloatSamplable, IntegerSamplable, PixelPack,
+    PixelUnpack, ShadowSamplable, TextureFormat, UnsignedIntegerSamplable,
 };
-use crate::image::image_source::Image2DSourceInternal;
+use crate::image::image_source::{row_stride_in_bytes, Image2DSourceInternal};
 use crate::image::sampler::{CompatibleSampler, SamplerData, ShadowSampler};
 use crate::image::texture_object_dropper::TextureObjectDropper;
 use crate::image::util::{
-    max_mipmap_levels, mipmap_size, region_2d_overlap_height, region_2d_overlap_width,
-    region_2d_sub_image, texture_data_as_js_buffer,
+    mipmap_size, region_2d_overlap_height, region_2d_overlap_width, region_2d_sub_image,
+    set_unsampled_parameters, upload_region_2d,
 };
-use crate::image::{Image2DSource, MaxMipmapLevelsExceeded, MipmapLevels, Region2D};
-use crate::runtime::state::ContextUpdate;
+use crate::image::{
+    complete_mipmap_levels, Image2DSource, MaxMipmapLevelsExceeded, MipmapLevels, PackOptions,
+    Region2D, UploadOptions,
+};
+use crate::runtime::state::{ContextUpdate, DynamicState};
 use crate::runtime::{Connection, RenderingContext};
-use crate::task::{ContextId, GpuTask, Progress};
-use crate::util::JsId;
+use crate::task::{sequence_iter, ContextId, GpuTask, Progress, SequenceIter};
+use crate::util::{DebugLabel, JsId};
 
 /// Provides the information necessary for the creation of a [Texture2D].
 ///
 /// See [RenderingContext::create_texture_2d] for details.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct Texture2DDescriptor<F>
 where
     F: TextureFormat + 'static,
@@ -80,6 +87,51 @@ where
 /// and [Texture2D::sampled_shadow]. A sampled [Texture2D] may be bound to a pipeline as a resource,
 /// see [web_glitz::pipeline::resources::Resources].
 ///
+/// # Texture2D and Texture2DArray
+///
+/// WebGL 2.0 does not support texture views: the storage of a [Texture2D] cannot be bound as a
+/// single-layer [Texture2DArray] (e.g. to a GLSL `sampler2DArray`), and a single layer of a
+/// [Texture2DArray] cannot be bound as a [Texture2D] (e.g. to a GLSL `sampler2D`). Reinterpreting
+/// the storage of one as the other therefore always requires a copy. Copying the image data is a
+/// GPU-side operation that may be performed with a blit, by attaching the destination to a render
+/// target and using the source image as the blit source. For example, to copy the base level of a
+/// [Texture2D] into layer `3` of the base level of a [Texture2DArray] with the same format and
+/// size:
+///
+/// ```rust
+/// # use web_glitz::runtime::RenderingContext;
+/// # use web_glitz::image::format::RGBA8;
+/// # use web_glitz::image::texture_2d::Texture2D;
+/// # use web_glitz::image::texture_2d_array::Texture2DArray;
+/// # fn wrapper<Rc>(
+/// #     context: &Rc,
+/// #     texture: Texture2D<RGBA8>,
+/// #     mut texture_array: Texture2DArray<RGBA8>
+/// # ) where Rc: RenderingContext {
+/// use web_glitz::image::Region2D;
+/// use web_glitz::rendering::{LoadOp, RenderTargetDescriptor, StoreOp};
+///
+/// let mut base_level = texture_array.base_level_mut();
+/// let mut layers = base_level.layers_mut();
+///
+/// let mut layer_target = context.create_render_target(
+///     RenderTargetDescriptor::new().attach_color_float(
+///         layers.get_mut(3).unwrap(),
+///         LoadOp::Load,
+///         StoreOp::Store,
+///     ),
+/// );
+///
+/// context.submit(layer_target.create_render_pass(|framebuffer| {
+///     framebuffer.blit_color_nearest_command(Region2D::Fill, &texture.base_level())
+/// }));
+/// # }
+/// ```
+///
+/// Note that no copy is required to render to a layer of a [Texture2DArray]: a layer may be
+/// attached to a render target directly, as in the example above. See [Texture2DArray] for the
+/// reverse copy.
+///
 /// # Example
 ///
 /// The following example creates a 2d texture with a width of 256 pixels and a height of 256
@@ -111,6 +163,8 @@ where
 /// ]);
 /// # }
 /// ```
+///
+/// [Texture2DArray]: crate::image::texture_2d_array::Texture2DArray
 pub struct Texture2D<F> {
     object_id: u64,
     data: Arc<Texture2DData>,
@@ -121,6 +175,32 @@ impl<F> Texture2D<F> {
     pub(crate) fn data(&self) -> &Arc<Texture2DData> {
         &self.data
     }
+
+    /// Assigns a debug `label` to this [Texture2D].
+    ///
+    /// The label is included in the [Debug](std::fmt::Debug) output for the texture and in the
+    /// messages of errors that concern the texture.
+    pub fn set_label(&self, label: &str) {
+        self.data.label.set(label);
+    }
+
+    /// Returns the debug label that was assigned to this [Texture2D] with [set_label], or `None` if
+    /// no label was assigned.
+    pub fn label(&self) -> Option<String> {
+        self.data.label.get()
+    }
+}
+
+impl<F> fmt::Debug for Texture2D<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Texture2D")
+            .field("object_id", &self.object_id)
+            .field("label", &self.data.label)
+            .field("width", &self.data.width)
+            .field("height", &self.data.height)
+            .field("levels", &self.data.levels)
+            .finish()
+    }
 }
 
 impl<F> Texture2D<F>
@@ -142,7 +222,7 @@ where
             levels,
             ..
         } = descriptor;
-        let max_mipmap_levels = max_mipmap_levels(*width, *height);
+        let max_mipmap_levels = complete_mipmap_levels(*width, *height);
 
         let levels = match levels {
             MipmapLevels::Complete => max_mipmap_levels,
@@ -161,10 +241,13 @@ where
         let data = Arc::new(Texture2DData {
             id: UnsafeCell::new(None),
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
+            dropper: Box::new(context.deletion_queue()),
             width: *width,
             height: *height,
             levels,
+            level_range: Cell::new((0, (levels as u8).saturating_sub(1))),
+            applied_level_range: Cell::new((0, (levels as u8).saturating_sub(1))),
+            label: DebugLabel::default(),
         });
 
         context.submit(AllocateCommand::<F> {
@@ -269,6 +352,56 @@ where
         }
     }
 
+    /// Returns a mutable reference to the `level` of this [Texture2D], together with a shared
+    /// reference to this [Texture2D], or `None` if the texture does not have the `level`.
+    ///
+    /// The mutable level may be attached to a render target, while the shared reference is used
+    /// to sample other levels of the same texture, e.g. to render into a mipmap chain level by
+    /// level. The sampled levels must exclude the attached level (see
+    /// [FloatSampledTexture2D::with_level_range]); binding a sampled texture for which the level
+    /// range includes the attached level results in an [AttachmentAliasingError].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::image::format::RGBA16F;
+    /// # use web_glitz::image::sampler::Sampler;
+    /// # use web_glitz::image::texture_2d::Texture2D;
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc, mut texture: Texture2D<RGBA16F>, sampler: Sampler)
+    /// # where Rc: RenderingContext {
+    /// use web_glitz::rendering::{LoadOp, RenderTargetDescriptor, StoreOp};
+    ///
+    /// // Refine level 3 from the coarser level 4.
+    /// let (texture, level_3) = texture.split_level_mut(3).unwrap();
+    /// let coarse = texture.float_sampled(&sampler).with_level_range(4, 4);
+    ///
+    /// let mut render_target = context.create_render_target(
+    ///     RenderTargetDescriptor::new().attach_color_float(level_3, LoadOp::Load, StoreOp::Store),
+    /// );
+    ///
+    /// // Create a render pass with a pipeline task that binds `coarse` as a resource...
+    /// # }
+    /// ```
+    ///
+    /// [AttachmentAliasingError]: crate::rendering::AttachmentAliasingError
+    pub fn split_level_mut(&mut self, level: usize) -> Option<(&Texture2D<F>, LevelMut<F>)> {
+        let handle: &Texture2D<F> = self;
+
+        if level < handle.data.levels {
+            // The shared reference can only be used to sample the level through a sampled
+            // texture, which is checked against the attached levels when it is bound.
+            Some((
+                handle,
+                LevelMut {
+                    inner: Level { handle, level },
+                },
+            ))
+        } else {
+            None
+        }
+    }
+
     /// The texture format for this [Texture2D]
     pub fn format(&self) -> F {
         self.format
@@ -283,11 +416,171 @@ where
     pub fn height(&self) -> u32 {
         self.data.height
     }
+
+    /// Returns a command which, when executed, restricts the mipmap levels of this [Texture2D] that
+    /// may be accessed by samplers to the levels `base_level` up to and including `max_level`.
+    ///
+    /// This does not change the levels that are allocated for the texture (see [MipmapLevels]);
+    /// all levels remain accessible for uploads. Levels outside of the range are ignored when the
+    /// texture is sampled: a sampler will treat `base_level` as the most detailed level and
+    /// `max_level` as the least detailed level. This may for example be used to stream in the
+    /// image data for a texture progressively: the least detailed levels are uploaded first and
+    /// the base level of the range is lowered as more detailed levels become available, so that
+    /// levels that have not yet been uploaded are never sampled.
+    ///
+    /// Note that [generate_mipmap_command] also respects this range: it generates the levels after
+    /// `base_level` from `base_level`, up to and including `max_level`.
+    ///
+    /// Initially the range covers all allocated levels.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::image::MipmapLevels;
+    /// use web_glitz::image::format::RGBA8;
+    /// use web_glitz::image::texture_2d::Texture2DDescriptor;
+    ///
+    /// let texture = context.try_create_texture_2d(&Texture2DDescriptor {
+    ///     format: RGBA8,
+    ///     width: 1024,
+    ///     height: 1024,
+    ///     levels: MipmapLevels::Complete
+    /// }).unwrap();
+    ///
+    /// // Only the levels 4 through 10 (64x64 pixels and smaller) have been uploaded so far.
+    /// context.submit(texture.set_level_range_command(4, 10));
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base_level` is greater than `max_level`, or if `max_level` is not smaller than
+    /// the number of levels allocated for this texture.
+    ///
+    /// [generate_mipmap_command]: Texture2D::generate_mipmap_command
+    pub fn set_level_range_command(
+        &self,
+        base_level: usize,
+        max_level: usize,
+    ) -> SetLevelRangeCommand {
+        if base_level > max_level {
+            panic!(
+                "The base level ({}) must not be greater than the max level ({}).",
+                base_level, max_level
+            );
+        }
+
+        if max_level >= self.data.levels {
+            panic!(
+                "The max level ({}) must be smaller than the number of levels allocated for the \
+                texture ({}).",
+                max_level, self.data.levels
+            );
+        }
+
+        SetLevelRangeCommand {
+            texture_data: self.data.clone(),
+            base_level,
+            max_level,
+        }
+    }
+
+    /// Returns a command which, when executed, generates the image data for this [Texture2D]'s
+    /// mipmap chain with a series of custom downsample passes.
+    ///
+    /// Calls `f` with each pair of adjacent levels in downsampling order (level `0` as the source
+    /// for level `1`, then level `1` as the source for level `2`, etc.) to obtain the task that
+    /// renders the source level into the target level. `f` is called when the command executes,
+    /// right before the pass for the target level runs, rather than when the command is created;
+    /// the task it returns must belong to the same context as this [Texture2D]. This task is
+    /// typically a [RenderPass] that runs a downsample pipeline that samples the source level.
+    /// Unlike [generate_mipmap_command], which uses the driver's (typically box) filter, this
+    /// allows using a custom filter kernel. If the texture uses an sRGB format, then a downsample
+    /// pipeline that samples and writes the levels also filters in linear color space, whereas the
+    /// driver's filter typically does not.
+    ///
+    /// A level may only be sampled while an adjacent level of the same texture is attached to a
+    /// render target if the sampled level range of the texture excludes the attached level. The
+    /// command therefore restricts the level range (see [set_level_range_command]) to the source
+    /// level before each pass. When the command finishes, it restores the level range the texture
+    /// had when the command began executing. A pipeline that samples the source level must also
+    /// declare this level range when it binds the texture (see
+    /// [FloatSampledTexture2D::with_level_range]), e.g. with
+    /// `source.texture().float_sampled(&sampler).with_level_range(source.level(), source.level())`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # use web_glitz::image::texture_2d::Texture2D;
+    /// # use web_glitz::image::format::RGBA8;
+    /// # fn wrapper<Rc>(context: &Rc, mut texture: Texture2D<RGBA8>)
+    /// # where
+    /// #     Rc: RenderingContext + Clone + 'static,
+    /// # {
+    /// use web_glitz::image::Region2D;
+    /// use web_glitz::rendering::{LoadOp, RenderTargetDescriptor, StoreOp};
+    ///
+    /// let pass_context = context.clone();
+    ///
+    /// let command = texture.generate_mipmap_custom_command(move |source, target| {
+    ///     let mut render_target = pass_context.create_render_target(
+    ///         RenderTargetDescriptor::new().attach_color_float(
+    ///             target,
+    ///             LoadOp::Load,
+    ///             StoreOp::Store,
+    ///         ),
+    ///     );
+    ///
+    ///     render_target.create_render_pass(|framebuffer| {
+    ///         framebuffer.blit_color_linear_command(Region2D::Fill, source)
+    ///     })
+    /// });
+    ///
+    /// context.submit(command);
+    /// # }
+    /// ```
+    ///
+    /// Here the passes simply blit each level into the next level; a custom filter would instead
+    /// run a pipeline that samples the `source` level (e.g. with a tent or Kaiser kernel). As `f`
+    /// is only called when the command executes, it takes ownership of the resources it uses (here
+    /// a clone of the rendering context).
+    ///
+    /// # Panics
+    ///
+    /// The command panics when it executes if a task returned by `f` belongs to a different context
+    /// than this [Texture2D].
+    ///
+    /// [RenderPass]: crate::rendering::RenderPass
+    /// [generate_mipmap_command]: Texture2D::generate_mipmap_command
+    /// [set_level_range_command]: Texture2D::set_level_range_command
+    pub fn generate_mipmap_custom_command<B, T>(
+        &mut self,
+        f: B,
+    ) -> GenerateMipmapCustomCommand<F, B, T>
+    where
+        B: FnMut(&Level<F>, LevelMut<F>) -> T,
+        T: GpuTask<Connection, Output = ()>,
+    {
+        GenerateMipmapCustomCommand {
+            texture: Texture2D {
+                object_id: self.object_id,
+                data: self.data.clone(),
+                format: self.format,
+            },
+            f,
+            target_level: 1,
+            pass: None,
+            initial_level_range: None,
+        }
+    }
 }
 
 impl<F> Texture2D<F>
 where
-    F: TextureFormat + Filterable + 'static,
+    F: TextureFormat + Filterable + FloatRenderable + 'static,
 {
     /// Returns a command which, when executed, will generate new mipmap data for the [Texture2D].
     ///
@@ -299,7 +592,17 @@ where
     /// overwritten. Note that the base level (level 0) is not modified (rather, it serves as the
     /// input for this process).
     ///
-    /// This operation is only available to a texture if the texture format implements [Filterable].
+    /// This operation is only available to a texture if the texture format implements both
+    /// [Filterable] and [FloatRenderable]. See [generate_mipmap_command_with_color_buffer_float] and
+    /// [generate_mipmap_command_with_float_linear] for formats that only support mipmap generation
+    /// when certain extensions are available.
+    ///
+    /// The implementation may be asked to favor the quality of the generated mipmaps over the speed
+    /// of their generation with [ContextOptionsBuilder::mipmap_hint].
+    ///
+    /// [generate_mipmap_command_with_color_buffer_float]: Texture2D::generate_mipmap_command_with_color_buffer_float
+    /// [generate_mipmap_command_with_float_linear]: Texture2D::generate_mipmap_command_with_float_linear
+    /// [ContextOptionsBuilder::mipmap_hint]: crate::runtime::ContextOptionsBuilder::mipmap_hint
     pub fn generate_mipmap_command(&self) -> GenerateMipmapCommand {
         GenerateMipmapCommand {
             texture_data: self.data.clone(),
@@ -307,6 +610,73 @@ where
     }
 }
 
+impl<F> Texture2D<F>
+where
+    F: TextureFormat + Filterable + color_buffer_float::FloatRenderable + 'static,
+{
+    /// Returns a command which, when executed, will generate new mipmap data for the [Texture2D].
+    ///
+    /// Identical to [generate_mipmap_command], except that it is also available to textures that
+    /// use a (16-bit) floating point format that is only renderable when the
+    /// [color_buffer_float] extension is available (mipmap generation requires a format to be both
+    /// filterable and renderable).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `extension` belongs to a different context than this [Texture2D].
+    ///
+    /// [generate_mipmap_command]: Texture2D::generate_mipmap_command
+    pub fn generate_mipmap_command_with_color_buffer_float(
+        &self,
+        extension: &color_buffer_float::Extension,
+    ) -> GenerateMipmapCommand {
+        if extension.context_id() != self.data.context_id {
+            panic!("Extension belongs to a different context than this texture.");
+        }
+
+        GenerateMipmapCommand {
+            texture_data: self.data.clone(),
+        }
+    }
+}
+
+impl<F> Texture2D<F>
+where
+    F: TextureFormat
+        + texture_float_linear::Filterable
+        + color_buffer_float::FloatRenderable
+        + 'static,
+{
+    /// Returns a command which, when executed, will generate new mipmap data for the [Texture2D].
+    ///
+    /// Identical to [generate_mipmap_command], except that it is also available to textures that
+    /// use a 32-bit floating point format, which is only filterable when the
+    /// [texture_float_linear] extension is available and only renderable when the
+    /// [color_buffer_float] extension is available (mipmap generation requires a format to be both
+    /// filterable and renderable).
+    ///
+    /// # Panics
+    ///
+    /// Panics if either of the extensions belongs to a different context than this [Texture2D].
+    ///
+    /// [generate_mipmap_command]: Texture2D::generate_mipmap_command
+    pub fn generate_mipmap_command_with_float_linear(
+        &self,
+        color_buffer_float: &color_buffer_float::Extension,
+        texture_float_linear: &texture_float_linear::Extension,
+    ) -> GenerateMipmapCommand {
+        if color_buffer_float.context_id() != self.data.context_id
+            || texture_float_linear.context_id() != self.data.context_id
+        {
+            panic!("Extension belongs to a different context than this texture.");
+        }
+
+        GenerateMipmapCommand {
+            texture_data: self.data.clone(),
+        }
+    }
+}
+
 impl<F> Texture2D<F>
 where
     F: TextureFormat + FloatSamplable + 'static,
@@ -333,8 +703,24 @@ where
         }
 
         FloatSampledTexture2D {
-            sampler_data: sampler.data().clone(),
+            sampler_data: Some(sampler.data().clone()),
+            texture_data: self.data().clone(),
+            level_range: None,
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns a [FloatSampledTexture2D] without a sampler object, for a `sampler2D` that is only
+    /// read with `texelFetch`.
+    ///
+    /// See [unsampled textures] for details.
+    ///
+    /// [unsampled textures]: crate::image#unsampled-textures
+    pub fn float_unsampled(&self) -> FloatSampledTexture2D {
+        FloatSampledTexture2D {
+            sampler_data: None,
             texture_data: self.data().clone(),
+            level_range: None,
             _marker: marker::PhantomData,
         }
     }
@@ -344,11 +730,56 @@ where
 /// point sampler.
 #[derive(Clone)]
 pub struct FloatSampledTexture2D<'a> {
-    pub(crate) sampler_data: Arc<SamplerData>,
+    pub(crate) sampler_data: Option<Arc<SamplerData>>,
     pub(crate) texture_data: Arc<Texture2DData>,
+    pub(crate) level_range: Option<(u8, u8)>,
     _marker: marker::PhantomData<&'a ()>,
 }
 
+impl<'a> FloatSampledTexture2D<'a> {
+    /// Restricts the mipmap levels that may be sampled to the levels from `base_level` up to and
+    /// including `max_level`.
+    ///
+    /// This level range only applies to this resource: it does not change the texture's own level
+    /// range (see [Texture2D::set_level_range_command]), which continues to apply wherever the
+    /// texture is bound without a level range.
+    ///
+    /// A texture may not be sampled while it is also attached to the framebuffer a pipeline task
+    /// draws to (see [AttachmentAliasingError]), unless the sampled level range excludes the
+    /// attached level. This allows rendering into a mipmap chain level by level while sampling
+    /// other levels of the same texture, e.g. to progressively refine from coarse to fine levels.
+    ///
+    /// See [Texture2D::split_level_mut] for an example.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base_level` is greater than `max_level`, or if `max_level` is not smaller than
+    /// the number of levels allocated for the texture.
+    ///
+    /// [AttachmentAliasingError]: crate::rendering::AttachmentAliasingError
+    pub fn with_level_range(self, base_level: usize, max_level: usize) -> Self {
+        if base_level > max_level {
+            panic!(
+                "The base level ({}) must not be greater than the max level ({}).",
+                base_level, max_level
+            );
+        }
+
+        if max_level >= self.texture_data.levels {
+            panic!(
+                "The max level ({}) must be smaller than the number of levels allocated for the \
+                texture ({}).",
+                max_level, self.texture_data.levels
+            );
+        }
+
+        FloatSampledTexture2D {
+            level_range: Some((base_level as u8, max_level as u8)),
+            ..self
+        }
+    }
+}
+
 impl<F> Texture2D<F>
 where
     F: TextureFormat + IntegerSamplable + 'static,
@@ -375,7 +806,21 @@ where
         }
 
         IntegerSampledTexture2D {
-            sampler_data: sampler.data().clone(),
+            sampler_data: Some(sampler.data().clone()),
+            texture_data: self.data().clone(),
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns an [IntegerSampledTexture2D] without a sampler object, for an `isampler2D` that is
+    /// only read with `texelFetch`.
+    ///
+    /// See [unsampled textures] for details.
+    ///
+    /// [unsampled textures]: crate::image#unsampled-textures
+    pub fn integer_unsampled(&self) -> IntegerSampledTexture2D {
+        IntegerSampledTexture2D {
+            sampler_data: None,
             texture_data: self.data().clone(),
             _marker: marker::PhantomData,
         }
@@ -386,7 +831,7 @@ where
 /// sampler.
 #[derive(Clone)]
 pub struct IntegerSampledTexture2D<'a> {
-    pub(crate) sampler_data: Arc<SamplerData>,
+    pub(crate) sampler_data: Option<Arc<SamplerData>>,
     pub(crate) texture_data: Arc<Texture2DData>,
     _marker: marker::PhantomData<&'a ()>,
 }
@@ -417,7 +862,21 @@ where
         }
 
         UnsignedIntegerSampledTexture2D {
-            sampler_data: sampler.data().clone(),
+            sampler_data: Some(sampler.data().clone()),
+            texture_data: self.data().clone(),
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns an [UnsignedIntegerSampledTexture2D] without a sampler object, for a `usampler2D`
+    /// that is only read with `texelFetch`.
+    ///
+    /// See [unsampled textures] for details.
+    ///
+    /// [unsampled textures]: crate::image#unsampled-textures
+    pub fn unsigned_integer_unsampled(&self) -> UnsignedIntegerSampledTexture2D {
+        UnsignedIntegerSampledTexture2D {
+            sampler_data: None,
             texture_data: self.data().clone(),
             _marker: marker::PhantomData,
         }
@@ -428,7 +887,7 @@ where
 /// integer sampler.
 #[derive(Clone)]
 pub struct UnsignedIntegerSampledTexture2D<'a> {
-    pub(crate) sampler_data: Arc<SamplerData>,
+    pub(crate) sampler_data: Option<Arc<SamplerData>>,
     pub(crate) texture_data: Arc<Texture2DData>,
     _marker: marker::PhantomData<&'a ()>,
 }
@@ -452,7 +911,7 @@ where
         }
 
         ShadowSampledTexture2D {
-            sampler_data: shadow_sampler.data().clone(),
+            sampler_data: Some(shadow_sampler.data().clone()),
             texture_data: self.data().clone(),
             _marker: marker::PhantomData,
         }
@@ -463,7 +922,7 @@ where
 /// sampler.
 #[derive(Clone)]
 pub struct ShadowSampledTexture2D<'a> {
-    pub(crate) sampler_data: Arc<SamplerData>,
+    pub(crate) sampler_data: Option<Arc<SamplerData>>,
     pub(crate) texture_data: Arc<Texture2DData>,
     _marker: marker::PhantomData<&'a ()>,
 }
@@ -487,6 +946,12 @@ pub(crate) struct Texture2DData {
     width: u32,
     height: u32,
     levels: usize,
+    // The level range set with `Texture2D::set_level_range_command`, and the level range that is
+    // currently set on the texture object. These differ after the texture was bound as a resource
+    // with a different level range (see `FloatSampledTexture2D::with_level_range`).
+    level_range: Cell<(u8, u8)>,
+    applied_level_range: Cell<(u8, u8)>,
+    label: DebugLabel,
 }
 
 impl Texture2DData {
@@ -494,9 +959,40 @@ impl Texture2DData {
         unsafe { *self.id.get() }
     }
 
+    /// Sets the level range of the texture object to the `level_range`, or to the texture's own
+    /// level range (see [Texture2D::set_level_range_command]) if the `level_range` is `None`,
+    /// unless that level range is already set.
+    ///
+    /// The texture object must be bound to the `TEXTURE_2D` target of the active texture unit.
+    pub(crate) fn apply_level_range(
+        &self,
+        gl: &Gl,
+        state: &mut DynamicState,
+        level_range: Option<(u8, u8)>,
+    ) {
+        let level_range = level_range.unwrap_or_else(|| self.level_range.get());
+
+        if level_range != self.applied_level_range.get() {
+            let (base_level, max_level) = level_range;
+
+            gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_BASE_LEVEL, base_level as i32);
+            gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MAX_LEVEL, max_level as i32);
+
+            self.applied_level_range.set(level_range);
+
+            // A bind group that bound the texture with the previous level range may no longer be
+            // skipped.
+            state.invalidate_bound_bind_groups();
+        }
+    }
+
     pub(crate) fn context_id(&self) -> u64 {
         self.context_id
     }
+
+    pub(crate) fn label(&self) -> &DebugLabel {
+        &self.label
+    }
 }
 
 impl PartialEq for Texture2DData {
@@ -883,6 +1379,11 @@ where
         self.handle.data()
     }
 
+    /// Returns a reference to the texture this [Level] belongs to.
+    pub fn texture(&self) -> &Texture2D<F> {
+        self.handle
+    }
+
     /// Returns the integer that identifies this level.
     ///
     /// For example, if this [Level] is the texture's base level, returns `0`; if it is the second
@@ -985,6 +1486,56 @@ where
     /// # }
     /// ```
     pub fn upload_command<D, T>(&self, data: Image2DSource<D, T>) -> UploadCommand<D, T, F>
+    where
+        T: PixelUnpack<F>,
+    {
+        self.upload_command_with_options(data, UploadOptions::default())
+    }
+
+    /// Returns a command which, when executed, replaces the image data in this [Level]'s image
+    /// with the image data provided in `data`, interpreting the data according to the given
+    /// `options`.
+    ///
+    /// Behaves identically to [upload_command], except that the `options` may be used to
+    /// vertically flip the image and/or to premultiply its color components with its alpha
+    /// component during the upload. See [UploadOptions] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::image::{Image2DSource, MipmapLevels, UploadOptions};
+    /// use web_glitz::image::format::RGBA8;
+    /// use web_glitz::image::texture_2d::Texture2DDescriptor;
+    ///
+    /// let texture = context.try_create_texture_2d(&Texture2DDescriptor {
+    ///     format: RGBA8,
+    ///     width: 256,
+    ///     height: 256,
+    ///     levels: MipmapLevels::Complete
+    /// }).unwrap();
+    ///
+    /// // Image data that stores its top row first, as is common for decoded image files.
+    /// let pixels: Vec<[u8; 4]> = vec![[255, 0, 0, 255]; 256 * 256];
+    /// let data = Image2DSource::from_pixels(pixels, 256, 256).unwrap();
+    ///
+    /// let base_level = texture.base_level();
+    /// let options = UploadOptions {
+    ///     flip_y: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// context.submit(base_level.upload_command_with_options(data, options));
+    /// # }
+    /// ```
+    ///
+    /// [upload_command]: Level::upload_command
+    pub fn upload_command_with_options<D, T>(
+        &self,
+        data: Image2DSource<D, T>,
+        options: UploadOptions,
+    ) -> UploadCommand<D, T, F>
     where
         T: PixelUnpack<F>,
     {
@@ -993,46 +1544,299 @@ where
             texture_data: self.handle.data.clone(),
             level: self.level,
             region: Region2D::Fill,
+            options,
             _marker: marker::PhantomData,
         }
     }
 
-    pub fn pack_to_buffer_command<P>(&self, buffer: BufferView<[P]>) -> PackToBufferCommand<F, P>
+    /// Returns a command which, when executed, replaces the image data in the `region` of this
+    /// [Level]'s image with the image data provided in `data`.
+    ///
+    /// Unlike uploading to a [sub_image], which silently ignores any part of the `data` that falls
+    /// outside of the sub-image, this requires the dimensions of the image provided in `data` to
+    /// exactly match the dimensions of the `region`. This makes it suitable for streaming content
+    /// into sub-rectangles of a larger texture, e.g. for a texture atlas.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width and height of the image provided in `data` do not match the width and
+    /// height of the `region`.
+    ///
+    /// Panics if the `region` does not fit inside this [Level]'s image.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::image::{Image2DSource, MipmapLevels, Region2D};
+    /// use web_glitz::image::format::RGBA8;
+    /// use web_glitz::image::texture_2d::Texture2DDescriptor;
+    ///
+    /// let atlas = context.try_create_texture_2d(&Texture2DDescriptor {
+    ///     format: RGBA8,
+    ///     width: 1024,
+    ///     height: 1024,
+    ///     levels: MipmapLevels::Partial(1)
+    /// }).unwrap();
+    ///
+    /// let sprite: Vec<[u8; 4]> = vec![[255, 0, 0, 255]; 32 * 16];
+    /// let data = Image2DSource::from_pixels(sprite, 32, 16).unwrap();
+    ///
+    /// let region = Region2D::Area((256, 128), 32, 16);
+    ///
+    /// context.submit(atlas.base_level().upload_region_command(region, data));
+    /// # }
+    /// ```
+    pub fn upload_region_command<D, T>(
+        &self,
+        region: Region2D,
+        data: Image2DSource<D, T>,
+    ) -> UploadCommand<D, T, F>
     where
-        P: PixelPack<F>,
+        T: PixelUnpack<F>,
     {
-        let offset = buffer.offset_in_bytes();
-        let buffer_data = buffer.buffer_data();
-        let texture_data = self.texture_data();
-
-        if buffer_data.context_id() != texture_data.context_id() {
-            panic!("Buffer belongs to a different context");
-        }
+        let (image_width, image_height) = data.dimensions();
+        let region = upload_region_2d(
+            region,
+            self.width(),
+            self.height(),
+            image_width,
+            image_height,
+        );
 
-        PackToBufferCommand {
-            texture_data: texture_data.clone(),
-            buffer_data: buffer_data.clone(),
-            offset,
+        UploadCommand {
+            data,
+            texture_data: self.handle.data.clone(),
             level: self.level,
-            region: Region2D::Fill,
+            region,
+            options: UploadOptions::default(),
             _marker: marker::PhantomData,
         }
     }
-}
-
-/// Returned from [Level::sub_image], a reference to a sub-region of a [Level]'s image.
-///
-/// See [Level::sub_image] for details.
-#[derive(PartialEq, Hash)]
-pub struct LevelSubImage<'a, F> {
-    handle: &'a Texture2D<F>,
-    level: usize,
-    region: Region2D,
-}
 
-impl<'a, F> LevelSubImage<'a, F>
-where
-    F: TextureFormat,
+    /// Returns a task which, when executed, replaces the image data in this [Level]'s image with
+    /// the image data provided in `data`, uploading the data in horizontal bands of at most
+    /// `rows_per_chunk` rows.
+    ///
+    /// Uploading a very large image with a single command (see [upload_command]) may stall the
+    /// main thread for a considerable amount of time. This instead returns a sequence of commands
+    /// that each upload one band of rows, starting with the bottom band (the band at the origin).
+    /// The resulting image is identical to the image that results from a single [upload_command]
+    /// with the same `data`, including the handling of `data` with dimensions that do not match
+    /// the dimensions of the [Level].
+    ///
+    /// All bands are uploaded in a single turn of the executor, unless the sequence is combined
+    /// with a progress callback (see [SequenceIter::with_progress]), in which case the task yields
+    /// after every band, giving the browser an opportunity to update the page in between bands.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows_per_chunk` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::image::{Image2DSource, MipmapLevels};
+    /// use web_glitz::image::format::RGBA8;
+    /// use web_glitz::image::texture_2d::Texture2DDescriptor;
+    ///
+    /// let texture = context.try_create_texture_2d(&Texture2DDescriptor {
+    ///     format: RGBA8,
+    ///     width: 4096,
+    ///     height: 4096,
+    ///     levels: MipmapLevels::Partial(1)
+    /// }).unwrap();
+    ///
+    /// let pixels: Vec<[u8; 4]> = vec![[255, 0, 0, 255]; 4096 * 4096];
+    /// let data = Image2DSource::from_pixels(pixels, 4096, 4096).unwrap();
+    ///
+    /// let task = texture
+    ///     .base_level()
+    ///     .upload_chunked_command(data, 256)
+    ///     .with_progress(|done, total| {
+    ///         // Update a loading bar...
+    ///     });
+    ///
+    /// context.submit(task);
+    /// # }
+    /// ```
+    ///
+    /// [upload_command]: Level::upload_command
+    /// [SequenceIter::with_progress]: crate::task::SequenceIter::with_progress
+    pub fn upload_chunked_command<D, T>(
+        &self,
+        data: Image2DSource<D, T>,
+        rows_per_chunk: u32,
+    ) -> SequenceIter<UploadChunkCommand<D, T, F>, Connection>
+    where
+        T: PixelUnpack<F>,
+        F: TextureFormat,
+    {
+        if rows_per_chunk == 0 {
+            panic!("`rows_per_chunk` must be greater than `0`.");
+        }
+
+        let (image_width, image_height) = data.dimensions();
+        let width = self.width().min(image_width);
+        let height = self.height().min(image_height);
+
+        let command = Rc::new(self.upload_command(data));
+        let chunk_count = (height + rows_per_chunk - 1) / rows_per_chunk;
+
+        sequence_iter((0..chunk_count).map(|i| {
+            let offset_y = i * rows_per_chunk;
+            let rows = rows_per_chunk.min(height - offset_y);
+
+            UploadChunkCommand {
+                command: command.clone(),
+                region: Region2D::Area((0, offset_y), width, rows),
+                options: UploadOptions {
+                    skip_rows: offset_y,
+                    ..Default::default()
+                },
+            }
+        }))
+    }
+
+    /// Returns a command which, when executed, copies the image data stored in this [Level] into
+    /// the `buffer`.
+    ///
+    /// The pixel data is stored in the `buffer` row by row, starting with the bottom row (the row
+    /// at the origin). Rows are tightly packed. See [pack_to_buffer_command_with_options] for
+    /// control over the layout of the rows in the `buffer`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `buffer` belongs to a different context than this [Level].
+    ///
+    /// [pack_to_buffer_command_with_options]: Level::pack_to_buffer_command_with_options
+    pub fn pack_to_buffer_command<P>(&self, buffer: BufferView<[P]>) -> PackToBufferCommand<F, P>
+    where
+        P: PixelPack<F>,
+    {
+        self.pack_to_buffer_command_with_options(buffer, PackOptions::default())
+    }
+
+    /// Returns a command which, when executed, copies the image data stored in this [Level] into
+    /// the `buffer`, where the layout of the rows in the `buffer` is controlled by the `options`.
+    ///
+    /// See [PackOptions] for details on the available options.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `buffer` belongs to a different context than this [Level].
+    ///
+    /// Panics if the `options` specify a row length other than `0` that is smaller than the width
+    /// of this [Level].
+    pub fn pack_to_buffer_command_with_options<P>(
+        &self,
+        buffer: BufferView<[P]>,
+        options: PackOptions,
+    ) -> PackToBufferCommand<F, P>
+    where
+        P: PixelPack<F>,
+    {
+        let offset = buffer.offset_in_bytes();
+        let buffer_data = buffer.buffer_data();
+        let texture_data = self.texture_data();
+
+        if buffer_data.context_id() != texture_data.context_id() {
+            panic!("Buffer belongs to a different context");
+        }
+
+        if options.row_length != 0 && options.row_length < self.width() {
+            panic!(
+                "The row length ({}) must not be smaller than the width of the image ({}).",
+                options.row_length,
+                self.width()
+            );
+        }
+
+        PackToBufferCommand {
+            texture_data: texture_data.clone(),
+            buffer_data: buffer_data.clone(),
+            offset,
+            level: self.level,
+            region: Region2D::Fill,
+            options,
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns a command which, when executed, clears the image of this [Level] to the `value`.
+    ///
+    /// The type of the `value` must match the format of the texture: a `[f32; 4]` for formats that
+    /// store floating point values, a `[i32; 4]` or a `[u32; 4]` for formats that store integer
+    /// or unsigned integer values respectively, a `f32` for depth formats, or a `(f32, i32)` for
+    /// depth-stencil formats (see [ClearValue]). Only formats that are renderable without
+    /// extensions may be cleared this way.
+    ///
+    /// This does not require a render target: the image is attached to a framebuffer that is used
+    /// only for clearing. To clear an image that is attached to a render target, use the
+    /// [LoadOp] of the attachment or one of the clear commands of the framebuffer's buffers
+    /// instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::image::MipmapLevels;
+    /// use web_glitz::image::format::{DepthComponent24, RGBA32UI};
+    /// use web_glitz::image::texture_2d::Texture2DDescriptor;
+    /// use web_glitz::task::sequence;
+    ///
+    /// let accumulation = context.try_create_texture_2d(&Texture2DDescriptor {
+    ///     format: RGBA32UI,
+    ///     width: 256,
+    ///     height: 256,
+    ///     levels: MipmapLevels::Partial(1)
+    /// }).unwrap();
+    ///
+    /// let depth = context.try_create_texture_2d(&Texture2DDescriptor {
+    ///     format: DepthComponent24,
+    ///     width: 256,
+    ///     height: 256,
+    ///     levels: MipmapLevels::Partial(1)
+    /// }).unwrap();
+    ///
+    /// context.submit(sequence(
+    ///     accumulation.base_level().clear_command([0, 0, 0, 0]),
+    ///     depth.base_level().clear_command(1.0),
+    /// ));
+    /// # }
+    /// ```
+    ///
+    /// [LoadOp]: crate::rendering::LoadOp
+    pub fn clear_command<V>(&self, value: V) -> ClearCommand<F, V>
+    where
+        V: ClearValue<F>,
+    {
+        ClearCommand {
+            texture_data: self.texture_data().clone(),
+            level: self.level,
+            value,
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
+/// Returned from [Level::sub_image], a reference to a sub-region of a [Level]'s image.
+///
+/// See [Level::sub_image] for details.
+#[derive(PartialEq, Hash)]
+pub struct LevelSubImage<'a, F> {
+    handle: &'a Texture2D<F>,
+    level: usize,
+    region: Region2D,
+}
+
+impl<'a, F> LevelSubImage<'a, F>
+where
+    F: TextureFormat,
 {
     pub(crate) fn level_ref(&self) -> Level<F> {
         Level {
@@ -1122,6 +1926,56 @@ where
     /// # }
     /// ```
     pub fn upload_command<D, T>(&self, data: Image2DSource<D, T>) -> UploadCommand<D, T, F>
+    where
+        T: PixelUnpack<F>,
+    {
+        self.upload_command_with_options(data, UploadOptions::default())
+    }
+
+    /// Returns a command which, when executed, replaces the image data in this [LevelSubImage]'s
+    /// image with the image data provided in `data`, interpreting the data according to the given
+    /// `options`.
+    ///
+    /// Behaves identically to [upload_command], except that the `options` may be used to
+    /// vertically flip the image and/or to premultiply its color components with its alpha
+    /// component during the upload. See [UploadOptions] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::image::{Image2DSource, MipmapLevels, Region2D, UploadOptions};
+    /// use web_glitz::image::format::RGBA8;
+    /// use web_glitz::image::texture_2d::Texture2DDescriptor;
+    ///
+    /// let texture = context.try_create_texture_2d(&Texture2DDescriptor {
+    ///     format: RGBA8,
+    ///     width: 256,
+    ///     height: 256,
+    ///     levels: MipmapLevels::Complete
+    /// }).unwrap();
+    ///
+    /// // Image data that stores its top row first, as is common for decoded image files.
+    /// let pixels: Vec<[u8; 4]> = vec![[255, 0, 0, 255]; 128 * 128];
+    /// let data = Image2DSource::from_pixels(pixels, 128, 128).unwrap();
+    ///
+    /// let sub_image = texture.base_level().sub_image(Region2D::Area((0, 0), 128, 128));
+    /// let options = UploadOptions {
+    ///     flip_y: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// context.submit(sub_image.upload_command_with_options(data, options));
+    /// # }
+    /// ```
+    ///
+    /// [upload_command]: LevelSubImage::upload_command
+    pub fn upload_command_with_options<D, T>(
+        &self,
+        data: Image2DSource<D, T>,
+        options: UploadOptions,
+    ) -> UploadCommand<D, T, F>
     where
         T: PixelUnpack<F>,
     {
@@ -1130,11 +1984,46 @@ where
             texture_data: self.handle.data.clone(),
             level: self.level,
             region: self.region,
+            options,
             _marker: marker::PhantomData,
         }
     }
 
+    /// Returns a command which, when executed, copies the image data stored in this [LevelSubImage] into
+    /// the `buffer`.
+    ///
+    /// The pixel data is stored in the `buffer` row by row, starting with the bottom row (the row
+    /// at the origin). Rows are tightly packed. See [pack_to_buffer_command_with_options] for
+    /// control over the layout of the rows in the `buffer`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `buffer` belongs to a different context than this [LevelSubImage].
+    ///
+    /// [pack_to_buffer_command_with_options]: LevelSubImage::pack_to_buffer_command_with_options
     pub fn pack_to_buffer_command<P>(&self, buffer: BufferView<[P]>) -> PackToBufferCommand<F, P>
+    where
+        P: PixelPack<F>,
+    {
+        self.pack_to_buffer_command_with_options(buffer, PackOptions::default())
+    }
+
+    /// Returns a command which, when executed, copies the image data stored in this [LevelSubImage] into
+    /// the `buffer`, where the layout of the rows in the `buffer` is controlled by the `options`.
+    ///
+    /// See [PackOptions] for details on the available options.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `buffer` belongs to a different context than this [LevelSubImage].
+    ///
+    /// Panics if the `options` specify a row length other than `0` that is smaller than the width
+    /// of this [LevelSubImage].
+    pub fn pack_to_buffer_command_with_options<P>(
+        &self,
+        buffer: BufferView<[P]>,
+        options: PackOptions,
+    ) -> PackToBufferCommand<F, P>
     where
         P: PixelPack<F>,
     {
@@ -1146,12 +2035,21 @@ where
             panic!("Buffer belongs to a different context");
         }
 
+        if options.row_length != 0 && options.row_length < self.width() {
+            panic!(
+                "The row length ({}) must not be smaller than the width of the image ({}).",
+                options.row_length,
+                self.width()
+            );
+        }
+
         PackToBufferCommand {
             texture_data: texture_data.clone(),
             buffer_data: buffer_data.clone(),
             offset,
             level: self.level,
             region: self.region,
+            options,
             _marker: marker::PhantomData,
         }
     }
@@ -1552,17 +2450,25 @@ where
             .apply(gl)
             .unwrap();
 
-        let levels = data.levels as i32;
+        // Allocating storage for an empty texture or for a texture without levels is an error; the
+        // texture object remains without storage, and commands that access its image data skip it.
+        if data.levels > 0 && data.width > 0 && data.height > 0 {
+            gl.tex_storage_2d(
+                Gl::TEXTURE_2D,
+                data.levels as i32,
+                F::ID,
+                data.width as i32,
+                data.height as i32,
+            );
+        }
 
-        gl.tex_storage_2d(
+        gl.tex_parameteri(
             Gl::TEXTURE_2D,
-            levels,
-            F::ID,
-            data.width as i32,
-            data.height as i32,
+            Gl::TEXTURE_MAX_LEVEL,
+            data.levels.saturating_sub(1) as i32,
         );
 
-        gl.tex_parameteri(Gl::TEXTURE_2D, Gl::TEXTURE_MAX_LEVEL, levels);
+        set_unsampled_parameters(gl, Gl::TEXTURE_2D);
 
         unsafe {
             *data.id.get() = Some(JsId::from_value(texture_object.into()));
@@ -1580,12 +2486,12 @@ pub struct UploadCommand<D, T, F> {
     texture_data: Arc<Texture2DData>,
     level: usize,
     region: Region2D,
+    options: UploadOptions,
     _marker: marker::PhantomData<[F]>,
 }
 
 unsafe impl<D, T, F> GpuTask<Connection> for UploadCommand<D, T, F>
 where
-    D: Borrow<[T]>,
     T: PixelUnpack<F>,
     F: TextureFormat,
 {
@@ -1596,11 +2502,23 @@ where
     }
 
     fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
-        let mut width = region_2d_overlap_width(self.texture_data.width, self.level, &self.region);
-        let height = region_2d_overlap_height(self.texture_data.height, self.level, &self.region);
+        self.upload(connection, &self.region, &self.options);
+
+        Progress::Finished(())
+    }
+}
+
+impl<D, T, F> UploadCommand<D, T, F>
+where
+    T: PixelUnpack<F>,
+    F: TextureFormat,
+{
+    fn upload(&self, connection: &mut Connection, region: &Region2D, options: &UploadOptions) {
+        let mut width = region_2d_overlap_width(self.texture_data.width, self.level, region);
+        let mut height = region_2d_overlap_height(self.texture_data.height, self.level, region);
 
         if width == 0 || height == 0 {
-            return Progress::Finished(());
+            return;
         }
 
         let (gl, state) = unsafe { connection.unpack_mut() };
@@ -1608,10 +2526,21 @@ where
         match &self.data.internal {
             Image2DSourceInternal::PixelData {
                 data,
+                as_js_buffer,
                 row_length,
+                image_height,
                 alignment,
-                ..
             } => {
+                let skip_pixels = options.skip_pixels.min(*row_length);
+                let skip_rows = options.skip_rows.min(*image_height);
+
+                width = width.min(*row_length - skip_pixels);
+                height = height.min(*image_height - skip_rows);
+
+                if width == 0 || height == 0 {
+                    return;
+                }
+
                 state.set_active_texture_lru().apply(gl).unwrap();
 
                 unsafe {
@@ -1637,18 +2566,35 @@ where
                         .apply(gl)
                         .unwrap();
                 } else {
-                    width = *row_length;
-
                     state.set_pixel_unpack_row_length(0).apply(gl).unwrap();
                 }
 
-                let (offset_x, offset_y) = match self.region {
+                let (offset_x, offset_y) = match *region {
                     Region2D::Fill => (0, 0),
                     Region2D::Area((offset_x, offset_y), ..) => (offset_x, offset_y),
                 };
 
-                let elements = *row_length as usize * height as usize;
-                let data_buffer = texture_data_as_js_buffer(data.borrow(), elements);
+                let len_in_bytes =
+                    row_stride_in_bytes(*row_length, mem::size_of::<T>(), *alignment)
+                        * (skip_rows + height) as usize;
+                let data_buffer = as_js_buffer(data, T::TYPE_ID, len_in_bytes);
+
+                state
+                    .set_pixel_unpack_flip_y(options.flip_y)
+                    .apply(gl)
+                    .unwrap();
+                state
+                    .set_pixel_unpack_premultiply_alpha(options.premultiply_alpha)
+                    .apply(gl)
+                    .unwrap();
+                state
+                    .set_pixel_unpack_skip_pixels(skip_pixels as i32)
+                    .apply(gl)
+                    .unwrap();
+                state
+                    .set_pixel_unpack_skip_rows(skip_rows as i32)
+                    .apply(gl)
+                    .unwrap();
 
                 gl.tex_sub_image_2d_with_i32_and_i32_and_u32_and_type_and_opt_array_buffer_view(
                     Gl::TEXTURE_2D,
@@ -1662,8 +2608,52 @@ where
                     Some(&data_buffer),
                 )
                 .unwrap();
+
+                // Reset the flags, so they don't affect other uploads.
+                state.set_pixel_unpack_flip_y(false).apply(gl).unwrap();
+                state
+                    .set_pixel_unpack_premultiply_alpha(false)
+                    .apply(gl)
+                    .unwrap();
+                state.set_pixel_unpack_skip_pixels(0).apply(gl).unwrap();
+                state.set_pixel_unpack_skip_rows(0).apply(gl).unwrap();
             }
         }
+    }
+}
+
+/// Uploads a band of rows of the data of an [UploadCommand] to a [Level].
+///
+/// See [Level::upload_chunked_command] for details.
+pub struct UploadChunkCommand<D, T, F> {
+    command: Rc<UploadCommand<D, T, F>>,
+    region: Region2D,
+    options: UploadOptions,
+}
+
+impl<D, T, F> Clone for UploadChunkCommand<D, T, F> {
+    fn clone(&self) -> Self {
+        UploadChunkCommand {
+            command: self.command.clone(),
+            region: self.region,
+            options: self.options,
+        }
+    }
+}
+
+unsafe impl<D, T, F> GpuTask<Connection> for UploadChunkCommand<D, T, F>
+where
+    T: PixelUnpack<F>,
+    F: TextureFormat,
+{
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        self.command.context_id()
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        self.command.upload(connection, &self.region, &self.options);
 
         Progress::Finished(())
     }
@@ -1678,6 +2668,7 @@ pub struct PackToBufferCommand<F, P> {
     offset: usize,
     level: usize,
     region: Region2D,
+    options: PackOptions,
     _marker: marker::PhantomData<(Box<[F]>, Box<[P]>)>,
 }
 
@@ -1734,6 +2725,15 @@ where
                 })
         }
 
+        state
+            .set_pixel_pack_alignment(self.options.alignment.into())
+            .apply(gl)
+            .unwrap();
+        state
+            .set_pixel_pack_row_length(self.options.row_length as i32)
+            .apply(gl)
+            .unwrap();
+
         gl.read_pixels_with_i32(
             offset_x as i32,
             offset_y as i32,
@@ -1745,10 +2745,212 @@ where
         )
         .unwrap();
 
+        // Restore the defaults, so they don't affect other pack operations.
+        state.set_pixel_pack_alignment(4).apply(gl).unwrap();
+        state.set_pixel_pack_row_length(0).apply(gl).unwrap();
+
+        Progress::Finished(())
+    }
+}
+
+/// Clears the image of a [Level] to a value.
+///
+/// See [Level::clear_command] for details.
+pub struct ClearCommand<F, V> {
+    texture_data: Arc<Texture2DData>,
+    level: usize,
+    value: V,
+    _marker: marker::PhantomData<Box<[F]>>,
+}
+
+unsafe impl<F, V> GpuTask<Connection> for ClearCommand<F, V>
+where
+    F: TextureFormat,
+    V: ClearValue<F>,
+{
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.texture_data.context_id)
+    }
+
+    fn progress(&mut self, context: &mut Connection) -> Progress<Self::Output> {
+        if self.texture_data.width == 0 || self.texture_data.height == 0 {
+            return Progress::Finished(());
+        }
+
+        let (gl, state) = unsafe { context.unpack_mut() };
+
+        state.bind_clear_framebuffer(gl);
+
+        unsafe {
+            self.texture_data
+                .id()
+                .unwrap()
+                .with_value_unchecked(|texture_object| {
+                    gl.framebuffer_texture_2d(
+                        Gl::DRAW_FRAMEBUFFER,
+                        V::ATTACHMENT,
+                        Gl::TEXTURE_2D,
+                        Some(&texture_object),
+                        self.level as i32,
+                    );
+                });
+        }
+
+        state.set_scissor_test_enabled(false).apply(gl).unwrap();
+        state.set_color_mask([true; 4]).apply(gl).unwrap();
+        state.set_depth_mask(true).apply(gl).unwrap();
+        state
+            .set_stencil_write_mask_front(0xffffffff)
+            .apply(gl)
+            .unwrap();
+        state
+            .set_stencil_write_mask_back(0xffffffff)
+            .apply(gl)
+            .unwrap();
+
+        self.value.clear_buffer(gl);
+
+        // Detach the image again, so that it cannot affect the completeness of the framebuffer when
+        // it is used to clear an image of a different size or at a different attachment point.
+        gl.framebuffer_texture_2d(Gl::DRAW_FRAMEBUFFER, V::ATTACHMENT, Gl::TEXTURE_2D, None, 0);
+
         Progress::Finished(())
     }
 }
 
+/// Returned from [Texture2D::set_level_range_command], restricts the mipmap levels of a [Texture2D]
+/// that may be accessed by samplers.
+///
+/// See [Texture2D::set_level_range_command] for details.
+pub struct SetLevelRangeCommand {
+    texture_data: Arc<Texture2DData>,
+    base_level: usize,
+    max_level: usize,
+}
+
+unsafe impl GpuTask<Connection> for SetLevelRangeCommand {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.texture_data.context_id)
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        let (gl, state) = unsafe { connection.unpack_mut() };
+
+        unsafe {
+            self.texture_data
+                .id()
+                .unwrap()
+                .with_value_unchecked(|texture_object| {
+                    state.set_active_texture_lru().apply(gl).unwrap();
+                    state
+                        .bind_texture_2d(Some(texture_object))
+                        .apply(gl)
+                        .unwrap();
+                });
+        }
+
+        self.texture_data
+            .level_range
+            .set((self.base_level as u8, self.max_level as u8));
+        self.texture_data.apply_level_range(gl, state, None);
+
+        Progress::Finished(())
+    }
+}
+
+/// Returned from [Texture2D::generate_mipmap_custom_command], generates the image data for a
+/// [Texture2D]'s mipmap chain with a series of custom downsample passes.
+///
+/// See [Texture2D::generate_mipmap_custom_command] for details.
+pub struct GenerateMipmapCustomCommand<F, B, T> {
+    texture: Texture2D<F>,
+    f: B,
+    target_level: usize,
+    pass: Option<T>,
+    initial_level_range: Option<(u8, u8)>,
+}
+
+unsafe impl<F, B, T> GpuTask<Connection> for GenerateMipmapCustomCommand<F, B, T>
+where
+    F: TextureFormat + 'static,
+    B: FnMut(&Level<F>, LevelMut<F>) -> T,
+    T: GpuTask<Connection, Output = ()>,
+{
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.texture.data.context_id)
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        let texture_data = &self.texture.data;
+        let initial_level_range = *self
+            .initial_level_range
+            .get_or_insert_with(|| texture_data.level_range.get());
+
+        while self.target_level < texture_data.levels {
+            let source = self.target_level - 1;
+
+            let mut pass = match self.pass.take() {
+                Some(pass) => pass,
+                None => {
+                    SetLevelRangeCommand {
+                        texture_data: texture_data.clone(),
+                        base_level: source,
+                        max_level: source,
+                    }
+                    .progress(connection);
+
+                    let handle = &self.texture;
+                    let source_level = Level {
+                        handle,
+                        level: source,
+                    };
+
+                    // The source and the target are distinct levels, so handing out a mutable
+                    // reference to the target level does not alias the source level.
+                    let target_level = LevelMut {
+                        inner: Level {
+                            handle,
+                            level: self.target_level,
+                        },
+                    };
+
+                    let pass = (self.f)(&source_level, target_level);
+
+                    ContextId::Id(texture_data.context_id)
+                        .combine(pass.context_id())
+                        .unwrap();
+
+                    pass
+                }
+            };
+
+            match pass.progress(connection) {
+                Progress::Finished(()) => self.target_level += 1,
+                Progress::ContinueFenced => {
+                    self.pass = Some(pass);
+
+                    return Progress::ContinueFenced;
+                }
+            }
+        }
+
+        let (base_level, max_level) = initial_level_range;
+
+        SetLevelRangeCommand {
+            texture_data: texture_data.clone(),
+            base_level: base_level as usize,
+            max_level: max_level as usize,
+        }
+        .progress(connection)
+    }
+}
+
 /// Returned from [Texture2D::generate_mipmap_command], generates the image data for a [Texture2D]'s
 /// mipmap chain.
 ///
@@ -1772,10 +2974,18 @@ unsafe impl GpuTask<Connection> for GenerateMipmapCommand {
                 .id()
                 .unwrap()
                 .with_value_unchecked(|texture_object| {
-                    state.bind_texture_2d(Some(texture_object));
+                    state.set_active_texture_lru().apply(gl).unwrap();
+                    state
+                        .bind_texture_2d(Some(texture_object))
+                        .apply(gl)
+                        .unwrap();
                 });
         }
 
+        // The texture may still have the level range of a resource it was last bound as, but the
+        // mipmap chain is generated for the texture's own level range.
+        self.texture_data.apply_level_range(gl, state, None);
+
         gl.generate_mipmap(Gl::TEXTURE_2D);
 
         Progress::Finished(())
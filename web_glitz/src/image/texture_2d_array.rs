@@ -1,36 +1,43 @@
 use std::borrow::Borrow;
 use std::cell::UnsafeCell;
 use std::cmp;
+use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::marker;
+use std::mem;
 use std::ops::{Deref, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 use std::sync::Arc;
 
 use web_sys::WebGl2RenderingContext as Gl;
 
+use crate::extensions::{color_buffer_float, texture_float_linear};
 use crate::image::format::{
-    Filterable, FloatSamplable, IntegerSamplable, PixelUnpack, ShadowSamplable, TextureFormat,
-    UnsignedIntegerSamplable,
+    Filterable, FloatRenderable, FloatSamplable, IntegerSamplable, PixelUnpack, ShadowSamplable,
+    TextureFormat, UnsignedIntegerSamplable,
+};
+use crate::image::image_source::{
+    row_stride_in_bytes, Image2DSourceInternal, LayeredImageSourceInternal,
 };
-use crate::image::image_source::{Image2DSourceInternal, LayeredImageSourceInternal};
 use crate::image::sampler::{CompatibleSampler, SamplerData, ShadowSampler};
 use crate::image::texture_object_dropper::TextureObjectDropper;
 use crate::image::util::{
-    max_mipmap_levels, mipmap_size, region_2d_overlap_height, region_2d_overlap_width,
-    region_2d_sub_image, region_3d_overlap_depth, region_3d_overlap_height,
-    region_3d_overlap_width, region_3d_sub_image, texture_data_as_js_buffer,
+    mipmap_size, region_2d_overlap_height, region_2d_overlap_width, region_2d_sub_image,
+    region_3d_overlap_depth, region_3d_overlap_height, region_3d_overlap_width,
+    region_3d_sub_image, set_unsampled_parameters, texture_data_as_js_buffer,
 };
 use crate::image::{
-    Image2DSource, LayeredImageSource, MaxMipmapLevelsExceeded, MipmapLevels, Region2D, Region3D,
+    complete_mipmap_levels, Image2DSource, LayeredImageSource, MaxMipmapLevelsExceeded,
+    MipmapLevels, Region2D, Region3D,
 };
 use crate::runtime::state::ContextUpdate;
 use crate::runtime::{Connection, RenderingContext};
 use crate::task::{ContextId, GpuTask, Progress};
-use crate::util::JsId;
+use crate::util::{DebugLabel, JsId};
 
 /// Provides the information necessary for the creation of a [Texture2DArray].
 ///
 /// See [RenderingContext::create_texture_2d_array] for details.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct Texture2DArrayDescriptor<F>
 where
     F: TextureFormat + 'static,
@@ -109,6 +116,49 @@ where
 /// [Texture2DArray] may be bound to a pipeline as a resource, see
 /// [web_glitz::pipeline::resources::Resources].
 ///
+/// # Texture2DArray and Texture2D
+///
+/// WebGL 2.0 does not support texture views: a single layer of a [Texture2DArray] cannot be bound
+/// as a [Texture2D] (e.g. to a GLSL `sampler2D`), and the storage of a [Texture2D] cannot be bound
+/// as a single-layer [Texture2DArray]. Reinterpreting the storage of one as the other therefore
+/// always requires a copy. Copying the image data is a GPU-side operation that may be performed
+/// with a blit, by attaching the destination to a render target and using the source image as the
+/// blit source. For example, to copy layer `3` of the base level of a [Texture2DArray] into the
+/// base level of a [Texture2D] with the same format and size:
+///
+/// ```rust
+/// # use web_glitz::runtime::RenderingContext;
+/// # use web_glitz::image::format::RGBA8;
+/// # use web_glitz::image::texture_2d::Texture2D;
+/// # use web_glitz::image::texture_2d_array::Texture2DArray;
+/// # fn wrapper<Rc>(
+/// #     context: &Rc,
+/// #     texture_array: Texture2DArray<RGBA8>,
+/// #     mut texture: Texture2D<RGBA8>
+/// # ) where Rc: RenderingContext {
+/// use web_glitz::image::Region2D;
+/// use web_glitz::rendering::{LoadOp, RenderTargetDescriptor, StoreOp};
+///
+/// let mut target = context.create_render_target(
+///     RenderTargetDescriptor::new().attach_color_float(
+///         texture.base_level_mut(),
+///         LoadOp::Load,
+///         StoreOp::Store,
+///     ),
+/// );
+///
+/// let base_level = texture_array.base_level();
+/// let layer = base_level.layers().get(3).unwrap();
+///
+/// context.submit(target.create_render_pass(|framebuffer| {
+///     framebuffer.blit_color_nearest_command(Region2D::Fill, &layer)
+/// }));
+/// # }
+/// ```
+///
+/// Note that no copy is required to render to a single layer: a layer may be attached to a render
+/// target directly (see [LevelLayersMut::get_mut]). See [Texture2D] for the reverse copy.
+///
 /// # Example
 ///
 /// The following example creates a 2d array texture with a width of 256 pixels, a height of 256 and
@@ -141,6 +191,8 @@ where
 /// ]);
 /// # }
 /// ```
+///
+/// [Texture2D]: crate::image::texture_2d::Texture2D
 pub struct Texture2DArray<F> {
     object_id: u64,
     data: Arc<Texture2DArrayData>,
@@ -151,6 +203,33 @@ impl<F> Texture2DArray<F> {
     pub(crate) fn data(&self) -> &Arc<Texture2DArrayData> {
         &self.data
     }
+
+    /// Assigns a debug `label` to this [Texture2DArray].
+    ///
+    /// The label is included in the [Debug](std::fmt::Debug) output for the texture and in the
+    /// messages of errors that concern the texture.
+    pub fn set_label(&self, label: &str) {
+        self.data.label.set(label);
+    }
+
+    /// Returns the debug label that was assigned to this [Texture2DArray] with [set_label], or `None` if
+    /// no label was assigned.
+    pub fn label(&self) -> Option<String> {
+        self.data.label.get()
+    }
+}
+
+impl<F> fmt::Debug for Texture2DArray<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Texture2DArray")
+            .field("object_id", &self.object_id)
+            .field("label", &self.data.label)
+            .field("width", &self.data.width)
+            .field("height", &self.data.height)
+            .field("depth", &self.data.depth)
+            .field("levels", &self.data.levels)
+            .finish()
+    }
 }
 
 impl<F> Texture2DArray<F>
@@ -173,7 +252,7 @@ where
             levels,
             ..
         } = descriptor;
-        let max_mipmap_levels = max_mipmap_levels(*width, *height);
+        let max_mipmap_levels = complete_mipmap_levels(*width, *height);
 
         let levels = match levels {
             MipmapLevels::Complete => max_mipmap_levels,
@@ -192,11 +271,12 @@ where
         let data = Arc::new(Texture2DArrayData {
             id: UnsafeCell::new(None),
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
+            dropper: Box::new(context.deletion_queue()),
             width: *width,
             height: *height,
             depth: *depth,
             levels,
+            label: DebugLabel::default(),
         });
 
         context.submit(AllocateCommand::<F> {
@@ -326,7 +406,7 @@ where
 
 impl<F> Texture2DArray<F>
 where
-    F: TextureFormat + Filterable + 'static,
+    F: TextureFormat + Filterable + FloatRenderable + 'static,
 {
     /// Returns a command which, when executed, will generate new mipmap data for the
     /// [Texture2DArray].
@@ -339,7 +419,17 @@ where
     /// been overwritten. Note that the base level (level 0) is not modified (rather, it serves as
     /// the input for this process).
     ///
-    /// This operation is only available to a texture if the texture format implements [Filterable].
+    /// This operation is only available to a texture if the texture format implements both
+    /// [Filterable] and [FloatRenderable]. See [generate_mipmap_command_with_color_buffer_float] and
+    /// [generate_mipmap_command_with_float_linear] for formats that only support mipmap generation
+    /// when certain extensions are available.
+    ///
+    /// The implementation may be asked to favor the quality of the generated mipmaps over the speed
+    /// of their generation with [ContextOptionsBuilder::mipmap_hint].
+    ///
+    /// [generate_mipmap_command_with_color_buffer_float]: Texture2DArray::generate_mipmap_command_with_color_buffer_float
+    /// [generate_mipmap_command_with_float_linear]: Texture2DArray::generate_mipmap_command_with_float_linear
+    /// [ContextOptionsBuilder::mipmap_hint]: crate::runtime::ContextOptionsBuilder::mipmap_hint
     pub fn generate_mipmap_command(&self) -> GenerateMipmapCommand {
         GenerateMipmapCommand {
             texture_data: self.data.clone(),
@@ -347,6 +437,73 @@ where
     }
 }
 
+impl<F> Texture2DArray<F>
+where
+    F: TextureFormat + Filterable + color_buffer_float::FloatRenderable + 'static,
+{
+    /// Returns a command which, when executed, will generate new mipmap data for the [Texture2DArray].
+    ///
+    /// Identical to [generate_mipmap_command], except that it is also available to textures that
+    /// use a (16-bit) floating point format that is only renderable when the
+    /// [color_buffer_float] extension is available (mipmap generation requires a format to be both
+    /// filterable and renderable).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `extension` belongs to a different context than this [Texture2DArray].
+    ///
+    /// [generate_mipmap_command]: Texture2DArray::generate_mipmap_command
+    pub fn generate_mipmap_command_with_color_buffer_float(
+        &self,
+        extension: &color_buffer_float::Extension,
+    ) -> GenerateMipmapCommand {
+        if extension.context_id() != self.data.context_id {
+            panic!("Extension belongs to a different context than this texture.");
+        }
+
+        GenerateMipmapCommand {
+            texture_data: self.data.clone(),
+        }
+    }
+}
+
+impl<F> Texture2DArray<F>
+where
+    F: TextureFormat
+        + texture_float_linear::Filterable
+        + color_buffer_float::FloatRenderable
+        + 'static,
+{
+    /// Returns a command which, when executed, will generate new mipmap data for the [Texture2DArray].
+    ///
+    /// Identical to [generate_mipmap_command], except that it is also available to textures that
+    /// use a 32-bit floating point format, which is only filterable when the
+    /// [texture_float_linear] extension is available and only renderable when the
+    /// [color_buffer_float] extension is available (mipmap generation requires a format to be both
+    /// filterable and renderable).
+    ///
+    /// # Panics
+    ///
+    /// Panics if either of the extensions belongs to a different context than this [Texture2DArray].
+    ///
+    /// [generate_mipmap_command]: Texture2DArray::generate_mipmap_command
+    pub fn generate_mipmap_command_with_float_linear(
+        &self,
+        color_buffer_float: &color_buffer_float::Extension,
+        texture_float_linear: &texture_float_linear::Extension,
+    ) -> GenerateMipmapCommand {
+        if color_buffer_float.context_id() != self.data.context_id
+            || texture_float_linear.context_id() != self.data.context_id
+        {
+            panic!("Extension belongs to a different context than this texture.");
+        }
+
+        GenerateMipmapCommand {
+            texture_data: self.data.clone(),
+        }
+    }
+}
+
 impl<F> Texture2DArray<F>
 where
     F: TextureFormat + FloatSamplable + 'static,
@@ -373,7 +530,21 @@ where
         }
 
         FloatSampledTexture2DArray {
-            sampler_data: sampler.data().clone(),
+            sampler_data: Some(sampler.data().clone()),
+            texture_data: self.data().clone(),
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns a [FloatSampledTexture2DArray] without a sampler object, for a `sampler2DArray` that
+    /// is only read with `texelFetch`.
+    ///
+    /// See [unsampled textures] for details.
+    ///
+    /// [unsampled textures]: crate::image#unsampled-textures
+    pub fn float_unsampled(&self) -> FloatSampledTexture2DArray {
+        FloatSampledTexture2DArray {
+            sampler_data: None,
             texture_data: self.data().clone(),
             _marker: marker::PhantomData,
         }
@@ -384,7 +555,7 @@ where
 /// point sampler.
 #[derive(Clone)]
 pub struct FloatSampledTexture2DArray<'a> {
-    pub(crate) sampler_data: Arc<SamplerData>,
+    pub(crate) sampler_data: Option<Arc<SamplerData>>,
     pub(crate) texture_data: Arc<Texture2DArrayData>,
     _marker: marker::PhantomData<&'a ()>,
 }
@@ -415,7 +586,21 @@ where
         }
 
         IntegerSampledTexture2DArray {
-            sampler_data: sampler.data().clone(),
+            sampler_data: Some(sampler.data().clone()),
+            texture_data: self.data().clone(),
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns an [IntegerSampledTexture2DArray] without a sampler object, for an `isampler2DArray`
+    /// that is only read with `texelFetch`.
+    ///
+    /// See [unsampled textures] for details.
+    ///
+    /// [unsampled textures]: crate::image#unsampled-textures
+    pub fn integer_unsampled(&self) -> IntegerSampledTexture2DArray {
+        IntegerSampledTexture2DArray {
+            sampler_data: None,
             texture_data: self.data().clone(),
             _marker: marker::PhantomData,
         }
@@ -426,7 +611,7 @@ where
 /// sampler.
 #[derive(Clone)]
 pub struct IntegerSampledTexture2DArray<'a> {
-    pub(crate) sampler_data: Arc<SamplerData>,
+    pub(crate) sampler_data: Option<Arc<SamplerData>>,
     pub(crate) texture_data: Arc<Texture2DArrayData>,
     _marker: marker::PhantomData<&'a ()>,
 }
@@ -458,7 +643,21 @@ where
         }
 
         UnsignedIntegerSampledTexture2DArray {
-            sampler_data: sampler.data().clone(),
+            sampler_data: Some(sampler.data().clone()),
+            texture_data: self.data().clone(),
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns an [UnsignedIntegerSampledTexture2DArray] without a sampler object, for a
+    /// `usampler2DArray` that is only read with `texelFetch`.
+    ///
+    /// See [unsampled textures] for details.
+    ///
+    /// [unsampled textures]: crate::image#unsampled-textures
+    pub fn unsigned_integer_unsampled(&self) -> UnsignedIntegerSampledTexture2DArray {
+        UnsignedIntegerSampledTexture2DArray {
+            sampler_data: None,
             texture_data: self.data().clone(),
             _marker: marker::PhantomData,
         }
@@ -469,7 +668,7 @@ where
 /// integer sampler.
 #[derive(Clone)]
 pub struct UnsignedIntegerSampledTexture2DArray<'a> {
-    pub(crate) sampler_data: Arc<SamplerData>,
+    pub(crate) sampler_data: Option<Arc<SamplerData>>,
     pub(crate) texture_data: Arc<Texture2DArrayData>,
     _marker: marker::PhantomData<&'a ()>,
 }
@@ -493,7 +692,7 @@ where
         }
 
         ShadowSampledTexture2DArray {
-            sampler_data: shadow_sampler.data().clone(),
+            sampler_data: Some(shadow_sampler.data().clone()),
             texture_data: self.data().clone(),
             _marker: marker::PhantomData,
         }
@@ -504,7 +703,7 @@ where
 /// sampler.
 #[derive(Clone)]
 pub struct ShadowSampledTexture2DArray<'a> {
-    pub(crate) sampler_data: Arc<SamplerData>,
+    pub(crate) sampler_data: Option<Arc<SamplerData>>,
     pub(crate) texture_data: Arc<Texture2DArrayData>,
     _marker: marker::PhantomData<&'a ()>,
 }
@@ -517,6 +716,7 @@ pub(crate) struct Texture2DArrayData {
     height: u32,
     depth: u32,
     levels: usize,
+    label: DebugLabel,
 }
 
 impl<F> PartialEq for Texture2DArray<F> {
@@ -539,6 +739,10 @@ impl Texture2DArrayData {
     pub(crate) fn context_id(&self) -> u64 {
         self.context_id
     }
+
+    pub(crate) fn label(&self) -> &DebugLabel {
+        &self.label
+    }
 }
 
 impl PartialEq for Texture2DArrayData {
@@ -2923,6 +3127,8 @@ where
 
         gl.tex_parameteri(Gl::TEXTURE_2D_ARRAY, Gl::TEXTURE_MAX_LEVEL, levels);
 
+        set_unsampled_parameters(gl, Gl::TEXTURE_2D_ARRAY);
+
         unsafe {
             *data.id.get() = Some(JsId::from_value(texture_object.into()));
         }
@@ -3058,7 +3264,6 @@ pub struct LevelLayerUploadCommand<D, T, F> {
 
 unsafe impl<D, T, F> GpuTask<Connection> for LevelLayerUploadCommand<D, T, F>
 where
-    D: Borrow<[T]>,
     T: PixelUnpack<F>,
     F: TextureFormat,
 {
@@ -3081,6 +3286,7 @@ where
         match &self.data.internal {
             Image2DSourceInternal::PixelData {
                 data,
+                as_js_buffer,
                 row_length,
                 alignment,
                 ..
@@ -3120,8 +3326,10 @@ where
                     Region2D::Area(offset, ..) => offset,
                 };
 
-                let elements = *row_length as usize * height as usize;
-                let data_buffer = texture_data_as_js_buffer(data.borrow(), elements);
+                let len_in_bytes =
+                    row_stride_in_bytes(*row_length, mem::size_of::<T>(), *alignment)
+                        * height as usize;
+                let data_buffer = as_js_buffer(data, T::TYPE_ID, len_in_bytes);
 
                 gl.tex_sub_image_3d_with_opt_array_buffer_view(
                     Gl::TEXTURE_2D_ARRAY,
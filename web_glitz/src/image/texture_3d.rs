@@ -1,36 +1,43 @@
 use std::borrow::Borrow;
 use std::cell::UnsafeCell;
 use std::cmp;
+use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::marker;
+use std::mem;
 use std::ops::{Deref, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 use std::sync::Arc;
 
 use web_sys::WebGl2RenderingContext as Gl;
 
+use crate::extensions::{color_buffer_float, texture_float_linear};
 use crate::image::format::{
-    Filterable, FloatSamplable, IntegerSamplable, PixelUnpack, TextureFormat,
+    Filterable, FloatRenderable, FloatSamplable, IntegerSamplable, PixelUnpack, TextureFormat,
     UnsignedIntegerSamplable,
 };
-use crate::image::image_source::{Image2DSourceInternal, LayeredImageSourceInternal};
+use crate::image::image_source::{
+    row_stride_in_bytes, Image2DSourceInternal, LayeredImageSourceInternal,
+};
 use crate::image::sampler::{CompatibleSampler, SamplerData};
 use crate::image::texture_object_dropper::TextureObjectDropper;
 use crate::image::util::{
-    max_mipmap_levels, mipmap_size, region_2d_overlap_height, region_2d_overlap_width,
-    region_2d_sub_image, region_3d_overlap_depth, region_3d_overlap_height,
-    region_3d_overlap_width, region_3d_sub_image, texture_data_as_js_buffer,
+    mipmap_size, region_2d_overlap_height, region_2d_overlap_width, region_2d_sub_image,
+    region_3d_overlap_depth, region_3d_overlap_height, region_3d_overlap_width,
+    region_3d_sub_image, set_unsampled_parameters, texture_data_as_js_buffer,
 };
 use crate::image::{
-    Image2DSource, LayeredImageSource, MaxMipmapLevelsExceeded, MipmapLevels, Region2D, Region3D,
+    complete_mipmap_levels_3d, Image2DSource, LayeredImageSource, MaxMipmapLevelsExceeded,
+    MipmapLevels, Region2D, Region3D,
 };
 use crate::runtime::state::ContextUpdate;
 use crate::runtime::{Connection, RenderingContext};
 use crate::task::{ContextId, GpuTask, Progress};
-use crate::util::JsId;
+use crate::util::{DebugLabel, JsId};
 
 /// Provides the information necessary for the creation of a [Texture3D].
 ///
 /// See [RenderingContext::create_texture_3d] for details.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct Texture3DDescriptor<F>
 where
     F: TextureFormat + 'static,
@@ -134,6 +141,33 @@ impl<F> Texture3D<F> {
     pub(crate) fn data(&self) -> &Arc<Texture3DData> {
         &self.data
     }
+
+    /// Assigns a debug `label` to this [Texture3D].
+    ///
+    /// The label is included in the [Debug](std::fmt::Debug) output for the texture and in the
+    /// messages of errors that concern the texture.
+    pub fn set_label(&self, label: &str) {
+        self.data.label.set(label);
+    }
+
+    /// Returns the debug label that was assigned to this [Texture3D] with [set_label], or `None` if
+    /// no label was assigned.
+    pub fn label(&self) -> Option<String> {
+        self.data.label.get()
+    }
+}
+
+impl<F> fmt::Debug for Texture3D<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Texture3D")
+            .field("object_id", &self.object_id)
+            .field("label", &self.data.label)
+            .field("width", &self.data.width)
+            .field("height", &self.data.height)
+            .field("depth", &self.data.depth)
+            .field("levels", &self.data.levels)
+            .finish()
+    }
 }
 
 impl<F> Texture3D<F>
@@ -156,7 +190,7 @@ where
             levels,
             ..
         } = descriptor;
-        let max_mipmap_levels = max_mipmap_levels(*width, *height);
+        let max_mipmap_levels = complete_mipmap_levels_3d(*width, *height, *depth);
 
         let levels = match levels {
             MipmapLevels::Complete => max_mipmap_levels,
@@ -175,11 +209,12 @@ where
         let data = Arc::new(Texture3DData {
             id: UnsafeCell::new(None),
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
+            dropper: Box::new(context.deletion_queue()),
             width: *width,
             height: *height,
             depth: *depth,
             levels,
+            label: DebugLabel::default(),
         });
 
         context.submit(AllocateCommand::<F> {
@@ -309,7 +344,7 @@ where
 
 impl<F> Texture3D<F>
 where
-    F: TextureFormat + Filterable + 'static,
+    F: TextureFormat + Filterable + FloatRenderable + 'static,
 {
     /// Returns a command which, when executed, will generate new mipmap data for the [Texture3D].
     ///
@@ -321,7 +356,17 @@ where
     /// been overwritten. Note that the base level (level 0) is not modified (rather, it serves as
     /// the input for this process).
     ///
-    /// This operation is only available to a texture if the texture format implements [Filterable].
+    /// This operation is only available to a texture if the texture format implements both
+    /// [Filterable] and [FloatRenderable]. See [generate_mipmap_command_with_color_buffer_float] and
+    /// [generate_mipmap_command_with_float_linear] for formats that only support mipmap generation
+    /// when certain extensions are available.
+    ///
+    /// The implementation may be asked to favor the quality of the generated mipmaps over the speed
+    /// of their generation with [ContextOptionsBuilder::mipmap_hint].
+    ///
+    /// [generate_mipmap_command_with_color_buffer_float]: Texture3D::generate_mipmap_command_with_color_buffer_float
+    /// [generate_mipmap_command_with_float_linear]: Texture3D::generate_mipmap_command_with_float_linear
+    /// [ContextOptionsBuilder::mipmap_hint]: crate::runtime::ContextOptionsBuilder::mipmap_hint
     pub fn generate_mipmap_command(&self) -> GenerateMipmapCommand {
         GenerateMipmapCommand {
             texture_data: self.data.clone(),
@@ -329,6 +374,73 @@ where
     }
 }
 
+impl<F> Texture3D<F>
+where
+    F: TextureFormat + Filterable + color_buffer_float::FloatRenderable + 'static,
+{
+    /// Returns a command which, when executed, will generate new mipmap data for the [Texture3D].
+    ///
+    /// Identical to [generate_mipmap_command], except that it is also available to textures that
+    /// use a (16-bit) floating point format that is only renderable when the
+    /// [color_buffer_float] extension is available (mipmap generation requires a format to be both
+    /// filterable and renderable).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `extension` belongs to a different context than this [Texture3D].
+    ///
+    /// [generate_mipmap_command]: Texture3D::generate_mipmap_command
+    pub fn generate_mipmap_command_with_color_buffer_float(
+        &self,
+        extension: &color_buffer_float::Extension,
+    ) -> GenerateMipmapCommand {
+        if extension.context_id() != self.data.context_id {
+            panic!("Extension belongs to a different context than this texture.");
+        }
+
+        GenerateMipmapCommand {
+            texture_data: self.data.clone(),
+        }
+    }
+}
+
+impl<F> Texture3D<F>
+where
+    F: TextureFormat
+        + texture_float_linear::Filterable
+        + color_buffer_float::FloatRenderable
+        + 'static,
+{
+    /// Returns a command which, when executed, will generate new mipmap data for the [Texture3D].
+    ///
+    /// Identical to [generate_mipmap_command], except that it is also available to textures that
+    /// use a 32-bit floating point format, which is only filterable when the
+    /// [texture_float_linear] extension is available and only renderable when the
+    /// [color_buffer_float] extension is available (mipmap generation requires a format to be both
+    /// filterable and renderable).
+    ///
+    /// # Panics
+    ///
+    /// Panics if either of the extensions belongs to a different context than this [Texture3D].
+    ///
+    /// [generate_mipmap_command]: Texture3D::generate_mipmap_command
+    pub fn generate_mipmap_command_with_float_linear(
+        &self,
+        color_buffer_float: &color_buffer_float::Extension,
+        texture_float_linear: &texture_float_linear::Extension,
+    ) -> GenerateMipmapCommand {
+        if color_buffer_float.context_id() != self.data.context_id
+            || texture_float_linear.context_id() != self.data.context_id
+        {
+            panic!("Extension belongs to a different context than this texture.");
+        }
+
+        GenerateMipmapCommand {
+            texture_data: self.data.clone(),
+        }
+    }
+}
+
 impl<F> Texture3D<F>
 where
     F: TextureFormat + FloatSamplable + 'static,
@@ -355,7 +467,21 @@ where
         }
 
         FloatSampledTexture3D {
-            sampler_data: sampler.data().clone(),
+            sampler_data: Some(sampler.data().clone()),
+            texture_data: self.data().clone(),
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns a [FloatSampledTexture3D] without a sampler object, for a `sampler3D` that is only
+    /// read with `texelFetch`.
+    ///
+    /// See [unsampled textures] for details.
+    ///
+    /// [unsampled textures]: crate::image#unsampled-textures
+    pub fn float_unsampled(&self) -> FloatSampledTexture3D {
+        FloatSampledTexture3D {
+            sampler_data: None,
             texture_data: self.data().clone(),
             _marker: marker::PhantomData,
         }
@@ -366,7 +492,7 @@ where
 /// point sampler.
 #[derive(Clone)]
 pub struct FloatSampledTexture3D<'a> {
-    pub(crate) sampler_data: Arc<SamplerData>,
+    pub(crate) sampler_data: Option<Arc<SamplerData>>,
     pub(crate) texture_data: Arc<Texture3DData>,
     _marker: marker::PhantomData<&'a ()>,
 }
@@ -397,7 +523,21 @@ where
         }
 
         IntegerSampledTexture3D {
-            sampler_data: sampler.data().clone(),
+            sampler_data: Some(sampler.data().clone()),
+            texture_data: self.data().clone(),
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns an [IntegerSampledTexture3D] without a sampler object, for an `isampler3D` that is
+    /// only read with `texelFetch`.
+    ///
+    /// See [unsampled textures] for details.
+    ///
+    /// [unsampled textures]: crate::image#unsampled-textures
+    pub fn integer_unsampled(&self) -> IntegerSampledTexture3D {
+        IntegerSampledTexture3D {
+            sampler_data: None,
             texture_data: self.data().clone(),
             _marker: marker::PhantomData,
         }
@@ -408,7 +548,7 @@ where
 /// sampler.
 #[derive(Clone)]
 pub struct IntegerSampledTexture3D<'a> {
-    pub(crate) sampler_data: Arc<SamplerData>,
+    pub(crate) sampler_data: Option<Arc<SamplerData>>,
     pub(crate) texture_data: Arc<Texture3DData>,
     _marker: marker::PhantomData<&'a ()>,
 }
@@ -439,7 +579,21 @@ where
         }
 
         UnsignedIntegerSampledTexture3D {
-            sampler_data: sampler.data().clone(),
+            sampler_data: Some(sampler.data().clone()),
+            texture_data: self.data().clone(),
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Returns an [UnsignedIntegerSampledTexture3D] without a sampler object, for a `usampler3D`
+    /// that is only read with `texelFetch`.
+    ///
+    /// See [unsampled textures] for details.
+    ///
+    /// [unsampled textures]: crate::image#unsampled-textures
+    pub fn unsigned_integer_unsampled(&self) -> UnsignedIntegerSampledTexture3D {
+        UnsignedIntegerSampledTexture3D {
+            sampler_data: None,
             texture_data: self.data().clone(),
             _marker: marker::PhantomData,
         }
@@ -450,7 +604,7 @@ where
 /// integer sampler.
 #[derive(Clone)]
 pub struct UnsignedIntegerSampledTexture3D<'a> {
-    pub(crate) sampler_data: Arc<SamplerData>,
+    pub(crate) sampler_data: Option<Arc<SamplerData>>,
     pub(crate) texture_data: Arc<Texture3DData>,
     _marker: marker::PhantomData<&'a ()>,
 }
@@ -475,6 +629,7 @@ pub(crate) struct Texture3DData {
     height: u32,
     depth: u32,
     levels: usize,
+    label: DebugLabel,
 }
 
 impl Texture3DData {
@@ -485,6 +640,10 @@ impl Texture3DData {
     pub(crate) fn context_id(&self) -> u64 {
         self.context_id
     }
+
+    pub(crate) fn label(&self) -> &DebugLabel {
+        &self.label
+    }
 }
 
 impl PartialEq for Texture3DData {
@@ -2868,6 +3027,8 @@ where
 
         gl.tex_parameteri(Gl::TEXTURE_3D, Gl::TEXTURE_MAX_LEVEL, levels);
 
+        set_unsampled_parameters(gl, Gl::TEXTURE_3D);
+
         unsafe {
             *data.id.get() = Some(JsId::from_value(texture_object.into()));
         }
@@ -3003,7 +3164,6 @@ pub struct LevelLayerUploadCommand<D, T, F> {
 
 unsafe impl<D, T, F> GpuTask<Connection> for LevelLayerUploadCommand<D, T, F>
 where
-    D: Borrow<[T]>,
     T: PixelUnpack<F>,
     F: TextureFormat,
 {
@@ -3026,6 +3186,7 @@ where
         match &self.data.internal {
             Image2DSourceInternal::PixelData {
                 data,
+                as_js_buffer,
                 row_length,
                 alignment,
                 ..
@@ -3065,8 +3226,10 @@ where
                     Region2D::Area(offset, ..) => offset,
                 };
 
-                let elements = *row_length as usize * height as usize;
-                let data_buffer = texture_data_as_js_buffer(data.borrow(), elements);
+                let len_in_bytes =
+                    row_stride_in_bytes(*row_length, mem::size_of::<T>(), *alignment)
+                        * height as usize;
+                let data_buffer = as_js_buffer(data, T::TYPE_ID, len_in_bytes);
 
                 gl.tex_sub_image_3d_with_opt_array_buffer_view(
                     Gl::TEXTURE_3D,
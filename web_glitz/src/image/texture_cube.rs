@@ -1,33 +1,41 @@
 use std::borrow::Borrow;
 use std::cell::UnsafeCell;
+use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::marker;
+use std::mem;
 
 use std::ops::{Deref, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
 use std::sync::Arc;
 
 use web_sys::WebGl2RenderingContext as Gl;
 
+use crate::extensions::{color_buffer_float, texture_float_linear};
 use crate::image::format::{
-    Filterable, FloatSamplable, IntegerSamplable, PixelUnpack, ShadowSamplable, TextureFormat,
-    UnsignedIntegerSamplable,
+    Filterable, FloatRenderable, FloatSamplable, IntegerSamplable, PixelUnpack, ShadowSamplable,
+    TextureFormat, UnsignedIntegerSamplable,
 };
-use crate::image::image_source::Image2DSourceInternal;
+use crate::image::image_source::{row_stride_in_bytes, Image2DSourceInternal};
 use crate::image::sampler::{CompatibleSampler, Sampler, SamplerData, ShadowSampler};
 use crate::image::texture_object_dropper::TextureObjectDropper;
 use crate::image::util::{
-    max_mipmap_levels, mipmap_size, region_2d_overlap_height, region_2d_overlap_width,
-    region_2d_sub_image, texture_data_as_js_buffer,
+    mipmap_size, region_2d_overlap_height, region_2d_overlap_width, region_2d_sub_image,
+};
+use crate::image::{
+    complete_mipmap_levels, Image2DSource, MaxMipmapLevelsExceeded, MipmapLevels, Region2D,
+    UploadOptions,
 };
-use crate::image::{Image2DSource, MaxMipmapLevelsExceeded, MipmapLevels, Region2D};
 use crate::runtime::state::ContextUpdate;
 use crate::runtime::{Connection, RenderingContext};
-use crate::task::{ContextId, GpuTask, Progress};
-use crate::util::JsId;
+use crate::task::{
+    join_iter, sequence_right, ContextId, GpuTask, JoinIter, Progress, SequenceRight,
+};
+use crate::util::{DebugLabel, JsId};
 
 /// Provides the information necessary for the creation of a [TextureCube].
 ///
 /// See [RenderingContext::create_texture_cube] for details.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub struct TextureCubeDescriptor<F>
 where
     F: TextureFormat + 'static,
@@ -95,6 +103,9 @@ where
 /// [TextureCube] may be bound to a pipeline as a resource, see
 /// [web_glitz::pipeline::resources::Resources].
 ///
+/// Filtered lookups near the edge of a face blend in texels from the adjacent faces, so no seams
+/// are visible between faces. WebGL 2.0 always samples cube maps this way; this cannot be disabled.
+///
 /// # Example
 ///
 /// The following example creates a cube map texture with a width of 256 pixels and a height of 256
@@ -153,6 +164,32 @@ impl<F> TextureCube<F> {
     pub(crate) fn data(&self) -> &Arc<TextureCubeData> {
         &self.data
     }
+
+    /// Assigns a debug `label` to this [TextureCube].
+    ///
+    /// The label is included in the [Debug](std::fmt::Debug) output for the texture and in the
+    /// messages of errors that concern the texture.
+    pub fn set_label(&self, label: &str) {
+        self.data.label.set(label);
+    }
+
+    /// Returns the debug label that was assigned to this [TextureCube] with [set_label], or `None` if
+    /// no label was assigned.
+    pub fn label(&self) -> Option<String> {
+        self.data.label.get()
+    }
+}
+
+impl<F> fmt::Debug for TextureCube<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TextureCube")
+            .field("object_id", &self.object_id)
+            .field("label", &self.data.label)
+            .field("width", &self.data.width)
+            .field("height", &self.data.height)
+            .field("levels", &self.data.levels)
+            .finish()
+    }
 }
 
 impl<F> TextureCube<F>
@@ -174,7 +211,7 @@ where
             levels,
             ..
         } = descriptor;
-        let max_mipmap_levels = max_mipmap_levels(*width, *height);
+        let max_mipmap_levels = complete_mipmap_levels(*width, *height);
 
         let levels = match levels {
             MipmapLevels::Complete => max_mipmap_levels,
@@ -193,10 +230,11 @@ where
         let data = Arc::new(TextureCubeData {
             id: UnsafeCell::new(None),
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
+            dropper: Box::new(context.deletion_queue()),
             width: *width,
             height: *height,
             levels,
+            label: DebugLabel::default(),
         });
 
         context.submit(AllocateCommand::<F> {
@@ -315,11 +353,89 @@ where
     pub fn height(&self) -> u32 {
         self.data.height
     }
+
+    /// Returns a command which, when executed, replaces the image data for all six faces of this
+    /// [TextureCube]'s base level with the image data provided in `sources`.
+    ///
+    /// The `sources` are given in the order positive x, negative x, positive y, negative y,
+    /// positive z, negative z. The six uploads are joined into a single task (see [join_iter]); no
+    /// guarantees are made about the order in which the faces are updated.
+    ///
+    /// See also [upload_all_faces_and_generate_mipmap_command], which also generates the data for
+    /// the remaining mipmap levels after the uploads have finished.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width and height of any of the `sources` do not match the width and height of
+    /// this [TextureCube].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::image::{Image2DSource, MipmapLevels};
+    /// use web_glitz::image::format::RGB8;
+    /// use web_glitz::image::texture_cube::TextureCubeDescriptor;
+    ///
+    /// let texture = context.try_create_texture_cube(&TextureCubeDescriptor {
+    ///     format: RGB8,
+    ///     width: 256,
+    ///     height: 256,
+    ///     levels: MipmapLevels::Partial(1)
+    /// }).unwrap();
+    ///
+    /// let face_data = |color: [u8; 3]| {
+    ///     Image2DSource::from_pixels(vec![color; 256 * 256], 256, 256).unwrap()
+    /// };
+    ///
+    /// context.submit(texture.upload_all_faces_command([
+    ///     face_data([255, 0, 0]),
+    ///     face_data([0, 255, 0]),
+    ///     face_data([0, 0, 255]),
+    ///     face_data([255, 255, 0]),
+    ///     face_data([255, 0, 255]),
+    ///     face_data([0, 255, 255]),
+    /// ]));
+    /// # }
+    /// ```
+    ///
+    /// [join_iter]: crate::task::join_iter
+    /// [upload_all_faces_and_generate_mipmap_command]: TextureCube::upload_all_faces_and_generate_mipmap_command
+    pub fn upload_all_faces_command<D, T>(
+        &self,
+        sources: [Image2DSource<D, T>; 6],
+    ) -> JoinIter<UploadCommand<D, T, F>, Connection>
+    where
+        T: PixelUnpack<F>,
+    {
+        for source in sources.iter() {
+            if source.dimensions() != (self.data.width, self.data.height) {
+                panic!(
+                    "Source dimensions {:?} do not match the texture's dimensions {:?}.",
+                    source.dimensions(),
+                    (self.data.width, self.data.height)
+                );
+            }
+        }
+
+        let [positive_x, negative_x, positive_y, negative_y, positive_z, negative_z] = sources;
+        let level = self.base_level();
+
+        join_iter(vec![
+            level.positive_x().upload_command(positive_x),
+            level.negative_x().upload_command(negative_x),
+            level.positive_y().upload_command(positive_y),
+            level.negative_y().upload_command(negative_y),
+            level.positive_z().upload_command(positive_z),
+            level.negative_z().upload_command(negative_z),
+        ])
+    }
 }
 
 impl<F> TextureCube<F>
 where
-    F: TextureFormat + Filterable + 'static,
+    F: TextureFormat + Filterable + FloatRenderable + 'static,
 {
     /// Returns a command which, when executed, will generate new mipmap data for the [TextureCube].
     ///
@@ -331,12 +447,156 @@ where
     /// been overwritten. Note that the base level (level 0) is not modified (rather, it serves as
     /// the input for this process).
     ///
-    /// This operation is only available to a texture if the texture format implements [Filterable].
+    /// This operation is only available to a texture if the texture format implements both
+    /// [Filterable] and [FloatRenderable]. See [generate_mipmap_command_with_color_buffer_float] and
+    /// [generate_mipmap_command_with_float_linear] for formats that only support mipmap generation
+    /// when certain extensions are available.
+    ///
+    /// The implementation may be asked to favor the quality of the generated mipmaps over the speed
+    /// of their generation with [ContextOptionsBuilder::mipmap_hint].
+    ///
+    /// [generate_mipmap_command_with_color_buffer_float]: TextureCube::generate_mipmap_command_with_color_buffer_float
+    /// [generate_mipmap_command_with_float_linear]: TextureCube::generate_mipmap_command_with_float_linear
+    /// [ContextOptionsBuilder::mipmap_hint]: crate::runtime::ContextOptionsBuilder::mipmap_hint
     pub fn generate_mipmap_command(&self) -> GenerateMipmapCommand {
         GenerateMipmapCommand {
             texture_data: self.data.clone(),
         }
     }
+
+    /// Returns a command which, when executed, replaces the image data for all six faces of this
+    /// [TextureCube]'s base level with the image data provided in `sources`, and then generates
+    /// new mipmap data for the remaining levels.
+    ///
+    /// Equivalent to sequencing [upload_all_faces_command] with [generate_mipmap_command]: mipmap
+    /// generation does not begin until all six uploads have finished. For formats that require
+    /// extensions for mipmap generation, sequence [upload_all_faces_command] with
+    /// [generate_mipmap_command_with_color_buffer_float] or
+    /// [generate_mipmap_command_with_float_linear] instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width and height of any of the `sources` do not match the width and height of
+    /// this [TextureCube].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::image::{Image2DSource, MipmapLevels};
+    /// use web_glitz::image::format::RGB8;
+    /// use web_glitz::image::texture_cube::TextureCubeDescriptor;
+    ///
+    /// let texture = context.try_create_texture_cube(&TextureCubeDescriptor {
+    ///     format: RGB8,
+    ///     width: 256,
+    ///     height: 256,
+    ///     levels: MipmapLevels::Complete
+    /// }).unwrap();
+    ///
+    /// let face_data = |color: [u8; 3]| {
+    ///     Image2DSource::from_pixels(vec![color; 256 * 256], 256, 256).unwrap()
+    /// };
+    ///
+    /// context.submit(texture.upload_all_faces_and_generate_mipmap_command([
+    ///     face_data([255, 0, 0]),
+    ///     face_data([0, 255, 0]),
+    ///     face_data([0, 0, 255]),
+    ///     face_data([255, 255, 0]),
+    ///     face_data([255, 0, 255]),
+    ///     face_data([0, 255, 255]),
+    /// ]));
+    /// # }
+    /// ```
+    ///
+    /// [upload_all_faces_command]: TextureCube::upload_all_faces_command
+    /// [generate_mipmap_command]: TextureCube::generate_mipmap_command
+    /// [generate_mipmap_command_with_color_buffer_float]: TextureCube::generate_mipmap_command_with_color_buffer_float
+    /// [generate_mipmap_command_with_float_linear]: TextureCube::generate_mipmap_command_with_float_linear
+    pub fn upload_all_faces_and_generate_mipmap_command<D, T>(
+        &self,
+        sources: [Image2DSource<D, T>; 6],
+    ) -> SequenceRight<
+        JoinIter<UploadCommand<D, T, F>, Connection>,
+        GenerateMipmapCommand,
+        Connection,
+    >
+    where
+        T: PixelUnpack<F>,
+    {
+        sequence_right(
+            self.upload_all_faces_command(sources),
+            self.generate_mipmap_command(),
+        )
+    }
+}
+
+impl<F> TextureCube<F>
+where
+    F: TextureFormat + Filterable + color_buffer_float::FloatRenderable + 'static,
+{
+    /// Returns a command which, when executed, will generate new mipmap data for the [TextureCube].
+    ///
+    /// Identical to [generate_mipmap_command], except that it is also available to textures that
+    /// use a (16-bit) floating point format that is only renderable when the
+    /// [color_buffer_float] extension is available (mipmap generation requires a format to be both
+    /// filterable and renderable).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `extension` belongs to a different context than this [TextureCube].
+    ///
+    /// [generate_mipmap_command]: TextureCube::generate_mipmap_command
+    pub fn generate_mipmap_command_with_color_buffer_float(
+        &self,
+        extension: &color_buffer_float::Extension,
+    ) -> GenerateMipmapCommand {
+        if extension.context_id() != self.data.context_id {
+            panic!("Extension belongs to a different context than this texture.");
+        }
+
+        GenerateMipmapCommand {
+            texture_data: self.data.clone(),
+        }
+    }
+}
+
+impl<F> TextureCube<F>
+where
+    F: TextureFormat
+        + texture_float_linear::Filterable
+        + color_buffer_float::FloatRenderable
+        + 'static,
+{
+    /// Returns a command which, when executed, will generate new mipmap data for the [TextureCube].
+    ///
+    /// Identical to [generate_mipmap_command], except that it is also available to textures that
+    /// use a 32-bit floating point format, which is only filterable when the
+    /// [texture_float_linear] extension is available and only renderable when the
+    /// [color_buffer_float] extension is available (mipmap generation requires a format to be both
+    /// filterable and renderable).
+    ///
+    /// # Panics
+    ///
+    /// Panics if either of the extensions belongs to a different context than this [TextureCube].
+    ///
+    /// [generate_mipmap_command]: TextureCube::generate_mipmap_command
+    pub fn generate_mipmap_command_with_float_linear(
+        &self,
+        color_buffer_float: &color_buffer_float::Extension,
+        texture_float_linear: &texture_float_linear::Extension,
+    ) -> GenerateMipmapCommand {
+        if color_buffer_float.context_id() != self.data.context_id
+            || texture_float_linear.context_id() != self.data.context_id
+        {
+            panic!("Extension belongs to a different context than this texture.");
+        }
+
+        GenerateMipmapCommand {
+            texture_data: self.data.clone(),
+        }
+    }
 }
 
 impl<F> TextureCube<F>
@@ -365,7 +625,7 @@ where
         }
 
         FloatSampledTextureCube {
-            sampler_data: sampler.data().clone(),
+            sampler_data: Some(sampler.data().clone()),
             texture_data: self.data().clone(),
             _marker: marker::PhantomData,
         }
@@ -376,7 +636,7 @@ where
 /// point sampler.
 #[derive(Clone)]
 pub struct FloatSampledTextureCube<'a> {
-    pub(crate) sampler_data: Arc<SamplerData>,
+    pub(crate) sampler_data: Option<Arc<SamplerData>>,
     pub(crate) texture_data: Arc<TextureCubeData>,
     _marker: marker::PhantomData<&'a ()>,
 }
@@ -407,7 +667,7 @@ where
         }
 
         IntegerSampledTextureCube {
-            sampler_data: sampler.data().clone(),
+            sampler_data: Some(sampler.data().clone()),
             texture_data: self.data().clone(),
             _marker: marker::PhantomData,
         }
@@ -418,7 +678,7 @@ where
 /// sampler.
 #[derive(Clone)]
 pub struct IntegerSampledTextureCube<'a> {
-    pub(crate) sampler_data: Arc<SamplerData>,
+    pub(crate) sampler_data: Option<Arc<SamplerData>>,
     pub(crate) texture_data: Arc<TextureCubeData>,
     _marker: marker::PhantomData<&'a ()>,
 }
@@ -452,7 +712,7 @@ where
         }
 
         UnsignedIntegerSampledTextureCube {
-            sampler_data: sampler.data().clone(),
+            sampler_data: Some(sampler.data().clone()),
             texture_data: self.data().clone(),
             _marker: marker::PhantomData,
         }
@@ -463,7 +723,7 @@ where
 /// integer sampler.
 #[derive(Clone)]
 pub struct UnsignedIntegerSampledTextureCube<'a> {
-    pub(crate) sampler_data: Arc<SamplerData>,
+    pub(crate) sampler_data: Option<Arc<SamplerData>>,
     pub(crate) texture_data: Arc<TextureCubeData>,
     _marker: marker::PhantomData<&'a ()>,
 }
@@ -487,7 +747,7 @@ where
         }
 
         ShadowSampledTextureCube {
-            sampler_data: shadow_sampler.data().clone(),
+            sampler_data: Some(shadow_sampler.data().clone()),
             texture_data: self.data().clone(),
             _marker: marker::PhantomData,
         }
@@ -498,7 +758,7 @@ where
 /// sampler.
 #[derive(Clone)]
 pub struct ShadowSampledTextureCube<'a> {
-    pub(crate) sampler_data: Arc<SamplerData>,
+    pub(crate) sampler_data: Option<Arc<SamplerData>>,
     pub(crate) texture_data: Arc<TextureCubeData>,
     _marker: marker::PhantomData<&'a ()>,
 }
@@ -522,6 +782,7 @@ pub(crate) struct TextureCubeData {
     width: u32,
     height: u32,
     levels: usize,
+    label: DebugLabel,
 }
 
 impl TextureCubeData {
@@ -532,6 +793,10 @@ impl TextureCubeData {
     pub(crate) fn context_id(&self) -> u64 {
         self.context_id
     }
+
+    pub(crate) fn label(&self) -> &DebugLabel {
+        &self.label
+    }
 }
 
 impl PartialEq for TextureCubeData {
@@ -1127,6 +1392,56 @@ where
     /// # }
     /// ```
     pub fn upload_command<D, T>(&self, data: Image2DSource<D, T>) -> UploadCommand<D, T, F>
+    where
+        T: PixelUnpack<F>,
+    {
+        self.upload_command_with_options(data, UploadOptions::default())
+    }
+
+    /// Returns a command which, when executed, replaces the image data in this [LevelFace]'s image
+    /// with the image data provided in `data`, interpreting the data according to the given
+    /// `options`.
+    ///
+    /// Behaves identically to [upload_command], except that the `options` may be used to
+    /// vertically flip the image and/or to premultiply its color components with its alpha
+    /// component during the upload. See [UploadOptions] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::image::{Image2DSource, MipmapLevels, UploadOptions};
+    /// use web_glitz::image::format::RGBA8;
+    /// use web_glitz::image::texture_cube::TextureCubeDescriptor;
+    ///
+    /// let texture = context.try_create_texture_cube(&TextureCubeDescriptor {
+    ///     format: RGBA8,
+    ///     width: 256,
+    ///     height: 256,
+    ///     levels: MipmapLevels::Complete
+    /// }).unwrap();
+    ///
+    /// // Image data that stores its top row first, as is common for decoded image files.
+    /// let pixels: Vec<[u8; 4]> = vec![[255, 0, 0, 255]; 256 * 256];
+    /// let data = Image2DSource::from_pixels(pixels, 256, 256).unwrap();
+    ///
+    /// let face = texture.base_level().positive_x();
+    /// let options = UploadOptions {
+    ///     flip_y: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// context.submit(face.upload_command_with_options(data, options));
+    /// # }
+    /// ```
+    ///
+    /// [upload_command]: LevelFace::upload_command
+    pub fn upload_command_with_options<D, T>(
+        &self,
+        data: Image2DSource<D, T>,
+        options: UploadOptions,
+    ) -> UploadCommand<D, T, F>
     where
         T: PixelUnpack<F>,
     {
@@ -1136,6 +1451,7 @@ where
             level: self.level,
             face: self.face,
             region: Region2D::Fill,
+            options,
             _marker: marker::PhantomData,
         }
     }
@@ -1253,6 +1569,57 @@ where
     /// # }
     /// ```
     pub fn upload_command<D, T>(&self, data: Image2DSource<D, T>) -> UploadCommand<D, T, F>
+    where
+        T: PixelUnpack<F>,
+    {
+        self.upload_command_with_options(data, UploadOptions::default())
+    }
+
+    /// Returns a command which, when executed, replaces the image data in this
+    /// [LevelFaceSubImage]'s image with the image data provided in `data`, interpreting the data
+    /// according to the given `options`.
+    ///
+    /// Behaves identically to [upload_command], except that the `options` may be used to
+    /// vertically flip the image and/or to premultiply its color components with its alpha
+    /// component during the upload. See [UploadOptions] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::image::{Image2DSource, MipmapLevels, Region2D, UploadOptions};
+    /// use web_glitz::image::format::RGBA8;
+    /// use web_glitz::image::texture_cube::TextureCubeDescriptor;
+    ///
+    /// let texture = context.try_create_texture_cube(&TextureCubeDescriptor {
+    ///     format: RGBA8,
+    ///     width: 256,
+    ///     height: 256,
+    ///     levels: MipmapLevels::Complete
+    /// }).unwrap();
+    ///
+    /// // Image data that stores its top row first, as is common for decoded image files.
+    /// let pixels: Vec<[u8; 4]> = vec![[255, 0, 0, 255]; 128 * 128];
+    /// let data = Image2DSource::from_pixels(pixels, 128, 128).unwrap();
+    ///
+    /// let face = texture.base_level().positive_x();
+    /// let sub_image = face.sub_image(Region2D::Area((0, 0), 128, 128));
+    /// let options = UploadOptions {
+    ///     flip_y: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// context.submit(sub_image.upload_command_with_options(data, options));
+    /// # }
+    /// ```
+    ///
+    /// [upload_command]: LevelFaceSubImage::upload_command
+    pub fn upload_command_with_options<D, T>(
+        &self,
+        data: Image2DSource<D, T>,
+        options: UploadOptions,
+    ) -> UploadCommand<D, T, F>
     where
         T: PixelUnpack<F>,
     {
@@ -1262,6 +1629,7 @@ where
             level: self.level,
             face: self.face,
             region: self.region,
+            options,
             _marker: marker::PhantomData,
         }
     }
@@ -1775,12 +2143,12 @@ pub struct UploadCommand<D, T, F> {
     level: usize,
     face: CubeFace,
     region: Region2D,
+    options: UploadOptions,
     _marker: marker::PhantomData<[F]>,
 }
 
 unsafe impl<D, T, F> GpuTask<Connection> for UploadCommand<D, T, F>
 where
-    D: Borrow<[T]>,
     T: PixelUnpack<F>,
     F: TextureFormat,
 {
@@ -1792,7 +2160,8 @@ where
 
     fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
         let mut width = region_2d_overlap_width(self.texture_data.width, self.level, &self.region);
-        let height = region_2d_overlap_height(self.texture_data.height, self.level, &self.region);
+        let mut height =
+            region_2d_overlap_height(self.texture_data.height, self.level, &self.region);
 
         if width == 0 || height == 0 {
             return Progress::Finished(());
@@ -1803,10 +2172,21 @@ where
         match &self.data.internal {
             Image2DSourceInternal::PixelData {
                 data,
+                as_js_buffer,
                 row_length,
+                image_height,
                 alignment,
-                ..
             } => {
+                let skip_pixels = self.options.skip_pixels.min(*row_length);
+                let skip_rows = self.options.skip_rows.min(*image_height);
+
+                width = width.min(*row_length - skip_pixels);
+                height = height.min(*image_height - skip_rows);
+
+                if width == 0 || height == 0 {
+                    return Progress::Finished(());
+                }
+
                 state.set_active_texture_lru().apply(gl).unwrap();
 
                 unsafe {
@@ -1832,8 +2212,6 @@ where
                         .apply(gl)
                         .unwrap();
                 } else {
-                    width = *row_length;
-
                     state.set_pixel_unpack_row_length(0).apply(gl).unwrap();
                 }
 
@@ -1842,8 +2220,27 @@ where
                     Region2D::Area(offset, ..) => offset,
                 };
 
-                let elements = *row_length as usize * height as usize;
-                let data_buffer = texture_data_as_js_buffer(data.borrow(), elements);
+                let len_in_bytes =
+                    row_stride_in_bytes(*row_length, mem::size_of::<T>(), *alignment)
+                        * (skip_rows + height) as usize;
+                let data_buffer = as_js_buffer(data, T::TYPE_ID, len_in_bytes);
+
+                state
+                    .set_pixel_unpack_flip_y(self.options.flip_y)
+                    .apply(gl)
+                    .unwrap();
+                state
+                    .set_pixel_unpack_premultiply_alpha(self.options.premultiply_alpha)
+                    .apply(gl)
+                    .unwrap();
+                state
+                    .set_pixel_unpack_skip_pixels(skip_pixels as i32)
+                    .apply(gl)
+                    .unwrap();
+                state
+                    .set_pixel_unpack_skip_rows(skip_rows as i32)
+                    .apply(gl)
+                    .unwrap();
 
                 gl.tex_sub_image_2d_with_i32_and_i32_and_u32_and_type_and_opt_array_buffer_view(
                     self.face.id(),
@@ -1857,6 +2254,15 @@ where
                     Some(&data_buffer),
                 )
                 .unwrap();
+
+                // Reset the flags, so they don't affect other uploads.
+                state.set_pixel_unpack_flip_y(false).apply(gl).unwrap();
+                state
+                    .set_pixel_unpack_premultiply_alpha(false)
+                    .apply(gl)
+                    .unwrap();
+                state.set_pixel_unpack_skip_pixels(0).apply(gl).unwrap();
+                state.set_pixel_unpack_skip_rows(0).apply(gl).unwrap();
             }
         }
 
@@ -1,6 +1,6 @@
 use wasm_bindgen::JsCast;
 
-use crate::runtime::{Connection, RenderingContext};
+use crate::runtime::{Connection, DeletionQueue};
 use crate::task::{ContextId, GpuTask, Progress};
 use crate::util::JsId;
 
@@ -8,12 +8,9 @@ pub(super) trait TextureObjectDropper {
     fn drop_texture_object(&self, id: JsId);
 }
 
-impl<T> TextureObjectDropper for T
-where
-    T: RenderingContext,
-{
+impl TextureObjectDropper for DeletionQueue {
     fn drop_texture_object(&self, id: JsId) {
-        self.submit(TextureDropCommand { id });
+        self.schedule(TextureDropCommand { id });
     }
 }
 
@@ -3,13 +3,27 @@ use std::{cmp, mem, slice};
 use js_sys::{
     Float32Array, Int16Array, Int32Array, Int8Array, Object, Uint16Array, Uint32Array, Uint8Array,
 };
+use wasm_bindgen::JsValue;
 use web_sys::WebGl2RenderingContext as Gl;
 
 use crate::image::format::{InternalFormat, PixelUnpack};
-use crate::image::{Region2D, Region3D};
-
-pub(crate) fn max_mipmap_levels(width: u32, height: u32) -> usize {
-    (cmp::max(width, height) as f64).log2() as usize + 1
+use crate::image::image_source::row_stride_in_bytes;
+use crate::image::{PackOptions, Region2D, Region3D};
+
+/// Sets the sampling parameters of the texture object bound to the `target` to nearest filtering
+/// and clamp-to-edge wrapping.
+///
+/// These parameters are only used when no sampler object is bound to the texture unit (e.g. for
+/// [Texture2D::float_unsampled]). Nearest filtering keeps textures with integer formats complete,
+/// which is required for `texelFetch` to return the texel values.
+///
+/// [Texture2D::float_unsampled]: crate::image::texture_2d::Texture2D::float_unsampled
+pub(crate) fn set_unsampled_parameters(gl: &Gl, target: u32) {
+    gl.tex_parameteri(target, Gl::TEXTURE_MIN_FILTER, Gl::NEAREST as i32);
+    gl.tex_parameteri(target, Gl::TEXTURE_MAG_FILTER, Gl::NEAREST as i32);
+    gl.tex_parameteri(target, Gl::TEXTURE_WRAP_S, Gl::CLAMP_TO_EDGE as i32);
+    gl.tex_parameteri(target, Gl::TEXTURE_WRAP_T, Gl::CLAMP_TO_EDGE as i32);
+    gl.tex_parameteri(target, Gl::TEXTURE_WRAP_R, Gl::CLAMP_TO_EDGE as i32);
 }
 
 pub(crate) fn mipmap_size(base_size: u32, level: usize) -> u32 {
@@ -105,6 +119,70 @@ pub(crate) fn region_3d_overlap_depth(base_depth: u32, region: &Region3D) -> u32
     }
 }
 
+/// Resolves the `region` of a level with the given `level_width` and `level_height` into an
+/// explicit area for the upload of an image with the given `image_width` and `image_height`.
+///
+/// # Panics
+///
+/// Panics if the dimensions of the image do not match the dimensions of the region, or if the
+/// region does not fit inside the level.
+pub(crate) fn upload_region_2d(
+    region: Region2D,
+    level_width: u32,
+    level_height: u32,
+    image_width: u32,
+    image_height: u32,
+) -> Region2D {
+    let ((offset_x, offset_y), width, height) = match region {
+        Region2D::Fill => ((0, 0), level_width, level_height),
+        Region2D::Area(offset, width, height) => (offset, width, height),
+    };
+
+    if image_width != width || image_height != height {
+        panic!(
+            "The image dimensions ({}x{}) do not match the region dimensions ({}x{}).",
+            image_width, image_height, width, height
+        );
+    }
+
+    if offset_x as u64 + width as u64 > level_width as u64
+        || offset_y as u64 + height as u64 > level_height as u64
+    {
+        panic!(
+            "The region does not fit inside the level ({}x{}).",
+            level_width, level_height
+        );
+    }
+
+    Region2D::Area((offset_x, offset_y), width, height)
+}
+
+/// Returns the minimum size in bytes of a pixel pack buffer that receives an image with the given
+/// `width` and `height`, for pixels of `pixel_size` bytes, packed with the given `options`.
+///
+/// Every row but the last is padded to the pack alignment.
+pub(crate) fn pack_size_in_bytes(
+    width: u32,
+    height: u32,
+    pixel_size: usize,
+    options: &PackOptions,
+) -> usize {
+    if width == 0 || height == 0 {
+        return 0;
+    }
+
+    let row_length = if options.row_length == 0 {
+        width
+    } else {
+        options.row_length
+    };
+
+    let row_size = width as usize * pixel_size;
+    let row_stride = row_stride_in_bytes(row_length, pixel_size, options.alignment);
+
+    row_stride * (height as usize - 1) + row_size
+}
+
 pub(crate) fn region_2d_sub_image(region_a: Region2D, region_b: Region2D) -> Region2D {
     match region_b {
         Region2D::Fill => region_a,
@@ -288,10 +366,78 @@ where
     }
 }
 
+/// Copies at most `max_len_in_bytes` of the raw image data `bytes` into a JS typed array that
+/// matches the texture data type identified by `type_id`.
+pub(crate) fn texture_bytes_as_js_buffer(
+    bytes: &[u8],
+    type_id: u32,
+    max_len_in_bytes: usize,
+) -> Object {
+    let bytes = if max_len_in_bytes < bytes.len() {
+        &bytes[0..max_len_in_bytes]
+    } else {
+        bytes
+    };
+
+    // Copy into a new JS array buffer, which ensures the data is suitably aligned for any of the
+    // typed array views.
+    let js_bytes = Uint8Array::from(bytes);
+
+    if let TextureBufferType::Uint8 = TextureBufferType::from_type_id(type_id) {
+        js_bytes.into()
+    } else {
+        js_buffer_view(&js_bytes.buffer(), 0, bytes.len() as u32, type_id)
+    }
+}
+
+/// Creates a JS typed array that matches the texture data type identified by `type_id`, for at most
+/// `max_len_in_bytes` of the bytes viewed by the `view`, without copying the data.
+///
+/// The byte offset of the `view` must be a multiple of the size of the elements of the typed array.
+pub(crate) fn typed_array_as_js_buffer(
+    view: &Uint8Array,
+    type_id: u32,
+    max_len_in_bytes: usize,
+) -> Object {
+    let len = cmp::min(view.byte_length() as usize, max_len_in_bytes) as u32;
+
+    js_buffer_view(&view.buffer(), view.byte_offset(), len, type_id)
+}
+
+/// Creates a JS typed array that matches the texture data type identified by `type_id`, for the
+/// `len` bytes starting at the `byte_offset` in the `buffer`.
+fn js_buffer_view(buffer: &JsValue, byte_offset: u32, len: u32, type_id: u32) -> Object {
+    match TextureBufferType::from_type_id(type_id) {
+        TextureBufferType::Float32 => {
+            Float32Array::new_with_byte_offset_and_length(buffer, byte_offset, len / 4).into()
+        }
+        TextureBufferType::Uint8 => {
+            Uint8Array::new_with_byte_offset_and_length(buffer, byte_offset, len).into()
+        }
+        TextureBufferType::Uint16 => {
+            Uint16Array::new_with_byte_offset_and_length(buffer, byte_offset, len / 2).into()
+        }
+        TextureBufferType::Uint32 => {
+            Uint32Array::new_with_byte_offset_and_length(buffer, byte_offset, len / 4).into()
+        }
+        TextureBufferType::Int8 => {
+            Int8Array::new_with_byte_offset_and_length(buffer, byte_offset, len).into()
+        }
+        TextureBufferType::Int16 => {
+            Int16Array::new_with_byte_offset_and_length(buffer, byte_offset, len / 2).into()
+        }
+        TextureBufferType::Int32 => {
+            Int32Array::new_with_byte_offset_and_length(buffer, byte_offset, len / 4).into()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use crate::image::Alignment;
+
     #[test]
     fn test_mipmap_size() {
         assert_eq!(mipmap_size(256, 0), 256);
@@ -304,4 +450,49 @@ mod tests {
         assert_eq!(mipmap_size(256, 7), 2);
         assert_eq!(mipmap_size(256, 8), 1);
     }
+
+    #[test]
+    fn test_pack_size_in_bytes() {
+        let aligned = PackOptions {
+            alignment: Alignment::Byte4,
+            row_length: 0,
+        };
+
+        assert_eq!(pack_size_in_bytes(4, 4, 4, &aligned), 64);
+        assert_eq!(pack_size_in_bytes(3, 2, 3, &aligned), 12 + 9);
+        assert_eq!(pack_size_in_bytes(0, 2, 3, &aligned), 0);
+
+        assert_eq!(pack_size_in_bytes(3, 2, 3, &PackOptions::default()), 9 + 9);
+
+        let strided = PackOptions {
+            alignment: Alignment::Byte,
+            row_length: 5,
+        };
+
+        assert_eq!(pack_size_in_bytes(3, 2, 3, &strided), 15 + 9);
+    }
+
+    #[test]
+    fn test_upload_region_2d() {
+        assert_eq!(
+            upload_region_2d(Region2D::Area((64, 32), 16, 8), 128, 128, 16, 8),
+            Region2D::Area((64, 32), 16, 8)
+        );
+        assert_eq!(
+            upload_region_2d(Region2D::Fill, 128, 64, 128, 64),
+            Region2D::Area((0, 0), 128, 64)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_upload_region_2d_dimensions_mismatch() {
+        upload_region_2d(Region2D::Area((0, 0), 16, 16), 128, 128, 16, 8);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_upload_region_2d_out_of_bounds() {
+        upload_region_2d(Region2D::Area((120, 0), 16, 16), 128, 128, 16, 16);
+    }
 }
@@ -53,10 +53,23 @@ impl BlendFactor {
 /// See the documentation for [Blending] for details on how these equations act.
 #[derive(Clone, Copy, PartialEq, Debug)]
 pub enum BlendEquation {
+    /// Adds the weighted source value to the weighted destination value.
     Addition,
+
+    /// Subtracts the weighted destination value from the weighted source value.
     Subtraction,
+
+    /// Subtracts the weighted source value from the weighted destination value.
     ReverseSubtraction,
+
+    /// Takes the smaller of the source value and the destination value.
+    ///
+    /// Note that this equation ignores the [BlendFactor]s.
     Min,
+
+    /// Takes the larger of the source value and the destination value.
+    ///
+    /// Note that this equation ignores the [BlendFactor]s.
     Max,
 }
 
@@ -88,16 +101,20 @@ impl BlendEquation {
 ///   `O = F_s * S - F_d * D`.
 /// - [BlendEquation::ReverseSubtraction]: the output of blending `O` is calculated as
 ///   `O = F_d * D - F_s * S`.
-/// - [BlendEquation::Min]: the output of blending `O` is calculated as `O = min(F_d * D, F_s * S)`.
-/// - [BlendEquation::Max]: the output of blending `O` is calculated as `O = max(F_d * D, F_s * S)`.
+/// - [BlendEquation::Min]: the output of blending `O` is calculated as `O = min(D, S)`.
+/// - [BlendEquation::Max]: the output of blending `O` is calculated as `O = max(D, S)`.
 ///
 /// Here `S` is the relevant portion of the source value: the [color_equation] will use the red,
 /// green and blue components of the source color as `S`, the [alpha_equation] will use the alpha
 /// components of the source color as `S`. `D` is the relevant portion of the destination value: the
 /// [color_function] will use the red, green and blue components of the destination color as `D`
 /// and the [alpha_function] will use the alpha component of the destination color as `D`. `F_s` and
-/// `F_d` are [BlendFactor]s for `S` and `D` respectively. The following blend factors are
-/// available:
+/// `F_d` are [BlendFactor]s for `S` and `D` respectively. Note that [BlendEquation::Min] and
+/// [BlendEquation::Max] do not apply the blend factors: when the [color_equation] is
+/// [BlendEquation::Min] or [BlendEquation::Max], the [source_color_factor] and
+/// [destination_color_factor] are irrelevant; when the [alpha_equation] is [BlendEquation::Min] or
+/// [BlendEquation::Max], the [source_alpha_factor] and [destination_alpha_factor] are irrelevant.
+/// The following blend factors are available:
 ///
 /// - [BlendFactor::Zero]: all color components are multiplied by `0`.
 /// - [BlendFactor::One]: all color components are multiplied by `1`.
@@ -160,18 +177,26 @@ pub struct Blending {
     pub constant_color: [f32; 4],
 
     /// The [BlendFactor] that the [color_equation] applies to the source value.
+    ///
+    /// Ignored if the [color_equation] is [BlendEquation::Min] or [BlendEquation::Max].
     pub source_color_factor: BlendFactor,
 
     /// The [BlendFactor] that the [alpha_equation] applies to the source value.
+    ///
+    /// Ignored if the [alpha_equation] is [BlendEquation::Min] or [BlendEquation::Max].
     pub source_alpha_factor: BlendFactor,
 
     /// The [BlendFactor] that the [color_equation] applies to the destination value.
+    ///
+    /// Ignored if the [color_equation] is [BlendEquation::Min] or [BlendEquation::Max].
     pub destination_color_factor: BlendFactor,
 
     /// The [BlendFactor] that the [alpha_equation] applies to the destination value.
+    ///
+    /// Ignored if the [alpha_equation] is [BlendEquation::Min] or [BlendEquation::Max].
     pub destination_alpha_factor: BlendFactor,
 
-    /// The [BlendFactor] used to combine the red, green and blue components of the source and
+    /// The [BlendEquation] used to combine the red, green and blue components of the source and
     /// destination colors.
     pub color_equation: BlendEquation,
 
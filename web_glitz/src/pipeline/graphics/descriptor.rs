@@ -1,19 +1,20 @@
+use std::fmt;
 use std::marker;
 use std::sync::Arc;
 
 use crate::image::Region2D;
 use crate::pipeline::graphics::shader::{FragmentShaderData, VertexShaderData};
 use crate::pipeline::graphics::{
-    Blending, DepthTest, FragmentShader, PrimitiveAssembly, StencilTest,
-    TransformFeedbackLayoutDescriptor, TypedTransformFeedbackLayout, TypedVertexInputLayout,
-    Untyped, VertexInputLayoutDescriptor, VertexShader, Viewport,
+    Blending, DepthTest, FragmentShader, PipelineState, PrimitiveAssembly, StencilTest,
+    TransformFeedbackBufferMode, TransformFeedbackLayoutDescriptor, TypedTransformFeedbackLayout,
+    TypedVertexInputLayout, Untyped, VertexInputLayoutDescriptor, VertexShader, Viewport,
 };
 use crate::pipeline::resources::{
     ResourceBindingsLayoutDescriptor, TypedResourceBindingsLayout,
     TypedResourceBindingsLayoutDescriptor,
 };
 
-#[derive(Clone, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub(crate) enum ResourceBindingsLayoutKind {
     Minimal(ResourceBindingsLayoutDescriptor),
     Typed(TypedResourceBindingsLayoutDescriptor),
@@ -28,6 +29,12 @@ impl ResourceBindingsLayoutKind {
     }
 }
 
+/// Marker type for a [GraphicsPipelineDescriptorBuilder] that describes a depth-only pipeline.
+///
+/// See [GraphicsPipelineDescriptorBuilder::no_fragment_shader].
+#[derive(Clone, Copy, Debug)]
+pub struct NoFragmentShader;
+
 /// Provides a description from which a [GraphicsPipeline] may be created.
 ///
 /// See [RenderingContext::create_graphics_pipeline] for details on how a
@@ -42,7 +49,7 @@ pub struct GraphicsPipelineDescriptor<V, R, Tf> {
     _resource_layout: marker::PhantomData<R>,
     _transform_feedback: marker::PhantomData<Tf>,
     pub(crate) vertex_shader_data: Arc<VertexShaderData>,
-    pub(crate) fragment_shader_data: Arc<FragmentShaderData>,
+    pub(crate) fragment_shader_data: Option<Arc<FragmentShaderData>>,
     pub(crate) vertex_attribute_layout: VertexInputLayoutDescriptor,
     pub(crate) transform_feedback_layout: Option<TransformFeedbackLayoutDescriptor>,
     pub(crate) resource_bindings_layout: ResourceBindingsLayoutKind,
@@ -54,6 +61,47 @@ pub struct GraphicsPipelineDescriptor<V, R, Tf> {
     pub(crate) viewport: Viewport,
 }
 
+// Implemented manually to avoid requiring the type parameters to be `Clone`: they only appear
+// as markers.
+impl<V, R, Tf> Clone for GraphicsPipelineDescriptor<V, R, Tf> {
+    fn clone(&self) -> Self {
+        GraphicsPipelineDescriptor {
+            _vertex_attribute_layout: marker::PhantomData,
+            _resource_layout: marker::PhantomData,
+            _transform_feedback: marker::PhantomData,
+            vertex_shader_data: self.vertex_shader_data.clone(),
+            fragment_shader_data: self.fragment_shader_data.clone(),
+            vertex_attribute_layout: self.vertex_attribute_layout.clone(),
+            transform_feedback_layout: self.transform_feedback_layout.clone(),
+            resource_bindings_layout: self.resource_bindings_layout.clone(),
+            primitive_assembly: self.primitive_assembly.clone(),
+            depth_test: self.depth_test.clone(),
+            stencil_test: self.stencil_test.clone(),
+            scissor_region: self.scissor_region,
+            blending: self.blending.clone(),
+            viewport: self.viewport.clone(),
+        }
+    }
+}
+
+impl<V, R, Tf> fmt::Debug for GraphicsPipelineDescriptor<V, R, Tf> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GraphicsPipelineDescriptor")
+            .field("vertex_shader", &self.vertex_shader_data)
+            .field("fragment_shader", &self.fragment_shader_data)
+            .field("vertex_attribute_layout", &self.vertex_attribute_layout)
+            .field("transform_feedback_layout", &self.transform_feedback_layout)
+            .field("resource_bindings_layout", &self.resource_bindings_layout)
+            .field("primitive_assembly", &self.primitive_assembly)
+            .field("depth_test", &self.depth_test)
+            .field("stencil_test", &self.stencil_test)
+            .field("scissor_region", &self.scissor_region)
+            .field("blending", &self.blending)
+            .field("viewport", &self.viewport)
+            .finish()
+    }
+}
+
 impl GraphicsPipelineDescriptor<(), (), ()> {
     /// Begins building a new [GraphicsPipelineDescriptor].
     ///
@@ -94,15 +142,16 @@ impl GraphicsPipelineDescriptor<(), (), ()> {
 ///   [PrimitiveAssembly] on the primitive assembly stage. Must be set explicitly, has no default
 ///   value.
 /// - The fragment shader stage can be specified with [fragment_shader]. See [FragmentShader] for
-///   details on the fragment shader stage. Must be set explicitly, has no default value.
+///   details on the fragment shader stage. Alternatively, [no_fragment_shader] may be used to
+///   create a depth-only pipeline. Must be set explicitly, has no default value.
 /// - The vertex input layout may be specified with [typed_vertex_input_layout] or
 ///   [untyped_vertex_input_layout]. Defaults to the (typed) empty vertex input layout `()`.
 /// - The resource bindings layout may be specified with [typed_resource_bindings_layout] or
 ///   [untyped_resource_bindings_layout]. Defaults to the (typed) empty resource bindings layout
 ///   `()`.
-/// - The transform feedback layout may be specified with [typed_transform_feedback_layout] or
-///   [untyped_transform_feedback_layout]. Defaults to the (typed) empty transform feedback layout
-///   `()`.
+/// - The transform feedback layout may be specified with [typed_transform_feedback_layout],
+///   [untyped_transform_feedback_layout] or [transform_feedback_varyings]. Defaults to the (typed)
+///   empty transform feedback layout `()`.
 /// - The depth test can be enabled with [enable_depth_test]. See [DepthTest] for details on the
 ///   depth test. If not set explicitly, will default to disabled.
 /// - The stencil test can be enabled with [enable_stencil_test]. See [StencilTest] for details on
@@ -113,6 +162,8 @@ impl GraphicsPipelineDescriptor<(), (), ()> {
 ///   that is the current draw target.
 /// - Blending can be enabled with [enable_blending]. See [Blending] for details on blending. If not
 ///   set explicitly, will default to disabled.
+/// - The depth test, stencil test and blending may also be set at once from a shared
+///   [PipelineState] with [state].
 /// - The viewport may be specified with [viewport]. See [Viewport] for details on the viewport. If
 ///   no viewport is explicitly specified, then the viewport will default to [Viewport::Auto].
 ///
@@ -121,7 +172,7 @@ impl GraphicsPipelineDescriptor<(), (), ()> {
 ///
 /// - The vertex shader with [vertex_shader].
 /// - The primitive assembly algorithm with [primitive_assembly].
-/// - The fragment shader with [fragment_shader].
+/// - The fragment shader with [fragment_shader] (or [no_fragment_shader]).
 ///
 /// # Example
 ///
@@ -173,6 +224,48 @@ pub struct GraphicsPipelineDescriptorBuilder<Vs, Pa, Fs, V, R, Tf> {
     viewport: Viewport,
 }
 
+impl<Vs, Pa, Fs, V, R, Tf> Clone for GraphicsPipelineDescriptorBuilder<Vs, Pa, Fs, V, R, Tf> {
+    fn clone(&self) -> Self {
+        GraphicsPipelineDescriptorBuilder {
+            _vertex_shader: marker::PhantomData,
+            _primitive_assembly: marker::PhantomData,
+            _fragment_shader: marker::PhantomData,
+            _transform_feedback: marker::PhantomData,
+            _vertex_attribute_layout: marker::PhantomData,
+            _resource_layout: marker::PhantomData,
+            vertex_shader: self.vertex_shader.clone(),
+            vertex_input_layout: self.vertex_input_layout.clone(),
+            transform_feedback_layout: self.transform_feedback_layout.clone(),
+            resource_bindings_layout: self.resource_bindings_layout.clone(),
+            fragment_shader: self.fragment_shader.clone(),
+            primitive_assembly: self.primitive_assembly.clone(),
+            depth_test: self.depth_test.clone(),
+            stencil_test: self.stencil_test.clone(),
+            scissor_region: self.scissor_region,
+            blending: self.blending.clone(),
+            viewport: self.viewport.clone(),
+        }
+    }
+}
+
+impl<Vs, Pa, Fs, V, R, Tf> fmt::Debug for GraphicsPipelineDescriptorBuilder<Vs, Pa, Fs, V, R, Tf> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GraphicsPipelineDescriptorBuilder")
+            .field("vertex_shader", &self.vertex_shader)
+            .field("fragment_shader", &self.fragment_shader)
+            .field("vertex_input_layout", &self.vertex_input_layout)
+            .field("transform_feedback_layout", &self.transform_feedback_layout)
+            .field("resource_bindings_layout", &self.resource_bindings_layout)
+            .field("primitive_assembly", &self.primitive_assembly)
+            .field("depth_test", &self.depth_test)
+            .field("stencil_test", &self.stencil_test)
+            .field("scissor_region", &self.scissor_region)
+            .field("blending", &self.blending)
+            .field("viewport", &self.viewport)
+            .finish()
+    }
+}
+
 impl<Vs, Pa, Fs, V, R, Tf> GraphicsPipelineDescriptorBuilder<Vs, Pa, Fs, V, R, Tf> {
     /// Specifies the [VertexShader] that any graphics pipeline created using the descriptor will
     /// use.
@@ -261,6 +354,64 @@ impl<Vs, Pa, Fs, V, R, Tf> GraphicsPipelineDescriptorBuilder<Vs, Pa, Fs, V, R, T
         }
     }
 
+    /// Specifies that any graphics pipeline created using the descriptor will be a depth-only
+    /// pipeline that does not use a fragment shader.
+    ///
+    /// Useful for rendering shadow maps or for depth pre-passes: only the vertex shader stage is
+    /// programmable, the depth values of the fragments are determined by the positions output by
+    /// the vertex shader. WebGL requires a fragment shader for every program; a trivial fragment
+    /// shader is compiled internally when the pipeline is created. Any pipeline created using the
+    /// descriptor will not write to any of the color buffers of the framebuffer it draws to.
+    ///
+    /// A depth-only pipeline can only be used with a framebuffer that has a depth buffer, see
+    /// [Framebuffer::pipeline_task]. Note that this does not enable the depth test: enable the
+    /// depth test with [enable_depth_test] to actually write depth values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::pipeline::graphics::{VertexShader, TypedVertexInputLayout};
+    /// # fn wrapper<MyVertex: TypedVertexInputLayout>(vertex_shader: VertexShader) {
+    /// use web_glitz::pipeline::graphics::{
+    ///     GraphicsPipelineDescriptor, PrimitiveAssembly, WindingOrder, CullingMode, DepthTest
+    /// };
+    ///
+    /// let shadow_pipeline_descriptor = GraphicsPipelineDescriptor::begin()
+    ///     .vertex_shader(&vertex_shader)
+    ///     .primitive_assembly(PrimitiveAssembly::Triangles {
+    ///         winding_order: WindingOrder::CounterClockwise,
+    ///         face_culling: CullingMode::Back
+    ///     })
+    ///     .no_fragment_shader()
+    ///     .enable_depth_test(DepthTest::default())
+    ///     .typed_vertex_attribute_layout::<MyVertex>()
+    ///     .finish();
+    /// # }
+    /// ```
+    pub fn no_fragment_shader(
+        self,
+    ) -> GraphicsPipelineDescriptorBuilder<Vs, Pa, NoFragmentShader, V, R, Tf> {
+        GraphicsPipelineDescriptorBuilder {
+            _vertex_shader: marker::PhantomData,
+            _primitive_assembly: marker::PhantomData,
+            _fragment_shader: marker::PhantomData,
+            _transform_feedback: marker::PhantomData,
+            _vertex_attribute_layout: marker::PhantomData,
+            _resource_layout: marker::PhantomData,
+            vertex_shader: self.vertex_shader,
+            vertex_input_layout: self.vertex_input_layout,
+            transform_feedback_layout: self.transform_feedback_layout,
+            resource_bindings_layout: self.resource_bindings_layout,
+            primitive_assembly: self.primitive_assembly,
+            fragment_shader: None,
+            depth_test: self.depth_test,
+            stencil_test: self.stencil_test,
+            scissor_region: self.scissor_region,
+            blending: self.blending,
+            viewport: self.viewport,
+        }
+    }
+
     /// Specifies a [TypedVertexAttributeLayout] type that determines the vertex input layout for
     /// any graphics pipeline created from the descriptor.
     ///
@@ -277,6 +428,17 @@ impl<Vs, Pa, Fs, V, R, Tf> GraphicsPipelineDescriptorBuilder<Vs, Pa, Fs, V, R, T
     /// Note that [TypedVertexAttributeLayout] is implemented for any type that implements [Vertex]
     /// and any tuple of types that implement [Vertex] (e.g. `(Vertex1, Vertex2)` where both
     /// `Vertex1` and `Vertex2` are types that implement [Vertex]).
+    ///
+    /// It is also implemented for the empty layout `()` (the default if no vertex input layout is
+    /// specified), which describes a pipeline that takes no vertex buffers. This is compatible with
+    /// a vertex shader that declares no vertex input attributes and instead derives its outputs
+    /// from `gl_VertexID` and/or `gl_InstanceID`, e.g. for procedural geometry or a fullscreen
+    /// pass. Such a pipeline may be invoked without binding any vertex buffers (see
+    /// [GraphicsPipelineTaskBuilder::draw_without_vertices]), or with the empty set of vertex
+    /// buffers `()` (see [GraphicsPipelineTaskBuilder::bind_vertex_buffers]).
+    ///
+    /// [GraphicsPipelineTaskBuilder::draw_without_vertices]: crate::rendering::GraphicsPipelineTaskBuilder::draw_without_vertices
+    /// [GraphicsPipelineTaskBuilder::bind_vertex_buffers]: crate::rendering::GraphicsPipelineTaskBuilder::bind_vertex_buffers
     pub fn typed_vertex_attribute_layout<T>(
         self,
     ) -> GraphicsPipelineDescriptorBuilder<Vs, Pa, Fs, T, R, Tf>
@@ -304,6 +466,72 @@ impl<Vs, Pa, Fs, V, R, Tf> GraphicsPipelineDescriptorBuilder<Vs, Pa, Fs, V, R, T
         }
     }
 
+    /// Specifies a [VertexInputLayoutDescriptor] that determines the layout of the vertex input
+    /// for any pipeline created using this descriptor.
+    ///
+    /// This is an alternative to [typed_vertex_attribute_layout] for vertex input layouts that are
+    /// only known at runtime, for example layouts that follow the attribute packing of a model
+    /// file loaded by an importer. The layout may be constructed with a
+    /// [VertexInputLayoutDescriptorBuilder]. The layout does not affect the resource bindings
+    /// layout: a pipeline with an untyped vertex input layout may still use a typed resource
+    /// bindings layout (see [typed_resource_bindings_layout]).
+    ///
+    /// As with a typed layout, the layout is checked against the actual vertex input layout
+    /// defined by the pipeline's programmable shader stages when the pipeline is created. However,
+    /// vertex buffers can not be checked against the layout at compile time; they must instead be
+    /// bound with [GraphicsPipelineTaskBuilder::bind_vertex_buffers_untyped] or
+    /// [GraphicsPipelineTaskBuilder::try_bind_vertex_buffers_untyped], which check the element
+    /// size of each buffer against the stride of the corresponding bind slot at runtime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::pipeline::graphics::{VertexShader, FragmentShader};
+    /// # use web_glitz::pipeline::resources::TypedResourceBindingsLayout;
+    /// # fn wrapper<MyResources: TypedResourceBindingsLayout>(
+    /// #     vertex_shader: VertexShader,
+    /// #     fragment_shader: FragmentShader
+    /// # ) {
+    /// use web_glitz::pipeline::graphics::{
+    ///     CullingMode, GraphicsPipelineDescriptor, InputRate, PrimitiveAssembly,
+    ///     VertexAttributeDescriptor, VertexInputLayoutDescriptorBuilder, WindingOrder,
+    /// };
+    /// use web_glitz::pipeline::graphics::attribute_format::VertexAttributeFormat;
+    ///
+    /// let mut layout_builder = VertexInputLayoutDescriptorBuilder::new(None);
+    ///
+    /// layout_builder.add_buffer_slot(20, InputRate::PerVertex)
+    ///     .add_attribute(VertexAttributeDescriptor {
+    ///         location: 0,
+    ///         name: None,
+    ///         offset_in_bytes: 0,
+    ///         format: VertexAttributeFormat::Float3_f32
+    ///     })
+    ///     .add_attribute(VertexAttributeDescriptor {
+    ///         location: 1,
+    ///         name: None,
+    ///         offset_in_bytes: 12,
+    ///         format: VertexAttributeFormat::Float2_f32
+    ///     });
+    ///
+    /// let graphics_pipeline_descriptor = GraphicsPipelineDescriptor::begin()
+    ///     .vertex_shader(&vertex_shader)
+    ///     .primitive_assembly(PrimitiveAssembly::Triangles {
+    ///         winding_order: WindingOrder::CounterClockwise,
+    ///         face_culling: CullingMode::None
+    ///     })
+    ///     .fragment_shader(&fragment_shader)
+    ///     .untyped_vertex_attribute_layout(layout_builder.finish())
+    ///     .typed_resource_bindings_layout::<MyResources>()
+    ///     .finish();
+    /// # }
+    /// ```
+    ///
+    /// [typed_vertex_attribute_layout]: GraphicsPipelineDescriptorBuilder::typed_vertex_attribute_layout
+    /// [typed_resource_bindings_layout]: GraphicsPipelineDescriptorBuilder::typed_resource_bindings_layout
+    /// [VertexInputLayoutDescriptorBuilder]: crate::pipeline::graphics::VertexInputLayoutDescriptorBuilder
+    /// [GraphicsPipelineTaskBuilder::bind_vertex_buffers_untyped]: crate::rendering::GraphicsPipelineTaskBuilder::bind_vertex_buffers_untyped
+    /// [GraphicsPipelineTaskBuilder::try_bind_vertex_buffers_untyped]: crate::rendering::GraphicsPipelineTaskBuilder::try_bind_vertex_buffers_untyped
     pub fn untyped_vertex_attribute_layout(
         self,
         vertex_attribute_layout: VertexInputLayoutDescriptor,
@@ -376,6 +604,65 @@ impl<Vs, Pa, Fs, V, R, Tf> GraphicsPipelineDescriptorBuilder<Vs, Pa, Fs, V, R, T
     pub fn untyped_transform_feedback_layout(
         self,
         transform_feedback_layout: TransformFeedbackLayoutDescriptor,
+    ) -> GraphicsPipelineDescriptorBuilder<Vs, Pa, Fs, V, R, Untyped> {
+        self.with_transform_feedback_layout(transform_feedback_layout)
+    }
+
+    /// Specifies the names of the transform stage output attributes ("varyings") that are to be
+    /// recorded as transform feedback by any pipeline created using this descriptor, and whether
+    /// these are to be recorded into a single buffer or into separate buffers.
+    ///
+    /// Unlike [typed_transform_feedback_layout] and [untyped_transform_feedback_layout], this does
+    /// not require the types of the varyings to be specified: when the descriptor that results from
+    /// this builder is used to create a graphics pipeline (see
+    /// [RenderingContext::create_graphics_pipeline]), the `varyings` are captured by name when the
+    /// pipeline's program is linked, after which their types are obtained by reflecting on the
+    /// linked program. If any of the `varyings` is not an output of the pipeline's transform
+    /// stage(s), then pipeline creation will fail and an error is returned instead. The resulting
+    /// layout may be inspected with [GraphicsPipeline::transform_feedback_layout]. This is useful
+    /// when the varyings are not known statically (e.g. when their names are generated), or when
+    /// only a subset of the outputs is to be recorded.
+    ///
+    /// If the `buffer_mode` is [TransformFeedbackBufferMode::Interleaved], then the layout will
+    /// contain a single buffer slot into which all varyings are recorded; if the `buffer_mode` is
+    /// [TransformFeedbackBufferMode::Separate], then the layout will contain a buffer slot for each
+    /// of the varyings.
+    ///
+    /// As the types of the varyings are not known statically, buffers may only be attached to the
+    /// resulting pipeline with [GraphicsPipeline::record_transform_feedback_unchecked].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::pipeline::graphics::{VertexShader, PrimitiveAssembly, FragmentShader};
+    /// # fn wrapper(vertex_shader: &VertexShader, fragment_shader: &FragmentShader) {
+    /// use web_glitz::pipeline::graphics::{GraphicsPipelineDescriptor, TransformFeedbackBufferMode};
+    ///
+    /// let descriptor = GraphicsPipelineDescriptor::begin()
+    ///     .vertex_shader(vertex_shader)
+    ///     .primitive_assembly(PrimitiveAssembly::Points)
+    ///     .fragment_shader(fragment_shader)
+    ///     .transform_feedback_varyings(
+    ///         &["position", "velocity"],
+    ///         TransformFeedbackBufferMode::Separate,
+    ///     )
+    ///     .finish();
+    /// # }
+    /// ```
+    pub fn transform_feedback_varyings(
+        self,
+        varyings: &[&str],
+        buffer_mode: TransformFeedbackBufferMode,
+    ) -> GraphicsPipelineDescriptorBuilder<Vs, Pa, Fs, V, R, Untyped> {
+        self.with_transform_feedback_layout(TransformFeedbackLayoutDescriptor::from_varyings(
+            varyings,
+            buffer_mode,
+        ))
+    }
+
+    fn with_transform_feedback_layout(
+        self,
+        transform_feedback_layout: TransformFeedbackLayoutDescriptor,
     ) -> GraphicsPipelineDescriptorBuilder<Vs, Pa, Fs, V, R, Untyped> {
         GraphicsPipelineDescriptorBuilder {
             _vertex_shader: marker::PhantomData,
@@ -507,6 +794,28 @@ impl<Vs, Pa, Fs, V, R, Tf> GraphicsPipelineDescriptorBuilder<Vs, Pa, Fs, V, R, T
         }
     }
 
+    /// Applies the depth test, stencil test and blending configuration bundled in the `state` to
+    /// any graphics pipeline created from the descriptor.
+    ///
+    /// Replaces any depth test, stencil test or blending configuration that was set previously; a
+    /// test or blending that is `None` in the `state` is disabled. The individual settings may
+    /// still be overridden afterwards with [enable_depth_test], [enable_stencil_test] or
+    /// [enable_blending].
+    ///
+    /// See [PipelineState] for details.
+    ///
+    /// [enable_depth_test]: GraphicsPipelineDescriptorBuilder::enable_depth_test
+    /// [enable_stencil_test]: GraphicsPipelineDescriptorBuilder::enable_stencil_test
+    /// [enable_blending]: GraphicsPipelineDescriptorBuilder::enable_blending
+    pub fn state(self, state: &PipelineState) -> Self {
+        GraphicsPipelineDescriptorBuilder {
+            depth_test: state.depth_test.clone(),
+            stencil_test: state.stencil_test.clone(),
+            blending: state.blending.clone(),
+            ..self
+        }
+    }
+
     /// Sets the viewport used by any graphics pipeline created from the descriptor.
     ///
     /// See [Viewport] for details on the viewport. Defaults to [Viewport::Auto].
@@ -525,7 +834,31 @@ impl<V, R, Tf>
             _resource_layout: marker::PhantomData,
             _transform_feedback: marker::PhantomData,
             vertex_shader_data: self.vertex_shader.unwrap(),
-            fragment_shader_data: self.fragment_shader.unwrap(),
+            fragment_shader_data: self.fragment_shader,
+            vertex_attribute_layout: self.vertex_input_layout,
+            transform_feedback_layout: self.transform_feedback_layout,
+            resource_bindings_layout: self.resource_bindings_layout,
+            primitive_assembly: self.primitive_assembly.unwrap(),
+            depth_test: self.depth_test,
+            stencil_test: self.stencil_test,
+            scissor_region: self.scissor_region,
+            blending: self.blending,
+            viewport: self.viewport,
+        }
+    }
+}
+
+impl<V, R, Tf>
+    GraphicsPipelineDescriptorBuilder<VertexShader, PrimitiveAssembly, NoFragmentShader, V, R, Tf>
+{
+    /// Finishes building and returns the [GraphicsPipelineDescriptor] for a depth-only pipeline.
+    pub fn finish(self) -> GraphicsPipelineDescriptor<V, R, Tf> {
+        GraphicsPipelineDescriptor {
+            _vertex_attribute_layout: marker::PhantomData,
+            _resource_layout: marker::PhantomData,
+            _transform_feedback: marker::PhantomData,
+            vertex_shader_data: self.vertex_shader.unwrap(),
+            fragment_shader_data: None,
             vertex_attribute_layout: self.vertex_input_layout,
             transform_feedback_layout: self.transform_feedback_layout,
             resource_bindings_layout: self.resource_bindings_layout,
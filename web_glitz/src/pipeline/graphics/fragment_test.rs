@@ -8,16 +8,43 @@ use crate::runtime::Connection;
 
 /// Enumerates the test functions that may be used with [DepthTest] and [StencilTest].
 ///
+/// All eight comparison functions supported by WebGL are available for both tests. The value that
+/// is tested is compared against the reference value: the fragment's depth value against the value
+/// in the depth buffer for the depth test, the reference value against the value in the stencil
+/// buffer for the stencil test.
+///
 /// See the documentation for [DepthTest] and [StencilTest] for details.
 #[derive(PartialEq, Debug, Clone, Copy)]
 pub enum TestFunction {
+    /// The test passes if the value is equal to the reference value (`gl.EQUAL`).
     Equal,
+
+    /// The test passes if the value is not equal to the reference value (`gl.NOTEQUAL`).
     NotEqual,
+
+    /// The test passes if the value is less than the reference value (`gl.LESS`).
     Less,
+
+    /// The test passes if the value is greater than the reference value (`gl.GREATER`).
     Greater,
+
+    /// The test passes if the value is less than or equal to the reference value (`gl.LEQUAL`).
     LessOrEqual,
+
+    /// The test passes if the value is greater than or equal to the reference value
+    /// (`gl.GEQUAL`).
     GreaterOrEqual,
+
+    /// The test never passes (`gl.NEVER`).
+    ///
+    /// For the stencil test, the stencil fail operation is still applied to every fragment, which
+    /// may be used to update the stencil buffer without drawing any fragments.
     NeverPass,
+
+    /// The test always passes (`gl.ALWAYS`).
+    ///
+    /// For the depth test, this writes the fragment's depth value unconditionally if depth writes
+    /// are enabled (e.g. to stamp a sky at the far plane).
     AlwaysPass,
 }
 
@@ -103,7 +130,11 @@ pub struct DepthTest {
 
     /// Whether or not the depth buffer will be updated when the depth test passes.
     ///
-    /// When set to `false`, the depth buffer will not be updated when the depth test passes.
+    /// When set to `false`, the depth buffer will not be updated when the depth test passes. This
+    /// is typically used when rendering transparent geometry: fragments are still tested against
+    /// the depth of the opaque geometry, but transparent objects do not occlude each other. The
+    /// setting only applies to the pipeline's draw commands; it does not affect commands that clear
+    /// the depth buffer.
     ///
     /// Defaults to `true`.
     pub write: bool,
@@ -124,6 +155,42 @@ pub struct DepthTest {
 }
 
 impl DepthTest {
+    /// Returns a [DepthTest] preset for rendering with a reversed depth range, where the near plane
+    /// maps to a depth of `1.0` and the far plane maps to a depth of `0.0`.
+    ///
+    /// Uses [TestFunction::Greater], so that fragments closer to the viewer (with greater depth
+    /// values) pass the test. Reversing the depth range distributes the floating point precision
+    /// of the depth buffer more evenly across the view distance, which reduces "Z-fighting" in
+    /// scenes with large view distances.
+    ///
+    /// Using this preset also requires:
+    ///
+    /// - that the depth attachment is cleared to `0.0`, rather than the conventional `1.0`, see
+    ///   [LoadOp::Clear];
+    /// - a projection matrix that maps the near plane to the far end of the clip volume, see
+    ///   [reverse_z_perspective] for an infinite perspective projection.
+    ///
+    /// Ideally used with a floating point depth format (e.g. [DepthComponent32F]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use web_glitz::pipeline::graphics::{DepthTest, TestFunction};
+    ///
+    /// let depth_test = DepthTest::reverse_z();
+    ///
+    /// assert_eq!(depth_test.test, TestFunction::Greater);
+    /// ```
+    ///
+    /// [LoadOp::Clear]: crate::rendering::LoadOp::Clear
+    /// [DepthComponent32F]: crate::image::format::DepthComponent32F
+    pub fn reverse_z() -> Self {
+        DepthTest {
+            test: TestFunction::Greater,
+            ..Default::default()
+        }
+    }
+
     pub(crate) fn apply(option: &Option<Self>, connection: &mut Connection) {
         let (gl, state) = unsafe { connection.unpack_mut() };
 
@@ -170,6 +237,45 @@ impl Default for DepthTest {
     }
 }
 
+/// Returns a column-major perspective projection matrix with an infinite far plane, for use with
+/// [DepthTest::reverse_z].
+///
+/// The `fov_y` is the vertical field of view in radians, `aspect` is the ratio of the viewport's
+/// width to its height and `near` is the distance to the near plane, which must be greater than
+/// `0.0`. Uses a right-handed view space in which the viewer looks down the negative Z axis. Points
+/// on the near plane are mapped to a depth of `1.0`; the depth of a point approaches `0.0` as its
+/// distance to the viewer approaches infinity.
+///
+/// Note that WebGL maps clip space depth from the range `-1.0..=1.0` onto the depth range, which
+/// loses some of the precision gained from reversing the depth range (WebGL does not expose a way
+/// to use a `0.0..=1.0` clip space depth range instead).
+///
+/// # Example
+///
+/// ```
+/// use web_glitz::pipeline::graphics::reverse_z_perspective;
+///
+/// let projection = reverse_z_perspective(std::f32::consts::FRAC_PI_2, 16.0 / 9.0, 0.1);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `near` is not greater than `0.0`.
+pub fn reverse_z_perspective(fov_y: f32, aspect: f32, near: f32) -> [[f32; 4]; 4] {
+    if !(near > 0.0) {
+        panic!("The near plane distance must be greater than `0.0`.");
+    }
+
+    let f = 1.0 / (fov_y / 2.0).tan();
+
+    [
+        [f / aspect, 0.0, 0.0, 0.0],
+        [0.0, f, 0.0, 0.0],
+        [0.0, 0.0, 1.0, -1.0],
+        [0.0, 0.0, 2.0 * near, 0.0],
+    ]
+}
+
 /// Defines how a fragment's depth output will be mapped onto the range `0.0..1.0` from the near
 /// plane at `0.0` to the far plane at `1.0`.
 ///
@@ -631,3 +737,36 @@ impl Default for StencilTest {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn depth(projection: &[[f32; 4]; 4], z: f32) -> f32 {
+        let clip_z = projection[2][2] * z + projection[3][2];
+        let clip_w = projection[2][3] * z + projection[3][3];
+
+        (clip_z / clip_w + 1.0) / 2.0
+    }
+
+    #[test]
+    fn test_test_function_ids() {
+        assert_eq!(TestFunction::Equal.id(), Gl::EQUAL);
+        assert_eq!(TestFunction::NotEqual.id(), Gl::NOTEQUAL);
+        assert_eq!(TestFunction::Less.id(), Gl::LESS);
+        assert_eq!(TestFunction::Greater.id(), Gl::GREATER);
+        assert_eq!(TestFunction::LessOrEqual.id(), Gl::LEQUAL);
+        assert_eq!(TestFunction::GreaterOrEqual.id(), Gl::GEQUAL);
+        assert_eq!(TestFunction::NeverPass.id(), Gl::NEVER);
+        assert_eq!(TestFunction::AlwaysPass.id(), Gl::ALWAYS);
+    }
+
+    #[test]
+    fn test_reverse_z_perspective() {
+        let projection = reverse_z_perspective(1.0, 1.0, 0.5);
+
+        assert_eq!(depth(&projection, -0.5), 1.0);
+        assert_eq!(depth(&projection, -1.0), 0.5);
+        assert!(depth(&projection, -1.0e6) < 1.0e-6);
+    }
+}
@@ -1,33 +1,50 @@
+use std::any::{Any, TypeId};
 use std::cell::UnsafeCell;
+use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::marker;
+use std::rc::Rc;
 use std::sync::Arc;
 
-use fnv::FnvHasher;
+use fnv::{FnvHashMap, FnvHasher};
 use wasm_bindgen::convert::IntoWasmAbi;
 use wasm_bindgen::JsCast;
+use web_sys::{WebGl2RenderingContext as Gl, WebGlProgram};
 
 use crate::image::Region2D;
 use crate::pipeline::graphics::descriptor::ResourceBindingsLayoutKind;
-use crate::pipeline::graphics::shader::{FragmentShaderData, VertexShaderData};
+use crate::pipeline::graphics::shader::{
+    FragmentShaderAllocateCommand, FragmentShaderData, VertexShaderData,
+};
 use crate::pipeline::graphics::util::BufferDescriptor;
+use crate::pipeline::graphics::vertex::layout_descriptor::VertexAttributeSlotDescriptor;
 use crate::pipeline::graphics::{
-    Blending, DepthTest, GraphicsPipelineDescriptor, PrimitiveAssembly, StencilTest,
+    Blending, DepthTest, GraphicsPipelineDescriptor, IncompatibleVertexInputLayout,
+    PrimitiveAssembly, StencilTest, TransformFeedbackBuffers,
     TransformFeedbackBuffersEncodingContext, TransformFeedbackLayoutDescriptor,
     TypedTransformFeedbackBuffers, TypedTransformFeedbackLayout, Untyped,
     VertexInputLayoutDescriptor, Viewport,
 };
-use crate::pipeline::resources::resource_slot::{SlotBindingUpdater, SlotType};
+use crate::pipeline::resources::resource_slot::{
+    ShaderResourceSlotDescriptor, SlotBindingUpdater, SlotType,
+};
 use crate::pipeline::resources::{
-    IncompatibleResources, ResourceBindingsLayoutDescriptor, ResourceSlotKind, ResourceSlotType,
+    IncompatibleResources, ResourceBindingsLayoutDescriptor, ResourceSlotIdentifier,
+    ResourceSlotKind, ResourceSlotType, ResourceSlotUsage, ResourceVisibility,
     TypedResourceBindingsLayout, TypedResourceBindingsLayoutDescriptor,
 };
 use crate::runtime::state::{ContextUpdate, DynamicState, ProgramKey};
-use crate::runtime::{Connection, CreateGraphicsPipelineError, RenderingContext};
+use crate::runtime::{
+    Connection, CreateGraphicsPipelineError, DeletionQueue, RenderingContext, ResourceLimit,
+};
 use crate::task::{ContextId, GpuTask, Progress};
-use crate::util::JsId;
+use crate::util::{DebugLabel, JsId};
 use staticvec::StaticVec;
 
+const DEPTH_ONLY_FRAGMENT_SHADER_SOURCE: &str = "#version 300 es
+void main() {}
+";
+
 /// Encapsulates the state for a graphics pipeline.
 ///
 /// See [RenderingContext::create_graphics_pipeline] for details on how a graphics pipeline is
@@ -47,6 +64,9 @@ pub struct GraphicsPipeline<V, R, Tf> {
     vertex_attribute_layout: VertexInputLayoutDescriptor,
     transform_feedback_layout: Option<TransformFeedbackLayoutDescriptor>,
     resource_bindings_layout: ResourceBindingsLayoutKind,
+    pub(crate) resource_slot_usage: Arc<[ResourceSlotUsage]>,
+    attribute_slot_descriptors: Rc<[VertexAttributeSlotDescriptor]>,
+    resource_slot_descriptors: Rc<[ShaderResourceSlotDescriptor]>,
     primitive_assembly: PrimitiveAssembly,
     program_id: JsId,
     depth_test: Option<DepthTest>,
@@ -54,7 +74,9 @@ pub struct GraphicsPipeline<V, R, Tf> {
     scissor_region: Region2D,
     blending: Option<Blending>,
     viewport: Viewport,
+    depth_only: bool,
     pub(crate) transform_feedback_data: Arc<UnsafeCell<Option<TransformFeedbackData>>>,
+    label: DebugLabel,
 }
 
 impl<V, R, Tf> GraphicsPipeline<V, R, Tf> {
@@ -66,6 +88,89 @@ impl<V, R, Tf> GraphicsPipeline<V, R, Tf> {
         self.program_id
     }
 
+    pub(crate) fn debug_label(&self) -> &DebugLabel {
+        &self.label
+    }
+
+    /// Assigns a debug `label` to this [GraphicsPipeline].
+    ///
+    /// The label is included in the [Debug](std::fmt::Debug) output for the pipeline and in the
+    /// messages of errors that concern the pipeline.
+    pub fn set_label(&self, label: &str) {
+        self.label.set(label);
+    }
+
+    /// Returns the debug label that was assigned to this [GraphicsPipeline] with [set_label], or
+    /// `None` if no label was assigned.
+    pub fn label(&self) -> Option<String> {
+        self.label.get()
+    }
+
+    /// Returns `true` if this is a depth-only pipeline that does not use a fragment shader, `false`
+    /// otherwise.
+    ///
+    /// A depth-only pipeline does not write to the color buffers of the framebuffer it draws to.
+    /// See [GraphicsPipelineDescriptorBuilder::no_fragment_shader] for details.
+    pub fn is_depth_only(&self) -> bool {
+        self.depth_only
+    }
+
+    /// Returns how the resource slots declared by this pipeline's resource bindings layout are
+    /// used by the pipeline's shader stages.
+    ///
+    /// The usage is reflected from the linked program when the pipeline is created. A slot that is
+    /// declared by the layout, but that is not referenced by either shader stage (for example,
+    /// because the shader compiler optimized it away), is [ResourceVisibility::Unused]; resources
+    /// bound to such a slot are not bound when the pipeline is active. If the context was created
+    /// in debug mode, a warning is logged to the console for every unused slot when the pipeline is
+    /// created.
+    ///
+    /// Note that WebGL only reports which shader stage(s) use a uniform block; the visibility of
+    /// a sampled-texture slot that is used is always [ResourceVisibility::Unspecified].
+    pub fn resource_slot_usage(&self) -> &[ResourceSlotUsage] {
+        &self.resource_slot_usage
+    }
+
+    /// Checks whether vertex input with the given `layout` may be used with this pipeline.
+    ///
+    /// Returns `Ok(())` if the `layout` declares an attribute of a compatible format for every
+    /// attribute location used by the pipeline's vertex shader, or an
+    /// [IncompatibleVertexInputLayout] error describing the first attribute location that is not
+    /// satisfied otherwise. Attributes are matched by location only; attribute names (see
+    /// [VertexAttributeDescriptor::name]) are ignored, as they are only used to assign attribute
+    /// locations when the pipeline is created.
+    ///
+    /// This performs the same check that is performed against the pipeline's own vertex input
+    /// layout when the pipeline is created. Applications that construct vertex input layouts at
+    /// runtime may use this to validate a layout once, rather than on every bind.
+    ///
+    /// [VertexAttributeDescriptor::name]: crate::pipeline::graphics::VertexAttributeDescriptor::name
+    pub fn is_vertex_layout_compatible(
+        &self,
+        layout: &VertexInputLayoutDescriptor,
+    ) -> Result<(), IncompatibleVertexInputLayout> {
+        layout.check_compatibility(&self.attribute_slot_descriptors)
+    }
+
+    /// Checks whether resources with the given `layout` may be used with this pipeline.
+    ///
+    /// Returns `Ok(())` if the `layout` declares a slot of a matching kind in the appropriate bind
+    /// group for every resource used by the pipeline's shader stages, or an [IncompatibleResources]
+    /// error describing the first resource that is not satisfied otherwise. Uniform buffer slots
+    /// are expected in bind group `0` and sampled texture slots are expected in bind group `1`.
+    ///
+    /// This performs the same check that is performed against a minimal resource bindings layout
+    /// (see [ResourceBindingsLayoutDescriptor]) when a pipeline is created. Note that the slot
+    /// indices declared by the `layout` must also match the slot indices declared by the
+    /// pipeline's own resource bindings layout for resources to be bound to the correct slots;
+    /// this is not checked.
+    pub fn is_resource_layout_compatible(
+        &self,
+        layout: &ResourceBindingsLayoutDescriptor,
+    ) -> Result<(), IncompatibleResources> {
+        match_resource_slots(layout, &self.resource_slot_descriptors, |_, _| ())
+    }
+
     /// Returns a description of the vertex input layout expected by the pipeline.
     ///
     /// See [VertexInputLayoutDescriptor] for details.
@@ -147,6 +252,59 @@ impl<V, R, Tf> GraphicsPipeline<V, R, Tf> {
     }
 }
 
+impl<V, R> GraphicsPipeline<V, R, Untyped> {
+    /// Returns a wrapped representation of this graphics pipeline that will record the output of
+    /// the vertex transformation stage(s) for the pipeline in the attached
+    /// `transform_feedback_buffers`, without checking the buffers against the pipeline's
+    /// [transform_feedback_layout].
+    ///
+    /// # Panics
+    ///
+    /// Panics if this pipeline does not record transform feedback, or if the number of
+    /// `transform_feedback_buffers` does not match the number of buffer slots in the pipeline's
+    /// [transform_feedback_layout].
+    ///
+    /// # Unsafe
+    ///
+    /// The element type of each of the `transform_feedback_buffers` must be bitwise compatible
+    /// with the attributes for the corresponding buffer slot in the pipeline's
+    /// [transform_feedback_layout].
+    ///
+    /// [transform_feedback_layout]: GraphicsPipeline::transform_feedback_layout
+    pub unsafe fn record_transform_feedback_unchecked<Fb>(
+        &mut self,
+        transform_feedback_buffers: Fb,
+    ) -> RecordTransformFeedback<V, R, Untyped, Fb>
+    where
+        Fb: TransformFeedbackBuffers,
+    {
+        let buffers = transform_feedback_buffers
+            .encode(&mut TransformFeedbackBuffersEncodingContext::new())
+            .into_descriptors();
+
+        let slot_count = self
+            .transform_feedback_layout
+            .as_ref()
+            .expect("Pipeline does not record transform feedback.")
+            .buffer_slots()
+            .count();
+
+        if buffers.len() != slot_count {
+            panic!(
+                "Expected {} transform feedback buffers, found {}.",
+                slot_count,
+                buffers.len()
+            );
+        }
+
+        RecordTransformFeedback {
+            pipeline: self,
+            buffers,
+            _marker: marker::PhantomData,
+        }
+    }
+}
+
 impl<V, Tf> GraphicsPipeline<V, Untyped, Tf> {
     /// Returns a minimal description of the resource bindings layout used by this pipeline.
     ///
@@ -184,117 +342,94 @@ impl<V, R, Tf> GraphicsPipeline<V, R, Tf> {
     where
         Rc: RenderingContext + Clone + 'static,
     {
-        let (gl, state) = unsafe { connection.unpack_mut() };
-
         if descriptor.vertex_shader_data.context_id() != context.id() {
             panic!("Vertex shader does not belong to the context.");
         }
 
-        if descriptor.fragment_shader_data.context_id() != context.id() {
-            panic!("Fragment shader does not belong to the context.");
-        }
+        let fragment_shader_data = match &descriptor.fragment_shader_data {
+            Some(fragment_shader_data) => {
+                if fragment_shader_data.context_id() != context.id() {
+                    panic!("Fragment shader does not belong to the context.");
+                }
+
+                fragment_shader_data.clone()
+            }
+            None => {
+                let mut allocate_command = FragmentShaderAllocateCommand::new(
+                    context,
+                    object_id,
+                    DEPTH_ONLY_FRAGMENT_SHADER_SOURCE,
+                );
+
+                match allocate_command.progress(connection) {
+                    Progress::Finished(Ok(fragment_shader)) => fragment_shader.data().clone(),
+                    _ => unreachable!(),
+                }
+            }
+        };
+
+        let debug = connection.debug();
+        let (gl, state) = unsafe { connection.unpack_mut() };
 
         // TODO: need to reference state later, but keep reference to the program as well. I'm sure
         // there some obvious better way to do this, but I'm too tired to see it right now. This
         // should be safe for now (as we're referencing different parts of `state`).
         let mut program_cache = unsafe { (&mut *(state as *mut DynamicState)).program_cache_mut() };
 
-        let transform_feedback_layout_key =
-            descriptor.transform_feedback_layout.as_ref().map(|layout| {
-                let mut hasher = FnvHasher::default();
-
-                layout.hash(&mut hasher);
-
-                hasher.finish()
-            });
-
         let program = program_cache.get_or_create(
-            ProgramKey {
-                vertex_shader_id: descriptor.vertex_shader_data.id().unwrap(),
-                fragment_shader_id: descriptor.fragment_shader_data.id().unwrap(),
-                resource_bindings_layout: descriptor.resource_bindings_layout.key(),
-                transform_feedback_layout_key,
-            },
+            program_key(descriptor, &fragment_shader_data),
+            &descriptor.vertex_attribute_layout,
             &descriptor.transform_feedback_layout,
             gl,
         )?;
 
         let program_object = program.gl_object();
 
-        if let Some(layout) = &descriptor.transform_feedback_layout {
+        let transform_feedback_layout = if let Some(layout) = &descriptor.transform_feedback_layout
+        {
             layout.check_compatibility(program_object, gl)?;
-        }
+
+            Some(layout.resolve(program_object, gl))
+        } else {
+            None
+        };
 
         descriptor
             .vertex_attribute_layout
             .check_compatibility(program.attribute_slot_descriptors())?;
 
+        check_resource_limits(program.resource_slot_descriptors(), program_object, gl)?;
+
         state.use_program(Some(program_object)).apply(gl).unwrap();
 
         let updater = SlotBindingUpdater::new(gl, program_object);
 
-        match &descriptor.resource_bindings_layout {
+        let layout_slots: Vec<LayoutSlot> = match &descriptor.resource_bindings_layout {
             ResourceBindingsLayoutKind::Minimal(layout) => {
+                match_resource_slots(
+                    layout,
+                    program.resource_slot_descriptors(),
+                    |slot, binding| updater.update_slot_binding(slot, binding),
+                )?;
+
                 let bind_groups = layout.bind_groups();
                 let mut iter = bind_groups.iter();
 
-                let bind_group_0 = iter
-                    .next()
-                    .filter(|g| g.bind_group_index() == 0)
-                    .ok_or(IncompatibleResources::MissingBindGroup(0))?;
-
-                let bind_group_1 = iter
-                    .next()
-                    .filter(|g| g.bind_group_index() == 1)
-                    .ok_or(IncompatibleResources::MissingBindGroup(1))?;
-
-                'outer_0: for slot in program.resource_slot_descriptors() {
-                    if slot.slot_type().is_kind(ResourceSlotKind::UniformBuffer) {
-                        for descriptor in bind_group_0.slots() {
-                            if &descriptor.slot_identifier == slot.identifier() {
-                                if !descriptor.slot_kind.is_uniform_buffer() {
-                                    return Err(IncompatibleResources::ResourceTypeMismatch(
-                                        slot.identifier().clone(),
-                                    )
-                                    .into());
-                                }
-
-                                updater.update_slot_binding(slot, descriptor.slot_index as u32);
-
-                                continue 'outer_0;
-                            }
-                        }
-
-                        return Err(IncompatibleResources::MissingResource(
-                            slot.identifier().clone(),
-                        )
-                        .into());
-                    } else if slot.slot_type().is_kind(ResourceSlotKind::SampledTexture) {
-                        for descriptor in bind_group_1.slots() {
-                            if &descriptor.slot_identifier == slot.identifier() {
-                                if !descriptor.slot_kind.is_sampled_texture() {
-                                    return Err(IncompatibleResources::ResourceTypeMismatch(
-                                        slot.identifier().clone(),
-                                    )
-                                    .into());
-                                }
-
-                                updater.update_slot_binding(slot, descriptor.slot_index as u32);
-
-                                continue 'outer_0;
-                            }
-                        }
-
-                        return Err(IncompatibleResources::MissingResource(
-                            slot.identifier().clone(),
-                        )
-                        .into());
-                    }
-
-                    return Err(
-                        IncompatibleResources::MissingResource(slot.identifier().clone()).into(),
-                    );
-                }
+                // The bind groups were checked by `match_resource_slots`.
+                let bind_group_0 = iter.next().unwrap();
+                let bind_group_1 = iter.next().unwrap();
+
+                bind_group_0
+                    .slots()
+                    .iter()
+                    .map(|s| (0, s.slot_identifier.clone(), s.slot_index, s.slot_kind))
+                    .chain(
+                        bind_group_1
+                            .slots()
+                            .iter()
+                            .map(|s| (1, s.slot_identifier.clone(), s.slot_index, s.slot_kind)),
+                    )
+                    .collect()
             }
             ResourceBindingsLayoutKind::Typed(layout) => {
                 let mut iter = layout.bind_groups().iter();
@@ -309,7 +444,7 @@ impl<V, R, Tf> GraphicsPipeline<V, R, Tf> {
                     .filter(|g| g.bind_group_index() == 1)
                     .ok_or(IncompatibleResources::MissingBindGroup(1))?;
 
-                'outer_1: for slot in program.resource_slot_descriptors() {
+                'outer_1: for slot in program.resource_slot_descriptors().iter() {
                     match slot.slot_type() {
                         SlotType::UniformBlock(uniform_block_slot) => {
                             for descriptor in bind_group_0.slots() {
@@ -338,8 +473,10 @@ impl<V, R, Tf> GraphicsPipeline<V, R, Tf> {
                                 }
                             }
 
-                            return Err(IncompatibleResources::MissingResource(
-                                slot.identifier().clone(),
+                            return Err(missing_uniform_block(
+                                slot.identifier(),
+                                program.resource_slot_descriptors(),
+                                bind_group_0.slots().iter().map(|s| &s.slot_identifier),
                             )
                             .into());
                         }
@@ -373,6 +510,50 @@ impl<V, R, Tf> GraphicsPipeline<V, R, Tf> {
                         }
                     }
                 }
+
+                bind_group_0
+                    .slots()
+                    .iter()
+                    .map(|s| {
+                        (
+                            0,
+                            s.slot_identifier.clone(),
+                            s.slot_index,
+                            s.slot_type.into(),
+                        )
+                    })
+                    .chain(bind_group_1.slots().iter().map(|s| {
+                        (
+                            1,
+                            s.slot_identifier.clone(),
+                            s.slot_index,
+                            s.slot_type.into(),
+                        )
+                    }))
+                    .collect()
+            }
+        };
+
+        let resource_slot_usage = resource_slot_usage(
+            layout_slots,
+            program.resource_slot_descriptors(),
+            program_object,
+            gl,
+        );
+
+        if debug {
+            for usage in resource_slot_usage.iter() {
+                if !usage.visibility.is_used() {
+                    web_sys::console::warn_1(
+                        &format!(
+                            "Resource slot `{}` (bind group {}, slot {}) is declared by the \
+                             pipeline's resource bindings layout, but is not used by the \
+                             pipeline's shader stages.",
+                            &*usage.slot_identifier, usage.bind_group_index, usage.slot_index
+                        )
+                        .into(),
+                    );
+                }
             }
         }
 
@@ -382,12 +563,15 @@ impl<V, R, Tf> GraphicsPipeline<V, R, Tf> {
             _transform_feedback_varyings_marker: marker::PhantomData,
             object_id,
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
+            dropper: Box::new(context.deletion_queue()),
             vertex_shader_data: descriptor.vertex_shader_data.clone(),
-            fragment_shader_data: descriptor.fragment_shader_data.clone(),
+            fragment_shader_data,
             vertex_attribute_layout: descriptor.vertex_attribute_layout.clone(),
-            transform_feedback_layout: descriptor.transform_feedback_layout.clone(),
+            transform_feedback_layout,
             resource_bindings_layout: descriptor.resource_bindings_layout.clone(),
+            resource_slot_usage: resource_slot_usage.into(),
+            attribute_slot_descriptors: program.attribute_slot_descriptors().clone(),
+            resource_slot_descriptors: program.resource_slot_descriptors().clone(),
             primitive_assembly: descriptor.primitive_assembly.clone(),
             program_id: JsId::from_abi(program_object.into_abi()),
             depth_test: descriptor.depth_test.clone(),
@@ -395,11 +579,496 @@ impl<V, R, Tf> GraphicsPipeline<V, R, Tf> {
             scissor_region: descriptor.scissor_region.clone(),
             blending: descriptor.blending.clone(),
             viewport: descriptor.viewport.clone(),
+            depth_only: descriptor.fragment_shader_data.is_none(),
             transform_feedback_data: Arc::new(UnsafeCell::new(None)),
+            label: DebugLabel::default(),
         })
     }
 }
 
+/// Matches the resource slots used by the shader stages against the slots declared by a minimal
+/// resource bindings `layout`.
+///
+/// Calls `f` with the binding index for every shader slot for which the layout declares a matching
+/// slot, and returns an error for the first shader slot for which it does not.
+fn match_resource_slots<F>(
+    layout: &ResourceBindingsLayoutDescriptor,
+    shader_slots: &[ShaderResourceSlotDescriptor],
+    mut f: F,
+) -> Result<(), IncompatibleResources>
+where
+    F: FnMut(&ShaderResourceSlotDescriptor, u32),
+{
+    let bind_groups = layout.bind_groups();
+    let mut iter = bind_groups.iter();
+
+    let bind_group_0 = iter
+        .next()
+        .filter(|g| g.bind_group_index() == 0)
+        .ok_or(IncompatibleResources::MissingBindGroup(0))?;
+
+    let bind_group_1 = iter
+        .next()
+        .filter(|g| g.bind_group_index() == 1)
+        .ok_or(IncompatibleResources::MissingBindGroup(1))?;
+
+    'outer: for slot in shader_slots {
+        if slot.slot_type().is_kind(ResourceSlotKind::UniformBuffer) {
+            for descriptor in bind_group_0.slots() {
+                if &descriptor.slot_identifier == slot.identifier() {
+                    if !descriptor.slot_kind.is_uniform_buffer() {
+                        return Err(IncompatibleResources::ResourceTypeMismatch(
+                            slot.identifier().clone(),
+                        ));
+                    }
+
+                    f(slot, descriptor.slot_index as u32);
+
+                    continue 'outer;
+                }
+            }
+
+            return Err(missing_uniform_block(
+                slot.identifier(),
+                shader_slots,
+                bind_group_0.slots().iter().map(|s| &s.slot_identifier),
+            ));
+        } else if slot.slot_type().is_kind(ResourceSlotKind::SampledTexture) {
+            for descriptor in bind_group_1.slots() {
+                if &descriptor.slot_identifier == slot.identifier() {
+                    if !descriptor.slot_kind.is_sampled_texture() {
+                        return Err(IncompatibleResources::ResourceTypeMismatch(
+                            slot.identifier().clone(),
+                        ));
+                    }
+
+                    f(slot, descriptor.slot_index as u32);
+
+                    continue 'outer;
+                }
+            }
+
+            return Err(IncompatibleResources::MissingResource(
+                slot.identifier().clone(),
+            ));
+        }
+
+        return Err(IncompatibleResources::MissingResource(
+            slot.identifier().clone(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Checks the number of uniform blocks and samplers used by the shader stages against the limits of
+/// the device.
+///
+/// Note that WebGL does not report which shader stage(s) use a sampler; samplers are therefore
+/// only checked against the combined limit for all stages.
+fn check_resource_limits(
+    shader_slots: &[ShaderResourceSlotDescriptor],
+    program: &WebGlProgram,
+    gl: &Gl,
+) -> Result<(), CreateGraphicsPipelineError> {
+    let mut vertex_uniform_blocks = 0;
+    let mut fragment_uniform_blocks = 0;
+    let mut combined_uniform_blocks = 0;
+    let mut combined_samplers = 0;
+
+    let is_referenced_by = |block: u32, stage: u32| {
+        gl.get_active_uniform_block_parameter(program, block, stage)
+            .unwrap()
+            .as_bool()
+            .unwrap_or(false)
+    };
+
+    for slot in shader_slots {
+        match slot.slot_type() {
+            SlotType::UniformBlock(block) => {
+                combined_uniform_blocks += 1;
+
+                if is_referenced_by(block.index(), Gl::UNIFORM_BLOCK_REFERENCED_BY_VERTEX_SHADER) {
+                    vertex_uniform_blocks += 1;
+                }
+
+                if is_referenced_by(
+                    block.index(),
+                    Gl::UNIFORM_BLOCK_REFERENCED_BY_FRAGMENT_SHADER,
+                ) {
+                    fragment_uniform_blocks += 1;
+                }
+            }
+            SlotType::TextureSampler(_) => combined_samplers += 1,
+        }
+    }
+
+    let limits = [
+        (
+            ResourceLimit::VertexUniformBlocks,
+            vertex_uniform_blocks,
+            Gl::MAX_VERTEX_UNIFORM_BLOCKS,
+        ),
+        (
+            ResourceLimit::FragmentUniformBlocks,
+            fragment_uniform_blocks,
+            Gl::MAX_FRAGMENT_UNIFORM_BLOCKS,
+        ),
+        (
+            ResourceLimit::CombinedUniformBlocks,
+            combined_uniform_blocks,
+            Gl::MAX_COMBINED_UNIFORM_BLOCKS,
+        ),
+        (
+            ResourceLimit::CombinedTextureImageUnits,
+            combined_samplers,
+            Gl::MAX_COMBINED_TEXTURE_IMAGE_UNITS,
+        ),
+    ];
+
+    for (limit, needed, parameter) in limits.iter() {
+        let available = gl.get_parameter(*parameter).unwrap().as_f64().unwrap() as u32;
+
+        if *needed > available {
+            return Err(CreateGraphicsPipelineError::ResourceLimitExceeded {
+                limit: *limit,
+                needed: *needed,
+                available,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// A slot declared by a resource bindings layout: its bind group index, identifier, slot index and
+/// slot kind.
+type LayoutSlot = (u32, ResourceSlotIdentifier, u32, ResourceSlotKind);
+
+/// Reflects how the shader stages use each of the `layout_slots` declared by the resource bindings
+/// layout.
+fn resource_slot_usage(
+    layout_slots: Vec<LayoutSlot>,
+    shader_slots: &[ShaderResourceSlotDescriptor],
+    program: &WebGlProgram,
+    gl: &Gl,
+) -> Vec<ResourceSlotUsage> {
+    let is_referenced_by = |block: u32, stage: u32| {
+        gl.get_active_uniform_block_parameter(program, block, stage)
+            .unwrap()
+            .as_bool()
+            .unwrap_or(false)
+    };
+
+    layout_slots
+        .into_iter()
+        .map(|slot| {
+            let (bind_group_index, slot_identifier, slot_index, slot_kind) = slot;
+
+            let shader_slot = shader_slots
+                .iter()
+                .find(|s| s.identifier() == &slot_identifier);
+
+            let visibility = match shader_slot.map(|s| s.slot_type()) {
+                Some(SlotType::UniformBlock(block)) => {
+                    let vertex = is_referenced_by(
+                        block.index(),
+                        Gl::UNIFORM_BLOCK_REFERENCED_BY_VERTEX_SHADER,
+                    );
+                    let fragment = is_referenced_by(
+                        block.index(),
+                        Gl::UNIFORM_BLOCK_REFERENCED_BY_FRAGMENT_SHADER,
+                    );
+
+                    match (vertex, fragment) {
+                        (true, true) => ResourceVisibility::VertexAndFragment,
+                        (true, false) => ResourceVisibility::Vertex,
+                        (false, true) => ResourceVisibility::Fragment,
+                        (false, false) => ResourceVisibility::Unused,
+                    }
+                }
+                Some(SlotType::TextureSampler(_)) => ResourceVisibility::Unspecified,
+                None => ResourceVisibility::Unused,
+            };
+
+            ResourceSlotUsage {
+                bind_group_index,
+                slot_identifier,
+                slot_index,
+                slot_kind,
+                visibility,
+            }
+        })
+        .collect()
+}
+
+/// Returns the error for a uniform block slot declared by the shader stages for which the resource
+/// layout does not declare a matching slot.
+///
+/// If the slot is an element of an array of uniform blocks and the layout does declare other
+/// elements of the array, then the layout's array is shorter than the array used by the shader
+/// stages.
+fn missing_uniform_block<'a, I>(
+    identifier: &ResourceSlotIdentifier,
+    shader_slots: &[ShaderResourceSlotDescriptor],
+    layout_identifiers: I,
+) -> IncompatibleResources
+where
+    I: Iterator<Item = &'a ResourceSlotIdentifier>,
+{
+    if let Some((name, _)) = identifier.array_element() {
+        let layout_len = layout_identifiers
+            .filter(|i| i.array_element().map(|(n, _)| n) == Some(name))
+            .count();
+
+        if layout_len > 0 {
+            let shader_len = shader_slots
+                .iter()
+                .filter(|s| s.slot_type().is_kind(ResourceSlotKind::UniformBuffer))
+                .filter_map(|s| s.identifier().array_element())
+                .filter(|(n, _)| *n == name)
+                .map(|(_, index)| index + 1)
+                .max()
+                .unwrap_or(0);
+
+            return IncompatibleResources::BlockArrayLengthMismatch {
+                identifier: identifier.clone(),
+                shader_len,
+                layout_len,
+            };
+        }
+    }
+
+    IncompatibleResources::MissingResource(identifier.clone())
+}
+
+impl<V, R, Tf> fmt::Debug for GraphicsPipeline<V, R, Tf> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GraphicsPipeline")
+            .field("object_id", &self.object_id)
+            .field("label", &self.label)
+            .field("depth_only", &self.depth_only)
+            .finish()
+    }
+}
+
+/// Cache of graphics pipelines, keyed by the descriptors they were created from.
+///
+/// See [RenderingContext::try_get_or_create_graphics_pipeline].
+pub(crate) struct GraphicsPipelineCache {
+    entries: FnvHashMap<u64, Vec<Rc<dyn Any>>>,
+}
+
+impl GraphicsPipelineCache {
+    pub(crate) fn new() -> Self {
+        GraphicsPipelineCache {
+            entries: FnvHashMap::default(),
+        }
+    }
+
+    /// Returns the cached pipeline that was created from a descriptor that is identical to the
+    /// `descriptor`, or `None` if no such pipeline was cached.
+    pub(crate) fn get<V, R, Tf>(
+        &self,
+        descriptor: &GraphicsPipelineDescriptor<V, R, Tf>,
+    ) -> Option<Rc<GraphicsPipeline<V, R, Tf>>>
+    where
+        V: 'static,
+        R: 'static,
+        Tf: 'static,
+    {
+        self.entries
+            .get(&pipeline_cache_key(descriptor))?
+            .iter()
+            .filter_map(|entry| entry.clone().downcast::<GraphicsPipeline<V, R, Tf>>().ok())
+            .find(|pipeline| pipeline.matches_descriptor(descriptor))
+    }
+
+    /// Caches the `pipeline` that was created from the `descriptor`.
+    pub(crate) fn insert<V, R, Tf>(
+        &mut self,
+        descriptor: &GraphicsPipelineDescriptor<V, R, Tf>,
+        pipeline: Rc<GraphicsPipeline<V, R, Tf>>,
+    ) where
+        V: 'static,
+        R: 'static,
+        Tf: 'static,
+    {
+        self.entries
+            .entry(pipeline_cache_key(descriptor))
+            .or_insert_with(Vec::new)
+            .push(pipeline);
+    }
+
+    /// Removes all pipelines from the cache.
+    ///
+    /// A pipeline is only destroyed once all other references to it have been dropped.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl<V, R, Tf> GraphicsPipeline<V, R, Tf> {
+    /// Whether or not this pipeline was created from a descriptor identical to the `descriptor`.
+    ///
+    /// Compares all state, including the state that is already accounted for by the pipeline cache
+    /// key (see [pipeline_cache_key]), as different descriptors may hash to the same key.
+    fn matches_descriptor(&self, descriptor: &GraphicsPipelineDescriptor<V, R, Tf>) -> bool {
+        let fragment_shader_matches = match &descriptor.fragment_shader_data {
+            Some(data) => Arc::ptr_eq(data, &self.fragment_shader_data),
+            None => self.depth_only,
+        };
+
+        Arc::ptr_eq(&descriptor.vertex_shader_data, &self.vertex_shader_data)
+            && fragment_shader_matches
+            && descriptor.vertex_attribute_layout == self.vertex_attribute_layout
+            && descriptor.resource_bindings_layout == self.resource_bindings_layout
+            && descriptor.transform_feedback_layout == self.transform_feedback_layout
+            && descriptor.scissor_region == self.scissor_region
+            && descriptor.primitive_assembly == self.primitive_assembly
+            && descriptor.depth_test == self.depth_test
+            && descriptor.stencil_test == self.stencil_test
+            && descriptor.blending == self.blending
+            && descriptor.viewport == self.viewport
+    }
+}
+
+/// Computes a hash of the `descriptor`'s pipeline type, shaders and layouts.
+///
+/// Shaders are identified by the address of their data: a cached pipeline holds on to its shader
+/// data, so the address cannot be reused by a different shader while the pipeline is cached.
+fn pipeline_cache_key<V, R, Tf>(descriptor: &GraphicsPipelineDescriptor<V, R, Tf>) -> u64
+where
+    V: 'static,
+    R: 'static,
+    Tf: 'static,
+{
+    let mut hasher = FnvHasher::default();
+
+    TypeId::of::<GraphicsPipeline<V, R, Tf>>().hash(&mut hasher);
+    (Arc::as_ptr(&descriptor.vertex_shader_data) as usize).hash(&mut hasher);
+    descriptor
+        .fragment_shader_data
+        .as_ref()
+        .map(|data| Arc::as_ptr(data) as usize)
+        .hash(&mut hasher);
+    descriptor.vertex_attribute_layout.hash(&mut hasher);
+    descriptor.resource_bindings_layout.key().hash(&mut hasher);
+    descriptor.transform_feedback_layout.hash(&mut hasher);
+    descriptor.scissor_region.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+fn program_key<V, R, Tf>(
+    descriptor: &GraphicsPipelineDescriptor<V, R, Tf>,
+    fragment_shader_data: &FragmentShaderData,
+) -> ProgramKey {
+    let transform_feedback_layout_key =
+        descriptor.transform_feedback_layout.as_ref().map(|layout| {
+            let mut hasher = FnvHasher::default();
+
+            layout.hash(&mut hasher);
+
+            hasher.finish()
+        });
+
+    ProgramKey {
+        vertex_shader_id: descriptor.vertex_shader_data.id().unwrap(),
+        fragment_shader_id: fragment_shader_data.id().unwrap(),
+        resource_bindings_layout: descriptor.resource_bindings_layout.key(),
+        transform_feedback_layout_key,
+        attribute_names_key: descriptor.vertex_attribute_layout.attribute_names_key(),
+    }
+}
+
+/// Task that creates a [GraphicsPipeline] without blocking on the linking of the pipeline's
+/// program, if the `KHR_parallel_shader_compile` extension is available.
+///
+/// See [RenderingContext::try_create_graphics_pipeline_async].
+pub(crate) struct CreateGraphicsPipelineAsyncCommand<Rc, V, R, Tf> {
+    context: Rc,
+    object_id: u64,
+    descriptor: GraphicsPipelineDescriptor<V, R, Tf>,
+    link_started: bool,
+}
+
+impl<Rc, V, R, Tf> CreateGraphicsPipelineAsyncCommand<Rc, V, R, Tf>
+where
+    Rc: RenderingContext + Clone + 'static,
+{
+    pub(crate) fn new(
+        context: Rc,
+        object_id: u64,
+        descriptor: GraphicsPipelineDescriptor<V, R, Tf>,
+    ) -> Self {
+        if descriptor.vertex_shader_data.context_id() != context.id() {
+            panic!("Vertex shader does not belong to the context.");
+        }
+
+        if let Some(fragment_shader_data) = &descriptor.fragment_shader_data {
+            if fragment_shader_data.context_id() != context.id() {
+                panic!("Fragment shader does not belong to the context.");
+            }
+        }
+
+        CreateGraphicsPipelineAsyncCommand {
+            context,
+            object_id,
+            descriptor,
+            link_started: false,
+        }
+    }
+}
+
+unsafe impl<Rc, V, R, Tf> GpuTask<Connection> for CreateGraphicsPipelineAsyncCommand<Rc, V, R, Tf>
+where
+    Rc: RenderingContext + Clone + 'static,
+{
+    type Output = Result<GraphicsPipeline<V, R, Tf>, CreateGraphicsPipelineError>;
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.context.id())
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        let parallel_shader_compile = connection.parallel_shader_compile_supported();
+
+        // Depth-only pipelines use an internal fragment shader that is only allocated when the
+        // pipeline is created; these are always linked synchronously.
+        if let (true, Some(fragment_shader_data)) = (
+            parallel_shader_compile,
+            &self.descriptor.fragment_shader_data,
+        ) {
+            let key = program_key(&self.descriptor, fragment_shader_data);
+            let (gl, state) = unsafe { connection.unpack_mut() };
+            let mut program_cache = state.program_cache_mut();
+
+            if !self.link_started {
+                program_cache.begin_link(
+                    key,
+                    &self.descriptor.vertex_attribute_layout,
+                    &self.descriptor.transform_feedback_layout,
+                    gl,
+                );
+
+                self.link_started = true;
+            }
+
+            if !program_cache.is_link_complete(&key, gl) {
+                return Progress::ContinueFenced;
+            }
+        }
+
+        Progress::Finished(GraphicsPipeline::create(
+            &self.context,
+            self.object_id,
+            connection,
+            &self.descriptor,
+        ))
+    }
+}
+
 impl<V, R, Tf> PartialEq for GraphicsPipeline<V, R, Tf> {
     fn eq(&self, other: &Self) -> bool {
         self.object_id == other.object_id
@@ -439,6 +1108,13 @@ pub struct ShaderLinkingError {
     pub(crate) error: String,
 }
 
+impl ShaderLinkingError {
+    /// Returns the program info log reported by the shader linker.
+    pub fn info_log(&self) -> &str {
+        &self.error
+    }
+}
+
 trait GraphicsPipelineDropper {
     fn drop_graphics_pipeline(
         &self,
@@ -447,16 +1123,13 @@ trait GraphicsPipelineDropper {
     );
 }
 
-impl<T> GraphicsPipelineDropper for T
-where
-    T: RenderingContext,
-{
+impl GraphicsPipelineDropper for DeletionQueue {
     fn drop_graphics_pipeline(
         &self,
         program_id: JsId,
         transform_feedback_data: Arc<UnsafeCell<Option<TransformFeedbackData>>>,
     ) {
-        self.submit(GraphicsPipelineDropCommand {
+        self.schedule(GraphicsPipelineDropCommand {
             program_id,
             transform_feedback_data,
         });
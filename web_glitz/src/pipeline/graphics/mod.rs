@@ -2,40 +2,51 @@ mod blending;
 pub use self::blending::{BlendEquation, BlendFactor, Blending};
 
 mod descriptor;
-pub use self::descriptor::{GraphicsPipelineDescriptor, GraphicsPipelineDescriptorBuilder};
+pub use self::descriptor::{
+    GraphicsPipelineDescriptor, GraphicsPipelineDescriptorBuilder, NoFragmentShader,
+};
 
 mod fragment_test;
 pub use self::fragment_test::{
-    DepthRange, DepthTest, PolygonOffset, StencilOperation, StencilTest, TestFunction,
+    reverse_z_perspective, DepthRange, DepthTest, PolygonOffset, StencilOperation, StencilTest,
+    TestFunction,
 };
 
 pub(crate) mod graphics_pipeline;
 pub use self::graphics_pipeline::{GraphicsPipeline, ShaderLinkingError};
 
+mod pipeline_state;
+pub use self::pipeline_state::PipelineState;
+
 pub(crate) mod primitive_assembly;
 pub use self::primitive_assembly::{CullingMode, LineWidth, PrimitiveAssembly, WindingOrder};
 
+mod stencil_mask;
+pub use self::stencil_mask::StencilMask;
+
 pub(crate) mod shader;
 pub use self::shader::{FragmentShader, VertexShader};
 
 pub(crate) mod transform_feedback;
 pub use self::transform_feedback::{
-    TransformFeedback, TransformFeedbackAttribute, TransformFeedbackAttributeDescriptor,
+    query_primitives_written, QueryPrimitivesWritten, TransformFeedback,
+    TransformFeedbackAttribute, TransformFeedbackAttributeDescriptor,
     TransformFeedbackAttributeIdentifier, TransformFeedbackAttributeType, TransformFeedbackBuffer,
-    TransformFeedbackBufferSlotAttributeAttacher, TransformFeedbackBufferSlotAttributes,
-    TransformFeedbackBufferSlotRef, TransformFeedbackBufferSlots, TransformFeedbackBuffers,
-    TransformFeedbackBuffersEncoding, TransformFeedbackBuffersEncodingContext,
-    TransformFeedbackLayoutAllocationHint, TransformFeedbackLayoutDescriptor,
-    TransformFeedbackLayoutDescriptorBuilder, TypedTransformFeedbackBuffer,
-    TypedTransformFeedbackBuffers, TypedTransformFeedbackLayout,
+    TransformFeedbackBufferMode, TransformFeedbackBufferSlotAttributeAttacher,
+    TransformFeedbackBufferSlotAttributes, TransformFeedbackBufferSlotRef,
+    TransformFeedbackBufferSlots, TransformFeedbackBuffers, TransformFeedbackBuffersEncoding,
+    TransformFeedbackBuffersEncodingContext, TransformFeedbackLayoutAllocationHint,
+    TransformFeedbackLayoutDescriptor, TransformFeedbackLayoutDescriptorBuilder,
+    TypedTransformFeedbackBuffer, TypedTransformFeedbackBuffers, TypedTransformFeedbackLayout,
 };
 
 pub(crate) mod vertex;
 pub use self::vertex::{
-    attribute_format, IncompatibleVertexInputLayout, IndexBuffer, IndexBufferSliceRange,
-    IndexBufferView, IndexBufferViewSliceIndex, IndexData, IndexDataDescriptor, IndexFormat,
-    IndexType, InputRate, TypedVertexBuffer, TypedVertexBuffers, TypedVertexInputLayout, Vertex,
-    VertexAttributeDescriptor, VertexAttributeType, VertexBuffer,
+    attribute_format, AutoIndexBuffer, IncompatibleVertexInputLayout, IndexBuffer,
+    IndexBufferSliceRange, IndexBufferView, IndexBufferViewSliceIndex, IndexData,
+    IndexDataDescriptor, IndexFormat, IndexOutOfRange, IndexType, InputRate, TypedVertexBuffer,
+    TypedVertexBuffers, TypedVertexInputLayout, Vertex, VertexArray, VertexAttributeDescriptor,
+    VertexAttributePointer, VertexAttributePointers, VertexAttributeType, VertexBuffer,
     VertexBufferSlotAttributeAttacher, VertexBufferSlotRef, VertexBuffers, VertexBuffersEncoding,
     VertexBuffersEncodingContext, VertexInputLayoutAllocationHint, VertexInputLayoutDescriptor,
     VertexInputLayoutDescriptorBuilder,
@@ -0,0 +1,75 @@
+use crate::pipeline::graphics::{Blending, DepthTest, StencilTest};
+
+/// Bundles the fixed-function fragment state of a graphics pipeline: the depth test, the stencil
+/// test and blending.
+///
+/// A [PipelineState] may be applied to a [GraphicsPipelineDescriptorBuilder] in one go with
+/// [GraphicsPipelineDescriptorBuilder::state]. This allows an application to define a small set of
+/// named states once and share them between many pipelines that differ only in their shaders and
+/// layouts.
+///
+/// Note that polygon offset is configured as part of the [DepthTest] (see
+/// [DepthTest::polygon_offset]), and that face culling is configured as part of the
+/// [PrimitiveAssembly], as it only applies to triangle topologies; neither is therefore a separate
+/// field of [PipelineState].
+///
+/// # Example
+///
+/// ```
+/// # use web_glitz::pipeline::graphics::{VertexShader, FragmentShader};
+/// # fn wrapper(vertex_shader: VertexShader, fragment_shader: FragmentShader) {
+/// use web_glitz::pipeline::graphics::{
+///     BlendFactor, Blending, CullingMode, DepthTest, GraphicsPipelineDescriptor, PipelineState,
+///     PrimitiveAssembly, WindingOrder,
+/// };
+///
+/// let opaque = PipelineState {
+///     depth_test: Some(DepthTest::default()),
+///     ..Default::default()
+/// };
+///
+/// let transparent = PipelineState {
+///     depth_test: Some(DepthTest {
+///         write: false,
+///         ..Default::default()
+///     }),
+///     blending: Some(Blending {
+///         source_color_factor: BlendFactor::SourceAlpha,
+///         destination_color_factor: BlendFactor::OneMinusSourceAlpha,
+///         ..Default::default()
+///     }),
+///     ..Default::default()
+/// };
+///
+/// let graphics_pipeline_descriptor = GraphicsPipelineDescriptor::begin()
+///     .vertex_shader(&vertex_shader)
+///     .primitive_assembly(PrimitiveAssembly::Triangles {
+///         winding_order: WindingOrder::CounterClockwise,
+///         face_culling: CullingMode::None
+///     })
+///     .fragment_shader(&fragment_shader)
+///     .state(&transparent)
+///     .finish();
+/// # }
+/// ```
+///
+/// [GraphicsPipelineDescriptorBuilder]: crate::pipeline::graphics::GraphicsPipelineDescriptorBuilder
+/// [GraphicsPipelineDescriptorBuilder::state]: crate::pipeline::graphics::GraphicsPipelineDescriptorBuilder::state
+/// [PrimitiveAssembly]: crate::pipeline::graphics::PrimitiveAssembly
+#[derive(Clone, PartialEq, Default, Debug)]
+pub struct PipelineState {
+    /// The depth test, or `None` if the depth test is disabled.
+    ///
+    /// See [DepthTest] for details on the depth test.
+    pub depth_test: Option<DepthTest>,
+
+    /// The stencil test, or `None` if the stencil test is disabled.
+    ///
+    /// See [StencilTest] for details on the stencil test.
+    pub stencil_test: Option<StencilTest>,
+
+    /// The blending configuration, or `None` if blending is disabled.
+    ///
+    /// See [Blending] for details on blending.
+    pub blending: Option<Blending>,
+}
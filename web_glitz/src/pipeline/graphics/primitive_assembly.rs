@@ -223,6 +223,15 @@ impl Topology {
             Topology::TriangleFan => Gl::TRIANGLE_FAN,
         }
     }
+
+    /// Returns the number of triangles assembled from `vertex_count` vertices with this topology.
+    pub(crate) fn triangle_count(&self, vertex_count: usize) -> usize {
+        match self {
+            Topology::Triangle => vertex_count / 3,
+            Topology::TriangleStrip | Topology::TriangleFan => vertex_count.saturating_sub(2),
+            _ => 0,
+        }
+    }
 }
 
 /// Defines the line width used by a [Rasterizer].
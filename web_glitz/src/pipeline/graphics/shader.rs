@@ -1,11 +1,12 @@
 use std::borrow::Borrow;
 use std::cell::UnsafeCell;
+use std::fmt;
 use std::sync::Arc;
 
 use wasm_bindgen::JsCast;
 use web_sys::WebGl2RenderingContext as Gl;
 
-use crate::runtime::{Connection, RenderingContext, ShaderCompilationError};
+use crate::runtime::{Connection, DeletionQueue, RenderingContext, ShaderCompilationError};
 use crate::task::{ContextId, GpuTask, Progress};
 use crate::util::JsId;
 use std::hash::{Hash, Hasher};
@@ -40,6 +41,14 @@ impl Hash for VertexShader {
     }
 }
 
+impl fmt::Debug for VertexShader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("VertexShader")
+            .field("object_id", &self.object_id)
+            .finish()
+    }
+}
+
 /// The programmable stage in the rendering pipeline that handles the processing of individual
 /// fragments generated by rasterization into a set of colors and a single depth value.
 ///
@@ -78,8 +87,17 @@ impl Hash for FragmentShader {
     }
 }
 
+impl fmt::Debug for FragmentShader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FragmentShader")
+            .field("object_id", &self.object_id)
+            .finish()
+    }
+}
+
 pub(crate) struct VertexShaderData {
     id: UnsafeCell<Option<JsId>>,
+    object_id: u64,
     context_id: u64,
     dropper: Box<dyn VertexShaderObjectDropper>,
 }
@@ -94,8 +112,18 @@ impl VertexShaderData {
     }
 }
 
+impl fmt::Debug for VertexShaderData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("VertexShaderData")
+            .field("object_id", &self.object_id)
+            .field("context_id", &self.context_id)
+            .finish()
+    }
+}
+
 pub(crate) struct FragmentShaderData {
     id: UnsafeCell<Option<JsId>>,
+    object_id: u64,
     context_id: u64,
     dropper: Box<dyn FragmentShaderObjectDropper>,
 }
@@ -110,16 +138,22 @@ impl FragmentShaderData {
     }
 }
 
+impl fmt::Debug for FragmentShaderData {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FragmentShaderData")
+            .field("object_id", &self.object_id)
+            .field("context_id", &self.context_id)
+            .finish()
+    }
+}
+
 trait VertexShaderObjectDropper {
     fn drop_shader_object(&self, id: JsId);
 }
 
-impl<T> VertexShaderObjectDropper for T
-where
-    T: RenderingContext,
-{
+impl VertexShaderObjectDropper for DeletionQueue {
     fn drop_shader_object(&self, id: JsId) {
-        self.submit(VertexShaderDropCommand { id });
+        self.schedule(VertexShaderDropCommand { id });
     }
 }
 
@@ -135,12 +169,9 @@ trait FragmentShaderObjectDropper {
     fn drop_shader_object(&self, id: JsId);
 }
 
-impl<T> FragmentShaderObjectDropper for T
-where
-    T: RenderingContext,
-{
+impl FragmentShaderObjectDropper for DeletionQueue {
     fn drop_shader_object(&self, id: JsId) {
-        self.submit(FragmentShaderDropCommand { id });
+        self.schedule(FragmentShaderDropCommand { id });
     }
 }
 
@@ -168,8 +199,9 @@ where
     {
         let data = Arc::new(VertexShaderData {
             id: UnsafeCell::new(None),
+            object_id,
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
+            dropper: Box::new(context.deletion_queue()),
         });
 
         VertexShaderAllocateCommand {
@@ -236,8 +268,9 @@ where
     {
         let data = Arc::new(FragmentShaderData {
             id: UnsafeCell::new(None),
+            object_id,
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
+            dropper: Box::new(context.deletion_queue()),
         });
 
         FragmentShaderAllocateCommand {
@@ -305,7 +338,7 @@ unsafe impl GpuTask<Connection> for VertexShaderDropCommand {
 
         state
             .program_cache_mut()
-            .remove_vertex_shader_dependent(self.id);
+            .remove_vertex_shader_dependent(gl, self.id);
         gl.delete_shader(Some(&value.unchecked_into()));
 
         Progress::Finished(())
@@ -329,7 +362,7 @@ unsafe impl GpuTask<Connection> for FragmentShaderDropCommand {
 
         state
             .program_cache_mut()
-            .remove_fragment_shader_dependent(self.id);
+            .remove_fragment_shader_dependent(gl, self.id);
         gl.delete_shader(Some(&value.unchecked_into()));
 
         Progress::Finished(())
@@ -0,0 +1,252 @@
+use crate::pipeline::graphics::{StencilOperation, StencilTest, TestFunction};
+
+// WebGL 2.0 stencil buffers always store 8-bit stencil values.
+const MAX_STENCIL_VALUE: u32 = 0xff;
+
+/// Helper for clipping draws to nested regions (e.g. nested scroll containers in a user
+/// interface) with the stencil buffer.
+///
+/// A [StencilMask] tracks the current nesting depth of the clip regions. The stencil buffer is
+/// expected to be cleared to `0` at the start of the render pass. Every fragment covered by all
+/// regions that are currently pushed then has a stencil value equal to the current depth, and every
+/// other fragment has a smaller stencil value. Three kinds of pipeline cooperate to maintain this:
+///
+/// - A "push" pipeline with the [StencilTest] returned by [push_test]. Drawing a region's geometry
+///   with this pipeline increments the stencil value of the fragments inside the region that are
+///   also inside the current clip region.
+/// - A "pop" pipeline with the [StencilTest] returned by [pop_test]. Drawing the same region's
+///   geometry again with this pipeline undoes the push.
+/// - Any number of "content" pipelines with the [StencilTest] returned by [clip_test]. Fragments
+///   drawn with these pipelines are discarded unless they lie inside the current clip region.
+///
+/// The mask only tracks the stencil reference value that each draw should use. Set it with
+/// [GraphicsPipelineTaskBuilder::stencil_reference] before the draw:
+///
+/// - before drawing a region with the push pipeline, use the value returned by [push];
+/// - before drawing a region with the pop pipeline, use the value returned by [pop];
+/// - before drawing content, use the value returned by [reference].
+///
+/// The push and pop pipelines typically do not need a fragment shader: a pipeline without a
+/// fragment shader does not write to the color buffers, so only the stencil buffer is updated (see
+/// [GraphicsPipelineDescriptorBuilder::no_fragment_shader]).
+///
+/// # Example
+///
+/// ```
+/// # use web_glitz::buffer::Buffer;
+/// # use web_glitz::pipeline::graphics::{GraphicsPipeline, Vertex};
+/// # use web_glitz::pipeline::resources::BindGroup;
+/// # use web_glitz::rendering::{DefaultDepthStencilBuffer, DefaultRGBBuffer, DefaultRenderTarget};
+/// # use web_glitz::runtime::RenderingContext;
+/// # fn wrapper<Rc, V>(
+/// #     context: &Rc,
+/// #     render_target: &mut DefaultRenderTarget<DefaultRGBBuffer, DefaultDepthStencilBuffer>,
+/// #     push_pipeline: &GraphicsPipeline<V, (), ()>,
+/// #     pop_pipeline: &GraphicsPipeline<V, (), ()>,
+/// #     content_pipeline: &GraphicsPipeline<V, (), ()>,
+/// #     scroll_view: &Buffer<[V]>,
+/// #     content: &Buffer<[V]>,
+/// # ) where Rc: RenderingContext, V: Vertex {
+/// use web_glitz::pipeline::graphics::StencilMask;
+/// use web_glitz::task::sequence3;
+///
+/// // The pipelines were created with `StencilMask::push_test()`, `StencilMask::pop_test()` and
+/// // `StencilMask::clip_test()` as their stencil tests respectively.
+///
+/// let mut mask = StencilMask::new();
+/// let resources = BindGroup::empty();
+///
+/// let push_reference = mask.push();
+/// let content_reference = mask.reference();
+/// let pop_reference = mask.pop();
+///
+/// let render_pass = render_target.create_render_pass(|framebuffer| {
+///     sequence3(
+///         framebuffer.pipeline_task(push_pipeline, |active_pipeline| {
+///             active_pipeline.task_builder()
+///                 .stencil_reference(push_reference)
+///                 .bind_vertex_buffers(scroll_view)
+///                 .bind_resources(&resources)
+///                 .draw(scroll_view.len(), 1)
+///                 .finish()
+///         }),
+///         framebuffer.pipeline_task(content_pipeline, |active_pipeline| {
+///             active_pipeline.task_builder()
+///                 .stencil_reference(content_reference)
+///                 .bind_vertex_buffers(content)
+///                 .bind_resources(&resources)
+///                 .draw(content.len(), 1)
+///                 .finish()
+///         }),
+///         framebuffer.pipeline_task(pop_pipeline, |active_pipeline| {
+///             active_pipeline.task_builder()
+///                 .stencil_reference(pop_reference)
+///                 .bind_vertex_buffers(scroll_view)
+///                 .bind_resources(&resources)
+///                 .draw(scroll_view.len(), 1)
+///                 .finish()
+///         }),
+///     )
+/// });
+///
+/// context.submit(render_pass);
+/// # }
+/// ```
+///
+/// Note that the render target in this example must have been created with a stencil buffer that
+/// is cleared to `0` when the render pass begins (e.g. with [LoadOp::Clear]).
+///
+/// [push_test]: StencilMask::push_test
+/// [pop_test]: StencilMask::pop_test
+/// [clip_test]: StencilMask::clip_test
+/// [push]: StencilMask::push
+/// [pop]: StencilMask::pop
+/// [reference]: StencilMask::reference
+/// [GraphicsPipelineTaskBuilder::stencil_reference]: crate::rendering::GraphicsPipelineTaskBuilder::stencil_reference
+/// [GraphicsPipelineDescriptorBuilder::no_fragment_shader]: crate::pipeline::graphics::GraphicsPipelineDescriptorBuilder::no_fragment_shader
+/// [LoadOp::Clear]: crate::rendering::LoadOp::Clear
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct StencilMask {
+    depth: u32,
+}
+
+impl StencilMask {
+    /// Creates a new [StencilMask] with no clip regions pushed.
+    pub fn new() -> Self {
+        StencilMask { depth: 0 }
+    }
+
+    /// The number of clip regions that are currently pushed.
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// The stencil reference value for draws that should be clipped to the current clip region,
+    /// to be used with a pipeline that uses the [clip_test].
+    ///
+    /// [clip_test]: StencilMask::clip_test
+    pub fn reference(&self) -> u32 {
+        self.depth
+    }
+
+    /// Pushes a new clip region and returns the stencil reference value for drawing the region's
+    /// geometry with a pipeline that uses the [push_test].
+    ///
+    /// The new clip region is the intersection of the region that is drawn and the current clip
+    /// region.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `255` clip regions are already pushed (WebGL 2.0 stencil buffers always store
+    /// 8-bit stencil values).
+    ///
+    /// [push_test]: StencilMask::push_test
+    pub fn push(&mut self) -> u32 {
+        if self.depth == MAX_STENCIL_VALUE {
+            panic!("Cannot push more than 255 clip regions onto a stencil mask.");
+        }
+
+        let reference = self.depth;
+
+        self.depth += 1;
+
+        reference
+    }
+
+    /// Pops the current clip region and returns the stencil reference value for drawing the
+    /// region's geometry again with a pipeline that uses the [pop_test].
+    ///
+    /// The geometry drawn to pop a region must cover the same fragments as the geometry that was
+    /// drawn when the region was pushed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no clip regions are pushed.
+    ///
+    /// [pop_test]: StencilMask::pop_test
+    pub fn pop(&mut self) -> u32 {
+        if self.depth == 0 {
+            panic!("Cannot pop a clip region from an empty stencil mask.");
+        }
+
+        let reference = self.depth;
+
+        self.depth -= 1;
+
+        reference
+    }
+
+    /// Returns the [StencilTest] for a pipeline that pushes clip regions.
+    ///
+    /// Increments the stencil value of fragments for which the stencil value is equal to the
+    /// reference value, for both front-facing and back-facing fragments.
+    pub fn push_test() -> StencilTest {
+        Self::stencil_test(StencilOperation::Increment)
+    }
+
+    /// Returns the [StencilTest] for a pipeline that pops clip regions.
+    ///
+    /// Decrements the stencil value of fragments for which the stencil value is equal to the
+    /// reference value, for both front-facing and back-facing fragments.
+    pub fn pop_test() -> StencilTest {
+        Self::stencil_test(StencilOperation::Decrement)
+    }
+
+    /// Returns the [StencilTest] for a pipeline that draws content clipped to the current clip
+    /// region.
+    ///
+    /// Passes fragments for which the stencil value is equal to the reference value, and does not
+    /// update the stencil buffer.
+    pub fn clip_test() -> StencilTest {
+        Self::stencil_test(StencilOperation::Keep)
+    }
+
+    fn stencil_test(pass_operation: StencilOperation) -> StencilTest {
+        StencilTest {
+            test_function_front: TestFunction::Equal,
+            pass_operation_front: pass_operation,
+            test_function_back: TestFunction::Equal,
+            pass_operation_back: pass_operation,
+            test_mask_front: MAX_STENCIL_VALUE,
+            test_mask_back: MAX_STENCIL_VALUE,
+            write_mask_front: MAX_STENCIL_VALUE,
+            write_mask_back: MAX_STENCIL_VALUE,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_pop_references() {
+        let mut mask = StencilMask::new();
+
+        assert_eq!(mask.push(), 0);
+        assert_eq!(mask.reference(), 1);
+        assert_eq!(mask.push(), 1);
+        assert_eq!(mask.reference(), 2);
+        assert_eq!(mask.pop(), 2);
+        assert_eq!(mask.reference(), 1);
+        assert_eq!(mask.pop(), 1);
+        assert_eq!(mask.depth(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pop_empty() {
+        StencilMask::new().pop();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_push_overflow() {
+        let mut mask = StencilMask::new();
+
+        for _ in 0..256 {
+            mask.push();
+        }
+    }
+}
@@ -102,6 +102,7 @@ impl Into<TransformFeedbackLayoutDescriptor> for () {
     fn into(self) -> TransformFeedbackLayoutDescriptor {
         TransformFeedbackLayoutDescriptor {
             layout: Vec::new(), // Won't allocate, see [Vec::new].
+            buffer_mode: TransformFeedbackBufferMode::Interleaved,
         }
     }
 }
@@ -237,12 +238,135 @@ pub enum TransformFeedbackAttributeType {
     UnsignedIntegerVector4,
 }
 
+impl TransformFeedbackAttributeType {
+    pub(crate) fn from_type_id(id: u32) -> Option<Self> {
+        match id {
+            Gl::FLOAT => Some(TransformFeedbackAttributeType::Float),
+            Gl::FLOAT_VEC2 => Some(TransformFeedbackAttributeType::FloatVector2),
+            Gl::FLOAT_VEC3 => Some(TransformFeedbackAttributeType::FloatVector3),
+            Gl::FLOAT_VEC4 => Some(TransformFeedbackAttributeType::FloatVector4),
+            Gl::FLOAT_MAT2 => Some(TransformFeedbackAttributeType::FloatMatrix2x2),
+            Gl::FLOAT_MAT2X3 => Some(TransformFeedbackAttributeType::FloatMatrix2x3),
+            Gl::FLOAT_MAT2X4 => Some(TransformFeedbackAttributeType::FloatMatrix2x4),
+            Gl::FLOAT_MAT3X2 => Some(TransformFeedbackAttributeType::FloatMatrix3x2),
+            Gl::FLOAT_MAT3 => Some(TransformFeedbackAttributeType::FloatMatrix3x3),
+            Gl::FLOAT_MAT3X4 => Some(TransformFeedbackAttributeType::FloatMatrix3x4),
+            Gl::FLOAT_MAT4X2 => Some(TransformFeedbackAttributeType::FloatMatrix4x2),
+            Gl::FLOAT_MAT4X3 => Some(TransformFeedbackAttributeType::FloatMatrix4x3),
+            Gl::FLOAT_MAT4 => Some(TransformFeedbackAttributeType::FloatMatrix4x4),
+            Gl::INT => Some(TransformFeedbackAttributeType::Integer),
+            Gl::INT_VEC2 => Some(TransformFeedbackAttributeType::IntegerVector2),
+            Gl::INT_VEC3 => Some(TransformFeedbackAttributeType::IntegerVector3),
+            Gl::INT_VEC4 => Some(TransformFeedbackAttributeType::IntegerVector4),
+            Gl::UNSIGNED_INT => Some(TransformFeedbackAttributeType::UnsignedInteger),
+            Gl::UNSIGNED_INT_VEC2 => Some(TransformFeedbackAttributeType::UnsignedIntegerVector2),
+            Gl::UNSIGNED_INT_VEC3 => Some(TransformFeedbackAttributeType::UnsignedIntegerVector3),
+            Gl::UNSIGNED_INT_VEC4 => Some(TransformFeedbackAttributeType::UnsignedIntegerVector4),
+            _ => None,
+        }
+    }
+}
+
+/// Enumerates the modes in which the transform stage output attributes of a graphics pipeline may
+/// be recorded into transform feedback buffers.
+///
+/// See [GraphicsPipelineDescriptorBuilder::transform_feedback_varyings].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TransformFeedbackBufferMode {
+    /// All output attributes are recorded into a single buffer, where the attributes for a vertex
+    /// are stored adjacently in the order in which they were specified.
+    Interleaved,
+
+    /// Each output attribute is recorded into a separate buffer, in the order in which the
+    /// attributes were specified.
+    ///
+    /// Note that WebGL 2.0 only guarantees support for recording up to 4 attributes in this mode.
+    Separate,
+}
+
+impl TransformFeedbackBufferMode {
+    pub(crate) fn id(&self) -> u32 {
+        match self {
+            TransformFeedbackBufferMode::Interleaved => Gl::INTERLEAVED_ATTRIBS,
+            TransformFeedbackBufferMode::Separate => Gl::SEPARATE_ATTRIBS,
+        }
+    }
+}
+
 #[derive(Clone, Hash, PartialEq, Eq, Debug)]
 pub struct TransformFeedbackLayoutDescriptor {
     layout: Vec<LayoutElement>,
+    buffer_mode: TransformFeedbackBufferMode,
 }
 
 impl TransformFeedbackLayoutDescriptor {
+    /// Creates a descriptor that records the `varyings` in the given `buffer_mode`, where the types
+    /// of the varyings are left unspecified.
+    ///
+    /// The types of the varyings are resolved by reflecting on the program after it has been
+    /// linked, see [resolve].
+    pub(crate) fn from_varyings(
+        varyings: &[&str],
+        buffer_mode: TransformFeedbackBufferMode,
+    ) -> Self {
+        let mut layout = Vec::with_capacity(varyings.len() * 2);
+
+        for varying in varyings {
+            if buffer_mode == TransformFeedbackBufferMode::Separate && layout.len() > 0 {
+                layout.push(LayoutElement::NextBindSlot);
+            }
+
+            layout.push(LayoutElement::NextVarying(varying.to_string().into()));
+        }
+
+        TransformFeedbackLayoutDescriptor {
+            layout,
+            buffer_mode,
+        }
+    }
+
+    pub(crate) fn buffer_mode(&self) -> TransformFeedbackBufferMode {
+        self.buffer_mode
+    }
+
+    /// Returns a copy of this descriptor in which the types of any varyings for which the type was
+    /// left unspecified (see [from_varyings]) are replaced with the types reported by the `program`.
+    ///
+    /// Should only be called after [check_compatibility] succeeded for the `program`.
+    pub(crate) fn resolve(&self, program: &WebGlProgram, gl: &Gl) -> Self {
+        let mut index = 0;
+
+        let layout = self
+            .layout
+            .iter()
+            .map(|element| match element {
+                LayoutElement::NextVarying(ident) => {
+                    let info = gl.get_transform_feedback_varying(program, index).unwrap();
+
+                    index += 1;
+
+                    LayoutElement::NextAttribute(TransformFeedbackAttributeDescriptor {
+                        ident: ident.clone(),
+                        attribute_type: TransformFeedbackAttributeType::from_type_id(info.type_())
+                            .unwrap(),
+                        size: info.size() as usize,
+                    })
+                }
+                LayoutElement::NextAttribute(attribute) => {
+                    index += 1;
+
+                    LayoutElement::NextAttribute(attribute.clone())
+                }
+                LayoutElement::NextBindSlot => LayoutElement::NextBindSlot,
+            })
+            .collect();
+
+        TransformFeedbackLayoutDescriptor {
+            layout,
+            buffer_mode: self.buffer_mode,
+        }
+    }
+
     pub(crate) fn check_compatibility(
         &self,
         program: &WebGlProgram,
@@ -250,12 +374,24 @@ impl TransformFeedbackLayoutDescriptor {
     ) -> Result<(), CreateGraphicsPipelineError> {
         let mut index = 0;
 
-        for group in self.buffer_slots() {
-            for attribute in group.attributes() {
+        for element in self.layout.iter() {
+            if let LayoutElement::NextVarying(ident) = element {
+                let info = gl.get_transform_feedback_varying(program, index).unwrap();
+
+                if info.name() != ident.deref()
+                    || TransformFeedbackAttributeType::from_type_id(info.type_()).is_none()
+                {
+                    return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                        ident.to_string(),
+                    ));
+                }
+
+                index += 1;
+            } else if let LayoutElement::NextAttribute(attribute) = element {
                 let info = gl.get_transform_feedback_varying(program, index).unwrap();
 
                 if info.size() != attribute.size as i32 {
-                    return Err(CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
+                    return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
                         attribute.ident.to_string(),
                     ));
                 }
@@ -263,191 +399,149 @@ impl TransformFeedbackLayoutDescriptor {
                 match attribute.attribute_type {
                     TransformFeedbackAttributeType::Float => {
                         if info.type_() != Gl::FLOAT {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::FloatVector2 => {
                         if info.type_() != Gl::FLOAT_VEC2 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::FloatVector3 => {
                         if info.type_() != Gl::FLOAT_VEC3 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::FloatVector4 => {
                         if info.type_() != Gl::FLOAT_VEC4 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::FloatMatrix2x2 => {
                         if info.type_() != Gl::FLOAT_MAT2 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::FloatMatrix2x3 => {
                         if info.type_() != Gl::FLOAT_MAT2X3 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::FloatMatrix2x4 => {
                         if info.type_() != Gl::FLOAT_MAT2X4 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::FloatMatrix3x2 => {
                         if info.type_() != Gl::FLOAT_MAT3X2 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::FloatMatrix3x3 => {
                         if info.type_() != Gl::FLOAT_MAT3 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::FloatMatrix3x4 => {
                         if info.type_() != Gl::FLOAT_MAT3X4 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::FloatMatrix4x2 => {
                         if info.type_() != Gl::FLOAT_MAT4X2 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::FloatMatrix4x3 => {
                         if info.type_() != Gl::FLOAT_MAT4X3 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::FloatMatrix4x4 => {
                         if info.type_() != Gl::FLOAT_MAT4 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::Integer => {
                         if info.type_() != Gl::INT {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::IntegerVector2 => {
                         if info.type_() != Gl::INT_VEC2 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::IntegerVector3 => {
                         if info.type_() != Gl::INT_VEC3 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::IntegerVector4 => {
                         if info.type_() != Gl::INT_VEC4 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::UnsignedInteger => {
                         if info.type_() != Gl::UNSIGNED_INT {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::UnsignedIntegerVector2 => {
                         if info.type_() != Gl::UNSIGNED_INT_VEC2 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::UnsignedIntegerVector3 => {
                         if info.type_() != Gl::UNSIGNED_INT_VEC3 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                     TransformFeedbackAttributeType::UnsignedIntegerVector4 => {
                         if info.type_() != Gl::UNSIGNED_INT_VEC4 {
-                            return Err(
-                                CreateGraphicsPipelineError::TransformFeedbackTypeMismatch(
-                                    attribute.ident.to_string(),
-                                ),
-                            );
+                            return Err(CreateGraphicsPipelineError::TransformFeedbackMismatch(
+                                attribute.ident.to_string(),
+                            ));
                         }
                     }
                 }
@@ -544,6 +638,9 @@ impl<'a> Iterator for TransformFeedbackBufferSlotAttributes<'a> {
 #[derive(Clone, PartialEq, Hash, Eq, Debug)]
 enum LayoutElement {
     NextAttribute(TransformFeedbackAttributeDescriptor),
+    // An attribute for which the type is only known after the program is linked. Never occurs in
+    // the layout of a graphics pipeline, see [TransformFeedbackLayoutDescriptor::resolve].
+    NextVarying(TransformFeedbackAttributeIdentifier),
     NextBindSlot,
 }
 
@@ -584,6 +681,7 @@ pub struct TransformFeedbackLayoutAllocationHint {
 ///
 /// let layout_descriptor = builder.finish();
 /// ```
+#[derive(Clone, Debug)]
 pub struct TransformFeedbackLayoutDescriptorBuilder {
     layout: Vec<LayoutElement>,
 }
@@ -619,6 +717,7 @@ impl TransformFeedbackLayoutDescriptorBuilder {
     pub fn finish(self) -> TransformFeedbackLayoutDescriptor {
         TransformFeedbackLayoutDescriptor {
             layout: self.layout,
+            buffer_mode: TransformFeedbackBufferMode::Interleaved,
         }
     }
 }
@@ -796,18 +895,26 @@ impl<'a> Serialize for TransformFeedbackVaryings<'a> {
     where
         S: Serializer,
     {
-        let TransformFeedbackVaryings(layout) = self;
-        let layout = &layout.layout;
+        let TransformFeedbackVaryings(descriptor) = self;
+        let layout = &descriptor.layout;
 
-        let mut seq = serializer.serialize_seq(Some(layout.len()))?;
+        // In separate mode each varying is implicitly recorded into the next buffer.
+        let separate = descriptor.buffer_mode == TransformFeedbackBufferMode::Separate;
+
+        let mut seq = serializer.serialize_seq(None)?;
 
         for element in layout {
             match element {
                 LayoutElement::NextAttribute(descriptor) => {
                     seq.serialize_element(&descriptor.ident)?;
                 }
+                LayoutElement::NextVarying(ident) => {
+                    seq.serialize_element(ident)?;
+                }
                 LayoutElement::NextBindSlot => {
-                    seq.serialize_element("gl_NextBuffer")?;
+                    if !separate {
+                        seq.serialize_element("gl_NextBuffer")?;
+                    }
                 }
             }
         }
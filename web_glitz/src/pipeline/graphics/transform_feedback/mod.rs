@@ -2,12 +2,16 @@ pub(crate) mod layout_descriptor;
 pub use self::layout_descriptor::{
     TransformFeedback, TransformFeedbackAttribute, TransformFeedbackAttributeDescriptor,
     TransformFeedbackAttributeIdentifier, TransformFeedbackAttributeType,
-    TransformFeedbackBufferSlotAttributeAttacher, TransformFeedbackBufferSlotAttributes,
-    TransformFeedbackBufferSlotRef, TransformFeedbackBufferSlots,
-    TransformFeedbackLayoutAllocationHint, TransformFeedbackLayoutDescriptor,
-    TransformFeedbackLayoutDescriptorBuilder, TypedTransformFeedbackLayout,
+    TransformFeedbackBufferMode, TransformFeedbackBufferSlotAttributeAttacher,
+    TransformFeedbackBufferSlotAttributes, TransformFeedbackBufferSlotRef,
+    TransformFeedbackBufferSlots, TransformFeedbackLayoutAllocationHint,
+    TransformFeedbackLayoutDescriptor, TransformFeedbackLayoutDescriptorBuilder,
+    TypedTransformFeedbackLayout,
 };
 
+mod primitives_written;
+pub use self::primitives_written::{query_primitives_written, QueryPrimitivesWritten};
+
 pub(crate) mod transform_feedback_buffers;
 pub use self::transform_feedback_buffers::{
     TransformFeedbackBuffer, TransformFeedbackBuffers, TransformFeedbackBuffersEncoding,
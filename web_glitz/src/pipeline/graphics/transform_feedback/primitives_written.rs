@@ -0,0 +1,234 @@
+use std::mem;
+
+use web_sys::{WebGl2RenderingContext as Gl, WebGlQuery};
+
+use crate::runtime::{Connection, DeletionQueue};
+use crate::task::{ContextId, GpuTask, Progress, ValidationContext};
+
+/// Wraps the `task` in a query that counts the number of primitives that were written to
+/// transform feedback buffers while the `task` was executing, then uses `f` to create a follow-up
+/// task from the `task`'s output and the number of primitives written.
+///
+/// WebGL 2.0 does not support indirect drawing: the instance count for a draw command must be known
+/// when the draw command is issued. This combinator allows the instance count for a draw command to
+/// be derived from the output of a preceding transform feedback pass without submitting the
+/// follow-up task manually: the query result is read back by the runtime as soon as it becomes
+/// available (the resulting task will fence until it does) and `f` is invoked immediately after.
+/// The output of the resulting task is the output of the task returned by `f`.
+///
+/// # Example
+///
+/// ```
+/// # use web_glitz::rendering::{DefaultRGBBuffer, DefaultRenderTarget};
+/// # use web_glitz::runtime::{Connection, RenderingContext};
+/// # use web_glitz::buffer::Buffer;
+/// # use web_glitz::pipeline::graphics::{GraphicsPipeline, Vertex};
+/// # use web_glitz::task::GpuTask;
+/// # fn wrapper<Rc, T, V>(
+/// #     context: &Rc,
+/// #     feedback_pass: T,
+/// #     mut render_target: DefaultRenderTarget<DefaultRGBBuffer, ()>,
+/// #     vertex_buffers: Buffer<[V]>,
+/// #     graphics_pipeline: GraphicsPipeline<V, (), ()>
+/// # )
+/// # where
+/// #     Rc: RenderingContext,
+/// #     T: GpuTask<Connection, Output = ()> + 'static,
+/// #     V: Vertex + 'static,
+/// # {
+/// use web_glitz::pipeline::graphics::query_primitives_written;
+///
+/// let task = query_primitives_written(feedback_pass, move |_, primitives_written| {
+///     render_target.create_render_pass(|framebuffer| {
+///         framebuffer.pipeline_task(&graphics_pipeline, |active_pipeline| {
+///             active_pipeline.task_builder()
+///                 .bind_vertex_buffers(&vertex_buffers)
+///                 .bind_resources(())
+///                 .draw(6, primitives_written as usize)
+///                 .finish()
+///         })
+///     })
+/// });
+///
+/// context.submit(task);
+/// # }
+/// ```
+///
+/// Here `feedback_pass` is a task that records transform feedback (see
+/// [GraphicsPipeline::record_transform_feedback]), for example a particle simulation that emits a
+/// varying number of particles. The follow-up render pass draws one instance for each primitive
+/// written by the `feedback_pass`.
+///
+/// If the resulting task is dropped before the query result became available (e.g. because it was
+/// cancelled, see [cancellable]), then the query object is deleted the next time a task is
+/// submitted to the context.
+///
+/// # Panics
+///
+/// The resulting task panics if the `task` does not finish immediately when it is progressed (if
+/// the `task` contains commands that require fencing).
+///
+/// [cancellable]: crate::task::cancellable
+pub fn query_primitives_written<T, F, U>(task: T, f: F) -> QueryPrimitivesWritten<T, F, U>
+where
+    T: GpuTask<Connection>,
+    F: FnOnce(T::Output, u32) -> U,
+    U: GpuTask<Connection>,
+{
+    QueryPrimitivesWritten {
+        context_id: task.context_id(),
+        state: QueryState::Pending(task, f),
+        deletion_queue: None,
+    }
+}
+
+/// Task returned by [query_primitives_written].
+///
+/// See [query_primitives_written] for details.
+pub struct QueryPrimitivesWritten<T, F, U>
+where
+    T: GpuTask<Connection>,
+{
+    context_id: ContextId,
+    state: QueryState<T, F, U, T::Output>,
+    deletion_queue: Option<DeletionQueue>,
+}
+
+enum QueryState<T, F, U, O> {
+    Pending(T, F),
+    Querying(WebGlQuery, O, F),
+    Continuation(U),
+    Finished,
+}
+
+unsafe impl<T, F, U> GpuTask<Connection> for QueryPrimitivesWritten<T, F, U>
+where
+    T: GpuTask<Connection>,
+    F: FnOnce(T::Output, u32) -> U,
+    U: GpuTask<Connection>,
+{
+    type Output = U::Output;
+
+    fn context_id(&self) -> ContextId {
+        self.context_id
+    }
+
+    fn validate(&self, context: &mut ValidationContext) {
+        match &self.state {
+            QueryState::Pending(task, _) => task.validate(context),
+            QueryState::Continuation(task) => task.validate(context),
+            _ => (),
+        }
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        loop {
+            match mem::replace(&mut self.state, QueryState::Finished) {
+                QueryState::Pending(mut task, f) => {
+                    connection.run_pending_deletions();
+
+                    let query = {
+                        let (gl, _) = unsafe { connection.unpack() };
+                        let query = gl.create_query().unwrap();
+
+                        gl.begin_query(Gl::TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN, &query);
+
+                        query
+                    };
+
+                    self.deletion_queue = Some(connection.deletion_queue().clone());
+
+                    let progress = task.progress(connection);
+                    let (gl, _) = unsafe { connection.unpack() };
+
+                    gl.end_query(Gl::TRANSFORM_FEEDBACK_PRIMITIVES_WRITTEN);
+
+                    let output = match progress {
+                        Progress::Finished(output) => output,
+                        Progress::ContinueFenced => {
+                            // Don't leave the query active, any later query for primitives written
+                            // would fail to begin.
+                            gl.delete_query(Some(&query));
+
+                            panic!("Cannot query primitives written for a fenced task.")
+                        }
+                    };
+
+                    self.state = QueryState::Querying(query, output, f);
+
+                    return Progress::ContinueFenced;
+                }
+                QueryState::Querying(query, output, f) => {
+                    let (gl, _) = unsafe { connection.unpack() };
+
+                    let available = gl
+                        .get_query_parameter(&query, Gl::QUERY_RESULT_AVAILABLE)
+                        .as_bool()
+                        .unwrap_or(false);
+
+                    if !available {
+                        self.state = QueryState::Querying(query, output, f);
+
+                        return Progress::ContinueFenced;
+                    }
+
+                    let primitives_written = gl
+                        .get_query_parameter(&query, Gl::QUERY_RESULT)
+                        .as_f64()
+                        .unwrap() as u32;
+
+                    gl.delete_query(Some(&query));
+
+                    self.state = QueryState::Continuation(f(output, primitives_written));
+                }
+                QueryState::Continuation(mut task) => {
+                    let progress = task.progress(connection);
+
+                    if let Progress::ContinueFenced = progress {
+                        self.state = QueryState::Continuation(task);
+                    }
+
+                    return progress;
+                }
+                QueryState::Finished => {
+                    panic!("Cannot progress QueryPrimitivesWritten after it has finished.")
+                }
+            }
+        }
+    }
+}
+
+impl<T, F, U> Drop for QueryPrimitivesWritten<T, F, U>
+where
+    T: GpuTask<Connection>,
+{
+    fn drop(&mut self) {
+        if let QueryState::Querying(query, ..) = &self.state {
+            if let Some(deletion_queue) = &self.deletion_queue {
+                deletion_queue.schedule(QueryDropCommand {
+                    query: query.clone(),
+                });
+            }
+        }
+    }
+}
+
+struct QueryDropCommand {
+    query: WebGlQuery,
+}
+
+unsafe impl GpuTask<Connection> for QueryDropCommand {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Any
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        let (gl, _) = unsafe { connection.unpack() };
+
+        gl.delete_query(Some(&self.query));
+
+        Progress::Finished(())
+    }
+}
@@ -10,6 +10,7 @@ pub(crate) struct BufferDescriptor {
     pub(crate) buffer_data: Arc<BufferData>,
     pub(crate) offset_in_bytes: u32,
     pub(crate) size_in_bytes: u32,
+    pub(crate) stride_in_bytes: u32,
 }
 
 impl BufferDescriptor {
@@ -27,6 +28,7 @@ impl BufferDescriptor {
             buffer_data: buffer_view.buffer_data().clone(),
             offset_in_bytes: buffer_view.offset_in_bytes() as u32,
             size_in_bytes: (mem::size_of::<T>() * buffer_view.len()) as u32,
+            stride_in_bytes: mem::size_of::<T>() as u32,
         }
     }
 }
@@ -18,6 +18,12 @@ impl VertexAttributeFormatIdentifier for Float_f32 {
     const FORMAT: VertexAttributeFormat = VertexAttributeFormat::Float_f32;
 }
 
+pub struct Float_f16;
+
+impl VertexAttributeFormatIdentifier for Float_f16 {
+    const FORMAT: VertexAttributeFormat = VertexAttributeFormat::Float_f16;
+}
+
 pub struct Float_i8_fixed;
 
 impl VertexAttributeFormatIdentifier for Float_i8_fixed {
@@ -72,6 +78,12 @@ impl VertexAttributeFormatIdentifier for Float2_f32 {
     const FORMAT: VertexAttributeFormat = VertexAttributeFormat::Float2_f32;
 }
 
+pub struct Float2_f16;
+
+impl VertexAttributeFormatIdentifier for Float2_f16 {
+    const FORMAT: VertexAttributeFormat = VertexAttributeFormat::Float2_f16;
+}
+
 pub struct Float2_i8_fixed;
 
 impl VertexAttributeFormatIdentifier for Float2_i8_fixed {
@@ -126,6 +138,12 @@ impl VertexAttributeFormatIdentifier for Float3_f32 {
     const FORMAT: VertexAttributeFormat = VertexAttributeFormat::Float3_f32;
 }
 
+pub struct Float3_f16;
+
+impl VertexAttributeFormatIdentifier for Float3_f16 {
+    const FORMAT: VertexAttributeFormat = VertexAttributeFormat::Float3_f16;
+}
+
 pub struct Float3_i8_fixed;
 
 impl VertexAttributeFormatIdentifier for Float3_i8_fixed {
@@ -180,6 +198,12 @@ impl VertexAttributeFormatIdentifier for Float4_f32 {
     const FORMAT: VertexAttributeFormat = VertexAttributeFormat::Float4_f32;
 }
 
+pub struct Float4_f16;
+
+impl VertexAttributeFormatIdentifier for Float4_f16 {
+    const FORMAT: VertexAttributeFormat = VertexAttributeFormat::Float4_f16;
+}
+
 pub struct Float4_i8_fixed;
 
 impl VertexAttributeFormatIdentifier for Float4_i8_fixed {
@@ -228,6 +252,18 @@ impl VertexAttributeFormatIdentifier for Float4_u16_norm {
     const FORMAT: VertexAttributeFormat = VertexAttributeFormat::Float4_u16_norm;
 }
 
+pub struct Float4_i2_10_10_10_rev_norm;
+
+impl VertexAttributeFormatIdentifier for Float4_i2_10_10_10_rev_norm {
+    const FORMAT: VertexAttributeFormat = VertexAttributeFormat::Float4_i2_10_10_10_rev_norm;
+}
+
+pub struct Float4_u2_10_10_10_rev_norm;
+
+impl VertexAttributeFormatIdentifier for Float4_u2_10_10_10_rev_norm {
+    const FORMAT: VertexAttributeFormat = VertexAttributeFormat::Float4_u2_10_10_10_rev_norm;
+}
+
 pub struct Float2x2_f32;
 
 impl VertexAttributeFormatIdentifier for Float2x2_f32 {
@@ -865,6 +901,26 @@ impl VertexAttributeFormatIdentifier for Integer4_u32 {
 /// that type can be used as the field type for a [Vertex] field that is marked as an attribute with
 /// format `F`.
 ///
+/// When the `mint` feature is enabled, this trait is also implemented for the `f32` vector and
+/// point types of the [mint](https://docs.rs/mint) crate (`Vector2`, `Vector3`, `Vector4`,
+/// `Point2` and `Point3`), which are compatible with the `Float2_f32`, `Float3_f32` and
+/// `Float4_f32` formats. Most math libraries can convert their vector types to and from these
+/// types (e.g. with `Into`), which allows a vertex type to use them as attribute fields:
+///
+/// ```
+/// # #![feature(const_fn, const_transmute, const_ptr_offset_from, ptr_offset_from)]
+/// # #[cfg(feature = "mint")]
+/// # mod wrapper {
+/// #[derive(web_glitz::derive::Vertex, Clone, Copy)]
+/// struct Vertex {
+///     #[vertex_attribute(location = 0, format = "Float3_f32")]
+///     position: mint::Point3<f32>,
+///     #[vertex_attribute(location = 1, format = "Float3_f32")]
+///     normal: mint::Vector3<f32>,
+/// }
+/// # }
+/// ```
+///
 /// See also [Vertex].
 ///
 /// # Unsafe
@@ -878,6 +934,7 @@ where
 }
 
 unsafe impl VertexAttributeFormatCompatible<Float_f32> for f32 {}
+unsafe impl VertexAttributeFormatCompatible<Float_f16> for u16 {}
 unsafe impl VertexAttributeFormatCompatible<Float_i8_fixed> for i8 {}
 unsafe impl VertexAttributeFormatCompatible<Float_i8_norm> for i8 {}
 unsafe impl VertexAttributeFormatCompatible<Float_u8_fixed> for u8 {}
@@ -887,6 +944,7 @@ unsafe impl VertexAttributeFormatCompatible<Float_i16_norm> for i16 {}
 unsafe impl VertexAttributeFormatCompatible<Float_u16_fixed> for u16 {}
 unsafe impl VertexAttributeFormatCompatible<Float_u16_norm> for u16 {}
 unsafe impl VertexAttributeFormatCompatible<Float2_f32> for [f32; 2] {}
+unsafe impl VertexAttributeFormatCompatible<Float2_f16> for [u16; 2] {}
 unsafe impl VertexAttributeFormatCompatible<Float2_i8_fixed> for [i8; 2] {}
 unsafe impl VertexAttributeFormatCompatible<Float2_i8_norm> for [i8; 2] {}
 unsafe impl VertexAttributeFormatCompatible<Float2_u8_fixed> for [u8; 2] {}
@@ -896,6 +954,7 @@ unsafe impl VertexAttributeFormatCompatible<Float2_i16_norm> for [i16; 2] {}
 unsafe impl VertexAttributeFormatCompatible<Float2_u16_fixed> for [u16; 2] {}
 unsafe impl VertexAttributeFormatCompatible<Float2_u16_norm> for [u16; 2] {}
 unsafe impl VertexAttributeFormatCompatible<Float3_f32> for [f32; 3] {}
+unsafe impl VertexAttributeFormatCompatible<Float3_f16> for [u16; 3] {}
 unsafe impl VertexAttributeFormatCompatible<Float3_i8_fixed> for [i8; 3] {}
 unsafe impl VertexAttributeFormatCompatible<Float3_i8_norm> for [i8; 3] {}
 unsafe impl VertexAttributeFormatCompatible<Float3_u8_fixed> for [u8; 3] {}
@@ -905,6 +964,7 @@ unsafe impl VertexAttributeFormatCompatible<Float3_i16_norm> for [i16; 3] {}
 unsafe impl VertexAttributeFormatCompatible<Float3_u16_fixed> for [u16; 3] {}
 unsafe impl VertexAttributeFormatCompatible<Float3_u16_norm> for [u16; 3] {}
 unsafe impl VertexAttributeFormatCompatible<Float4_f32> for [f32; 4] {}
+unsafe impl VertexAttributeFormatCompatible<Float4_f16> for [u16; 4] {}
 unsafe impl VertexAttributeFormatCompatible<Float4_i8_fixed> for [i8; 4] {}
 unsafe impl VertexAttributeFormatCompatible<Float4_i8_norm> for [i8; 4] {}
 unsafe impl VertexAttributeFormatCompatible<Float4_u8_fixed> for [u8; 4] {}
@@ -913,6 +973,8 @@ unsafe impl VertexAttributeFormatCompatible<Float4_i16_fixed> for [i16; 4] {}
 unsafe impl VertexAttributeFormatCompatible<Float4_i16_norm> for [i16; 4] {}
 unsafe impl VertexAttributeFormatCompatible<Float4_u16_fixed> for [u16; 4] {}
 unsafe impl VertexAttributeFormatCompatible<Float4_u16_norm> for [u16; 4] {}
+unsafe impl VertexAttributeFormatCompatible<Float4_i2_10_10_10_rev_norm> for u32 {}
+unsafe impl VertexAttributeFormatCompatible<Float4_u2_10_10_10_rev_norm> for u32 {}
 unsafe impl VertexAttributeFormatCompatible<Float2x2_f32> for [[f32; 2]; 2] {}
 unsafe impl VertexAttributeFormatCompatible<Float2x2_i8_fixed> for [[i8; 2]; 2] {}
 unsafe impl VertexAttributeFormatCompatible<Float2x2_i8_norm> for [[i8; 2]; 2] {}
@@ -1019,11 +1081,35 @@ unsafe impl VertexAttributeFormatCompatible<Integer4_u8> for [u8; 4] {}
 unsafe impl VertexAttributeFormatCompatible<Integer4_u16> for [u16; 4] {}
 unsafe impl VertexAttributeFormatCompatible<Integer4_u32> for [u32; 4] {}
 
+// The `mint` vector and point types are `#[repr(C)]` structs of their components, which makes them
+// memory compatible with the corresponding arrays.
+#[cfg(feature = "mint")]
+unsafe impl VertexAttributeFormatCompatible<Float2_f32> for mint::Vector2<f32> {}
+#[cfg(feature = "mint")]
+unsafe impl VertexAttributeFormatCompatible<Float3_f32> for mint::Vector3<f32> {}
+#[cfg(feature = "mint")]
+unsafe impl VertexAttributeFormatCompatible<Float4_f32> for mint::Vector4<f32> {}
+#[cfg(feature = "mint")]
+unsafe impl VertexAttributeFormatCompatible<Float2_f32> for mint::Point2<f32> {}
+#[cfg(feature = "mint")]
+unsafe impl VertexAttributeFormatCompatible<Float3_f32> for mint::Point3<f32> {}
+
 /// Enumerates all available attribute memory formats.
+///
+/// The `Float` formats are converted to floating point values when read by a vertex shader: the
+/// `f16` formats read IEEE 754 half-precision values from their raw bits (e.g. stored as `u16`),
+/// the `fixed` formats convert integer data directly, the `norm` formats normalize integer data to
+/// the `[0, 1]` range (unsigned) or the `[-1, 1]` range (signed). The packed `2_10_10_10_rev`
+/// formats store all 4 components in a single `u32`: the first component in the 10 least
+/// significant bits, followed by the second and third components (10 bits each) and the fourth
+/// component in the 2 most significant bits. The `Integer` formats are not converted; they must be
+/// bound to integer attribute slots (`int`, `ivec*`, `uint`, `uvec*`) of matching signedness, see
+/// [is_compatible].
 #[derive(Clone, Copy, PartialEq, Hash, Debug)]
 #[allow(non_camel_case_types)]
 pub enum VertexAttributeFormat {
     Float_f32,
+    Float_f16,
     Float_i8_fixed,
     Float_i8_norm,
     Float_i16_fixed,
@@ -1033,6 +1119,7 @@ pub enum VertexAttributeFormat {
     Float_u16_fixed,
     Float_u16_norm,
     Float2_f32,
+    Float2_f16,
     Float2_i8_fixed,
     Float2_i8_norm,
     Float2_i16_fixed,
@@ -1042,6 +1129,7 @@ pub enum VertexAttributeFormat {
     Float2_u16_fixed,
     Float2_u16_norm,
     Float3_f32,
+    Float3_f16,
     Float3_i8_fixed,
     Float3_i8_norm,
     Float3_i16_fixed,
@@ -1051,6 +1139,7 @@ pub enum VertexAttributeFormat {
     Float3_u16_fixed,
     Float3_u16_norm,
     Float4_f32,
+    Float4_f16,
     Float4_i8_fixed,
     Float4_i8_norm,
     Float4_i16_fixed,
@@ -1059,6 +1148,8 @@ pub enum VertexAttributeFormat {
     Float4_u8_norm,
     Float4_u16_fixed,
     Float4_u16_norm,
+    Float4_i2_10_10_10_rev_norm,
+    Float4_u2_10_10_10_rev_norm,
     Float2x2_f32,
     Float2x2_i8_fixed,
     Float2x2_i8_norm,
@@ -1172,6 +1263,7 @@ impl VertexAttributeFormat {
     pub fn is_compatible(&self, attribute_type: VertexAttributeType) -> bool {
         match self {
             VertexAttributeFormat::Float_f32 => attribute_type == VertexAttributeType::Float,
+            VertexAttributeFormat::Float_f16 => attribute_type == VertexAttributeType::Float,
             VertexAttributeFormat::Float_i8_fixed => attribute_type == VertexAttributeType::Float,
             VertexAttributeFormat::Float_i8_norm => attribute_type == VertexAttributeType::Float,
             VertexAttributeFormat::Float_i16_fixed => attribute_type == VertexAttributeType::Float,
@@ -1183,6 +1275,9 @@ impl VertexAttributeFormat {
             VertexAttributeFormat::Float2_f32 => {
                 attribute_type == VertexAttributeType::FloatVector2
             }
+            VertexAttributeFormat::Float2_f16 => {
+                attribute_type == VertexAttributeType::FloatVector2
+            }
             VertexAttributeFormat::Float2_i8_fixed => {
                 attribute_type == VertexAttributeType::FloatVector2
             }
@@ -1210,6 +1305,9 @@ impl VertexAttributeFormat {
             VertexAttributeFormat::Float3_f32 => {
                 attribute_type == VertexAttributeType::FloatVector3
             }
+            VertexAttributeFormat::Float3_f16 => {
+                attribute_type == VertexAttributeType::FloatVector3
+            }
             VertexAttributeFormat::Float3_i8_fixed => {
                 attribute_type == VertexAttributeType::FloatVector3
             }
@@ -1237,6 +1335,9 @@ impl VertexAttributeFormat {
             VertexAttributeFormat::Float4_f32 => {
                 attribute_type == VertexAttributeType::FloatVector4
             }
+            VertexAttributeFormat::Float4_f16 => {
+                attribute_type == VertexAttributeType::FloatVector4
+            }
             VertexAttributeFormat::Float4_i8_fixed => {
                 attribute_type == VertexAttributeType::FloatVector4
             }
@@ -1261,6 +1362,12 @@ impl VertexAttributeFormat {
             VertexAttributeFormat::Float4_u16_norm => {
                 attribute_type == VertexAttributeType::FloatVector4
             }
+            VertexAttributeFormat::Float4_i2_10_10_10_rev_norm => {
+                attribute_type == VertexAttributeType::FloatVector4
+            }
+            VertexAttributeFormat::Float4_u2_10_10_10_rev_norm => {
+                attribute_type == VertexAttributeType::FloatVector4
+            }
             VertexAttributeFormat::Float2x2_f32 => {
                 attribute_type == VertexAttributeType::FloatMatrix2x2
             }
@@ -1576,6 +1683,7 @@ impl VertexAttributeFormat {
     pub fn size_in_bytes(&self) -> u8 {
         match self {
             VertexAttributeFormat::Float_f32 => 4,
+            VertexAttributeFormat::Float_f16 => 2,
             VertexAttributeFormat::Float_i8_fixed => 1,
             VertexAttributeFormat::Float_i8_norm => 1,
             VertexAttributeFormat::Float_i16_fixed => 2,
@@ -1585,6 +1693,7 @@ impl VertexAttributeFormat {
             VertexAttributeFormat::Float_u16_fixed => 2,
             VertexAttributeFormat::Float_u16_norm => 2,
             VertexAttributeFormat::Float2_f32 => 8,
+            VertexAttributeFormat::Float2_f16 => 4,
             VertexAttributeFormat::Float2_i8_fixed => 2,
             VertexAttributeFormat::Float2_i8_norm => 2,
             VertexAttributeFormat::Float2_i16_fixed => 4,
@@ -1594,6 +1703,7 @@ impl VertexAttributeFormat {
             VertexAttributeFormat::Float2_u16_fixed => 4,
             VertexAttributeFormat::Float2_u16_norm => 4,
             VertexAttributeFormat::Float3_f32 => 12,
+            VertexAttributeFormat::Float3_f16 => 6,
             VertexAttributeFormat::Float3_i8_fixed => 3,
             VertexAttributeFormat::Float3_i8_norm => 3,
             VertexAttributeFormat::Float3_i16_fixed => 6,
@@ -1603,6 +1713,7 @@ impl VertexAttributeFormat {
             VertexAttributeFormat::Float3_u16_fixed => 6,
             VertexAttributeFormat::Float3_u16_norm => 6,
             VertexAttributeFormat::Float4_f32 => 16,
+            VertexAttributeFormat::Float4_f16 => 8,
             VertexAttributeFormat::Float4_i8_fixed => 4,
             VertexAttributeFormat::Float4_i8_norm => 4,
             VertexAttributeFormat::Float4_i16_fixed => 8,
@@ -1611,6 +1722,8 @@ impl VertexAttributeFormat {
             VertexAttributeFormat::Float4_u8_norm => 4,
             VertexAttributeFormat::Float4_u16_fixed => 8,
             VertexAttributeFormat::Float4_u16_norm => 8,
+            VertexAttributeFormat::Float4_i2_10_10_10_rev_norm => 4,
+            VertexAttributeFormat::Float4_u2_10_10_10_rev_norm => 4,
             VertexAttributeFormat::Float2x2_f32 => 16,
             VertexAttributeFormat::Float2x2_i8_fixed => 4,
             VertexAttributeFormat::Float2x2_i8_norm => 4,
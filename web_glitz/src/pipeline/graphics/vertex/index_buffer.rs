@@ -1,5 +1,6 @@
 use std::borrow::Borrow;
 use std::cell::UnsafeCell;
+use std::convert::TryFrom;
 use std::hash::{Hash, Hasher};
 use std::marker;
 use std::mem;
@@ -12,7 +13,7 @@ use web_sys::WebGl2RenderingContext as Gl;
 
 use crate::buffer::UsageHint;
 use crate::runtime::state::ContextUpdate;
-use crate::runtime::{Connection, RenderingContext};
+use crate::runtime::{Connection, DeletionQueue, RenderingContext};
 use crate::task::{ContextId, GpuTask, Progress};
 use crate::util::JsId;
 use std::mem::MaybeUninit;
@@ -53,6 +54,11 @@ where
 
 /// Trait implemented for types that can be used as indices for a [VertexArray] encoded in the
 /// associated [IndexType].
+///
+/// Implemented for `u8`, `u16` and `u32`. Choose the smallest type that can address all vertices
+/// in a mesh: `u8` for tiny meshes of at most 255 vertices, `u16` for meshes of at most 65535
+/// vertices and `u32` for larger meshes. See [IndexType::max_index] for the largest vertex index
+/// each type can address.
 pub unsafe trait IndexFormat: Copy {
     /// The [IndexType] associated with this [IndexFormat].
     const TYPE: IndexType;
@@ -91,6 +97,11 @@ impl Hash for IndexDataDescriptor {
 }
 
 /// Enumerates the available type encodings for [VertexArray] indices.
+///
+/// Note that WebGL 2.0 always enables primitive restart with a fixed index: the maximum value
+/// representable by the index type (e.g. `65535` for [IndexType::UnsignedShort]) does not address
+/// a vertex, instead it ends the current primitive (for strip and fan topologies) or is ignored.
+/// See [IndexType::max_index].
 #[derive(Clone, Copy, PartialEq, Hash, Debug)]
 pub enum IndexType {
     UnsignedByte,
@@ -106,6 +117,59 @@ impl IndexType {
             IndexType::UnsignedInt => Gl::UNSIGNED_INT,
         }
     }
+
+    /// Returns the largest index that addresses a vertex for this [IndexType].
+    ///
+    /// This is one less than the maximum value representable by the index type, as the maximum
+    /// value is reserved as the primitive restart index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use web_glitz::pipeline::graphics::IndexType;
+    ///
+    /// assert_eq!(IndexType::UnsignedByte.max_index(), 254);
+    /// assert_eq!(IndexType::UnsignedShort.max_index(), 65534);
+    /// assert_eq!(IndexType::UnsignedInt.max_index(), 4294967294);
+    /// ```
+    pub fn max_index(&self) -> u32 {
+        match self {
+            IndexType::UnsignedByte => u8::max_value() as u32 - 1,
+            IndexType::UnsignedShort => u16::max_value() as u32 - 1,
+            IndexType::UnsignedInt => u32::max_value() - 1,
+        }
+    }
+
+    /// Returns the smallest [IndexType] that can address each of `vertex_count` vertices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use web_glitz::pipeline::graphics::IndexType;
+    ///
+    /// assert_eq!(IndexType::smallest_for_vertex_count(255), IndexType::UnsignedByte);
+    /// assert_eq!(IndexType::smallest_for_vertex_count(256), IndexType::UnsignedShort);
+    /// assert_eq!(IndexType::smallest_for_vertex_count(70000), IndexType::UnsignedInt);
+    /// ```
+    pub fn smallest_for_vertex_count(vertex_count: usize) -> IndexType {
+        let max_index = vertex_count.saturating_sub(1) as u64;
+
+        if max_index <= IndexType::UnsignedByte.max_index() as u64 {
+            IndexType::UnsignedByte
+        } else if max_index <= IndexType::UnsignedShort.max_index() as u64 {
+            IndexType::UnsignedShort
+        } else {
+            IndexType::UnsignedInt
+        }
+    }
+
+    pub(crate) fn size_in_bytes(&self) -> u32 {
+        match self {
+            IndexType::UnsignedByte => 1,
+            IndexType::UnsignedShort => 2,
+            IndexType::UnsignedInt => 4,
+        }
+    }
 }
 
 /// A GPU-accessible memory buffer that contains an indexed list for indexed drawing.
@@ -134,6 +198,20 @@ impl IndexType {
 /// let index_buffer: IndexBuffer<u16> = context.create_index_buffer([1, 2, 3, 4], UsageHint::StreamDraw);
 /// # }
 /// ```
+///
+/// The index type is determined by the element type of the buffer: `u8`, `u16` and `u32` indices
+/// are supported (see [IndexFormat]). Draw commands that use the buffer decode the indices
+/// accordingly. For example, a mesh with more than 65535 vertices requires `u32` indices:
+///
+/// ```rust
+/// # use web_glitz::runtime::RenderingContext;
+/// # fn wrapper<Rc>(context: &Rc, indices: Vec<u32>) where Rc: RenderingContext + Clone + 'static {
+/// use web_glitz::pipeline::graphics::IndexBuffer;
+/// use web_glitz::buffer::UsageHint;
+///
+/// let index_buffer: IndexBuffer<u32> = context.create_index_buffer(indices, UsageHint::StaticDraw);
+/// # }
+/// ```
 pub struct IndexBuffer<T> {
     object_id: u64,
     data: Arc<IndexBufferData>,
@@ -157,7 +235,7 @@ where
         let buffer_data = Arc::new(IndexBufferData {
             id: UnsafeCell::new(None),
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
+            dropper: Box::new(context.deletion_queue()),
             usage_hint,
             len: data.borrow().len(),
         });
@@ -192,7 +270,7 @@ where
         let buffer_data = Arc::new(IndexBufferData {
             id: UnsafeCell::new(None),
             context_id: context.id(),
-            dropper: Box::new(context.clone()),
+            dropper: Box::new(context.deletion_queue()),
             usage_hint,
             len,
         });
@@ -366,6 +444,85 @@ impl<T> Hash for IndexBuffer<T> {
     }
 }
 
+/// An [IndexBuffer] for which the [IndexFormat] was selected at runtime.
+///
+/// See [RenderingContext::create_index_buffer_auto].
+#[derive(PartialEq, Hash)]
+pub enum AutoIndexBuffer {
+    UnsignedByte(IndexBuffer<u8>),
+    UnsignedShort(IndexBuffer<u16>),
+    UnsignedInt(IndexBuffer<u32>),
+}
+
+impl AutoIndexBuffer {
+    /// Returns the [IndexType] of the indices contained in this [AutoIndexBuffer].
+    pub fn index_type(&self) -> IndexType {
+        match self {
+            AutoIndexBuffer::UnsignedByte(_) => IndexType::UnsignedByte,
+            AutoIndexBuffer::UnsignedShort(_) => IndexType::UnsignedShort,
+            AutoIndexBuffer::UnsignedInt(_) => IndexType::UnsignedInt,
+        }
+    }
+
+    /// Returns the number of indices contained in this [AutoIndexBuffer].
+    pub fn len(&self) -> usize {
+        match self {
+            AutoIndexBuffer::UnsignedByte(buffer) => buffer.len(),
+            AutoIndexBuffer::UnsignedShort(buffer) => buffer.len(),
+            AutoIndexBuffer::UnsignedInt(buffer) => buffer.len(),
+        }
+    }
+}
+
+impl<'a> IndexData for &'a AutoIndexBuffer {
+    fn descriptor(&self) -> IndexDataDescriptor {
+        match self {
+            AutoIndexBuffer::UnsignedByte(buffer) => buffer.descriptor(),
+            AutoIndexBuffer::UnsignedShort(buffer) => buffer.descriptor(),
+            AutoIndexBuffer::UnsignedInt(buffer) => buffer.descriptor(),
+        }
+    }
+}
+
+/// Error returned when an index does not fit the [IndexType] that was selected for an index
+/// buffer.
+///
+/// See [RenderingContext::create_index_buffer_auto].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct IndexOutOfRange {
+    /// The position of the index in the index data.
+    pub position: usize,
+
+    /// The value of the index.
+    pub index: u32,
+
+    /// The index type that was selected for the index buffer.
+    pub index_type: IndexType,
+}
+
+/// Narrows the `indices` to index format `T`, or returns an error if any of the indices exceeds
+/// the [IndexType::max_index] for `T`.
+pub(crate) fn narrow_indices<T>(indices: &[u32]) -> Result<Vec<T>, IndexOutOfRange>
+where
+    T: IndexFormat + TryFrom<u32>,
+{
+    indices
+        .iter()
+        .enumerate()
+        .map(|(position, &index)| {
+            if index > T::TYPE.max_index() {
+                Err(IndexOutOfRange {
+                    position,
+                    index,
+                    index_type: T::TYPE,
+                })
+            } else {
+                Ok(T::try_from(index).ok().unwrap())
+            }
+        })
+        .collect()
+}
+
 impl<'a, T> From<&'a IndexBuffer<T>> for IndexBufferView<'a, T> {
     fn from(buffer: &'a IndexBuffer<T>) -> IndexBufferView<'a, T> {
         IndexBufferView {
@@ -804,6 +961,8 @@ where
                 self.offset_in_bytes as i32,
                 data,
             );
+
+            state.record_buffer_upload();
         };
 
         Progress::Finished(())
@@ -814,12 +973,9 @@ trait BufferObjectDropper {
     fn drop_buffer_object(&self, id: JsId);
 }
 
-impl<T> BufferObjectDropper for T
-where
-    T: RenderingContext,
-{
+impl BufferObjectDropper for DeletionQueue {
     fn drop_buffer_object(&self, id: JsId) {
-        self.submit(DropCommand { id });
+        self.schedule(DropCommand { id });
     }
 }
 
@@ -939,6 +1095,8 @@ where
                 initial,
                 data.usage_hint.gl_id(),
             );
+
+            state.record_buffer_upload();
         }
 
         unsafe {
@@ -974,3 +1132,26 @@ unsafe impl GpuTask<Connection> for DropCommand {
         Progress::Finished(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_narrow_indices() {
+        assert_eq!(narrow_indices::<u8>(&[0, 1, 254]), Ok(vec![0u8, 1, 254]));
+        assert_eq!(narrow_indices::<u16>(&[0, 65534]), Ok(vec![0u16, 65534]));
+    }
+
+    #[test]
+    fn test_narrow_indices_out_of_range() {
+        assert_eq!(
+            narrow_indices::<u8>(&[0, 1, 255, 3]),
+            Err(IndexOutOfRange {
+                position: 2,
+                index: 255,
+                index_type: IndexType::UnsignedByte
+            })
+        );
+    }
+}
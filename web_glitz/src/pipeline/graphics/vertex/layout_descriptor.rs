@@ -74,6 +74,7 @@ impl_typed_vertex_input_layout!(
 );
 
 /// Helper type for implementing [TypedVertexInputLayout] for (tuples of) [Vertex] types.
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub struct StaticVertexBufferSlotDescriptor {
     /// The stride in bytes between successive vertices in the bind slot.
     pub stride: u8,
@@ -202,6 +203,37 @@ impl VertexInputLayoutDescriptor {
         Ok(())
     }
 
+    /// Returns an iterator over the `(name, location)` pairs of the attributes in this layout that
+    /// are bound by name, see [VertexAttributeDescriptor::name].
+    pub(crate) fn named_attributes(&self) -> impl Iterator<Item = (&'static str, u32)> + '_ {
+        self.layout.iter().filter_map(|element| match element {
+            LayoutElement::NextAttribute(VertexAttributeDescriptor {
+                name: Some(name),
+                location,
+                ..
+            }) => Some((*name, *location)),
+            _ => None,
+        })
+    }
+
+    /// Returns a key that identifies the name bindings for the attributes in this layout, or
+    /// `None` if none of the attributes are bound by name.
+    pub(crate) fn attribute_names_key(&self) -> Option<u64> {
+        let mut named_attributes = self.named_attributes().peekable();
+
+        if named_attributes.peek().is_some() {
+            let mut hasher = FnvHasher::default();
+
+            for named_attribute in named_attributes {
+                named_attribute.hash(&mut hasher);
+            }
+
+            Some(hasher.finish())
+        } else {
+            None
+        }
+    }
+
     /// Returns an iterator over the vertex buffer binding slots described by this descriptor.
     pub fn buffer_slots(&self) -> VertexBufferSlots {
         VertexBufferSlots {
@@ -353,11 +385,13 @@ pub struct VertexInputLayoutAllocationHint {
 /// builder.add_buffer_slot(28, InputRate::PerVertex)
 ///     .add_attribute(VertexAttributeDescriptor {
 ///         location: 0,
+///         name: None,
 ///         offset_in_bytes: 0,
 ///         format: VertexAttributeFormat::Float4_f32
 ///     })
 ///     .add_attribute(VertexAttributeDescriptor {
 ///         location: 1,
+///         name: None,
 ///         offset_in_bytes: 16,
 ///         format: VertexAttributeFormat::Float3_f32
 ///     });
@@ -365,12 +399,14 @@ pub struct VertexInputLayoutAllocationHint {
 /// builder.add_buffer_slot(16, InputRate::PerInstance)
 ///     .add_attribute(VertexAttributeDescriptor {
 ///         location: 2,
+///         name: None,
 ///         offset_in_bytes: 0,
 ///         format: VertexAttributeFormat::Float4_f32
 ///     });
 ///
 /// let layout_descriptor = builder.finish();
 /// ```
+#[derive(Clone, Debug)]
 pub struct VertexInputLayoutDescriptorBuilder {
     initial_bind_slot: Option<BindSlot>,
     layout: Vec<LayoutElement>,
@@ -565,6 +601,27 @@ pub struct VertexAttributeDescriptor {
     /// ```
     pub location: u32,
 
+    /// The name of the attribute in the shader code, if the attribute should be bound to the
+    /// [location] by name.
+    ///
+    /// If `Some`, then when a [GraphicsPipeline] is created the shader attribute with this name is
+    /// assigned the [location] before the pipeline's program is linked. This allows the shader code
+    /// to declare the attribute without a `layout` qualifier:
+    ///
+    /// ```glsl
+    /// in vec4 position;
+    /// ```
+    ///
+    /// The order in which attributes are declared in the shader code then no longer affects the
+    /// locations they are bound to. Note that an explicit `layout(location=...)` qualifier in the
+    /// shader code takes precedence over the name binding.
+    ///
+    /// If `None`, then the attribute must be assigned the [location] by the shader code.
+    ///
+    /// [location]: VertexAttributeDescriptor::location
+    /// [GraphicsPipeline]: crate::pipeline::graphics::GraphicsPipeline
+    pub name: Option<&'static str>,
+
     /// The offset in bytes of the first value in the attribute value sequence relative to the start
     /// of a [VertexInputDescriptor].
     ///
@@ -604,6 +661,22 @@ impl VertexAttributeDescriptor {
                     gl.vertex_attrib_divisor(self.location, 1);
                 }
             }
+            VertexAttributeFormat::Float_f16 => {
+                gl.vertex_attrib_pointer_with_i32(
+                    self.location,
+                    1,
+                    Gl::HALF_FLOAT,
+                    false,
+                    stride_in_bytes,
+                    base_offset_in_bytes + self.offset_in_bytes as i32,
+                );
+
+                gl.enable_vertex_attrib_array(self.location);
+
+                if input_rate == InputRate::PerInstance {
+                    gl.vertex_attrib_divisor(self.location, 1);
+                }
+            }
             VertexAttributeFormat::Float_i8_fixed => {
                 gl.vertex_attrib_pointer_with_i32(
                     self.location,
@@ -744,6 +817,22 @@ impl VertexAttributeDescriptor {
                     gl.vertex_attrib_divisor(self.location, 1);
                 }
             }
+            VertexAttributeFormat::Float2_f16 => {
+                gl.vertex_attrib_pointer_with_i32(
+                    self.location,
+                    2,
+                    Gl::HALF_FLOAT,
+                    false,
+                    stride_in_bytes,
+                    base_offset_in_bytes + self.offset_in_bytes as i32,
+                );
+
+                gl.enable_vertex_attrib_array(self.location);
+
+                if input_rate == InputRate::PerInstance {
+                    gl.vertex_attrib_divisor(self.location, 1);
+                }
+            }
             VertexAttributeFormat::Float2_i8_fixed => {
                 gl.vertex_attrib_pointer_with_i32(
                     self.location,
@@ -888,6 +977,22 @@ impl VertexAttributeDescriptor {
                     gl.vertex_attrib_divisor(self.location, 1);
                 }
             }
+            VertexAttributeFormat::Float3_f16 => {
+                gl.vertex_attrib_pointer_with_i32(
+                    self.location,
+                    3,
+                    Gl::HALF_FLOAT,
+                    false,
+                    stride_in_bytes,
+                    base_offset_in_bytes + self.offset_in_bytes as i32,
+                );
+
+                gl.enable_vertex_attrib_array(self.location);
+
+                if input_rate == InputRate::PerInstance {
+                    gl.vertex_attrib_divisor(self.location, 1);
+                }
+            }
             VertexAttributeFormat::Float3_i8_fixed => {
                 gl.vertex_attrib_pointer_with_i32(
                     self.location,
@@ -1032,6 +1137,22 @@ impl VertexAttributeDescriptor {
                     gl.vertex_attrib_divisor(self.location, 1);
                 }
             }
+            VertexAttributeFormat::Float4_f16 => {
+                gl.vertex_attrib_pointer_with_i32(
+                    self.location,
+                    4,
+                    Gl::HALF_FLOAT,
+                    false,
+                    stride_in_bytes,
+                    base_offset_in_bytes + self.offset_in_bytes as i32,
+                );
+
+                gl.enable_vertex_attrib_array(self.location);
+
+                if input_rate == InputRate::PerInstance {
+                    gl.vertex_attrib_divisor(self.location, 1);
+                }
+            }
             VertexAttributeFormat::Float4_i8_fixed => {
                 gl.vertex_attrib_pointer_with_i32(
                     self.location,
@@ -1160,6 +1281,38 @@ impl VertexAttributeDescriptor {
                     gl.vertex_attrib_divisor(self.location, 1);
                 }
             }
+            VertexAttributeFormat::Float4_i2_10_10_10_rev_norm => {
+                gl.vertex_attrib_pointer_with_i32(
+                    self.location,
+                    4,
+                    Gl::INT_2_10_10_10_REV,
+                    true,
+                    stride_in_bytes,
+                    base_offset_in_bytes + self.offset_in_bytes as i32,
+                );
+
+                gl.enable_vertex_attrib_array(self.location);
+
+                if input_rate == InputRate::PerInstance {
+                    gl.vertex_attrib_divisor(self.location, 1);
+                }
+            }
+            VertexAttributeFormat::Float4_u2_10_10_10_rev_norm => {
+                gl.vertex_attrib_pointer_with_i32(
+                    self.location,
+                    4,
+                    Gl::UNSIGNED_INT_2_10_10_10_REV,
+                    true,
+                    stride_in_bytes,
+                    base_offset_in_bytes + self.offset_in_bytes as i32,
+                );
+
+                gl.enable_vertex_attrib_array(self.location);
+
+                if input_rate == InputRate::PerInstance {
+                    gl.vertex_attrib_divisor(self.location, 1);
+                }
+            }
             VertexAttributeFormat::Float2x2_f32 => {
                 gl.vertex_attrib_pointer_with_i32(
                     self.location,
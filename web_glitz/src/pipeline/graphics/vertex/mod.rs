@@ -1,15 +1,19 @@
 pub(crate) mod vertex_buffers;
 pub use self::vertex_buffers::{
-    TypedVertexBuffer, TypedVertexBuffers, VertexBuffer, VertexBuffers, VertexBuffersEncoding,
-    VertexBuffersEncodingContext,
+    TypedVertexBuffer, TypedVertexBuffers, VertexAttributePointer, VertexAttributePointers,
+    VertexBuffer, VertexBuffers, VertexBuffersEncoding, VertexBuffersEncodingContext,
 };
 
 pub(crate) mod index_buffer;
 pub use self::index_buffer::{
-    IndexBuffer, IndexBufferSliceRange, IndexBufferView, IndexBufferViewSliceIndex, IndexData,
-    IndexDataDescriptor, IndexFormat, IndexType,
+    AutoIndexBuffer, IndexBuffer, IndexBufferSliceRange, IndexBufferView,
+    IndexBufferViewSliceIndex, IndexData, IndexDataDescriptor, IndexFormat, IndexOutOfRange,
+    IndexType,
 };
 
+pub(crate) mod vertex_array;
+pub use self::vertex_array::VertexArray;
+
 pub(crate) mod layout_descriptor;
 pub use self::layout_descriptor::{
     IncompatibleVertexInputLayout, InputRate, TypedVertexInputLayout, VertexAttributeDescriptor,
@@ -59,6 +63,69 @@ pub mod attribute_format;
 /// `[f32; 2]` must implement `VertexAttributeFormatCompatible<Float2_f32>` (which it does) and
 /// `[u8;3 ]` must implement `VertexAttributeFormatCompatible<Float3_u8_norm>` (which it does).
 ///
+/// Matrix attributes may be declared with one of the matrix formats (e.g. `Float4x4_f32`). A matrix
+/// attribute consumes one attribute location for each of its columns, starting at the declared
+/// `location`:
+///
+/// ```rust
+/// # #![feature(const_fn, const_transmute, const_ptr_offset_from, ptr_offset_from)]
+/// #[derive(web_glitz::derive::Vertex, Clone, Copy)]
+/// struct Instance {
+///     // Consumes locations 2, 3, 4 and 5.
+///     #[vertex_attribute(location = 2, format = "Float4x4_f32")]
+///     model_matrix: [[f32; 4]; 4],
+///
+///     #[vertex_attribute(location = 6, format = "Float4_f32")]
+///     color: [f32; 4],
+/// }
+/// ```
+///
+/// The struct will fail to compile if the locations consumed by any two attributes overlap; in the
+/// example above, declaring `color` at any location in the range `2..6` would result in an error.
+///
+/// Integer attributes that should not be converted to floating point values (e.g. bone indices for
+/// GPU skinning) may be declared with one of the `Integer` formats (e.g. `Integer4_u8` or
+/// `Integer4_i32`). These formats may only be bound to attribute slots of an integer type in the
+/// vertex shader (e.g. `ivec4` for signed formats, `uvec4` for unsigned formats); binding them to
+/// a floating point attribute slot, or binding a floating point format (including the `fixed` and
+/// `norm` formats) to an integer attribute slot, results in an error when the pipeline is created:
+///
+/// ```rust
+/// # #![feature(const_fn, const_transmute, const_ptr_offset_from, ptr_offset_from)]
+/// #[derive(web_glitz::derive::Vertex, Clone, Copy)]
+/// struct SkinnedVertex {
+///     #[vertex_attribute(location = 0, format = "Float3_f32")]
+///     position: [f32; 3],
+///
+///     // Bound to an `in uvec4 a_joints` attribute slot.
+///     #[vertex_attribute(location = 1, format = "Integer4_u8")]
+///     joints: [u8; 4],
+///
+///     #[vertex_attribute(location = 2, format = "Float4_f32")]
+///     weights: [f32; 4],
+/// }
+/// ```
+///
+/// By default, the shader code must assign each attribute its location with a `layout` qualifier
+/// (e.g. `layout(location=0) in vec3 position;`). Alternatively, an attribute may declare a `name`,
+/// in which case the shader attribute with that name is bound to the attribute's `location` when
+/// a pipeline is created, and the shader code may omit the `layout` qualifier (see
+/// [VertexAttributeDescriptor::name]). A `name` without a value uses the field name:
+///
+/// ```rust
+/// # #![feature(const_fn, const_transmute, const_ptr_offset_from, ptr_offset_from)]
+/// #[derive(web_glitz::derive::Vertex, Clone, Copy)]
+/// struct Vertex {
+///     // Bound to an `in vec2 position` attribute.
+///     #[vertex_attribute(location = 0, name, format = "Float2_f32")]
+///     position: [f32; 2],
+///
+///     // Bound to an `in vec3 a_color` attribute.
+///     #[vertex_attribute(location = 1, name = "a_color", format = "Float3_u8_norm")]
+///     color: [u8; 3],
+/// }
+/// ```
+///
 /// Note that in this example we also derive `Clone` and `Copy`. This is not strictly required to
 /// derive the [Vertex] trait, however, a [Buffer] can only store an array of a type that implements
 /// the `Copy` trait. Therefor if we intend to create [Buffer] with our [Vertex] type, then we must
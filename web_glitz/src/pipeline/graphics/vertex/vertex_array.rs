@@ -0,0 +1,125 @@
+use std::marker;
+
+use crate::pipeline::graphics::util::BufferDescriptor;
+use crate::pipeline::graphics::{
+    IndexData, IndexDataDescriptor, VertexBuffers, VertexBuffersEncodingContext,
+};
+
+use staticvec::StaticVec;
+
+/// A retained combination of a (set of) vertex buffer(s) and an index buffer that may be bound to
+/// a graphics pipeline with a single command.
+///
+/// Static meshes typically use the same vertex buffers and index buffer for every draw. Rather than
+/// binding the vertex buffers and index buffer separately for every pipeline task (see
+/// [GraphicsPipelineTaskBuilder::bind_vertex_buffers] and
+/// [GraphicsPipelineTaskBuilder::bind_index_buffer]), the combination may be encoded once into a
+/// [VertexArray], which may then be bound with [GraphicsPipelineTaskBuilder::bind_vertex_array].
+///
+/// A [VertexArray] keeps alive any borrows held by the vertex buffers and index buffer it was
+/// created from: the `Vb` and `Ib` type parameters are the types of the vertex buffers and index
+/// buffer respectively. If the vertex buffers implement [TypedVertexBuffers], then binding the
+/// [VertexArray] carries the same static layout compatibility guarantees as binding the vertex
+/// buffers directly.
+///
+/// Note that the GL vertex array object (VAO) for a combination of a vertex input layout, vertex
+/// buffers and index buffer is created once, when the combination is first drawn with, and is then
+/// cached by the rendering context for as long as the buffers are alive. Subsequent draws with the
+/// same combination only bind the cached VAO.
+///
+/// # Example
+///
+/// ```
+/// # use web_glitz::buffer::Buffer;
+/// # use web_glitz::pipeline::graphics::{GraphicsPipeline, IndexBuffer, Vertex};
+/// # use web_glitz::rendering::{DefaultRGBBuffer, DefaultRenderTarget};
+/// # use web_glitz::runtime::RenderingContext;
+/// # fn wrapper<Rc, V>(
+/// #     context: &Rc,
+/// #     mut render_target: DefaultRenderTarget<DefaultRGBBuffer, ()>,
+/// #     vertex_buffer: &Buffer<[V]>,
+/// #     index_buffer: &IndexBuffer<u16>,
+/// #     graphics_pipeline: GraphicsPipeline<V, (), ()>
+/// # ) where Rc: RenderingContext, V: Vertex {
+/// use web_glitz::pipeline::graphics::VertexArray;
+///
+/// let mesh = VertexArray::new(vertex_buffer, index_buffer);
+///
+/// let render_pass = render_target.create_render_pass(|framebuffer| {
+///     framebuffer.pipeline_task(&graphics_pipeline, |active_pipeline| {
+///         active_pipeline.task_builder()
+///             .bind_vertex_array(&mesh)
+///             .bind_resources(())
+///             .draw_indexed(mesh.index_count(), 1)
+///             .finish()
+///     })
+/// });
+///
+/// context.submit(render_pass);
+/// # }
+/// ```
+///
+/// [GraphicsPipelineTaskBuilder::bind_vertex_buffers]: crate::rendering::GraphicsPipelineTaskBuilder::bind_vertex_buffers
+/// [GraphicsPipelineTaskBuilder::bind_index_buffer]: crate::rendering::GraphicsPipelineTaskBuilder::bind_index_buffer
+/// [GraphicsPipelineTaskBuilder::bind_vertex_array]: crate::rendering::GraphicsPipelineTaskBuilder::bind_vertex_array
+/// [TypedVertexBuffers]: crate::pipeline::graphics::TypedVertexBuffers
+pub struct VertexArray<Vb, Ib> {
+    vertex_buffers: StaticVec<BufferDescriptor, 16>,
+    index_buffer: IndexDataDescriptor,
+    _vertex_buffers: marker::PhantomData<Vb>,
+    _index_buffer: marker::PhantomData<Ib>,
+}
+
+impl<Vb, Ib> VertexArray<Vb, Ib>
+where
+    Vb: VertexBuffers,
+    Ib: IndexData,
+{
+    /// Creates a new [VertexArray] from the given `vertex_buffers` and `index_buffer`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vertex buffers and the index buffer do not all belong to the same context.
+    pub fn new(vertex_buffers: Vb, index_buffer: Ib) -> Self {
+        let vertex_buffers = vertex_buffers
+            .encode(&mut VertexBuffersEncodingContext::new())
+            .into_descriptors();
+        let index_buffer = index_buffer.descriptor();
+        let context_id = index_buffer.buffer_data.context_id();
+
+        for (i, buffer) in vertex_buffers.iter().enumerate() {
+            if buffer.buffer_data.context_id() != context_id {
+                panic!(
+                    "Vertex buffer {} belongs to a different context than the index buffer.",
+                    i
+                );
+            }
+        }
+
+        VertexArray {
+            vertex_buffers,
+            index_buffer,
+            _vertex_buffers: marker::PhantomData,
+            _index_buffer: marker::PhantomData,
+        }
+    }
+}
+
+impl<Vb, Ib> VertexArray<Vb, Ib> {
+    /// The number of indices in the index buffer.
+    pub fn index_count(&self) -> usize {
+        self.index_buffer.len as usize
+    }
+
+    pub(crate) fn context_id(&self) -> u64 {
+        self.index_buffer.buffer_data.context_id()
+    }
+
+    pub(crate) fn vertex_buffers(&self) -> &StaticVec<BufferDescriptor, 16> {
+        &self.vertex_buffers
+    }
+
+    pub(crate) fn index_buffer(&self) -> &IndexDataDescriptor {
+        &self.index_buffer
+    }
+}
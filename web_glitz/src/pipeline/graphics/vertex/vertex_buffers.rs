@@ -1,6 +1,12 @@
+use std::fmt;
+use std::ops::Deref;
+
 use crate::buffer::{Buffer, BufferView, BufferViewMut};
+use crate::pipeline::graphics::attribute_format::VertexAttributeFormat;
 use crate::pipeline::graphics::util::BufferDescriptor;
-use crate::pipeline::graphics::{TypedVertexInputLayout, Vertex};
+use crate::pipeline::graphics::{
+    InputRate, TypedVertexInputLayout, Vertex, VertexInputLayoutDescriptor,
+};
 use staticvec::StaticVec;
 
 /// Encodes a description of a (set of) buffer(s) or buffer region(s) that can serve as the vertex
@@ -89,11 +95,140 @@ impl<'a> VertexBuffersEncoding<'a> {
             .push(BufferDescriptor::from_buffer_view(buffer.into()));
     }
 
+    /// Describes how the attributes in the `layout` are sourced from the buffers in this
+    /// encoding, as a list of [VertexAttributePointer]s.
+    ///
+    /// The `layout` is typically the vertex input layout of the graphics pipeline the buffers are
+    /// to be bound to. The [VertexAttributePointers] implement [Display], which formats them as a
+    /// human-readable table. See also [GraphicsPipelineTaskBuilder::describe_vertex_buffers].
+    ///
+    /// Attributes defined on a bind slot for which this encoding does not provide a buffer are
+    /// omitted.
+    ///
+    /// [Display]: std::fmt::Display
+    /// [GraphicsPipelineTaskBuilder::describe_vertex_buffers]: crate::rendering::GraphicsPipelineTaskBuilder::describe_vertex_buffers
+    pub fn describe(&self, layout: &VertexInputLayoutDescriptor) -> VertexAttributePointers {
+        resolve_attribute_pointers(
+            layout,
+            self.descriptors
+                .iter()
+                .map(|descriptor| descriptor.offset_in_bytes),
+        )
+    }
+
     pub(crate) fn into_descriptors(self) -> StaticVec<BufferDescriptor, 16> {
         self.descriptors
     }
 }
 
+/// Describes how the values for a vertex attribute are sourced from a vertex buffer.
+///
+/// See [VertexBuffersEncoding::describe].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct VertexAttributePointer {
+    /// The shader location of the attribute.
+    ///
+    /// Note that matrix attributes occupy one location for each of their columns, starting at this
+    /// location.
+    pub location: u32,
+
+    /// The index of the bind slot of the buffer from which the attribute values are sourced.
+    pub bind_slot: u32,
+
+    /// The offset in bytes of the first attribute value, relative to the start of the buffer.
+    ///
+    /// This is the sum of the offset of the buffer region bound to the [bind_slot] and the
+    /// [VertexAttributeDescriptor::offset_in_bytes].
+    ///
+    /// [bind_slot]: VertexAttributePointer::bind_slot
+    /// [VertexAttributeDescriptor::offset_in_bytes]: crate::pipeline::graphics::VertexAttributeDescriptor::offset_in_bytes
+    pub offset_in_bytes: u32,
+
+    /// The stride in bytes between successive attribute values.
+    pub stride_in_bytes: u32,
+
+    /// The number of instances drawn before the attribute advances to its next value, or `0` if the
+    /// attribute advances for every vertex.
+    pub divisor: u32,
+
+    /// The data format in which the attribute values are stored.
+    pub format: VertexAttributeFormat,
+}
+
+/// A list of [VertexAttributePointer]s, returned from [VertexBuffersEncoding::describe].
+///
+/// Dereferences to a slice of [VertexAttributePointer]s. Implements [Display], which formats the
+/// pointers as a table with one row per attribute.
+///
+/// [Display]: std::fmt::Display
+#[derive(Clone, PartialEq, Debug)]
+pub struct VertexAttributePointers {
+    pointers: Vec<VertexAttributePointer>,
+}
+
+impl Deref for VertexAttributePointers {
+    type Target = [VertexAttributePointer];
+
+    fn deref(&self) -> &Self::Target {
+        &self.pointers
+    }
+}
+
+impl fmt::Display for VertexAttributePointers {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>8} {:>4} {:>6} {:>6} {:>7}  format",
+            "location", "slot", "offset", "stride", "divisor"
+        )?;
+
+        for pointer in self.pointers.iter() {
+            writeln!(
+                f,
+                "{:>8} {:>4} {:>6} {:>6} {:>7}  {:?}",
+                pointer.location,
+                pointer.bind_slot,
+                pointer.offset_in_bytes,
+                pointer.stride_in_bytes,
+                pointer.divisor,
+                pointer.format
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+fn resolve_attribute_pointers<I>(
+    layout: &VertexInputLayoutDescriptor,
+    base_offsets: I,
+) -> VertexAttributePointers
+where
+    I: IntoIterator<Item = u32>,
+{
+    let mut pointers = Vec::new();
+
+    for (i, (bind_slot, base_offset)) in layout.buffer_slots().zip(base_offsets).enumerate() {
+        let divisor = match bind_slot.input_rate() {
+            InputRate::PerVertex => 0,
+            InputRate::PerInstance => 1,
+        };
+
+        for attribute in bind_slot.attributes() {
+            pointers.push(VertexAttributePointer {
+                location: attribute.location,
+                bind_slot: i as u32,
+                offset_in_bytes: base_offset + attribute.offset_in_bytes as u32,
+                stride_in_bytes: bind_slot.stride_in_bytes() as u32,
+                divisor,
+                format: attribute.format,
+            });
+        }
+    }
+
+    VertexAttributePointers { pointers }
+}
+
 impl<T> VertexBuffer for Buffer<[T]> {
     fn encode(self, encoding: &mut VertexBuffersEncoding) {
         encoding.add_vertex_buffer(&self);
@@ -211,6 +346,21 @@ where
     type Vertex = T;
 }
 
+impl VertexBuffers for () {
+    fn encode<'a>(
+        self,
+        context: &'a mut VertexBuffersEncodingContext,
+    ) -> VertexBuffersEncoding<'a> {
+        VertexBuffersEncoding::new(context)
+    }
+}
+
+// The empty set of vertex buffers trivially provides data for every attribute in the empty vertex
+// input layout.
+unsafe impl TypedVertexBuffers for () {
+    type Layout = ();
+}
+
 macro_rules! impl_vertex_buffers {
     ($($T:ident),*) => {
         #[allow(unused_parens)]
@@ -259,3 +409,79 @@ impl_vertex_buffers!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
 impl_vertex_buffers!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13);
 impl_vertex_buffers!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14);
 impl_vertex_buffers!(T0, T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12, T13, T14, T15);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::pipeline::graphics::{
+        VertexAttributeDescriptor, VertexInputLayoutDescriptorBuilder,
+    };
+
+    #[test]
+    fn test_resolve_attribute_pointers() {
+        let mut builder = VertexInputLayoutDescriptorBuilder::new(None);
+
+        builder
+            .add_buffer_slot(28, InputRate::PerVertex)
+            .add_attribute(VertexAttributeDescriptor {
+                location: 0,
+                name: None,
+                offset_in_bytes: 0,
+                format: VertexAttributeFormat::Float4_f32,
+            })
+            .add_attribute(VertexAttributeDescriptor {
+                location: 1,
+                name: None,
+                offset_in_bytes: 16,
+                format: VertexAttributeFormat::Float3_f32,
+            });
+
+        builder
+            .add_buffer_slot(16, InputRate::PerInstance)
+            .add_attribute(VertexAttributeDescriptor {
+                location: 2,
+                name: None,
+                offset_in_bytes: 0,
+                format: VertexAttributeFormat::Float4_f32,
+            });
+
+        let layout = builder.finish();
+        let pointers = resolve_attribute_pointers(&layout, vec![0, 64]);
+
+        assert_eq!(
+            &*pointers,
+            &[
+                VertexAttributePointer {
+                    location: 0,
+                    bind_slot: 0,
+                    offset_in_bytes: 0,
+                    stride_in_bytes: 28,
+                    divisor: 0,
+                    format: VertexAttributeFormat::Float4_f32,
+                },
+                VertexAttributePointer {
+                    location: 1,
+                    bind_slot: 0,
+                    offset_in_bytes: 16,
+                    stride_in_bytes: 28,
+                    divisor: 0,
+                    format: VertexAttributeFormat::Float3_f32,
+                },
+                VertexAttributePointer {
+                    location: 2,
+                    bind_slot: 1,
+                    offset_in_bytes: 64,
+                    stride_in_bytes: 16,
+                    divisor: 1,
+                    format: VertexAttributeFormat::Float4_f32,
+                },
+            ][..]
+        );
+
+        assert_eq!(
+            pointers.to_string().lines().nth(3),
+            Some("       2    1     64     16       1  Float4_f32")
+        );
+    }
+}
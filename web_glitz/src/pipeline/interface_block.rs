@@ -14,7 +14,7 @@
 /// `std140` memory units defined in the [std140] crate:
 ///
 /// ```
-/// # #![feature(const_fn, const_loop, const_if_match, const_ptr_offset_from, const_transmute, ptr_offset_from)]
+/// # #![feature(const_fn, const_loop, const_if_match, const_panic, const_ptr_offset_from, const_transmute, ptr_offset_from)]
 /// use web_glitz::pipeline::interface_block::InterfaceBlock;
 ///
 /// #[std140::repr_std140]
@@ -29,7 +29,7 @@
 /// [InterfaceBlock]:
 ///
 /// ```
-/// # #![feature(const_fn, const_loop, const_if_match, const_ptr_offset_from, const_transmute, ptr_offset_from)]
+/// # #![feature(const_fn, const_loop, const_if_match, const_panic, const_ptr_offset_from, const_transmute, ptr_offset_from)]
 /// use web_glitz::pipeline::interface_block::InterfaceBlock;
 ///
 /// #[std140::repr_std140]
@@ -63,6 +63,12 @@
 /// implemented on types to mark them as having a stable representation. [StableRepr] is already
 /// implemented for all [std140::ReprStd140] types, including any struct marked with
 /// `#[std140::repr_std140]`.
+///
+/// When deriving [InterfaceBlock], the offset of each field is verified at compile time against
+/// the offset the std140 layout rules dictate for that field (see
+/// [InterfaceBlockComponent::STD140_BASE_ALIGNMENT]). A field that is not correctly padded causes a
+/// compilation error that names the offending field. Note that this check requires the
+/// `const_panic` feature.
 pub unsafe trait InterfaceBlock: StableRepr {
     const MEMORY_UNITS: &'static [MemoryUnit];
 }
@@ -82,6 +88,14 @@ pub unsafe trait InterfaceBlock: StableRepr {
 /// layout specified by [MEMORY_UNITS].
 pub unsafe trait InterfaceBlockComponent: StableRepr {
     const MEMORY_UNITS: &'static [MemoryUnit];
+
+    /// The base alignment of this component under the std140 layout rules, or `None` if it is not
+    /// known.
+    ///
+    /// When [InterfaceBlock] is derived for a struct, the offset of each field is checked at
+    /// compile time against the offset the std140 layout rules dictate for that field. This check
+    /// is skipped for fields of a type for which this is `None`. Defaults to `None`.
+    const STD140_BASE_ALIGNMENT: Option<usize> = None;
 }
 
 unsafe impl<T> InterfaceBlockComponent for T
@@ -89,6 +103,9 @@ where
     T: InterfaceBlock,
 {
     const MEMORY_UNITS: &'static [MemoryUnit] = T::MEMORY_UNITS;
+
+    // The base alignment of a structure is rounded up to the base alignment of a `vec4`.
+    const STD140_BASE_ALIGNMENT: Option<usize> = Some(16);
 }
 
 /// Marker trait for types that are guaranteed have a stable memory representation across builds.
@@ -131,6 +148,28 @@ pub struct MemoryUnit {
     ///
     /// See the documentation [UnitLayout] for details.
     pub layout: UnitLayout,
+
+    /// The struct field that declared this [MemoryUnit], if known.
+    ///
+    /// When [InterfaceBlock] is derived, this identifies the field in the (innermost) struct that
+    /// declared the memory unit. This is used to name the offending field when an interface block
+    /// type does not match the layout of a uniform block declared in a pipeline's shader stages
+    /// (see the [Display] implementation of [IncompatibleInterface]). Manual implementations of
+    /// [InterfaceBlockComponent] may set this to `None`.
+    ///
+    /// [Display]: std::fmt::Display
+    /// [IncompatibleInterface]: crate::pipeline::resources::IncompatibleInterface
+    pub field: Option<MemoryUnitField>,
+}
+
+/// Identifies the struct field that declared a [MemoryUnit].
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct MemoryUnitField {
+    /// The name of the struct that declares the field.
+    pub struct_name: &'static str,
+
+    /// The name of the field, or its position for a tuple struct.
+    pub field_name: &'static str,
 }
 
 /// Enumerates the value orderings in memory for matrices.
@@ -326,34 +365,38 @@ pub enum UnitLayout {
 unsafe impl<T> StableRepr for T where T: std140::ReprStd140 {}
 
 macro_rules! impl_interface_block_component_std140 {
-    ($T:ident, $layout:expr) => {
+    ($T:ident, $alignment:expr, $layout:expr) => {
         unsafe impl InterfaceBlockComponent for std140::$T {
             const MEMORY_UNITS: &'static [MemoryUnit] = &[MemoryUnit {
                 offset: 0,
                 layout: $layout,
+                field: None,
             }];
+
+            const STD140_BASE_ALIGNMENT: Option<usize> = Some($alignment);
         }
     };
 }
 
-impl_interface_block_component_std140!(float, UnitLayout::Float);
-impl_interface_block_component_std140!(vec2, UnitLayout::FloatVector2);
-impl_interface_block_component_std140!(vec3, UnitLayout::FloatVector3);
-impl_interface_block_component_std140!(vec4, UnitLayout::FloatVector4);
-impl_interface_block_component_std140!(int, UnitLayout::Integer);
-impl_interface_block_component_std140!(ivec2, UnitLayout::IntegerVector2);
-impl_interface_block_component_std140!(ivec3, UnitLayout::IntegerVector3);
-impl_interface_block_component_std140!(ivec4, UnitLayout::IntegerVector4);
-impl_interface_block_component_std140!(uint, UnitLayout::UnsignedInteger);
-impl_interface_block_component_std140!(uvec2, UnitLayout::UnsignedIntegerVector2);
-impl_interface_block_component_std140!(uvec3, UnitLayout::UnsignedIntegerVector3);
-impl_interface_block_component_std140!(uvec4, UnitLayout::UnsignedIntegerVector4);
-impl_interface_block_component_std140!(boolean, UnitLayout::Bool);
-impl_interface_block_component_std140!(bvec2, UnitLayout::BoolVector2);
-impl_interface_block_component_std140!(bvec3, UnitLayout::BoolVector3);
-impl_interface_block_component_std140!(bvec4, UnitLayout::BoolVector4);
+impl_interface_block_component_std140!(float, 4, UnitLayout::Float);
+impl_interface_block_component_std140!(vec2, 8, UnitLayout::FloatVector2);
+impl_interface_block_component_std140!(vec3, 16, UnitLayout::FloatVector3);
+impl_interface_block_component_std140!(vec4, 16, UnitLayout::FloatVector4);
+impl_interface_block_component_std140!(int, 4, UnitLayout::Integer);
+impl_interface_block_component_std140!(ivec2, 8, UnitLayout::IntegerVector2);
+impl_interface_block_component_std140!(ivec3, 16, UnitLayout::IntegerVector3);
+impl_interface_block_component_std140!(ivec4, 16, UnitLayout::IntegerVector4);
+impl_interface_block_component_std140!(uint, 4, UnitLayout::UnsignedInteger);
+impl_interface_block_component_std140!(uvec2, 8, UnitLayout::UnsignedIntegerVector2);
+impl_interface_block_component_std140!(uvec3, 16, UnitLayout::UnsignedIntegerVector3);
+impl_interface_block_component_std140!(uvec4, 16, UnitLayout::UnsignedIntegerVector4);
+impl_interface_block_component_std140!(boolean, 4, UnitLayout::Bool);
+impl_interface_block_component_std140!(bvec2, 8, UnitLayout::BoolVector2);
+impl_interface_block_component_std140!(bvec3, 16, UnitLayout::BoolVector3);
+impl_interface_block_component_std140!(bvec4, 16, UnitLayout::BoolVector4);
 impl_interface_block_component_std140!(
     mat2x2,
+    16,
     UnitLayout::Matrix2x2 {
         order: MatrixOrder::ColumnMajor,
         matrix_stride: 16
@@ -361,6 +404,7 @@ impl_interface_block_component_std140!(
 );
 impl_interface_block_component_std140!(
     mat2x3,
+    16,
     UnitLayout::Matrix2x3 {
         order: MatrixOrder::ColumnMajor,
         matrix_stride: 16
@@ -368,6 +412,7 @@ impl_interface_block_component_std140!(
 );
 impl_interface_block_component_std140!(
     mat2x4,
+    16,
     UnitLayout::Matrix2x4 {
         order: MatrixOrder::ColumnMajor,
         matrix_stride: 16
@@ -375,6 +420,7 @@ impl_interface_block_component_std140!(
 );
 impl_interface_block_component_std140!(
     mat3x2,
+    16,
     UnitLayout::Matrix3x2 {
         order: MatrixOrder::ColumnMajor,
         matrix_stride: 16
@@ -382,6 +428,7 @@ impl_interface_block_component_std140!(
 );
 impl_interface_block_component_std140!(
     mat3x3,
+    16,
     UnitLayout::Matrix3x3 {
         order: MatrixOrder::ColumnMajor,
         matrix_stride: 16
@@ -389,6 +436,7 @@ impl_interface_block_component_std140!(
 );
 impl_interface_block_component_std140!(
     mat3x4,
+    16,
     UnitLayout::Matrix3x4 {
         order: MatrixOrder::ColumnMajor,
         matrix_stride: 16
@@ -396,6 +444,7 @@ impl_interface_block_component_std140!(
 );
 impl_interface_block_component_std140!(
     mat4x2,
+    16,
     UnitLayout::Matrix4x2 {
         order: MatrixOrder::ColumnMajor,
         matrix_stride: 16
@@ -403,6 +452,7 @@ impl_interface_block_component_std140!(
 );
 impl_interface_block_component_std140!(
     mat4x3,
+    16,
     UnitLayout::Matrix4x3 {
         order: MatrixOrder::ColumnMajor,
         matrix_stride: 16
@@ -410,6 +460,7 @@ impl_interface_block_component_std140!(
 );
 impl_interface_block_component_std140!(
     mat4x4,
+    16,
     UnitLayout::Matrix4x4 {
         order: MatrixOrder::ColumnMajor,
         matrix_stride: 16
@@ -427,7 +478,11 @@ macro_rules! impl_interface_block_component_std140_array {
                     stride: 16,
                     len: LEN,
                 },
+                field: None,
             }];
+
+            // The base alignment of an array is rounded up to the base alignment of a `vec4`.
+            const STD140_BASE_ALIGNMENT: Option<usize> = Some(16);
         }
     };
 }
@@ -462,7 +517,10 @@ macro_rules! impl_interface_block_component_std140_matrix_array {
                     matrix_stride: 16,
                     len: LEN,
                 },
+                field: None,
             }];
+
+            const STD140_BASE_ALIGNMENT: Option<usize> = Some(16);
         }
     };
 }
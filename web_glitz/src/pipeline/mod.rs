@@ -11,7 +11,14 @@
 //! Pipeline resources are memory resources that shared by all pipeline invocations. This
 //! distinguishes resources from inputs, where each invocation receives separate inputs. See
 //! [resources] for details.
+//!
+//! # Double precision emulation
+//!
+//! WebGL 2.0 shaders do not support double precision values. The [split_double] module provides
+//! interface block types that represent a double precision value as a pair of single precision
+//! values, see [split_double] for details.
 
 pub mod graphics;
 pub mod interface_block;
 pub mod resources;
+pub mod split_double;
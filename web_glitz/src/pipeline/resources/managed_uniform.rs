@@ -0,0 +1,151 @@
+use std::mem;
+use std::slice;
+use std::sync::{Arc, Mutex};
+
+use web_sys::WebGl2RenderingContext as Gl;
+
+use crate::buffer::{Buffer, BufferData, UsageHint};
+use crate::pipeline::interface_block::InterfaceBlock;
+use crate::runtime::state::ContextUpdate;
+use crate::runtime::{Connection, RenderingContext};
+
+/// A uniform block resource that manages its own GPU-accessible memory.
+///
+/// WebGlitz does not support plain (non-block) uniforms; data for the programmable stages of a
+/// pipeline must be provided through uniform blocks that are backed by a [Buffer]. For small values
+/// that change frequently (e.g. a time value or a color), managing a separate buffer and submitting
+/// upload commands can be cumbersome. A [ManagedUniform] owns a small buffer that it keeps hidden;
+/// new values are set with [set](ManagedUniform::set) and are uploaded to the buffer the next time
+/// a bind group that contains the [ManagedUniform] is bound to a pipeline.
+///
+/// A [ManagedUniform] may be used as a [Resource] in a bind group:
+///
+/// ```
+/// # #![feature(const_fn, const_loop, const_if_match, const_panic, const_ptr_offset_from, const_transmute, ptr_offset_from)]
+/// # use web_glitz::runtime::RenderingContext;
+/// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
+/// use web_glitz::pipeline::resources::ManagedUniform;
+///
+/// #[std140::repr_std140]
+/// #[derive(web_glitz::derive::InterfaceBlock, Clone, Copy)]
+/// struct Time {
+///     seconds: std140::float,
+/// }
+///
+/// #[derive(web_glitz::derive::Resources)]
+/// struct Resources<'a> {
+///     #[resource(binding=0, name="Time")]
+///     time: &'a ManagedUniform<Time>,
+/// }
+///
+/// let time = ManagedUniform::new(context);
+///
+/// let bind_group = context.create_bind_group(Resources {
+///     time: &time,
+/// });
+///
+/// // Later, e.g. once per frame:
+/// time.set(Time {
+///     seconds: std140::float(1.5),
+/// });
+/// # }
+/// ```
+///
+/// Only the value that was set last before the bind group is bound is uploaded; values that are
+/// set and then replaced before the bind group is next bound are never uploaded.
+///
+/// Note that the value is uploaded when the bind group is bound during the execution of a
+/// pipeline task, not when [set](ManagedUniform::set) is called. If the same [ManagedUniform] is
+/// bound by more than one pipeline task, then the tasks that execute after the value was set will
+/// all observe the new value.
+///
+/// [Resource]: crate::pipeline::resources::Resource
+pub struct ManagedUniform<T> {
+    buffer: Buffer<T>,
+    pending: Arc<PendingUniformUpload>,
+}
+
+impl<T> ManagedUniform<T>
+where
+    T: InterfaceBlock + Copy + 'static,
+{
+    /// Creates a new [ManagedUniform] for the given `context`.
+    ///
+    /// The uniform's memory is zero-initialized until a value is set with
+    /// [set](ManagedUniform::set).
+    pub fn new<Rc>(context: &Rc) -> Self
+    where
+        Rc: RenderingContext,
+    {
+        // WebGL zero-initializes the data store for newly allocated buffer objects and all types
+        // that implement `InterfaceBlock` are valid when zeroed.
+        let buffer = unsafe {
+            context
+                .create_buffer_uninit::<T>(UsageHint::DynamicDraw)
+                .assume_init()
+        };
+
+        ManagedUniform {
+            buffer,
+            pending: Arc::new(PendingUniformUpload {
+                data: Mutex::new(None),
+            }),
+        }
+    }
+
+    /// Sets the value of this [ManagedUniform].
+    ///
+    /// The value is uploaded the next time a bind group that contains this [ManagedUniform] is
+    /// bound to a pipeline; if another value is set before then, then this value is discarded.
+    pub fn set(&self, value: T) {
+        let bytes =
+            unsafe { slice::from_raw_parts(&value as *const T as *const u8, mem::size_of::<T>()) };
+
+        *self.pending.data.lock().unwrap() = Some(bytes.to_vec());
+    }
+}
+
+impl<T> ManagedUniform<T> {
+    pub(crate) fn buffer_data(&self) -> &Arc<BufferData> {
+        self.buffer.data()
+    }
+
+    pub(crate) fn pending(&self) -> &Arc<PendingUniformUpload> {
+        &self.pending
+    }
+
+    pub(crate) fn size_in_bytes(&self) -> usize {
+        mem::size_of::<T>()
+    }
+}
+
+pub(crate) struct PendingUniformUpload {
+    data: Mutex<Option<Vec<u8>>>,
+}
+
+impl PendingUniformUpload {
+    /// Uploads the value that was set last (if any) to the buffer described by `buffer_data`.
+    pub(crate) fn flush(&self, buffer_data: &BufferData, connection: &mut Connection) {
+        let data = self.data.lock().unwrap().take();
+
+        if let Some(data) = data {
+            let (gl, state) = unsafe { connection.unpack_mut() };
+
+            unsafe {
+                buffer_data
+                    .id()
+                    .unwrap()
+                    .with_value_unchecked(|buffer_object| {
+                        state
+                            .bind_copy_write_buffer(Some(&buffer_object))
+                            .apply(gl)
+                            .unwrap();
+                    });
+            }
+
+            gl.buffer_sub_data_with_i32_and_u8_array(Gl::COPY_WRITE_BUFFER, 0, &data);
+
+            state.record_buffer_upload();
+        }
+    }
+}
@@ -1,11 +1,15 @@
+mod managed_uniform;
+pub use self::managed_uniform::ManagedUniform;
+
 mod resources;
 pub use self::resources::{
     BindGroup, BindGroupLayoutBuilder, EncodeBindableResourceGroup, IncompatibleResources,
     InvalidBindGroupSequence, InvalidResourceSlotSequence, LayoutAllocationHint, Resource,
     ResourceBindings, ResourceBindingsLayoutBuilder, ResourceBindingsLayoutBuilderError,
     ResourceBindingsLayoutDescriptor, ResourceSlotDescriptor, ResourceSlotIdentifier,
-    ResourceSlotKind, ResourceSlotType, Resources, SampledTextureType, TypedBindableResourceGroup,
-    TypedResourceBindings, TypedResourceBindingsLayout, TypedResourceBindingsLayoutDescriptor,
+    ResourceSlotKind, ResourceSlotType, ResourceSlotUsage, ResourceVisibility, Resources,
+    SampledTextureType, TypedBindableResourceGroup, TypedResourceBindings,
+    TypedResourceBindingsLayout, TypedResourceBindingsLayoutDescriptor,
     TypedResourceSlotDescriptor,
 };
 
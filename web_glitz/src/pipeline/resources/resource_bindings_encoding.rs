@@ -1,5 +1,7 @@
 use std::borrow::Borrow;
+use std::fmt;
 use std::marker;
+use std::ops::RangeInclusive;
 use std::sync::Arc;
 
 use crate::buffer::{BufferData, BufferView};
@@ -19,9 +21,14 @@ use crate::image::texture_cube::{
     FloatSampledTextureCube, IntegerSampledTextureCube, ShadowSampledTextureCube, TextureCubeData,
     UnsignedIntegerSampledTextureCube,
 };
-use crate::pipeline::resources::resources::{BindGroup, BindGroupInternal};
+use crate::pipeline::resources::managed_uniform::{ManagedUniform, PendingUniformUpload};
+use crate::pipeline::resources::resources::{
+    BindGroup, BindGroupInternal, Resource, ResourceSlotKind, ResourceSlotType, ResourceSlotUsage,
+    SampledTextureType,
+};
 use crate::runtime::state::{BufferRange, ContextUpdate};
 use crate::runtime::Connection;
+use crate::util::JsId;
 
 pub struct BindGroupEncoding<'a, E> {
     #[allow(dead_code)]
@@ -47,7 +54,62 @@ pub(crate) struct ResourceBindingDescriptor {
 }
 
 impl ResourceBindingDescriptor {
+    /// Returns the texture unit, the ID of the texture and the range of mipmap levels that may be
+    /// sampled if this is a sampled texture binding, or `None` otherwise.
+    pub(crate) fn sampled_texture(&self) -> Option<(u32, JsId, RangeInclusive<u8>)> {
+        if let BindingDescriptorInternal::SampledTexture {
+            unit, texture_data, ..
+        } = &self.internal
+        {
+            let (id, levels) = match texture_data {
+                TextureData::Texture2D(data, Some((base_level, max_level))) => {
+                    (data.id(), *base_level..=*max_level)
+                }
+                TextureData::Texture2D(data, None) => (data.id(), 0..=u8::MAX),
+                TextureData::Texture2DArray(data) => (data.id(), 0..=u8::MAX),
+                TextureData::Texture3D(data) => (data.id(), 0..=u8::MAX),
+                TextureData::TextureCube(data) => (data.id(), 0..=u8::MAX),
+            };
+
+            id.map(|id| (*unit, id, levels))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the resource slot this binding binds to.
+    pub(crate) fn slot_index(&self) -> u32 {
+        match &self.internal {
+            BindingDescriptorInternal::BufferView { index, .. } => *index,
+            BindingDescriptorInternal::SampledTexture { unit, .. } => *unit,
+            BindingDescriptorInternal::Null { slot, .. } => *slot,
+        }
+    }
+
+    /// Returns the kind of the resource slot this binding binds to.
+    pub(crate) fn slot_kind(&self) -> ResourceSlotKind {
+        match &self.internal {
+            BindingDescriptorInternal::BufferView { .. } => ResourceSlotKind::UniformBuffer,
+            BindingDescriptorInternal::SampledTexture { .. } => ResourceSlotKind::SampledTexture,
+            BindingDescriptorInternal::Null { slot_type, .. } => (*slot_type).into(),
+        }
+    }
+
+    /// Uploads any pending data for a managed uniform binding, without binding the resource.
+    pub(crate) fn flush_pending_upload(&self, connection: &mut Connection) {
+        if let BindingDescriptorInternal::BufferView {
+            buffer_data,
+            pending_upload: Some(pending_upload),
+            ..
+        } = &self.internal
+        {
+            pending_upload.flush(buffer_data, connection);
+        }
+    }
+
     pub(crate) fn bind(&self, connection: &mut Connection) {
+        self.flush_pending_upload(connection);
+
         let (gl, state) = unsafe { connection.unpack_mut() };
 
         match &self.internal {
@@ -56,6 +118,7 @@ impl ResourceBindingDescriptor {
                 buffer_data,
                 offset,
                 size,
+                ..
             } => unsafe {
                 buffer_data
                     .id()
@@ -81,14 +144,20 @@ impl ResourceBindingDescriptor {
                 state.set_active_texture(*unit).apply(gl).unwrap();
 
                 match texture_data {
-                    TextureData::Texture2D(data) => unsafe {
-                        data.id().unwrap().with_value_unchecked(|texture_object| {
-                            state
-                                .bind_texture_2d(Some(texture_object))
-                                .apply(gl)
-                                .unwrap();
-                        });
-                    },
+                    TextureData::Texture2D(data, level_range) => {
+                        unsafe {
+                            data.id().unwrap().with_value_unchecked(|texture_object| {
+                                state
+                                    .bind_texture_2d(Some(texture_object))
+                                    .apply(gl)
+                                    .unwrap();
+                            });
+                        }
+
+                        // Restores the texture's own level range if the texture was last bound with
+                        // a different level range.
+                        data.apply_level_range(gl, state, *level_range);
+                    }
                     TextureData::Texture2DArray(data) => unsafe {
                         data.id().unwrap().with_value_unchecked(|texture_object| {
                             state
@@ -115,18 +184,64 @@ impl ResourceBindingDescriptor {
                     },
                 }
 
-                unsafe {
-                    sampler_data
-                        .id()
-                        .unwrap()
-                        .with_value_unchecked(|sampler_object| {
-                            state
-                                .bind_sampler(*unit, Some(sampler_object))
-                                .apply(gl)
-                                .unwrap();
-                        });
+                if let Some(sampler_data) = sampler_data {
+                    unsafe {
+                        sampler_data
+                            .id()
+                            .unwrap()
+                            .with_value_unchecked(|sampler_object| {
+                                state
+                                    .bind_sampler(*unit, Some(sampler_object))
+                                    .apply(gl)
+                                    .unwrap();
+                            });
+                    }
+                } else {
+                    // Unbind any sampler object, so that the texture is read with its own sampling
+                    // parameters (see `image::util::set_unsampled_parameters`).
+                    state.bind_sampler(*unit, None).apply(gl).unwrap();
                 }
             }
+            BindingDescriptorInternal::Null { slot, slot_type } => match slot_type {
+                ResourceSlotType::UniformBuffer(_) => {
+                    state.set_active_uniform_buffer_index(*slot);
+                    state
+                        .bind_uniform_buffer_range(BufferRange::None)
+                        .apply(gl)
+                        .unwrap();
+                }
+                ResourceSlotType::SampledTexture(texture_type) => {
+                    state.set_active_texture(*slot).apply(gl).unwrap();
+
+                    match texture_type {
+                        SampledTextureType::FloatSampler2D
+                        | SampledTextureType::IntegerSampler2D
+                        | SampledTextureType::UnsignedIntegerSampler2D
+                        | SampledTextureType::Sampler2DShadow => {
+                            state.bind_texture_2d(None).apply(gl).unwrap();
+                        }
+                        SampledTextureType::FloatSampler2DArray
+                        | SampledTextureType::IntegerSampler2DArray
+                        | SampledTextureType::UnsignedIntegerSampler2DArray
+                        | SampledTextureType::Sampler2DArrayShadow => {
+                            state.bind_texture_2d_array(None).apply(gl).unwrap();
+                        }
+                        SampledTextureType::FloatSampler3D
+                        | SampledTextureType::IntegerSampler3D
+                        | SampledTextureType::UnsignedIntegerSampler3D => {
+                            state.bind_texture_3d(None).apply(gl).unwrap();
+                        }
+                        SampledTextureType::FloatSamplerCube
+                        | SampledTextureType::IntegerSamplerCube
+                        | SampledTextureType::UnsignedIntegerSamplerCube
+                        | SampledTextureType::SamplerCubeShadow => {
+                            state.bind_texture_cube_map(None).apply(gl).unwrap();
+                        }
+                    }
+
+                    state.bind_sampler(*slot, None).apply(gl).unwrap();
+                }
+            },
         }
     }
 }
@@ -137,16 +252,24 @@ enum BindingDescriptorInternal {
         buffer_data: Arc<BufferData>,
         offset: usize,
         size: usize,
+        pending_upload: Option<Arc<PendingUniformUpload>>,
     },
     SampledTexture {
         unit: u32,
-        sampler_data: Arc<SamplerData>,
+        sampler_data: Option<Arc<SamplerData>>,
         texture_data: TextureData,
     },
+    // Binds no resource to the slot, see `BindGroupEncoder::add_optional`.
+    Null {
+        slot: u32,
+        slot_type: ResourceSlotType,
+    },
 }
 
 enum TextureData {
-    Texture2D(Arc<Texture2DData>),
+    // The optional level range restricts the levels that may be sampled to the base level up to
+    // and including the max level (see `FloatSampledTexture2D::with_level_range`).
+    Texture2D(Arc<Texture2DData>, Option<(u8, u8)>),
     Texture2DArray(Arc<Texture2DArrayData>),
     Texture3D(Arc<Texture3DData>),
     TextureCube(Arc<TextureCubeData>),
@@ -185,6 +308,51 @@ impl<'a> BindGroupEncoder<'a, ()> {
 }
 
 impl<'a, E> BindGroupEncoder<'a, E> {
+    /// Adds the `resource` to the bind group at the `slot` if it is `Some`, or binds no resource
+    /// to the `slot` if the `resource` is `None`.
+    ///
+    /// When a bind group binds no resource to a slot, then any resource that was previously bound
+    /// to the slot's binding index is unbound: no buffer is bound to a uniform buffer binding
+    /// index, no texture (of the texture target that matches `R`) and no sampler is bound to a
+    /// texture unit.
+    pub fn add_optional<R>(
+        self,
+        slot: u32,
+        resource: Option<R>,
+    ) -> BindGroupEncoder<'a, (Option<R::Encoding>, E)>
+    where
+        R: Resource,
+    {
+        let (context, bindings) = if let Some(resource) = resource {
+            let BindGroupEncoder {
+                context, bindings, ..
+            } = resource.encode(slot, self);
+
+            (context, bindings)
+        } else {
+            let BindGroupEncoder {
+                context,
+                mut bindings,
+                ..
+            } = self;
+
+            bindings.push(ResourceBindingDescriptor {
+                internal: BindingDescriptorInternal::Null {
+                    slot,
+                    slot_type: R::TYPE,
+                },
+            });
+
+            (context, bindings)
+        };
+
+        BindGroupEncoder {
+            context,
+            bindings,
+            _marker: marker::PhantomData,
+        }
+    }
+
     pub fn add_buffer_view<'b, T>(
         self,
         slot: u32,
@@ -197,7 +365,10 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if buffer_view.buffer_data().context_id() != context.context_id {
-            panic!("Buffer does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                buffer_view.buffer_data().label().describe("Buffer")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
@@ -206,6 +377,39 @@ impl<'a, E> BindGroupEncoder<'a, E> {
                 buffer_data: buffer_view.buffer_data().clone(),
                 offset: buffer_view.offset_in_bytes(),
                 size: buffer_view.size_in_bytes(),
+                pending_upload: None,
+            },
+        });
+
+        BindGroupEncoder {
+            context,
+            bindings,
+            _marker: marker::PhantomData,
+        }
+    }
+
+    pub fn add_managed_uniform<'b, T>(
+        self,
+        slot: u32,
+        managed_uniform: &'b ManagedUniform<T>,
+    ) -> BindGroupEncoder<'a, (&'b ManagedUniform<T>, E)> {
+        let BindGroupEncoder {
+            context,
+            mut bindings,
+            ..
+        } = self;
+
+        if managed_uniform.buffer_data().context_id() != context.context_id {
+            panic!("Managed uniform does not belong to same context as the bind group encoder");
+        }
+
+        bindings.push(ResourceBindingDescriptor {
+            internal: BindingDescriptorInternal::BufferView {
+                index: slot,
+                buffer_data: managed_uniform.buffer_data().clone(),
+                offset: 0,
+                size: managed_uniform.size_in_bytes(),
+                pending_upload: Some(managed_uniform.pending().clone()),
             },
         });
 
@@ -228,14 +432,20 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if sampled_texture.texture_data.context_id() != context.context_id {
-            panic!("Texture does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                sampled_texture.texture_data.label().describe("Texture")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
             internal: BindingDescriptorInternal::SampledTexture {
                 unit: slot,
                 sampler_data: sampled_texture.sampler_data.clone(),
-                texture_data: TextureData::Texture2D(sampled_texture.texture_data.clone()),
+                texture_data: TextureData::Texture2D(
+                    sampled_texture.texture_data.clone(),
+                    sampled_texture.level_range,
+                ),
             },
         });
 
@@ -258,7 +468,10 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if sampled_texture.texture_data.context_id() != context.context_id {
-            panic!("Texture does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                sampled_texture.texture_data.label().describe("Texture")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
@@ -288,7 +501,10 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if sampled_texture.texture_data.context_id() != context.context_id {
-            panic!("Texture does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                sampled_texture.texture_data.label().describe("Texture")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
@@ -318,7 +534,10 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if sampled_texture.texture_data.context_id() != context.context_id {
-            panic!("Texture does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                sampled_texture.texture_data.label().describe("Texture")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
@@ -348,14 +567,17 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if sampled_texture.texture_data.context_id() != context.context_id {
-            panic!("Texture does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                sampled_texture.texture_data.label().describe("Texture")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
             internal: BindingDescriptorInternal::SampledTexture {
                 unit: slot,
                 sampler_data: sampled_texture.sampler_data.clone(),
-                texture_data: TextureData::Texture2D(sampled_texture.texture_data.clone()),
+                texture_data: TextureData::Texture2D(sampled_texture.texture_data.clone(), None),
             },
         });
 
@@ -378,7 +600,10 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if sampled_texture.texture_data.context_id() != context.context_id {
-            panic!("Texture does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                sampled_texture.texture_data.label().describe("Texture")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
@@ -408,7 +633,10 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if sampled_texture.texture_data.context_id() != context.context_id {
-            panic!("Texture does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                sampled_texture.texture_data.label().describe("Texture")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
@@ -438,7 +666,10 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if sampled_texture.texture_data.context_id() != context.context_id {
-            panic!("Texture does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                sampled_texture.texture_data.label().describe("Texture")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
@@ -468,14 +699,17 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if sampled_texture.texture_data.context_id() != context.context_id {
-            panic!("Texture does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                sampled_texture.texture_data.label().describe("Texture")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
             internal: BindingDescriptorInternal::SampledTexture {
                 unit: slot,
                 sampler_data: sampled_texture.sampler_data.clone(),
-                texture_data: TextureData::Texture2D(sampled_texture.texture_data.clone()),
+                texture_data: TextureData::Texture2D(sampled_texture.texture_data.clone(), None),
             },
         });
 
@@ -498,7 +732,10 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if sampled_texture.texture_data.context_id() != context.context_id {
-            panic!("Texture does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                sampled_texture.texture_data.label().describe("Texture")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
@@ -528,7 +765,10 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if sampled_texture.texture_data.context_id() != context.context_id {
-            panic!("Texture does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                sampled_texture.texture_data.label().describe("Texture")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
@@ -558,7 +798,10 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if sampled_texture.texture_data.context_id() != context.context_id {
-            panic!("Texture does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                sampled_texture.texture_data.label().describe("Texture")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
@@ -588,14 +831,17 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if sampled_texture.texture_data.context_id() != context.context_id {
-            panic!("Texture does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                sampled_texture.texture_data.label().describe("Texture")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
             internal: BindingDescriptorInternal::SampledTexture {
                 unit: slot,
                 sampler_data: sampled_texture.sampler_data.clone(),
-                texture_data: TextureData::Texture2D(sampled_texture.texture_data.clone()),
+                texture_data: TextureData::Texture2D(sampled_texture.texture_data.clone(), None),
             },
         });
 
@@ -618,7 +864,10 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if sampled_texture.texture_data.context_id() != context.context_id {
-            panic!("Texture does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                sampled_texture.texture_data.label().describe("Texture")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
@@ -648,7 +897,10 @@ impl<'a, E> BindGroupEncoder<'a, E> {
         } = self;
 
         if sampled_texture.texture_data.context_id() != context.context_id {
-            panic!("Texture does not belong to same context as the bind group encoder");
+            panic!(
+                "{} does not belong to same context as the bind group encoder",
+                sampled_texture.texture_data.label().describe("Texture")
+            );
         }
 
         bindings.push(ResourceBindingDescriptor {
@@ -720,21 +972,95 @@ impl_finish_bind_group_encoding!(
 
 #[derive(Clone)]
 pub struct BindGroupDescriptor {
-    #[allow(dead_code)]
     pub(crate) bind_group_index: u32,
+    pub(crate) object_id: Option<u64>,
     pub(crate) bindings: Option<Arc<Vec<ResourceBindingDescriptor>>>,
 }
 
+impl fmt::Debug for BindGroupDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("BindGroupDescriptor")
+            .field("bind_group_index", &self.bind_group_index)
+            .field("object_id", &self.object_id)
+            .finish()
+    }
+}
+
 impl BindGroupDescriptor {
-    pub(crate) fn bind(&self, connection: &mut Connection) {
-        if let Some(bindings) = &self.bindings {
-            for binding in bindings.iter() {
-                binding.bind(connection);
+    /// Returns an iterator over the texture units, texture IDs and sampled level ranges of the
+    /// sampled textures in this bind group.
+    pub(crate) fn sampled_textures(
+        &self,
+    ) -> impl Iterator<Item = (u32, JsId, RangeInclusive<u8>)> + '_ {
+        self.bindings
+            .iter()
+            .flat_map(|bindings| bindings.iter())
+            .filter_map(|binding| binding.sampled_texture())
+    }
+
+    /// Binds the resources in this bind group, unless this bind group is still bound at its bind
+    /// group index, in which case only pending managed uniform data is uploaded.
+    ///
+    /// Resources in slots that the `slot_usage` of the active pipeline marks as unused are not
+    /// bound. A bind group for which any resources were skipped is not recorded as bound, so that
+    /// it is bound in full for the next pipeline that may use these slots.
+    pub(crate) fn bind(&self, connection: &mut Connection, slot_usage: &[ResourceSlotUsage]) {
+        if let (Some(object_id), Some(bindings)) = (self.object_id, &self.bindings) {
+            let (_, state) = unsafe { connection.unpack_mut() };
+
+            if state.skip_bind_group(self.bind_group_index, object_id) {
+                for binding in bindings.iter() {
+                    binding.flush_pending_upload(connection);
+                }
+            } else {
+                let mut skipped = false;
+
+                for binding in bindings.iter() {
+                    let is_unused = is_unused_slot(
+                        slot_usage,
+                        self.bind_group_index,
+                        binding.slot_kind(),
+                        binding.slot_index(),
+                    );
+
+                    if is_unused {
+                        binding.flush_pending_upload(connection);
+
+                        skipped = true;
+                    } else {
+                        binding.bind(connection);
+                    }
+                }
+
+                if !skipped {
+                    let (_, state) = unsafe { connection.unpack_mut() };
+
+                    state.set_bound_bind_group(self.bind_group_index, object_id);
+                }
             }
         }
     }
 }
 
+/// Whether the `slot_usage` of a pipeline marks the slot of the given `slot_kind` at the given
+/// `slot_index` in the bind group at the given `bind_group_index` as unused.
+///
+/// Uniform buffer slots and sampled-texture slots are indexed separately, so a slot only matches if
+/// its kind matches as well as its index.
+fn is_unused_slot(
+    slot_usage: &[ResourceSlotUsage],
+    bind_group_index: u32,
+    slot_kind: ResourceSlotKind,
+    slot_index: u32,
+) -> bool {
+    slot_usage.iter().any(|usage| {
+        usage.bind_group_index == bind_group_index
+            && usage.slot_kind == slot_kind
+            && usage.slot_index == slot_index
+            && !usage.visibility.is_used()
+    })
+}
+
 pub struct ResourceBindingsEncodingContext {
     context_id: u64,
 }
@@ -783,18 +1109,18 @@ impl<'a, B> StaticResourceBindingsEncoder<'a, B> {
         bind_group_index: u32,
         bind_group: &BindGroup<T>,
     ) -> StaticResourceBindingsEncoder<'a, (BindGroupDescriptor, B)> {
-        let bindings = match &bind_group.internal {
-            BindGroupInternal::Empty => None,
+        let (object_id, bindings) = match &bind_group.internal {
+            BindGroupInternal::Empty => (None, None),
             BindGroupInternal::NotEmpty {
+                object_id,
                 context_id,
                 encoding,
-                ..
             } => {
                 if self.context.context_id != *context_id {
                     panic!("Bind group belongs to a different context than the current pipeline.");
                 }
 
-                Some(encoding.clone())
+                (Some(*object_id), Some(encoding.clone()))
             }
         };
 
@@ -803,6 +1129,7 @@ impl<'a, B> StaticResourceBindingsEncoder<'a, B> {
             bind_groups: (
                 BindGroupDescriptor {
                     bind_group_index,
+                    object_id,
                     bindings,
                 },
                 self.bind_groups,
@@ -1005,3 +1332,81 @@ generate_encoder_finish!(
     BindGroupDescriptor | b14,
     BindGroupDescriptor | b15
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::pipeline::resources::{ResourceSlotIdentifier, ResourceVisibility};
+
+    fn usage(slot_kind: ResourceSlotKind, visibility: ResourceVisibility) -> ResourceSlotUsage {
+        ResourceSlotUsage {
+            bind_group_index: 1,
+            slot_identifier: ResourceSlotIdentifier::Static("Slot"),
+            slot_index: 0,
+            slot_kind,
+            visibility,
+        }
+    }
+
+    #[test]
+    fn test_is_unused_slot() {
+        let slot_usage = [usage(
+            ResourceSlotKind::UniformBuffer,
+            ResourceVisibility::Unused,
+        )];
+
+        assert!(is_unused_slot(
+            &slot_usage,
+            1,
+            ResourceSlotKind::UniformBuffer,
+            0
+        ));
+    }
+
+    #[test]
+    fn test_is_unused_slot_used() {
+        let slot_usage = [usage(
+            ResourceSlotKind::UniformBuffer,
+            ResourceVisibility::Fragment,
+        )];
+
+        assert!(!is_unused_slot(
+            &slot_usage,
+            1,
+            ResourceSlotKind::UniformBuffer,
+            0
+        ));
+    }
+
+    #[test]
+    fn test_is_unused_slot_other_kind() {
+        // An unused uniform buffer slot does not affect a sampled-texture slot with the same index.
+        let slot_usage = [usage(
+            ResourceSlotKind::UniformBuffer,
+            ResourceVisibility::Unused,
+        )];
+
+        assert!(!is_unused_slot(
+            &slot_usage,
+            1,
+            ResourceSlotKind::SampledTexture,
+            0
+        ));
+    }
+
+    #[test]
+    fn test_is_unused_slot_other_bind_group() {
+        let slot_usage = [usage(
+            ResourceSlotKind::UniformBuffer,
+            ResourceVisibility::Unused,
+        )];
+
+        assert!(!is_unused_slot(
+            &slot_usage,
+            0,
+            ResourceSlotKind::UniformBuffer,
+            0
+        ));
+    }
+}
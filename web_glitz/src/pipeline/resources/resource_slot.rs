@@ -1,3 +1,5 @@
+use std::fmt;
+
 use js_sys::{Uint32Array, Uint8Array};
 use web_sys::{WebGl2RenderingContext as Gl, WebGlProgram, WebGlUniformLocation};
 
@@ -485,6 +487,7 @@ impl UniformBlockSlot {
             layout.push(MemoryUnit {
                 offset: offsets[i] as usize,
                 layout: unit,
+                field: None,
             });
         }
 
@@ -526,12 +529,57 @@ impl UniformBlockSlot {
     }
 }
 
+/// Error returned when the memory layout of an interface block type does not match the layout of
+/// the uniform block declared in a pipeline's shader stages.
+///
+/// If the interface block type derives [InterfaceBlock], then [MemoryUnit::field] identifies the
+/// struct field that declared the offending memory unit.
 #[derive(Debug)]
 pub enum IncompatibleInterface {
+    /// Variant returned when the uniform block declares a memory unit (contained in this variant)
+    /// at an offset for which the interface block type does not declare a memory unit.
     MissingUnit(MemoryUnit),
+
+    /// Variant returned when the interface block type declares a memory unit (the first value) at
+    /// an offset where the uniform block declares a memory unit with a different layout (the second
+    /// value).
     UnitLayoutMismatch(MemoryUnit, UnitLayout),
 }
 
+impl fmt::Display for IncompatibleInterface {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IncompatibleInterface::MissingUnit(unit) => write!(
+                f,
+                "the interface block type declares no memory unit at offset {}, where the uniform \
+                 block declares `{:?}`",
+                unit.offset, unit.layout
+            ),
+            IncompatibleInterface::UnitLayoutMismatch(unit, expected_layout) => {
+                if let Some(field) = &unit.field {
+                    write!(
+                        f,
+                        "field `{}.{}` at offset {} has layout `{:?}`, but the uniform block \
+                         declares `{:?}`",
+                        field.struct_name,
+                        field.field_name,
+                        unit.offset,
+                        unit.layout,
+                        expected_layout
+                    )
+                } else {
+                    write!(
+                        f,
+                        "the memory unit at offset {} has layout `{:?}`, but the uniform block \
+                         declares `{:?}`",
+                        unit.offset, unit.layout, expected_layout
+                    )
+                }
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct TextureSamplerSlot {
     location: WebGlUniformLocation,
@@ -578,3 +626,64 @@ impl<'a> SlotBindingUpdater<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::pipeline::interface_block::MemoryUnitField;
+
+    #[test]
+    fn test_display_missing_unit() {
+        let error = IncompatibleInterface::MissingUnit(MemoryUnit {
+            offset: 16,
+            layout: UnitLayout::FloatVector4,
+            field: None,
+        });
+
+        assert_eq!(
+            error.to_string(),
+            "the interface block type declares no memory unit at offset 16, where the uniform \
+             block declares `FloatVector4`"
+        );
+    }
+
+    #[test]
+    fn test_display_unit_layout_mismatch_field() {
+        let error = IncompatibleInterface::UnitLayoutMismatch(
+            MemoryUnit {
+                offset: 16,
+                layout: UnitLayout::FloatVector3,
+                field: Some(MemoryUnitField {
+                    struct_name: "Light",
+                    field_name: "color",
+                }),
+            },
+            UnitLayout::FloatVector4,
+        );
+
+        assert_eq!(
+            error.to_string(),
+            "field `Light.color` at offset 16 has layout `FloatVector3`, but the uniform block \
+             declares `FloatVector4`"
+        );
+    }
+
+    #[test]
+    fn test_display_unit_layout_mismatch_unknown_field() {
+        let error = IncompatibleInterface::UnitLayoutMismatch(
+            MemoryUnit {
+                offset: 0,
+                layout: UnitLayout::Float,
+                field: None,
+            },
+            UnitLayout::Integer,
+        );
+
+        assert_eq!(
+            error.to_string(),
+            "the memory unit at offset 0 has layout `Float`, but the uniform block declares \
+             `Integer`"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 use std::borrow::Borrow;
+use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::marker;
 use std::ops::Deref;
@@ -23,6 +24,7 @@ use crate::image::texture_cube::{
     UnsignedIntegerSampledTextureCube,
 };
 use crate::pipeline::interface_block::{InterfaceBlock, MemoryUnit};
+use crate::pipeline::resources::managed_uniform::ManagedUniform;
 use crate::pipeline::resources::resource_bindings_encoding::{
     BindGroupEncoding, BindGroupEncodingContext, ResourceBindingDescriptor,
 };
@@ -40,6 +42,12 @@ use crate::pipeline::resources::{
 /// More than one bind group may be bound to a pipeline, see
 /// [GraphicsPipelineTaskBuilder::bind_resources] and
 /// [GraphicsPipelineTaskBuilder::bind_resources_untyped] for details.
+///
+/// A bind group has a stable identity: two [BindGroup] values compare equal only if they refer to
+/// the same bind group. When a bind group is bound again to the same bind group index, and none of
+/// the context's texture, sampler or uniform buffer bindings have changed since it was last bound,
+/// then re-binding its resources is skipped (any pending updates to managed uniform data are still
+/// uploaded).
 pub struct BindGroup<T> {
     pub(crate) internal: BindGroupInternal,
     _marker: marker::PhantomData<T>,
@@ -121,7 +129,7 @@ impl<T> Hash for BindGroup<T> {
 /// This type only contains the minimally necessary information for initializing a pipeline. See
 /// also [TypedResourceBindingsLayoutDescriptor] for a type that includes information that may be
 /// type checked against the resource types defined by the pipeline's shader stages.
-#[derive(Clone, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct ResourceBindingsLayoutDescriptor {
     layout: Vec<LayoutElement>,
     bind_groups: usize,
@@ -150,13 +158,13 @@ impl ResourceBindingsLayoutDescriptor {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 enum LayoutElement {
     Slot(ResourceSlotDescriptor),
     NextBindGroup(BindGroupElement),
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 struct BindGroupElement {
     bind_group_index: u32,
     len: usize,
@@ -408,6 +416,39 @@ pub struct InvalidBindGroupSequence {
 /// contiguous); all resource slots must be added to their respective bind groups in increaseing
 /// order of slot index and no 2 resource slots within the same bind group may declare the same
 /// slot index (but slot indices do not have to be contiguous).
+///
+/// # Bind groups in WebGL 2.0
+///
+/// WebGL 2.0 does not have a native concept of bind groups: uniform buffers and sampled textures
+/// are bound to two separate sets of binding points (the uniform buffer binding points and the
+/// texture units). A pipeline therefore uses exactly two implicit bind groups: the resource slots
+/// in bind group `0` are mapped to uniform buffer binding points, and the resource slots in bind
+/// group `1` are mapped to texture units; the slot index of a resource slot is used as the index
+/// of the binding point or texture unit. A layout used to create a pipeline must declare both of
+/// these bind groups, even if one of them does not contain any resource slots (use
+/// [add_empty_bind_group] to explicitly declare an empty bind group). Bind groups declared at
+/// other bind group indices are accepted by the builder, but are currently not used by pipelines:
+///
+/// ```
+/// # use web_glitz::pipeline::resources::{ResourceBindingsLayoutBuilderError};
+/// use web_glitz::pipeline::resources::{ResourceSlotDescriptor, ResourceBindingsLayoutBuilder, ResourceSlotIdentifier, ResourceSlotKind};
+///
+/// // A layout for a pipeline that only samples textures.
+/// let resource_bindings_layout = ResourceBindingsLayoutBuilder::new(None)
+///     .add_empty_bind_group(0)?
+///     .add_bind_group(1)?
+///         .add_resource_slot(ResourceSlotDescriptor {
+///             slot_index: 0,
+///             slot_identifier: ResourceSlotIdentifier::Static("texture_0"),
+///             slot_kind: ResourceSlotKind::SampledTexture
+///         })?
+///         .finish()
+///     .finish();
+/// # Ok::<(), ResourceBindingsLayoutBuilderError>(())
+/// ```
+///
+/// [add_empty_bind_group]: ResourceBindingsLayoutBuilder::add_empty_bind_group
+#[derive(Clone, Debug)]
 pub struct ResourceBindingsLayoutBuilder {
     layout: Vec<LayoutElement>,
     last_bind_group_index: Option<u32>,
@@ -472,6 +513,22 @@ impl ResourceBindingsLayoutBuilder {
         })
     }
 
+    /// Adds a bind group without any resource slots to the layout with the given
+    /// `bind_group_index`.
+    ///
+    /// Equivalent to calling [add_bind_group] and immediately finishing the resulting
+    /// [BindGroupLayoutBuilder] without adding any resource slots. Returns an
+    /// [InvalidBindGroupSequence] error if the `bind_group_index` is not greater than the previous
+    /// bind group index (if any).
+    ///
+    /// [add_bind_group]: ResourceBindingsLayoutBuilder::add_bind_group
+    pub fn add_empty_bind_group(
+        self,
+        bind_group_index: u32,
+    ) -> Result<Self, InvalidBindGroupSequence> {
+        Ok(self.add_bind_group(bind_group_index)?.finish())
+    }
+
     /// Finishes this builder and returns the resulting [ResourceBindingsLayoutDescriptor].
     pub fn finish(self) -> ResourceBindingsLayoutDescriptor {
         ResourceBindingsLayoutDescriptor {
@@ -494,6 +551,7 @@ pub struct InvalidResourceSlotSequence {
 
 /// Returned from [ResourceBindingsLayoutDescriptorBuilder::add_bind_group], accumulates the
 /// resource slot descriptors for a bind group layout description.
+#[derive(Clone, Debug)]
 pub struct BindGroupLayoutBuilder {
     builder: ResourceBindingsLayoutBuilder,
     bind_group_index: u32,
@@ -547,7 +605,7 @@ impl BindGroupLayoutBuilder {
 ///
 /// See also [ResourceBindingsLayoutDescriptor] a descriptor that only includes the minimum of
 /// information necessary to initialize a pipeline.
-#[derive(Clone, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct TypedResourceBindingsLayoutDescriptor {
     bind_groups: &'static [TypedBindGroupLayoutDescriptor],
 }
@@ -592,7 +650,7 @@ impl TypedResourceBindingsLayoutDescriptor {
 }
 
 /// Describes the resource slot layout of a bind group in a [TypedResourceBindingsLayoutDescriptor].
-#[derive(Clone, Debug)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct TypedBindGroupLayoutDescriptor {
     bind_group_index: u32,
     resource_slots: &'static [TypedResourceSlotDescriptor],
@@ -706,6 +764,19 @@ pub trait TypedBindGroupLayout {
 /// This trait is implemented for any type that implements the [Resources] trait. The [Resources]
 /// trait may automatically derived (see the documentation for the [Resources] trait for details).
 ///
+/// This trait is also implemented for a single [Resource] (e.g. `&Buffer<T>` or a sampled
+/// texture) and for tuples of up to 8 [Resource]s. These assign slot indices sequentially, in the
+/// order in which the resources appear in the tuple, starting at `0`; uniform buffers and sampled
+/// textures share a single sequence of slot indices, as the slot indices within a bind group must
+/// be unique. For example, for a `(FloatSampledTexture2D, &Buffer<T>, FloatSampledTexture2D)`
+/// tuple, the first texture is bound to slot `0`, the buffer is bound to slot `1` and the second
+/// texture is bound to slot `2`. Such a group does not name its resource slots and therefore does
+/// not describe a typed bind group layout: it must be bound with
+/// [GraphicsPipelineTaskBuilder::bind_resources_untyped] to a pipeline that was created with a
+/// [ResourceBindingsLayoutDescriptor] that declares matching slot indices (see
+/// [ResourceBindingsLayoutBuilder]). Derive [Resources] instead to obtain a typed bind group, for
+/// which compatibility with the pipeline is verified by the type-checker.
+///
 /// # Example
 ///
 /// ```
@@ -735,6 +806,8 @@ pub trait TypedBindGroupLayout {
 ///     }
 /// }
 /// ```
+///
+/// [GraphicsPipelineTaskBuilder::bind_resources_untyped]: crate::rendering::GraphicsPipelineTaskBuilder::bind_resources_untyped
 pub trait EncodeBindableResourceGroup {
     type Encoding;
 
@@ -940,6 +1013,23 @@ impl Hash for ResourceSlotIdentifier {
     }
 }
 
+impl ResourceSlotIdentifier {
+    /// If this identifier uses GLSL array element notation (e.g. `Lights[2]`), returns the name of
+    /// the array and the index of the element.
+    pub(crate) fn array_element(&self) -> Option<(&str, usize)> {
+        let identifier: &str = self.deref();
+
+        if !identifier.ends_with(']') {
+            return None;
+        }
+
+        let open = identifier.rfind('[')?;
+        let index = identifier[open + 1..identifier.len() - 1].parse().ok()?;
+
+        Some((&identifier[..open], index))
+    }
+}
+
 impl Deref for ResourceSlotIdentifier {
     type Target = str;
 
@@ -982,6 +1072,65 @@ impl From<TypedResourceSlotDescriptor> for ResourceSlotDescriptor {
     }
 }
 
+/// Describes how a resource slot declared by a pipeline's resource layout is used by the pipeline's
+/// shader stages, as reflected from the linked program.
+///
+/// See [GraphicsPipeline::resource_slot_usage].
+///
+/// [GraphicsPipeline::resource_slot_usage]: crate::pipeline::graphics::GraphicsPipeline::resource_slot_usage
+#[derive(Clone, PartialEq, Debug)]
+pub struct ResourceSlotUsage {
+    /// The index of the bind group that contains the slot.
+    pub bind_group_index: u32,
+
+    /// The identifier for the slot.
+    pub slot_identifier: ResourceSlotIdentifier,
+
+    /// The index of the slot.
+    pub slot_index: u32,
+
+    /// The kind of resource slot.
+    ///
+    /// Uniform buffer slots and sampled-texture slots are indexed separately: a uniform buffer
+    /// slot and a sampled-texture slot in the same bind group may share a slot index.
+    pub slot_kind: ResourceSlotKind,
+
+    /// The shader stages that use the slot.
+    pub visibility: ResourceVisibility,
+}
+
+/// Enumerates the shader stages that may use a resource slot.
+///
+/// See also [ResourceSlotUsage].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ResourceVisibility {
+    /// The slot is not used by any of the shader stages.
+    ///
+    /// Resources bound to this slot are not bound when the pipeline is active.
+    Unused,
+
+    /// The slot is only used by the vertex stage.
+    Vertex,
+
+    /// The slot is only used by the fragment stage.
+    Fragment,
+
+    /// The slot is used by both the vertex stage and the fragment stage.
+    VertexAndFragment,
+
+    /// The slot is used, but WebGL does not report which stage(s) use it.
+    ///
+    /// This is always the case for sampled-texture slots.
+    Unspecified,
+}
+
+impl ResourceVisibility {
+    /// Whether or not the slot is used by any of the shader stages.
+    pub fn is_used(&self) -> bool {
+        *self != ResourceVisibility::Unused
+    }
+}
+
 /// Enumerates the different kinds of resource slots a pipeline can define.
 ///
 /// See also [ResourceSlotDescriptor].
@@ -1049,22 +1198,40 @@ pub enum ResourceSlotType {
 }
 
 /// Enumerates the types available for sampled-texture resource slot.
+///
+/// When a pipeline is created, the sampled-texture type of each resource in the pipeline's resource
+/// layout is validated against the GLSL sampler type of the matching uniform in the shader code.
 #[derive(Clone, Copy, Hash, PartialEq, Debug)]
 pub enum SampledTextureType {
+    /// Matches a GLSL `sampler2D`, see [FloatSampledTexture2D].
     FloatSampler2D,
+    /// Matches a GLSL `isampler2D`, see [IntegerSampledTexture2D].
     IntegerSampler2D,
+    /// Matches a GLSL `usampler2D`, see [UnsignedIntegerSampledTexture2D].
     UnsignedIntegerSampler2D,
+    /// Matches a GLSL `sampler2DArray`, see [FloatSampledTexture2DArray].
     FloatSampler2DArray,
+    /// Matches a GLSL `isampler2DArray`, see [IntegerSampledTexture2DArray].
     IntegerSampler2DArray,
+    /// Matches a GLSL `usampler2DArray`, see [UnsignedIntegerSampledTexture2DArray].
     UnsignedIntegerSampler2DArray,
+    /// Matches a GLSL `sampler3D`, see [FloatSampledTexture3D].
     FloatSampler3D,
+    /// Matches a GLSL `isampler3D`, see [IntegerSampledTexture3D].
     IntegerSampler3D,
+    /// Matches a GLSL `usampler3D`, see [UnsignedIntegerSampledTexture3D].
     UnsignedIntegerSampler3D,
+    /// Matches a GLSL `samplerCube`, see [FloatSampledTextureCube].
     FloatSamplerCube,
+    /// Matches a GLSL `isamplerCube`, see [IntegerSampledTextureCube].
     IntegerSamplerCube,
+    /// Matches a GLSL `usamplerCube`, see [UnsignedIntegerSampledTextureCube].
     UnsignedIntegerSamplerCube,
+    /// Matches a GLSL `sampler2DShadow`, see [ShadowSampledTexture2D].
     Sampler2DShadow,
+    /// Matches a GLSL `sampler2DArrayShadow`, see [ShadowSampledTexture2DArray].
     Sampler2DArrayShadow,
+    /// Matches a GLSL `samplerCubeShadow`, see [ShadowSampledTextureCube].
     SamplerCubeShadow,
 }
 
@@ -1122,7 +1289,7 @@ pub enum SampledTextureType {
 /// instances should be bound to the pipeline:
 ///
 /// ```
-/// # #![feature(const_fn, const_loop, const_if_match, const_ptr_offset_from, const_transmute, ptr_offset_from)]
+/// # #![feature(const_fn, const_loop, const_if_match, const_panic, const_ptr_offset_from, const_transmute, ptr_offset_from)]
 /// use web_glitz::image::texture_2d::FloatSampledTexture2D;
 /// use web_glitz::image::texture_2d_array::FloatSampledTexture2DArray;
 /// use web_glitz::buffer::Buffer;
@@ -1168,6 +1335,64 @@ pub enum SampledTextureType {
 /// with `#[resource(...)]` will result in a compilation error. If multiple `#[resource(...)]`
 /// fields are defined, then all fields must declare a unique `binding` index; 2 or more
 /// `#[resource(...)]` fields with the same `binding` index will also result in a compilation error.
+///
+/// A field with an array type may additionally declare a `len` that matches the length of the
+/// array, in which case the field binds an array of resources to sequential binding indices,
+/// starting at the declared `binding` index. The element type of the array must implement
+/// [Resource]. The resource names use GLSL array element notation:
+///
+/// ```
+/// # #![feature(const_fn, const_loop, const_if_match, const_panic, const_ptr_offset_from, const_transmute, ptr_offset_from)]
+/// use web_glitz::buffer::Buffer;
+///
+/// #[derive(web_glitz::derive::Resources)]
+/// struct SkinningResources<'a> {
+///     // Binds `Bones[0]` to binding `1`, `Bones[1]` to binding `2`, etc.
+///     #[resource(binding=1, len=4, name="Bones")]
+///     bones: [&'a Buffer<BoneMatrices>; 4],
+/// }
+///
+/// #[std140::repr_std140]
+/// #[derive(web_glitz::derive::InterfaceBlock)]
+/// struct BoneMatrices {
+///     matrices: std140::array<std140::mat4x4, 16>
+/// }
+/// ```
+///
+/// Here the bindings occupied by an array field may not overlap with the bindings of any other
+/// `#[resource(...)]` field.
+///
+/// An array field of buffers (or buffer views, e.g. ranges of a single buffer) binds an array of
+/// uniform blocks in the shader code, such as `layout(std140) uniform Bones { ... } bones[4];`.
+/// When a pipeline is created, the array length declared by the field is validated against the
+/// elements of the uniform block array used by the pipeline's shader stages; if the shader stages
+/// use more elements than the field declares, then pipeline creation fails with an
+/// [IncompatibleResources::BlockArrayLengthMismatch] error.
+///
+/// A field may wrap its resource in an [Option] to declare an optional binding. If the field is
+/// `Some`, then the resource is bound as normal; if the field is `None`, then no resource is bound
+/// to the slot (see [BindGroupEncoder::add_optional]). This allows a single [Resources] type to be
+/// used with variations of a shader in which some resource slots have been removed or optimized
+/// out (e.g. a material shader that only declares a normal map when the material uses one):
+///
+/// ```
+/// # #![feature(const_fn, const_loop, const_if_match, const_panic, const_ptr_offset_from, const_transmute, ptr_offset_from)]
+/// use web_glitz::image::texture_2d::FloatSampledTexture2D;
+///
+/// #[derive(web_glitz::derive::Resources)]
+/// struct MaterialResources<'a> {
+///     #[resource(binding=0)]
+///     base_color_map: FloatSampledTexture2D<'a>,
+///
+///     #[resource(binding=1)]
+///     normal_map: Option<FloatSampledTexture2D<'a>>,
+/// }
+/// ```
+///
+/// Resource slots that are declared by a [Resources] type, but that are not used by a pipeline's
+/// shader code, are ignored when the pipeline is created. If the shader code does use a slot for
+/// which the binding is `None`, then sampling a texture slot returns `(0, 0, 0, 1)`, and drawing
+/// with a uniform block slot without a bound buffer generates a WebGL error.
 pub unsafe trait Resources {
     type Encoding;
 
@@ -1226,6 +1451,104 @@ unsafe impl TypedBindableResourceGroup for () {
     type Layout = ();
 }
 
+macro_rules! impl_encode_bindable_resource_group_for_resource {
+    ($([$($generics:tt)*] $R:ty;)*) => {
+        $(
+            impl<$($generics)*> EncodeBindableResourceGroup for $R
+            where
+                $R: Resource,
+            {
+                type Encoding = (<$R as Resource>::Encoding,);
+
+                fn encode_bindable_resource_group(
+                    self,
+                    encoding_context: &mut BindGroupEncodingContext,
+                ) -> BindGroupEncoding<Self::Encoding> {
+                    let encoder = BindGroupEncoder::new(encoding_context, Some(1));
+
+                    Resource::encode(self, 0, encoder).finish()
+                }
+            }
+        )*
+    }
+}
+
+impl_encode_bindable_resource_group_for_resource! {
+    ['a, T] &'a Buffer<T>;
+    ['a, T] BufferView<'a, T>;
+    ['a, T] &'a ManagedUniform<T>;
+    ['a] FloatSampledTexture2D<'a>;
+    ['a] FloatSampledTexture2DArray<'a>;
+    ['a] FloatSampledTexture3D<'a>;
+    ['a] FloatSampledTextureCube<'a>;
+    ['a] IntegerSampledTexture2D<'a>;
+    ['a] IntegerSampledTexture2DArray<'a>;
+    ['a] IntegerSampledTexture3D<'a>;
+    ['a] IntegerSampledTextureCube<'a>;
+    ['a] UnsignedIntegerSampledTexture2D<'a>;
+    ['a] UnsignedIntegerSampledTexture2DArray<'a>;
+    ['a] UnsignedIntegerSampledTexture3D<'a>;
+    ['a] UnsignedIntegerSampledTextureCube<'a>;
+    ['a] ShadowSampledTexture2D<'a>;
+    ['a] ShadowSampledTexture2DArray<'a>;
+    ['a] ShadowSampledTextureCube<'a>;
+}
+
+macro_rules! impl_encode_bindable_resource_group_for_tuple {
+    ($n:tt, $($R:ident: $i:tt),*) => {
+        impl<$($R),*> EncodeBindableResourceGroup for ($($R,)*)
+        where
+            $($R: Resource),*
+        {
+            type Encoding = ($(<$R as Resource>::Encoding,)*);
+
+            #[allow(non_snake_case)]
+            fn encode_bindable_resource_group(
+                self,
+                encoding_context: &mut BindGroupEncodingContext,
+            ) -> BindGroupEncoding<Self::Encoding> {
+                let encoder = BindGroupEncoder::new(encoding_context, Some($n));
+
+                let ($($R,)*) = self;
+
+                $(
+                    let encoder = $R.encode($i, encoder);
+                )*
+
+                encoder.finish()
+            }
+        }
+    }
+}
+
+impl_encode_bindable_resource_group_for_tuple!(1, R0: 0);
+impl_encode_bindable_resource_group_for_tuple!(2, R0: 0, R1: 1);
+impl_encode_bindable_resource_group_for_tuple!(3, R0: 0, R1: 1, R2: 2);
+impl_encode_bindable_resource_group_for_tuple!(4, R0: 0, R1: 1, R2: 2, R3: 3);
+impl_encode_bindable_resource_group_for_tuple!(5, R0: 0, R1: 1, R2: 2, R3: 3, R4: 4);
+impl_encode_bindable_resource_group_for_tuple!(6, R0: 0, R1: 1, R2: 2, R3: 3, R4: 4, R5: 5);
+impl_encode_bindable_resource_group_for_tuple!(
+    7,
+    R0: 0,
+    R1: 1,
+    R2: 2,
+    R3: 3,
+    R4: 4,
+    R5: 5,
+    R6: 6
+);
+impl_encode_bindable_resource_group_for_tuple!(
+    8,
+    R0: 0,
+    R1: 1,
+    R2: 2,
+    R3: 3,
+    R4: 4,
+    R5: 5,
+    R6: 6,
+    R7: 7
+);
+
 /// Error returned when a [ResourceBindingsLayoutDescriptor] or
 /// [TypedResourceBindingsLayoutDescriptor] does not match resource slots declared in a pipeline's
 /// shader stages.
@@ -1235,7 +1558,75 @@ pub enum IncompatibleResources {
     MissingResource(ResourceSlotIdentifier),
     ResourceTypeMismatch(ResourceSlotIdentifier),
     IncompatibleInterface(ResourceSlotIdentifier, IncompatibleInterface),
-    SlotBindingMismatch { expected: usize, actual: usize },
+    SlotBindingMismatch {
+        expected: usize,
+        actual: usize,
+    },
+
+    /// The shader stages declare an array of uniform blocks (e.g.
+    /// `uniform Lights { ... } lights[4];`) with more elements than the array of buffers that the
+    /// resource layout declares for it (e.g. a field marked with
+    /// `#[resource(binding=0, len=2, name="Lights")]`).
+    ///
+    /// The `identifier` identifies the first element that the layout does not declare,
+    /// `shader_len` is the number of elements used by the shader stages and `layout_len` is the
+    /// number of elements declared by the layout.
+    ///
+    /// Note that WebGL only reports the elements of a uniform block array that are active in the
+    /// shader stages. Elements that are not referenced by the shader code may not be active, in
+    /// which case they do not need to be declared by the layout.
+    BlockArrayLengthMismatch {
+        identifier: ResourceSlotIdentifier,
+        shader_len: usize,
+        layout_len: usize,
+    },
+}
+
+impl fmt::Display for IncompatibleResources {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IncompatibleResources::MissingBindGroup(bind_group_index) => write!(
+                f,
+                "the resource layout does not declare bind group {}",
+                bind_group_index
+            ),
+            IncompatibleResources::MissingResource(identifier) => write!(
+                f,
+                "the resource layout does not declare the shader's resource slot `{}`",
+                identifier.deref()
+            ),
+            IncompatibleResources::ResourceTypeMismatch(identifier) => write!(
+                f,
+                "the resource layout declares a different resource type for the shader's resource \
+                 slot `{}`",
+                identifier.deref()
+            ),
+            IncompatibleResources::IncompatibleInterface(identifier, error) => write!(
+                f,
+                "the interface block type declared for uniform block `{}` does not match the \
+                 shader: {}",
+                identifier.deref(),
+                error
+            ),
+            IncompatibleResources::SlotBindingMismatch { expected, actual } => write!(
+                f,
+                "expected {} resource slot bindings, but the resource layout declares {}",
+                expected, actual
+            ),
+            IncompatibleResources::BlockArrayLengthMismatch {
+                identifier,
+                shader_len,
+                layout_len,
+            } => write!(
+                f,
+                "the shader stages use {} elements of a uniform block array, but the resource \
+                 layout only declares {} (`{}` is not declared)",
+                shader_len,
+                layout_len,
+                identifier.deref()
+            ),
+        }
+    }
 }
 
 /// Trait implemented for types that can be bound to a pipeline as a resource.
@@ -1289,6 +1680,23 @@ where
     }
 }
 
+unsafe impl<'a, T> Resource for &'a ManagedUniform<T>
+where
+    T: InterfaceBlock,
+{
+    type Encoding = Self;
+
+    const TYPE: ResourceSlotType = ResourceSlotType::UniformBuffer(T::MEMORY_UNITS);
+
+    fn encode<E>(
+        self,
+        slot_index: u32,
+        encoder: BindGroupEncoder<E>,
+    ) -> BindGroupEncoder<(Self::Encoding, E)> {
+        encoder.add_managed_uniform(slot_index, self)
+    }
+}
+
 unsafe impl<'a> Resource for FloatSampledTexture2D<'a> {
     type Encoding = Self;
 
@@ -1513,3 +1921,122 @@ unsafe impl<'a> Resource for ShadowSampledTextureCube<'a> {
         encoder.add_shadow_sampled_texture_cube(slot_index, self)
     }
 }
+
+unsafe impl<R> Resource for Option<R>
+where
+    R: Resource,
+{
+    type Encoding = Option<R::Encoding>;
+
+    const TYPE: ResourceSlotType = R::TYPE;
+
+    fn encode<E>(
+        self,
+        slot_index: u32,
+        encoder: BindGroupEncoder<E>,
+    ) -> BindGroupEncoder<(Self::Encoding, E)> {
+        encoder.add_optional(slot_index, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::RefCell;
+
+    // Only used as the resource type for `BindGroupEncoder::add_optional`, never encoded.
+    struct Unused;
+
+    unsafe impl Resource for Unused {
+        type Encoding = ();
+
+        const TYPE: ResourceSlotType = ResourceSlotType::UniformBuffer(&[]);
+
+        fn encode<E>(
+            self,
+            _slot_index: u32,
+            _encoder: BindGroupEncoder<E>,
+        ) -> BindGroupEncoder<((), E)> {
+            unreachable!()
+        }
+    }
+
+    // Mock resources that record the slot index at which they are encoded.
+    struct MockBuffer<'a>(&'a RefCell<Vec<u32>>);
+
+    unsafe impl<'a> Resource for MockBuffer<'a> {
+        type Encoding = Option<()>;
+
+        const TYPE: ResourceSlotType = ResourceSlotType::UniformBuffer(&[]);
+
+        fn encode<E>(
+            self,
+            slot_index: u32,
+            encoder: BindGroupEncoder<E>,
+        ) -> BindGroupEncoder<(Option<()>, E)> {
+            self.0.borrow_mut().push(slot_index);
+
+            encoder.add_optional::<Unused>(slot_index, None)
+        }
+    }
+
+    struct MockTexture<'a>(&'a RefCell<Vec<u32>>);
+
+    unsafe impl<'a> Resource for MockTexture<'a> {
+        type Encoding = Option<()>;
+
+        const TYPE: ResourceSlotType =
+            ResourceSlotType::SampledTexture(SampledTextureType::FloatSampler2D);
+
+        fn encode<E>(
+            self,
+            slot_index: u32,
+            encoder: BindGroupEncoder<E>,
+        ) -> BindGroupEncoder<(Option<()>, E)> {
+            self.0.borrow_mut().push(slot_index);
+
+            encoder.add_optional::<Unused>(slot_index, None)
+        }
+    }
+
+    #[test]
+    fn test_encode_tuple_mixed_sequential_slot_indices() {
+        let slots = RefCell::new(Vec::new());
+        let mut encoding_context = BindGroupEncodingContext::new(0);
+
+        (
+            MockTexture(&slots),
+            MockBuffer(&slots),
+            MockTexture(&slots),
+            MockBuffer(&slots),
+        )
+            .encode_bindable_resource_group(&mut encoding_context);
+
+        assert_eq!(*slots.borrow(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_resource_slot_identifier_array_element() {
+        assert_eq!(
+            ResourceSlotIdentifier::Static("Lights[2]").array_element(),
+            Some(("Lights", 2))
+        );
+        assert_eq!(
+            ResourceSlotIdentifier::Dynamic("Scene.Lights[10]".to_string()).array_element(),
+            Some(("Scene.Lights", 10))
+        );
+        assert_eq!(
+            ResourceSlotIdentifier::Static("Lights").array_element(),
+            None
+        );
+        assert_eq!(
+            ResourceSlotIdentifier::Static("Lights[]").array_element(),
+            None
+        );
+        assert_eq!(
+            ResourceSlotIdentifier::Static("Lights]").array_element(),
+            None
+        );
+    }
+}
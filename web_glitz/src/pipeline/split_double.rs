@@ -0,0 +1,196 @@
+//! Emulation of double precision values in interface blocks.
+//!
+//! WebGL 2.0 shaders do not support double precision floating point values. When the coordinates
+//! in a scene span a large range (e.g. positions on the surface of a planet), single precision
+//! world coordinates cannot represent small offsets between nearby positions, which shows up as
+//! jitter when the camera moves. The common workaround is to split each `f64` value into a "high"
+//! `f32` part (the value rounded to single precision) and a "low" `f32` part (the rounding error),
+//! and to subtract the high and low parts of the camera position separately in the shader:
+//!
+//! ```glsl
+//! struct Split64Vec3 {
+//!     vec3 high;
+//!     vec3 low;
+//! };
+//!
+//! layout(std140) uniform Positions {
+//!     Split64Vec3 model_origin;
+//!     Split64Vec3 eye_position;
+//! };
+//!
+//! vec3 relative_to_eye(Split64Vec3 position, Split64Vec3 eye) {
+//!     vec3 high_difference = position.high - eye.high;
+//!     vec3 low_difference = position.low - eye.low;
+//!
+//!     return high_difference + low_difference;
+//! }
+//! ```
+//!
+//! The differences between the high parts and between the low parts are exact (or nearly exact)
+//! for positions near the camera, so the resulting position relative to the camera retains the
+//! precision of the original `f64` values. The model-view matrix should then only encode the
+//! camera's rotation, not its translation.
+//!
+//! This module provides [Split64] (a `vec2` with the high part in `x` and the low part in `y`)
+//! and [Split64Vec3] (the `Split64Vec3` struct above), which may be used as fields of an
+//! [InterfaceBlock] type:
+//!
+//! ```
+//! # #![feature(const_fn, const_loop, const_if_match, const_panic, const_ptr_offset_from, const_transmute, ptr_offset_from)]
+//! use web_glitz::pipeline::split_double::Split64Vec3;
+//!
+//! #[std140::repr_std140]
+//! #[derive(web_glitz::derive::InterfaceBlock, Clone, Copy)]
+//! struct Positions {
+//!     model_origin: Split64Vec3,
+//!     eye_position: Split64Vec3,
+//! }
+//!
+//! let positions = Positions {
+//!     model_origin: Split64Vec3::from([6_378_137.0, 12.25, -3.5]),
+//!     eye_position: Split64Vec3::from([6_378_140.125, 10.0, -2.0]),
+//! };
+//! ```
+//!
+//! [InterfaceBlock]: crate::pipeline::interface_block::InterfaceBlock
+
+use crate::pipeline::interface_block::{
+    InterfaceBlock, InterfaceBlockComponent, MemoryUnit, MemoryUnitField, UnitLayout,
+};
+
+/// A double precision value that is split into a high and a low single precision part.
+///
+/// Corresponds to a GLSL `vec2`, where `x` holds the [high] part and `y` holds the [low] part. See
+/// the module documentation for details.
+///
+/// [high]: Split64::high
+/// [low]: Split64::low
+#[repr(C, align(8))]
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub struct Split64 {
+    /// The value rounded to single precision.
+    pub high: f32,
+
+    /// The difference between the value and [high], rounded to single precision.
+    ///
+    /// [high]: Split64::high
+    pub low: f32,
+}
+
+impl Split64 {
+    /// Returns the value as a double precision value (the sum of the high and the low part).
+    pub fn value(&self) -> f64 {
+        self.high as f64 + self.low as f64
+    }
+}
+
+impl From<f64> for Split64 {
+    fn from(value: f64) -> Self {
+        let high = value as f32;
+        let low = (value - high as f64) as f32;
+
+        Split64 { high, low }
+    }
+}
+
+// Bitwise compatible with `std140::vec2`.
+unsafe impl std140::ReprStd140 for Split64 {}
+
+unsafe impl InterfaceBlockComponent for Split64 {
+    const MEMORY_UNITS: &'static [MemoryUnit] = &[MemoryUnit {
+        offset: 0,
+        layout: UnitLayout::FloatVector2,
+        field: None,
+    }];
+
+    const STD140_BASE_ALIGNMENT: Option<usize> = Some(8);
+}
+
+/// A vector of 3 double precision values that is split into a high and a low single precision
+/// vector.
+///
+/// Corresponds to a GLSL struct with a `vec3 high` member followed by a `vec3 low` member. See the
+/// module documentation for details.
+#[std140::repr_std140]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Split64Vec3 {
+    /// The vector rounded to single precision.
+    pub high: std140::vec3,
+
+    /// The difference between the vector and [high], rounded to single precision.
+    ///
+    /// [high]: Split64Vec3::high
+    pub low: std140::vec3,
+}
+
+impl Split64Vec3 {
+    /// Returns the vector as a double precision vector (the sum of the high and the low part).
+    pub fn value(&self) -> [f64; 3] {
+        [
+            self.high.0 as f64 + self.low.0 as f64,
+            self.high.1 as f64 + self.low.1 as f64,
+            self.high.2 as f64 + self.low.2 as f64,
+        ]
+    }
+}
+
+impl From<[f64; 3]> for Split64Vec3 {
+    fn from(value: [f64; 3]) -> Self {
+        let [x, y, z] = value;
+        let x = Split64::from(x);
+        let y = Split64::from(y);
+        let z = Split64::from(z);
+
+        Split64Vec3 {
+            high: std140::vec3(x.high, y.high, z.high),
+            low: std140::vec3(x.low, y.low, z.low),
+        }
+    }
+}
+
+unsafe impl InterfaceBlock for Split64Vec3 {
+    const MEMORY_UNITS: &'static [MemoryUnit] = &[
+        MemoryUnit {
+            offset: 0,
+            layout: UnitLayout::FloatVector3,
+            field: Some(MemoryUnitField {
+                struct_name: "Split64Vec3",
+                field_name: "high",
+            }),
+        },
+        MemoryUnit {
+            offset: 16,
+            layout: UnitLayout::FloatVector3,
+            field: Some(MemoryUnitField {
+                struct_name: "Split64Vec3",
+                field_name: "low",
+            }),
+        },
+    ];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split64_preserves_precision() {
+        let value = 6_378_137.123_456_789;
+        let split = Split64::from(value);
+
+        assert_eq!(split.high, value as f32);
+        assert!((split.value() - value).abs() < 1e-7);
+    }
+
+    #[test]
+    fn test_split64_vec3_layout() {
+        assert_eq!(std::mem::size_of::<Split64Vec3>(), 32);
+
+        let split = Split64Vec3::from([1.0e7 + 0.25, -3.5, 0.1]);
+        let [x, y, z] = split.value();
+
+        assert!((x - (1.0e7 + 0.25)).abs() < 1e-8);
+        assert_eq!(y, -3.5);
+        assert!((z - 0.1).abs() < 1e-8);
+    }
+}
@@ -301,6 +301,81 @@ where
     }
 }
 
+/// The kind of values stored by an image attached to a render target.
+///
+/// See [AttachmentRef::kind].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum AttachmentKind {
+    /// The image is attached to a color slot; the index of the color slot is given.
+    Color(u8),
+
+    /// The image is attached to the depth-stencil slot and stores depth values.
+    Depth,
+
+    /// The image is attached to the depth-stencil slot and stores stencil values.
+    Stencil,
+
+    /// The image is attached to the depth-stencil slot and stores combined depth and stencil
+    /// values.
+    DepthStencil,
+}
+
+/// Describes an image that is attached to a [RenderTarget] or a [MultisampleRenderTarget].
+///
+/// See [RenderTarget::attachments] and [MultisampleRenderTarget::attachments].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct AttachmentRef {
+    kind: AttachmentKind,
+    internal_format: u32,
+    width: u32,
+    height: u32,
+    samples: u8,
+}
+
+impl AttachmentRef {
+    pub(crate) fn new(
+        kind: AttachmentKind,
+        internal_format: u32,
+        data: &AttachmentData,
+        samples: u8,
+    ) -> Self {
+        AttachmentRef {
+            kind,
+            internal_format,
+            width: data.width,
+            height: data.height,
+            samples,
+        }
+    }
+
+    /// The kind of values stored by the attached image and the slot it is attached to.
+    pub fn kind(&self) -> AttachmentKind {
+        self.kind
+    }
+
+    /// The identifier of the storage format of the attached image (see [InternalFormat::ID]), e.g.
+    /// `WebGl2RenderingContext::RGBA8`.
+    pub fn internal_format(&self) -> u32 {
+        self.internal_format
+    }
+
+    /// The width of the attached image.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// The height of the attached image.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The size of the sampling grid of the attached image; `1` for images attached to a
+    /// [RenderTarget] that is not multisampled.
+    pub fn samples(&self) -> u8 {
+        self.samples
+    }
+}
+
 #[derive(Clone, Hash, PartialEq)]
 pub(crate) struct AttachmentData {
     pub(crate) context_id: u64,
@@ -320,6 +395,17 @@ impl AttachmentData {
         }
     }
 
+    /// The mipmap level at which the image is attached; always `0` for renderbuffers.
+    pub(crate) fn level(&self) -> u8 {
+        match &self.kind {
+            AttachableImageRefKind::Texture2DLevel { level, .. } => *level,
+            AttachableImageRefKind::Texture2DArrayLevelLayer { level, .. } => *level,
+            AttachableImageRefKind::Texture3DLevelLayer { level, .. } => *level,
+            AttachableImageRefKind::TextureCubeLevelFace { level, .. } => *level,
+            AttachableImageRefKind::Renderbuffer { .. } => 0,
+        }
+    }
+
     pub(crate) fn attach(&self, gl: &Gl, target: u32, slot: u32) {
         unsafe {
             match &self.kind {
@@ -1,6 +1,10 @@
 use std::cell::Cell;
 use std::marker;
 
+use staticvec::StaticVec;
+use web_sys::WebGl2RenderingContext as Gl;
+
+use crate::image::Region2D;
 use crate::rendering::render_target::RenderTargetData;
 use crate::rendering::{
     DefaultDepthBuffer, DefaultDepthStencilBuffer, DefaultRGBABuffer, DefaultRGBBuffer,
@@ -16,16 +20,23 @@ pub struct DefaultMultisampleRenderTarget<C, Ds> {
     context_id: u64,
     samples: u8,
     render_pass_id_gen: ObjectIdGen,
+    gl: Gl,
     color_buffer: marker::PhantomData<C>,
     depth_stencil_buffer: marker::PhantomData<Ds>,
 }
 
 impl<C, Ds> DefaultMultisampleRenderTarget<C, Ds> {
-    pub(crate) fn new(context_id: u64, samples: u8, render_pass_id_gen: ObjectIdGen) -> Self {
+    pub(crate) fn new(
+        context_id: u64,
+        samples: u8,
+        render_pass_id_gen: ObjectIdGen,
+        gl: Gl,
+    ) -> Self {
         DefaultMultisampleRenderTarget {
             context_id,
             samples,
             render_pass_id_gen,
+            gl,
             color_buffer: marker::PhantomData,
             depth_stencil_buffer: marker::PhantomData,
         }
@@ -34,6 +45,22 @@ impl<C, Ds> DefaultMultisampleRenderTarget<C, Ds> {
     pub fn samples(&self) -> u8 {
         self.samples
     }
+
+    /// Returns the size of the drawing buffer of the default framebuffer as a `(width, height)`
+    /// tuple, in pixels.
+    ///
+    /// The size of the drawing buffer is determined by the `width` and `height` attributes of the
+    /// canvas element the context was initialized with and may change when these attributes are
+    /// modified (see also [ResizeObserverHandle::observe]). Note that the browser may clamp the
+    /// size of the drawing buffer if the canvas is too large.
+    ///
+    /// [ResizeObserverHandle::observe]: crate::runtime::ResizeObserverHandle::observe
+    pub fn size(&self) -> (u32, u32) {
+        (
+            self.gl.drawing_buffer_width() as u32,
+            self.gl.drawing_buffer_height() as u32,
+        )
+    }
 }
 
 impl DefaultMultisampleRenderTarget<DefaultRGBBuffer, ()> {
@@ -52,6 +79,10 @@ impl DefaultMultisampleRenderTarget<DefaultRGBBuffer, ()> {
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: false,
+                has_stencil_buffer: false,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
             samples: self.samples,
         });
@@ -87,6 +118,10 @@ impl DefaultMultisampleRenderTarget<DefaultRGBBuffer, DefaultDepthStencilBuffer>
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: true,
+                has_stencil_buffer: true,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
             samples: self.samples,
         });
@@ -122,6 +157,10 @@ impl DefaultMultisampleRenderTarget<DefaultRGBBuffer, DefaultDepthBuffer> {
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: true,
+                has_stencil_buffer: false,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
             samples: self.samples,
         });
@@ -157,6 +196,10 @@ impl DefaultMultisampleRenderTarget<DefaultRGBBuffer, DefaultStencilBuffer> {
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: false,
+                has_stencil_buffer: true,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
             samples: self.samples,
         });
@@ -192,6 +235,10 @@ impl DefaultMultisampleRenderTarget<DefaultRGBABuffer, ()> {
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: false,
+                has_stencil_buffer: false,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
             samples: self.samples,
         });
@@ -227,6 +274,10 @@ impl DefaultMultisampleRenderTarget<DefaultRGBABuffer, DefaultDepthStencilBuffer
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: true,
+                has_stencil_buffer: true,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
             samples: self.samples,
         });
@@ -262,6 +313,10 @@ impl DefaultMultisampleRenderTarget<DefaultRGBABuffer, DefaultDepthBuffer> {
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: true,
+                has_stencil_buffer: false,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
             samples: self.samples,
         });
@@ -297,6 +352,10 @@ impl DefaultMultisampleRenderTarget<DefaultRGBABuffer, DefaultStencilBuffer> {
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: false,
+                has_stencil_buffer: true,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
             samples: self.samples,
         });
@@ -1,6 +1,10 @@
 use std::cell::Cell;
 use std::marker;
 
+use staticvec::StaticVec;
+use web_sys::WebGl2RenderingContext as Gl;
+
+use crate::image::Region2D;
 use crate::rendering::render_target::RenderTargetData;
 use crate::rendering::{
     DefaultDepthBuffer, DefaultDepthStencilBuffer, DefaultRGBABuffer, DefaultRGBBuffer,
@@ -14,19 +18,37 @@ use crate::task::{ContextId, GpuTask};
 pub struct DefaultRenderTarget<C, Ds> {
     context_id: u64,
     render_pass_id_gen: ObjectIdGen,
+    gl: Gl,
     color_buffer: marker::PhantomData<C>,
     depth_stencil_buffer: marker::PhantomData<Ds>,
 }
 
 impl<C, Ds> DefaultRenderTarget<C, Ds> {
-    pub(crate) fn new(context_id: u64, render_pass_id_gen: ObjectIdGen) -> Self {
+    pub(crate) fn new(context_id: u64, render_pass_id_gen: ObjectIdGen, gl: Gl) -> Self {
         DefaultRenderTarget {
             context_id,
             render_pass_id_gen,
+            gl,
             color_buffer: marker::PhantomData,
             depth_stencil_buffer: marker::PhantomData,
         }
     }
+
+    /// Returns the size of the drawing buffer of the default framebuffer as a `(width, height)`
+    /// tuple, in pixels.
+    ///
+    /// The size of the drawing buffer is determined by the `width` and `height` attributes of the
+    /// canvas element the context was initialized with and may change when these attributes are
+    /// modified (see also [ResizeObserverHandle::observe]). Note that the browser may clamp the
+    /// size of the drawing buffer if the canvas is too large.
+    ///
+    /// [ResizeObserverHandle::observe]: crate::runtime::ResizeObserverHandle::observe
+    pub fn size(&self) -> (u32, u32) {
+        (
+            self.gl.drawing_buffer_width() as u32,
+            self.gl.drawing_buffer_height() as u32,
+        )
+    }
 }
 
 impl DefaultRenderTarget<DefaultRGBBuffer, ()> {
@@ -45,6 +67,10 @@ impl DefaultRenderTarget<DefaultRGBBuffer, ()> {
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: false,
+                has_stencil_buffer: false,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
         });
 
@@ -79,6 +105,10 @@ impl DefaultRenderTarget<DefaultRGBBuffer, DefaultDepthStencilBuffer> {
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: true,
+                has_stencil_buffer: true,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
         });
 
@@ -113,6 +143,10 @@ impl DefaultRenderTarget<DefaultRGBBuffer, DefaultDepthBuffer> {
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: true,
+                has_stencil_buffer: false,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
         });
 
@@ -147,6 +181,10 @@ impl DefaultRenderTarget<DefaultRGBBuffer, DefaultStencilBuffer> {
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: false,
+                has_stencil_buffer: true,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
         });
 
@@ -181,6 +219,10 @@ impl DefaultRenderTarget<DefaultRGBABuffer, ()> {
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: false,
+                has_stencil_buffer: false,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
         });
 
@@ -215,6 +257,10 @@ impl DefaultRenderTarget<DefaultRGBABuffer, DefaultDepthStencilBuffer> {
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: true,
+                has_stencil_buffer: true,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
         });
 
@@ -249,6 +295,10 @@ impl DefaultRenderTarget<DefaultRGBABuffer, DefaultDepthBuffer> {
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: true,
+                has_stencil_buffer: false,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
         });
 
@@ -283,6 +333,10 @@ impl DefaultRenderTarget<DefaultRGBABuffer, DefaultStencilBuffer> {
                 context_id: self.context_id,
                 render_pass_id: id,
                 last_pipeline_task_id: Cell::new(0),
+                has_depth_buffer: false,
+                has_stencil_buffer: true,
+                attachment_ids: StaticVec::new(),
+                render_area: Region2D::Fill,
             },
         });
 
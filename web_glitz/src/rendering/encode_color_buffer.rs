@@ -185,6 +185,13 @@ pub struct FloatAttachment<I> {
     pub(crate) store_op: StoreOp,
 }
 
+impl<I> FloatAttachment<I> {
+    /// Returns a reference to the attached image.
+    pub fn image(&self) -> &I {
+        &self.image
+    }
+}
+
 impl<I> EncodeColorBuffer for FloatAttachment<I>
 where
     I: AsAttachment,
@@ -224,6 +231,13 @@ pub struct IntegerAttachment<I> {
     pub(crate) store_op: StoreOp,
 }
 
+impl<I> IntegerAttachment<I> {
+    /// Returns a reference to the attached image.
+    pub fn image(&self) -> &I {
+        &self.image
+    }
+}
+
 impl<I> EncodeColorBuffer for IntegerAttachment<I>
 where
     I: AsAttachment,
@@ -249,6 +263,13 @@ pub struct UnsignedIntegerAttachment<I> {
     pub(crate) store_op: StoreOp,
 }
 
+impl<I> UnsignedIntegerAttachment<I> {
+    /// Returns a reference to the attached image.
+    pub fn image(&self) -> &I {
+        &self.image
+    }
+}
+
 impl<I> EncodeColorBuffer for UnsignedIntegerAttachment<I>
 where
     I: AsAttachment,
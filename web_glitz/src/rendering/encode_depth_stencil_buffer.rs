@@ -2,7 +2,7 @@ use std::marker;
 
 use crate::image::format::{InternalFormat, Multisamplable};
 use crate::rendering::attachment::AttachmentData;
-use crate::rendering::load_op::LoadAction;
+use crate::rendering::load_op::{depth_stencil_aspects_action, LoadAction};
 use crate::rendering::{
     AsAttachment, AsMultisampleAttachment, DepthBuffer, DepthStencilBuffer, LoadOp,
     RenderingOutputBuffer, StencilBuffer, StoreOp,
@@ -49,6 +49,7 @@ pub struct DepthStencilBufferEncoding<'a, 'b, B> {
     pub(crate) buffer: B,
     pub(crate) load_action: LoadAction,
     pub(crate) store_op: StoreOp,
+    pub(crate) stencil_store_op: StoreOp,
     pub(crate) depth_stencil_type: DepthStencilAttachmentType,
     pub(crate) image: AttachmentData,
     _context: &'a mut DepthStencilBufferEncodingContext,
@@ -74,6 +75,36 @@ where
             buffer: DepthStencilBuffer::new(context.render_pass_id, image.width, image.height),
             load_action: load_op.as_load_depth_stencil_action(),
             store_op,
+            stencil_store_op: store_op,
+            depth_stencil_type: DepthStencilAttachmentType::DepthStencil,
+            image,
+            _context: context,
+            _image_ref: marker::PhantomData,
+        }
+    }
+
+    /// Same as [depth_stencil_attachment], except that the depth aspect and the stencil aspect of
+    /// the image use independent load and store operations.
+    ///
+    /// [depth_stencil_attachment]: DepthStencilBufferEncoding::depth_stencil_attachment
+    pub fn depth_stencil_attachment_separate<I>(
+        context: &'a mut DepthStencilBufferEncodingContext,
+        image: &'b mut I,
+        depth_load_op: LoadOp<f32>,
+        depth_store_op: StoreOp,
+        stencil_load_op: LoadOp<i32>,
+        stencil_store_op: StoreOp,
+    ) -> Self
+    where
+        I: AsAttachment<Format = F>,
+    {
+        let image = image.as_attachment().into_data();
+
+        DepthStencilBufferEncoding {
+            buffer: DepthStencilBuffer::new(context.render_pass_id, image.width, image.height),
+            load_action: depth_stencil_aspects_action(&depth_load_op, &stencil_load_op),
+            store_op: depth_store_op,
+            stencil_store_op,
             depth_stencil_type: DepthStencilAttachmentType::DepthStencil,
             image,
             _context: context,
@@ -97,6 +128,37 @@ where
             buffer: DepthStencilBuffer::new(context.render_pass_id, image.width, image.height),
             load_action: load_op.as_load_depth_stencil_action(),
             store_op,
+            stencil_store_op: store_op,
+            depth_stencil_type: DepthStencilAttachmentType::DepthStencil,
+            image,
+            _context: context,
+            _image_ref: marker::PhantomData,
+        }
+    }
+
+    /// Same as [multisample_depth_stencil_attachment], except that the depth aspect and the
+    /// stencil aspect of the image use independent load and store operations.
+    ///
+    /// [multisample_depth_stencil_attachment]: DepthStencilBufferEncoding::multisample_depth_stencil_attachment
+    pub fn multisample_depth_stencil_attachment_separate<I>(
+        context: &'a mut DepthStencilBufferEncodingContext,
+        image: &'b mut I,
+        depth_load_op: LoadOp<f32>,
+        depth_store_op: StoreOp,
+        stencil_load_op: LoadOp<i32>,
+        stencil_store_op: StoreOp,
+    ) -> Self
+    where
+        I: AsMultisampleAttachment<SampleFormat = F>,
+        F: Multisamplable,
+    {
+        let image = image.as_multisample_attachment().into_data();
+
+        DepthStencilBufferEncoding {
+            buffer: DepthStencilBuffer::new(context.render_pass_id, image.width, image.height),
+            load_action: depth_stencil_aspects_action(&depth_load_op, &stencil_load_op),
+            store_op: depth_store_op,
+            stencil_store_op,
             depth_stencil_type: DepthStencilAttachmentType::DepthStencil,
             image,
             _context: context,
@@ -124,6 +186,7 @@ where
             buffer: DepthBuffer::new(context.render_pass_id, image.width, image.height),
             load_action: load_op.as_load_depth_action(),
             store_op,
+            stencil_store_op: store_op,
             depth_stencil_type: DepthStencilAttachmentType::Depth,
             image,
             _context: context,
@@ -147,6 +210,7 @@ where
             buffer: DepthBuffer::new(context.render_pass_id, image.width, image.height),
             load_action: load_op.as_load_depth_action(),
             store_op,
+            stencil_store_op: store_op,
             depth_stencil_type: DepthStencilAttachmentType::Depth,
             image,
             _context: context,
@@ -174,6 +238,7 @@ where
             buffer: StencilBuffer::new(context.render_pass_id, image.width, image.height),
             load_action: load_op.as_load_stencil_action(),
             store_op,
+            stencil_store_op: store_op,
             depth_stencil_type: DepthStencilAttachmentType::Stencil,
             image,
             _context: context,
@@ -184,8 +249,29 @@ where
 
 pub struct DepthStencilAttachment<I> {
     pub(crate) image: I,
-    pub(crate) load_op: LoadOp<(f32, i32)>,
-    pub(crate) store_op: StoreOp,
+    pub(crate) depth_load_op: LoadOp<f32>,
+    pub(crate) depth_store_op: StoreOp,
+    pub(crate) stencil_load_op: LoadOp<i32>,
+    pub(crate) stencil_store_op: StoreOp,
+}
+
+impl<I> DepthStencilAttachment<I> {
+    pub(crate) fn new(image: I, load_op: LoadOp<(f32, i32)>, store_op: StoreOp) -> Self {
+        let (depth_load_op, stencil_load_op) = load_op.split();
+
+        DepthStencilAttachment {
+            image,
+            depth_load_op,
+            depth_store_op: store_op,
+            stencil_load_op,
+            stencil_store_op: store_op,
+        }
+    }
+
+    /// Returns a reference to the attached image.
+    pub fn image(&self) -> &I {
+        &self.image
+    }
 }
 
 impl<I> EncodeDepthStencilBuffer for DepthStencilAttachment<I>
@@ -198,11 +284,13 @@ where
         &'a mut self,
         context: &'b mut DepthStencilBufferEncodingContext,
     ) -> DepthStencilBufferEncoding<'b, 'a, Self::Buffer> {
-        DepthStencilBufferEncoding::depth_stencil_attachment(
+        DepthStencilBufferEncoding::depth_stencil_attachment_separate(
             context,
             &mut self.image,
-            self.load_op,
-            self.store_op,
+            self.depth_load_op,
+            self.depth_store_op,
+            self.stencil_load_op,
+            self.stencil_store_op,
         )
     }
 }
@@ -217,11 +305,13 @@ where
         &'a mut self,
         context: &'b mut DepthStencilBufferEncodingContext,
     ) -> DepthStencilBufferEncoding<'b, 'a, Self::Buffer> {
-        DepthStencilBufferEncoding::multisample_depth_stencil_attachment(
+        DepthStencilBufferEncoding::multisample_depth_stencil_attachment_separate(
             context,
             &mut self.image,
-            self.load_op,
-            self.store_op,
+            self.depth_load_op,
+            self.depth_store_op,
+            self.stencil_load_op,
+            self.stencil_store_op,
         )
     }
 }
@@ -232,6 +322,13 @@ pub struct DepthAttachment<I> {
     pub(crate) store_op: StoreOp,
 }
 
+impl<I> DepthAttachment<I> {
+    /// Returns a reference to the attached image.
+    pub fn image(&self) -> &I {
+        &self.image
+    }
+}
+
 impl<I> EncodeDepthStencilBuffer for DepthAttachment<I>
 where
     I: AsAttachment,
@@ -276,6 +373,13 @@ pub struct StencilAttachment<I> {
     pub(crate) store_op: StoreOp,
 }
 
+impl<I> StencilAttachment<I> {
+    /// Returns a reference to the attached image.
+    pub fn image(&self) -> &I {
+        &self.image
+    }
+}
+
 impl<I> EncodeDepthStencilBuffer for StencilAttachment<I>
 where
     I: AsAttachment,
@@ -311,4 +415,18 @@ impl DepthStencilAttachmentType {
             DepthStencilAttachmentType::Stencil => DepthStencilAttachmentDescriptor::Stencil(image),
         }
     }
+
+    pub(crate) fn has_depth(&self) -> bool {
+        match self {
+            DepthStencilAttachmentType::DepthStencil | DepthStencilAttachmentType::Depth => true,
+            DepthStencilAttachmentType::Stencil => false,
+        }
+    }
+
+    pub(crate) fn has_stencil(&self) -> bool {
+        match self {
+            DepthStencilAttachmentType::DepthStencil | DepthStencilAttachmentType::Stencil => true,
+            DepthStencilAttachmentType::Depth => false,
+        }
+    }
 }
@@ -2,11 +2,13 @@ use std::borrow::Borrow;
 use std::cell::{Cell, UnsafeCell};
 use std::hash::{Hash, Hasher};
 use std::marker;
+use std::ops::RangeInclusive;
 use std::sync::Arc;
 
 use fnv::FnvHasher;
 use web_sys::WebGl2RenderingContext as Gl;
 
+use crate::extensions::multi_draw;
 use crate::image::format::{
     DepthRenderable, DepthStencilRenderable, Filterable, FloatRenderable, IntegerRenderable,
     InternalFormat, Multisamplable, Multisample, RenderbufferFormat, StencilRenderable,
@@ -31,19 +33,20 @@ use crate::pipeline::graphics::primitive_assembly::Topology;
 use crate::pipeline::graphics::shader::{FragmentShaderData, VertexShaderData};
 use crate::pipeline::graphics::util::BufferDescriptor;
 use crate::pipeline::graphics::{
-    Blending, DepthTest, GraphicsPipeline, IndexData, IndexDataDescriptor, PrimitiveAssembly,
-    StencilTest, TypedVertexBuffers, TypedVertexInputLayout, VertexBuffers,
-    VertexBuffersEncodingContext, VertexInputLayoutDescriptor, Viewport,
+    Blending, CullingMode, DepthTest, GraphicsPipeline, IndexData, IndexDataDescriptor, InputRate,
+    PrimitiveAssembly, StencilTest, TypedVertexBuffers, TypedVertexInputLayout, VertexArray,
+    VertexAttributePointers, VertexBuffers, VertexBuffersEncodingContext,
+    VertexInputLayoutDescriptor, Viewport,
 };
 use crate::pipeline::resources::{
-    BindGroupDescriptor, ResourceBindings, ResourceBindingsEncodingContext, TypedResourceBindings,
-    TypedResourceBindingsLayout,
+    BindGroupDescriptor, ResourceBindings, ResourceBindingsEncodingContext, ResourceSlotUsage,
+    TypedResourceBindings, TypedResourceBindingsLayout,
 };
 use crate::rendering::attachment::{Attachment, AttachmentData};
 use crate::rendering::RenderPassContext;
 use crate::runtime::state::{BufferRange, ContextUpdate, DynamicState};
 use crate::runtime::Connection;
-use crate::task::{sequence, ContextId, Empty, GpuTask, Progress, Sequence};
+use crate::task::{sequence, ContextId, Empty, GpuTask, Progress, Sequence, ValidationContext};
 use crate::util::JsId;
 use crate::Unspecified;
 use staticvec::StaticVec;
@@ -58,14 +61,14 @@ pub trait GraphicsPipelineState<V, R, Tf> {
     /// See [Framebuffer::pipeline_task] for details.
     fn pipeline_task<F, T>(&self, target: &GraphicsPipelineTarget, f: F) -> PipelineTask<T>
     where
-        F: Fn(ActiveGraphicsPipeline<V, R, Tf>) -> T,
+        F: FnOnce(ActiveGraphicsPipeline<V, R, Tf>) -> T,
         T: GpuTask<PipelineTaskContext>;
 }
 
 impl<V, R, Tf> GraphicsPipelineState<V, R, Tf> for GraphicsPipeline<V, R, Tf> {
     fn pipeline_task<F, T>(&self, target: &GraphicsPipelineTarget, f: F) -> PipelineTask<T>
     where
-        F: Fn(ActiveGraphicsPipeline<V, R, Tf>) -> T,
+        F: FnOnce(ActiveGraphicsPipeline<V, R, Tf>) -> T,
         T: GpuTask<PipelineTaskContext>,
     {
         PipelineTask::new(target, self, None, f)
@@ -77,7 +80,7 @@ impl<'a, V, R, Tf, Fb> GraphicsPipelineState<V, R, Tf>
 {
     fn pipeline_task<F, T>(&self, target: &GraphicsPipelineTarget, f: F) -> PipelineTask<T>
     where
-        F: Fn(ActiveGraphicsPipeline<V, R, Tf>) -> T,
+        F: FnOnce(ActiveGraphicsPipeline<V, R, Tf>) -> T,
         T: GpuTask<PipelineTaskContext>,
     {
         PipelineTask::new(target, &self.pipeline, Some(self.buffers.clone()), f)
@@ -89,6 +92,10 @@ pub struct GraphicsPipelineTarget {
     pub(crate) context_id: u64,
     pub(crate) render_pass_id: u64,
     pub(crate) last_pipeline_task_id: Cell<u64>,
+    pub(crate) has_depth_buffer: bool,
+    pub(crate) has_stencil_buffer: bool,
+    pub(crate) attachment_ids: StaticVec<(JsId, u8), 17>,
+    pub(crate) render_area: Region2D,
 }
 
 impl GraphicsPipelineTarget {
@@ -102,6 +109,10 @@ impl GraphicsPipelineTarget {
     /// current framebuffer serves as the output target for the `graphics_pipeline` (your draw
     /// commands may modify the current framebuffer).
     ///
+    /// The function `f` is called exactly once, before this method returns. It may therefore
+    /// borrow data from the surrounding scope (e.g. per-frame scene data) or take ownership of
+    /// captured values, see [RenderTarget::create_render_pass] for details.
+    ///
     /// # Example
     ///
     /// ```
@@ -144,10 +155,18 @@ impl GraphicsPipelineTarget {
     ///
     /// Panics if the task returned by `f` contains commands that were constructed for a different
     /// pipeline task context.
+    ///
+    /// Panics if the `graphics_pipeline` specifies a [StencilTest], but the framebuffer does not
+    /// have a stencil buffer (the render target does not have a depth-stencil or stencil
+    /// attachment).
+    ///
+    /// Panics if the `graphics_pipeline` is a depth-only pipeline (see
+    /// [GraphicsPipelineDescriptorBuilder::no_fragment_shader]), but the framebuffer does not have
+    /// a depth buffer (the render target does not have a depth-stencil or depth attachment).
     pub fn pipeline_task<P, V, R, Tf, F, T>(&self, pipeline: &P, f: F) -> PipelineTask<T>
     where
         P: GraphicsPipelineState<V, R, Tf>,
-        F: Fn(ActiveGraphicsPipeline<V, R, Tf>) -> T,
+        F: FnOnce(ActiveGraphicsPipeline<V, R, Tf>) -> T,
         T: GpuTask<PipelineTaskContext>,
     {
         pipeline.pipeline_task(self, f)
@@ -194,6 +213,11 @@ where
     /// The `source` must be a single-sample image. For transferring pixel data from a multisample
     /// source image, see [resolve_color_command].
     ///
+    /// Note that the `source` is an image, rather than a framebuffer: to transfer the pixels of a
+    /// specific color attachment of another render target (e.g. the third color attachment of a
+    /// G-buffer), use the image that is attached at that position as the `source`. The transfer
+    /// therefore does not depend on a "read buffer" selection.
+    ///
     /// The `region` of the color buffers is constrained to the area of intersection of all color
     /// buffers; a `region` value of [Region::Fill] will match this area of intersection (note that
     /// the origin of a region is in its bottom-left corner). If a `region` bigger than the
@@ -246,6 +270,7 @@ where
             target_region: region,
             target: self.color.descriptor(),
             source: source_descriptor,
+            depth_source: None,
         }
     }
 
@@ -296,6 +321,43 @@ where
     /// Here `render_target` is a [RenderTarget] or [DefaultRenderTarget] and `texture` is a
     /// [Texture2D].
     ///
+    /// The `source` may be any image that is attached to another (offscreen) [RenderTarget], which
+    /// allows blitting directly between offscreen render targets without involving the default
+    /// render target. For example, a downsample step in a post-processing pyramid may blit the
+    /// color image of one render target into a half-sized render target:
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # use web_glitz::image::texture_2d::Texture2D;
+    /// # use web_glitz::image::format::RGBA8;
+    /// # fn wrapper<Rc>(
+    /// #     context: &Rc,
+    /// #     full_res: Texture2D<RGBA8>,
+    /// #     mut half_res: Texture2D<RGBA8>
+    /// # ) where Rc: RenderingContext {
+    /// use web_glitz::image::Region2D;
+    /// use web_glitz::rendering::{LoadOp, RenderTargetDescriptor, StoreOp};
+    ///
+    /// // `full_res` is the color image of a previous (offscreen) render pass.
+    /// let mut half_res_target = context.create_render_target(
+    ///     RenderTargetDescriptor::new().attach_color_float(
+    ///         half_res.base_level_mut(),
+    ///         LoadOp::Load,
+    ///         StoreOp::Store,
+    ///     ),
+    /// );
+    ///
+    /// let downsample = half_res_target.create_render_pass(|framebuffer| {
+    ///     framebuffer.blit_color_linear_command(Region2D::Fill, &full_res.base_level())
+    /// });
+    ///
+    /// context.submit(downsample);
+    /// # }
+    /// ```
+    ///
+    /// The format of the `source` must be [BlitColorCompatible] with each of the color buffers in
+    /// the destination framebuffer; this is verified statically.
+    ///
     /// # Panics
     ///
     /// Panics if `source` belongs to a different context than the framebuffer.
@@ -318,6 +380,7 @@ where
             target_region: region,
             target: self.color.descriptor(),
             source: source_descriptor,
+            depth_source: None,
         }
     }
 
@@ -444,9 +507,83 @@ where
                 },
             },
             source: source_descriptor,
+            depth_source: None,
         }
     }
 
+    /// Transfers a rectangle of pixels from the `color_source` onto a `region` of each of the color
+    /// buffers in the framebuffer, and a rectangle of depth values from the `depth_source` onto the
+    /// same `region` of the depth-stencil buffer, in a single blit operation.
+    ///
+    /// This is equivalent to a [blit_color_nearest_command] followed by a [blit_depth_command] with
+    /// the same `region`, but transfers both aspects with a single blit operation, which guarantees
+    /// that the color and depth values are transferred with identical source and destination
+    /// rectangles. WebGL 2.0 requires "nearest" filtering for any blit that includes depth values,
+    /// so "nearest" filtering is always used if the sources and the `region` have different sizes.
+    ///
+    /// The image data stored in the `color_source` must be stored in a format that is
+    /// [BlitColorCompatible] with each of the color buffers in the framebuffer, and the depth data
+    /// stored in the `depth_source` must be stored in the same format as the framebuffer's
+    /// depth-stencil buffer; this is verified statically. As the blit operation reads both aspects
+    /// from a single source rectangle, the `color_source` and the `depth_source` must describe
+    /// rectangles with the same origin and the same size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::rendering::{RenderTarget, DepthStencilAttachment, FloatAttachment};
+    /// # use web_glitz::image::format::{Depth24Stencil8, RGBA8};
+    /// # use web_glitz::image::renderbuffer::Renderbuffer;
+    /// # fn wrapper(
+    /// # mut render_target: RenderTarget<(FloatAttachment<Renderbuffer<RGBA8>>,), DepthStencilAttachment<Renderbuffer<Depth24Stencil8>>>,
+    /// # color_renderbuffer: Renderbuffer<RGBA8>,
+    /// # depth_renderbuffer: Renderbuffer<Depth24Stencil8>
+    /// # ) {
+    /// use web_glitz::image::Region2D;
+    ///
+    /// let render_pass = render_target.create_render_pass(|framebuffer| {
+    ///     framebuffer.blit_color_depth_command(
+    ///         Region2D::Fill,
+    ///         &color_renderbuffer,
+    ///         &depth_renderbuffer,
+    ///     )
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// Here `render_target` is a [RenderTarget] and `color_renderbuffer` and `depth_renderbuffer`
+    /// are [Renderbuffer]s, e.g. the attachments of a previous (offscreen) render pass.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `color_source` or `depth_source` belongs to a different context than the
+    /// framebuffer.
+    ///
+    /// Panics if the `color_source` and the `depth_source` do not describe rectangles with the same
+    /// origin and size.
+    pub fn blit_color_depth_command<Sc, Sd>(
+        &self,
+        region: Region2D,
+        color_source: &Sc,
+        depth_source: &Sd,
+    ) -> BlitCommand
+    where
+        C: BlitColorTarget,
+        Sc: BlitColorCompatible<C>,
+        Sd: BlitSource<Format = F>,
+    {
+        color_depth_blit_command(
+            &self.pipeline_target,
+            self.color
+                .descriptor()
+                .intersect(self.depth_stencil.width(), self.depth_stencil.height()),
+            region,
+            color_source.descriptor(),
+            depth_source.descriptor(),
+            Gl::DEPTH_STENCIL_ATTACHMENT,
+        )
+    }
+
     /// Transfers a rectangle of only depth values from the `source` depth-stencil image onto a
     /// `region` of the depth-stencil buffer in framebuffer, using "nearest" filtering if the
     /// `source` and the `region` have different sizes.
@@ -512,6 +649,7 @@ where
                 },
             },
             source: source_descriptor,
+            depth_source: None,
         }
     }
 
@@ -580,6 +718,7 @@ where
                 },
             },
             source: source_descriptor,
+            depth_source: None,
         }
     }
 
@@ -822,9 +961,83 @@ where
                 },
             },
             source: source_descriptor,
+            depth_source: None,
         }
     }
 
+    /// Transfers a rectangle of pixels from the `color_source` onto a `region` of each of the color
+    /// buffers in the framebuffer, and a rectangle of depth values from the `depth_source` onto the
+    /// same `region` of the depth buffer, in a single blit operation.
+    ///
+    /// This is equivalent to a [blit_color_nearest_command] followed by a [blit_depth_command] with
+    /// the same `region`, but transfers both aspects with a single blit operation, which guarantees
+    /// that the color and depth values are transferred with identical source and destination
+    /// rectangles. WebGL 2.0 requires "nearest" filtering for any blit that includes depth values,
+    /// so "nearest" filtering is always used if the sources and the `region` have different sizes.
+    ///
+    /// The image data stored in the `color_source` must be stored in a format that is
+    /// [BlitColorCompatible] with each of the color buffers in the framebuffer, and the depth data
+    /// stored in the `depth_source` must be stored in the same format as the framebuffer's
+    /// depth buffer; this is verified statically. As the blit operation reads both aspects
+    /// from a single source rectangle, the `color_source` and the `depth_source` must describe
+    /// rectangles with the same origin and the same size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::rendering::{RenderTarget, DepthAttachment, FloatAttachment};
+    /// # use web_glitz::image::format::{DepthComponent24, RGBA8};
+    /// # use web_glitz::image::renderbuffer::Renderbuffer;
+    /// # fn wrapper(
+    /// # mut render_target: RenderTarget<(FloatAttachment<Renderbuffer<RGBA8>>,), DepthAttachment<Renderbuffer<DepthComponent24>>>,
+    /// # color_renderbuffer: Renderbuffer<RGBA8>,
+    /// # depth_renderbuffer: Renderbuffer<DepthComponent24>
+    /// # ) {
+    /// use web_glitz::image::Region2D;
+    ///
+    /// let render_pass = render_target.create_render_pass(|framebuffer| {
+    ///     framebuffer.blit_color_depth_command(
+    ///         Region2D::Fill,
+    ///         &color_renderbuffer,
+    ///         &depth_renderbuffer,
+    ///     )
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// Here `render_target` is a [RenderTarget] and `color_renderbuffer` and `depth_renderbuffer`
+    /// are [Renderbuffer]s, e.g. the attachments of a previous (offscreen) render pass.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `color_source` or `depth_source` belongs to a different context than the
+    /// framebuffer.
+    ///
+    /// Panics if the `color_source` and the `depth_source` do not describe rectangles with the same
+    /// origin and size.
+    pub fn blit_color_depth_command<Sc, Sd>(
+        &self,
+        region: Region2D,
+        color_source: &Sc,
+        depth_source: &Sd,
+    ) -> BlitCommand
+    where
+        C: BlitColorTarget,
+        Sc: BlitColorCompatible<C>,
+        Sd: BlitSource<Format = F>,
+    {
+        color_depth_blit_command(
+            &self.pipeline_target,
+            self.color
+                .descriptor()
+                .intersect(self.depth_stencil.width(), self.depth_stencil.height()),
+            region,
+            color_source.descriptor(),
+            depth_source.descriptor(),
+            Gl::DEPTH_ATTACHMENT,
+        )
+    }
+
     /// Transfers a rectangle of depth values from a multisample `source` depth image onto the depth
     /// buffer of the framebuffer.
     ///
@@ -949,6 +1162,7 @@ where
                 },
             },
             source: source_descriptor,
+            depth_source: None,
         }
     }
 
@@ -1043,6 +1257,7 @@ pub struct PipelineTaskContext {
     pipeline_task_id: u64,
     connection: *mut Connection,
     attribute_layout: *const VertexInputLayoutDescriptor,
+    resource_slot_usage: *const [ResourceSlotUsage],
     vertex_buffers: StaticVec<BufferDescriptor, 16>,
     index_buffer: Option<IndexDataDescriptor>,
 }
@@ -1097,13 +1312,16 @@ pub struct PipelineTask<T> {
     transform_feedback_data: Arc<UnsafeCell<Option<TransformFeedbackData>>>,
     transform_feedback_buffers: Option<StaticVec<BufferDescriptor, 16>>,
     attribute_layout: VertexInputLayoutDescriptor,
+    resource_slot_usage: Arc<[ResourceSlotUsage]>,
     primitive_assembly: PrimitiveAssembly,
     depth_test: Option<DepthTest>,
     stencil_test: Option<StencilTest>,
     scissor_region: Region2D,
     blending: Option<Blending>,
     viewport: Viewport,
+    depth_only: bool,
     framebuffer_dimensions: Option<(u32, u32)>,
+    render_area: Region2D,
 }
 
 impl<T> PipelineTask<T>
@@ -1117,10 +1335,27 @@ where
         f: F,
     ) -> Self
     where
-        F: Fn(ActiveGraphicsPipeline<V, R, Tf>) -> T,
+        F: FnOnce(ActiveGraphicsPipeline<V, R, Tf>) -> T,
     {
         if framebuffer_data.context_id != pipeline.context_id() {
-            panic!("The pipeline does not belong to the same context as the framebuffer.");
+            panic!(
+                "{} does not belong to the same context as the framebuffer.",
+                pipeline.debug_label().describe("The pipeline")
+            );
+        }
+
+        if pipeline.stencil_test().is_some() && !framebuffer_data.has_stencil_buffer {
+            panic!(
+                "{} uses a stencil test, but the framebuffer has no stencil buffer.",
+                pipeline.debug_label().describe("The pipeline")
+            );
+        }
+
+        if pipeline.is_depth_only() && !framebuffer_data.has_depth_buffer {
+            panic!(
+                "{} is depth-only, but the framebuffer has no depth buffer.",
+                pipeline.debug_label().describe("The pipeline")
+            );
         }
 
         let id = framebuffer_data.last_pipeline_task_id.get();
@@ -1136,6 +1371,7 @@ where
         let task = f(ActiveGraphicsPipeline {
             pipeline_task_id,
             pipeline,
+            attachment_ids: framebuffer_data.attachment_ids.clone(),
         });
 
         if task.context_id() != ContextId::Any
@@ -1154,13 +1390,34 @@ where
             vertex_shader_data: pipeline.vertex_shader_data.clone(),
             fragment_shader_data: pipeline.fragment_shader_data.clone(),
             attribute_layout: pipeline.vertex_attribute_layout().clone(),
+            resource_slot_usage: pipeline.resource_slot_usage.clone(),
             primitive_assembly: pipeline.primitive_assembly().clone(),
             depth_test: pipeline.depth_test().cloned(),
             stencil_test: pipeline.stencil_test().cloned(),
             scissor_region: pipeline.scissor_region().clone(),
             blending: pipeline.blending().cloned(),
             viewport: pipeline.viewport().clone(),
+            depth_only: pipeline.is_depth_only(),
             framebuffer_dimensions: framebuffer_data.dimensions,
+            render_area: framebuffer_data.render_area,
+        }
+    }
+}
+
+/// Returns the region covered by both `a` and `b`.
+fn intersect_regions(a: Region2D, b: Region2D) -> Region2D {
+    match (a, b) {
+        (Region2D::Fill, region) | (region, Region2D::Fill) => region,
+        (
+            Region2D::Area((a_x, a_y), a_width, a_height),
+            Region2D::Area((b_x, b_y), b_width, b_height),
+        ) => {
+            let x = a_x.max(b_x);
+            let y = a_y.max(b_y);
+            let end_x = (a_x + a_width).min(b_x + b_width).max(x);
+            let end_y = (a_y + a_height).min(b_y + b_height).max(y);
+
+            Region2D::Area((x, y), end_x - x, end_y - y)
         }
     }
 }
@@ -1175,6 +1432,18 @@ where
         ContextId::Id(self.render_pass_id)
     }
 
+    fn validate(&self, context: &mut ValidationContext) {
+        let transform_feedback_buffers = self
+            .transform_feedback_buffers
+            .as_ref()
+            .map(|buffers| &buffers[..])
+            .unwrap_or(&[]);
+
+        context.begin_pipeline_task(transform_feedback_buffers);
+        self.task.validate(context);
+        context.end_pipeline_task();
+    }
+
     fn progress(&mut self, context: &mut RenderPassContext) -> Progress<Self::Output> {
         let (gl, state) = unsafe { context.unpack_mut() };
 
@@ -1328,7 +1597,7 @@ where
             }
         }
 
-        match self.scissor_region {
+        match intersect_regions(self.scissor_region, self.render_area) {
             Region2D::Area((x, y), width, height) => {
                 let (gl, state) = unsafe { context.unpack_mut() };
 
@@ -1363,10 +1632,19 @@ where
         StencilTest::apply(&self.stencil_test, connection);
         Blending::apply(&self.blending, connection);
 
+        let (gl, state) = unsafe { connection.unpack_mut() };
+
+        if self.depth_only {
+            state.set_color_mask([false; 4]).apply(gl).unwrap();
+        } else {
+            state.set_color_mask([true; 4]).apply(gl).unwrap();
+        }
+
         let res = self.task.progress(&mut PipelineTaskContext {
             pipeline_task_id: self.id,
             connection: context.connection_mut() as *mut Connection,
             attribute_layout: &self.attribute_layout,
+            resource_slot_usage: &*self.resource_slot_usage,
             vertex_buffers: StaticVec::new(),
             index_buffer: None,
         });
@@ -1392,6 +1670,7 @@ where
 pub struct ActiveGraphicsPipeline<'a, V, R, Tf> {
     pipeline_task_id: u64,
     pipeline: &'a GraphicsPipeline<V, R, Tf>,
+    attachment_ids: StaticVec<(JsId, u8), 17>,
 }
 
 impl<'a, V, R, Tf> ActiveGraphicsPipeline<'a, V, R, Tf> {
@@ -1441,6 +1720,9 @@ impl<'a, V, R, Tf> ActiveGraphicsPipeline<'a, V, R, Tf> {
             context_id: self.pipeline.context_id(),
             topology: self.pipeline.primitive_assembly().topology(),
             pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids.clone(),
+            vertex_input_layout: self.pipeline.vertex_attribute_layout(),
+            instance_count: None,
             task: Empty,
             _pipeline: marker::PhantomData,
             _vertex_buffers: marker::PhantomData,
@@ -1450,12 +1732,18 @@ impl<'a, V, R, Tf> ActiveGraphicsPipeline<'a, V, R, Tf> {
     }
 }
 
+// WebGL 2.0 stencil buffers always store 8-bit stencil values.
+const MAX_STENCIL_VALUE: u32 = 0xff;
+
 /// A builder interface that enforces valid sequencing of pipeline commands.
 ///
 /// See [ActiveGraphicsPipeline::task_builder].
 pub struct GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb, T> {
     context_id: u64,
     pipeline_task_id: u64,
+    attachment_ids: StaticVec<(JsId, u8), 17>,
+    vertex_input_layout: &'a VertexInputLayoutDescriptor,
+    instance_count: Option<usize>,
     topology: Topology,
     task: T,
     _pipeline: marker::PhantomData<ActiveGraphicsPipeline<'a, V, R, ()>>,
@@ -1465,6 +1753,69 @@ pub struct GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb, T> {
 }
 
 impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb, T> {
+    /// Describes how the attributes of the active graphics pipeline would be sourced from the
+    /// `vertex_buffers` if they were bound with [bind_vertex_buffers] or
+    /// [bind_vertex_buffers_untyped].
+    ///
+    /// Returns one [VertexAttributePointer] for every attribute in the pipeline's vertex input
+    /// layout, which reports the attribute's location, the bind slot of the buffer it is sourced
+    /// from, its offset in bytes relative to the start of that buffer, its stride in bytes, its
+    /// instance divisor and its format. The [VertexAttributePointers] implement [Display], which
+    /// formats them as a human-readable table. This does not bind the `vertex_buffers`; it is
+    /// intended as a debugging aid for vertex data that does not render as expected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::rendering::DefaultRGBBuffer;
+    /// # use web_glitz::rendering::DefaultRenderTarget;
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # use web_glitz::buffer::BufferView;
+    /// # use web_glitz::pipeline::graphics::{GraphicsPipeline, Vertex};
+    /// # fn wrapper<Rc, V>(
+    /// #     context: &Rc,
+    /// #     mut render_target: DefaultRenderTarget<DefaultRGBBuffer, ()>,
+    /// #     vertex_buffer: BufferView<[V]>,
+    /// #     graphics_pipeline: GraphicsPipeline<V, (), ()>
+    /// # )
+    /// # where
+    /// #     Rc: RenderingContext,
+    /// #     V: Vertex,
+    /// # {
+    /// let render_pass = render_target.create_render_pass(|framebuffer| {
+    ///     framebuffer.pipeline_task(&graphics_pipeline, |active_pipeline| {
+    ///         let builder = active_pipeline.task_builder();
+    ///
+    ///         // Prints a table with a row for each attribute, e.g.:
+    ///         //
+    ///         // location slot offset stride divisor  format
+    ///         //        0    0      0     20       0  Float2_f32
+    ///         //        1    0      8     20       0  Float3_f32
+    ///         println!("{}", builder.describe_vertex_buffers(vertex_buffer));
+    ///
+    ///         builder
+    ///             .bind_vertex_buffers(vertex_buffer)
+    ///             .bind_resources(())
+    ///             .draw(16, 1)
+    ///             .finish()
+    ///     })
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// [bind_vertex_buffers]: GraphicsPipelineTaskBuilder::bind_vertex_buffers
+    /// [bind_vertex_buffers_untyped]: GraphicsPipelineTaskBuilder::bind_vertex_buffers_untyped
+    /// [VertexAttributePointer]: crate::pipeline::graphics::VertexAttributePointer
+    /// [Display]: std::fmt::Display
+    pub fn describe_vertex_buffers<VbNew>(&self, vertex_buffers: VbNew) -> VertexAttributePointers
+    where
+        VbNew: VertexBuffers,
+    {
+        vertex_buffers
+            .encode(&mut VertexBuffersEncodingContext::new())
+            .describe(self.vertex_input_layout)
+    }
+
     /// Binds typed a (set of) vertex buffer(s) to the active graphics pipeline.
     ///
     /// When the active graphics pipeline is invoked (see [draw] and [draw_indexed]), then the
@@ -1513,10 +1864,15 @@ impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb,
             }
         }
 
+        let instance_count = per_instance_count(&vertex_buffers, self.vertex_input_layout);
+
         GraphicsPipelineTaskBuilder {
             context_id: self.context_id,
             topology: self.topology,
             pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids,
+            vertex_input_layout: self.vertex_input_layout,
+            instance_count,
             task: sequence(
                 self.task,
                 BindVertexBuffersCommand {
@@ -1549,9 +1905,18 @@ impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb,
     /// already implemented for any tuple of buffers (up to 16 buffers) where each buffer contains a
     /// slice of [Vertex] types.
     ///
+    /// See [try_bind_vertex_buffers_untyped] for a version of this method that returns an error
+    /// rather than panicking when the vertex buffers are not compatible with the bind slots of the
+    /// pipeline's vertex input layout.
+    ///
     /// # Panics
     ///
     /// Panics of any of the vertex buffers belong to a different context than the pipeline.
+    ///
+    /// Panics if the number of vertex buffers does not match the number of bind slots in the vertex
+    /// input layout specified for the pipeline, or if the size of the elements in any of the vertex
+    /// buffers does not evenly divide the stride of the corresponding bind slot (see
+    /// [IncompatibleVertexBuffers]).
     pub unsafe fn bind_vertex_buffers_untyped<VbNew>(
         self,
         vertex_buffers: VbNew,
@@ -1564,6 +1929,72 @@ impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb,
         Rb,
         Sequence<T, BindVertexBuffersCommand, PipelineTaskContext>,
     >
+    where
+        VbNew: VertexBuffers,
+        T: GpuTask<PipelineTaskContext>,
+    {
+        match self.try_bind_vertex_buffers_untyped(vertex_buffers) {
+            Ok(builder) => builder,
+            Err(IncompatibleVertexBuffers::BufferCountMismatch {
+                expected_count,
+                actual_count,
+            }) => panic!(
+                "{} vertex buffers were bound, but the pipeline expects {} vertex buffers.",
+                actual_count, expected_count
+            ),
+            Err(IncompatibleVertexBuffers::StrideMismatch {
+                bind_slot,
+                expected_stride,
+                element_size,
+            }) => panic!(
+                "The vertex buffer bound to slot {} has an element size of {} bytes, which does \
+                 not evenly divide the stride of {} bytes the pipeline expects.",
+                bind_slot, element_size, expected_stride
+            ),
+        }
+    }
+
+    /// Binds a (set of) vertex buffer(s) to the active graphics pipeline, or returns an error if
+    /// the vertex buffers are not compatible with the bind slots of the vertex input layout
+    /// specified for the pipeline.
+    ///
+    /// Returns [IncompatibleVertexBuffers::BufferCountMismatch] if the number of vertex buffers
+    /// does not match the number of bind slots. Returns
+    /// [IncompatibleVertexBuffers::StrideMismatch] if the size of the elements in any of the
+    /// vertex buffers does not evenly divide the stride of the corresponding bind slot. The
+    /// pipeline reads the buffer with the bind slot's stride, so a buffer may hold interleaved
+    /// vertex data as a slice of smaller elements (e.g. a `[f32]` or `[u8]` slice); a buffer with
+    /// an element type of which the size does not divide the stride, however, most likely holds
+    /// data for a different vertex input layout. A mismatched stride does not result in a WebGL
+    /// error; the pipeline would instead read its vertex attributes from the wrong offsets in the
+    /// buffer.
+    ///
+    /// See [bind_vertex_buffers_untyped] for details.
+    ///
+    /// # Unsafe
+    ///
+    /// The vertex buffers must contain data compatible with the vertex input layout specified for
+    /// the pipeline. Note that a matching stride is a necessary, but not a sufficient condition
+    /// for compatibility.
+    ///
+    /// # Panics
+    ///
+    /// Panics of any of the vertex buffers belong to a different context than the pipeline.
+    pub unsafe fn try_bind_vertex_buffers_untyped<VbNew>(
+        self,
+        vertex_buffers: VbNew,
+    ) -> Result<
+        GraphicsPipelineTaskBuilder<
+            'a,
+            V,
+            R,
+            VbNew,
+            Ib,
+            Rb,
+            Sequence<T, BindVertexBuffersCommand, PipelineTaskContext>,
+        >,
+        IncompatibleVertexBuffers,
+    >
     where
         VbNew: VertexBuffers,
         T: GpuTask<PipelineTaskContext>,
@@ -1578,10 +2009,27 @@ impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb,
             }
         }
 
-        GraphicsPipelineTaskBuilder {
+        let element_sizes: Vec<u32> = vertex_buffers
+            .iter()
+            .map(|buffer| buffer.stride_in_bytes)
+            .collect();
+        let slot_strides: Vec<u32> = self
+            .vertex_input_layout
+            .buffer_slots()
+            .map(|slot| slot.stride_in_bytes() as u32)
+            .collect();
+
+        check_vertex_buffers(&element_sizes, &slot_strides)?;
+
+        let instance_count = per_instance_count(&vertex_buffers, self.vertex_input_layout);
+
+        Ok(GraphicsPipelineTaskBuilder {
             context_id: self.context_id,
             topology: self.topology,
             pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids,
+            vertex_input_layout: self.vertex_input_layout,
+            instance_count,
             task: sequence(
                 self.task,
                 BindVertexBuffersCommand {
@@ -1593,7 +2041,7 @@ impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb,
             _vertex_buffers: marker::PhantomData,
             _index_buffer: marker::PhantomData,
             _resource_bindings: marker::PhantomData,
-        }
+        })
     }
 
     /// Binds an index buffer to the graphics pipeline.
@@ -1633,6 +2081,9 @@ impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb,
             context_id: self.context_id,
             topology: self.topology,
             pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids,
+            vertex_input_layout: self.vertex_input_layout,
+            instance_count: self.instance_count,
             task: sequence(
                 self.task,
                 BindIndexBufferCommand {
@@ -1647,6 +2098,67 @@ impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb,
         }
     }
 
+    /// Binds the vertex buffers and the index buffer of a [VertexArray] to the active graphics
+    /// pipeline with a single command.
+    ///
+    /// This is equivalent to binding the vertex buffers the [VertexArray] was created from with
+    /// [bind_vertex_buffers] and then binding its index buffer with [bind_index_buffer], but avoids
+    /// re-encoding the buffers for every pipeline task.
+    ///
+    /// The vertex buffers of the `vertex_array` must be a [TypedVertexBuffers] type with a vertex
+    /// attribute layout that matches the vertex attribute layout specified for the pipeline. This
+    /// is statically verified by the type system, see [bind_vertex_buffers] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vertex array belongs to a different context than the pipeline.
+    pub fn bind_vertex_array<VbNew, IbNew>(
+        self,
+        vertex_array: &VertexArray<VbNew, IbNew>,
+    ) -> GraphicsPipelineTaskBuilder<
+        'a,
+        V,
+        R,
+        VbNew,
+        IbNew,
+        Rb,
+        Sequence<T, BindVertexArrayCommand, PipelineTaskContext>,
+    >
+    where
+        V: TypedVertexInputLayout,
+        VbNew: TypedVertexBuffers<Layout = V>,
+        IbNew: IndexData,
+        T: GpuTask<PipelineTaskContext>,
+    {
+        if vertex_array.context_id() != self.context_id {
+            panic!("Vertex array belongs to a different context.");
+        }
+
+        let instance_count =
+            per_instance_count(vertex_array.vertex_buffers(), self.vertex_input_layout);
+
+        GraphicsPipelineTaskBuilder {
+            context_id: self.context_id,
+            topology: self.topology,
+            pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids,
+            vertex_input_layout: self.vertex_input_layout,
+            instance_count,
+            task: sequence(
+                self.task,
+                BindVertexArrayCommand {
+                    pipeline_task_id: self.pipeline_task_id,
+                    vertex_buffers: vertex_array.vertex_buffers().clone(),
+                    index_buffer: vertex_array.index_buffer().clone(),
+                },
+            ),
+            _pipeline: marker::PhantomData,
+            _vertex_buffers: marker::PhantomData,
+            _index_buffer: marker::PhantomData,
+            _resource_bindings: marker::PhantomData,
+        }
+    }
+
     /// Binds one or more bind groups containing typed resource groups to the active graphics
     /// pipeline.
     ///
@@ -1659,10 +2171,15 @@ impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb,
     /// ensure compatibility of the resource bindings with the pipeline.
     ///
     /// See also [bind_resources_untyped] for an unsafe alternative with relaxed type constraints.
+    /// See [try_bind_resources] for a version of this method that returns an error rather than
+    /// panicking when a bound texture is also attached to the framebuffer.
     ///
     /// # Panics
     ///
     /// Panics if any of the bind groups belong to a different context than the pipeline.
+    ///
+    /// Panics if any of the bind groups contains a sampled texture that is also attached to the
+    /// framebuffer the pipeline task draws to (see [AttachmentAliasingError]).
     pub fn bind_resources<RbNew>(
         self,
         resource_bindings: RbNew,
@@ -1680,24 +2197,75 @@ impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb,
         RbNew: TypedResourceBindings<Layout = R>,
         T: GpuTask<PipelineTaskContext>,
     {
-        GraphicsPipelineTaskBuilder {
+        match self.try_bind_resources(resource_bindings) {
+            Ok(builder) => builder,
+            Err(error) => panic!(
+                "The texture bound to unit {} in bind group {} is also attached to the \
+                 framebuffer.",
+                error.texture_unit, error.bind_group_index
+            ),
+        }
+    }
+
+    /// Binds one or more bind groups containing typed resource groups to the active graphics
+    /// pipeline, or returns an error if any of the bind groups contains a sampled texture that is
+    /// also attached to the framebuffer the pipeline task draws to.
+    ///
+    /// Sampling a texture while it is also being rendered to results in undefined behaviour. The
+    /// borrow checker prevents this in most cases, as attaching a texture image to a render target
+    /// requires a mutable borrow of the texture; this runtime check also covers cases where this
+    /// cannot be verified statically.
+    ///
+    /// See [bind_resources] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the bind groups belong to a different context than the pipeline.
+    pub fn try_bind_resources<RbNew>(
+        self,
+        resource_bindings: RbNew,
+    ) -> Result<
+        GraphicsPipelineTaskBuilder<
+            'a,
+            V,
+            R,
+            Vb,
+            Ib,
+            RbNew,
+            Sequence<T, BindResourcesCommand<RbNew::BindGroups>, PipelineTaskContext>,
+        >,
+        AttachmentAliasingError,
+    >
+    where
+        R: TypedResourceBindingsLayout,
+        RbNew: TypedResourceBindings<Layout = R>,
+        T: GpuTask<PipelineTaskContext>,
+    {
+        let bind_groups = resource_bindings
+            .encode(&mut ResourceBindingsEncodingContext::new(self.context_id))
+            .bind_groups;
+
+        check_attachment_aliasing(bind_groups.borrow(), &self.attachment_ids)?;
+
+        Ok(GraphicsPipelineTaskBuilder {
             context_id: self.context_id,
             topology: self.topology,
             pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids,
+            vertex_input_layout: self.vertex_input_layout,
+            instance_count: self.instance_count,
             task: sequence(
                 self.task,
                 BindResourcesCommand {
                     pipeline_task_id: self.pipeline_task_id,
-                    resource_bindings: resource_bindings
-                        .encode(&mut ResourceBindingsEncodingContext::new(self.context_id))
-                        .bind_groups,
+                    resource_bindings: bind_groups,
                 },
             ),
             _pipeline: marker::PhantomData,
             _vertex_buffers: marker::PhantomData,
             _index_buffer: marker::PhantomData,
             _resource_bindings: marker::PhantomData,
-        }
+        })
     }
 
     /// Binds one or more bind groups to the active graphics pipeline.
@@ -1720,6 +2288,9 @@ impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb,
     /// # Panics
     ///
     /// Panics if any of the bind groups belong to a different context than the pipeline.
+    ///
+    /// Panics if any of the bind groups contains a sampled texture that is also attached to the
+    /// framebuffer the pipeline task draws to (see [AttachmentAliasingError]).
     pub unsafe fn bind_resources_untyped<RbNew>(
         self,
         resource_bindings: RbNew,
@@ -1736,17 +2307,30 @@ impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb,
         RbNew: ResourceBindings,
         T: GpuTask<PipelineTaskContext>,
     {
+        let bind_groups = resource_bindings
+            .encode(&mut ResourceBindingsEncodingContext::new(self.context_id))
+            .bind_groups;
+
+        if let Err(error) = check_attachment_aliasing(bind_groups.borrow(), &self.attachment_ids) {
+            panic!(
+                "The texture bound to unit {} in bind group {} is also attached to the \
+                 framebuffer.",
+                error.texture_unit, error.bind_group_index
+            );
+        }
+
         GraphicsPipelineTaskBuilder {
             context_id: self.context_id,
             topology: self.topology,
             pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids,
+            vertex_input_layout: self.vertex_input_layout,
+            instance_count: self.instance_count,
             task: sequence(
                 self.task,
                 BindResourcesCommand {
                     pipeline_task_id: self.pipeline_task_id,
-                    resource_bindings: resource_bindings
-                        .encode(&mut ResourceBindingsEncodingContext::new(self.context_id))
-                        .bind_groups,
+                    resource_bindings: bind_groups,
                 },
             ),
             _pipeline: marker::PhantomData,
@@ -1831,6 +2415,9 @@ impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb,
             context_id: self.context_id,
             topology: self.topology,
             pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids,
+            vertex_input_layout: self.vertex_input_layout,
+            instance_count: self.instance_count,
             task: sequence(
                 self.task,
                 DrawCommand {
@@ -1847,36 +2434,278 @@ impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb,
         }
     }
 
-    /// Creates a [DrawIndexedCommand] that will execute the active graphics pipeline, streaming
-    /// `index_count` vertex indices for `instance_count` instances from the currently bound index
-    /// buffer, which produces a vertex stream by indexing into the vertex array defined by the
-    /// currently bound per-vertex vertex buffers.
-    ///
-    /// This command may only be added to the builder after an index buffer has been bound (see
-    /// [bind_index_buffer]. If the pipeline requires vertex buffers, then this command may only be
-    /// added to the builder after appropriate vertex buffers have been bound (see
-    /// [bind_vertex_buffers]). If the pipeline requires resources, then this command may only be
-    /// added to the builder after appropriate resources have been bound (see [bind_resources]).
+    /// Creates a [DrawCommand] that will execute the active graphics pipeline, streaming
+    /// `vertex_count` vertices for every instance in the currently bound per-instance vertex
+    /// buffers.
     ///
-    /// This will draw to the framebuffer that created the encapsulating [PipelineTask] (see
-    /// [Framebuffer::pipeline_task]). The pipeline's first color output will be stored to the
-    /// framebuffer's first color buffer (if present), the second color output will be stored to the
-    /// framebuffer's second color buffer (if present), etc. The pipeline's depth test (if enabled)
-    /// may update the framebuffer's depth-stencil buffer (if it present and is a [DepthRenderable]
-    /// or [DepthStencilRenderable] format, otherwise the depth test will act as if it was
-    /// disabled). The pipeline's stencil test (if enabled) may update the framebuffer's
-    /// depth-stencil buffer (if it is present and is [StencilRenderable] or
-    /// [DepthStencilRenderable] format, otherwise the stencil test will act as if was disabled).
+    /// The instance count is the number of elements (see [Buffer::len]) in the vertex buffer
+    /// that is bound to a bind slot with an [InputRate::PerInstance] input rate. If more than one
+    /// vertex buffer is bound to such a slot, then the smallest number of elements in any of these
+    /// buffers is used. Deriving the instance count from the bound buffers avoids the instance
+    /// count getting out of sync with the instance data when an instance buffer is resized.
     ///
-    /// See also [draw] for drawing without an index buffer.
+    /// See [draw] for details. See [try_draw_all_instances] for a version of this method that
+    /// returns an error rather than panicking when no per-instance vertex buffer is bound.
     ///
     /// # Example
     ///
     /// ```
     /// # use web_glitz::rendering::DefaultRGBBuffer;
     /// # use web_glitz::rendering::DefaultRenderTarget;
-    /// # use web_glitz::buffer::{UsageHint, BufferView};
-    /// # use web_glitz::pipeline::graphics::{GraphicsPipeline, Vertex, IndexBufferView};
+    /// # use web_glitz::buffer::BufferView;
+    /// # use web_glitz::pipeline::graphics::{GraphicsPipeline, Vertex};
+    /// # fn wrapper<V, I>(
+    /// #     mut render_target: DefaultRenderTarget<DefaultRGBBuffer, ()>,
+    /// #     vertex_buffer: BufferView<[V]>,
+    /// #     instance_buffer: BufferView<[I]>,
+    /// #     graphics_pipeline: GraphicsPipeline<(V, I), (), ()>
+    /// # )
+    /// # where
+    /// #     V: Vertex,
+    /// #     I: Vertex,
+    /// # {
+    /// let render_pass = render_target.create_render_pass(|framebuffer| {
+    ///     framebuffer.pipeline_task(&graphics_pipeline, |active_pipeline| {
+    ///         active_pipeline.task_builder()
+    ///             .bind_vertex_buffers((vertex_buffer, instance_buffer))
+    ///             .bind_resources(())
+    ///             .draw_all_instances(vertex_buffer.len())
+    ///             .finish()
+    ///     })
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// Here the instance count is `instance_buffer.len()`, provided that the vertex input layout
+    /// of `I` uses an [InputRate::PerInstance] input rate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if none of the currently bound vertex buffers is bound to a bind slot with an
+    /// [InputRate::PerInstance] input rate.
+    ///
+    /// [draw]: GraphicsPipelineTaskBuilder::draw
+    /// [try_draw_all_instances]: GraphicsPipelineTaskBuilder::try_draw_all_instances
+    /// [Buffer::len]: crate::buffer::Buffer::len
+    /// [InputRate::PerInstance]: crate::pipeline::graphics::InputRate::PerInstance
+    pub fn draw_all_instances(
+        self,
+        vertex_count: usize,
+    ) -> GraphicsPipelineTaskBuilder<
+        'a,
+        V,
+        R,
+        Vb,
+        Ib,
+        R,
+        Sequence<T, DrawCommand, PipelineTaskContext>,
+    >
+    where
+        Vb: VertexBuffers,
+        Rb: ResourceBindings,
+        T: GpuTask<PipelineTaskContext>,
+    {
+        match self.try_draw_all_instances(vertex_count) {
+            Ok(builder) => builder,
+            Err(_) => panic!("No vertex buffer is bound to a per-instance bind slot."),
+        }
+    }
+
+    /// Creates a [DrawCommand] that will execute the active graphics pipeline, streaming
+    /// `vertex_count` vertices for every instance in the currently bound per-instance vertex
+    /// buffers, or returns an error if no per-instance vertex buffer is bound.
+    ///
+    /// See [draw_all_instances] for details.
+    ///
+    /// [draw_all_instances]: GraphicsPipelineTaskBuilder::draw_all_instances
+    pub fn try_draw_all_instances(
+        self,
+        vertex_count: usize,
+    ) -> Result<
+        GraphicsPipelineTaskBuilder<
+            'a,
+            V,
+            R,
+            Vb,
+            Ib,
+            R,
+            Sequence<T, DrawCommand, PipelineTaskContext>,
+        >,
+        MissingInstanceBuffer,
+    >
+    where
+        Vb: VertexBuffers,
+        Rb: ResourceBindings,
+        T: GpuTask<PipelineTaskContext>,
+    {
+        let instance_count = self.instance_count.ok_or(MissingInstanceBuffer)?;
+
+        Ok(self.draw(vertex_count, instance_count))
+    }
+
+    /// Creates a [DrawEachCommand] that will execute the active graphics pipeline once for each of
+    /// the `ranges`, streaming [DrawRange::count] vertices starting at vertex [DrawRange::first]
+    /// for [DrawRange::instance_count] instances from the currently bound vertex buffers.
+    ///
+    /// This is equivalent to adding a sequence of [draw] commands to the builder (one for each of
+    /// the `ranges`), except that the program, vertex buffers and resources remain bound across all
+    /// draws. If the `WEBGL_multi_draw` extension is available, then all draws are submitted with a
+    /// single call.
+    ///
+    /// See also [draw_indexed_each] for indexed mode drawing with an index buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::rendering::DefaultRGBBuffer;
+    /// # use web_glitz::rendering::DefaultRenderTarget;
+    /// # use web_glitz::buffer::{UsageHint, BufferView};
+    /// # use web_glitz::pipeline::graphics::{GraphicsPipeline, Vertex};
+    /// # fn wrapper<V>(
+    /// #     mut render_target: DefaultRenderTarget<DefaultRGBBuffer, ()>,
+    /// #     vertex_buffers: BufferView<[V]>,
+    /// #     graphics_pipeline: GraphicsPipeline<V, (), ()>
+    /// # )
+    /// # where
+    /// #     V: Vertex,
+    /// # {
+    /// # let resources = ();
+    /// use web_glitz::rendering::DrawRange;
+    ///
+    /// let sub_meshes = vec![
+    ///     DrawRange { first: 0, count: 36, instance_count: 1 },
+    ///     DrawRange { first: 36, count: 24, instance_count: 1 },
+    ///     DrawRange { first: 60, count: 12, instance_count: 1 },
+    /// ];
+    ///
+    /// let render_pass = render_target.create_render_pass(|framebuffer| {
+    ///     framebuffer.pipeline_task(&graphics_pipeline, |active_pipeline| {
+    ///         active_pipeline.task_builder()
+    ///             .bind_vertex_buffers(vertex_buffers)
+    ///             .bind_resources(resources)
+    ///             .draw_each(sub_meshes)
+    ///             .finish()
+    ///     })
+    /// });
+    /// # }
+    /// ```
+    pub fn draw_each<I>(
+        self,
+        ranges: I,
+    ) -> GraphicsPipelineTaskBuilder<
+        'a,
+        V,
+        R,
+        Vb,
+        Ib,
+        R,
+        Sequence<T, DrawEachCommand, PipelineTaskContext>,
+    >
+    where
+        I: IntoIterator<Item = DrawRange>,
+        Vb: VertexBuffers,
+        Rb: ResourceBindings,
+        T: GpuTask<PipelineTaskContext>,
+    {
+        GraphicsPipelineTaskBuilder {
+            context_id: self.context_id,
+            topology: self.topology,
+            pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids,
+            vertex_input_layout: self.vertex_input_layout,
+            instance_count: self.instance_count,
+            task: sequence(
+                self.task,
+                DrawEachCommand {
+                    pipeline_task_id: self.pipeline_task_id,
+                    topology: self.topology,
+                    ranges: ranges.into_iter().collect(),
+                },
+            ),
+            _pipeline: marker::PhantomData,
+            _vertex_buffers: marker::PhantomData,
+            _index_buffer: marker::PhantomData,
+            _resource_bindings: marker::PhantomData,
+        }
+    }
+
+    /// Creates a [DrawEachCommand] that will execute the active graphics pipeline once for each
+    /// pair of a vertex offset in `firsts` and a vertex count in `counts`, submitting all draws with
+    /// a single call.
+    ///
+    /// Requires a reference to the [multi_draw::Extension] object, which acts as a proof that the
+    /// `WEBGL_multi_draw` extension is available (see the [multi_draw] module for details). See
+    /// also [draw_each], which does not require the extension.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `firsts` and `counts` do not have the same length.
+    ///
+    /// Panics if the `extension` belongs to a different context than the pipeline.
+    pub fn multi_draw_arrays(
+        self,
+        extension: &multi_draw::Extension,
+        firsts: &[usize],
+        counts: &[usize],
+    ) -> GraphicsPipelineTaskBuilder<
+        'a,
+        V,
+        R,
+        Vb,
+        Ib,
+        R,
+        Sequence<T, DrawEachCommand, PipelineTaskContext>,
+    >
+    where
+        Vb: VertexBuffers,
+        Rb: ResourceBindings,
+        T: GpuTask<PipelineTaskContext>,
+    {
+        if extension.context_id() != self.context_id {
+            panic!("Extension belongs to a different context than the pipeline.");
+        }
+
+        if firsts.len() != counts.len() {
+            panic!("`firsts` and `counts` must have the same length.");
+        }
+
+        self.draw_each(firsts.iter().zip(counts).map(|(first, count)| DrawRange {
+            first: *first,
+            count: *count,
+            instance_count: 1,
+        }))
+    }
+
+    /// Creates a [DrawIndexedCommand] that will execute the active graphics pipeline, streaming
+    /// `index_count` vertex indices for `instance_count` instances from the currently bound index
+    /// buffer, which produces a vertex stream by indexing into the vertex array defined by the
+    /// currently bound per-vertex vertex buffers.
+    ///
+    /// This command may only be added to the builder after an index buffer has been bound (see
+    /// [bind_index_buffer]. If the pipeline requires vertex buffers, then this command may only be
+    /// added to the builder after appropriate vertex buffers have been bound (see
+    /// [bind_vertex_buffers]). If the pipeline requires resources, then this command may only be
+    /// added to the builder after appropriate resources have been bound (see [bind_resources]).
+    ///
+    /// This will draw to the framebuffer that created the encapsulating [PipelineTask] (see
+    /// [Framebuffer::pipeline_task]). The pipeline's first color output will be stored to the
+    /// framebuffer's first color buffer (if present), the second color output will be stored to the
+    /// framebuffer's second color buffer (if present), etc. The pipeline's depth test (if enabled)
+    /// may update the framebuffer's depth-stencil buffer (if it present and is a [DepthRenderable]
+    /// or [DepthStencilRenderable] format, otherwise the depth test will act as if it was
+    /// disabled). The pipeline's stencil test (if enabled) may update the framebuffer's
+    /// depth-stencil buffer (if it is present and is [StencilRenderable] or
+    /// [DepthStencilRenderable] format, otherwise the stencil test will act as if was disabled).
+    ///
+    /// See also [draw] for drawing without an index buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::rendering::DefaultRGBBuffer;
+    /// # use web_glitz::rendering::DefaultRenderTarget;
+    /// # use web_glitz::buffer::{UsageHint, BufferView};
+    /// # use web_glitz::pipeline::graphics::{GraphicsPipeline, Vertex, IndexBufferView};
     /// # fn wrapper<V>(
     /// #     mut render_target: DefaultRenderTarget<DefaultRGBBuffer, ()>,
     /// #     vertex_buffers: BufferView<[V]>,
@@ -1927,6 +2756,9 @@ impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb,
             context_id: self.context_id,
             topology: self.topology,
             pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids,
+            vertex_input_layout: self.vertex_input_layout,
+            instance_count: self.instance_count,
             task: sequence(
                 self.task,
                 DrawIndexedCommand {
@@ -1943,22 +2775,802 @@ impl<'a, V, R, Vb, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, V, R, Vb, Ib, Rb,
         }
     }
 
-    /// Finishes the builder and returns the resulting pipeline task.
-    pub fn finish(self) -> T {
-        self.task
+    /// Creates a [DrawIndexedEachCommand] that will execute the active graphics pipeline in indexed
+    /// mode once for each of the `ranges`, streaming [DrawRange::count] vertex indices starting at
+    /// index [DrawRange::first] for [DrawRange::instance_count] instances from the currently bound
+    /// index buffer.
+    ///
+    /// This is equivalent to adding a sequence of [draw_indexed] commands to the builder (one for
+    /// each of the `ranges`), except that the program, vertex buffers, index buffer and resources
+    /// remain bound across all draws. If the `WEBGL_multi_draw` extension is available, then all
+    /// draws are submitted with a single call.
+    ///
+    /// This command may only be added to the builder after an index buffer has been bound (see
+    /// [bind_index_buffer]).
+    ///
+    /// See also [draw_each] for drawing without an index buffer.
+    pub fn draw_indexed_each<I>(
+        self,
+        ranges: I,
+    ) -> GraphicsPipelineTaskBuilder<
+        'a,
+        V,
+        R,
+        Vb,
+        Ib,
+        R,
+        Sequence<T, DrawIndexedEachCommand, PipelineTaskContext>,
+    >
+    where
+        I: IntoIterator<Item = DrawRange>,
+        Vb: VertexBuffers,
+        Ib: IndexData,
+        Rb: ResourceBindings,
+        T: GpuTask<PipelineTaskContext>,
+    {
+        GraphicsPipelineTaskBuilder {
+            context_id: self.context_id,
+            topology: self.topology,
+            pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids,
+            vertex_input_layout: self.vertex_input_layout,
+            instance_count: self.instance_count,
+            task: sequence(
+                self.task,
+                DrawIndexedEachCommand {
+                    pipeline_task_id: self.pipeline_task_id,
+                    topology: self.topology,
+                    ranges: ranges.into_iter().collect(),
+                },
+            ),
+            _pipeline: marker::PhantomData,
+            _vertex_buffers: marker::PhantomData,
+            _index_buffer: marker::PhantomData,
+            _resource_bindings: marker::PhantomData,
+        }
+    }
+
+    /// Creates a [DrawIndexedEachCommand] that will execute the active graphics pipeline in indexed
+    /// mode once for each pair of an index count in `counts` and an index offset in `offsets`,
+    /// submitting all draws with a single call.
+    ///
+    /// The `offsets` are specified in indices (not bytes), relative to the start of the currently
+    /// bound index buffer.
+    ///
+    /// Requires a reference to the [multi_draw::Extension] object, which acts as a proof that the
+    /// `WEBGL_multi_draw` extension is available (see the [multi_draw] module for details). See
+    /// also [draw_indexed_each], which does not require the extension.
+    ///
+    /// This command may only be added to the builder after an index buffer has been bound (see
+    /// [bind_index_buffer]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `counts` and `offsets` do not have the same length.
+    ///
+    /// Panics if the `extension` belongs to a different context than the pipeline.
+    pub fn multi_draw_elements(
+        self,
+        extension: &multi_draw::Extension,
+        counts: &[usize],
+        offsets: &[usize],
+    ) -> GraphicsPipelineTaskBuilder<
+        'a,
+        V,
+        R,
+        Vb,
+        Ib,
+        R,
+        Sequence<T, DrawIndexedEachCommand, PipelineTaskContext>,
+    >
+    where
+        Vb: VertexBuffers,
+        Ib: IndexData,
+        Rb: ResourceBindings,
+        T: GpuTask<PipelineTaskContext>,
+    {
+        if extension.context_id() != self.context_id {
+            panic!("Extension belongs to a different context than the pipeline.");
+        }
+
+        if counts.len() != offsets.len() {
+            panic!("`counts` and `offsets` must have the same length.");
+        }
+
+        self.draw_indexed_each(counts.iter().zip(offsets).map(|(count, offset)| DrawRange {
+            first: *offset,
+            count: *count,
+            instance_count: 1,
+        }))
+    }
+
+    /// Changes the stencil reference value for subsequent draw commands in this pipeline task.
+    ///
+    /// The stencil test function and test mask configured for the pipeline (see [StencilTest]) are
+    /// kept; only the reference value is replaced, for both front-facing and back-facing
+    /// fragments. This allows e.g. a stencil reference that is incremented for each nesting level
+    /// of clipped regions, without creating a separate pipeline for each level. The new reference
+    /// value applies until the end of this pipeline task, or until it is changed again. See
+    /// [StencilMask] for a helper that manages the reference values for nested clip regions.
+    ///
+    /// Has no effect if the pipeline does not use a [StencilTest].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `reference` value does not fit in 8 bits (WebGL 2.0 stencil buffers always
+    /// store 8-bit stencil values).
+    ///
+    /// [StencilMask]: crate::pipeline::graphics::StencilMask
+    pub fn stencil_reference(
+        self,
+        reference: u32,
+    ) -> GraphicsPipelineTaskBuilder<
+        'a,
+        V,
+        R,
+        Vb,
+        Ib,
+        Rb,
+        Sequence<T, SetStencilReferenceCommand, PipelineTaskContext>,
+    >
+    where
+        T: GpuTask<PipelineTaskContext>,
+    {
+        if reference > MAX_STENCIL_VALUE {
+            panic!(
+                "The stencil reference value `{}` does not fit in an 8-bit stencil buffer.",
+                reference
+            );
+        }
+
+        GraphicsPipelineTaskBuilder {
+            context_id: self.context_id,
+            topology: self.topology,
+            pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids,
+            vertex_input_layout: self.vertex_input_layout,
+            instance_count: self.instance_count,
+            task: sequence(
+                self.task,
+                SetStencilReferenceCommand {
+                    pipeline_task_id: self.pipeline_task_id,
+                    reference,
+                },
+            ),
+            _pipeline: marker::PhantomData,
+            _vertex_buffers: marker::PhantomData,
+            _index_buffer: marker::PhantomData,
+            _resource_bindings: marker::PhantomData,
+        }
+    }
+
+    /// Changes the stencil write mask for subsequent draw commands in this pipeline task.
+    ///
+    /// Replaces the write mask configured for the pipeline (see [StencilTest::write_mask_front] and
+    /// [StencilTest::write_mask_back]) for both front-facing and back-facing fragments. The new
+    /// write mask applies until the end of this pipeline task, or until it is changed again.
+    ///
+    /// Has no effect if the pipeline does not use a [StencilTest].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `write_mask` does not fit in 8 bits (WebGL 2.0 stencil buffers always store
+    /// 8-bit stencil values).
+    pub fn stencil_write_mask(
+        self,
+        write_mask: u32,
+    ) -> GraphicsPipelineTaskBuilder<
+        'a,
+        V,
+        R,
+        Vb,
+        Ib,
+        Rb,
+        Sequence<T, SetStencilWriteMaskCommand, PipelineTaskContext>,
+    >
+    where
+        T: GpuTask<PipelineTaskContext>,
+    {
+        if write_mask > MAX_STENCIL_VALUE {
+            panic!(
+                "The stencil write mask `{}` does not fit in an 8-bit stencil buffer.",
+                write_mask
+            );
+        }
+
+        GraphicsPipelineTaskBuilder {
+            context_id: self.context_id,
+            topology: self.topology,
+            pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids,
+            vertex_input_layout: self.vertex_input_layout,
+            instance_count: self.instance_count,
+            task: sequence(
+                self.task,
+                SetStencilWriteMaskCommand {
+                    pipeline_task_id: self.pipeline_task_id,
+                    write_mask,
+                },
+            ),
+            _pipeline: marker::PhantomData,
+            _vertex_buffers: marker::PhantomData,
+            _index_buffer: marker::PhantomData,
+            _resource_bindings: marker::PhantomData,
+        }
+    }
+
+    /// Overrides the pipeline's face-culling mode for subsequent draw commands in this pipeline
+    /// task.
+    ///
+    /// The pipeline itself keeps the [CullingMode] it was created with: the override applies until
+    /// the end of this pipeline task, or until it is changed again. This allows e.g. drawing the
+    /// back-faces and then the front-faces of a transparent mesh with a single pipeline.
+    ///
+    /// Face-culling only applies to triangle primitives; this has no effect on pipelines that
+    /// draw points or lines.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::rendering::DefaultRGBBuffer;
+    /// # use web_glitz::rendering::DefaultRenderTarget;
+    /// # use web_glitz::buffer::BufferView;
+    /// # use web_glitz::pipeline::graphics::{GraphicsPipeline, Vertex};
+    /// # fn wrapper<V>(
+    /// #     mut render_target: DefaultRenderTarget<DefaultRGBBuffer, ()>,
+    /// #     vertex_buffer: BufferView<[V]>,
+    /// #     graphics_pipeline: GraphicsPipeline<V, (), ()>
+    /// # )
+    /// # where
+    /// #     V: Vertex,
+    /// # {
+    /// use web_glitz::pipeline::graphics::CullingMode;
+    ///
+    /// let render_pass = render_target.create_render_pass(|framebuffer| {
+    ///     framebuffer.pipeline_task(&graphics_pipeline, |active_pipeline| {
+    ///         active_pipeline.task_builder()
+    ///             .bind_vertex_buffers(vertex_buffer)
+    ///             .bind_resources(())
+    ///             .culling_mode(CullingMode::Front)
+    ///             .draw(36, 1)
+    ///             .culling_mode(CullingMode::Back)
+    ///             .draw(36, 1)
+    ///             .finish()
+    ///     })
+    /// });
+    /// # }
+    /// ```
+    pub fn culling_mode(
+        self,
+        culling_mode: CullingMode,
+    ) -> GraphicsPipelineTaskBuilder<
+        'a,
+        V,
+        R,
+        Vb,
+        Ib,
+        Rb,
+        Sequence<T, SetCullingModeCommand, PipelineTaskContext>,
+    >
+    where
+        T: GpuTask<PipelineTaskContext>,
+    {
+        GraphicsPipelineTaskBuilder {
+            context_id: self.context_id,
+            topology: self.topology,
+            pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids,
+            vertex_input_layout: self.vertex_input_layout,
+            instance_count: self.instance_count,
+            task: sequence(
+                self.task,
+                SetCullingModeCommand {
+                    pipeline_task_id: self.pipeline_task_id,
+                    culling_mode,
+                },
+            ),
+            _pipeline: marker::PhantomData,
+            _vertex_buffers: marker::PhantomData,
+            _index_buffer: marker::PhantomData,
+            _resource_bindings: marker::PhantomData,
+        }
+    }
+
+    /// Overrides the pipeline's blending configuration for subsequent draw commands in this
+    /// pipeline task.
+    ///
+    /// If `blending` is `None`, then blending is disabled. The pipeline itself keeps the
+    /// [Blending] it was created with: the override applies until the end of this pipeline task,
+    /// or until it is changed again. This allows e.g. drawing particles with alpha blending or
+    /// additive blending depending on material data, with a single pipeline.
+    ///
+    /// The same rules apply as for the blending configuration of the pipeline itself (see
+    /// [GraphicsPipelineDescriptorBuilder::enable_blending]): blending only applies to color
+    /// buffers with a fixed-point or floating point format, it is ignored for color buffers with
+    /// an integer format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::rendering::DefaultRGBBuffer;
+    /// # use web_glitz::rendering::DefaultRenderTarget;
+    /// # use web_glitz::buffer::BufferView;
+    /// # use web_glitz::pipeline::graphics::{GraphicsPipeline, Vertex};
+    /// # fn wrapper<V>(
+    /// #     mut render_target: DefaultRenderTarget<DefaultRGBBuffer, ()>,
+    /// #     opaque_particles: BufferView<[V]>,
+    /// #     additive_particles: BufferView<[V]>,
+    /// #     graphics_pipeline: GraphicsPipeline<V, (), ()>
+    /// # )
+    /// # where
+    /// #     V: Vertex,
+    /// # {
+    /// use web_glitz::pipeline::graphics::{Blending, BlendFactor};
+    ///
+    /// let render_pass = render_target.create_render_pass(|framebuffer| {
+    ///     framebuffer.pipeline_task(&graphics_pipeline, |active_pipeline| {
+    ///         active_pipeline.task_builder()
+    ///             .bind_resources(())
+    ///             .blending(None)
+    ///             .bind_vertex_buffers(&opaque_particles)
+    ///             .draw(opaque_particles.len(), 1)
+    ///             .blending(Some(Blending {
+    ///                 source_color_factor: BlendFactor::One,
+    ///                 destination_color_factor: BlendFactor::One,
+    ///                 ..Default::default()
+    ///             }))
+    ///             .bind_vertex_buffers(&additive_particles)
+    ///             .draw(additive_particles.len(), 1)
+    ///             .finish()
+    ///     })
+    /// });
+    /// # }
+    /// ```
+    pub fn blending(
+        self,
+        blending: Option<Blending>,
+    ) -> GraphicsPipelineTaskBuilder<
+        'a,
+        V,
+        R,
+        Vb,
+        Ib,
+        Rb,
+        Sequence<T, SetBlendingCommand, PipelineTaskContext>,
+    >
+    where
+        T: GpuTask<PipelineTaskContext>,
+    {
+        GraphicsPipelineTaskBuilder {
+            context_id: self.context_id,
+            topology: self.topology,
+            pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids,
+            vertex_input_layout: self.vertex_input_layout,
+            instance_count: self.instance_count,
+            task: sequence(
+                self.task,
+                SetBlendingCommand {
+                    pipeline_task_id: self.pipeline_task_id,
+                    blending,
+                },
+            ),
+            _pipeline: marker::PhantomData,
+            _vertex_buffers: marker::PhantomData,
+            _index_buffer: marker::PhantomData,
+            _resource_bindings: marker::PhantomData,
+        }
+    }
+
+    /// Finishes the builder and returns the resulting pipeline task.
+    pub fn finish(self) -> T {
+        self.task
+    }
+}
+
+impl<'a, R, Ib, Rb, T> GraphicsPipelineTaskBuilder<'a, (), R, Unspecified, Ib, Rb, T> {
+    /// Creates a [DrawCommand] that will execute the active graphics pipeline for `vertex_count`
+    /// vertices without any vertex buffers bound.
+    ///
+    /// This is only available for pipelines with an empty vertex input layout, for use with
+    /// vertex shaders that do not declare any vertex input attributes, but instead derive their
+    /// outputs from `gl_VertexID` (and/or `gl_InstanceID`). For example, a "fullscreen triangle"
+    /// for a post-processing pass may be drawn with the following vertex shader:
+    ///
+    /// ```glsl
+    /// #version 300 es
+    ///
+    /// out vec2 uv;
+    ///
+    /// void main() {
+    ///     uv = vec2((gl_VertexID << 1) & 2, gl_VertexID & 2);
+    ///     gl_Position = vec4(uv * 2.0 - 1.0, 0.0, 1.0);
+    /// }
+    /// ```
+    ///
+    /// If the pipeline requires resources, then this command may only be added to the builder
+    /// after appropriate resources have been bound (see [bind_resources]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::rendering::DefaultRGBBuffer;
+    /// # use web_glitz::rendering::DefaultRenderTarget;
+    /// # use web_glitz::pipeline::graphics::GraphicsPipeline;
+    /// # fn wrapper(
+    /// #     mut render_target: DefaultRenderTarget<DefaultRGBBuffer, ()>,
+    /// #     graphics_pipeline: GraphicsPipeline<(), (), ()>
+    /// # ) {
+    /// # let resources = ();
+    /// let render_pass = render_target.create_render_pass(|framebuffer| {
+    ///     framebuffer.pipeline_task(&graphics_pipeline, |active_pipeline| {
+    ///         active_pipeline.task_builder()
+    ///             .bind_resources(resources)
+    ///             .draw_without_vertices(3)
+    ///             .finish()
+    ///     })
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// Alternatively, the empty set of vertex buffers `()` may be bound explicitly, e.g. to draw
+    /// several instances with [draw]:
+    ///
+    /// ```
+    /// # use web_glitz::rendering::DefaultRGBBuffer;
+    /// # use web_glitz::rendering::DefaultRenderTarget;
+    /// # use web_glitz::pipeline::graphics::GraphicsPipeline;
+    /// # fn wrapper(
+    /// #     mut render_target: DefaultRenderTarget<DefaultRGBBuffer, ()>,
+    /// #     graphics_pipeline: GraphicsPipeline<(), (), ()>
+    /// # ) {
+    /// # let resources = ();
+    /// let render_pass = render_target.create_render_pass(|framebuffer| {
+    ///     framebuffer.pipeline_task(&graphics_pipeline, |active_pipeline| {
+    ///         active_pipeline.task_builder()
+    ///             .bind_vertex_buffers(())
+    ///             .bind_resources(resources)
+    ///             .draw(3, 16)
+    ///             .finish()
+    ///     })
+    /// });
+    /// # }
+    /// ```
+    pub fn draw_without_vertices(
+        self,
+        vertex_count: usize,
+    ) -> GraphicsPipelineTaskBuilder<
+        'a,
+        (),
+        R,
+        Unspecified,
+        Ib,
+        R,
+        Sequence<T, DrawCommand, PipelineTaskContext>,
+    >
+    where
+        Rb: ResourceBindings,
+        T: GpuTask<PipelineTaskContext>,
+    {
+        GraphicsPipelineTaskBuilder {
+            context_id: self.context_id,
+            topology: self.topology,
+            pipeline_task_id: self.pipeline_task_id,
+            attachment_ids: self.attachment_ids,
+            vertex_input_layout: self.vertex_input_layout,
+            instance_count: self.instance_count,
+            task: sequence(
+                self.task,
+                DrawCommand {
+                    pipeline_task_id: self.pipeline_task_id,
+                    topology: self.topology,
+                    vertex_count,
+                    instance_count: 1,
+                },
+            ),
+            _pipeline: marker::PhantomData,
+            _vertex_buffers: marker::PhantomData,
+            _index_buffer: marker::PhantomData,
+            _resource_bindings: marker::PhantomData,
+        }
+    }
+}
+
+/// Command that binds a (set of) vertex buffer(s) to the currently bound graphics pipeline.
+///
+/// See [GraphicsPipelineTaskBuilder::bind_vertex_buffers].
+#[derive(Clone)]
+pub struct BindVertexBuffersCommand {
+    pipeline_task_id: u64,
+    vertex_buffers: Option<StaticVec<BufferDescriptor, 16>>,
+}
+
+unsafe impl GpuTask<PipelineTaskContext> for BindVertexBuffersCommand {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.pipeline_task_id)
+    }
+
+    fn validate(&self, context: &mut ValidationContext) {
+        if let Some(vertex_buffers) = &self.vertex_buffers {
+            context.record_vertex_buffers(vertex_buffers);
+        }
+    }
+
+    fn progress(&mut self, execution_context: &mut PipelineTaskContext) -> Progress<Self::Output> {
+        execution_context.vertex_buffers =
+            self.vertex_buffers.take().expect("Cannot progress twice");
+
+        Progress::Finished(())
+    }
+}
+
+/// Command that binds an index buffer to the currently bound graphics pipeline.
+///
+/// See [GraphicsPipelineTaskBuilder::bind_index_buffer].
+#[derive(Clone)]
+pub struct BindIndexBufferCommand {
+    pipeline_task_id: u64,
+    index_buffer: IndexDataDescriptor,
+}
+
+unsafe impl GpuTask<PipelineTaskContext> for BindIndexBufferCommand {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.pipeline_task_id)
+    }
+
+    fn progress(&mut self, execution_context: &mut PipelineTaskContext) -> Progress<Self::Output> {
+        execution_context.index_buffer = Some(self.index_buffer.clone());
+
+        Progress::Finished(())
+    }
+}
+
+/// Command that binds the vertex buffers and index buffer of a [VertexArray] to the currently
+/// bound graphics pipeline.
+///
+/// See [GraphicsPipelineTaskBuilder::bind_vertex_array].
+#[derive(Clone)]
+pub struct BindVertexArrayCommand {
+    pipeline_task_id: u64,
+    vertex_buffers: StaticVec<BufferDescriptor, 16>,
+    index_buffer: IndexDataDescriptor,
+}
+
+unsafe impl GpuTask<PipelineTaskContext> for BindVertexArrayCommand {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.pipeline_task_id)
+    }
+
+    fn validate(&self, context: &mut ValidationContext) {
+        context.record_vertex_buffers(&self.vertex_buffers);
+    }
+
+    fn progress(&mut self, execution_context: &mut PipelineTaskContext) -> Progress<Self::Output> {
+        execution_context.vertex_buffers = self.vertex_buffers.clone();
+        execution_context.index_buffer = Some(self.index_buffer.clone());
+
+        Progress::Finished(())
+    }
+}
+
+/// Error returned when a sampled texture that is bound to a pipeline task is also attached to the
+/// framebuffer the pipeline task draws to.
+///
+/// Sampling a texture while it is also being rendered to results in undefined behaviour. A texture
+/// may be sampled while one of its levels is attached if the sampled level range excludes the
+/// attached level (see [FloatSampledTexture2D::with_level_range]).
+///
+/// See [GraphicsPipelineTaskBuilder::try_bind_resources].
+///
+/// [FloatSampledTexture2D::with_level_range]: crate::image::texture_2d::FloatSampledTexture2D::with_level_range
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct AttachmentAliasingError {
+    /// The index of the bind group that contains the texture.
+    pub bind_group_index: u32,
+
+    /// The texture unit the texture is bound to.
+    pub texture_unit: u32,
+}
+
+/// Error returned when the vertex buffers bound to a pipeline are not compatible with the bind
+/// slots of the pipeline's vertex input layout.
+///
+/// See [GraphicsPipelineTaskBuilder::try_bind_vertex_buffers_untyped].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum IncompatibleVertexBuffers {
+    /// The number of vertex buffers does not match the number of bind slots.
+    BufferCountMismatch {
+        /// The number of bind slots in the pipeline's vertex input layout.
+        expected_count: usize,
+
+        /// The number of vertex buffers.
+        actual_count: usize,
+    },
+
+    /// The size of the elements in a vertex buffer does not evenly divide the stride of the bind
+    /// slot it is bound to.
+    StrideMismatch {
+        /// The index of the bind slot the vertex buffer is bound to.
+        bind_slot: u32,
+
+        /// The stride in bytes specified for the bind slot by the pipeline's vertex input layout.
+        expected_stride: u32,
+
+        /// The size in bytes of the elements in the vertex buffer.
+        element_size: u32,
+    },
+}
+
+/// Error returned when the instance count for a draw is to be derived from the bound per-instance
+/// vertex buffers, but none of the bound vertex buffers is bound to a per-instance bind slot.
+///
+/// See [GraphicsPipelineTaskBuilder::try_draw_all_instances].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MissingInstanceBuffer;
+
+// Returns the smallest number of elements in any of the `vertex_buffers` that are bound to a bind
+// slot with a per-instance input rate, or `None` if none of the buffers is bound to such a slot.
+//
+// The number of elements is determined with the stride of the bind slot, rather than the size of
+// the elements in the buffer, which may be smaller for an untyped buffer (e.g. a `[f32]` slice).
+fn per_instance_count(
+    vertex_buffers: &[BufferDescriptor],
+    vertex_input_layout: &VertexInputLayoutDescriptor,
+) -> Option<usize> {
+    min_instance_count(
+        vertex_buffers
+            .iter()
+            .zip(vertex_input_layout.buffer_slots())
+            .map(|(buffer, slot)| (buffer.size_in_bytes, slot.stride_in_bytes() as u32)),
+        vertex_input_layout
+            .buffer_slots()
+            .map(|slot| slot.input_rate()),
+    )
+}
+
+fn min_instance_count<B, S>(buffers: B, slot_input_rates: S) -> Option<usize>
+where
+    B: IntoIterator<Item = (u32, u32)>,
+    S: IntoIterator<Item = InputRate>,
+{
+    buffers
+        .into_iter()
+        .zip(slot_input_rates)
+        .filter(|(_, input_rate)| *input_rate == InputRate::PerInstance)
+        .map(|((size_in_bytes, stride_in_bytes), _)| {
+            if stride_in_bytes == 0 {
+                0
+            } else {
+                (size_in_bytes / stride_in_bytes) as usize
+            }
+        })
+        .min()
+}
+
+fn check_vertex_buffers(
+    element_sizes: &[u32],
+    slot_strides: &[u32],
+) -> Result<(), IncompatibleVertexBuffers> {
+    if element_sizes.len() != slot_strides.len() {
+        return Err(IncompatibleVertexBuffers::BufferCountMismatch {
+            expected_count: slot_strides.len(),
+            actual_count: element_sizes.len(),
+        });
+    }
+
+    for (bind_slot, (element_size, expected_stride)) in
+        element_sizes.iter().zip(slot_strides).enumerate()
+    {
+        if *element_size == 0 || expected_stride % element_size != 0 {
+            return Err(IncompatibleVertexBuffers::StrideMismatch {
+                bind_slot: bind_slot as u32,
+                expected_stride: *expected_stride,
+                element_size: *element_size,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates the [BlitCommand] for [Framebuffer::blit_color_depth_command], where the
+/// `depth_attachment` is the attachment point of the framebuffer's depth or depth-stencil buffer.
+fn color_depth_blit_command(
+    pipeline_target: &GraphicsPipelineTarget,
+    target: BlitTargetDescriptor,
+    region: Region2D,
+    color_source_descriptor: BlitSourceDescriptor,
+    depth_source_descriptor: BlitSourceDescriptor,
+    depth_attachment: u32,
+) -> BlitCommand {
+    if color_source_descriptor.context_id != pipeline_target.context_id {
+        panic!("The color source image belongs to a different context than the framebuffer.");
+    }
+
+    if depth_source_descriptor.context_id != pipeline_target.context_id {
+        panic!("The depth source image belongs to a different context than the framebuffer.");
+    }
+
+    if color_source_descriptor.region != depth_source_descriptor.region {
+        panic!(
+            "The color source and the depth source must describe the same rectangle; the color \
+             source describes {:?}, the depth source describes {:?}.",
+            color_source_descriptor.region, depth_source_descriptor.region
+        );
+    }
+
+    BlitCommand {
+        render_pass_id: pipeline_target.render_pass_id,
+        read_slot: Gl::COLOR_ATTACHMENT0,
+        bitmask: Gl::COLOR_BUFFER_BIT | Gl::DEPTH_BUFFER_BIT,
+        filter: Gl::NEAREST,
+        target_region: region,
+        target,
+        source: color_source_descriptor,
+        depth_source: Some((depth_attachment, depth_source_descriptor)),
+    }
+}
+
+fn check_attachment_aliasing(
+    bind_groups: &[BindGroupDescriptor],
+    attachment_ids: &[(JsId, u8)],
+) -> Result<(), AttachmentAliasingError> {
+    find_attachment_alias(
+        bind_groups.iter().flat_map(|group| {
+            group
+                .sampled_textures()
+                .map(move |(unit, id, levels)| (group.bind_group_index, unit, id, levels))
+        }),
+        attachment_ids,
+    )
+}
+
+/// Finds the first sampled texture for which the sampled level range includes a level that is
+/// attached to the framebuffer, where the `attachment_ids` are the IDs of the attached images and
+/// the mipmap levels at which they are attached.
+fn find_attachment_alias<I>(
+    sampled_textures: I,
+    attachment_ids: &[(JsId, u8)],
+) -> Result<(), AttachmentAliasingError>
+where
+    I: IntoIterator<Item = (u32, u32, JsId, RangeInclusive<u8>)>,
+{
+    for (bind_group_index, texture_unit, id, levels) in sampled_textures {
+        let is_aliased = attachment_ids
+            .iter()
+            .any(|(attachment_id, level)| *attachment_id == id && levels.contains(level));
+
+        if is_aliased {
+            return Err(AttachmentAliasingError {
+                bind_group_index,
+                texture_unit,
+            });
+        }
     }
+
+    Ok(())
 }
 
-/// Command that binds a (set of) vertex buffer(s) to the currently bound graphics pipeline.
+/// Command that binds a set of resources to the resource slots of the currently bound pipeline.
 ///
-/// See [GraphicsPipelineTaskBuilder::bind_vertex_buffers].
+/// See [GraphicsPipelineTaskBuilder::bind_resources].
 #[derive(Clone)]
-pub struct BindVertexBuffersCommand {
+pub struct BindResourcesCommand<Rb> {
     pipeline_task_id: u64,
-    vertex_buffers: Option<StaticVec<BufferDescriptor, 16>>,
+    resource_bindings: Rb,
 }
 
-unsafe impl GpuTask<PipelineTaskContext> for BindVertexBuffersCommand {
+unsafe impl<Rb> GpuTask<PipelineTaskContext> for BindResourcesCommand<Rb>
+where
+    Rb: Borrow<[BindGroupDescriptor]>,
+    Rb: Borrow<[BindGroupDescriptor]>,
+{
     type Output = ();
 
     fn context_id(&self) -> ContextId {
@@ -1966,60 +3578,128 @@ unsafe impl GpuTask<PipelineTaskContext> for BindVertexBuffersCommand {
     }
 
     fn progress(&mut self, execution_context: &mut PipelineTaskContext) -> Progress<Self::Output> {
-        execution_context.vertex_buffers =
-            self.vertex_buffers.take().expect("Cannot progress twice");
+        let resource_slot_usage = unsafe { &*execution_context.resource_slot_usage };
+
+        for descriptor in self.resource_bindings.borrow().iter() {
+            descriptor.bind(execution_context.connection_mut(), resource_slot_usage);
+        }
 
         Progress::Finished(())
     }
 }
 
-/// Command that binds an index buffer to the currently bound graphics pipeline.
+/// Command that changes the stencil reference value for the currently bound graphics pipeline.
 ///
-/// See [GraphicsPipelineTaskBuilder::bind_index_buffer].
-#[derive(Clone)]
-pub struct BindIndexBufferCommand {
+/// See [GraphicsPipelineTaskBuilder::stencil_reference].
+#[derive(Clone, Copy)]
+pub struct SetStencilReferenceCommand {
     pipeline_task_id: u64,
-    index_buffer: IndexDataDescriptor,
+    reference: u32,
 }
 
-unsafe impl GpuTask<PipelineTaskContext> for BindIndexBufferCommand {
+unsafe impl GpuTask<PipelineTaskContext> for SetStencilReferenceCommand {
     type Output = ();
 
     fn context_id(&self) -> ContextId {
         ContextId::Id(self.pipeline_task_id)
     }
 
-    fn progress(&mut self, execution_context: &mut PipelineTaskContext) -> Progress<Self::Output> {
-        execution_context.index_buffer = Some(self.index_buffer.clone());
+    fn progress(&mut self, context: &mut PipelineTaskContext) -> Progress<Self::Output> {
+        let (gl, state) = unsafe { (*context.connection).unpack_mut() };
+
+        let func_front = state.stencil_func_front();
+        let mask_front = state.stencil_test_mask_front();
+
+        state
+            .set_stencil_func_front(func_front, self.reference as i32, mask_front)
+            .apply(gl)
+            .unwrap();
+
+        let func_back = state.stencil_func_back();
+        let mask_back = state.stencil_test_mask_back();
+
+        state
+            .set_stencil_func_back(func_back, self.reference as i32, mask_back)
+            .apply(gl)
+            .unwrap();
 
         Progress::Finished(())
     }
 }
 
-/// Command that binds a set of resources to the resource slots of the currently bound pipeline.
+/// Command that changes the stencil write mask for the currently bound graphics pipeline.
 ///
-/// See [GraphicsPipelineTaskBuilder::bind_resources].
+/// See [GraphicsPipelineTaskBuilder::stencil_write_mask].
+#[derive(Clone, Copy)]
+pub struct SetStencilWriteMaskCommand {
+    pipeline_task_id: u64,
+    write_mask: u32,
+}
+
+unsafe impl GpuTask<PipelineTaskContext> for SetStencilWriteMaskCommand {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.pipeline_task_id)
+    }
+
+    fn progress(&mut self, context: &mut PipelineTaskContext) -> Progress<Self::Output> {
+        let (gl, state) = unsafe { (*context.connection).unpack_mut() };
+
+        state
+            .set_stencil_write_mask_front(self.write_mask)
+            .apply(gl)
+            .unwrap();
+        state
+            .set_stencil_write_mask_back(self.write_mask)
+            .apply(gl)
+            .unwrap();
+
+        Progress::Finished(())
+    }
+}
+
+/// Command that overrides the face-culling mode for the currently bound graphics pipeline.
+///
+/// See [GraphicsPipelineTaskBuilder::culling_mode].
+#[derive(Clone, Copy)]
+pub struct SetCullingModeCommand {
+    pipeline_task_id: u64,
+    culling_mode: CullingMode,
+}
+
+unsafe impl GpuTask<PipelineTaskContext> for SetCullingModeCommand {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.pipeline_task_id)
+    }
+
+    fn progress(&mut self, context: &mut PipelineTaskContext) -> Progress<Self::Output> {
+        self.culling_mode.apply(unsafe { &mut *context.connection });
+
+        Progress::Finished(())
+    }
+}
+
+/// Command that overrides the blending configuration for the currently bound graphics pipeline.
+///
+/// See [GraphicsPipelineTaskBuilder::blending].
 #[derive(Clone)]
-pub struct BindResourcesCommand<Rb> {
+pub struct SetBlendingCommand {
     pipeline_task_id: u64,
-    resource_bindings: Rb,
+    blending: Option<Blending>,
 }
 
-unsafe impl<Rb> GpuTask<PipelineTaskContext> for BindResourcesCommand<Rb>
-where
-    Rb: Borrow<[BindGroupDescriptor]>,
-    Rb: Borrow<[BindGroupDescriptor]>,
-{
+unsafe impl GpuTask<PipelineTaskContext> for SetBlendingCommand {
     type Output = ();
 
     fn context_id(&self) -> ContextId {
         ContextId::Id(self.pipeline_task_id)
     }
 
-    fn progress(&mut self, execution_context: &mut PipelineTaskContext) -> Progress<Self::Output> {
-        for descriptor in self.resource_bindings.borrow().iter() {
-            descriptor.bind(execution_context.connection_mut());
-        }
+    fn progress(&mut self, context: &mut PipelineTaskContext) -> Progress<Self::Output> {
+        Blending::apply(&self.blending, context.connection_mut());
 
         Progress::Finished(())
     }
@@ -2043,6 +3723,10 @@ unsafe impl GpuTask<PipelineTaskContext> for DrawCommand {
         ContextId::Id(self.pipeline_task_id)
     }
 
+    fn validate(&self, context: &mut ValidationContext) {
+        context.record_draw();
+    }
+
     fn progress(&mut self, context: &mut PipelineTaskContext) -> Progress<Self::Output> {
         let (gl, state) = unsafe { (*context.connection).unpack_mut() };
 
@@ -2054,6 +3738,8 @@ unsafe impl GpuTask<PipelineTaskContext> for DrawCommand {
             );
         }
 
+        state.record_draw(self.topology, self.vertex_count, self.instance_count);
+
         if self.instance_count == 1 {
             gl.draw_arrays(self.topology.id(), 0, self.vertex_count as i32);
         } else {
@@ -2087,6 +3773,10 @@ unsafe impl GpuTask<PipelineTaskContext> for DrawIndexedCommand {
         ContextId::Id(self.pipeline_task_id)
     }
 
+    fn validate(&self, context: &mut ValidationContext) {
+        context.record_draw();
+    }
+
     fn progress(&mut self, context: &mut PipelineTaskContext) -> Progress<Self::Output> {
         let (gl, state) = unsafe { (*context.connection).unpack_mut() };
 
@@ -2100,6 +3790,8 @@ unsafe impl GpuTask<PipelineTaskContext> for DrawIndexedCommand {
                 );
             }
 
+            state.record_draw(self.topology, self.index_count, self.instance_count);
+
             if self.instance_count == 1 {
                 gl.draw_elements_with_i32(
                     self.topology.id(),
@@ -2124,6 +3816,235 @@ unsafe impl GpuTask<PipelineTaskContext> for DrawIndexedCommand {
     }
 }
 
+/// Describes a single draw for a [DrawEachCommand] or a [DrawIndexedEachCommand].
+///
+/// See [GraphicsPipelineTaskBuilder::draw_each] and
+/// [GraphicsPipelineTaskBuilder::draw_indexed_each].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct DrawRange {
+    /// The first vertex (or the first index, for indexed drawing) of the draw.
+    pub first: usize,
+
+    /// The number of vertices (or indices, for indexed drawing) to stream.
+    pub count: usize,
+
+    /// The number of instances to draw.
+    pub instance_count: usize,
+}
+
+/// Command that runs the currently bound graphics pipeline once for each of a set of
+/// [DrawRange]s.
+///
+/// See [GraphicsPipelineTaskBuilder::draw_each].
+#[derive(Clone)]
+pub struct DrawEachCommand {
+    pipeline_task_id: u64,
+    topology: Topology,
+    ranges: Vec<DrawRange>,
+}
+
+unsafe impl GpuTask<PipelineTaskContext> for DrawEachCommand {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.pipeline_task_id)
+    }
+
+    fn validate(&self, context: &mut ValidationContext) {
+        if !self.ranges.is_empty() {
+            context.record_draw();
+        }
+    }
+
+    fn progress(&mut self, context: &mut PipelineTaskContext) -> Progress<Self::Output> {
+        if self.ranges.is_empty() {
+            return Progress::Finished(());
+        }
+
+        let multi_draw = unsafe { (*context.connection).multi_draw_extension() };
+        let (gl, state) = unsafe { (*context.connection).unpack_mut() };
+
+        unsafe {
+            state.vertex_array_cache_mut().bind_or_create(
+                &*context.attribute_layout,
+                &context.vertex_buffers,
+                gl,
+            );
+        }
+
+        for range in self.ranges.iter() {
+            state.record_draw(self.topology, range.count, range.instance_count);
+        }
+
+        let mode = self.topology.id();
+
+        if let Some(multi_draw) = multi_draw {
+            let draw_count = self.ranges.len() as i32;
+            let mut firsts: Vec<i32> = self.ranges.iter().map(|r| r.first as i32).collect();
+            let mut counts: Vec<i32> = self.ranges.iter().map(|r| r.count as i32).collect();
+
+            if self.ranges.iter().all(|r| r.instance_count == 1) {
+                multi_draw.multi_draw_arrays_webgl_with_i32_array_and_i32_array(
+                    mode,
+                    &mut firsts,
+                    0,
+                    &mut counts,
+                    0,
+                    draw_count,
+                );
+            } else {
+                let mut instance_counts: Vec<i32> = self
+                    .ranges
+                    .iter()
+                    .map(|r| r.instance_count as i32)
+                    .collect();
+
+                multi_draw
+                    .multi_draw_arrays_instanced_webgl_with_i32_array_and_i32_array_and_i32_array(
+                        mode,
+                        &mut firsts,
+                        0,
+                        &mut counts,
+                        0,
+                        &mut instance_counts,
+                        0,
+                        draw_count,
+                    );
+            }
+        } else {
+            for range in self.ranges.iter() {
+                if range.instance_count == 1 {
+                    gl.draw_arrays(mode, range.first as i32, range.count as i32);
+                } else {
+                    gl.draw_arrays_instanced(
+                        mode,
+                        range.first as i32,
+                        range.count as i32,
+                        range.instance_count as i32,
+                    );
+                }
+            }
+        }
+
+        Progress::Finished(())
+    }
+}
+
+/// Command that runs the currently bound graphics pipeline in indexed mode once for each of a set
+/// of [DrawRange]s.
+///
+/// See [GraphicsPipelineTaskBuilder::draw_indexed_each].
+#[derive(Clone)]
+pub struct DrawIndexedEachCommand {
+    pipeline_task_id: u64,
+    topology: Topology,
+    ranges: Vec<DrawRange>,
+}
+
+unsafe impl GpuTask<PipelineTaskContext> for DrawIndexedEachCommand {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Id(self.pipeline_task_id)
+    }
+
+    fn validate(&self, context: &mut ValidationContext) {
+        if !self.ranges.is_empty() {
+            context.record_draw();
+        }
+    }
+
+    fn progress(&mut self, context: &mut PipelineTaskContext) -> Progress<Self::Output> {
+        let multi_draw = unsafe { (*context.connection).multi_draw_extension() };
+        let (gl, state) = unsafe { (*context.connection).unpack_mut() };
+
+        if let Some(index_buffer) = &context.index_buffer {
+            if self.ranges.is_empty() {
+                return Progress::Finished(());
+            }
+
+            unsafe {
+                state.vertex_array_cache_mut().bind_or_create_indexed(
+                    &*context.attribute_layout,
+                    &context.vertex_buffers,
+                    index_buffer,
+                    gl,
+                );
+            }
+
+            for range in self.ranges.iter() {
+                state.record_draw(self.topology, range.count, range.instance_count);
+            }
+
+            let mode = self.topology.id();
+            let index_type = index_buffer.index_type.id();
+            let index_size = index_buffer.index_type.size_in_bytes();
+            let offset_of =
+                |range: &DrawRange| (index_buffer.offset + range.first as u32 * index_size) as i32;
+
+            if let Some(multi_draw) = multi_draw {
+                let draw_count = self.ranges.len() as i32;
+                let mut counts: Vec<i32> = self.ranges.iter().map(|r| r.count as i32).collect();
+                let mut offsets: Vec<i32> = self.ranges.iter().map(offset_of).collect();
+
+                if self.ranges.iter().all(|r| r.instance_count == 1) {
+                    multi_draw.multi_draw_elements_webgl_with_i32_array_and_i32_array(
+                        mode,
+                        &mut counts,
+                        0,
+                        index_type,
+                        &mut offsets,
+                        0,
+                        draw_count,
+                    );
+                } else {
+                    let mut instance_counts: Vec<i32> = self
+                        .ranges
+                        .iter()
+                        .map(|r| r.instance_count as i32)
+                        .collect();
+
+                    multi_draw
+                        .multi_draw_elements_instanced_webgl_with_i32_array_and_i32_array_and_i32_array(
+                            mode,
+                            &mut counts,
+                            0,
+                            index_type,
+                            &mut offsets,
+                            0,
+                            &mut instance_counts,
+                            0,
+                            draw_count,
+                        );
+                }
+            } else {
+                for range in self.ranges.iter() {
+                    if range.instance_count == 1 {
+                        gl.draw_elements_with_i32(
+                            mode,
+                            range.count as i32,
+                            index_type,
+                            offset_of(range),
+                        );
+                    } else {
+                        gl.draw_elements_instanced_with_i32(
+                            mode,
+                            range.count as i32,
+                            index_type,
+                            offset_of(range),
+                            range.instance_count as i32,
+                        );
+                    }
+                }
+            }
+        } else {
+            panic!("No index buffer.");
+        }
+
+        Progress::Finished(())
+    }
+}
+
 /// Helper trait implemented by color buffers that can serve as a target for a [BlitCommand],
 /// see [Framebuffer::blit_color_nearest_command] and [Framebuffer::blit_color_linear_command].
 pub trait BlitColorTarget {
@@ -2229,6 +4150,23 @@ enum BlitTargetDescriptorInternal {
     FBO { width: u32, height: u32 },
 }
 
+impl BlitTargetDescriptor {
+    fn intersect(self, width: u32, height: u32) -> Self {
+        match self.internal {
+            BlitTargetDescriptorInternal::Default => self,
+            BlitTargetDescriptorInternal::FBO {
+                width: target_width,
+                height: target_height,
+            } => BlitTargetDescriptor {
+                internal: BlitTargetDescriptorInternal::FBO {
+                    width: min(width, target_width),
+                    height: min(height, target_height),
+                },
+            },
+        }
+    }
+}
+
 /// Trait implemented by image reference types that can serve as the image data source for a color
 /// [BlitCommand].
 ///
@@ -2557,9 +4495,13 @@ impl_resolve_image_compatible!(
 /// Encapsulates a command that transfers a rectangle of pixels from a source image into the
 /// framebuffer.
 ///
+/// The source image is attached to an internal read framebuffer for the duration of the transfer,
+/// which always reads from the source image regardless of the position at which that image may be
+/// attached to other render targets.
+///
 /// See [Framebuffer::blit_color_nearest_command], [Framebuffer::blit_color_linear_command],
-/// [Framebuffer::blit_depth_stencil_command], [Framebuffer::blit_depth_command] and
-/// [Framebuffer::blit_stencil_command]
+/// [Framebuffer::blit_depth_stencil_command], [Framebuffer::blit_depth_command],
+/// [Framebuffer::blit_stencil_command] and [Framebuffer::blit_color_depth_command].
 #[derive(Clone)]
 pub struct BlitCommand {
     render_pass_id: u64,
@@ -2569,6 +4511,9 @@ pub struct BlitCommand {
     target: BlitTargetDescriptor,
     target_region: Region2D,
     source: BlitSourceDescriptor,
+    // Only used by combined color+depth blits, where the depth source is attached to the read
+    // framebuffer alongside the color source.
+    depth_source: Option<(u32, BlitSourceDescriptor)>,
 }
 
 unsafe impl GpuTask<RenderPassContext> for BlitCommand {
@@ -2604,6 +4549,12 @@ unsafe impl GpuTask<RenderPassContext> for BlitCommand {
             .attachment
             .attach(gl, Gl::READ_FRAMEBUFFER, self.read_slot);
 
+        if let Some((read_slot, depth_source)) = &self.depth_source {
+            depth_source
+                .attachment
+                .attach(gl, Gl::READ_FRAMEBUFFER, *read_slot);
+        }
+
         let ((src_x0, src_y0), src_width, src_height) = self.source.region;
         let src_x1 = src_x0 + src_width;
         let src_y1 = src_y0 + src_height;
@@ -2717,6 +4668,12 @@ unsafe impl GpuTask<RenderPassContext> for ResolveImageCommand {
 }
 
 /// Represents the color buffer for a [DefaultRenderTarget] without an alpha channel.
+///
+/// A context's default render target uses this color buffer type if alpha was disabled when the
+/// context was initialized (see [ContextOptionsBuilder::disable_alpha]). The canvas is composited
+/// with the page as fully opaque, regardless of the values that are written to it.
+///
+/// [ContextOptionsBuilder::disable_alpha]: crate::runtime::ContextOptionsBuilder::disable_alpha
 pub struct DefaultRGBBuffer {
     render_pass_id: u64,
 }
@@ -2765,6 +4722,15 @@ impl DefaultRGBBuffer {
 }
 
 /// Represents the color buffer for a [DefaultRenderTarget] with an alpha channel.
+///
+/// A context's default render target uses this color buffer type unless alpha was disabled when the
+/// context was initialized (see [ContextOptionsBuilder::disable_alpha]). The alpha values written
+/// to this buffer are used when the canvas is composited with the page: regions with an alpha
+/// value of `0.0` let the page behind the canvas show through. See [ContextOptions] for details on how
+/// the color values are interpreted during compositing.
+///
+/// [ContextOptions]: crate::runtime::ContextOptions
+/// [ContextOptionsBuilder::disable_alpha]: crate::runtime::ContextOptionsBuilder::disable_alpha
 pub struct DefaultRGBABuffer {
     render_pass_id: u64,
 }
@@ -3621,6 +5587,8 @@ unsafe impl GpuTask<RenderPassContext> for ClearFloatCommand {
             }
         }
 
+        state.set_color_mask([true; 4]).apply(gl).unwrap();
+
         gl.clear_bufferfv_with_f32_array(Gl::COLOR, self.buffer_index, &self.clear_value);
 
         Progress::Finished(())
@@ -3659,6 +5627,8 @@ unsafe impl GpuTask<RenderPassContext> for ClearIntegerCommand {
             }
         }
 
+        state.set_color_mask([true; 4]).apply(gl).unwrap();
+
         gl.clear_bufferiv_with_i32_array(Gl::COLOR, self.buffer_index, &self.clear_value);
 
         Progress::Finished(())
@@ -3697,6 +5667,8 @@ unsafe impl GpuTask<RenderPassContext> for ClearUnsignedIntegerCommand {
             }
         }
 
+        state.set_color_mask([true; 4]).apply(gl).unwrap();
+
         gl.clear_bufferuiv_with_u32_array(Gl::COLOR, self.buffer_index, &self.clear_value);
 
         Progress::Finished(())
@@ -3735,6 +5707,16 @@ unsafe impl GpuTask<RenderPassContext> for ClearDepthStencilCommand {
             }
         }
 
+        state.set_depth_mask(true).apply(gl).unwrap();
+        state
+            .set_stencil_write_mask_front(0xffffffff)
+            .apply(gl)
+            .unwrap();
+        state
+            .set_stencil_write_mask_back(0xffffffff)
+            .apply(gl)
+            .unwrap();
+
         gl.clear_bufferfi(Gl::DEPTH_STENCIL, 0, self.depth, self.stencil);
 
         Progress::Finished(())
@@ -3773,6 +5755,8 @@ unsafe impl GpuTask<RenderPassContext> for ClearDepthCommand {
             }
         }
 
+        state.set_depth_mask(true).apply(gl).unwrap();
+
         gl.clear_bufferfv_with_f32_array(Gl::DEPTH, 0, &[self.depth]);
 
         Progress::Finished(())
@@ -3811,8 +5795,183 @@ unsafe impl GpuTask<RenderPassContext> for ClearStencilCommand {
             }
         }
 
+        state
+            .set_stencil_write_mask_front(0xffffffff)
+            .apply(gl)
+            .unwrap();
+        state
+            .set_stencil_write_mask_back(0xffffffff)
+            .apply(gl)
+            .unwrap();
+
         gl.clear_bufferiv_with_i32_array(Gl::STENCIL, 0, &[self.stencil]);
 
         Progress::Finished(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_attachment_alias_none() {
+        let attachment_ids = [(JsId::from_abi(1), 0), (JsId::from_abi(2), 0)];
+        let sampled_textures = vec![
+            (0, 0, JsId::from_abi(3), 0..=255),
+            (1, 1, JsId::from_abi(4), 0..=255),
+        ];
+
+        assert_eq!(
+            find_attachment_alias(sampled_textures, &attachment_ids),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_find_attachment_alias_aliased() {
+        let attachment_ids = [(JsId::from_abi(1), 0), (JsId::from_abi(2), 0)];
+        let sampled_textures = vec![
+            (0, 0, JsId::from_abi(3), 0..=255),
+            (1, 5, JsId::from_abi(2), 0..=255),
+        ];
+
+        assert_eq!(
+            find_attachment_alias(sampled_textures, &attachment_ids),
+            Err(AttachmentAliasingError {
+                bind_group_index: 1,
+                texture_unit: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_find_attachment_alias_no_attachments() {
+        let sampled_textures = vec![(0, 0, JsId::from_abi(1), 0..=255)];
+
+        assert_eq!(find_attachment_alias(sampled_textures, &[]), Ok(()));
+    }
+
+    #[test]
+    fn test_find_attachment_alias_other_level() {
+        let attachment_ids = [(JsId::from_abi(1), 3)];
+        let sampled_textures = vec![(0, 0, JsId::from_abi(1), 4..=4)];
+
+        assert_eq!(
+            find_attachment_alias(sampled_textures, &attachment_ids),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_find_attachment_alias_same_level() {
+        let attachment_ids = [(JsId::from_abi(1), 3)];
+        let sampled_textures = vec![(0, 2, JsId::from_abi(1), 2..=3)];
+
+        assert_eq!(
+            find_attachment_alias(sampled_textures, &attachment_ids),
+            Err(AttachmentAliasingError {
+                bind_group_index: 0,
+                texture_unit: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_vertex_buffers_matching() {
+        assert_eq!(check_vertex_buffers(&[12, 32], &[12, 32]), Ok(()));
+    }
+
+    #[test]
+    fn test_check_vertex_buffers_interleaved_untyped() {
+        // E.g. a `[f32]` buffer and a `[u8]` buffer that hold interleaved vertex data.
+        assert_eq!(check_vertex_buffers(&[4, 1], &[20, 12]), Ok(()));
+    }
+
+    #[test]
+    fn test_check_vertex_buffers_stride_mismatch() {
+        assert_eq!(
+            check_vertex_buffers(&[12, 24], &[12, 32]),
+            Err(IncompatibleVertexBuffers::StrideMismatch {
+                bind_slot: 1,
+                expected_stride: 32,
+                element_size: 24
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_vertex_buffers_element_larger_than_stride() {
+        assert_eq!(
+            check_vertex_buffers(&[24], &[12]),
+            Err(IncompatibleVertexBuffers::StrideMismatch {
+                bind_slot: 0,
+                expected_stride: 12,
+                element_size: 24
+            })
+        );
+    }
+
+    #[test]
+    fn test_check_vertex_buffers_count_mismatch() {
+        assert_eq!(
+            check_vertex_buffers(&[12], &[12, 32]),
+            Err(IncompatibleVertexBuffers::BufferCountMismatch {
+                expected_count: 2,
+                actual_count: 1
+            })
+        );
+        assert_eq!(
+            check_vertex_buffers(&[12, 32, 16], &[12, 32]),
+            Err(IncompatibleVertexBuffers::BufferCountMismatch {
+                expected_count: 2,
+                actual_count: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_min_instance_count() {
+        assert_eq!(
+            min_instance_count(
+                vec![(120, 12), (64, 16), (96, 16)],
+                vec![
+                    InputRate::PerVertex,
+                    InputRate::PerInstance,
+                    InputRate::PerInstance
+                ]
+            ),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn test_min_instance_count_no_instance_buffer() {
+        assert_eq!(
+            min_instance_count(vec![(120, 12)], vec![InputRate::PerVertex]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_intersect_regions_fill() {
+        let area = Region2D::Area((10, 20), 30, 40);
+
+        assert_eq!(intersect_regions(Region2D::Fill, area), area);
+        assert_eq!(intersect_regions(area, Region2D::Fill), area);
+        assert_eq!(
+            intersect_regions(Region2D::Fill, Region2D::Fill),
+            Region2D::Fill
+        );
+    }
+
+    #[test]
+    fn test_intersect_regions_areas() {
+        let a = Region2D::Area((0, 0), 100, 100);
+        let b = Region2D::Area((50, 80), 100, 100);
+        let c = Region2D::Area((200, 200), 10, 10);
+
+        assert_eq!(intersect_regions(a, b), Region2D::Area((50, 80), 50, 20));
+        assert_eq!(intersect_regions(a, c), Region2D::Area((200, 200), 0, 0));
+    }
+}
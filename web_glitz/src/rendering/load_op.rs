@@ -1,8 +1,92 @@
 use web_sys::WebGl2RenderingContext as Gl;
 
+use crate::runtime::state::{ContextUpdate, DynamicState};
+
+/// Specifies how the contents of a render target attachment are initialized when a render pass
+/// begins.
+///
+/// # Example
+///
+/// An accumulation pass that adds to the existing contents of a color image may load those
+/// contents rather than clear them:
+///
+/// ```
+/// # use web_glitz::runtime::RenderingContext;
+/// # use web_glitz::image::texture_2d::Texture2D;
+/// # use web_glitz::image::format::RGBA8;
+/// # fn wrapper<Rc>(context: &Rc, mut texture: Texture2D<RGBA8>) where Rc: RenderingContext {
+/// use web_glitz::rendering::{RenderTargetDescriptor, LoadOp, StoreOp};
+///
+/// let render_target_descriptor = RenderTargetDescriptor::new()
+///     .attach_color_float(texture.base_level_mut(), LoadOp::Load, StoreOp::Store);
+///
+/// let render_target = context.create_render_target(render_target_descriptor);
+/// # }
+/// ```
+///
+/// The type of the clear value follows the attachment's component type: float color attachments
+/// (see [RenderTargetDescriptor::attach_color_float]) are cleared with an `[f32; 4]` value,
+/// integer color attachments (see [RenderTargetDescriptor::attach_color_integer]) with an
+/// `[i32; 4]` value and unsigned integer color attachments (see
+/// [RenderTargetDescriptor::attach_color_unsigned_integer]) with a `[u32; 4]` value. It is
+/// therefore not possible to accidentally clear an integer attachment with a float value (which
+/// WebGL would silently ignore). For example, an object ID buffer may be cleared to a sentinel ID:
+///
+/// ```
+/// # use web_glitz::runtime::RenderingContext;
+/// # use web_glitz::image::renderbuffer::Renderbuffer;
+/// # use web_glitz::image::format::R32UI;
+/// # fn wrapper<Rc>(context: &Rc, mut id_image: Renderbuffer<R32UI>) where Rc: RenderingContext {
+/// use web_glitz::rendering::{RenderTargetDescriptor, LoadOp, StoreOp};
+///
+/// let clear_id = LoadOp::Clear([u32::MAX, 0, 0, 0]);
+///
+/// let render_target_descriptor = RenderTargetDescriptor::new()
+///     .attach_color_unsigned_integer(&mut id_image, clear_id, StoreOp::Store);
+///
+/// let render_target = context.create_render_target(render_target_descriptor);
+/// # }
+/// ```
+///
+/// Depth-stencil attachments are cleared with a `(depth, stencil)` pair, depth attachments with a
+/// depth value and stencil attachments with a stencil value. A renderer that uses a reversed depth
+/// range (where `0.0` is the far plane) may for example clear its depth attachment to `0.0`
+/// (rather than the conventional `1.0`):
+///
+/// ```
+/// # use web_glitz::runtime::RenderingContext;
+/// # use web_glitz::image::renderbuffer::Renderbuffer;
+/// # use web_glitz::image::format::DepthComponent32F;
+/// # fn wrapper<Rc>(context: &Rc, mut depth_image: Renderbuffer<DepthComponent32F>) where Rc: RenderingContext {
+/// use web_glitz::rendering::{RenderTargetDescriptor, LoadOp, StoreOp};
+///
+/// let render_target_descriptor = RenderTargetDescriptor::new()
+///     .attach_depth(&mut depth_image, LoadOp::Clear(0.0), StoreOp::Store);
+///
+/// let render_target = context.create_render_target(render_target_descriptor);
+/// # }
+/// ```
+///
+/// Note that the default render target (see [DefaultRenderTarget]) does not declare load
+/// operations: its contents are always preserved between render passes within the same frame.
+/// However, unless the context was created with [ContextOptions::preserve_drawing_buffer] set to
+/// `true`, the browser may clear the default render target's buffers after they have been
+/// presented, so their contents should not be relied upon across frames.
+///
+/// [RenderTargetDescriptor::attach_color_float]: crate::rendering::RenderTargetDescriptor::attach_color_float
+/// [RenderTargetDescriptor::attach_color_integer]: crate::rendering::RenderTargetDescriptor::attach_color_integer
+/// [RenderTargetDescriptor::attach_color_unsigned_integer]: crate::rendering::RenderTargetDescriptor::attach_color_unsigned_integer
 #[derive(Clone, Copy, PartialEq)]
 pub enum LoadOp<T> {
+    /// Preserves the existing contents of the attachment.
+    ///
+    /// Rendering commands in the render pass will build upon whatever the attachment contained
+    /// before the render pass began. On some (tile-based) hardware, loading the existing contents
+    /// has a performance cost; prefer [LoadOp::Clear] when the existing contents are not needed.
     Load,
+
+    /// Clears the attachment to the given value before any rendering commands in the render pass
+    /// are executed.
     Clear(T),
 }
 
@@ -34,12 +118,46 @@ impl LoadOp<[u32; 4]> {
 }
 
 impl LoadOp<(f32, i32)> {
+    pub(crate) fn assert_valid_stencil(&self) {
+        if let LoadOp::Clear((_, stencil)) = self {
+            assert_valid_stencil_clear_value(*stencil);
+        }
+    }
+
     pub(crate) fn as_load_depth_stencil_action(&self) -> LoadAction {
         match self {
             LoadOp::Load => LoadAction::Load,
             LoadOp::Clear((depth, stencil)) => LoadAction::ClearDepthStencil(*depth, *stencil),
         }
     }
+
+    /// Splits this load operation into a load operation for the depth aspect and a load operation
+    /// for the stencil aspect.
+    pub(crate) fn split(&self) -> (LoadOp<f32>, LoadOp<i32>) {
+        match self {
+            LoadOp::Load => (LoadOp::Load, LoadOp::Load),
+            LoadOp::Clear((depth, stencil)) => (LoadOp::Clear(*depth), LoadOp::Clear(*stencil)),
+        }
+    }
+}
+
+/// Returns the [LoadAction] for a combined depth-stencil attachment for which the depth aspect and
+/// the stencil aspect have independent load operations.
+///
+/// Clearing only one aspect of a combined depth-stencil attachment leaves the other aspect intact,
+/// so an aspect that is loaded is simply not cleared.
+pub(crate) fn depth_stencil_aspects_action(
+    depth_load_op: &LoadOp<f32>,
+    stencil_load_op: &LoadOp<i32>,
+) -> LoadAction {
+    match (depth_load_op, stencil_load_op) {
+        (LoadOp::Load, LoadOp::Load) => LoadAction::Load,
+        (LoadOp::Clear(depth), LoadOp::Load) => LoadAction::ClearDepth(*depth),
+        (LoadOp::Load, LoadOp::Clear(stencil)) => LoadAction::ClearStencil(*stencil),
+        (LoadOp::Clear(depth), LoadOp::Clear(stencil)) => {
+            LoadAction::ClearDepthStencil(*depth, *stencil)
+        }
+    }
 }
 
 impl LoadOp<f32> {
@@ -52,6 +170,12 @@ impl LoadOp<f32> {
 }
 
 impl LoadOp<i32> {
+    pub(crate) fn assert_valid_stencil(&self) {
+        if let LoadOp::Clear(stencil) = self {
+            assert_valid_stencil_clear_value(*stencil);
+        }
+    }
+
     pub(crate) fn as_load_stencil_action(&self) -> LoadAction {
         match self {
             LoadOp::Load => LoadAction::Load,
@@ -60,6 +184,18 @@ impl LoadOp<i32> {
     }
 }
 
+/// The number of stencil bits stored by all stencil renderable formats.
+const STENCIL_BITS: u32 = 8;
+
+fn assert_valid_stencil_clear_value(stencil: i32) {
+    if stencil < 0 || stencil >= 1 << STENCIL_BITS {
+        panic!(
+            "Stencil clear value {} does not fit in the attachment's {} stencil bits.",
+            stencil, STENCIL_BITS
+        );
+    }
+}
+
 #[derive(Clone, Copy)]
 pub(crate) enum LoadAction {
     Load,
@@ -96,3 +232,181 @@ impl LoadAction {
         }
     }
 }
+
+/// A set of load actions that can be performed with a single `gl.clear` call, rather than with a
+/// separate `gl.clearBuffer*` call for each buffer.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) struct CombinedClear {
+    color: Option<[f32; 4]>,
+    depth: Option<f32>,
+    stencil: Option<i32>,
+}
+
+impl CombinedClear {
+    /// Returns a [CombinedClear] for the `color_actions` and the `depth_stencil_action` if they can
+    /// be combined into a single `gl.clear` call, or `None` otherwise.
+    ///
+    /// `gl.clear` clears all color draw buffers to the same float value, so the actions can only
+    /// be combined if either all color attachments are loaded, or all color attachments are float
+    /// attachments that are cleared to the same value. Returns `None` if none of the actions clear
+    /// a buffer.
+    pub(crate) fn try_combine(
+        color_actions: &[LoadAction],
+        depth_stencil_action: Option<&LoadAction>,
+    ) -> Option<Self> {
+        let mut color = None;
+
+        for (i, action) in color_actions.iter().enumerate() {
+            match action {
+                LoadAction::Load if color.is_none() => (),
+                LoadAction::ClearColorFloat(_, value) if i == 0 => color = Some(*value),
+                LoadAction::ClearColorFloat(_, value) if color == Some(*value) => (),
+                _ => return None,
+            }
+        }
+
+        let (depth, stencil) = match depth_stencil_action {
+            Some(LoadAction::ClearDepthStencil(depth, stencil)) => (Some(*depth), Some(*stencil)),
+            Some(LoadAction::ClearDepth(depth)) => (Some(*depth), None),
+            Some(LoadAction::ClearStencil(stencil)) => (None, Some(*stencil)),
+            _ => (None, None),
+        };
+
+        if color.is_none() && depth.is_none() && stencil.is_none() {
+            return None;
+        }
+
+        Some(CombinedClear {
+            color,
+            depth,
+            stencil,
+        })
+    }
+
+    /// The buffer mask for the `gl.clear` call.
+    pub(crate) fn mask(&self) -> u32 {
+        let mut mask = 0;
+
+        if self.color.is_some() {
+            mask |= Gl::COLOR_BUFFER_BIT;
+        }
+
+        if self.depth.is_some() {
+            mask |= Gl::DEPTH_BUFFER_BIT;
+        }
+
+        if self.stencil.is_some() {
+            mask |= Gl::STENCIL_BUFFER_BIT;
+        }
+
+        mask
+    }
+
+    pub(crate) fn perform(&self, gl: &Gl, state: &mut DynamicState) {
+        if let Some(color) = self.color {
+            state.set_clear_color(color).apply(gl).unwrap();
+        }
+
+        if let Some(depth) = self.depth {
+            state.set_clear_depth(depth).apply(gl).unwrap();
+        }
+
+        if let Some(stencil) = self.stencil {
+            state.set_clear_stencil(stencil).apply(gl).unwrap();
+        }
+
+        gl.clear(self.mask());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assert_valid_stencil() {
+        LoadOp::Clear((1.0, 0)).assert_valid_stencil();
+        LoadOp::Clear(255).assert_valid_stencil();
+        LoadOp::<i32>::Load.assert_valid_stencil();
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_valid_stencil_too_large() {
+        LoadOp::Clear((0.0, 256)).assert_valid_stencil();
+    }
+
+    #[test]
+    fn test_depth_stencil_aspects_action() {
+        let split = LoadOp::Clear((1.0, 0)).split();
+
+        assert!(split == (LoadOp::Clear(1.0), LoadOp::Clear(0)));
+
+        let clear_depth_keep_stencil =
+            depth_stencil_aspects_action(&LoadOp::Clear(1.0), &LoadOp::Load);
+        let combined = CombinedClear::try_combine(&[], Some(&clear_depth_keep_stencil)).unwrap();
+
+        assert_eq!(combined.mask(), Gl::DEPTH_BUFFER_BIT);
+
+        let keep_depth_clear_stencil =
+            depth_stencil_aspects_action(&LoadOp::Load, &LoadOp::Clear(0));
+        let combined = CombinedClear::try_combine(&[], Some(&keep_depth_clear_stencil)).unwrap();
+
+        assert_eq!(combined.mask(), Gl::STENCIL_BUFFER_BIT);
+
+        let load_both = depth_stencil_aspects_action(&LoadOp::Load, &LoadOp::Load);
+
+        assert_eq!(CombinedClear::try_combine(&[], Some(&load_both)), None);
+    }
+
+    #[test]
+    fn test_combined_clear_common_case() {
+        let color_actions = [
+            LoadAction::ClearColorFloat(0, [0.0, 0.0, 0.0, 1.0]),
+            LoadAction::ClearColorFloat(1, [0.0, 0.0, 0.0, 1.0]),
+        ];
+        let depth_stencil_action = LoadAction::ClearDepthStencil(1.0, 0);
+
+        let combined =
+            CombinedClear::try_combine(&color_actions, Some(&depth_stencil_action)).unwrap();
+
+        assert_eq!(
+            combined.mask(),
+            Gl::COLOR_BUFFER_BIT | Gl::DEPTH_BUFFER_BIT | Gl::STENCIL_BUFFER_BIT
+        );
+    }
+
+    #[test]
+    fn test_combined_clear_loaded_depth() {
+        let color_actions = [LoadAction::ClearColorFloat(0, [0.0; 4])];
+
+        let combined = CombinedClear::try_combine(&color_actions, Some(&LoadAction::Load)).unwrap();
+
+        assert_eq!(combined.mask(), Gl::COLOR_BUFFER_BIT);
+    }
+
+    #[test]
+    fn test_combined_clear_fallback() {
+        let distinct_values = [
+            LoadAction::ClearColorFloat(0, [0.0; 4]),
+            LoadAction::ClearColorFloat(1, [1.0; 4]),
+        ];
+
+        assert_eq!(CombinedClear::try_combine(&distinct_values, None), None);
+
+        let integer = [LoadAction::ClearColorInteger(0, [0; 4])];
+
+        assert_eq!(CombinedClear::try_combine(&integer, None), None);
+
+        let partially_loaded = [LoadAction::ClearColorFloat(0, [0.0; 4]), LoadAction::Load];
+
+        assert_eq!(CombinedClear::try_combine(&partially_loaded, None), None);
+
+        let loaded = [LoadAction::Load];
+
+        assert_eq!(
+            CombinedClear::try_combine(&loaded, Some(&LoadAction::Load)),
+            None
+        );
+    }
+}
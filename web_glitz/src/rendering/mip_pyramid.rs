@@ -0,0 +1,260 @@
+use crate::image::format::TextureFormat;
+use crate::image::texture_2d::{Texture2D, Texture2DDescriptor};
+use crate::image::util::mipmap_size;
+use crate::image::{complete_mipmap_levels, MipmapLevels};
+use crate::runtime::RenderingContext;
+
+/// A chain of progressively smaller 2-dimensional textures, as used by multi-pass
+/// downsample/upsample effects such as bloom or depth-of-field.
+///
+/// Level `0` has the base width and height; each subsequent level is half the width and half the
+/// height of the previous level, rounded down, but never smaller than `1` (the same sizes as the
+/// levels of a mipmap chain, see the [crate::image] module documentation). Unlike the levels
+/// of a single mipmapped texture, each level is a separate [Texture2D], so that one level may be
+/// attached to a render target while an adjacent level is sampled.
+///
+/// # Example
+///
+/// ```
+/// # use web_glitz::runtime::RenderingContext;
+/// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
+/// use web_glitz::image::Region2D;
+/// use web_glitz::image::format::RGBA8;
+/// use web_glitz::rendering::{LoadOp, MipPyramid, RenderTargetDescriptor, StoreOp};
+/// use web_glitz::task::sequence_iter;
+///
+/// let mut pyramid = MipPyramid::new(context, 1024, 768, RGBA8, 5);
+///
+/// // Render the scene into `pyramid.level_mut(0)`...
+///
+/// let downsample_passes = pyramid.downsample_passes(|source, target| {
+///     let mut render_target = context.create_render_target(
+///         RenderTargetDescriptor::new().attach_color_float(
+///             target.base_level_mut(),
+///             LoadOp::Load,
+///             StoreOp::Store,
+///         ),
+///     );
+///
+///     render_target.create_render_pass(|framebuffer| {
+///         framebuffer.blit_color_linear_command(Region2D::Fill, &source.base_level())
+///     })
+/// });
+///
+/// context.submit(sequence_iter(downsample_passes));
+/// # }
+/// ```
+///
+/// Here the passes simply blit each level into the next level; an actual bloom effect would
+/// typically run a filtering pipeline that samples the `source` level instead.
+pub struct MipPyramid<F>
+where
+    F: TextureFormat + 'static,
+{
+    levels: Vec<Texture2D<F>>,
+}
+
+impl<F> MipPyramid<F>
+where
+    F: TextureFormat + 'static,
+{
+    /// Allocates a new pyramid of `levels` textures in the given `format`, where the first level
+    /// is `base_width` by `base_height` pixels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base_width` or `base_height` is `0`.
+    ///
+    /// Panics if `levels` is `0` or is greater than the number of levels in the complete mipmap
+    /// chain for an image of `base_width` by `base_height` pixels.
+    pub fn new<Rc>(
+        context: &Rc,
+        base_width: u32,
+        base_height: u32,
+        format: F,
+        levels: usize,
+    ) -> Self
+    where
+        Rc: RenderingContext,
+    {
+        let levels = level_sizes(base_width, base_height, levels)
+            .into_iter()
+            .map(|(width, height)| {
+                context
+                    .try_create_texture_2d(&Texture2DDescriptor {
+                        format,
+                        width,
+                        height,
+                        levels: MipmapLevels::Partial(1),
+                    })
+                    .unwrap()
+            })
+            .collect();
+
+        MipPyramid { levels }
+    }
+
+    /// The number of levels in the pyramid.
+    pub fn len(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Returns a reference to the texture for the `level`, e.g. for use as a sampled resource.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `level` is out of bounds.
+    pub fn level(&self, level: usize) -> &Texture2D<F> {
+        &self.levels[level]
+    }
+
+    /// Returns a mutable reference to the texture for the `level`, e.g. for use as a render target
+    /// attachment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `level` is out of bounds.
+    pub fn level_mut(&mut self, level: usize) -> &mut Texture2D<F> {
+        &mut self.levels[level]
+    }
+
+    /// Returns a reference to the texture for the `source` level together with a mutable reference
+    /// to the texture for the `target` level.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source` and `target` are the same level, or if either level is out of bounds.
+    pub fn source_and_target(
+        &mut self,
+        source: usize,
+        target: usize,
+    ) -> (&Texture2D<F>, &mut Texture2D<F>) {
+        if source == target {
+            panic!("The source level and the target level must be different levels.");
+        }
+
+        if source < target {
+            let (head, tail) = self.levels.split_at_mut(target);
+
+            (&head[source], &mut tail[0])
+        } else {
+            let (head, tail) = self.levels.split_at_mut(source);
+
+            (&tail[0], &mut head[target])
+        }
+    }
+
+    /// Calls `f` with each pair of adjacent levels in downsampling order (level `0` as the source
+    /// for level `1`, then level `1` as the source for level `2`, etc.) and collects the results,
+    /// typically the render passes for the downsample chain.
+    ///
+    /// The passes should be executed in order, see [sequence_iter].
+    ///
+    /// [sequence_iter]: crate::task::sequence_iter
+    pub fn downsample_passes<T, B>(&mut self, mut f: B) -> Vec<T>
+    where
+        B: FnMut(&Texture2D<F>, &mut Texture2D<F>) -> T,
+    {
+        (1..self.levels.len())
+            .map(|target| {
+                let (source, target) = self.source_and_target(target - 1, target);
+
+                f(source, target)
+            })
+            .collect()
+    }
+
+    /// Calls `f` with each pair of adjacent levels in upsampling order (the smallest level as the
+    /// source for the level above it, etc., finishing with level `1` as the source for level `0`)
+    /// and collects the results, typically the render passes for the upsample chain.
+    ///
+    /// The passes should be executed in order, see [sequence_iter].
+    ///
+    /// [sequence_iter]: crate::task::sequence_iter
+    pub fn upsample_passes<T, B>(&mut self, mut f: B) -> Vec<T>
+    where
+        B: FnMut(&Texture2D<F>, &mut Texture2D<F>) -> T,
+    {
+        (0..self.levels.len().saturating_sub(1))
+            .rev()
+            .map(|target| {
+                let (source, target) = self.source_and_target(target + 1, target);
+
+                f(source, target)
+            })
+            .collect()
+    }
+}
+
+// Returns the width and height of each of the `levels` of a pyramid with a base of `base_width` by
+// `base_height` pixels. Panics under the same conditions as `MipPyramid::new`.
+fn level_sizes(base_width: u32, base_height: u32, levels: usize) -> Vec<(u32, u32)> {
+    if base_width == 0 || base_height == 0 {
+        panic!("The base width and the base height of a pyramid must be at least 1.");
+    }
+
+    let max_levels = complete_mipmap_levels(base_width, base_height);
+
+    if levels == 0 || levels > max_levels {
+        panic!(
+            "A pyramid with a base of {} by {} must have between 1 and {} levels, {} given.",
+            base_width, base_height, max_levels, levels
+        );
+    }
+
+    (0..levels)
+        .map(|level| {
+            (
+                mipmap_size(base_width, level),
+                mipmap_size(base_height, level),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_sizes() {
+        assert_eq!(
+            level_sizes(1024, 768, 5),
+            vec![(1024, 768), (512, 384), (256, 192), (128, 96), (64, 48)]
+        );
+    }
+
+    #[test]
+    fn test_level_sizes_rounds_down() {
+        assert_eq!(level_sizes(5, 3, 3), vec![(5, 3), (2, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn test_level_sizes_complete_chain() {
+        // The height reaches `1` first; the width keeps halving until it also reaches `1`.
+        assert_eq!(level_sizes(8, 2, 4), vec![(8, 2), (4, 1), (2, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn test_level_sizes_single_level() {
+        assert_eq!(level_sizes(1, 1, 1), vec![(1, 1)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_level_sizes_zero_base_width() {
+        level_sizes(0, 768, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_level_sizes_zero_levels() {
+        level_sizes(1024, 768, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_level_sizes_too_many_levels() {
+        level_sizes(8, 2, 5);
+    }
+}
@@ -1,6 +1,7 @@
 pub(crate) mod attachment;
 pub use self::attachment::{
-    AsAttachment, AsMultisampleAttachment, Attachment, MultisampleAttachment,
+    AsAttachment, AsMultisampleAttachment, Attachment, AttachmentKind, AttachmentRef,
+    MultisampleAttachment,
 };
 
 pub(crate) mod default_multisample_render_target;
@@ -11,14 +12,16 @@ pub use self::default_render_target::DefaultRenderTarget;
 
 pub(crate) mod framebuffer;
 pub use self::framebuffer::{
-    ActiveGraphicsPipeline, BindIndexBufferCommand, BindResourcesCommand, BindVertexBuffersCommand,
-    BlitColorCompatible, BlitColorTarget, BlitCommand, BlitSource, BlitSourceDescriptor,
-    BlitTargetDescriptor, DefaultDepthBuffer, DefaultDepthStencilBuffer, DefaultRGBABuffer,
-    DefaultRGBBuffer, DefaultStencilBuffer, DepthBuffer, DepthStencilBuffer, DrawCommand,
-    DrawIndexedCommand, FloatBuffer, Framebuffer, GraphicsPipelineTarget,
-    GraphicsPipelineTaskBuilder, IntegerBuffer, MultisampleFramebuffer, RenderingOutputBuffer,
-    ResolveColorCompatible, ResolveSource, ResolveSourceDescriptor, StencilBuffer,
-    UnsignedIntegerBuffer,
+    ActiveGraphicsPipeline, AttachmentAliasingError, BindIndexBufferCommand, BindResourcesCommand,
+    BindVertexArrayCommand, BindVertexBuffersCommand, BlitColorCompatible, BlitColorTarget,
+    BlitCommand, BlitSource, BlitSourceDescriptor, BlitTargetDescriptor, DefaultDepthBuffer,
+    DefaultDepthStencilBuffer, DefaultRGBABuffer, DefaultRGBBuffer, DefaultStencilBuffer,
+    DepthBuffer, DepthStencilBuffer, DrawCommand, DrawEachCommand, DrawIndexedCommand,
+    DrawIndexedEachCommand, DrawRange, FloatBuffer, Framebuffer, GraphicsPipelineTarget,
+    GraphicsPipelineTaskBuilder, IncompatibleVertexBuffers, IntegerBuffer, MissingInstanceBuffer,
+    MultisampleFramebuffer, RenderingOutputBuffer, ResolveColorCompatible, ResolveSource,
+    ResolveSourceDescriptor, SetBlendingCommand, SetCullingModeCommand, SetStencilReferenceCommand,
+    SetStencilWriteMaskCommand, StencilBuffer, UnsignedIntegerBuffer,
 };
 
 mod render_pass;
@@ -48,5 +51,8 @@ pub use self::render_target::{
 pub(crate) mod load_op;
 pub use self::load_op::LoadOp;
 
+mod mip_pyramid;
+pub use self::mip_pyramid::MipPyramid;
+
 mod store_op;
 pub use self::store_op::StoreOp;
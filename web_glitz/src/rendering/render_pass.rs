@@ -1,11 +1,13 @@
 use js_sys::Uint32Array;
 use web_sys::WebGl2RenderingContext as Gl;
 
+use crate::image::Region2D;
+use crate::rendering::load_op::CombinedClear;
 use crate::rendering::render_target::RenderTargetData;
 use crate::rendering::StoreOp;
 use crate::runtime::state::{ContextUpdate, DepthStencilAttachmentDescriptor, DynamicState};
 use crate::runtime::Connection;
-use crate::task::{ContextId, GpuTask, Progress};
+use crate::task::{ContextId, GpuTask, Progress, ValidationContext};
 
 /// Encapsulates a render pass.
 ///
@@ -19,6 +21,59 @@ use crate::task::{ContextId, GpuTask, Progress};
 ///
 /// For details on how a [RenderPass] is created, see [RenderTarget::create_render_pass] and
 /// [MultisampleRenderTarget::create_render_pass].
+///
+/// # Sub-passes
+///
+/// The framebuffer stays bound for the entire render pass task, and the load and store operations
+/// are only performed at the start and the end of the render pass. A render pass task that
+/// sequences several pipeline tasks therefore acts as a series of sub-passes that share the
+/// framebuffer, without reloading or restoring the attached images in between. For example, a
+/// depth pre-pass followed by a shading pass:
+///
+/// ```
+/// # use web_glitz::rendering::{DefaultRGBBuffer, DefaultDepthBuffer, DefaultRenderTarget};
+/// # use web_glitz::buffer::Buffer;
+/// # use web_glitz::pipeline::graphics::{GraphicsPipeline, Vertex};
+/// # use web_glitz::pipeline::resources::BindGroup;
+/// # fn wrapper<V>(
+/// #     mut render_target: DefaultRenderTarget<DefaultRGBBuffer, DefaultDepthBuffer>,
+/// #     vertex_buffer: Buffer<[V]>,
+/// #     depth_pipeline: GraphicsPipeline<V, (), ()>,
+/// #     shading_pipeline: GraphicsPipeline<V, (), ()>
+/// # )
+/// # where
+/// #     V: Vertex,
+/// # {
+/// # let resources = BindGroup::empty();
+/// use web_glitz::task::sequence;
+///
+/// let render_pass = render_target.create_render_pass(|framebuffer| {
+///     let depth_sub_pass = framebuffer.pipeline_task(&depth_pipeline, |active_pipeline| {
+///         active_pipeline.task_builder()
+///             .bind_vertex_buffers(&vertex_buffer)
+///             .bind_resources(&resources)
+///             .draw(16, 1)
+///             .finish()
+///     });
+///
+///     let shading_sub_pass = framebuffer.pipeline_task(&shading_pipeline, |active_pipeline| {
+///         active_pipeline.task_builder()
+///             .bind_vertex_buffers(&vertex_buffer)
+///             .bind_resources(&resources)
+///             .draw(16, 1)
+///             .finish()
+///     });
+///
+///     sequence(depth_sub_pass, shading_sub_pass)
+/// });
+/// # }
+/// ```
+///
+/// Note that WebGL 2.0 does not support input attachments: a pipeline task cannot sample an image
+/// that is attached to the framebuffer it draws to. A sub-pass that needs to sample the output of
+/// a previous sub-pass (e.g. the lighting pass of a deferred renderer, which samples the geometry
+/// buffers) must instead be a separate [RenderPass] with a render target that does not include
+/// the sampled images.
 #[derive(Clone)]
 pub struct RenderPass<T> {
     pub(crate) id: u64,
@@ -76,6 +131,10 @@ where
         ContextId::Id(self.context_id)
     }
 
+    fn validate(&self, context: &mut ValidationContext) {
+        self.task.validate(context)
+    }
+
     fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
         let (gl, state) = unsafe { connection.unpack_mut() };
 
@@ -94,12 +153,61 @@ where
                     .bind_or_create(data, gl)
                     .set_draw_buffers(data.draw_buffers());
 
-                for i in 0..data.color_count {
-                    data.load_ops[i].perform(gl);
+                // Clearing a color buffer respects the color mask; a depth-only pipeline task in an
+                // earlier render pass may have left it disabled.
+                state.set_color_mask([true; 4]).apply(gl).unwrap();
+
+                // Likewise, clearing the depth or stencil buffer respects the depth mask and the
+                // stencil write masks, which a pipeline task may have left (partially) disabled (see
+                // DepthTest::write and StencilTest::write_mask_front/write_mask_back).
+                state.set_depth_mask(true).apply(gl).unwrap();
+                state
+                    .set_stencil_write_mask_front(0xffffffff)
+                    .apply(gl)
+                    .unwrap();
+                state
+                    .set_stencil_write_mask_back(0xffffffff)
+                    .apply(gl)
+                    .unwrap();
+
+                // Clearing also respects the scissor test, which we use to restrict the clear to the
+                // render area.
+                match data.render_area {
+                    Region2D::Area((x, y), width, height) => {
+                        state.set_scissor_test_enabled(true).apply(gl).unwrap();
+                        state
+                            .set_scissor_rect((x as i32, y as i32, width, height))
+                            .apply(gl)
+                            .unwrap();
+                    }
+                    Region2D::Fill => {
+                        state.set_scissor_test_enabled(false).apply(gl).unwrap();
+                    }
                 }
 
-                if &data.depth_stencil_attachment != &DepthStencilAttachmentDescriptor::None {
-                    data.load_ops[16].perform(gl);
+                let depth_stencil_load_op =
+                    if &data.depth_stencil_attachment != &DepthStencilAttachmentDescriptor::None {
+                        Some(&data.load_ops[16])
+                    } else {
+                        None
+                    };
+
+                // Clearing all buffers with a single `gl.clear` call is typically faster than
+                // clearing each buffer individually, but is only possible if all color buffers are
+                // cleared to the same float value (or are all loaded).
+                if let Some(combined_clear) = CombinedClear::try_combine(
+                    &data.load_ops[0..data.color_count],
+                    depth_stencil_load_op,
+                ) {
+                    combined_clear.perform(gl, state);
+                } else {
+                    for i in 0..data.color_count {
+                        data.load_ops[i].perform(gl);
+                    }
+
+                    if let Some(load_op) = depth_stencil_load_op {
+                        load_op.perform(gl);
+                    }
                 }
 
                 let output = self.task.progress(&mut RenderPassContext {
@@ -118,19 +226,36 @@ where
                     }
                 }
 
+                let depth_stencil_store_op = data.store_ops[16];
+
+                // For a combined depth-stencil attachment, the depth aspect and the stencil aspect
+                // may be invalidated independently.
                 if let Some(buffer_id) = match &data.depth_stencil_attachment {
                     DepthStencilAttachmentDescriptor::DepthStencil(_) => {
-                        Some(Gl::DEPTH_STENCIL_ATTACHMENT)
+                        match (depth_stencil_store_op, data.stencil_store_op) {
+                            (StoreOp::DontCare, StoreOp::DontCare) => {
+                                Some(Gl::DEPTH_STENCIL_ATTACHMENT)
+                            }
+                            (StoreOp::DontCare, StoreOp::Store) => Some(Gl::DEPTH_ATTACHMENT),
+                            (StoreOp::Store, StoreOp::DontCare) => Some(Gl::STENCIL_ATTACHMENT),
+                            (StoreOp::Store, StoreOp::Store) => None,
+                        }
+                    }
+                    DepthStencilAttachmentDescriptor::Depth(_)
+                        if depth_stencil_store_op == StoreOp::DontCare =>
+                    {
+                        Some(Gl::DEPTH_ATTACHMENT)
                     }
-                    DepthStencilAttachmentDescriptor::Depth(_) => Some(Gl::DEPTH_ATTACHMENT),
-                    DepthStencilAttachmentDescriptor::Stencil(_) => Some(Gl::STENCIL_ATTACHMENT),
-                    DepthStencilAttachmentDescriptor::None => None,
+                    DepthStencilAttachmentDescriptor::Stencil(_)
+                        if depth_stencil_store_op == StoreOp::DontCare =>
+                    {
+                        Some(Gl::STENCIL_ATTACHMENT)
+                    }
+                    _ => None,
                 } {
-                    if data.store_ops[16] == StoreOp::DontCare {
-                        invalidate_buffers[invalidate_counter] = buffer_id;
+                    invalidate_buffers[invalidate_counter] = buffer_id;
 
-                        invalidate_counter += 1;
-                    }
+                    invalidate_counter += 1;
                 }
 
                 if invalidate_counter > 0 {
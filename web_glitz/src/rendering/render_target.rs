@@ -3,32 +3,51 @@ use std::cmp;
 use std::hash::{Hash, Hasher};
 
 use crate::image::format::{
-    DepthRenderable, DepthStencilRenderable, FloatRenderable, IntegerRenderable, Multisamplable,
-    Multisample, RenderbufferFormat, StencilRenderable, TextureFormat, UnsignedIntegerRenderable,
+    DepthRenderable, DepthStencilRenderable, FloatRenderable, IntegerRenderable, InternalFormat,
+    Multisamplable, Multisample, RenderbufferFormat, StencilRenderable, TextureFormat,
+    UnsignedIntegerRenderable,
 };
 use crate::image::renderbuffer::Renderbuffer;
 use crate::image::texture_2d::LevelMut as Texture2DLevelMut;
 use crate::image::texture_2d_array::LevelLayerMut as Texture2DArrayLevelLayerMut;
 use crate::image::texture_3d::LevelLayerMut as Texture3DLevelLayerMut;
 use crate::image::texture_cube::LevelFaceMut as TextureCubeLevelFaceMut;
+use crate::image::Region2D;
 use crate::rendering::attachment::AttachmentData;
 use crate::rendering::load_op::LoadAction;
 use crate::rendering::{
-    AsAttachment, AsMultisampleAttachment, ColorBufferEncoding, ColorBufferEncodingContext,
-    DepthAttachment, DepthStencilAttachment, DepthStencilBufferEncoding,
-    DepthStencilBufferEncodingContext, EncodeColorBuffer, EncodeDepthStencilBuffer,
-    EncodeMultisampleColorBuffer, EncodeMultisampleDepthStencilBuffer, FloatAttachment,
-    Framebuffer, GraphicsPipelineTarget, IntegerAttachment, LoadOp, MultisampleFramebuffer,
-    RenderPass, RenderPassContext, StencilAttachment, StoreOp, UnsignedIntegerAttachment,
+    AsAttachment, AsMultisampleAttachment, AttachmentKind, AttachmentRef, ColorBufferEncoding,
+    ColorBufferEncodingContext, DepthAttachment, DepthStencilAttachment,
+    DepthStencilBufferEncoding, DepthStencilBufferEncodingContext, EncodeColorBuffer,
+    EncodeDepthStencilBuffer, EncodeMultisampleColorBuffer, EncodeMultisampleDepthStencilBuffer,
+    FloatAttachment, Framebuffer, GraphicsPipelineTarget, IntegerAttachment, LoadOp,
+    MultisampleFramebuffer, RenderPass, RenderPassContext, StencilAttachment, StoreOp,
+    UnsignedIntegerAttachment,
 };
 use crate::runtime::single_threaded::ObjectIdGen;
 use crate::runtime::state::{AttachmentSet, DepthStencilAttachmentDescriptor, DrawBuffer};
 use crate::task::{ContextId, GpuTask};
+use crate::util::JsId;
+
+use staticvec::StaticVec;
 
 /// Marker trait for image reference types that may be attached to a [RenderTargetDescriptor] as a
 /// floating point color attachment.
 ///
+/// Implemented for images with a format that implements [FloatRenderable]. Images with a floating
+/// point format (e.g. [RGBA32F]) only implement this trait when wrapped in a
+/// [color_buffer_float::Extended] type proof, see [color_buffer_float]. Images with a
+/// half-precision floating point format (e.g. [RGBA16F]) also implement this trait when wrapped in
+/// a [color_buffer_half_float::Extended] type proof, see [color_buffer_half_float].
+///
 /// See [RenderTargetDescriptor::attach_color_float] for details.
+///
+/// [RGBA16F]: crate::image::format::RGBA16F
+/// [RGBA32F]: crate::image::format::RGBA32F
+/// [color_buffer_float::Extended]: crate::extensions::color_buffer_float::Extended
+/// [color_buffer_float]: crate::extensions::color_buffer_float
+/// [color_buffer_half_float::Extended]: crate::extensions::color_buffer_half_float::Extended
+/// [color_buffer_half_float]: crate::extensions::color_buffer_half_float
 pub unsafe trait AttachColorFloat: AsAttachment {}
 
 unsafe impl<'a, T> AttachColorFloat for &'a mut T where T: AttachColorFloat {}
@@ -442,6 +461,8 @@ pub struct RenderTargetDescriptor<C, Ds> {
     pub(crate) depth_stencil_attachment: Ds,
     pub(crate) color_attachment_count: u8,
     pub(crate) context_id: RenderTargetContextId,
+    pub(crate) render_area: Region2D,
+    pub(crate) attachments: StaticVec<AttachmentRef, 17>,
 }
 
 impl RenderTargetDescriptor<(), ()> {
@@ -452,6 +473,60 @@ impl RenderTargetDescriptor<(), ()> {
             depth_stencil_attachment: (),
             color_attachment_count: 0,
             context_id: RenderTargetContextId::new(),
+            render_area: Region2D::Fill,
+            attachments: StaticVec::new(),
+        }
+    }
+}
+
+impl<C, Ds> RenderTargetDescriptor<C, Ds> {
+    /// Restricts render passes on the render target to the given `render_area`.
+    ///
+    /// Only the framebuffer region covered by the `render_area` will be modified by a render pass:
+    /// any [LoadOp::Clear] operations only clear the `render_area`, and the output of any
+    /// pipeline tasks in the render pass is restricted to the `render_area` (the scissor region of
+    /// a graphics pipeline is intersected with the `render_area`). Contents outside of the
+    /// `render_area` are left unchanged. This is useful for incremental rendering, where only a
+    /// "dirty" region of a large image needs to be redrawn.
+    ///
+    /// Note that the render area does not affect the viewport of the pipeline tasks in the render
+    /// pass: a pipeline with a [Viewport::Auto] viewport still maps to the full framebuffer, so that
+    /// geometry is rendered in the same position as it would be without a render area.
+    ///
+    /// Defaults to [Region2D::Fill], which does not restrict the render pass.
+    ///
+    /// # Panics
+    ///
+    /// When a render pass is created for the render target, panics if the `render_area` does not
+    /// fit within the dimensions of the framebuffer (the smallest width and height of all attached
+    /// images).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
+    /// use web_glitz::image::format::RGBA8;
+    /// use web_glitz::image::renderbuffer::RenderbufferDescriptor;
+    /// use web_glitz::image::Region2D;
+    /// use web_glitz::rendering::{RenderTargetDescriptor, LoadOp, StoreOp};
+    ///
+    /// let mut color_image = context.create_renderbuffer(&RenderbufferDescriptor{
+    ///     format: RGBA8,
+    ///     width: 2000,
+    ///     height: 2000
+    /// });
+    ///
+    /// // Only redraw a 200 by 100 pixel region at offset (300, 400).
+    /// let render_target_descriptor = RenderTargetDescriptor::new()
+    ///     .attach_color_float(&mut color_image, LoadOp::Clear([1.0; 4]), StoreOp::Store)
+    ///     .render_area(Region2D::Area((300, 400), 200, 100));
+    /// # }
+    /// ```
+    pub fn render_area(self, render_area: Region2D) -> Self {
+        RenderTargetDescriptor {
+            render_area,
+            ..self
         }
     }
 }
@@ -478,6 +553,11 @@ impl<C> RenderTargetDescriptor<C, ()> {
     ///     .attach_depth_stencil(&mut depth_stencil_image, LoadOp::Load, StoreOp::Store);
     /// # }
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `load_op` clears to a stencil value that does not fit in 8 bits (outside of
+    /// the range `0..=255`).
     pub fn attach_depth_stencil<Ds>(
         self,
         mut image: Ds,
@@ -487,17 +567,106 @@ impl<C> RenderTargetDescriptor<C, ()> {
     where
         Ds: AttachDepthStencil,
     {
-        let context_id = image.as_attachment().into_data().context_id;
+        load_op.assert_valid_stencil();
+
+        let data = image.as_attachment().into_data();
+        let mut attachments = self.attachments;
+
+        attachments.push(AttachmentRef::new(
+            AttachmentKind::DepthStencil,
+            <Ds::Format as InternalFormat>::ID,
+            &data,
+            1,
+        ));
+
+        RenderTargetDescriptor {
+            color_attachments: self.color_attachments,
+            depth_stencil_attachment: DepthStencilAttachment::new(image, load_op, store_op),
+            color_attachment_count: self.color_attachment_count,
+            context_id: self.context_id.record("depth-stencil", data.context_id),
+            render_area: self.render_area,
+            attachments,
+        }
+    }
+
+    /// Attaches an image to the depth-stencil slot that stores combined depth and stencil values,
+    /// with independent load and store operations for the depth aspect and the stencil aspect of
+    /// the image.
+    ///
+    /// This allows e.g. a render pass to clear the depth values while preserving a stencil mask
+    /// that was written by an earlier render pass, without having to use separate depth and stencil
+    /// images. As the `image` must be a combined depth-stencil image (see [AttachDepthStencil]),
+    /// both aspects are always present in the attachment's format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
+    /// use web_glitz::image::format::Depth24Stencil8;
+    /// use web_glitz::image::renderbuffer::RenderbufferDescriptor;
+    /// use web_glitz::rendering::{RenderTargetDescriptor, LoadOp, StoreOp};
+    ///
+    /// let mut depth_stencil_image = context.create_renderbuffer(&RenderbufferDescriptor{
+    ///     format: Depth24Stencil8,
+    ///     width: 500,
+    ///     height: 500
+    /// });
+    ///
+    /// // Clear the depth values, but keep the stencil mask from a previous render pass.
+    /// let render_target_descriptor = RenderTargetDescriptor::new()
+    ///     .attach_depth_stencil_separate(
+    ///         &mut depth_stencil_image,
+    ///         LoadOp::Clear(1.0),
+    ///         StoreOp::DontCare,
+    ///         LoadOp::Load,
+    ///         StoreOp::Store,
+    ///     );
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `stencil_load_op` clears to a stencil value that does not fit in 8 bits
+    /// (outside of the range `0..=255`).
+    ///
+    /// [AttachDepthStencil]: crate::rendering::AttachDepthStencil
+    pub fn attach_depth_stencil_separate<Ds>(
+        self,
+        mut image: Ds,
+        depth_load_op: LoadOp<f32>,
+        depth_store_op: StoreOp,
+        stencil_load_op: LoadOp<i32>,
+        stencil_store_op: StoreOp,
+    ) -> RenderTargetDescriptor<C, DepthStencilAttachment<Ds>>
+    where
+        Ds: AttachDepthStencil,
+    {
+        stencil_load_op.assert_valid_stencil();
+
+        let data = image.as_attachment().into_data();
+        let mut attachments = self.attachments;
+
+        attachments.push(AttachmentRef::new(
+            AttachmentKind::DepthStencil,
+            <Ds::Format as InternalFormat>::ID,
+            &data,
+            1,
+        ));
 
         RenderTargetDescriptor {
             color_attachments: self.color_attachments,
             depth_stencil_attachment: DepthStencilAttachment {
                 image,
-                load_op,
-                store_op,
+                depth_load_op,
+                depth_store_op,
+                stencil_load_op,
+                stencil_store_op,
             },
             color_attachment_count: self.color_attachment_count,
-            context_id: self.context_id.record("depth-stencil", context_id),
+            context_id: self.context_id.record("depth-stencil", data.context_id),
+            render_area: self.render_area,
+            attachments,
         }
     }
 
@@ -531,7 +700,15 @@ impl<C> RenderTargetDescriptor<C, ()> {
     where
         Ds: AttachDepth,
     {
-        let context_id = image.as_attachment().into_data().context_id;
+        let data = image.as_attachment().into_data();
+        let mut attachments = self.attachments;
+
+        attachments.push(AttachmentRef::new(
+            AttachmentKind::Depth,
+            <Ds::Format as InternalFormat>::ID,
+            &data,
+            1,
+        ));
 
         RenderTargetDescriptor {
             color_attachments: self.color_attachments,
@@ -541,12 +718,19 @@ impl<C> RenderTargetDescriptor<C, ()> {
                 store_op,
             },
             color_attachment_count: self.color_attachment_count,
-            context_id: self.context_id.record("depth-stencil", context_id),
+            context_id: self.context_id.record("depth-stencil", data.context_id),
+            render_area: self.render_area,
+            attachments,
         }
     }
 
     /// Attaches an image to the depth-stencil slot that stores stencil values.
     ///
+    /// A render target with only a stencil attachment (and no color attachments) may be used for
+    /// cheap masking passes that only modify stencil values. Note that an image that stores
+    /// combined depth and stencil values must instead be attached with [attach_depth_stencil]; a
+    /// pipeline that does not specify a [DepthTest] will not modify its depth values.
+    ///
     /// # Example
     ///
     /// ```
@@ -566,6 +750,11 @@ impl<C> RenderTargetDescriptor<C, ()> {
     ///     .attach_stencil(&mut stencil_image, LoadOp::Load, StoreOp::Store);
     /// # }
     /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `load_op` clears to a stencil value that does not fit in 8 bits (outside of
+    /// the range `0..=255`).
     pub fn attach_stencil<Ds>(
         self,
         mut image: Ds,
@@ -575,7 +764,17 @@ impl<C> RenderTargetDescriptor<C, ()> {
     where
         Ds: AttachStencil,
     {
-        let context_id = image.as_attachment().into_data().context_id;
+        load_op.assert_valid_stencil();
+
+        let data = image.as_attachment().into_data();
+        let mut attachments = self.attachments;
+
+        attachments.push(AttachmentRef::new(
+            AttachmentKind::Stencil,
+            <Ds::Format as InternalFormat>::ID,
+            &data,
+            1,
+        ));
 
         RenderTargetDescriptor {
             color_attachments: self.color_attachments,
@@ -585,7 +784,9 @@ impl<C> RenderTargetDescriptor<C, ()> {
                 store_op,
             },
             color_attachment_count: self.color_attachment_count,
-            context_id: self.context_id.record("depth-stencil", context_id),
+            context_id: self.context_id.record("depth-stencil", data.context_id),
+            render_area: self.render_area,
+            attachments,
         }
     }
 }
@@ -595,6 +796,16 @@ macro_rules! impl_attach_render_target_color {
         impl<$($C,)* Ds> RenderTargetDescriptor<($($C,)*), Ds> {
             /// Attaches an image that stores floating point values to the next color slot.
             ///
+            /// The image's format must implement [FloatRenderable]. Formats that store floating
+            /// point values (e.g. [RGBA16F] or [RGBA32F]) are only renderable when the
+            /// [color_buffer_float] extension is available: an image with such a format must first
+            /// be wrapped with the extension object, which acts as a type proof for the
+            /// availability of the extension (see [color_buffer_float] for an example). Attaching
+            /// such an image without the extension object results in a compile error. Images with
+            /// a half-precision floating point format (e.g. [RGBA16F]) may alternatively be wrapped
+            /// with the [color_buffer_half_float] extension object, which is available on more
+            /// devices.
+            ///
             /// # Example
             ///
             /// ```
@@ -614,8 +825,17 @@ macro_rules! impl_attach_render_target_color {
             ///     .attach_color_float(&mut color_image, LoadOp::Load, StoreOp::Store);
             /// # }
             /// ```
+            ///
+            /// [RGBA16F]: crate::image::format::RGBA16F
+            /// [RGBA32F]: crate::image::format::RGBA32F
+            /// [color_buffer_float]: crate::extensions::color_buffer_float
+            /// [color_buffer_half_float]: crate::extensions::color_buffer_half_float
             pub fn attach_color_float<C>(self, mut image: C, load_op: LoadOp<[f32; 4]>, store_op: StoreOp) -> RenderTargetDescriptor<($($C,)* FloatAttachment<C>,), Ds> where C: AttachColorFloat {
-                let context_id = image.as_attachment().into_data().context_id;
+                let data = image.as_attachment().into_data();
+                let mut attachments = self.attachments;
+
+                // Insert before the depth-stencil attachment, if one was already attached.
+                attachments.insert($count - 1, AttachmentRef::new(AttachmentKind::Color($count - 1), <C::Format as InternalFormat>::ID, &data, 1));
 
                 #[allow(non_snake_case)]
                 let ($($C,)*) = self.color_attachments;
@@ -628,7 +848,9 @@ macro_rules! impl_attach_render_target_color {
                     },),
                     depth_stencil_attachment: self.depth_stencil_attachment,
                     color_attachment_count: $count,
-                    context_id: self.context_id.record($label, context_id)
+                    context_id: self.context_id.record($label, data.context_id),
+                    render_area: self.render_area,
+                    attachments,
                 }
             }
 
@@ -654,7 +876,11 @@ macro_rules! impl_attach_render_target_color {
             /// # }
             /// ```
             pub fn attach_color_integer<C>(self, mut image: C, load_op: LoadOp<[i32; 4]>, store_op: StoreOp) -> RenderTargetDescriptor<($($C,)* IntegerAttachment<C>,), Ds> where C: AttachColorInteger {
-                let context_id = image.as_attachment().into_data().context_id;
+                let data = image.as_attachment().into_data();
+                let mut attachments = self.attachments;
+
+                // Insert before the depth-stencil attachment, if one was already attached.
+                attachments.insert($count - 1, AttachmentRef::new(AttachmentKind::Color($count - 1), <C::Format as InternalFormat>::ID, &data, 1));
 
                 #[allow(non_snake_case)]
                 let ($($C,)*) = self.color_attachments;
@@ -667,7 +893,9 @@ macro_rules! impl_attach_render_target_color {
                     },),
                     depth_stencil_attachment: self.depth_stencil_attachment,
                     color_attachment_count: $count,
-                    context_id: self.context_id.record($label, context_id)
+                    context_id: self.context_id.record($label, data.context_id),
+                    render_area: self.render_area,
+                    attachments,
                 }
             }
 
@@ -693,7 +921,11 @@ macro_rules! impl_attach_render_target_color {
             /// # }
             /// ```
             pub fn attach_color_unsigned_integer<C>(self, mut image: C, load_op: LoadOp<[u32; 4]>, store_op: StoreOp) -> RenderTargetDescriptor<($($C,)* UnsignedIntegerAttachment<C>,), Ds> where C: AttachColorUnsignedInteger {
-                let context_id = image.as_attachment().into_data().context_id;
+                let data = image.as_attachment().into_data();
+                let mut attachments = self.attachments;
+
+                // Insert before the depth-stencil attachment, if one was already attached.
+                attachments.insert($count - 1, AttachmentRef::new(AttachmentKind::Color($count - 1), <C::Format as InternalFormat>::ID, &data, 1));
 
                 #[allow(non_snake_case)]
                 let ($($C,)*) = self.color_attachments;
@@ -706,7 +938,9 @@ macro_rules! impl_attach_render_target_color {
                     },),
                     depth_stencil_attachment: self.depth_stencil_attachment,
                     color_attachment_count: $count,
-                    context_id: self.context_id.record($label, context_id)
+                    context_id: self.context_id.record($label, data.context_id),
+                    render_area: self.render_area,
+                    attachments,
                 }
             }
         }
@@ -823,6 +1057,7 @@ pub struct MultisampleRenderTargetDescriptor<C, Ds> {
     pub(crate) samples: u8,
     pub(crate) color_attachment_count: u8,
     pub(crate) context_id: RenderTargetContextId,
+    pub(crate) attachments: StaticVec<AttachmentRef, 17>,
 }
 
 impl MultisampleRenderTargetDescriptor<(), ()> {
@@ -835,6 +1070,7 @@ impl MultisampleRenderTargetDescriptor<(), ()> {
             samples,
             color_attachment_count: 0,
             context_id: RenderTargetContextId::new(),
+            attachments: StaticVec::new(),
         }
     }
 }
@@ -867,6 +1103,9 @@ impl<C> MultisampleRenderTargetDescriptor<C, ()> {
     /// Panics if the sampling grid size used by the `image` does not match the sampling grid size
     /// specified for this [MultisampleRenderTargetDescriptor] (see
     /// [MultisampleRenderTargetDescriptor::new]).
+    ///
+    /// Panics if the `load_op` clears to a stencil value that does not fit in 8 bits (outside of
+    /// the range `0..=255`).
     pub fn attach_depth_stencil<Ds>(
         self,
         mut image: Ds,
@@ -876,9 +1115,11 @@ impl<C> MultisampleRenderTargetDescriptor<C, ()> {
     where
         Ds: AttachMultisampleDepthStencil,
     {
+        load_op.assert_valid_stencil();
+
         let attachment = image.as_multisample_attachment();
         let image_samples = attachment.samples();
-        let context_id = attachment.into_data().context_id;
+        let data = attachment.into_data();
 
         if image_samples != self.samples {
             panic!(
@@ -887,16 +1128,113 @@ impl<C> MultisampleRenderTargetDescriptor<C, ()> {
             );
         }
 
+        let mut attachments = self.attachments;
+
+        attachments.push(AttachmentRef::new(
+            AttachmentKind::DepthStencil,
+            <Ds::SampleFormat as InternalFormat>::ID,
+            &data,
+            image_samples,
+        ));
+
+        MultisampleRenderTargetDescriptor {
+            color_attachments: self.color_attachments,
+            depth_stencil_attachment: DepthStencilAttachment::new(image, load_op, store_op),
+            samples: self.samples,
+            color_attachment_count: self.color_attachment_count,
+            context_id: self.context_id.record("depth-stencil", data.context_id),
+            attachments,
+        }
+    }
+
+    /// Attaches an image to the depth-stencil slot that stores combined depth and stencil values,
+    /// with independent load and store operations for the depth aspect and the stencil aspect of
+    /// the image.
+    ///
+    /// See [RenderTargetDescriptor::attach_depth_stencil_separate] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
+    /// use web_glitz::image::format::{Depth24Stencil8, Multisample};
+    /// use web_glitz::image::renderbuffer::RenderbufferDescriptor;
+    /// use web_glitz::rendering::{MultisampleRenderTargetDescriptor, LoadOp, StoreOp};
+    ///
+    /// let mut depth_stencil_image = context.try_create_multisample_renderbuffer(&RenderbufferDescriptor {
+    ///     format: Multisample(Depth24Stencil8, 4),
+    ///     width: 500,
+    ///     height: 500
+    /// }).unwrap();
+    ///
+    /// let render_target_descriptor = MultisampleRenderTargetDescriptor::new(4)
+    ///     .attach_depth_stencil_separate(
+    ///         &mut depth_stencil_image,
+    ///         LoadOp::Clear(1.0),
+    ///         StoreOp::DontCare,
+    ///         LoadOp::Load,
+    ///         StoreOp::Store,
+    ///     );
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sampling grid size used by the `image` does not match the sampling grid size
+    /// specified for this [MultisampleRenderTargetDescriptor] (see
+    /// [MultisampleRenderTargetDescriptor::new]).
+    ///
+    /// Panics if the `stencil_load_op` clears to a stencil value that does not fit in 8 bits
+    /// (outside of the range `0..=255`).
+    ///
+    /// [RenderTargetDescriptor::attach_depth_stencil_separate]: crate::rendering::RenderTargetDescriptor::attach_depth_stencil_separate
+    pub fn attach_depth_stencil_separate<Ds>(
+        self,
+        mut image: Ds,
+        depth_load_op: LoadOp<f32>,
+        depth_store_op: StoreOp,
+        stencil_load_op: LoadOp<i32>,
+        stencil_store_op: StoreOp,
+    ) -> MultisampleRenderTargetDescriptor<C, DepthStencilAttachment<Ds>>
+    where
+        Ds: AttachMultisampleDepthStencil,
+    {
+        stencil_load_op.assert_valid_stencil();
+
+        let attachment = image.as_multisample_attachment();
+        let image_samples = attachment.samples();
+        let data = attachment.into_data();
+
+        if image_samples != self.samples {
+            panic!(
+                "Descriptor expects {} samples, but image uses {} samples",
+                self.samples, image_samples
+            );
+        }
+
+        let mut attachments = self.attachments;
+
+        attachments.push(AttachmentRef::new(
+            AttachmentKind::DepthStencil,
+            <Ds::SampleFormat as InternalFormat>::ID,
+            &data,
+            image_samples,
+        ));
+
         MultisampleRenderTargetDescriptor {
             color_attachments: self.color_attachments,
             depth_stencil_attachment: DepthStencilAttachment {
                 image,
-                load_op,
-                store_op,
+                depth_load_op,
+                depth_store_op,
+                stencil_load_op,
+                stencil_store_op,
             },
             samples: self.samples,
             color_attachment_count: self.color_attachment_count,
-            context_id: self.context_id.record("depth-stencil", context_id),
+            context_id: self.context_id.record("depth-stencil", data.context_id),
+            attachments,
         }
     }
 
@@ -938,7 +1276,7 @@ impl<C> MultisampleRenderTargetDescriptor<C, ()> {
     {
         let attachment = image.as_multisample_attachment();
         let image_samples = attachment.samples();
-        let context_id = attachment.into_data().context_id;
+        let data = attachment.into_data();
 
         if image_samples != self.samples {
             panic!(
@@ -947,6 +1285,15 @@ impl<C> MultisampleRenderTargetDescriptor<C, ()> {
             );
         }
 
+        let mut attachments = self.attachments;
+
+        attachments.push(AttachmentRef::new(
+            AttachmentKind::Depth,
+            <Ds::SampleFormat as InternalFormat>::ID,
+            &data,
+            image_samples,
+        ));
+
         MultisampleRenderTargetDescriptor {
             color_attachments: self.color_attachments,
             depth_stencil_attachment: DepthAttachment {
@@ -956,7 +1303,8 @@ impl<C> MultisampleRenderTargetDescriptor<C, ()> {
             },
             samples: self.samples,
             color_attachment_count: self.color_attachment_count,
-            context_id: self.context_id.record("depth-stencil", context_id),
+            context_id: self.context_id.record("depth-stencil", data.context_id),
+            attachments,
         }
     }
 }
@@ -966,6 +1314,16 @@ macro_rules! impl_attach_multisample_render_target_color {
         impl<$($C,)* Ds> MultisampleRenderTargetDescriptor<($($C,)*), Ds> {
             /// Attaches an image that stores floating point values to the next color slot.
             ///
+            /// The image's format must implement [FloatRenderable]. Formats that store floating
+            /// point values (e.g. [RGBA16F] or [RGBA32F]) are only renderable when the
+            /// [color_buffer_float] extension is available: an image with such a format must first
+            /// be wrapped with the extension object (see
+            /// [color_buffer_float::Extension::extend_multisample]), which acts as a type proof for
+            /// the availability of the extension. Attaching such an image without the extension
+            /// object results in a compile error. Images with a half-precision floating point
+            /// format (e.g. [RGBA16F]) may alternatively be wrapped with the
+            /// [color_buffer_half_float] extension object, which is available on more devices.
+            ///
             /// # Example
             ///
             /// ```
@@ -991,6 +1349,12 @@ macro_rules! impl_attach_multisample_render_target_color {
             /// Panics if the sampling grid size used by the `image` does not match the sampling
             /// grid size specified for this [MultisampleRenderTargetDescriptor] (see
             /// [MultisampleRenderTargetDescriptor::new]).
+            ///
+            /// [RGBA16F]: crate::image::format::RGBA16F
+            /// [RGBA32F]: crate::image::format::RGBA32F
+            /// [color_buffer_float]: crate::extensions::color_buffer_float
+            /// [color_buffer_float::Extension::extend_multisample]: crate::extensions::color_buffer_float::Extension::extend_multisample
+            /// [color_buffer_half_float]: crate::extensions::color_buffer_half_float
             pub fn attach_color_float<C>(
                 self,
                 mut image: C,
@@ -1001,7 +1365,7 @@ macro_rules! impl_attach_multisample_render_target_color {
             {
                 let attachment = image.as_multisample_attachment();
                 let image_samples = attachment.samples();
-                let context_id = attachment.into_data().context_id;
+                let data = attachment.into_data();
 
                 if image_samples != self.samples {
                     panic!(
@@ -1011,6 +1375,11 @@ macro_rules! impl_attach_multisample_render_target_color {
                     );
                 }
 
+                let mut attachments = self.attachments;
+
+                // Insert before the depth-stencil attachment, if one was already attached.
+                attachments.insert($count - 1, AttachmentRef::new(AttachmentKind::Color($count - 1), <C::SampleFormat as InternalFormat>::ID, &data, image_samples));
+
                 #[allow(non_snake_case)]
                 let ($($C,)*) = self.color_attachments;
 
@@ -1023,7 +1392,8 @@ macro_rules! impl_attach_multisample_render_target_color {
                     depth_stencil_attachment: self.depth_stencil_attachment,
                     samples: self.samples,
                     color_attachment_count: $count,
-                    context_id: self.context_id.record($label, context_id)
+                    context_id: self.context_id.record($label, data.context_id),
+                    attachments,
                 }
             }
         }
@@ -1138,12 +1508,134 @@ impl_attach_multisample_render_target_color!(
 /// the function is executed, which may modify the contents of the framebuffer. Finally, the
 /// [StoreOp]s associated with each of the images attached to the render target will be performed to
 /// store the (modified) contents of the framebuffer back to these images.
+///
+/// The function is called exactly once, before [create_render_pass] returns; it is not stored in
+/// the render pass. It may therefore borrow data from the surrounding scope, such as per-frame
+/// scene data, and need not be `'static`. The same applies to the function passed to
+/// [Framebuffer::pipeline_task]. Only the task that is returned has to outlive the scope, which it
+/// does, as commands hold on to the resources they use rather than borrowing them:
+///
+/// ```
+/// # use web_glitz::rendering::DefaultRGBBuffer;
+/// # use web_glitz::rendering::DefaultRenderTarget;
+/// # use web_glitz::runtime::RenderingContext;
+/// # use web_glitz::buffer::Buffer;
+/// # use web_glitz::pipeline::graphics::{GraphicsPipeline, Vertex};
+/// # use web_glitz::pipeline::resources::BindGroup;
+/// use web_glitz::task::sequence_iter;
+///
+/// struct Mesh<V> {
+///     vertices: Buffer<[V]>,
+///     vertex_count: usize,
+/// }
+///
+/// fn render_meshes<Rc, V>(
+///     context: &Rc,
+///     render_target: &mut DefaultRenderTarget<DefaultRGBBuffer, ()>,
+///     graphics_pipeline: &GraphicsPipeline<V, (), ()>,
+///     meshes: &[Mesh<V>],
+/// ) where
+///     Rc: RenderingContext,
+///     V: Vertex,
+/// {
+///     let resources = BindGroup::empty();
+///
+///     // Both closures borrow `meshes` and `resources`; the resulting render pass does not.
+///     let render_pass = render_target.create_render_pass(|framebuffer| {
+///         framebuffer.pipeline_task(graphics_pipeline, |active_pipeline| {
+///             sequence_iter(meshes.iter().map(|mesh| {
+///                 active_pipeline.task_builder()
+///                     .bind_vertex_buffers(&mesh.vertices)
+///                     .bind_resources(&resources)
+///                     .draw(mesh.vertex_count, 1)
+///                     .finish()
+///             }))
+///         })
+///     });
+///
+///     context.submit(render_pass);
+/// }
+/// ```
+///
+/// # Sharing an attachment between render targets
+///
+/// The same image may be attached to more than one render target. A typical example is a renderer
+/// that draws opaque geometry, transparent geometry and an overlay in separate render passes with
+/// different color attachments, but with a single shared depth image:
+///
+/// ```
+/// # use web_glitz::runtime::RenderingContext;
+/// # use web_glitz::image::format::{DepthComponent24, RGBA8};
+/// # use web_glitz::image::renderbuffer::Renderbuffer;
+/// # fn wrapper<Rc>(
+/// #     context: &Rc,
+/// #     mut scene_image: Renderbuffer<RGBA8>,
+/// #     mut transparency_image: Renderbuffer<RGBA8>,
+/// #     mut depth_image: Renderbuffer<DepthComponent24>,
+/// # ) where Rc: RenderingContext {
+/// use web_glitz::image::Region2D;
+/// use web_glitz::rendering::{LoadOp, StoreOp, RenderTargetDescriptor};
+/// use web_glitz::task::sequence;
+///
+/// let mut opaque_target = context.create_render_target(
+///     RenderTargetDescriptor::new()
+///         .attach_color_float(&mut scene_image, LoadOp::Clear([0.0; 4]), StoreOp::Store)
+///         .attach_depth(&mut depth_image, LoadOp::Clear(1.0), StoreOp::Store)
+/// );
+///
+/// let opaque_pass = opaque_target.create_render_pass(|framebuffer| {
+///     // Draw the opaque geometry...
+///     framebuffer.color.0.clear_command([0.0; 4], Region2D::Fill)
+/// });
+///
+/// // The render pass does not borrow the render target, so we may drop the render target to
+/// // release its borrow on the depth image.
+/// drop(opaque_target);
+///
+/// let mut transparent_target = context.create_render_target(
+///     RenderTargetDescriptor::new()
+///         .attach_color_float(&mut transparency_image, LoadOp::Clear([0.0; 4]), StoreOp::Store)
+///         .attach_depth(&mut depth_image, LoadOp::Load, StoreOp::DontCare)
+/// );
+///
+/// let transparent_pass = transparent_target.create_render_pass(|framebuffer| {
+///     // Draw the transparent geometry, depth tested against the opaque geometry...
+///     framebuffer.color.0.clear_command([0.0; 4], Region2D::Fill)
+/// });
+///
+/// context.submit(sequence(opaque_pass, transparent_pass));
+/// # }
+/// ```
+///
+/// This is safe because of the following borrow and lifetime model:
+///
+/// - A render target holds an exclusive borrow on each of its attached images for as long as the
+///   render target is alive. The borrow checker therefore prevents an image from being attached to
+///   two render targets that are alive at the same time, and prevents an image from being bound as
+///   a pipeline resource in a render pass while it is attached.
+/// - A [RenderPass] does not borrow its render target or the attached images: it holds on to the
+///   image data it needs (keeping the image data alive until the render pass has executed, even if
+///   the image itself is dropped). The render target may therefore be dropped as soon as its render
+///   passes have been created, after which its images may be attached to another render target.
+/// - An image is only loaded into a framebuffer while the render pass that uses it executes, and
+///   render passes never execute concurrently. Use [sequence] (or a similar combinator) to
+///   guarantee that render passes that share an image execute in the intended order; the load and
+///   store operations then carry the image's contents from one render pass into the next.
+///
+/// As render targets are cheap to create and the GPU framebuffer objects for a combination of
+/// attachments are cached by the rendering context, recreating the render targets for each frame
+/// is the intended usage pattern; there is no need to keep long-lived render targets that share
+/// attachments (e.g. through an [Rc](std::rc::Rc)).
+///
+/// [sequence]: crate::task::sequence
 pub struct RenderTarget<C, Ds> {
     pub(crate) color_attachments: C,
     pub(crate) depth_stencil_attachment: Ds,
     pub(crate) object_id: u64,
     pub(crate) context_id: u64,
     pub(crate) render_pass_id_gen: ObjectIdGen,
+    pub(crate) render_area: Region2D,
+    pub(crate) attachments: StaticVec<AttachmentRef, 17>,
 }
 
 impl<C, Ds> PartialEq for RenderTarget<C, Ds> {
@@ -1158,6 +1650,95 @@ impl<C, Ds> Hash for RenderTarget<C, Ds> {
     }
 }
 
+impl<C, Ds> RenderTarget<C, Ds> {
+    /// Returns a reference to the tuple of color attachments, in the order in which they were
+    /// attached.
+    ///
+    /// Each attachment provides access to the attached image through its `image` method. This may
+    /// for example be used to sample the output of a render pass in a subsequent render pass,
+    /// without having to hold on to a separate reference to the attached texture:
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # use web_glitz::image::texture_2d::Texture2D;
+    /// # use web_glitz::image::format::RGBA8;
+    /// # use web_glitz::image::sampler::{Linear, Sampler};
+    /// # fn wrapper<Rc>(
+    /// #     context: &Rc,
+    /// #     texture: &mut Texture2D<RGBA8>,
+    /// #     sampler: &Sampler<Linear, Linear>,
+    /// # )
+    /// # where
+    /// #     Rc: RenderingContext,
+    /// # {
+    /// use web_glitz::rendering::{LoadOp, RenderTargetDescriptor, StoreOp};
+    ///
+    /// let render_target = context.create_render_target(
+    ///     RenderTargetDescriptor::new()
+    ///         .attach_color_float(texture.base_level_mut(), LoadOp::Load, StoreOp::Store),
+    /// );
+    ///
+    /// // Create and submit a render pass that outputs to the render target...
+    ///
+    /// let sampled = render_target
+    ///     .color_attachments()
+    ///     .0
+    ///     .image()
+    ///     .texture()
+    ///     .float_sampled(sampler);
+    /// # }
+    /// ```
+    ///
+    /// Note that a texture must not be sampled in a render pass that also outputs to that same
+    /// texture: use a different render target for the pass that samples the texture.
+    pub fn color_attachments(&self) -> &C {
+        &self.color_attachments
+    }
+
+    /// Returns a reference to the depth-stencil attachment, or `&()` if the render target does not
+    /// have a depth-stencil attachment.
+    ///
+    /// The attachment provides access to the attached image through its `image` method.
+    pub fn depth_stencil_attachment(&self) -> &Ds {
+        &self.depth_stencil_attachment
+    }
+
+    /// Returns an iterator over descriptions of the images attached to this render target.
+    ///
+    /// Yields the color attachments in the order of their color slots, followed by the
+    /// depth-stencil attachment (if any). Each [AttachmentRef] describes the kind of values the
+    /// image stores, its storage format, its size and its sampling grid size. Unlike
+    /// [color_attachments] and [depth_stencil_attachment], this does not require knowledge of the
+    /// concrete attachment types, which allows building generic passes (e.g. a debug view) that
+    /// adapt to the configuration of the render target:
+    ///
+    /// ```
+    /// # use web_glitz::rendering::RenderTarget;
+    /// # fn wrapper<C, Ds>(render_target: &RenderTarget<C, Ds>) {
+    /// use web_glitz::rendering::AttachmentKind;
+    ///
+    /// for attachment in render_target.attachments() {
+    ///     if let AttachmentKind::Color(slot) = attachment.kind() {
+    ///         let label = format!(
+    ///             "Color {} ({}x{})",
+    ///             slot,
+    ///             attachment.width(),
+    ///             attachment.height()
+    ///         );
+    ///
+    ///         // Offer to visualize the attachment...
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [color_attachments]: RenderTarget::color_attachments
+    /// [depth_stencil_attachment]: RenderTarget::depth_stencil_attachment
+    pub fn attachments(&self) -> impl Iterator<Item = AttachmentRef> + '_ {
+        self.attachments.iter().copied()
+    }
+}
+
 pub struct MultisampleRenderTarget<C, Ds> {
     pub(crate) color_attachments: C,
     pub(crate) depth_stencil_attachment: Ds,
@@ -1165,6 +1746,7 @@ pub struct MultisampleRenderTarget<C, Ds> {
     pub(crate) object_id: u64,
     pub(crate) context_id: u64,
     pub(crate) render_pass_id_gen: ObjectIdGen,
+    pub(crate) attachments: StaticVec<AttachmentRef, 17>,
 }
 
 impl<C, Ds> PartialEq for MultisampleRenderTarget<C, Ds> {
@@ -1179,6 +1761,29 @@ impl<C, Ds> Hash for MultisampleRenderTarget<C, Ds> {
     }
 }
 
+impl<C, Ds> MultisampleRenderTarget<C, Ds> {
+    /// Returns a reference to the tuple of color attachments, in the order in which they were
+    /// attached.
+    ///
+    /// See also [RenderTarget::color_attachments].
+    pub fn color_attachments(&self) -> &C {
+        &self.color_attachments
+    }
+
+    /// Returns a reference to the depth-stencil attachment, or `&()` if the render target does not
+    /// have a depth-stencil attachment.
+    pub fn depth_stencil_attachment(&self) -> &Ds {
+        &self.depth_stencil_attachment
+    }
+
+    /// Returns an iterator over descriptions of the images attached to this render target.
+    ///
+    /// See also [RenderTarget::attachments].
+    pub fn attachments(&self) -> impl Iterator<Item = AttachmentRef> + '_ {
+        self.attachments.iter().copied()
+    }
+}
+
 macro_rules! impl_create_render_pass {
     ($C0:ident $(,$C:ident)*) => {
         #[allow(unused_parens)]
@@ -1210,6 +1815,7 @@ macro_rules! impl_create_render_pass {
                 let mut render_target = CustomRenderTargetData {
                     load_ops: [LoadAction::Load; 17],
                     store_ops: [StoreOp::Store; 17],
+                    stencil_store_op: StoreOp::Store,
                     color_count: 0,
                     color_attachments: [
                         None,
@@ -1230,6 +1836,7 @@ macro_rules! impl_create_render_pass {
                         None,
                     ],
                     depth_stencil_attachment: DepthStencilAttachmentDescriptor::None,
+                    render_area: self.render_area,
                 };
 
                 let ($C0, $($C,)*) = &mut self.color_attachments;
@@ -1294,6 +1901,8 @@ macro_rules! impl_create_render_pass {
 
                 render_target.color_count = color_count;
 
+                validate_render_area(&self.render_area, width, height);
+
                 let task = f(&Framebuffer {
                     color: ($C0, $($C,)*),
                     depth_stencil: (),
@@ -1302,6 +1911,10 @@ macro_rules! impl_create_render_pass {
                         context_id: self.context_id,
                         render_pass_id: id,
                         last_pipeline_task_id: Cell::new(0),
+                        has_depth_buffer: false,
+                        has_stencil_buffer: false,
+                        attachment_ids: render_target.attachment_ids(),
+                        render_area: self.render_area,
                     }
                 });
 
@@ -1349,6 +1962,7 @@ macro_rules! impl_create_render_pass {
                 let mut render_target = CustomRenderTargetData {
                     load_ops: [LoadAction::Load; 17],
                     store_ops: [StoreOp::Store; 17],
+                    stencil_store_op: StoreOp::Store,
                     color_count: 0,
                     color_attachments: [
                         None,
@@ -1369,6 +1983,7 @@ macro_rules! impl_create_render_pass {
                         None,
                     ],
                     depth_stencil_attachment: DepthStencilAttachmentDescriptor::None,
+                    render_area: Region2D::Fill,
                 };
 
                 let ($C0, $($C,)*) = &mut self.color_attachments;
@@ -1442,6 +2057,10 @@ macro_rules! impl_create_render_pass {
                         context_id: self.context_id,
                         render_pass_id: id,
                         last_pipeline_task_id: Cell::new(0),
+                        has_depth_buffer: false,
+                        has_stencil_buffer: false,
+                        attachment_ids: render_target.attachment_ids(),
+                        render_area: Region2D::Fill,
                     }
                 });
 
@@ -1515,6 +2134,7 @@ macro_rules! impl_create_render_pass_depth_stencil {
                 let DepthStencilBufferEncoding {
                     load_action,
                     store_op,
+                    stencil_store_op,
                     depth_stencil_type,
                     image,
                     buffer,
@@ -1530,6 +2150,7 @@ macro_rules! impl_create_render_pass_depth_stencil {
                 let mut render_target = CustomRenderTargetData {
                     load_ops: [LoadAction::Load; 17],
                     store_ops: [StoreOp::Store; 17],
+                    stencil_store_op,
                     color_count: 0,
                     color_attachments: [
                         None,
@@ -1550,6 +2171,7 @@ macro_rules! impl_create_render_pass_depth_stencil {
                         None,
                     ],
                     depth_stencil_attachment: depth_stencil_type.descriptor(image),
+                    render_area: self.render_area,
                 };
 
                 render_target.load_ops[16] = load_action;
@@ -1592,6 +2214,8 @@ macro_rules! impl_create_render_pass_depth_stencil {
 
                 render_target.color_count = color_count;
 
+                validate_render_area(&self.render_area, width, height);
+
                 let task = f(&Framebuffer {
                     color: ($($C,)*),
                     depth_stencil: buffer,
@@ -1600,6 +2224,10 @@ macro_rules! impl_create_render_pass_depth_stencil {
                         context_id: self.context_id,
                         render_pass_id: id,
                         last_pipeline_task_id: Cell::new(0),
+                        has_depth_buffer: depth_stencil_type.has_depth(),
+                        has_stencil_buffer: depth_stencil_type.has_stencil(),
+                        attachment_ids: render_target.attachment_ids(),
+                        render_area: self.render_area,
                     }
                 });
 
@@ -1652,6 +2280,7 @@ macro_rules! impl_create_render_pass_depth_stencil {
                 let DepthStencilBufferEncoding {
                     load_action,
                     store_op,
+                    stencil_store_op,
                     depth_stencil_type,
                     image,
                     buffer,
@@ -1667,6 +2296,7 @@ macro_rules! impl_create_render_pass_depth_stencil {
                 let mut render_target = CustomRenderTargetData {
                     load_ops: [LoadAction::Load; 17],
                     store_ops: [StoreOp::Store; 17],
+                    stencil_store_op,
                     color_count: 0,
                     color_attachments: [
                         None,
@@ -1687,6 +2317,7 @@ macro_rules! impl_create_render_pass_depth_stencil {
                         None,
                     ],
                     depth_stencil_attachment: depth_stencil_type.descriptor(image),
+                    render_area: Region2D::Fill,
                 };
 
                 render_target.load_ops[16] = load_action;
@@ -1738,6 +2369,10 @@ macro_rules! impl_create_render_pass_depth_stencil {
                         context_id: self.context_id,
                         render_pass_id: id,
                         last_pipeline_task_id: Cell::new(0),
+                        has_depth_buffer: depth_stencil_type.has_depth(),
+                        has_stencil_buffer: depth_stencil_type.has_stencil(),
+                        attachment_ids: render_target.attachment_ids(),
+                        render_area: Region2D::Fill,
                     }
                 });
 
@@ -1839,6 +2474,22 @@ impl RenderTargetContextId {
     }
 }
 
+fn validate_render_area(render_area: &Region2D, width: u32, height: u32) {
+    if let Region2D::Area((x, y), area_width, area_height) = *render_area {
+        let exceeds_width = x
+            .checked_add(area_width)
+            .map_or(true, |right| right > width);
+        let exceeds_height = y.checked_add(area_height).map_or(true, |top| top > height);
+
+        if exceeds_width || exceeds_height {
+            panic!(
+                "The render area does not fit within the framebuffer dimensions ({} by {}).",
+                width, height
+            );
+        }
+    }
+}
+
 #[derive(Clone)]
 pub(crate) enum RenderTargetData {
     Default,
@@ -1849,12 +2500,37 @@ pub(crate) enum RenderTargetData {
 pub(crate) struct CustomRenderTargetData {
     pub(crate) load_ops: [LoadAction; 17],
     pub(crate) store_ops: [StoreOp; 17],
+    // The store operation for the stencil aspect of a combined depth-stencil attachment; the store
+    // operation for the depth aspect is `store_ops[16]`.
+    pub(crate) stencil_store_op: StoreOp,
     pub(crate) color_count: usize,
     pub(crate) color_attachments: [Option<AttachmentData>; 16],
     pub(crate) depth_stencil_attachment: DepthStencilAttachmentDescriptor,
+    pub(crate) render_area: Region2D,
 }
 
 impl CustomRenderTargetData {
+    /// Returns the IDs of the attached images, together with the mipmap level at which each image
+    /// is attached.
+    pub(crate) fn attachment_ids(&self) -> StaticVec<(JsId, u8), 17> {
+        let mut ids = StaticVec::new();
+
+        for attachment in self.color_attachments[0..self.color_count].iter().flatten() {
+            ids.push((attachment.id(), attachment.level()));
+        }
+
+        match &self.depth_stencil_attachment {
+            DepthStencilAttachmentDescriptor::Depth(attachment)
+            | DepthStencilAttachmentDescriptor::Stencil(attachment)
+            | DepthStencilAttachmentDescriptor::DepthStencil(attachment) => {
+                ids.push((attachment.id(), attachment.level()));
+            }
+            DepthStencilAttachmentDescriptor::None => (),
+        }
+
+        ids
+    }
+
     pub(crate) fn draw_buffers(&self) -> &[DrawBuffer] {
         const DRAW_BUFFERS_SEQUENTIAL: [DrawBuffer; 16] = [
             DrawBuffer::Color0,
@@ -1898,3 +2574,32 @@ impl AttachmentSet for CustomRenderTargetData {
         &self.depth_stencil_attachment
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_render_area_fits() {
+        validate_render_area(&Region2D::Area((10, 20), 30, 40), 40, 60);
+        validate_render_area(&Region2D::Fill, 40, 60);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit within the framebuffer")]
+    fn test_validate_render_area_exceeds() {
+        validate_render_area(&Region2D::Area((10, 20), 31, 40), 40, 60);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit within the framebuffer")]
+    fn test_validate_render_area_offset_overflow() {
+        validate_render_area(&Region2D::Area((u32::MAX, 0), 2, 10), 40, 60);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit within the framebuffer")]
+    fn test_validate_render_area_size_overflow() {
+        validate_render_area(&Region2D::Area((0, 1), 10, u32::MAX), 40, 60);
+    }
+}
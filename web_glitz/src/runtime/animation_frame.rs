@@ -0,0 +1,112 @@
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::window;
+
+/// Passed to the callback registered with [RenderingContext::run_animation_frames] for every
+/// animation frame.
+///
+/// [RenderingContext::run_animation_frames]: crate::runtime::RenderingContext::run_animation_frames
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FrameContext {
+    /// The timestamp of the animation frame in milliseconds, as provided by the browser to the
+    /// `requestAnimationFrame` callback.
+    pub timestamp: f64,
+
+    /// The time in milliseconds that elapsed since the previous animation frame, or `0.0` for the
+    /// first animation frame.
+    pub delta: f64,
+
+    /// The index of the animation frame, starting at `0` for the first animation frame.
+    pub frame: u64,
+}
+
+/// Handle to an animation frame loop started with [RenderingContext::run_animation_frames].
+///
+/// The loop only runs for as long as this handle is alive; dropping the handle cancels the pending
+/// animation frame request and stops the loop.
+///
+/// [RenderingContext::run_animation_frames]: crate::runtime::RenderingContext::run_animation_frames
+pub struct AnimationHandle {
+    shared: Rc<AnimationShared>,
+}
+
+struct AnimationShared {
+    request_id: Cell<Option<i32>>,
+    callback: RefCell<Option<Closure<dyn FnMut(f64)>>>,
+}
+
+impl AnimationShared {
+    fn request_next_frame(&self) {
+        if let Some(callback) = self.callback.borrow().as_ref() {
+            let request_id = window()
+                .unwrap()
+                .request_animation_frame(callback.as_ref().unchecked_ref())
+                .unwrap();
+
+            self.request_id.set(Some(request_id));
+        }
+    }
+}
+
+impl AnimationHandle {
+    /// Starts a loop that calls `f` for every animation frame, until the returned handle is
+    /// dropped or `f` returns `false`.
+    pub(crate) fn start<F>(mut f: F) -> Self
+    where
+        F: FnMut(FrameContext) -> bool + 'static,
+    {
+        let shared = Rc::new(AnimationShared {
+            request_id: Cell::new(None),
+            callback: RefCell::new(None),
+        });
+
+        // The callback only holds a weak reference to the shared state, so that dropping the
+        // handle also drops the callback, rather than creating a reference cycle that would keep
+        // the loop alive indefinitely.
+        let weak: Weak<AnimationShared> = Rc::downgrade(&shared);
+        let mut previous_timestamp = None;
+        let mut frame = 0;
+
+        let callback = Closure::wrap(Box::new(move |timestamp: f64| {
+            let delta = previous_timestamp.map_or(0.0, |previous| timestamp - previous);
+
+            previous_timestamp = Some(timestamp);
+
+            let proceed = f(FrameContext {
+                timestamp,
+                delta,
+                frame,
+            });
+
+            frame += 1;
+
+            if let Some(shared) = weak.upgrade() {
+                if proceed {
+                    shared.request_next_frame();
+                } else {
+                    shared.request_id.set(None);
+                }
+            }
+        }) as Box<dyn FnMut(f64)>);
+
+        *shared.callback.borrow_mut() = Some(callback);
+
+        shared.request_next_frame();
+
+        AnimationHandle { shared }
+    }
+}
+
+impl Drop for AnimationHandle {
+    fn drop(&mut self) {
+        if let Some(request_id) = self.shared.request_id.take() {
+            window()
+                .unwrap()
+                .cancel_animation_frame(request_id)
+                .unwrap();
+        }
+    }
+}
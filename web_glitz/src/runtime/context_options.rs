@@ -1,17 +1,31 @@
+use std::fmt;
 use std::marker;
 
 use serde_derive::Serialize;
+use web_sys::WebGl2RenderingContext as Gl;
 
 use crate::rendering::{
     DefaultDepthBuffer, DefaultDepthStencilBuffer, DefaultMultisampleRenderTarget,
     DefaultRGBABuffer, DefaultRGBBuffer, DefaultRenderTarget, DefaultStencilBuffer,
 };
 
+/// Hint to the user agent about which GPU configuration is suitable for a WebGlitz rendering
+/// context.
+///
+/// Maps onto the `powerPreference` WebGL context creation attribute. Note that this is only a hint:
+/// the user agent is free to ignore it.
 #[derive(Debug, Clone, Copy, PartialEq, Serialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum PowerPreference {
+    /// Let the user agent decide which GPU configuration is most suitable.
     Default,
+
+    /// Prioritizes rendering performance over power consumption, e.g. on systems with both an
+    /// integrated and a discrete GPU, the discrete GPU is preferred.
     HighPerformance,
+
+    /// Prioritizes power saving over rendering performance, e.g. on systems with both an integrated
+    /// and a discrete GPU, the integrated GPU is preferred.
     LowPower,
 }
 
@@ -21,30 +35,162 @@ impl Default for PowerPreference {
     }
 }
 
+/// Hint to the implementation about the quality of the mipmaps that are generated by a WebGlitz
+/// rendering context.
+///
+/// Maps onto the `GENERATE_MIPMAP_HINT` WebGL hint, see [ContextOptionsBuilder::mipmap_hint]. Note
+/// that this is only a hint: the implementation is free to ignore it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MipmapHint {
+    /// Let the implementation decide how mipmaps are generated.
+    DontCare,
+
+    /// Prioritizes the speed of mipmap generation over the quality of the generated mipmaps.
+    Fastest,
+
+    /// Prioritizes the quality of the generated mipmaps over the speed of mipmap generation.
+    Nicest,
+}
+
+impl MipmapHint {
+    pub(crate) fn id(&self) -> u32 {
+        match self {
+            MipmapHint::DontCare => Gl::DONT_CARE,
+            MipmapHint::Fastest => Gl::FASTEST,
+            MipmapHint::Nicest => Gl::NICEST,
+        }
+    }
+}
+
+impl Default for MipmapHint {
+    fn default() -> Self {
+        MipmapHint::DontCare
+    }
+}
+
+/// Options used to initialize a WebGlitz rendering context.
+///
+/// Options may be constructed with the [ContextOptionsBuilder] returned by [ContextOptions::begin]:
+///
+/// ```
+/// use web_glitz::runtime::{ContextOptions, PowerPreference};
+///
+/// let options = ContextOptions::begin()
+///     .enable_depth()
+///     .premultiplied_alpha(true)
+///     .power_preference(PowerPreference::HighPerformance)
+///     .fail_if_major_performance_caveat(true)
+///     .finish();
+/// ```
+///
+/// The type parameter `T` encodes the type of default render target that will be returned when a
+/// runtime is initialized with these options.
+///
+/// # Canvas compositing
+///
+/// By default, the default render target has an alpha channel (its color buffer is a
+/// [DefaultRGBABuffer]) and the canvas is composited with the rest of the page using the alpha
+/// values written to the default render target; regions with an alpha value of `0.0` let the page
+/// behind the canvas show through, which is useful for e.g. an overlay canvas. If alpha is disabled
+/// with [ContextOptionsBuilder::disable_alpha], then the default render target's color buffer is a
+/// [DefaultRGBBuffer] instead and the canvas is always composited as fully opaque.
+///
+/// When alpha is enabled, the compositor assumes by default that the color values have been
+/// premultiplied by their alpha value (see [ContextOptionsBuilder::premultiplied_alpha]): a
+/// fragment that is meant to be fully transparent must then be written as `[0.0, 0.0, 0.0, 0.0]`,
+/// and a color channel should never exceed the alpha value (otherwise the compositing results are
+/// undefined). If your shaders output straight (non-premultiplied) alpha, disable
+/// `premultiplied_alpha` instead:
+///
+/// ```
+/// use web_glitz::rendering::{DefaultRGBABuffer, DefaultRenderTarget};
+/// use web_glitz::runtime::ContextOptions;
+///
+/// let options = ContextOptions::begin()
+///     .disable_antialias()
+///     .premultiplied_alpha(false)
+///     .finish();
+///
+/// // The default render target will have an alpha channel.
+/// let _: &ContextOptions<DefaultRenderTarget<DefaultRGBABuffer, ()>> = &options;
+/// ```
+///
+/// Note that the default render target is cleared to transparent black (`[0.0, 0.0, 0.0, 0.0]`)
+/// after presentation, unless [ContextOptionsBuilder::preserve_drawbuffer] is enabled.
 pub struct ContextOptions<T> {
     render_target: marker::PhantomData<T>,
     preserve_drawing_buffer: bool,
     fail_if_major_performance_caveat: bool,
     premultiplied_alpha: bool,
     power_preference: PowerPreference,
+    debug: bool,
+    mipmap_hint: MipmapHint,
+}
+
+impl<T> Clone for ContextOptions<T> {
+    fn clone(&self) -> Self {
+        ContextOptions {
+            render_target: marker::PhantomData,
+            preserve_drawing_buffer: self.preserve_drawing_buffer,
+            fail_if_major_performance_caveat: self.fail_if_major_performance_caveat,
+            premultiplied_alpha: self.premultiplied_alpha,
+            power_preference: self.power_preference,
+            debug: self.debug,
+            mipmap_hint: self.mipmap_hint,
+        }
+    }
+}
+
+impl<T> fmt::Debug for ContextOptions<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ContextOptions")
+            .field("preserve_drawing_buffer", &self.preserve_drawing_buffer)
+            .field(
+                "fail_if_major_performance_caveat",
+                &self.fail_if_major_performance_caveat,
+            )
+            .field("premultiplied_alpha", &self.premultiplied_alpha)
+            .field("power_preference", &self.power_preference)
+            .field("debug", &self.debug)
+            .field("mipmap_hint", &self.mipmap_hint)
+            .finish()
+    }
 }
 
 impl<T> ContextOptions<T> {
+    /// Whether or not the default render target's buffers will be preserved after presentation.
     pub fn preserve_drawing_buffer(&self) -> bool {
         self.preserve_drawing_buffer
     }
 
+    /// Whether or not context creation should fail if the system's performance is expected to be
+    /// low (e.g. when only a software renderer is available).
     pub fn fail_if_major_performance_caveat(&self) -> bool {
         self.fail_if_major_performance_caveat
     }
 
+    /// Whether or not the page compositor will assume the default color buffer contains colors
+    /// with premultiplied alpha.
     pub fn premultiplied_alpha(&self) -> bool {
         self.premultiplied_alpha
     }
 
+    /// Hint to the user agent about which GPU configuration is suitable for the context, see
+    /// [PowerPreference].
     pub fn power_preference(&self) -> PowerPreference {
         self.power_preference
     }
+
+    /// Whether or not the context will check for errors after every command it executes, see
+    /// [ContextOptionsBuilder::debug].
+    pub fn debug(&self) -> bool {
+        self.debug
+    }
+
+    /// Hint to the implementation about the quality of generated mipmaps, see [MipmapHint].
+    pub fn mipmap_hint(&self) -> MipmapHint {
+        self.mipmap_hint
+    }
 }
 
 impl Default for ContextOptions<DefaultMultisampleRenderTarget<DefaultRGBABuffer, ()>> {
@@ -55,11 +201,17 @@ impl Default for ContextOptions<DefaultMultisampleRenderTarget<DefaultRGBABuffer
             preserve_drawing_buffer: false,
             premultiplied_alpha: true,
             power_preference: PowerPreference::default(),
+            debug: false,
+            mipmap_hint: MipmapHint::default(),
         }
     }
 }
 
 impl ContextOptions<DefaultMultisampleRenderTarget<DefaultRGBABuffer, ()>> {
+    /// Returns a [ContextOptionsBuilder] for constructing a new set of [ContextOptions].
+    ///
+    /// The builder starts out with an antialiased RGBA default render target without a
+    /// depth-stencil buffer.
     pub fn begin() -> ContextOptionsBuilder<DefaultMultisampleRenderTarget<DefaultRGBABuffer, ()>> {
         ContextOptionsBuilder {
             render_target: marker::PhantomData,
@@ -67,19 +219,58 @@ impl ContextOptions<DefaultMultisampleRenderTarget<DefaultRGBABuffer, ()>> {
             preserve_drawbuffer: false,
             premultiplied_alpha: true,
             power_preference: PowerPreference::default(),
+            debug: false,
+            mipmap_hint: MipmapHint::default(),
         }
     }
 }
 
+/// Builder for [ContextOptions], see [ContextOptions::begin].
 pub struct ContextOptionsBuilder<T> {
     render_target: marker::PhantomData<T>,
     fail_if_major_performance_caveat: bool,
     preserve_drawbuffer: bool,
     premultiplied_alpha: bool,
     power_preference: PowerPreference,
+    debug: bool,
+    mipmap_hint: MipmapHint,
+}
+
+impl<T> Clone for ContextOptionsBuilder<T> {
+    fn clone(&self) -> Self {
+        ContextOptionsBuilder {
+            render_target: marker::PhantomData,
+            fail_if_major_performance_caveat: self.fail_if_major_performance_caveat,
+            preserve_drawbuffer: self.preserve_drawbuffer,
+            premultiplied_alpha: self.premultiplied_alpha,
+            power_preference: self.power_preference,
+            debug: self.debug,
+            mipmap_hint: self.mipmap_hint,
+        }
+    }
+}
+
+impl<T> fmt::Debug for ContextOptionsBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ContextOptionsBuilder")
+            .field(
+                "fail_if_major_performance_caveat",
+                &self.fail_if_major_performance_caveat,
+            )
+            .field("preserve_drawbuffer", &self.preserve_drawbuffer)
+            .field("premultiplied_alpha", &self.premultiplied_alpha)
+            .field("power_preference", &self.power_preference)
+            .field("debug", &self.debug)
+            .field("mipmap_hint", &self.mipmap_hint)
+            .finish()
+    }
 }
 
 impl<T> ContextOptionsBuilder<T> {
+    /// Sets whether or not context creation should fail if the system's performance is expected to
+    /// be low, e.g. when only a software renderer is available.
+    ///
+    /// Defaults to `false`.
     pub fn fail_if_major_performance_caveat(
         mut self,
         fail_if_major_performance_caveat: bool,
@@ -89,24 +280,88 @@ impl<T> ContextOptionsBuilder<T> {
         self
     }
 
+    /// Sets whether or not the default render target's buffers will be preserved until they are
+    /// cleared or overwritten, rather than being cleared after presentation.
+    ///
+    /// Defaults to `false`.
     pub fn preserve_drawbuffer(mut self, preserve_drawbuffer: bool) -> Self {
         self.preserve_drawbuffer = preserve_drawbuffer;
 
         self
     }
 
+    /// Sets whether or not the page compositor will assume the default color buffer contains
+    /// colors with premultiplied alpha.
+    ///
+    /// Maps onto the `premultipliedAlpha` WebGL context creation attribute. This must match the
+    /// colors your shaders write to the default render target: if `true`, color values must
+    /// already be multiplied by their alpha value; if `false`, color values are straight
+    /// (non-premultiplied). A mismatch typically shows up as dark or bright fringes around
+    /// antialiased or partially transparent edges.
+    ///
+    /// Has no effect if alpha is disabled (see [disable_alpha]). See also the "Canvas compositing"
+    /// section of the documentation for [ContextOptions].
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [disable_alpha]: ContextOptionsBuilder::disable_alpha
     pub fn premultiplied_alpha(mut self, premultiplied_alpha: bool) -> Self {
         self.premultiplied_alpha = premultiplied_alpha;
 
         self
     }
 
+    /// Sets a hint to the user agent about which GPU configuration is suitable for the context.
+    ///
+    /// For example, [PowerPreference::HighPerformance] may be used to request the discrete GPU on
+    /// a system with both an integrated and a discrete GPU, whereas [PowerPreference::LowPower] may
+    /// be used to request the integrated GPU.
+    ///
+    /// Defaults to [PowerPreference::Default].
     pub fn power_preference(mut self, power_preference: PowerPreference) -> Self {
         self.power_preference = power_preference;
 
         self
     }
 
+    /// Sets whether or not the context will check for errors after every command it executes.
+    ///
+    /// When enabled, the context queries the WebGL error state after each command and reports any
+    /// error as a [CommandError] that identifies the command that produced it. Errors are logged to
+    /// the browser console and can be retrieved with [RenderingContext::take_command_errors].
+    ///
+    /// Querying the error state forces the browser to synchronize with the GPU process, which can
+    /// be very slow; this should only be enabled while debugging.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [CommandError]: crate::runtime::CommandError
+    /// [RenderingContext::take_command_errors]: crate::runtime::RenderingContext::take_command_errors
+    pub fn debug(mut self, debug: bool) -> Self {
+        self.debug = debug;
+
+        self
+    }
+
+    /// Sets a hint to the implementation about the quality of the mipmaps that are generated by
+    /// the context's mipmap generation commands (e.g. [Texture2D::generate_mipmap_command]).
+    ///
+    /// On some implementations [MipmapHint::Nicest] produces noticeably better mipmaps (e.g. by
+    /// using a better filter than a simple box filter) at little cost. The hint applies to all
+    /// mipmap generation commands executed by the context. For full control over the filter that
+    /// is used, see [Texture2D::generate_mipmap_custom_command].
+    ///
+    /// Defaults to [MipmapHint::DontCare].
+    ///
+    /// [Texture2D::generate_mipmap_command]: crate::image::texture_2d::Texture2D::generate_mipmap_command
+    /// [Texture2D::generate_mipmap_custom_command]: crate::image::texture_2d::Texture2D::generate_mipmap_custom_command
+    pub fn mipmap_hint(mut self, mipmap_hint: MipmapHint) -> Self {
+        self.mipmap_hint = mipmap_hint;
+
+        self
+    }
+
+    /// Finishes building and returns the [ContextOptions].
     pub fn finish(self) -> ContextOptions<T> {
         ContextOptions {
             render_target: marker::PhantomData,
@@ -114,11 +369,19 @@ impl<T> ContextOptionsBuilder<T> {
             preserve_drawing_buffer: self.preserve_drawbuffer,
             premultiplied_alpha: self.premultiplied_alpha,
             power_preference: self.power_preference,
+            debug: self.debug,
+            mipmap_hint: self.mipmap_hint,
         }
     }
 }
 
 impl<C, Ds> ContextOptionsBuilder<DefaultMultisampleRenderTarget<C, Ds>> {
+    /// Disables antialiasing for the default render target.
+    ///
+    /// The default render target becomes a (single-sample) [DefaultRenderTarget]. See
+    /// [enable_antialias] for details on antialiasing the default render target.
+    ///
+    /// [enable_antialias]: ContextOptionsBuilder::enable_antialias
     pub fn disable_antialias(self) -> ContextOptionsBuilder<DefaultRenderTarget<C, Ds>> {
         ContextOptionsBuilder {
             render_target: marker::PhantomData,
@@ -126,11 +389,18 @@ impl<C, Ds> ContextOptionsBuilder<DefaultMultisampleRenderTarget<C, Ds>> {
             preserve_drawbuffer: self.preserve_drawbuffer,
             premultiplied_alpha: self.premultiplied_alpha,
             power_preference: self.power_preference,
+            debug: self.debug,
+            mipmap_hint: self.mipmap_hint,
         }
     }
 }
 
 impl<Ds> ContextOptionsBuilder<DefaultMultisampleRenderTarget<DefaultRGBABuffer, Ds>> {
+    /// Disables the alpha channel of the default render target.
+    ///
+    /// The default render target's color buffer becomes a [DefaultRGBBuffer] and the canvas is
+    /// always composited with the page as fully opaque. See also the "Canvas compositing" section
+    /// of the documentation for [ContextOptions].
     pub fn disable_alpha(
         self,
     ) -> ContextOptionsBuilder<DefaultMultisampleRenderTarget<DefaultRGBBuffer, Ds>> {
@@ -140,6 +410,8 @@ impl<Ds> ContextOptionsBuilder<DefaultMultisampleRenderTarget<DefaultRGBABuffer,
             preserve_drawbuffer: self.preserve_drawbuffer,
             premultiplied_alpha: self.premultiplied_alpha,
             power_preference: self.power_preference,
+            debug: self.debug,
+            mipmap_hint: self.mipmap_hint,
         }
     }
 }
@@ -154,6 +426,8 @@ impl<C> ContextOptionsBuilder<DefaultMultisampleRenderTarget<C, ()>> {
             preserve_drawbuffer: self.preserve_drawbuffer,
             premultiplied_alpha: self.premultiplied_alpha,
             power_preference: self.power_preference,
+            debug: self.debug,
+            mipmap_hint: self.mipmap_hint,
         }
     }
 
@@ -166,6 +440,8 @@ impl<C> ContextOptionsBuilder<DefaultMultisampleRenderTarget<C, ()>> {
             preserve_drawbuffer: self.preserve_drawbuffer,
             premultiplied_alpha: self.premultiplied_alpha,
             power_preference: self.power_preference,
+            debug: self.debug,
+            mipmap_hint: self.mipmap_hint,
         }
     }
 }
@@ -180,6 +456,8 @@ impl<C> ContextOptionsBuilder<DefaultMultisampleRenderTarget<C, DefaultDepthBuff
             preserve_drawbuffer: self.preserve_drawbuffer,
             premultiplied_alpha: self.premultiplied_alpha,
             power_preference: self.power_preference,
+            debug: self.debug,
+            mipmap_hint: self.mipmap_hint,
         }
     }
 }
@@ -194,11 +472,64 @@ impl<C> ContextOptionsBuilder<DefaultMultisampleRenderTarget<C, DefaultStencilBu
             preserve_drawbuffer: self.preserve_drawbuffer,
             premultiplied_alpha: self.premultiplied_alpha,
             power_preference: self.power_preference,
+            debug: self.debug,
+            mipmap_hint: self.mipmap_hint,
+        }
+    }
+}
+
+impl<C, Ds> ContextOptionsBuilder<DefaultRenderTarget<C, Ds>> {
+    /// Enables antialiasing for the default render target (antialiasing is enabled by default,
+    /// this reverses [disable_antialias]).
+    ///
+    /// The default render target becomes a [DefaultMultisampleRenderTarget]. Note that WebGL only
+    /// allows antialiasing of the default render target to be enabled or disabled: the number of
+    /// samples is chosen by the browser and may be different on different systems (it may be
+    /// queried with [DefaultMultisampleRenderTarget::samples] after the context was initialized).
+    /// If you require explicit control over the sample count, disable antialiasing for the default
+    /// render target and instead render to a [MultisampleRenderTarget] with the sample count of
+    /// your choice (see [RenderingContext::supported_samples]), then resolve it onto the default
+    /// render target (see `examples/8_resolve`):
+    ///
+    /// ```
+    /// use web_glitz::runtime::ContextOptions;
+    ///
+    /// let explicit_samples = Some(4);
+    ///
+    /// let builder = ContextOptions::begin().disable_antialias().enable_depth();
+    ///
+    /// if explicit_samples.is_some() {
+    ///     // Render to a multisample render target with the requested sample count and resolve it
+    ///     // onto a default render target that is not antialiased.
+    ///     let options = builder.finish();
+    /// } else {
+    ///     // Let the browser antialias the default render target.
+    ///     let options = builder.enable_antialias().finish();
+    /// }
+    /// ```
+    ///
+    /// [disable_antialias]: ContextOptionsBuilder::disable_antialias
+    /// [MultisampleRenderTarget]: crate::rendering::MultisampleRenderTarget
+    /// [RenderingContext::supported_samples]: crate::runtime::RenderingContext::supported_samples
+    pub fn enable_antialias(self) -> ContextOptionsBuilder<DefaultMultisampleRenderTarget<C, Ds>> {
+        ContextOptionsBuilder {
+            render_target: marker::PhantomData,
+            fail_if_major_performance_caveat: self.fail_if_major_performance_caveat,
+            preserve_drawbuffer: self.preserve_drawbuffer,
+            premultiplied_alpha: self.premultiplied_alpha,
+            power_preference: self.power_preference,
+            debug: self.debug,
+            mipmap_hint: self.mipmap_hint,
         }
     }
 }
 
 impl<Ds> ContextOptionsBuilder<DefaultRenderTarget<DefaultRGBABuffer, Ds>> {
+    /// Disables the alpha channel of the default render target.
+    ///
+    /// The default render target's color buffer becomes a [DefaultRGBBuffer] and the canvas is
+    /// always composited with the page as fully opaque. See also the "Canvas compositing" section
+    /// of the documentation for [ContextOptions].
     pub fn disable_alpha(self) -> ContextOptionsBuilder<DefaultRenderTarget<DefaultRGBBuffer, Ds>> {
         ContextOptionsBuilder {
             render_target: marker::PhantomData,
@@ -206,6 +537,8 @@ impl<Ds> ContextOptionsBuilder<DefaultRenderTarget<DefaultRGBABuffer, Ds>> {
             preserve_drawbuffer: self.preserve_drawbuffer,
             premultiplied_alpha: self.premultiplied_alpha,
             power_preference: self.power_preference,
+            debug: self.debug,
+            mipmap_hint: self.mipmap_hint,
         }
     }
 }
@@ -218,6 +551,8 @@ impl<C> ContextOptionsBuilder<DefaultRenderTarget<C, ()>> {
             preserve_drawbuffer: self.preserve_drawbuffer,
             premultiplied_alpha: self.premultiplied_alpha,
             power_preference: self.power_preference,
+            debug: self.debug,
+            mipmap_hint: self.mipmap_hint,
         }
     }
 
@@ -230,6 +565,8 @@ impl<C> ContextOptionsBuilder<DefaultRenderTarget<C, ()>> {
             preserve_drawbuffer: self.preserve_drawbuffer,
             premultiplied_alpha: self.premultiplied_alpha,
             power_preference: self.power_preference,
+            debug: self.debug,
+            mipmap_hint: self.mipmap_hint,
         }
     }
 }
@@ -244,6 +581,8 @@ impl<C> ContextOptionsBuilder<DefaultRenderTarget<C, DefaultDepthBuffer>> {
             preserve_drawbuffer: self.preserve_drawbuffer,
             premultiplied_alpha: self.premultiplied_alpha,
             power_preference: self.power_preference,
+            debug: self.debug,
+            mipmap_hint: self.mipmap_hint,
         }
     }
 }
@@ -258,6 +597,8 @@ impl<C> ContextOptionsBuilder<DefaultRenderTarget<C, DefaultStencilBuffer>> {
             preserve_drawbuffer: self.preserve_drawbuffer,
             premultiplied_alpha: self.premultiplied_alpha,
             power_preference: self.power_preference,
+            debug: self.debug,
+            mipmap_hint: self.mipmap_hint,
         }
     }
 }
@@ -0,0 +1,225 @@
+use std::any;
+use std::cell::RefCell;
+use std::fmt;
+
+use web_sys::WebGl2RenderingContext as Gl;
+
+use crate::runtime::Connection;
+use crate::task::{GpuTask, Progress};
+
+/// Error reported by a context with debugging enabled when a command produced a WebGL error.
+///
+/// See [ContextOptionsBuilder::debug].
+///
+/// [ContextOptionsBuilder::debug]: crate::runtime::ContextOptionsBuilder::debug
+#[derive(Clone, PartialEq, Debug)]
+pub struct CommandError {
+    code: u32,
+    command: &'static str,
+    index: usize,
+    execution_context: &'static str,
+}
+
+impl CommandError {
+    /// The WebGL error code (as returned by `getError`).
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    /// The name of the command that produced the error.
+    pub fn command(&self) -> &'static str {
+        self.command
+    }
+
+    /// The index of the command in the order in which the commands in the submitted task were
+    /// executed, starting at `0`.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let code = match self.code {
+            Gl::INVALID_ENUM => "INVALID_ENUM",
+            Gl::INVALID_VALUE => "INVALID_VALUE",
+            Gl::INVALID_OPERATION => "INVALID_OPERATION",
+            Gl::INVALID_FRAMEBUFFER_OPERATION => "INVALID_FRAMEBUFFER_OPERATION",
+            Gl::OUT_OF_MEMORY => "OUT_OF_MEMORY",
+            Gl::CONTEXT_LOST_WEBGL => "CONTEXT_LOST_WEBGL",
+            _ => "UNKNOWN_ERROR",
+        };
+
+        write!(
+            f,
+            "{} #{} in {} failed: {} (0x{:04X})",
+            self.command, self.index, self.execution_context, code, self.code
+        )
+    }
+}
+
+struct Frame {
+    command: &'static str,
+    execution_context: &'static str,
+    has_children: bool,
+}
+
+struct Tracker {
+    gl: Gl,
+    frames: Vec<Frame>,
+    command_count: usize,
+    error: Option<CommandError>,
+}
+
+impl Tracker {
+    fn check(&mut self, frame: &Frame) {
+        let code = self.gl.get_error();
+
+        // Only the first error is recorded; it is the most likely to identify the command that
+        // actually failed. Later errors are usually a consequence of the first.
+        if code != Gl::NO_ERROR && self.error.is_none() {
+            self.error = Some(CommandError {
+                code,
+                command: frame.command,
+                index: self.command_count,
+                execution_context: frame.execution_context,
+            });
+        }
+    }
+}
+
+thread_local!(static TRACKER: RefCell<Option<Tracker>> = RefCell::new(None));
+
+/// Progresses a top-level `task` that was submitted to the context associated with the
+/// `connection`, checking for errors after each command if debugging is enabled for the
+/// connection.
+pub(crate) fn progress_job<T>(task: &mut T, connection: &mut Connection) -> Progress<T::Output>
+where
+    T: GpuTask<Connection>,
+{
+    if !connection.debug() {
+        return task.progress(connection);
+    }
+
+    let gl = unsafe { connection.unpack().0.clone() };
+
+    // Clear any error that did not originate from a command (e.g. from direct use of the WebGL
+    // context), so that it is not attributed to the first command.
+    while gl.get_error() != Gl::NO_ERROR {}
+
+    TRACKER.with(|tracker| {
+        *tracker.borrow_mut() = Some(Tracker {
+            gl,
+            frames: Vec::new(),
+            command_count: 0,
+            error: None,
+        })
+    });
+
+    let progress = track::<T, Connection, _>(|| task.progress(connection));
+
+    let tracker = TRACKER.with(|tracker| tracker.borrow_mut().take());
+
+    if let Some(error) = tracker.and_then(|tracker| tracker.error) {
+        web_sys::console::error_1(&error.to_string().into());
+
+        connection.push_command_error(error);
+    }
+
+    progress
+}
+
+/// Calls `f`, which progresses a (sub-)task of type `T` with an execution context of type `Ec`.
+///
+/// If error tracking is active, then checks for errors after `f` returns and attributes any error
+/// to the (sub-)task if it did not itself progress any tracked sub-tasks.
+pub(crate) fn track<T, Ec, F>(f: F) -> Progress<T::Output>
+where
+    T: GpuTask<Ec>,
+    F: FnOnce() -> Progress<T::Output>,
+{
+    let active = TRACKER.with(|tracker| {
+        if let Some(tracker) = tracker.borrow_mut().as_mut() {
+            if let Some(parent) = tracker.frames.last_mut() {
+                parent.has_children = true;
+            }
+
+            tracker.frames.push(Frame {
+                command: short_type_name::<T>(),
+                execution_context: execution_context_name::<Ec>(),
+                has_children: false,
+            });
+
+            true
+        } else {
+            false
+        }
+    });
+
+    let progress = f();
+
+    if active {
+        TRACKER.with(|tracker| {
+            if let Some(tracker) = tracker.borrow_mut().as_mut() {
+                let frame = tracker.frames.pop().unwrap();
+
+                // Only leaf tasks are considered to be commands; any errors produced by their
+                // sub-tasks have already been attributed to those sub-tasks.
+                if !frame.has_children {
+                    tracker.check(&frame);
+                    tracker.command_count += 1;
+                }
+            }
+        });
+    }
+
+    progress
+}
+
+fn short_type_name<T>() -> &'static str {
+    let name = any::type_name::<T>();
+    let name = &name[..name.find('<').unwrap_or(name.len())];
+
+    &name[name.rfind("::").map(|i| i + 2).unwrap_or(0)..]
+}
+
+fn execution_context_name<Ec>() -> &'static str {
+    match short_type_name::<Ec>() {
+        "RenderPassContext" => "render pass",
+        "PipelineTaskContext" => "pipeline task",
+        _ => "task",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct PlainCommand;
+
+    struct GenericCommand<T>(T);
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(short_type_name::<PlainCommand>(), "PlainCommand");
+        assert_eq!(
+            short_type_name::<GenericCommand<Vec<u8>>>(),
+            "GenericCommand"
+        );
+    }
+
+    #[test]
+    fn test_command_error_display() {
+        let error = CommandError {
+            code: Gl::INVALID_OPERATION,
+            command: "DrawCommand",
+            index: 3,
+            execution_context: "pipeline task",
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "DrawCommand #3 in pipeline task failed: INVALID_OPERATION (0x0502)"
+        );
+    }
+}
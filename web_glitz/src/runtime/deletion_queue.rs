@@ -0,0 +1,123 @@
+use std::cell::RefCell;
+use std::mem;
+use std::rc::{Rc, Weak};
+
+use crate::runtime::Connection;
+use crate::task::{BoxedTask, GpuTask};
+
+/// Holds the deletion commands for GPU objects that have been dropped, but that have not yet been
+/// submitted to the context that owns them.
+///
+/// Owned by the context; resources only hold a weak [DeletionQueue] handle to it.
+pub(crate) struct PendingDeletions {
+    tasks: RefCell<Vec<BoxedTask<()>>>,
+}
+
+impl PendingDeletions {
+    pub(crate) fn new() -> Self {
+        PendingDeletions {
+            tasks: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns a new weak [DeletionQueue] handle to these pending deletions.
+    pub(crate) fn handle(self: &Rc<Self>) -> DeletionQueue {
+        DeletionQueue {
+            pending: Rc::downgrade(self),
+        }
+    }
+
+    /// Removes all pending deletion commands, returning them in the order in which they were
+    /// scheduled.
+    pub(crate) fn take(&self) -> Vec<BoxedTask<()>> {
+        // Swap the tasks out rather than draining in place: executing a deletion command may drop
+        // other resources, which will try to schedule new deletions.
+        mem::replace(&mut *self.tasks.borrow_mut(), Vec::new())
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.tasks.borrow().len()
+    }
+}
+
+/// A weak handle to a [RenderingContext]'s queue of pending GPU object deletions.
+///
+/// GPU resources (buffers, textures, samplers, pipelines, etc.) hold one of these handles rather
+/// than a strong reference to their context, so that dropping a resource never keeps its context
+/// alive. When a resource is dropped, the command that deletes its GPU object is added to the
+/// queue; the queue is drained on the context's thread the next time a task is submitted to the
+/// context (see [RenderingContext::submit]), or when the context is flushed
+/// ([RenderingContext::flush]) or finished ([RenderingContext::finish]).
+///
+/// If the context has already been dropped when a resource is dropped, then no deletion is
+/// scheduled: the GPU object was already released together with the context.
+///
+/// [RenderingContext]: crate::runtime::RenderingContext
+/// [RenderingContext::submit]: crate::runtime::RenderingContext::submit
+/// [RenderingContext::flush]: crate::runtime::RenderingContext::flush
+/// [RenderingContext::finish]: crate::runtime::RenderingContext::finish
+#[derive(Clone)]
+pub struct DeletionQueue {
+    pending: Weak<PendingDeletions>,
+}
+
+impl DeletionQueue {
+    /// Adds the `task` to the queue, returns `false` if the context that owns the queue has
+    /// already been dropped (in which case the `task` is discarded), `true` otherwise.
+    pub(crate) fn schedule<T>(&self, task: T) -> bool
+    where
+        T: GpuTask<Connection, Output = ()> + 'static,
+    {
+        if let Some(pending) = self.pending.upgrade() {
+            pending.tasks.borrow_mut().push(BoxedTask::new(task));
+
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::task::{ContextId, Progress};
+
+    struct NoopDropCommand;
+
+    unsafe impl GpuTask<Connection> for NoopDropCommand {
+        type Output = ();
+
+        fn context_id(&self) -> ContextId {
+            ContextId::Any
+        }
+
+        fn progress(&mut self, _connection: &mut Connection) -> Progress<Self::Output> {
+            Progress::Finished(())
+        }
+    }
+
+    #[test]
+    fn test_drop_resource_before_context() {
+        let pending = Rc::new(PendingDeletions::new());
+        let queue = pending.handle();
+
+        assert!(queue.schedule(NoopDropCommand));
+        assert!(queue.schedule(NoopDropCommand));
+        assert_eq!(pending.len(), 2);
+
+        assert_eq!(pending.take().len(), 2);
+        assert_eq!(pending.len(), 0);
+    }
+
+    #[test]
+    fn test_drop_resource_after_context() {
+        let pending = Rc::new(PendingDeletions::new());
+        let queue = pending.handle();
+
+        mem::drop(pending);
+
+        assert!(!queue.schedule(NoopDropCommand));
+    }
+}
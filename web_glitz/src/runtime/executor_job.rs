@@ -1,10 +1,14 @@
 use futures::channel::oneshot::{channel, Sender};
 
+use crate::runtime::debug;
 use crate::runtime::{Connection, Execution};
 use crate::task::{GpuTask, Progress};
 
 pub(crate) trait ExecutorJob {
     fn progress(&mut self, connection: &mut Connection) -> JobState;
+
+    /// Whether the job's task was cancelled, see [GpuTask::is_cancelled].
+    fn is_cancelled(&self) -> bool;
 }
 
 #[derive(PartialEq)]
@@ -26,7 +30,7 @@ where
     T: GpuTask<Connection>,
 {
     fn progress(&mut self, connection: &mut Connection) -> JobState {
-        match self.task.progress(connection) {
+        match debug::progress_job(&mut self.task, connection) {
             Progress::Finished(res) => {
                 self.result_tx
                     .take()
@@ -39,6 +43,10 @@ where
             Progress::ContinueFenced => JobState::ContinueFenced,
         }
     }
+
+    fn is_cancelled(&self) -> bool {
+        self.task.is_cancelled()
+    }
 }
 
 pub(crate) fn job<T>(task: T) -> (Job<T>, Execution<T::Output>)
@@ -53,3 +61,34 @@ where
 
     (job, Execution::Pending(rx))
 }
+
+/// A job for a task of which the output is discarded, see
+/// [RenderingContext::submit_and_forget].
+///
+/// [RenderingContext::submit_and_forget]: crate::runtime::RenderingContext::submit_and_forget
+pub(crate) struct DetachedJob<T> {
+    task: T,
+}
+
+impl<T> ExecutorJob for DetachedJob<T>
+where
+    T: GpuTask<Connection, Output = ()>,
+{
+    fn progress(&mut self, connection: &mut Connection) -> JobState {
+        match debug::progress_job(&mut self.task, connection) {
+            Progress::Finished(()) => JobState::Finished,
+            Progress::ContinueFenced => JobState::ContinueFenced,
+        }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.task.is_cancelled()
+    }
+}
+
+pub(crate) fn detached_job<T>(task: T) -> DetachedJob<T>
+where
+    T: GpuTask<Connection, Output = ()>,
+{
+    DetachedJob { task }
+}
@@ -40,6 +40,27 @@ impl FencedTaskQueue {
             gl.clone()
         };
 
+        // Cancelled jobs are removed right away, rather than once their fences are signalled. A
+        // cancelled job is progressed one final time so that it can finish (e.g. a `Cancellable`
+        // task finishes with `None`).
+        let mut index = 0;
+
+        while index < self.queue.len() {
+            if self.queue[index].1.is_cancelled() {
+                let (fence, mut job) = self.queue.remove(index).unwrap();
+
+                gl.delete_sync(Some(&fence));
+
+                if JobState::ContinueFenced == job.progress(&mut self.connection.borrow_mut()) {
+                    let new_fence = gl.fence_sync(Gl::SYNC_GPU_COMMANDS_COMPLETE, 0).unwrap();
+
+                    self.queue.push_back((new_fence, job));
+                }
+            } else {
+                index += 1;
+            }
+        }
+
         while let Some((fence, _)) = self.queue.front() {
             let sync_status = gl
                 .get_sync_parameter(fence, Gl::SYNC_STATUS)
@@ -47,7 +68,11 @@ impl FencedTaskQueue {
                 .unwrap() as u32;
 
             if sync_status == Gl::SIGNALED {
-                let (_, mut job) = self.queue.pop_front().unwrap();
+                let (fence, mut job) = self.queue.pop_front().unwrap();
+
+                // The sync object has served its purpose; delete it rather than waiting for it to
+                // be garbage collected.
+                gl.delete_sync(Some(&fence));
 
                 if JobState::ContinueFenced == job.progress(&mut self.connection.borrow_mut()) {
                     let new_fence = gl.fence_sync(Gl::SYNC_GPU_COMMANDS_COMPLETE, 0).unwrap();
@@ -66,6 +91,16 @@ impl FencedTaskQueue {
 
         self.queue.is_empty()
     }
+
+    /// Removes all jobs from the queue without progressing them, deleting their sync objects.
+    fn clear(&mut self) {
+        let connection = self.connection.borrow();
+        let (gl, _) = unsafe { connection.unpack() };
+
+        for (fence, _) in self.queue.drain(..) {
+            gl.delete_sync(Some(&fence));
+        }
+    }
 }
 
 pub(crate) struct JsTimeoutFencedTaskRunner {
@@ -74,6 +109,21 @@ pub(crate) struct JsTimeoutFencedTaskRunner {
 }
 
 impl JsTimeoutFencedTaskRunner {
+    /// Checks the fences for the queued jobs immediately, rather than waiting for the next timeout,
+    /// and progresses the jobs for which the fence has become signalled.
+    ///
+    /// Does nothing if the queue or the connection is currently in use (e.g. when called while a
+    /// task is being executed).
+    pub(crate) fn run_now(&self) {
+        if let Ok(mut queue) = self.queue.try_borrow_mut() {
+            if queue.connection.try_borrow_mut().is_err() {
+                return;
+            }
+
+            queue.run();
+        }
+    }
+
     pub(crate) fn new(connection: Rc<RefCell<Connection>>) -> Self {
         JsTimeoutFencedTaskRunner {
             queue: Rc::new(RefCell::new(FencedTaskQueue::new(connection))),
@@ -81,6 +131,12 @@ impl JsTimeoutFencedTaskRunner {
         }
     }
 
+    /// Stops the loop that checks the fences and discards all queued jobs without finishing them.
+    pub(crate) fn clear(&mut self) {
+        self.loop_handle = None;
+        self.queue.borrow_mut().clear();
+    }
+
     pub(crate) fn schedule(&mut self, job: Box<dyn ExecutorJob>) {
         self.queue.borrow_mut().push(job);
 
@@ -0,0 +1,205 @@
+use web_sys::WebGl2RenderingContext as Gl;
+
+/// Describes the capabilities of an internal image format on a specific rendering context.
+///
+/// See [RenderingContext::is_format_supported], [RenderingContext::is_color_renderable] and
+/// [RenderingContext::is_texture_filterable].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FormatSupport {
+    pub supported: bool,
+    pub color_renderable: bool,
+    pub texture_filterable: bool,
+}
+
+impl FormatSupport {
+    /// Determines the capabilities of the format identified by `internal_format` on the context.
+    ///
+    /// Note that this may enable the `EXT_color_buffer_float`, `EXT_color_buffer_half_float` and
+    /// `OES_texture_float_linear` extensions on the context, if they are available.
+    pub(crate) fn probe(gl: &Gl, internal_format: u32) -> Self {
+        let supported = is_core_format(internal_format);
+
+        let color_renderable = if is_core_color_renderable(internal_format) {
+            true
+        } else if is_extended_color_renderable(internal_format) {
+            has_extension(gl, "EXT_color_buffer_float")
+                || (is_half_float_color_renderable(internal_format)
+                    && has_extension(gl, "EXT_color_buffer_half_float"))
+        } else {
+            false
+        };
+
+        // Confirm that the implementation reports sample counts for the format (this is only valid
+        // for renderable formats, so we only query formats that should be renderable to avoid
+        // generating an error).
+        let color_renderable = color_renderable
+            && gl
+                .get_internalformat_parameter(Gl::RENDERBUFFER, internal_format, Gl::SAMPLES)
+                .map(|samples| !samples.is_null() && !samples.is_undefined())
+                .unwrap_or(false);
+
+        let texture_filterable = if is_core_filterable(internal_format) {
+            true
+        } else if is_extended_filterable(internal_format) {
+            has_extension(gl, "OES_texture_float_linear")
+        } else {
+            false
+        };
+
+        FormatSupport {
+            supported,
+            color_renderable,
+            texture_filterable,
+        }
+    }
+}
+
+fn has_extension(gl: &Gl, name: &str) -> bool {
+    gl.get_extension(name).ok().flatten().is_some()
+}
+
+fn is_core_format(internal_format: u32) -> bool {
+    match internal_format {
+        Gl::R8
+        | Gl::R16F
+        | Gl::R32F
+        | Gl::R8UI
+        | Gl::R8I
+        | Gl::R16UI
+        | Gl::R16I
+        | Gl::R32UI
+        | Gl::R32I
+        | Gl::RG8
+        | Gl::RG16F
+        | Gl::RG32F
+        | Gl::RG8UI
+        | Gl::RG8I
+        | Gl::RG16UI
+        | Gl::RG16I
+        | Gl::RG32UI
+        | Gl::RG32I
+        | Gl::RGB8
+        | Gl::SRGB8
+        | Gl::RGB565
+        | Gl::R11F_G11F_B10F
+        | Gl::RGB9_E5
+        | Gl::RGB16F
+        | Gl::RGB32F
+        | Gl::RGB8UI
+        | Gl::RGB8I
+        | Gl::RGB16UI
+        | Gl::RGB16I
+        | Gl::RGB32UI
+        | Gl::RGB32I
+        | Gl::RGBA8
+        | Gl::SRGB8_ALPHA8
+        | Gl::RGB5_A1
+        | Gl::RGBA4
+        | Gl::RGB10_A2
+        | Gl::RGB10_A2UI
+        | Gl::RGBA16F
+        | Gl::RGBA32F
+        | Gl::RGBA8UI
+        | Gl::RGBA8I
+        | Gl::RGBA16UI
+        | Gl::RGBA16I
+        | Gl::RGBA32UI
+        | Gl::RGBA32I
+        | Gl::DEPTH_COMPONENT16
+        | Gl::DEPTH_COMPONENT24
+        | Gl::DEPTH_COMPONENT32F
+        | Gl::STENCIL_INDEX8
+        | Gl::DEPTH24_STENCIL8
+        | Gl::DEPTH32F_STENCIL8
+        | Gl::LUMINANCE
+        | Gl::LUMINANCE_ALPHA
+        | Gl::ALPHA => true,
+        _ => false,
+    }
+}
+
+fn is_core_color_renderable(internal_format: u32) -> bool {
+    match internal_format {
+        Gl::R8
+        | Gl::RG8
+        | Gl::RGB8
+        | Gl::RGBA8
+        | Gl::SRGB8_ALPHA8
+        | Gl::RGBA4
+        | Gl::RGB565
+        | Gl::RGB5_A1
+        | Gl::RGB10_A2
+        | Gl::R8I
+        | Gl::R16I
+        | Gl::R32I
+        | Gl::RG8I
+        | Gl::RG16I
+        | Gl::RG32I
+        | Gl::RGBA8I
+        | Gl::RGBA16I
+        | Gl::RGBA32I
+        | Gl::R8UI
+        | Gl::R16UI
+        | Gl::R32UI
+        | Gl::RG8UI
+        | Gl::RG16UI
+        | Gl::RG32UI
+        | Gl::RGBA8UI
+        | Gl::RGB10_A2UI
+        | Gl::RGBA16UI
+        | Gl::RGBA32UI => true,
+        _ => false,
+    }
+}
+
+fn is_extended_color_renderable(internal_format: u32) -> bool {
+    match internal_format {
+        Gl::R16F
+        | Gl::RG16F
+        | Gl::RGBA16F
+        | Gl::R32F
+        | Gl::RG32F
+        | Gl::RGBA32F
+        | Gl::R11F_G11F_B10F => true,
+        _ => false,
+    }
+}
+
+fn is_half_float_color_renderable(internal_format: u32) -> bool {
+    match internal_format {
+        Gl::R16F | Gl::RG16F | Gl::RGBA16F => true,
+        _ => false,
+    }
+}
+
+fn is_core_filterable(internal_format: u32) -> bool {
+    match internal_format {
+        Gl::R8
+        | Gl::R16F
+        | Gl::RG8
+        | Gl::RG16F
+        | Gl::RGB8
+        | Gl::SRGB8
+        | Gl::RGB565
+        | Gl::R11F_G11F_B10F
+        | Gl::RGB9_E5
+        | Gl::RGB16F
+        | Gl::RGBA8
+        | Gl::SRGB8_ALPHA8
+        | Gl::RGB5_A1
+        | Gl::RGBA4
+        | Gl::RGB10_A2
+        | Gl::RGBA16F
+        | Gl::LUMINANCE
+        | Gl::LUMINANCE_ALPHA
+        | Gl::ALPHA => true,
+        _ => false,
+    }
+}
+
+fn is_extended_filterable(internal_format: u32) -> bool {
+    match internal_format {
+        Gl::R32F | Gl::RG32F | Gl::RGB32F | Gl::RGBA32F => true,
+        _ => false,
+    }
+}
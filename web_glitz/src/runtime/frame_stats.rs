@@ -0,0 +1,98 @@
+/// Statistics on the work a context has issued to the GPU.
+///
+/// Obtained with [SingleThreadedContext::debug_frame_stats]. The statistics are kept by the
+/// context on the CPU side as commands are executed, they do not involve any GPU queries, and are
+/// therefore cheap. The counts returned by a context are cumulative (they count all work issued
+/// since the context was created); to obtain the statistics for a single frame, capture the
+/// statistics at the start and at the end of the frame and use [since] to compute the difference:
+///
+/// ```
+/// # use web_glitz::runtime::single_threaded::SingleThreadedContext;
+/// # fn wrapper(context: &SingleThreadedContext) {
+/// let start = context.debug_frame_stats();
+///
+/// // Submit the tasks for this frame...
+///
+/// let frame_stats = context.debug_frame_stats().since(&start);
+///
+/// web_sys::console::log_1(&format!("{:?}", frame_stats).into());
+/// # }
+/// ```
+///
+/// Note that a task's work is only counted once the task has actually been executed; a task that
+/// was submitted, but that is still waiting on a fence, will not yet be included in the counts.
+///
+/// [SingleThreadedContext::debug_frame_stats]: crate::runtime::single_threaded::SingleThreadedContext::debug_frame_stats
+/// [since]: FrameStats::since
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct FrameStats {
+    /// The number of draw calls.
+    ///
+    /// Every [DrawRange] of a `draw_each` or `draw_indexed_each` command counts as a separate draw
+    /// call, regardless of whether or not the ranges were drawn with a single call to a
+    /// multi-draw extension.
+    ///
+    /// [DrawRange]: crate::rendering::DrawRange
+    pub draw_calls: u64,
+
+    /// The number of GL state changes, see [DynamicState::state_change_count].
+    ///
+    /// [DynamicState::state_change_count]: crate::runtime::state::DynamicState::state_change_count
+    pub state_changes: u64,
+
+    /// The number of times data was uploaded from client memory into a GPU buffer.
+    pub buffer_uploads: u64,
+
+    /// The number of vertices (or indices, for indexed draws) streamed by draw calls, summed over
+    /// all instances.
+    pub vertices: u64,
+
+    /// An estimate of the number of triangles assembled by draw calls, summed over all instances.
+    ///
+    /// Estimated from the vertex count and the primitive topology of each draw; draws that
+    /// assemble points or lines do not contribute to this count.
+    pub triangles: u64,
+}
+
+impl FrameStats {
+    /// Returns the statistics for the work issued after `earlier` was captured.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use web_glitz::runtime::FrameStats;
+    ///
+    /// let earlier = FrameStats {
+    ///     draw_calls: 10,
+    ///     state_changes: 40,
+    ///     buffer_uploads: 2,
+    ///     vertices: 600,
+    ///     triangles: 200,
+    /// };
+    ///
+    /// let later = FrameStats {
+    ///     draw_calls: 15,
+    ///     state_changes: 52,
+    ///     buffer_uploads: 3,
+    ///     vertices: 900,
+    ///     triangles: 300,
+    /// };
+    ///
+    /// assert_eq!(later.since(&earlier), FrameStats {
+    ///     draw_calls: 5,
+    ///     state_changes: 12,
+    ///     buffer_uploads: 1,
+    ///     vertices: 300,
+    ///     triangles: 100,
+    /// });
+    /// ```
+    pub fn since(&self, earlier: &FrameStats) -> FrameStats {
+        FrameStats {
+            draw_calls: self.draw_calls.saturating_sub(earlier.draw_calls),
+            state_changes: self.state_changes.saturating_sub(earlier.state_changes),
+            buffer_uploads: self.buffer_uploads.saturating_sub(earlier.buffer_uploads),
+            vertices: self.vertices.saturating_sub(earlier.vertices),
+            triangles: self.triangles.saturating_sub(earlier.triangles),
+        }
+    }
+}
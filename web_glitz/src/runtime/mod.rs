@@ -3,19 +3,45 @@
 //! WebGlitz currently only provides a single threaded runtime that can run on the main WASM thread,
 //! see the documentation for the [single_threaded] module for details.
 
+mod animation_frame;
+pub use self::animation_frame::{AnimationHandle, FrameContext};
+
 mod context_options;
-pub use self::context_options::{ContextOptions, ContextOptionsBuilder, PowerPreference};
+pub use self::context_options::{
+    ContextOptions, ContextOptionsBuilder, MipmapHint, PowerPreference,
+};
+
+pub(crate) mod debug;
+pub use self::debug::CommandError;
+
+mod deletion_queue;
+#[doc(hidden)]
+pub use self::deletion_queue::DeletionQueue;
+pub(crate) use self::deletion_queue::PendingDeletions;
 
 mod rendering_context;
 pub use self::rendering_context::{
-    Connection, CreateGraphicsPipelineError, Execution, RenderingContext, ShaderCompilationError,
-    UnsupportedSampleCount,
+    Connection, CreateGraphicsPipelineError, Execution, PollHandle, RenderingContext,
+    ResourceLimit, ShaderCompilationError, UnsupportedSampleCount,
 };
 
+mod format_support;
+#[doc(hidden)]
+pub use self::format_support::FormatSupport;
+
+mod frame_stats;
+pub use self::frame_stats::FrameStats;
+
+mod resize_observer;
+pub use self::resize_observer::{match_canvas_to_display, ResizeObserverHandle};
+
 pub mod single_threaded;
 
 pub mod state;
 
+mod state_snapshot;
+pub use self::state_snapshot::{ObjectHandle, StateMismatch, StateSnapshot};
+
 mod supported_samples;
 pub use self::supported_samples::{SupportedSamples, SupportedSamplesIter};
 
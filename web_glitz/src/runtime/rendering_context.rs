@@ -1,17 +1,26 @@
 use std::borrow::Borrow;
+use std::cell::RefCell;
+use std::fmt;
+use std::mem;
+use std::ops::Deref;
 use std::pin::Pin;
+use std::rc::{Rc, Weak};
 use std::task::Poll;
 
 use futures::channel::oneshot::Receiver;
 use futures::future::Future;
 use futures::task::Context;
 
-use web_sys::WebGl2RenderingContext as Gl;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlImageElement, WebGl2RenderingContext as Gl, WebglMultiDraw};
 
-use crate::buffer::{Buffer, IntoBuffer, UsageHint};
+use crate::buffer::{
+    Buffer, BufferView, FrameAllocator, IntoBuffer, MapRead, SharedBufferPool, UsageHint,
+};
 use crate::extensions::Extension;
 use crate::image::format::{
-    InternalFormat, Multisamplable, Multisample, RenderbufferFormat, TextureFormat,
+    Filterable, FloatRenderable, InternalFormat, Multisamplable, Multisample, PixelUnpack,
+    RenderbufferFormat, TextureFormat,
 };
 use crate::image::renderbuffer::{Renderbuffer, RenderbufferDescriptor};
 use crate::image::sampler::{
@@ -22,10 +31,12 @@ use crate::image::texture_2d::{Texture2D, Texture2DDescriptor};
 use crate::image::texture_2d_array::{Texture2DArray, Texture2DArrayDescriptor};
 use crate::image::texture_3d::{Texture3D, Texture3DDescriptor};
 use crate::image::texture_cube::{TextureCube, TextureCubeDescriptor};
-use crate::image::MaxMipmapLevelsExceeded;
+use crate::image::{Image2DSource, MaxMipmapLevelsExceeded, MipmapLevels, UploadOptions};
+use crate::pipeline::graphics::vertex::index_buffer::narrow_indices;
 use crate::pipeline::graphics::{
-    FragmentShader, GraphicsPipeline, GraphicsPipelineDescriptor, IncompatibleVertexInputLayout,
-    IndexBuffer, IndexFormat, ShaderLinkingError, VertexShader,
+    AutoIndexBuffer, FragmentShader, GraphicsPipeline, GraphicsPipelineDescriptor,
+    IncompatibleVertexInputLayout, IndexBuffer, IndexFormat, IndexOutOfRange, IndexType,
+    ShaderLinkingError, VertexShader,
 };
 use crate::pipeline::resources::{
     BindGroup, EncodeBindableResourceGroup, IncompatibleResources, ResourceSlotIdentifier,
@@ -34,9 +45,16 @@ use crate::rendering::{
     MultisampleRenderTarget, MultisampleRenderTargetDescriptor, RenderTarget,
     RenderTargetDescriptor,
 };
+use crate::runtime::fenced::JsTimeoutFencedTaskRunner;
 use crate::runtime::state::{CreateProgramError, DynamicState};
-use crate::runtime::SupportedSamples;
-use crate::task::GpuTask;
+use crate::runtime::{
+    AnimationHandle, CommandError, DeletionQueue, FormatSupport, FrameContext, PendingDeletions,
+    SupportedSamples,
+};
+use crate::task::{
+    sequence, ContextId, GpuTask, GpuTaskExt, OptionTask, Progress, ValidationContext,
+    ValidationError,
+};
 use std::mem::MaybeUninit;
 
 /// Trait implemented by types that can serve as a WebGlitz rendering context.
@@ -62,11 +80,30 @@ use std::mem::MaybeUninit;
 ///    - [RenderTarget]s, see [create_render_target], [try_create_render_target],
 ///      [create_multisample_render_target] and [try_create_multisample_render_target].
 /// 3. Submission of [GpuTask]s to the GPU with [submit].
-/// 4. Extension initialization, see [get_extension].
+/// 4. Extension initialization, see [get_extension] and [with_extension].
 pub trait RenderingContext {
     /// Identifier that uniquely identifies this rendering context.
     fn id(&self) -> u64;
 
+    // Not part of the public API. Returns a weak handle to this context's queue of pending GPU
+    // object deletions, which GPU resources created by this context hold (rather than a strong
+    // reference to the context) to schedule the deletion of their GPU objects when they are
+    // dropped, see `DeletionQueue`.
+    #[doc(hidden)]
+    fn deletion_queue(&self) -> DeletionQueue;
+
+    // Not part of the public API. Returns the capabilities of the format `F` on this context, see
+    // [is_format_supported], [is_color_renderable] and [is_texture_filterable].
+    #[doc(hidden)]
+    fn format_support<F>(&self) -> FormatSupport
+    where
+        F: InternalFormat;
+
+    // Not part of the public API. Returns the buffer pool shared by the [FrameAllocator]s of this
+    // context, see [frame_allocator].
+    #[doc(hidden)]
+    fn shared_buffer_pool(&self) -> SharedBufferPool;
+
     /// Returns the requested extension, or `None` if the extension is not available on this
     /// context.
     ///
@@ -75,6 +112,46 @@ pub trait RenderingContext {
     where
         T: Extension;
 
+    /// Calls `f` with the requested extension if the extension is available on this context and
+    /// returns the result, or returns `None` without calling `f` if the extension is not
+    /// available.
+    ///
+    /// This is a convenience wrapper around [get_extension] that keeps the extension-dependent
+    /// code in a single closure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # use web_glitz::image::texture_2d::Texture2D;
+    /// # use web_glitz::image::format::RGBA16F;
+    /// # fn wrapper<Rc>(context: &Rc, mut texture: Texture2D<RGBA16F>) where Rc: RenderingContext {
+    /// use web_glitz::extensions::color_buffer_float::Extension as ColorBufferFloatExtension;
+    /// use web_glitz::rendering::{RenderTargetDescriptor, LoadOp, StoreOp};
+    ///
+    /// let render_target = context.with_extension(|extension: &ColorBufferFloatExtension| {
+    ///     context.create_render_target(RenderTargetDescriptor::new().attach_color_float(
+    ///         extension.extend(texture.base_level_mut()),
+    ///         LoadOp::Clear([0.0; 4]),
+    ///         StoreOp::Store,
+    ///     ))
+    /// });
+    ///
+    /// if render_target.is_none() {
+    ///     // Fall back to a render path that does not use a floating point render target.
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [get_extension]: RenderingContext::get_extension
+    fn with_extension<T, F, R>(&self, f: F) -> Option<R>
+    where
+        T: Extension,
+        F: FnOnce(&T) -> R,
+    {
+        self.get_extension::<T>().map(|extension| f(&extension))
+    }
+
     /// Returns information about the sampling grid sizes that are supported for the `format` in
     /// descending order of size.
     ///
@@ -94,10 +171,91 @@ pub trait RenderingContext {
     /// ```
     ///
     /// Here `context` is a [RenderingContext].
+    ///
+    /// See [SupportedSamples::iter] for enumerating the supported sampling grid sizes (e.g. to
+    /// populate a quality setting) and [SupportedSamples::contains_samples] for validating a
+    /// sampling grid size. See [SupportedSamples::best_common_samples] for choosing a sampling grid
+    /// size that is supported by several formats.
     fn supported_samples<F>(&self, format: F) -> SupportedSamples
     where
         F: InternalFormat + Multisamplable;
 
+    /// Returns `true` if the `format` may be used as the storage format for images on this context,
+    /// `false` otherwise.
+    ///
+    /// All formats defined in [web_glitz::image::format] are supported by WebGL 2.0; this returns
+    /// `false` for custom [InternalFormat] implementations that identify a format that WebGlitz
+    /// does not know to be supported.
+    ///
+    /// The result is cached per format.
+    ///
+    /// [web_glitz::image::format]: crate::image::format
+    fn is_format_supported<F>(&self, _format: F) -> bool
+    where
+        F: InternalFormat,
+    {
+        self.format_support::<F>().supported
+    }
+
+    /// Returns `true` if images that use the `format` may be attached as color attachments to a
+    /// render target on this context, `false` otherwise.
+    ///
+    /// Formats that store floating point values (e.g. [RGBA16F] or [R11F_G11F_B10F]) are only
+    /// color-renderable if the [color_buffer_float] extension is available; half-precision
+    /// floating point formats (e.g. [RGBA16F]) are also color-renderable if only the
+    /// [color_buffer_half_float] extension is available. Note that this method will enable these
+    /// extensions if they are available. This allows picking the best available format when the
+    /// renderer starts:
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
+    /// use web_glitz::image::format::RGBA16F;
+    ///
+    /// let use_hdr = context.is_color_renderable(RGBA16F);
+    ///
+    /// if !use_hdr {
+    ///     // Fall back to rendering to RGBA8 images.
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// Note that an image with a floating point format must still be wrapped with the
+    /// [color_buffer_float] (or [color_buffer_half_float]) extension object to attach it to a
+    /// render target.
+    ///
+    /// The result is cached per format.
+    ///
+    /// [RGBA16F]: crate::image::format::RGBA16F
+    /// [R11F_G11F_B10F]: crate::image::format::R11F_G11F_B10F
+    /// [color_buffer_float]: crate::extensions::color_buffer_float
+    /// [color_buffer_half_float]: crate::extensions::color_buffer_half_float
+    fn is_color_renderable<F>(&self, _format: F) -> bool
+    where
+        F: InternalFormat,
+    {
+        self.format_support::<F>().color_renderable
+    }
+
+    /// Returns `true` if textures that use the `format` may be sampled with linear filtering on
+    /// this context, `false` otherwise.
+    ///
+    /// Formats that store 32-bit floating point values (e.g. [RGBA32F]) are only filterable if the
+    /// [texture_float_linear] extension is available; note that this method will enable that
+    /// extension if it is available. Integer formats and depth-stencil formats are never
+    /// filterable.
+    ///
+    /// The result is cached per format.
+    ///
+    /// [RGBA32F]: crate::image::format::RGBA32F
+    /// [texture_float_linear]: crate::extensions::texture_float_linear
+    fn is_texture_filterable<F>(&self, _format: F) -> bool
+    where
+        F: InternalFormat,
+    {
+        self.format_support::<F>().texture_filterable
+    }
+
     /// Creates a new group of bindable resources.
     ///
     /// The resulting [BindGroup] may be bound to a pipeline such that all invocations of the
@@ -115,7 +273,7 @@ pub trait RenderingContext {
     /// [Resources] trait to define a typed bind group layout:
     ///
     /// ```
-    /// # #![feature(const_fn, const_loop, const_if_match, const_ptr_offset_from, const_transmute, ptr_offset_from)]
+    /// # #![feature(const_fn, const_loop, const_if_match, const_panic, const_ptr_offset_from, const_transmute, ptr_offset_from)]
     /// # use web_glitz::runtime::RenderingContext;
     /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
     /// use web_glitz::buffer::{Buffer, UsageHint};
@@ -146,6 +304,24 @@ pub trait RenderingContext {
     /// });
     /// # }
     /// ```
+    ///
+    /// For simple cases a single resource, or a tuple of resources, may also be used to create an
+    /// untyped bind group, which assigns slot indices sequentially (see
+    /// [EncodeBindableResourceGroup] for details):
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # use web_glitz::buffer::Buffer;
+    /// # use web_glitz::image::texture_2d::FloatSampledTexture2D;
+    /// # fn wrapper<Rc>(
+    /// #     context: &Rc,
+    /// #     uniform_buffer: &Buffer<std140::mat4x4>,
+    /// #     texture: FloatSampledTexture2D,
+    /// # ) where Rc: RenderingContext {
+    /// // Binds the buffer to slot `0` and the texture to slot `1`.
+    /// let bind_group = context.create_bind_group((uniform_buffer, texture));
+    /// # }
+    /// ```
     fn create_bind_group<T>(&self, resources: T) -> BindGroup<T>
     where
         T: EncodeBindableResourceGroup;
@@ -159,7 +335,7 @@ pub trait RenderingContext {
     /// pipeline):
     ///
     /// ```
-    /// # #![feature(const_fn, const_loop, const_if_match, const_ptr_offset_from, const_transmute, ptr_offset_from)]
+    /// # #![feature(const_fn, const_loop, const_if_match, const_panic, const_ptr_offset_from, const_transmute, ptr_offset_from)]
     /// # use web_glitz::runtime::RenderingContext;
     /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
     /// use web_glitz::buffer::UsageHint;
@@ -236,7 +412,7 @@ pub trait RenderingContext {
     /// # Examples
     ///
     /// ```
-    /// # #![feature(const_fn, const_loop, const_if_match, const_ptr_offset_from, const_transmute, ptr_offset_from)]
+    /// # #![feature(const_fn, const_loop, const_if_match, const_panic, const_ptr_offset_from, const_transmute, ptr_offset_from)]
     /// # use web_glitz::runtime::RenderingContext;
     /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
     /// use std::mem::MaybeUninit;
@@ -276,7 +452,7 @@ pub trait RenderingContext {
     /// # Examples
     ///
     /// ```
-    /// # #![feature(const_fn, const_loop, const_if_match, const_ptr_offset_from, const_transmute, ptr_offset_from)]
+    /// # #![feature(const_fn, const_loop, const_if_match, const_panic, const_ptr_offset_from, const_transmute, ptr_offset_from)]
     /// # use web_glitz::runtime::RenderingContext;
     /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
     /// use std::mem::MaybeUninit;
@@ -319,6 +495,32 @@ pub trait RenderingContext {
     where
         T: 'static;
 
+    /// Returns a [FrameAllocator] for allocating transient [Buffer]s from a pool of recycled
+    /// buffer objects.
+    ///
+    /// All [FrameAllocator]s returned from the same context share the same pool.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::buffer::UsageHint;
+    ///
+    /// let allocator = context.frame_allocator();
+    ///
+    /// let buffer = allocator.buffer([1.0, 2.0, 3.0, 4.0], UsageHint::StreamDraw);
+    /// # }
+    /// ```
+    ///
+    /// See [FrameAllocator] for details.
+    fn frame_allocator(&self) -> FrameAllocator<Self>
+    where
+        Self: Clone + 'static,
+    {
+        FrameAllocator::new(self.clone(), self.shared_buffer_pool())
+    }
+
     /// Creates a new [IndexBuffer].
     ///
     /// # Examples
@@ -348,6 +550,95 @@ pub trait RenderingContext {
         D: Borrow<[T]> + 'static,
         T: IndexFormat + 'static;
 
+    /// Creates a new [AutoIndexBuffer] that stores the `indices` using the smallest [IndexFormat]
+    /// that can address `vertex_count` vertices, or returns an error if any of the indices does not
+    /// fit that format.
+    ///
+    /// The format is selected with [IndexType::smallest_for_vertex_count]: `u8` indices for meshes
+    /// of at most 255 vertices, `u16` indices for meshes of at most 65535 vertices, and `u32`
+    /// indices otherwise. Returns an [IndexOutOfRange] error for the first index that exceeds the
+    /// [IndexType::max_index] for the selected format, rather than silently truncating it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
+    /// use web_glitz::buffer::UsageHint;
+    /// use web_glitz::pipeline::graphics::IndexType;
+    ///
+    /// let index_buffer = context
+    ///     .create_index_buffer_auto(&[0, 1, 2, 2, 1, 3], 4, UsageHint::StaticDraw)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(index_buffer.index_type(), IndexType::UnsignedByte);
+    /// # }
+    /// ```
+    ///
+    /// The resulting [AutoIndexBuffer] may be bound to a graphics pipeline task in the same way as
+    /// an [IndexBuffer].
+    fn create_index_buffer_auto(
+        &self,
+        indices: &[u32],
+        vertex_count: usize,
+        usage_hint: UsageHint,
+    ) -> Result<AutoIndexBuffer, IndexOutOfRange> {
+        let buffer = match IndexType::smallest_for_vertex_count(vertex_count) {
+            IndexType::UnsignedByte => AutoIndexBuffer::UnsignedByte(
+                self.create_index_buffer(narrow_indices::<u8>(indices)?, usage_hint),
+            ),
+            IndexType::UnsignedShort => AutoIndexBuffer::UnsignedShort(
+                self.create_index_buffer(narrow_indices::<u16>(indices)?, usage_hint),
+            ),
+            IndexType::UnsignedInt => AutoIndexBuffer::UnsignedInt(
+                self.create_index_buffer(narrow_indices::<u32>(indices)?, usage_hint),
+            ),
+        };
+
+        Ok(buffer)
+    }
+
+    /// Creates a new [IndexBuffer] from the indices produced by an iterator.
+    ///
+    /// The iterator is consumed once, when this method is called.
+    ///
+    /// # Example
+    ///
+    /// The following example lazily generates the indices for a grid of quads, where each quad is
+    /// drawn as 2 triangles:
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # use web_glitz::pipeline::graphics::IndexBuffer;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
+    /// use web_glitz::buffer::UsageHint;
+    ///
+    /// let columns = 16;
+    /// let rows = 16;
+    ///
+    /// let indices = (0..rows).flat_map(|row| {
+    ///     (0..columns).flat_map(move |column| {
+    ///         let a = row * (columns + 1) + column;
+    ///         let b = a + columns + 1;
+    ///
+    ///         vec![a, b, a + 1, a + 1, b, b + 1]
+    ///     })
+    /// });
+    ///
+    /// let index_buffer: IndexBuffer<u16> =
+    ///     context.create_index_buffer_from_iter(indices, UsageHint::StaticDraw);
+    /// # }
+    /// ```
+    fn create_index_buffer_from_iter<I, T>(&self, iter: I, usage_hint: UsageHint) -> IndexBuffer<T>
+    where
+        I: IntoIterator<Item = T>,
+        T: IndexFormat + 'static,
+    {
+        let data: Box<[T]> = iter.into_iter().collect();
+
+        self.create_index_buffer(data, usage_hint)
+    }
+
     /// Creates a new [IndexBuffer] with uninitialized data.
     ///
     /// # Examples
@@ -595,6 +886,139 @@ pub trait RenderingContext {
         descriptor: &GraphicsPipelineDescriptor<V, R, Tf>,
     ) -> Result<GraphicsPipeline<V, R, Tf>, CreateGraphicsPipelineError>;
 
+    /// Creates a new [GraphicsPipeline] from the given [GraphicsPipelineDescriptor] without
+    /// blocking on the compilation of the pipeline's shader program, and returns the result as a
+    /// [Future].
+    ///
+    /// Linking a shader program may take a significant amount of time.
+    /// [try_create_graphics_pipeline] waits for the link to complete, which blocks the main thread.
+    /// If the `KHR_parallel_shader_compile` extension is available, then this method instead starts
+    /// the link and only queries the result once the link has completed, which allows many
+    /// pipelines to be created (e.g. during a loading screen) without causing the page to hang. If
+    /// the extension is not available, then the pipeline is created synchronously (the returned
+    /// [Execution] will already be resolved). The default implementation always creates the
+    /// pipeline synchronously.
+    ///
+    /// Note that shaders in WebGL are compiled and linked lazily by most implementations; for the
+    /// best results, also create the [VertexShader] and [FragmentShader] for the pipeline ahead of
+    /// time.
+    ///
+    /// See [try_create_graphics_pipeline] for details on the errors that may occur.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # use web_glitz::pipeline::graphics::{GraphicsPipelineDescriptor, TypedVertexInputLayout};
+    /// # use web_glitz::pipeline::resources::TypedResourceBindingsLayout;
+    /// # fn wrapper<Rc, MyVertex, MyResources>(
+    /// #     context: &Rc,
+    /// #     descriptor: GraphicsPipelineDescriptor<MyVertex, MyResources, ()>
+    /// # ) where Rc: RenderingContext, MyVertex: TypedVertexInputLayout + 'static, MyResources: TypedResourceBindingsLayout + 'static {
+    /// use futures::future::FutureExt;
+    /// use wasm_bindgen_futures::spawn_local;
+    ///
+    /// let future_pipeline = context.try_create_graphics_pipeline_async(descriptor);
+    ///
+    /// spawn_local(future_pipeline.map(|result| {
+    ///     let graphics_pipeline = result.unwrap();
+    ///
+    ///     // Do something with the pipeline...
+    /// }));
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [VertexShader] or the [FragmentShader] provided for the pipeline belong to
+    /// a different [RenderingContext].
+    fn try_create_graphics_pipeline_async<V, R, Tf>(
+        &self,
+        descriptor: GraphicsPipelineDescriptor<V, R, Tf>,
+    ) -> Execution<Result<GraphicsPipeline<V, R, Tf>, CreateGraphicsPipelineError>>
+    where
+        V: 'static,
+        R: 'static,
+        Tf: 'static,
+    {
+        self.try_create_graphics_pipeline(&descriptor).into()
+    }
+
+    /// Returns a [GraphicsPipeline] for the given [GraphicsPipelineDescriptor] from this context's
+    /// pipeline cache, or creates a new pipeline and adds it to the cache if the cache does not yet
+    /// contain a pipeline for an identical descriptor.
+    ///
+    /// Two descriptors are identical if they use the same [VertexShader] and [FragmentShader]
+    /// objects, describe the same vertex input and resource bindings layouts, and specify the same
+    /// fixed-function state (primitive assembly, depth test, stencil test, scissor region, blending
+    /// and viewport). Descriptors are looked up by a hash of their contents, which makes this
+    /// suitable for requesting pipelines on demand (e.g. for each material permutation in a render
+    /// graph) without maintaining a separate map of pipelines.
+    ///
+    /// Only pipelines that do not record transform feedback can be cached: recording transform
+    /// feedback requires exclusive access to the pipeline (see
+    /// [GraphicsPipeline::record_transform_feedback]), which a shared pipeline cannot provide. Use
+    /// [try_create_graphics_pipeline] to create a pipeline that records transform feedback.
+    ///
+    /// Cached pipelines are kept alive by the cache, as are the shaders they were created with,
+    /// until the cache is cleared with [clear_graphics_pipeline_cache].
+    ///
+    /// See [try_create_graphics_pipeline] for details on the errors that may occur. If an error
+    /// occurs, nothing is added to the cache.
+    ///
+    /// Caching is opt-in for implementors: the default implementation does not cache pipelines and
+    /// creates a new pipeline on every call with [try_create_graphics_pipeline]. The
+    /// [SingleThreadedContext] maintains a cache.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # use web_glitz::pipeline::graphics::{GraphicsPipelineDescriptor, TypedVertexInputLayout};
+    /// # use web_glitz::pipeline::resources::TypedResourceBindingsLayout;
+    /// # fn wrapper<Rc, MyVertex, MyResources>(
+    /// #     context: &Rc,
+    /// #     descriptor: &GraphicsPipelineDescriptor<MyVertex, MyResources, ()>
+    /// # ) where Rc: RenderingContext, MyVertex: TypedVertexInputLayout + 'static, MyResources: TypedResourceBindingsLayout + 'static {
+    /// let pipeline = context.try_get_or_create_graphics_pipeline(descriptor).unwrap();
+    ///
+    /// // Requesting a pipeline for the same descriptor again returns the cached pipeline.
+    /// let cached_pipeline = context.try_get_or_create_graphics_pipeline(descriptor).unwrap();
+    ///
+    /// assert_eq!(pipeline, cached_pipeline);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [VertexShader] or the [FragmentShader] provided for the pipeline belong to
+    /// a different [RenderingContext].
+    ///
+    /// [clear_graphics_pipeline_cache]: RenderingContext::clear_graphics_pipeline_cache
+    /// [try_create_graphics_pipeline]: RenderingContext::try_create_graphics_pipeline
+    /// [SingleThreadedContext]: crate::runtime::single_threaded::SingleThreadedContext
+    fn try_get_or_create_graphics_pipeline<V, R>(
+        &self,
+        descriptor: &GraphicsPipelineDescriptor<V, R, ()>,
+    ) -> Result<Rc<GraphicsPipeline<V, R, ()>>, CreateGraphicsPipelineError>
+    where
+        V: 'static,
+        R: 'static,
+    {
+        self.try_create_graphics_pipeline(descriptor).map(Rc::new)
+    }
+
+    /// Removes all pipelines from the pipeline cache used by [try_get_or_create_graphics_pipeline].
+    ///
+    /// Pipelines that are still referenced elsewhere remain valid; a pipeline is only destroyed
+    /// once all references to it have been dropped. Subsequent requests create new pipelines.
+    ///
+    /// The default implementation does nothing, as the default implementation of
+    /// [try_get_or_create_graphics_pipeline] does not cache pipelines.
+    ///
+    /// [try_get_or_create_graphics_pipeline]: RenderingContext::try_get_or_create_graphics_pipeline
+    fn clear_graphics_pipeline_cache(&self) {}
+
     /// Creates a new [RenderTarget] from the given descriptor.
     ///
     /// The descriptor must only attach one color buffer. As multiple color buffers are not
@@ -817,6 +1241,87 @@ pub trait RenderingContext {
     where
         F: TextureFormat + 'static;
 
+    /// Creates a new [Texture2D] with the dimensions of the `image_element` and submits a task that
+    /// uploads the image to the texture's base level.
+    ///
+    /// If the texture has more than one mipmap level, the task also generates the image data for
+    /// the other levels from the base level (see [Texture2D::generate_mipmap_command]). The
+    /// `options` may be used to flip the image vertically or to premultiply its alpha component
+    /// during the upload, see [UploadOptions] for details.
+    ///
+    /// Returns a future that resolves to the texture when the task has finished.
+    ///
+    /// This is a convenience method that combines [try_create_texture_2d],
+    /// [Image2DSource::from_image_element], [Level::upload_command_with_options] and
+    /// [Texture2D::generate_mipmap_command].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `image_element` is not yet "complete" (see [HtmlImageElement::complete]).
+    ///
+    /// Panics if `levels` specifies more mipmap levels than the image's dimensions support.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # use web_sys::HtmlImageElement;
+    /// # fn wrapper<Rc>(context: &Rc, image_element: &HtmlImageElement) where Rc: RenderingContext + Clone + 'static {
+    /// use web_glitz::image::{MipmapLevels, UploadOptions};
+    /// use web_glitz::image::format::SRGB8_ALPHA8;
+    ///
+    /// let options = UploadOptions {
+    ///     flip_y: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let future_texture = context.create_texture_2d_from_image_element(
+    ///     image_element,
+    ///     SRGB8_ALPHA8,
+    ///     MipmapLevels::Complete,
+    ///     options,
+    /// );
+    /// # }
+    /// ```
+    ///
+    /// [try_create_texture_2d]: RenderingContext::try_create_texture_2d
+    /// [Level::upload_command_with_options]: crate::image::texture_2d::Level::upload_command_with_options
+    fn create_texture_2d_from_image_element<F>(
+        &self,
+        image_element: &HtmlImageElement,
+        format: F,
+        levels: MipmapLevels,
+        options: UploadOptions,
+    ) -> Execution<Texture2D<F>>
+    where
+        F: TextureFormat + Filterable + FloatRenderable + 'static,
+        [u8; 4]: PixelUnpack<F>,
+    {
+        let data = Image2DSource::from_image_element(image_element);
+        let (width, height) = data.dimensions();
+
+        let texture = self
+            .try_create_texture_2d(&Texture2DDescriptor {
+                format,
+                width,
+                height,
+                levels,
+            })
+            .unwrap();
+
+        let upload = texture
+            .base_level()
+            .upload_command_with_options(data, options);
+
+        let generate_mipmap = if texture.levels().len() > 1 {
+            Some(texture.generate_mipmap_command())
+        } else {
+            None
+        };
+
+        self.submit(sequence(upload, OptionTask::from(generate_mipmap)).map(move |_| texture))
+    }
+
     /// Creates a new [Texture2DArray] from the given `descriptor`, or returns an error if the
     /// descriptor was invalid.
     ///
@@ -938,6 +1443,10 @@ pub trait RenderingContext {
     /// });
     /// # }
     /// ```
+    ///
+    /// Samplers created from identical descriptors share the same underlying sampler object: if a
+    /// [Sampler] created from an equal descriptor is still alive, then the returned [Sampler]
+    /// compares equal to that sampler and no new sampler object is allocated.
     fn create_sampler<Min, Mag>(
         &self,
         descriptor: &SamplerDescriptor<Min, Mag>,
@@ -965,8 +1474,94 @@ pub trait RenderingContext {
     /// });
     /// # }
     /// ```
+    ///
+    /// As with [create_sampler], shadow samplers created from identical descriptors share the same
+    /// underlying sampler object.
     fn create_shadow_sampler(&self, descriptor: &ShadowSamplerDescriptor) -> ShadowSampler;
 
+    /// Returns the errors that were reported for commands executed by this context since the last
+    /// call to this method, in the order in which they were reported.
+    ///
+    /// Errors are only reported if debugging was enabled when the context was initialized, see
+    /// [ContextOptionsBuilder::debug]; otherwise this always returns an empty [Vec]. At most one
+    /// error is reported for each submitted task: the first command that produced an error.
+    ///
+    /// The default implementation does not report errors and always returns an empty [Vec].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
+    /// for error in context.take_command_errors() {
+    ///     // Prints e.g. "DrawCommand #3 in pipeline task failed: INVALID_OPERATION (0x0502)"
+    ///     println!("{}", error);
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [ContextOptionsBuilder::debug]: crate::runtime::ContextOptionsBuilder::debug
+    fn take_command_errors(&self) -> Vec<CommandError> {
+        Vec::new()
+    }
+
+    /// Flushes any commands that were issued by previously submitted tasks to the GPU.
+    ///
+    /// Commands are typically flushed implicitly by the browser when control returns to the event
+    /// loop. Flushing explicitly may allow the GPU to start working on commands earlier, e.g.
+    /// before a long-running computation on the CPU, but flushing too often adds overhead.
+    ///
+    /// Does not wait for the commands to finish executing, see [finish] for a method that can be
+    /// used to observe when the GPU has finished executing commands.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::{Connection, RenderingContext};
+    /// # use web_glitz::task::GpuTask;
+    /// # fn wrapper<Rc, T>(context: &Rc, task: T) where Rc: RenderingContext, T: GpuTask<Connection, Output=()> + 'static {
+    /// context.submit(task);
+    /// context.flush();
+    /// # }
+    /// ```
+    fn flush(&self) {
+        self.submit_and_forget(FlushCommand);
+    }
+
+    /// Returns a [Future] that resolves when the GPU has finished executing all commands issued by
+    /// previously submitted tasks.
+    ///
+    /// Unlike WebGL's `finish`, this does not block the main thread: the future is backed by a GPU
+    /// fence, which is checked periodically. Note however that waiting for the future to resolve
+    /// before submitting further work stalls the pipeline: the GPU becomes idle while the CPU
+    /// prepares the next batch of commands. Only use this when you genuinely need the GPU to be
+    /// done, e.g. before taking a timing measurement; to read back data from the GPU, prefer the
+    /// commands that directly output the data (e.g. [Buffer::download_command]), as these already
+    /// wait for the GPU only as long as necessary.
+    ///
+    /// Any tasks that are still waiting on a fence (e.g. download commands) when [finish] is
+    /// called, may complete after the future returned by [finish] resolves.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::{Connection, RenderingContext};
+    /// # use web_glitz::task::GpuTask;
+    /// # fn wrapper<Rc, T>(context: &Rc, task: T) where Rc: RenderingContext, T: GpuTask<Connection, Output=()> + 'static {
+    /// use futures::future::FutureExt;
+    /// use wasm_bindgen_futures::spawn_local;
+    ///
+    /// context.submit(task);
+    ///
+    /// spawn_local(context.finish().map(|_| {
+    ///     // The GPU has finished executing the task's commands...
+    /// }));
+    /// # }
+    /// ```
+    fn finish(&self) -> Execution<()> {
+        self.submit(FinishCommand { fenced: false })
+    }
+
     /// Submits the `task` for execution and returns the output of the task as a [Future] result.
     ///
     /// When the task finishes ([GpuTask::progress] returns [Progress::Finished]), the [Future]
@@ -1001,58 +1596,439 @@ pub trait RenderingContext {
     /// Note that in many cases the output of a task is not relevant (the output is often just the
     /// empty tuple `()`). In this case it is not necessary to ever poll the future for the task to
     /// be executed: any task that is submitted will be executed, regardless of whether the future
-    /// returned by [submit] is ever polled or just simply dropped immediately.
+    /// returned by [submit] is ever polled or just simply dropped immediately. For such tasks,
+    /// consider [submit_and_forget], which does not return a [Future] at all. To abandon a task
+    /// explicitly after it was submitted, wrap it with [cancellable] before submitting it.
     ///
     /// # Panics
     ///
     /// Panics if the task belongs to a different [RenderingContext] ([GpuTask::context_id] returns
     /// a value that is not compatible with this current context).
+    ///
+    /// [cancellable]: crate::task::cancellable
+    /// [submit_and_forget]: RenderingContext::submit_and_forget
     fn submit<T>(&self, task: T) -> Execution<T::Output>
     where
         T: GpuTask<Connection> + 'static;
+
+    /// Submits the `task` for execution without returning a handle to its output.
+    ///
+    /// This is intended for the common case of tasks that do not produce an output (e.g. most
+    /// render passes and uploads): rather than returning an [Execution] that is immediately
+    /// discarded, the context manages the task internally, including waiting on any GPU fences the
+    /// task may require, until the task finishes.
+    ///
+    /// As with [submit], no guarantees are given about the execution order of tasks that have been
+    /// submitted separately.
+    ///
+    /// There is no way to observe the completion of the task or any errors that may occur while
+    /// the task is executed: errors are not propagated to the caller. If the context was created
+    /// with debugging enabled (see [ContextOptions::debug]), then errors generated by the task's
+    /// commands are logged to the browser console (see also [take_command_errors]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::{Connection, RenderingContext};
+    /// # use web_glitz::task::GpuTask;
+    /// # fn wrapper<Rc, T>(context: &Rc, render_pass: T) where Rc: RenderingContext, T: GpuTask<Connection, Output=()> + 'static {
+    /// context.submit_and_forget(render_pass);
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the task belongs to a different [RenderingContext] ([GpuTask::context_id] returns
+    /// a value that is not compatible with this current context).
+    ///
+    /// [submit]: RenderingContext::submit
+    /// [ContextOptions::debug]: crate::runtime::ContextOptions::debug
+    /// [take_command_errors]: RenderingContext::take_command_errors
+    fn submit_and_forget<T>(&self, task: T)
+    where
+        T: GpuTask<Connection, Output = ()> + 'static,
+    {
+        self.submit(task);
+    }
+
+    /// Checks the `task` graph for likely mistakes without submitting it.
+    ///
+    /// This is an opt-in debugging aid: it walks the task graph on the CPU and does not issue any
+    /// commands to the GPU. Validation is not performed by [submit] or [submit_and_forget]; call
+    /// this explicitly before submitting, typically only in debug builds. The following is
+    /// checked:
+    ///
+    /// - every pipeline task contains at least one draw command (see
+    ///   [ValidationError::PipelineTaskWithoutDraw]);
+    /// - no pipeline task binds a buffer as a vertex buffer that is also one of its transform
+    ///   feedback buffers (see [ValidationError::VertexBufferIsTransformFeedbackBuffer]).
+    ///
+    /// Note that the compatibility of a render target's attachments is already checked when the
+    /// render target is created, and is therefore not checked again here.
+    ///
+    /// Returns `Ok(())` if no mistakes were found, or all [ValidationError]s that were found
+    /// otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::{Connection, RenderingContext};
+    /// # use web_glitz::task::GpuTask;
+    /// # fn wrapper<Rc, T>(context: &Rc, render_pass: T) where Rc: RenderingContext, T: GpuTask<Connection, Output=()> + 'static {
+    /// if cfg!(debug_assertions) {
+    ///     if let Err(errors) = context.validate(&render_pass) {
+    ///         for error in errors {
+    ///             web_sys::console::warn_1(&error.to_string().into());
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// context.submit_and_forget(render_pass);
+    /// # }
+    /// ```
+    ///
+    /// [submit]: RenderingContext::submit
+    /// [submit_and_forget]: RenderingContext::submit_and_forget
+    fn validate<T>(&self, task: &T) -> Result<(), Vec<ValidationError>>
+    where
+        T: GpuTask<Connection>,
+    {
+        let mut context = ValidationContext::new();
+
+        task.validate(&mut context);
+
+        context.finish()
+    }
+
+    /// Submits the `task` for execution and returns a [PollHandle] that may be polled manually for
+    /// the task's output, as an alternative to the [Future] returned by [submit].
+    ///
+    /// This is intended for integration with custom event loops (e.g. a fixed-timestep loop driven
+    /// by your own scheduler) that do not run a futures executor: call [PollHandle::poll] once per
+    /// tick until it returns the output. Polling the handle also checks the GPU fences of any tasks
+    /// that are waiting on a fence, so that these tasks progress on your loop's schedule rather
+    /// than only on the context's own timer.
+    ///
+    /// The caller is responsible for polling the handle frequently enough: a task's output only
+    /// becomes available through the handle when it is polled. Note that as with [submit], the
+    /// task is executed regardless of whether or not the handle is ever polled.
+    ///
+    /// The default implementation returns a handle that does not check any GPU fences when it is
+    /// polled; tasks that wait on a fence then only progress on the context's own schedule.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::{Connection, RenderingContext};
+    /// # use web_glitz::task::GpuTask;
+    /// # fn wrapper<Rc, T>(context: &Rc, task: T) where Rc: RenderingContext, T: GpuTask<Connection, Output=u32> + 'static {
+    /// let mut handle = context.submit_poll(task);
+    ///
+    /// // Later, on every tick of your loop:
+    /// if let Some(output) = handle.poll() {
+    ///     // Do something with the output...
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the task belongs to a different [RenderingContext] ([GpuTask::context_id] returns
+    /// a value that is not compatible with this current context).
+    ///
+    /// [submit]: RenderingContext::submit
+    fn submit_poll<T>(&self, task: T) -> PollHandle<T::Output>
+    where
+        T: GpuTask<Connection> + 'static,
+    {
+        PollHandle::new(self.submit(task), Weak::new())
+    }
+
+    /// Reads the contents of the buffer `view` and returns a [Future] that resolves to a
+    /// [MappedRead] that dereferences to the contents as a slice.
+    ///
+    /// This is intended for reading back data that was computed on the GPU (e.g. the output of a
+    /// transform feedback simulation) when the data only needs to be inspected briefly: the
+    /// [MappedRead] borrows the buffer for as long as it is held and may then simply be dropped.
+    ///
+    /// Note that WebGL cannot map GPU memory into the address space of the application: under the
+    /// hood, the contents are copied into CPU memory, as with a [BufferView::download_command],
+    /// and the [MappedRead] dereferences to this copy. The copy reflects the state of the buffer at
+    /// the time the read is executed; changes made to the buffer afterwards are not observed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::buffer::Buffer;
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # async fn wrapper<Rc>(context: &Rc, particles: &Buffer<[[f32; 4]]>) where Rc: RenderingContext {
+    /// let mapped = context.map_read(particles).await;
+    ///
+    /// for position in mapped.iter() {
+    ///     // Do something with the position...
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer belongs to a different [RenderingContext].
+    ///
+    /// [BufferView::download_command]: crate::buffer::BufferView::download_command
+    fn map_read<'a, V, T>(&self, view: V) -> MapRead<'a, [T]>
+    where
+        V: Into<BufferView<'a, [T]>>,
+        T: Copy + 'static,
+    {
+        MapRead::new(self.submit(view.into().download_command()))
+    }
+
+    /// Runs an animation frame loop that invokes `f` on every animation frame and submits the task
+    /// it returns.
+    ///
+    /// Uses the browser's `requestAnimationFrame` to schedule the loop, which aligns the frames
+    /// with the display's refresh rate and pauses the loop while the page is hidden. For every
+    /// animation frame, `f` receives a [FrameContext] that holds the frame's timestamp, the time
+    /// that elapsed since the previous frame and the frame's index; the task returned by `f` is
+    /// submitted (see [submit]) before the next animation frame is requested.
+    ///
+    /// The loop runs for as long as the returned [AnimationHandle] is alive; dropping the handle
+    /// cancels the pending animation frame request and stops the loop. Note that the handle must
+    /// therefore be kept alive for the loop to keep running, e.g. by storing it alongside the rest
+    /// of the application state (or by calling [std::mem::forget] on it, for a loop that should
+    /// run for the lifetime of the page).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # use web_glitz::buffer::Buffer;
+    /// # use web_glitz::rendering::{DefaultRGBBuffer, DefaultRenderTarget};
+    /// # use web_glitz::pipeline::graphics::{GraphicsPipeline, Vertex};
+    /// # fn wrapper<Rc, V>(
+    /// #     context: &Rc,
+    /// #     mut render_target: DefaultRenderTarget<DefaultRGBBuffer, ()>,
+    /// #     vertex_buffer: Buffer<[V]>,
+    /// #     uniform_buffer: Buffer<f32>,
+    /// #     graphics_pipeline: GraphicsPipeline<V, (), ()>
+    /// # ) where Rc: RenderingContext + Clone + 'static, V: Vertex + 'static {
+    /// use web_glitz::runtime::FrameContext;
+    /// use web_glitz::task::sequence;
+    ///
+    /// let animation = context.run_animation_frames(move |frame: FrameContext| {
+    ///     let time = (frame.timestamp / 1000.0) as f32;
+    ///
+    ///     let render_pass = render_target.create_render_pass(|framebuffer| {
+    ///         framebuffer.pipeline_task(&graphics_pipeline, |active_pipeline| {
+    ///             active_pipeline.task_builder()
+    ///                 .bind_vertex_buffers(&vertex_buffer)
+    ///                 .bind_resources(())
+    ///                 .draw(vertex_buffer.len(), 1)
+    ///                 .finish()
+    ///         })
+    ///     });
+    ///
+    ///     sequence(uniform_buffer.upload_command(time), render_pass)
+    /// });
+    ///
+    /// // The loop stops when `animation` is dropped.
+    /// # }
+    /// ```
+    ///
+    /// Here `context` is a [RenderingContext] and `render_target` is the [DefaultRenderTarget]
+    /// returned when the context was initialized. Note that the render target is moved into the
+    /// closure, rather than passed to `f` through the [FrameContext], as the render target is owned
+    /// by the application.
+    ///
+    /// [submit]: RenderingContext::submit
+    /// [DefaultRenderTarget]: crate::rendering::DefaultRenderTarget
+    fn run_animation_frames<F, T>(&self, mut f: F) -> AnimationHandle
+    where
+        Self: Clone + 'static,
+        F: FnMut(FrameContext) -> T + 'static,
+        T: GpuTask<Connection> + 'static,
+    {
+        let context = self.clone();
+
+        AnimationHandle::start(move |frame| {
+            // Stop the loop once the context was destroyed, its frames can no longer be rendered.
+            match context.submit(f(frame)) {
+                Execution::Cancelled => false,
+                _ => true,
+            }
+        })
+    }
 }
 
 #[derive(PartialEq, Debug)]
 pub struct ShaderCompilationError(pub(crate) String);
 
 /// Error returned from [RenderingContext::create_graphics_pipeline].
+///
+/// Each variant corresponds to a distinct failure cause and carries the context that is relevant to
+/// that cause (e.g. the attribute location or resource name involved), which allows matching on the
+/// variants to present specific error messages. A description of the error is also available
+/// through its [Display] implementation.
+///
+/// Note that shader compilation errors are not reported by this type: shaders are compiled
+/// separately, before the pipeline is created, see [RenderingContext::try_create_vertex_shader]
+/// and [RenderingContext::try_create_fragment_shader].
+///
+/// [Display]: std::fmt::Display
 #[derive(Debug)]
 pub enum CreateGraphicsPipelineError {
     /// Variant that is returned when the programmable shader stages fail to link into a valid
     /// program.
     ///
-    /// Typically the result of a prior stage's outputs not matching the succeeding stage's inputs.
-    ShaderLinkingError(ShaderLinkingError),
-
-    /// Variant that is returned when any of the programmable shader stages define an uniform type
-    /// that is not supported by WebGlitz.
-    ///
-    /// Note that WebGlitz does not support non-opaque uniform types (such as `float`, `vec4`,
-    /// `mat4`) outside of uniform blocks, only opaque (texture/shader types) are supported. All
-    /// basic non-opaque uniform slots must be declared as part of a uniform block.
-    UnsupportedUniformType(ResourceSlotIdentifier, &'static str),
+    /// Typically the result of the vertex stage's outputs not matching the fragment stage's inputs.
+    /// The linker's info log is available through [ShaderLinkingError::info_log].
+    FragmentShaderLink(ShaderLinkingError),
 
     /// Variant that is returned when the input attribute layout declared for the pipeline (see
     /// [GraphicsPipelineBuilder::vertex_input_layout]) does not match the actual input attribute
     /// layout as defined by the shader code.
-    IncompatibleInputAttributeLayout(IncompatibleVertexInputLayout),
+    ///
+    /// The `location` identifies the attribute slot for which the mismatch was detected.
+    VertexLayoutMismatch {
+        location: u32,
+        error: IncompatibleVertexInputLayout,
+    },
 
     /// Variant that is returned when the resource layout declared for the pipeline (see
     /// [GraphicsPipelineBuilder::resource_layout]) does not match the resource layout as defined by
     /// the shader code.
-    IncompatibleResources(IncompatibleResources),
+    ///
+    /// The `bind_group` is the index of the bind group involved if the mismatch concerns a missing
+    /// bind group, the `name` identifies the resource slot involved if the mismatch concerns a
+    /// specific resource slot. The `error` describes the mismatch in detail.
+    ResourceLayoutMismatch {
+        bind_group: Option<u32>,
+        name: Option<ResourceSlotIdentifier>,
+        error: IncompatibleResources,
+    },
+
+    /// Variant that is returned when the transform feedback layout declared for the pipeline does
+    /// not match the transform feedback varyings recorded by the shader code.
+    ///
+    /// Contains the name of the varying for which the mismatch was detected.
+    TransformFeedbackMismatch(String),
+
+    /// Variant that is returned when any of the programmable shader stages use a feature that is
+    /// not supported by WebGlitz.
+    ///
+    /// Currently this is only returned for uniform types that are not supported: WebGlitz does not
+    /// support non-opaque uniform types (such as `float`, `vec4`, `mat4`) outside of uniform
+    /// blocks, only opaque (texture/shader types) are supported. All basic non-opaque uniform slots
+    /// must be declared as part of a uniform block. The `slot` identifies the uniform, the `description`
+    /// describes the unsupported feature.
+    UnsupportedFeature {
+        slot: ResourceSlotIdentifier,
+        description: &'static str,
+    },
+
+    /// Variant that is returned when the programmable shader stages use more resources of a
+    /// certain kind than the device supports.
+    ///
+    /// The `limit` identifies the kind of resource, `needed` is the number of resources of that
+    /// kind used by the shader stages and `available` is the maximum supported by the device. This
+    /// may be used to detect that a shader exceeds the limits of low-end hardware when the pipeline
+    /// is created, so that the application may fall back to a simpler shader.
+    ResourceLimitExceeded {
+        limit: ResourceLimit,
+        needed: u32,
+        available: u32,
+    },
+}
+
+/// Enumerates the device limits on the number of resources a graphics pipeline may use.
+///
+/// See [CreateGraphicsPipelineError::ResourceLimitExceeded].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum ResourceLimit {
+    /// The maximum number of uniform blocks the vertex stage may use
+    /// (`MAX_VERTEX_UNIFORM_BLOCKS`).
+    VertexUniformBlocks,
+
+    /// The maximum number of uniform blocks the fragment stage may use
+    /// (`MAX_FRAGMENT_UNIFORM_BLOCKS`).
+    FragmentUniformBlocks,
+
+    /// The maximum number of uniform blocks all stages combined may use
+    /// (`MAX_COMBINED_UNIFORM_BLOCKS`).
+    CombinedUniformBlocks,
+
+    /// The maximum number of samplers all stages combined may use
+    /// (`MAX_COMBINED_TEXTURE_IMAGE_UNITS`).
+    CombinedTextureImageUnits,
+}
+
+impl fmt::Display for ResourceLimit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ResourceLimit::VertexUniformBlocks => f.write_str("vertex stage uniform blocks"),
+            ResourceLimit::FragmentUniformBlocks => f.write_str("fragment stage uniform blocks"),
+            ResourceLimit::CombinedUniformBlocks => f.write_str("combined uniform blocks"),
+            ResourceLimit::CombinedTextureImageUnits => f.write_str("combined samplers"),
+        }
+    }
+}
 
-    TransformFeedbackTypeMismatch(String),
+impl fmt::Display for CreateGraphicsPipelineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CreateGraphicsPipelineError::FragmentShaderLink(error) => {
+                write!(f, "shader stages failed to link: {}", error.info_log())
+            }
+            CreateGraphicsPipelineError::VertexLayoutMismatch { location, error } => match error {
+                IncompatibleVertexInputLayout::MissingAttribute { .. } => write!(
+                    f,
+                    "the vertex input layout declares no attribute for location {}",
+                    location
+                ),
+                IncompatibleVertexInputLayout::TypeMismatch { .. } => write!(
+                    f,
+                    "the vertex input layout's attribute at location {} does not match the \
+                     shader's attribute type",
+                    location
+                ),
+            },
+            CreateGraphicsPipelineError::ResourceLayoutMismatch { error, .. } => {
+                fmt::Display::fmt(error, f)
+            }
+            CreateGraphicsPipelineError::TransformFeedbackMismatch(name) => write!(
+                f,
+                "the transform feedback layout does not match the shader's varying `{}`",
+                name
+            ),
+            CreateGraphicsPipelineError::UnsupportedFeature { slot, description } => {
+                write!(
+                    f,
+                    "unsupported feature for `{}`: {}",
+                    slot.deref(),
+                    description
+                )
+            }
+            CreateGraphicsPipelineError::ResourceLimitExceeded {
+                limit,
+                needed,
+                available,
+            } => write!(
+                f,
+                "the shader stages use {} {}, but the device supports at most {}",
+                needed, limit, available
+            ),
+        }
+    }
 }
 
 impl From<CreateProgramError> for CreateGraphicsPipelineError {
     fn from(err: CreateProgramError) -> Self {
         match err {
             CreateProgramError::ShaderLinkingError(error) => {
-                CreateGraphicsPipelineError::ShaderLinkingError(ShaderLinkingError { error })
+                CreateGraphicsPipelineError::FragmentShaderLink(ShaderLinkingError { error })
             }
-            CreateProgramError::UnsupportedUniformType(identifier, error) => {
-                CreateGraphicsPipelineError::UnsupportedUniformType(identifier, error)
+            CreateProgramError::UnsupportedUniformType(slot, description) => {
+                CreateGraphicsPipelineError::UnsupportedFeature { slot, description }
             }
         }
     }
@@ -1060,19 +2036,43 @@ impl From<CreateProgramError> for CreateGraphicsPipelineError {
 
 impl From<ShaderLinkingError> for CreateGraphicsPipelineError {
     fn from(error: ShaderLinkingError) -> Self {
-        CreateGraphicsPipelineError::ShaderLinkingError(error)
+        CreateGraphicsPipelineError::FragmentShaderLink(error)
     }
 }
 
 impl From<IncompatibleVertexInputLayout> for CreateGraphicsPipelineError {
     fn from(error: IncompatibleVertexInputLayout) -> Self {
-        CreateGraphicsPipelineError::IncompatibleInputAttributeLayout(error)
+        let location = match error {
+            IncompatibleVertexInputLayout::MissingAttribute { location } => location,
+            IncompatibleVertexInputLayout::TypeMismatch { location } => location,
+        };
+
+        CreateGraphicsPipelineError::VertexLayoutMismatch { location, error }
     }
 }
 
 impl From<IncompatibleResources> for CreateGraphicsPipelineError {
     fn from(error: IncompatibleResources) -> Self {
-        CreateGraphicsPipelineError::IncompatibleResources(error)
+        let (bind_group, name) = match &error {
+            IncompatibleResources::MissingBindGroup(index) => (Some(*index), None),
+            IncompatibleResources::MissingResource(identifier) => (None, Some(identifier.clone())),
+            IncompatibleResources::ResourceTypeMismatch(identifier) => {
+                (None, Some(identifier.clone()))
+            }
+            IncompatibleResources::IncompatibleInterface(identifier, _) => {
+                (None, Some(identifier.clone()))
+            }
+            IncompatibleResources::SlotBindingMismatch { .. } => (None, None),
+            IncompatibleResources::BlockArrayLengthMismatch { identifier, .. } => {
+                (None, Some(identifier.clone()))
+            }
+        };
+
+        CreateGraphicsPipelineError::ResourceLayoutMismatch {
+            bind_group,
+            name,
+            error,
+        }
     }
 }
 
@@ -1098,6 +2098,14 @@ pub enum Execution<O> {
 
     /// Variant returned when the task did not finish immediately upon submission.
     Pending(Receiver<O>),
+
+    /// Variant for a task that was discarded without finishing because its context was destroyed
+    /// (see [SingleThreadedContext::destroy]).
+    ///
+    /// A cancelled [Execution] never resolves when awaited.
+    ///
+    /// [SingleThreadedContext::destroy]: crate::runtime::single_threaded::SingleThreadedContext::destroy
+    Cancelled,
 }
 
 impl<O> Execution<O> {
@@ -1108,13 +2116,39 @@ impl<O> Execution<O> {
             unreachable!()
         }
     }
+
+    /// Returns `true` if the task was discarded without finishing because its context was
+    /// destroyed, `false` otherwise.
+    ///
+    /// Once this returns `true`, the [Execution] is [Execution::Cancelled].
+    pub fn is_cancelled(&mut self) -> bool {
+        match self {
+            Execution::Ready(_) => false,
+            Execution::Pending(recv) => match recv.try_recv() {
+                Ok(Some(output)) => {
+                    *self = Execution::Ready(Some(output));
+
+                    false
+                }
+                Ok(None) => false,
+                Err(_) => {
+                    *self = Execution::Cancelled;
+
+                    true
+                }
+            },
+            Execution::Cancelled => true,
+        }
+    }
 }
 
 impl<O> Future for Execution<O> {
     type Output = O;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<O> {
-        match unsafe { self.get_unchecked_mut() } {
+        let execution = unsafe { self.get_unchecked_mut() };
+
+        match execution {
             Execution::Ready(ref mut output) => {
                 let output = output
                     .take()
@@ -1125,8 +2159,63 @@ impl<O> Future for Execution<O> {
             Execution::Pending(ref mut recv) => match Pin::new(recv).poll(cx) {
                 Poll::Ready(Ok(output)) => Poll::Ready(output),
                 Poll::Pending => Poll::Pending,
-                _ => unreachable!(),
+                Poll::Ready(Err(_)) => {
+                    // The task was discarded before it finished: its context was destroyed.
+                    *execution = Execution::Cancelled;
+
+                    Poll::Pending
+                }
             },
+            Execution::Cancelled => Poll::Pending,
+        }
+    }
+}
+
+/// Returned from [RenderingContext::submit_poll], a handle to the output of a submitted [GpuTask]
+/// that is polled manually.
+///
+/// See [RenderingContext::submit_poll] for details.
+pub struct PollHandle<O> {
+    execution: Execution<O>,
+    fenced_task_runner: Weak<RefCell<JsTimeoutFencedTaskRunner>>,
+}
+
+impl<O> PollHandle<O> {
+    pub(crate) fn new(
+        execution: Execution<O>,
+        fenced_task_runner: Weak<RefCell<JsTimeoutFencedTaskRunner>>,
+    ) -> Self {
+        PollHandle {
+            execution,
+            fenced_task_runner,
+        }
+    }
+
+    /// Returns the task's output if the task has finished, or `None` if the task has not yet
+    /// finished.
+    ///
+    /// Checks the GPU fences of any tasks that are waiting on a fence before checking for the
+    /// output. The output is only returned once: after the output was returned, this method always
+    /// returns `None`.
+    ///
+    /// This method should not be called from within a [GpuTask]; fences are not checked if it is.
+    pub fn poll(&mut self) -> Option<O> {
+        match &mut self.execution {
+            Execution::Ready(output) => output.take(),
+            Execution::Pending(recv) => {
+                if let Ok(Some(output)) = recv.try_recv() {
+                    return Some(output);
+                }
+
+                if let Some(runner) = self.fenced_task_runner.upgrade() {
+                    if let Ok(runner) = runner.try_borrow() {
+                        runner.run_now();
+                    }
+                }
+
+                recv.try_recv().ok().flatten()
+            }
+            Execution::Cancelled => None,
         }
     }
 }
@@ -1158,6 +2247,13 @@ pub struct Connection {
     context_id: u64,
     gl: Gl,
     state: DynamicState,
+    multi_draw: Option<Option<WebglMultiDraw>>,
+    parallel_shader_compile: Option<bool>,
+    debug: bool,
+    command_errors: Vec<CommandError>,
+    deletion_queue: DeletionQueue,
+    // Backs the `deletion_queue` until the queue of a context is set with `set_deletion_queue`.
+    own_deletions: Option<Rc<PendingDeletions>>,
 }
 
 impl Connection {
@@ -1170,10 +2266,18 @@ impl Connection {
     ///
     /// The `state` must accurately reflect the current state of the [WebGl2RenderingContext].
     pub unsafe fn new(context_id: u64, gl: Gl, state: DynamicState) -> Self {
+        let own_deletions = Rc::new(PendingDeletions::new());
+
         Connection {
             context_id,
             gl,
             state,
+            multi_draw: None,
+            parallel_shader_compile: None,
+            debug: false,
+            command_errors: Vec::new(),
+            deletion_queue: own_deletions.handle(),
+            own_deletions: Some(own_deletions),
         }
     }
 
@@ -1223,4 +2327,244 @@ impl Connection {
     pub unsafe fn unpack_mut(&mut self) -> (&mut Gl, &mut DynamicState) {
         (&mut self.gl, &mut self.state)
     }
+
+    /// Returns the `WEBGL_multi_draw` extension object if the extension is available, or `None`
+    /// otherwise.
+    ///
+    /// The availability of the extension is only queried once; the result is cached for all
+    /// subsequent calls.
+    pub(crate) fn multi_draw_extension(&mut self) -> Option<WebglMultiDraw> {
+        let gl = &self.gl;
+
+        self.multi_draw
+            .get_or_insert_with(|| {
+                gl.get_extension("WEBGL_multi_draw")
+                    .ok()
+                    .flatten()
+                    .map(|extension| extension.unchecked_into())
+            })
+            .clone()
+    }
+
+    /// Returns `true` if the `KHR_parallel_shader_compile` extension is available, `false`
+    /// otherwise.
+    ///
+    /// The availability of the extension is only queried once; the result is cached for all
+    /// subsequent calls.
+    pub(crate) fn parallel_shader_compile_supported(&mut self) -> bool {
+        let gl = &self.gl;
+
+        *self.parallel_shader_compile.get_or_insert_with(|| {
+            gl.get_extension("KHR_parallel_shader_compile")
+                .ok()
+                .flatten()
+                .is_some()
+        })
+    }
+
+    /// Whether or not errors are checked after every command.
+    pub(crate) fn debug(&self) -> bool {
+        self.debug
+    }
+
+    pub(crate) fn set_debug(&mut self, debug: bool) {
+        self.debug = debug;
+    }
+
+    pub(crate) fn push_command_error(&mut self, error: CommandError) {
+        self.command_errors.push(error);
+    }
+
+    pub(crate) fn take_command_errors(&mut self) -> Vec<CommandError> {
+        mem::replace(&mut self.command_errors, Vec::new())
+    }
+
+    /// The queue of pending deletions of the context that owns this connection, used by tasks that
+    /// create transient GPU objects that must be released if the task is dropped before it
+    /// finishes.
+    ///
+    /// If no context queue was set with [set_deletion_queue], then this is a queue owned by the
+    /// connection itself, which is drained by [run_pending_deletions].
+    ///
+    /// [set_deletion_queue]: Connection::set_deletion_queue
+    /// [run_pending_deletions]: Connection::run_pending_deletions
+    pub(crate) fn deletion_queue(&self) -> &DeletionQueue {
+        &self.deletion_queue
+    }
+
+    pub(crate) fn set_deletion_queue(&mut self, deletion_queue: DeletionQueue) {
+        self.run_pending_deletions();
+
+        self.deletion_queue = deletion_queue;
+        self.own_deletions = None;
+    }
+
+    /// Runs the deletion commands that were scheduled on the queue owned by the connection itself,
+    /// see [deletion_queue]. Does nothing if the queue of a context was set, as that queue is
+    /// drained by the context.
+    ///
+    /// [deletion_queue]: Connection::deletion_queue
+    pub(crate) fn run_pending_deletions(&mut self) {
+        if let Some(own_deletions) = self.own_deletions.clone() {
+            for mut task in own_deletions.take() {
+                task.progress(self);
+            }
+        }
+    }
+}
+
+/// Task that flushes all previously issued commands to the GPU, see [RenderingContext::flush].
+struct FlushCommand;
+
+unsafe impl GpuTask<Connection> for FlushCommand {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Any
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        let (gl, _) = unsafe { connection.unpack() };
+
+        gl.flush();
+
+        Progress::Finished(())
+    }
+}
+
+/// Task that finishes once a fence inserted after all previously issued commands is signalled, see
+/// [RenderingContext::finish].
+struct FinishCommand {
+    fenced: bool,
+}
+
+unsafe impl GpuTask<Connection> for FinishCommand {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Any
+    }
+
+    fn progress(&mut self, _connection: &mut Connection) -> Progress<Self::Output> {
+        if self.fenced {
+            Progress::Finished(())
+        } else {
+            self.fenced = true;
+
+            Progress::ContinueFenced
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::pipeline::interface_block::{MemoryUnit, UnitLayout};
+    use crate::pipeline::resources::IncompatibleInterface;
+
+    #[test]
+    fn test_create_graphics_pipeline_error_from_missing_bind_group() {
+        let error: CreateGraphicsPipelineError = IncompatibleResources::MissingBindGroup(1).into();
+
+        if let CreateGraphicsPipelineError::ResourceLayoutMismatch {
+            bind_group, name, ..
+        } = &error
+        {
+            assert_eq!(*bind_group, Some(1));
+            assert!(name.is_none());
+        } else {
+            panic!(
+                "Expected a `ResourceLayoutMismatch` error, found `{:?}`.",
+                error
+            );
+        }
+
+        assert_eq!(
+            error.to_string(),
+            "the resource layout does not declare bind group 1"
+        );
+    }
+
+    #[test]
+    fn test_create_graphics_pipeline_error_from_incompatible_interface() {
+        let error: CreateGraphicsPipelineError = IncompatibleResources::IncompatibleInterface(
+            ResourceSlotIdentifier::Static("Uniforms"),
+            IncompatibleInterface::MissingUnit(MemoryUnit {
+                offset: 16,
+                layout: UnitLayout::Float,
+                field: None,
+            }),
+        )
+        .into();
+
+        if let CreateGraphicsPipelineError::ResourceLayoutMismatch {
+            bind_group, name, ..
+        } = &error
+        {
+            assert!(bind_group.is_none());
+            assert_eq!(name.as_ref().map(|name| name.deref()), Some("Uniforms"));
+        } else {
+            panic!(
+                "Expected a `ResourceLayoutMismatch` error, found `{:?}`.",
+                error
+            );
+        }
+
+        assert_eq!(
+            error.to_string(),
+            "the interface block type declared for uniform block `Uniforms` does not match the \
+             shader: the interface block type declares no memory unit at offset 16, where the \
+             uniform block declares `Float`"
+        );
+    }
+
+    #[test]
+    fn test_create_graphics_pipeline_error_from_slot_binding_mismatch() {
+        let error: CreateGraphicsPipelineError = IncompatibleResources::SlotBindingMismatch {
+            expected: 2,
+            actual: 1,
+        }
+        .into();
+
+        assert_eq!(
+            error.to_string(),
+            "expected 2 resource slot bindings, but the resource layout declares 1"
+        );
+    }
+
+    #[test]
+    fn test_create_graphics_pipeline_error_from_incompatible_vertex_input_layout() {
+        let error: CreateGraphicsPipelineError =
+            IncompatibleVertexInputLayout::MissingAttribute { location: 3 }.into();
+
+        if let CreateGraphicsPipelineError::VertexLayoutMismatch { location, .. } = &error {
+            assert_eq!(*location, 3);
+        } else {
+            panic!(
+                "Expected a `VertexLayoutMismatch` error, found `{:?}`.",
+                error
+            );
+        }
+
+        assert_eq!(
+            error.to_string(),
+            "the vertex input layout declares no attribute for location 3"
+        );
+    }
+
+    #[test]
+    fn test_create_graphics_pipeline_error_from_unsupported_uniform_type() {
+        let error: CreateGraphicsPipelineError = CreateProgramError::UnsupportedUniformType(
+            ResourceSlotIdentifier::Static("color"),
+            "non-opaque uniforms must be declared in a uniform block",
+        )
+        .into();
+
+        assert_eq!(
+            error.to_string(),
+            "unsupported feature for `color`: non-opaque uniforms must be declared in a uniform \
+             block"
+        );
+    }
 }
@@ -0,0 +1,174 @@
+use std::cell::Cell;
+
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{window, HtmlCanvasElement};
+
+// Minimal bindings for the `ResizeObserver` API; the `web-sys` bindings for this API require the
+// `web_sys_unstable_apis` configuration flag.
+#[wasm_bindgen]
+extern "C" {
+    type ResizeObserver;
+
+    #[wasm_bindgen(constructor)]
+    fn new(callback: &js_sys::Function) -> ResizeObserver;
+
+    #[wasm_bindgen(method)]
+    fn observe(this: &ResizeObserver, target: &HtmlCanvasElement);
+
+    #[wasm_bindgen(method, catch, js_name = observe)]
+    fn observe_with_options(
+        this: &ResizeObserver,
+        target: &HtmlCanvasElement,
+        options: &JsValue,
+    ) -> Result<(), JsValue>;
+
+    #[wasm_bindgen(method)]
+    fn disconnect(this: &ResizeObserver);
+}
+
+/// Handle to a resize callback registered with [ResizeObserverHandle::observe].
+///
+/// The callback is only invoked for as long as this handle is alive; dropping the handle
+/// unregisters the callback.
+pub struct ResizeObserverHandle {
+    observer: ResizeObserver,
+    _callback: Closure<dyn FnMut(JsValue)>,
+}
+
+impl ResizeObserverHandle {
+    /// Registers a callback that is invoked when the display size of the `canvas` changes.
+    ///
+    /// The callback receives the new display size of the canvas as a `(width, height)` tuple, in
+    /// device pixels (the canvas's CSS size multiplied by the device pixel ratio). This is
+    /// typically the size you want to give the canvas's drawing buffer, by setting the canvas
+    /// element's `width` and `height` attributes; the new size of the drawing buffer can afterwards
+    /// be queried from the default render target (see e.g. [DefaultRenderTarget::size]).
+    ///
+    /// The callback is also invoked when only the device pixel ratio changes (e.g. when the
+    /// browser is zoomed), on browsers that support observing the device pixel content box of an
+    /// element.
+    ///
+    /// The callback remains registered for as long as the returned [ResizeObserverHandle] is
+    /// alive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::ResizeObserverHandle;
+    /// # use web_sys::HtmlCanvasElement;
+    /// # fn wrapper(canvas: HtmlCanvasElement) {
+    /// let canvas_clone = canvas.clone();
+    ///
+    /// let handle = ResizeObserverHandle::observe(&canvas, move |width, height| {
+    ///     canvas_clone.set_width(width);
+    ///     canvas_clone.set_height(height);
+    ///
+    ///     // Rebuild any offscreen render targets that depend on the canvas size...
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// [DefaultRenderTarget::size]: crate::rendering::DefaultRenderTarget::size
+    pub fn observe<F>(canvas: &HtmlCanvasElement, mut f: F) -> Self
+    where
+        F: FnMut(u32, u32) + 'static,
+    {
+        let last_size = Cell::new(None);
+        let canvas_clone = canvas.clone();
+
+        let callback = Closure::wrap(Box::new(move |_| {
+            let size = device_pixel_size(&canvas_clone, None);
+
+            if last_size.get() != Some(size) {
+                last_size.set(Some(size));
+
+                f(size.0, size.1);
+            }
+        }) as Box<dyn FnMut(JsValue)>);
+
+        let observer = ResizeObserver::new(callback.as_ref().unchecked_ref());
+
+        // Observing the device pixel content box ensures the callback also fires when only the
+        // device pixel ratio changes (e.g. when the window is moved to a different display). Not
+        // all browsers support this box option; fall back to observing the default content box.
+        let options = js_sys::Object::new();
+
+        js_sys::Reflect::set(
+            &options,
+            &JsValue::from_str("box"),
+            &JsValue::from_str("device-pixel-content-box"),
+        )
+        .unwrap();
+
+        if observer.observe_with_options(canvas, &options).is_err() {
+            observer.observe(canvas);
+        }
+
+        ResizeObserverHandle {
+            observer,
+            _callback: callback,
+        }
+    }
+}
+
+impl Drop for ResizeObserverHandle {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+/// Sets the size of the drawing buffer of the `canvas` to the canvas's display size in device
+/// pixels.
+///
+/// The new size is the canvas's CSS size (`clientWidth` and `clientHeight`) multiplied by the
+/// device pixel ratio. If `dpr_cap` is `Some`, then the device pixel ratio is capped to the given
+/// value; this can be used to limit the cost of rendering on displays with very high pixel
+/// densities. The default render target of a context created from the `canvas` will use the new
+/// size for subsequent render passes.
+///
+/// Does nothing if the drawing buffer already has the desired size, so that the drawing buffer is
+/// not reallocated needlessly. Returns `true` if the size changed, `false` otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// # use web_sys::HtmlCanvasElement;
+/// # fn wrapper(canvas: HtmlCanvasElement) {
+/// use web_glitz::runtime::{match_canvas_to_display, ResizeObserverHandle};
+///
+/// let canvas_clone = canvas.clone();
+///
+/// let handle = ResizeObserverHandle::observe(&canvas, move |_, _| {
+///     match_canvas_to_display(&canvas_clone, Some(2.0));
+/// });
+/// # }
+/// ```
+pub fn match_canvas_to_display(canvas: &HtmlCanvasElement, dpr_cap: Option<f32>) -> bool {
+    let (width, height) = device_pixel_size(canvas, dpr_cap);
+
+    // Setting the width or height attribute reallocates the drawing buffer, even if the value does
+    // not change, so only set them if the size actually changed.
+    if canvas.width() != width || canvas.height() != height {
+        canvas.set_width(width);
+        canvas.set_height(height);
+
+        true
+    } else {
+        false
+    }
+}
+
+fn device_pixel_size(canvas: &HtmlCanvasElement, dpr_cap: Option<f32>) -> (u32, u32) {
+    let mut ratio = window().unwrap().device_pixel_ratio();
+
+    if let Some(dpr_cap) = dpr_cap {
+        ratio = ratio.min(dpr_cap as f64);
+    }
+
+    let width = (canvas.client_width() as f64 * ratio).round() as u32;
+    let height = (canvas.client_height() as f64 * ratio).round() as u32;
+
+    (width, height)
+}
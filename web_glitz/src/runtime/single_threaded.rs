@@ -118,23 +118,26 @@ use js_sys::{Int32Array, Promise};
 use serde_derive::Serialize;
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::{JsCast, JsValue};
-use web_sys::{HtmlCanvasElement, WebGl2RenderingContext as Gl};
+use web_sys::{HtmlCanvasElement, WebGl2RenderingContext as Gl, WebglLoseContext};
 
-use crate::buffer::{Buffer, BufferId, IntoBuffer, UsageHint};
+use crate::buffer::{Buffer, BufferId, IntoBuffer, PurgePoolCommand, SharedBufferPool, UsageHint};
 use crate::extensions::Extension;
 use crate::image::format::{
     InternalFormat, Multisamplable, Multisample, RenderbufferFormat, TextureFormat,
 };
 use crate::image::renderbuffer::{Renderbuffer, RenderbufferDescriptor};
 use crate::image::sampler::{
-    MagnificationFilter, MinificationFilter, Sampler, SamplerDescriptor, ShadowSampler,
-    ShadowSamplerDescriptor,
+    MagnificationFilter, MinificationFilter, Sampler, SamplerCache, SamplerDescriptor,
+    ShadowSampler, ShadowSamplerDescriptor,
 };
 use crate::image::texture_2d::{Texture2D, Texture2DDescriptor};
 use crate::image::texture_2d_array::{Texture2DArray, Texture2DArrayDescriptor};
 use crate::image::texture_3d::{Texture3D, Texture3DDescriptor};
 use crate::image::texture_cube::{TextureCube, TextureCubeDescriptor};
 use crate::image::MaxMipmapLevelsExceeded;
+use crate::pipeline::graphics::graphics_pipeline::{
+    CreateGraphicsPipelineAsyncCommand, GraphicsPipelineCache,
+};
 use crate::pipeline::graphics::shader::{
     FragmentShaderAllocateCommand, VertexShaderAllocateCommand,
 };
@@ -149,15 +152,17 @@ use crate::rendering::{
     MultisampleRenderTarget, MultisampleRenderTargetDescriptor, RenderTarget,
     RenderTargetDescriptor,
 };
-use crate::runtime::executor_job::{job, ExecutorJob, JobState};
+use crate::runtime::debug;
+use crate::runtime::executor_job::{detached_job, job, ExecutorJob, JobState};
 use crate::runtime::fenced::JsTimeoutFencedTaskRunner;
 use crate::runtime::rendering_context::{
     CreateGraphicsPipelineError, MaxColorBuffersExceeded, UnsupportedSampleCount,
 };
 use crate::runtime::state::DynamicState;
 use crate::runtime::{
-    Connection, ContextOptions, Execution, PowerPreference, RenderingContext,
-    ShaderCompilationError, SupportedSamples,
+    CommandError, Connection, ContextOptions, DeletionQueue, Execution, FormatSupport, FrameStats,
+    MipmapHint, PendingDeletions, PollHandle, PowerPreference, RenderingContext,
+    ShaderCompilationError, StateMismatch, StateSnapshot, SupportedSamples,
 };
 use crate::task::{GpuTask, Progress};
 use wasm_bindgen::__rt::core::mem::MaybeUninit;
@@ -219,6 +224,11 @@ pub struct SingleThreadedContext {
     object_id_gen: ObjectIdGen,
     max_color_attachments: u8,
     supported_samples_cache: Rc<RefCell<HashMap<u32, SupportedSamples>>>,
+    format_support_cache: Rc<RefCell<HashMap<u32, FormatSupport>>>,
+    sampler_cache: Rc<RefCell<SamplerCache>>,
+    graphics_pipeline_cache: Rc<RefCell<GraphicsPipelineCache>>,
+    buffer_pool: SharedBufferPool,
+    pending_deletions: Rc<PendingDeletions>,
 }
 
 impl RenderingContext for SingleThreadedContext {
@@ -226,6 +236,36 @@ impl RenderingContext for SingleThreadedContext {
         self.id
     }
 
+    fn deletion_queue(&self) -> DeletionQueue {
+        self.pending_deletions.handle()
+    }
+
+    fn shared_buffer_pool(&self) -> SharedBufferPool {
+        self.buffer_pool.clone()
+    }
+
+    fn format_support<F>(&self) -> FormatSupport
+    where
+        F: InternalFormat,
+    {
+        let mut cache = self.format_support_cache.borrow_mut();
+
+        if let Some(format_support) = cache.get(&F::ID) {
+            *format_support
+        } else {
+            let executor = self.executor.deref().borrow();
+            let connection = executor.connection.deref().borrow();
+
+            let (gl, _) = unsafe { connection.unpack() };
+
+            let format_support = FormatSupport::probe(gl, F::ID);
+
+            cache.insert(F::ID, format_support);
+
+            format_support
+        }
+    }
+
     fn get_extension<T>(&self) -> Option<T>
     where
         T: Extension,
@@ -405,6 +445,49 @@ impl RenderingContext for SingleThreadedContext {
         GraphicsPipeline::create(self, object_id, &mut connection, descriptor)
     }
 
+    fn try_create_graphics_pipeline_async<V, R, Tf>(
+        &self,
+        descriptor: GraphicsPipelineDescriptor<V, R, Tf>,
+    ) -> Execution<Result<GraphicsPipeline<V, R, Tf>, CreateGraphicsPipelineError>>
+    where
+        V: 'static,
+        R: 'static,
+        Tf: 'static,
+    {
+        let object_id = self.object_id_gen.next();
+
+        self.submit(CreateGraphicsPipelineAsyncCommand::new(
+            self.clone(),
+            object_id,
+            descriptor,
+        ))
+    }
+
+    fn try_get_or_create_graphics_pipeline<V, R>(
+        &self,
+        descriptor: &GraphicsPipelineDescriptor<V, R, ()>,
+    ) -> Result<Rc<GraphicsPipeline<V, R, ()>>, CreateGraphicsPipelineError>
+    where
+        V: 'static,
+        R: 'static,
+    {
+        if let Some(pipeline) = self.graphics_pipeline_cache.borrow().get(descriptor) {
+            return Ok(pipeline);
+        }
+
+        let pipeline = Rc::new(self.try_create_graphics_pipeline(descriptor)?);
+
+        self.graphics_pipeline_cache
+            .borrow_mut()
+            .insert(descriptor, pipeline.clone());
+
+        Ok(pipeline)
+    }
+
+    fn clear_graphics_pipeline_cache(&self) {
+        self.graphics_pipeline_cache.borrow_mut().clear();
+    }
+
     fn create_render_target<C, Ds>(
         &self,
         descriptor: RenderTargetDescriptor<(C,), Ds>,
@@ -413,6 +496,8 @@ impl RenderingContext for SingleThreadedContext {
             color_attachments,
             depth_stencil_attachment,
             context_id,
+            render_area,
+            attachments,
             ..
         } = descriptor;
         let object_id = self.object_id_gen.next();
@@ -425,6 +510,8 @@ impl RenderingContext for SingleThreadedContext {
             object_id,
             context_id: self.id,
             render_pass_id_gen: self.object_id_gen.clone(),
+            render_area,
+            attachments,
         }
     }
 
@@ -437,6 +524,8 @@ impl RenderingContext for SingleThreadedContext {
             depth_stencil_attachment,
             color_attachment_count,
             context_id,
+            render_area,
+            attachments,
         } = descriptor;
 
         context_id.verify(self.id);
@@ -455,6 +544,8 @@ impl RenderingContext for SingleThreadedContext {
                 object_id,
                 context_id: self.id,
                 render_pass_id_gen: self.object_id_gen.clone(),
+                render_area,
+                attachments,
             })
         }
     }
@@ -468,6 +559,7 @@ impl RenderingContext for SingleThreadedContext {
             depth_stencil_attachment,
             samples,
             context_id,
+            attachments,
             ..
         } = descriptor;
         let object_id = self.object_id_gen.next();
@@ -481,6 +573,7 @@ impl RenderingContext for SingleThreadedContext {
             object_id,
             context_id: self.id,
             render_pass_id_gen: self.object_id_gen.clone(),
+            attachments,
         }
     }
 
@@ -494,6 +587,7 @@ impl RenderingContext for SingleThreadedContext {
             samples,
             color_attachment_count,
             context_id,
+            attachments,
         } = descriptor;
 
         context_id.verify(self.id);
@@ -513,6 +607,7 @@ impl RenderingContext for SingleThreadedContext {
                 object_id,
                 context_id: self.id,
                 render_pass_id_gen: self.object_id_gen.clone(),
+                attachments,
             })
         }
     }
@@ -573,26 +668,90 @@ impl RenderingContext for SingleThreadedContext {
         Min: MinificationFilter + Copy + 'static,
         Mag: MagnificationFilter + Copy + 'static,
     {
-        let object_id = self.object_id_gen.next();
+        let key = descriptor.key();
+        let mut cache = self.sampler_cache.borrow_mut();
 
-        Sampler::new(self, object_id, descriptor)
+        if let Some((object_id, data)) = cache.get(&key) {
+            Sampler::shared(object_id, data, descriptor)
+        } else {
+            let object_id = self.object_id_gen.next();
+            let sampler = Sampler::new(self, object_id, descriptor);
+
+            cache.insert(key, sampler.object_id(), sampler.data());
+
+            sampler
+        }
     }
 
     fn create_shadow_sampler(&self, descriptor: &ShadowSamplerDescriptor) -> ShadowSampler {
-        let object_id = self.object_id_gen.next();
+        let key = descriptor.key();
+        let mut cache = self.sampler_cache.borrow_mut();
 
-        ShadowSampler::new(self, object_id, descriptor)
+        if let Some((object_id, data)) = cache.get(&key) {
+            ShadowSampler::shared(object_id, data, descriptor)
+        } else {
+            let object_id = self.object_id_gen.next();
+            let sampler = ShadowSampler::new(self, object_id, descriptor);
+
+            cache.insert(key, sampler.object_id(), sampler.data());
+
+            sampler
+        }
+    }
+
+    fn take_command_errors(&self) -> Vec<CommandError> {
+        let executor = self.executor.deref().borrow();
+        let mut connection = executor.connection.deref().borrow_mut();
+
+        connection.take_command_errors()
     }
 
     fn submit<T>(&self, task: T) -> Execution<T::Output>
     where
         T: GpuTask<Connection> + 'static,
     {
+        self.submit_pending_deletions();
+
         self.executor.accept(task)
     }
+
+    fn submit_and_forget<T>(&self, task: T)
+    where
+        T: GpuTask<Connection, Output = ()> + 'static,
+    {
+        self.submit_pending_deletions();
+
+        self.executor.accept_and_forget(task)
+    }
+
+    fn submit_poll<T>(&self, task: T) -> PollHandle<T::Output>
+    where
+        T: GpuTask<Connection> + 'static,
+    {
+        let execution = self.submit(task);
+
+        PollHandle::new(
+            execution,
+            Rc::downgrade(&self.executor.fenced_task_queue_runner),
+        )
+    }
 }
 
 impl SingleThreadedContext {
+    fn set_debug(&self, debug: bool) {
+        let executor = self.executor.deref().borrow();
+
+        executor.connection.deref().borrow_mut().set_debug(debug);
+    }
+
+    fn set_mipmap_hint(&self, mipmap_hint: MipmapHint) {
+        let executor = self.executor.deref().borrow();
+        let connection = executor.connection.deref().borrow();
+        let (gl, _) = unsafe { connection.unpack() };
+
+        gl.hint(Gl::GENERATE_MIPMAP_HINT, mipmap_hint.id());
+    }
+
     pub unsafe fn from_webgl2_context(gl: Gl, state: DynamicState) -> Self {
         let id = ID_GEN.with(|id_gen| id_gen.next());
 
@@ -606,23 +765,225 @@ impl SingleThreadedContext {
             .unwrap()
             .as_f64()
             .unwrap() as u8;
+        let pending_deletions = Rc::new(PendingDeletions::new());
+
+        let mut connection = Connection::new(id, gl, state);
+
+        connection.set_deletion_queue(pending_deletions.handle());
+
+        let object_id_gen = ObjectIdGen::new(id);
 
         SingleThreadedContext {
-            executor: SingleThreadedExecutor::new(Connection::new(id, gl, state)).into(),
+            executor: SingleThreadedExecutor::new(connection).into(),
             id,
-            object_id_gen: ObjectIdGen::new(id),
+            object_id_gen: object_id_gen.clone(),
             max_color_attachments,
             supported_samples_cache: Rc::new(RefCell::new(HashMap::new())),
+            format_support_cache: Rc::new(RefCell::new(HashMap::new())),
+            sampler_cache: Rc::new(RefCell::new(SamplerCache::new())),
+            graphics_pipeline_cache: Rc::new(RefCell::new(GraphicsPipelineCache::new())),
+            buffer_pool: SharedBufferPool::new(object_id_gen),
+            pending_deletions,
         }
     }
+
+    /// Submits the deletion commands for all GPU objects that were dropped since the last time
+    /// pending deletions were submitted, see [DeletionQueue].
+    fn submit_pending_deletions(&self) {
+        for task in self.pending_deletions.take() {
+            self.executor.accept(task);
+        }
+    }
+
+    /// Returns the number of GL state changes (e.g. program binds, enabling or disabling the depth
+    /// test, sampler binds) that have been issued by this context since it was created.
+    ///
+    /// State changes that would not alter the current state of the context are skipped and are not
+    /// counted. Intended for debugging and profiling, e.g. to verify that many draws that share a
+    /// pipeline do not re-issue the same state for every draw.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called while the context is executing a task.
+    pub fn debug_state_change_count(&self) -> u64 {
+        let connection = self.executor.connection.borrow();
+        let (_, state) = unsafe { connection.unpack() };
+
+        state.state_change_count()
+    }
+
+    /// Returns the number of times binding a [BindGroup] was skipped by this context since it was
+    /// created, because that same bind group was still bound to the same bind group index.
+    ///
+    /// Intended for debugging and profiling, e.g. to verify that many draws that share a bind group
+    /// do not re-bind its resources for every draw.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called while the context is executing a task.
+    pub fn debug_skipped_bind_group_count(&self) -> u64 {
+        let connection = self.executor.connection.borrow();
+        let (_, state) = unsafe { connection.unpack() };
+
+        state.skipped_bind_group_count()
+    }
+
+    /// Returns the number of GPU objects (e.g. buffers, textures, pipelines) that have been
+    /// dropped, but for which the deletion has not yet been submitted to this context.
+    ///
+    /// The deletions are submitted the next time a task is submitted to this context, or when this
+    /// context is flushed or finished. Intended for debugging, e.g. to verify that dropped
+    /// resources release their GPU objects.
+    pub fn debug_pending_deletion_count(&self) -> usize {
+        self.pending_deletions.len()
+    }
+
+    /// Returns [FrameStats] (the number of draw calls, state changes, buffer uploads, and vertices
+    /// and triangles drawn) for all work that has been executed by this context since it was
+    /// created.
+    ///
+    /// The statistics are collected on the CPU side as commands are executed and do not involve
+    /// any GPU queries. Use [FrameStats::since] to obtain the statistics for a single frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called while the context is executing a task.
+    pub fn debug_frame_stats(&self) -> FrameStats {
+        let connection = self.executor.connection.borrow();
+        let (_, state) = unsafe { connection.unpack() };
+
+        state.frame_stats()
+    }
+
+    /// Captures a [StateSnapshot] of the GL state tracked by this context (e.g. the program in
+    /// use, blending, depth test and face-culling configuration, the viewport).
+    ///
+    /// This only reads the context's state cache, it does not query the driver. Intended for
+    /// debugging, e.g. to log the state of the context after a submitted task failed:
+    ///
+    /// ```
+    /// # use web_glitz::runtime::single_threaded::SingleThreadedContext;
+    /// # fn wrapper(context: &SingleThreadedContext) {
+    /// web_sys::console::log_1(&format!("{:#?}", context.debug_capture_state()).into());
+    /// # }
+    /// ```
+    ///
+    /// See also [debug_verify_state].
+    ///
+    /// # Panics
+    ///
+    /// Panics if called while the context is executing a task.
+    ///
+    /// [debug_verify_state]: SingleThreadedContext::debug_verify_state
+    pub fn debug_capture_state(&self) -> StateSnapshot {
+        let connection = self.executor.connection.borrow();
+        let (_, state) = unsafe { connection.unpack() };
+
+        state.snapshot()
+    }
+
+    /// Compares the GL state tracked by this context against the state reported by the driver,
+    /// returning a [StateMismatch] for each parameter for which the values differ.
+    ///
+    /// Returns an empty `Vec` if the tracked state matches the driver state for every parameter
+    /// included in a [StateSnapshot]. A mismatch indicates that the context's state cache is out
+    /// of sync with the driver, e.g. because state was changed on the raw WebGL context without
+    /// updating the state cache (see [Connection::unpack_mut]).
+    ///
+    /// Unlike [debug_capture_state], this queries the driver for every parameter; each query is
+    /// synchronous and may stall the GPU pipeline. Intended for debugging only.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called while the context is executing a task.
+    ///
+    /// [debug_capture_state]: SingleThreadedContext::debug_capture_state
+    pub fn debug_verify_state(&self) -> Vec<StateMismatch> {
+        let connection = self.executor.connection.borrow();
+        let (gl, state) = unsafe { connection.unpack() };
+
+        state.snapshot().verify(gl)
+    }
+
+    /// Destroys this context, releasing all GPU objects associated with it.
+    ///
+    /// Dropping the last handle to a context also releases its GPU objects, but only once the
+    /// browser garbage collects the underlying WebGL context, and only if no resources (e.g. a
+    /// [Buffer] or [Texture2D]) are kept alive elsewhere in the application. Browsers limit the
+    /// number of live WebGL contexts per page; an application that creates a new context for each
+    /// view of a single-page application should destroy the context when the view is closed,
+    /// rather than relying on it being dropped.
+    ///
+    /// Destroying the context:
+    ///
+    /// 1. Deletes the GPU objects of any resources that have already been dropped, of any cached
+    ///    graphics pipelines (see [RenderingContext::try_get_or_create_graphics_pipeline]) and of
+    ///    any pooled buffers (see [FrameAllocator]).
+    /// 2. Discards any submitted tasks that have not yet finished (tasks that are waiting on a
+    ///    fence and tasks that were submitted while another task was executing). The [Execution]
+    ///    for such a task is cancelled (see [Execution::is_cancelled]).
+    /// 3. Loses the WebGL context with the `WEBGL_lose_context` extension (if available), which
+    ///    instructs the browser to release all remaining GPU objects, including those of resources
+    ///    that are still alive.
+    ///
+    /// Any remaining handles to the context (this handle and its clones) are poisoned: a task
+    /// submitted through such a handle is discarded immediately and its [Execution] is cancelled.
+    /// An animation frame loop started on the context (see
+    /// [RenderingContext::run_animation_frames]) stops. Resources that are still alive may still be
+    /// dropped safely. Destroying a context that was already destroyed has no effect.
+    ///
+    /// Returns [ContextBusy] if called while the context is executing a task (e.g. from within
+    /// [GpuTask::progress]), in which case the context is not destroyed.
+    ///
+    /// [RenderingContext::try_get_or_create_graphics_pipeline]: crate::runtime::RenderingContext::try_get_or_create_graphics_pipeline
+    /// [RenderingContext::run_animation_frames]: crate::runtime::RenderingContext::run_animation_frames
+    /// [FrameAllocator]: crate::buffer::FrameAllocator
+    pub fn destroy(&self) -> Result<(), ContextBusy> {
+        if self.executor.connection.try_borrow_mut().is_err() {
+            return Err(ContextBusy);
+        }
+
+        if self.executor.destroyed.get() {
+            return Ok(());
+        }
+
+        // Clearing the pipeline cache drops the cached pipelines, which schedules their deletion.
+        self.graphics_pipeline_cache.borrow_mut().clear();
+        self.executor
+            .accept(PurgePoolCommand::new(&self.buffer_pool));
+        self.submit_pending_deletions();
+
+        // Dropping the discarded jobs drops their result senders, which cancels their executions.
+        self.executor.fenced_task_queue_runner.borrow_mut().clear();
+        self.executor.buffer.borrow_mut().clear();
+        self.executor.destroyed.set(true);
+
+        let connection = self.executor.connection.borrow();
+        let (gl, _) = unsafe { connection.unpack() };
+
+        if let Ok(Some(extension)) = gl.get_extension("WEBGL_lose_context") {
+            extension
+                .unchecked_into::<WebglLoseContext>()
+                .lose_context();
+        }
+
+        Ok(())
+    }
 }
 
+/// Error returned by [SingleThreadedContext::destroy] when it is called while the context is
+/// executing a task.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ContextBusy;
+
 struct SingleThreadedExecutor {
     connection: Rc<RefCell<Connection>>,
     fenced_task_queue_runner: Rc<RefCell<JsTimeoutFencedTaskRunner>>,
     buffer: Rc<RefCell<VecDeque<Box<dyn ExecutorJob>>>>,
     process_buffer_closure: Rc<RefCell<Option<Closure<dyn FnMut(JsValue)>>>>,
     process_buffer_promise: Promise,
+    process_buffer_queued: Rc<Cell<bool>>,
+    destroyed: Cell<bool>,
 }
 
 impl SingleThreadedExecutor {
@@ -648,6 +1009,12 @@ impl SingleThreadedExecutor {
         let fenced_task_queue_runner_clone = fenced_task_queue_runner.clone();
         let buffer_clone = buffer.clone();
 
+        // Tracks whether a micro-task that processes the buffer is queued. This is tracked
+        // separately from the buffer's length, as the buffer may be cleared while a micro-task is
+        // queued (see `SingleThreadedContext::destroy`).
+        let process_buffer_queued = Rc::new(Cell::new(false));
+        let process_buffer_queued_clone = process_buffer_queued.clone();
+
         let callback = Closure::wrap(Box::new(move |_| {
             while let Some(mut job) = buffer_clone.borrow_mut().pop_front() {
                 if let JobState::ContinueFenced = job.progress(&mut connection_clone.borrow_mut()) {
@@ -655,6 +1022,8 @@ impl SingleThreadedExecutor {
                 }
             }
 
+            process_buffer_queued_clone.set(false);
+
             if Rc::strong_count(&rc_clone) == 1 {
                 // The executor was dropped, clean up after ourselves
                 let callback = rc_clone.borrow_mut().take();
@@ -671,6 +1040,8 @@ impl SingleThreadedExecutor {
             buffer,
             process_buffer_closure: rc,
             process_buffer_promise: Promise::resolve(&JsValue::null()),
+            process_buffer_queued,
+            destroyed: Cell::new(false),
         }
     }
 
@@ -678,8 +1049,12 @@ impl SingleThreadedExecutor {
     where
         T: GpuTask<Connection> + 'static,
     {
+        if self.destroyed.get() {
+            return Execution::Cancelled;
+        }
+
         if let Ok(mut connection) = self.connection.try_borrow_mut() {
-            let output = task.progress(&mut connection);
+            let output = debug::progress_job(&mut task, &mut connection);
 
             // Explicitly drop the connection reference, otherwise it lives until the end of the
             // scope while the task queue runner may want to use it below, causing a panic.
@@ -703,35 +1078,61 @@ impl SingleThreadedExecutor {
             // after the current task is done.
 
             let (job, execution) = job(task);
-            let mut buffer = self.buffer.borrow_mut();
 
-            buffer.push_back(Box::new(job));
+            self.buffer_job(Box::new(job));
+
+            execution
+        }
+    }
+
+    fn accept_and_forget<T>(&self, mut task: T)
+    where
+        T: GpuTask<Connection, Output = ()> + 'static,
+    {
+        if self.destroyed.get() {
+            return;
+        }
 
-            // Only queue a new micro task if this if the first job to be buffered, otherwise a
-            // micro task will have already been queued.
-            if buffer.len() == 1 {
-                let ref_cell: &RefCell<_> = self.process_buffer_closure.borrow();
-                let callback_ref = ref_cell.borrow();
+        if let Ok(mut connection) = self.connection.try_borrow_mut() {
+            let output = debug::progress_job(&mut task, &mut connection);
 
-                let promise = self
-                    .process_buffer_promise
-                    .then(callback_ref.as_ref().unwrap());
+            mem::drop(connection);
 
-                // Explicitly drop promise to get around "unused_must_use" warning; we really don't
-                // need to use this promise...
-                mem::drop(promise);
+            if let Progress::ContinueFenced = output {
+                self.fenced_task_queue_runner
+                    .borrow_mut()
+                    .schedule(Box::new(detached_job(task)));
             }
+        } else {
+            self.buffer_job(Box::new(detached_job(task)));
+        }
+    }
 
-            execution
+    fn buffer_job(&self, job: Box<dyn ExecutorJob>) {
+        self.buffer.borrow_mut().push_back(job);
+
+        // Only queue a new micro task if no micro task is queued yet.
+        if !self.process_buffer_queued.get() {
+            self.process_buffer_queued.set(true);
+
+            let ref_cell: &RefCell<_> = self.process_buffer_closure.borrow();
+            let callback_ref = ref_cell.borrow();
+
+            let promise = self
+                .process_buffer_promise
+                .then(callback_ref.as_ref().unwrap());
+
+            // Explicitly drop promise to get around "unused_must_use" warning; we really don't
+            // need to use this promise...
+            mem::drop(promise);
         }
     }
 }
 
 impl Drop for SingleThreadedExecutor {
     fn drop(&mut self) {
-        // Only schedule the callback if the buffer is empty, otherwise a callback is already
-        // queued.
-        if self.buffer.deref().borrow().len() == 0 {
+        // Only schedule the callback if no callback is queued yet.
+        if !self.process_buffer_queued.get() {
             let ref_cell: &RefCell<_> = self.process_buffer_closure.borrow();
             let callback_ref = ref_cell.borrow();
 
@@ -791,13 +1192,19 @@ impl Options for ContextOptions<DefaultMultisampleRenderTarget<DefaultRGBABuffer
             .map_err(|e| e.as_string().unwrap())?
             .unwrap()
             .unchecked_into();
+
         let state = DynamicState::initial(&gl);
         let samples = gl.get_parameter(Gl::SAMPLES).unwrap().as_f64().unwrap() as u8;
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
         let render_target = DefaultMultisampleRenderTarget::new(
             context.id(),
             samples,
             context.object_id_gen.clone(),
+            gl,
         );
 
         Ok((context, render_target))
@@ -833,13 +1240,19 @@ impl Options
             .map_err(|e| e.as_string().unwrap())?
             .unwrap()
             .unchecked_into();
+
         let state = DynamicState::initial(&gl);
         let samples = gl.get_parameter(Gl::SAMPLES).unwrap().as_f64().unwrap() as u8;
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
         let render_target = DefaultMultisampleRenderTarget::new(
             context.id(),
             samples,
             context.object_id_gen.clone(),
+            gl,
         );
 
         Ok((context, render_target))
@@ -878,11 +1291,16 @@ impl Options
 
         let state = DynamicState::initial(&gl);
         let samples = gl.get_parameter(Gl::SAMPLES).unwrap().as_f64().unwrap() as u8;
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
         let render_target = DefaultMultisampleRenderTarget::new(
             context.id(),
             samples,
             context.object_id_gen.clone(),
+            gl,
         );
 
         Ok((context, render_target))
@@ -918,13 +1336,19 @@ impl Options
             .map_err(|e| e.as_string().unwrap())?
             .unwrap()
             .unchecked_into();
+
         let state = DynamicState::initial(&gl);
         let samples = gl.get_parameter(Gl::SAMPLES).unwrap().as_f64().unwrap() as u8;
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
         let render_target = DefaultMultisampleRenderTarget::new(
             context.id(),
             samples,
             context.object_id_gen.clone(),
+            gl,
         );
 
         Ok((context, render_target))
@@ -958,13 +1382,19 @@ impl Options for ContextOptions<DefaultMultisampleRenderTarget<DefaultRGBBuffer,
             .map_err(|e| e.as_string().unwrap())?
             .unwrap()
             .unchecked_into();
+
         let state = DynamicState::initial(&gl);
         let samples = gl.get_parameter(Gl::SAMPLES).unwrap().as_f64().unwrap() as u8;
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
         let render_target = DefaultMultisampleRenderTarget::new(
             context.id(),
             samples,
             context.object_id_gen.clone(),
+            gl,
         );
 
         Ok((context, render_target))
@@ -1000,13 +1430,19 @@ impl Options
             .map_err(|e| e.as_string().unwrap())?
             .unwrap()
             .unchecked_into();
+
         let state = DynamicState::initial(&gl);
         let samples = gl.get_parameter(Gl::SAMPLES).unwrap().as_f64().unwrap() as u8;
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
         let render_target = DefaultMultisampleRenderTarget::new(
             context.id(),
             samples,
             context.object_id_gen.clone(),
+            gl,
         );
 
         Ok((context, render_target))
@@ -1045,11 +1481,16 @@ impl Options
 
         let state = DynamicState::initial(&gl);
         let samples = gl.get_parameter(Gl::SAMPLES).unwrap().as_f64().unwrap() as u8;
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
         let render_target = DefaultMultisampleRenderTarget::new(
             context.id(),
             samples,
             context.object_id_gen.clone(),
+            gl,
         );
 
         Ok((context, render_target))
@@ -1085,13 +1526,19 @@ impl Options
             .map_err(|e| e.as_string().unwrap())?
             .unwrap()
             .unchecked_into();
+
         let state = DynamicState::initial(&gl);
         let samples = gl.get_parameter(Gl::SAMPLES).unwrap().as_f64().unwrap() as u8;
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
         let render_target = DefaultMultisampleRenderTarget::new(
             context.id(),
             samples,
             context.object_id_gen.clone(),
+            gl,
         );
 
         Ok((context, render_target))
@@ -1125,9 +1572,15 @@ impl Options for ContextOptions<DefaultRenderTarget<DefaultRGBABuffer, ()>> {
             .map_err(|e| e.as_string().unwrap())?
             .unwrap()
             .unchecked_into();
+
         let state = DynamicState::initial(&gl);
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
-        let render_target = DefaultRenderTarget::new(context.id(), context.object_id_gen.clone());
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
+        let render_target =
+            DefaultRenderTarget::new(context.id(), context.object_id_gen.clone(), gl);
 
         Ok((context, render_target))
     }
@@ -1160,9 +1613,15 @@ impl Options for ContextOptions<DefaultRenderTarget<DefaultRGBABuffer, DefaultDe
             .map_err(|e| e.as_string().unwrap())?
             .unwrap()
             .unchecked_into();
+
         let state = DynamicState::initial(&gl);
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
-        let render_target = DefaultRenderTarget::new(context.id(), context.object_id_gen.clone());
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
+        let render_target =
+            DefaultRenderTarget::new(context.id(), context.object_id_gen.clone(), gl);
 
         Ok((context, render_target))
     }
@@ -1197,8 +1656,13 @@ impl Options for ContextOptions<DefaultRenderTarget<DefaultRGBABuffer, DefaultDe
             .unchecked_into();
 
         let state = DynamicState::initial(&gl);
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
-        let render_target = DefaultRenderTarget::new(context.id(), context.object_id_gen.clone());
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
+        let render_target =
+            DefaultRenderTarget::new(context.id(), context.object_id_gen.clone(), gl);
 
         Ok((context, render_target))
     }
@@ -1231,9 +1695,15 @@ impl Options for ContextOptions<DefaultRenderTarget<DefaultRGBABuffer, DefaultSt
             .map_err(|e| e.as_string().unwrap())?
             .unwrap()
             .unchecked_into();
+
         let state = DynamicState::initial(&gl);
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
-        let render_target = DefaultRenderTarget::new(context.id(), context.object_id_gen.clone());
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
+        let render_target =
+            DefaultRenderTarget::new(context.id(), context.object_id_gen.clone(), gl);
 
         Ok((context, render_target))
     }
@@ -1266,9 +1736,15 @@ impl Options for ContextOptions<DefaultRenderTarget<DefaultRGBBuffer, ()>> {
             .map_err(|e| e.as_string().unwrap())?
             .unwrap()
             .unchecked_into();
+
         let state = DynamicState::initial(&gl);
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
-        let render_target = DefaultRenderTarget::new(context.id(), context.object_id_gen.clone());
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
+        let render_target =
+            DefaultRenderTarget::new(context.id(), context.object_id_gen.clone(), gl);
 
         Ok((context, render_target))
     }
@@ -1301,9 +1777,15 @@ impl Options for ContextOptions<DefaultRenderTarget<DefaultRGBBuffer, DefaultDep
             .map_err(|e| e.as_string().unwrap())?
             .unwrap()
             .unchecked_into();
+
         let state = DynamicState::initial(&gl);
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
-        let render_target = DefaultRenderTarget::new(context.id(), context.object_id_gen.clone());
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
+        let render_target =
+            DefaultRenderTarget::new(context.id(), context.object_id_gen.clone(), gl);
 
         Ok((context, render_target))
     }
@@ -1338,8 +1820,13 @@ impl Options for ContextOptions<DefaultRenderTarget<DefaultRGBBuffer, DefaultDep
             .unchecked_into();
 
         let state = DynamicState::initial(&gl);
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
-        let render_target = DefaultRenderTarget::new(context.id(), context.object_id_gen.clone());
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
+        let render_target =
+            DefaultRenderTarget::new(context.id(), context.object_id_gen.clone(), gl);
 
         Ok((context, render_target))
     }
@@ -1372,9 +1859,15 @@ impl Options for ContextOptions<DefaultRenderTarget<DefaultRGBBuffer, DefaultSte
             .map_err(|e| e.as_string().unwrap())?
             .unwrap()
             .unchecked_into();
+
         let state = DynamicState::initial(&gl);
-        let context = SingleThreadedContext::from_webgl2_context(gl, state);
-        let render_target = DefaultRenderTarget::new(context.id(), context.object_id_gen.clone());
+        let context = SingleThreadedContext::from_webgl2_context(gl.clone(), state);
+
+        context.set_debug(self.debug());
+        context.set_mipmap_hint(self.mipmap_hint());
+
+        let render_target =
+            DefaultRenderTarget::new(context.id(), context.object_id_gen.clone(), gl);
 
         Ok((context, render_target))
     }
@@ -1,6 +1,7 @@
 use std::borrow::Borrow;
 use std::collections::hash_map::Entry;
 use std::hash::{Hash, Hasher};
+use std::rc::Rc;
 
 use fnv::{FnvHashMap, FnvHasher};
 
@@ -15,6 +16,7 @@ use web_sys::{
 
 use crate::pipeline::graphics::transform_feedback::layout_descriptor::TransformFeedbackVaryings;
 
+use crate::pipeline::graphics::primitive_assembly::Topology;
 use crate::pipeline::graphics::util::BufferDescriptor;
 use crate::pipeline::graphics::vertex::index_buffer::IndexDataDescriptor;
 use crate::pipeline::graphics::vertex::layout_descriptor::VertexAttributeSlotDescriptor;
@@ -29,6 +31,7 @@ use crate::pipeline::resources::resource_slot::{
 use crate::pipeline::resources::{ResourceSlotIdentifier, SampledTextureType};
 use crate::rendering::attachment::AttachmentData;
 use crate::runtime::index_lru::IndexLRU;
+use crate::runtime::{FrameStats, ObjectHandle, StateSnapshot};
 use crate::util::{identical, JsId};
 use std::ops::Deref;
 use wasm_bindgen::convert::{IntoWasmAbi, RefFromWasmAbi};
@@ -37,7 +40,9 @@ pub struct DynamicState {
     framebuffer_cache: FnvHashMap<u64, (Framebuffer, [Option<JsId>; 17])>,
     vertex_array_cache: FnvHashMap<u64, (WebGlVertexArrayObject, [Option<JsId>; 17])>,
     program_cache: FnvHashMap<ProgramKey, Program>,
+    pending_programs: FnvHashMap<ProgramKey, WebGlProgram>,
     read_framebuffer: WebGlFramebuffer,
+    clear_framebuffer: WebGlFramebuffer,
     max_draw_buffers: usize,
     active_program: Option<u32>,
     bound_array_buffer: Option<u32>,
@@ -101,28 +106,36 @@ pub struct DynamicState {
     blend_func_source_alpha: BlendFactor,
     blend_func_destination_rgb: BlendFactor,
     blend_func_destination_alpha: BlendFactor,
-    //    color_mask: [bool;4],
+    color_mask: [bool; 4],
     //    cull_face: CullFace,
     //    front_face: FrontFace,
     line_width: f32,
-    //    pixel_pack_alignment: u32,
+    pixel_pack_alignment: i32,
     pixel_unpack_alignment: i32,
-    //    pixel_unpack_flip_y: bool,
-    //    pixel_unpack_premultiply_alpha: bool,
+    pixel_unpack_flip_y: bool,
+    pixel_unpack_premultiply_alpha: bool,
     //    pixel_unpack_colorspace_conversion: ColorspaceConversion,
-    //    pixel_pack_row_length: u32,
+    pixel_pack_row_length: i32,
     //    pixel_pack_skip_pixels: u32,
     //    pixel_pack_skip_rows: u32,
     pixel_unpack_row_length: i32,
     pixel_unpack_image_height: i32,
-    //    pixel_unpack_skip_pixels: u32,
-    //    pixel_unpack_skip_rows: u32,
+    pixel_unpack_skip_pixels: i32,
+    pixel_unpack_skip_rows: i32,
     //    pixel_unpack_skip_images: u32,
     //    sample_coverage: SampleCoverage,
     scissor: (i32, i32, u32, u32),
     viewport: (i32, i32, i32, i32),
     front_face: WindingOrder,
     cull_face: CullingMode,
+    state_change_count: u64,
+    bound_bind_groups: Vec<Option<(u64, u64)>>,
+    resource_bindings_generation: u64,
+    skipped_bind_group_count: u64,
+    draw_call_count: u64,
+    drawn_vertex_count: u64,
+    drawn_triangle_count: u64,
+    buffer_upload_count: u64,
 }
 
 impl DynamicState {
@@ -138,6 +151,128 @@ impl DynamicState {
         ProgramCache { state: self }
     }
 
+    /// Returns the number of state changes that have been issued to the WebGL context since this
+    /// [DynamicState] was created.
+    ///
+    /// Only actual state changes are counted: state updates that match the current state are
+    /// skipped and do not increment this count.
+    pub fn state_change_count(&self) -> u64 {
+        self.state_change_count
+    }
+
+    /// Returns the number of times binding a bind group was skipped since this [DynamicState] was
+    /// created, because that same bind group was still bound for the same bind group index.
+    pub fn skipped_bind_group_count(&self) -> u64 {
+        self.skipped_bind_group_count
+    }
+
+    /// Returns [FrameStats] for all work that has been issued to the WebGL context since this
+    /// [DynamicState] was created.
+    pub fn frame_stats(&self) -> FrameStats {
+        FrameStats {
+            draw_calls: self.draw_call_count,
+            state_changes: self.state_change_count,
+            buffer_uploads: self.buffer_upload_count,
+            vertices: self.drawn_vertex_count,
+            triangles: self.drawn_triangle_count,
+        }
+    }
+
+    /// Records a draw call that streams `vertex_count` vertices (or indices) for each of
+    /// `instance_count` instances with the given `topology`.
+    pub(crate) fn record_draw(
+        &mut self,
+        topology: Topology,
+        vertex_count: usize,
+        instance_count: usize,
+    ) {
+        let instance_count = instance_count as u64;
+
+        self.draw_call_count += 1;
+        self.drawn_vertex_count += vertex_count as u64 * instance_count;
+        self.drawn_triangle_count += topology.triangle_count(vertex_count) as u64 * instance_count;
+    }
+
+    /// Records an upload of data from client memory into a GPU buffer.
+    pub(crate) fn record_buffer_upload(&mut self) {
+        self.buffer_upload_count += 1;
+    }
+
+    /// Returns a [StateSnapshot] of the current tracked state.
+    pub fn snapshot(&self) -> StateSnapshot {
+        StateSnapshot {
+            program: self.active_program.map(ObjectHandle),
+            vertex_array: self.bound_vertex_array.map(ObjectHandle),
+            array_buffer: self.bound_array_buffer.map(ObjectHandle),
+            draw_framebuffer: self.bound_draw_framebuffer.map(ObjectHandle),
+            read_framebuffer: self.bound_read_framebuffer.map(ObjectHandle),
+            active_texture: self.active_texture,
+            depth_test_enabled: self.depth_test_enabled,
+            stencil_test_enabled: self.stencil_test_enabled,
+            scissor_test_enabled: self.scissor_test_enabled,
+            blend_enabled: self.blend_enabled,
+            polygon_offset_fill_enabled: self.polygon_offset_fill_enabled,
+            rasterizer_discard_enabled: self.rasterizer_discard_enabled,
+            depth_func: self.depth_func,
+            depth_mask: self.depth_mask,
+            color_mask: self.color_mask,
+            blend_color: self.blend_color,
+            blend_equation_rgb: self.blend_equation_rgb,
+            blend_equation_alpha: self.blend_equation_alpha,
+            blend_func_source_rgb: self.blend_func_source_rgb,
+            blend_func_source_alpha: self.blend_func_source_alpha,
+            blend_func_destination_rgb: self.blend_func_destination_rgb,
+            blend_func_destination_alpha: self.blend_func_destination_alpha,
+            cull_face: self.cull_face,
+            front_face: self.front_face,
+            viewport: self.viewport,
+            scissor: self.scissor,
+        }
+    }
+
+    /// Returns `true` if the bind group identified by `object_id` is still bound at the
+    /// `bind_group_index`, in which case binding it again may be skipped.
+    ///
+    /// A bind group is considered "still bound" if it was the last bind group bound at the
+    /// `bind_group_index` (see [set_bound_bind_group]) and no texture, sampler or uniform buffer
+    /// bindings have changed since it was bound. Note that this is conservative: a change to any
+    /// binding busts the record for every bind group index, not just the indices that use the
+    /// binding.
+    pub(crate) fn skip_bind_group(&mut self, bind_group_index: u32, object_id: u64) -> bool {
+        let bound = self
+            .bound_bind_groups
+            .get(bind_group_index as usize)
+            .copied()
+            .flatten();
+
+        if bound == Some((object_id, self.resource_bindings_generation)) {
+            self.skipped_bind_group_count += 1;
+
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Records that the bind group identified by `object_id` was bound at the `bind_group_index`.
+    ///
+    /// Must be called after all of the bind group's resources have been bound.
+    pub(crate) fn set_bound_bind_group(&mut self, bind_group_index: u32, object_id: u64) {
+        let index = bind_group_index as usize;
+
+        if index >= self.bound_bind_groups.len() {
+            self.bound_bind_groups.resize(index + 1, None);
+        }
+
+        self.bound_bind_groups[index] = Some((object_id, self.resource_bindings_generation));
+    }
+
+    /// Ensures that no bind group is considered "still bound" (see [skip_bind_group]), e.g. after
+    /// a parameter of a bound texture was changed.
+    pub(crate) fn invalidate_bound_bind_groups(&mut self) {
+        self.resource_bindings_generation += 1;
+    }
+
     pub(crate) fn bind_default_read_framebuffer(&mut self, gl: &Gl) {
         let current = unsafe {
             self.bound_read_framebuffer
@@ -154,6 +289,28 @@ impl DynamicState {
         }
     }
 
+    /// Binds the framebuffer that is used only for clearing as the draw framebuffer, and disables
+    /// rasterizer discard, which would otherwise also discard the clear.
+    pub(crate) fn bind_clear_framebuffer(&mut self, gl: &Gl) {
+        self.set_rasterizer_discard_enabled(false)
+            .apply(gl)
+            .unwrap();
+
+        let current = unsafe {
+            self.bound_draw_framebuffer
+                .map(|abi| JsValue::ref_from_abi(abi))
+        };
+
+        if !identical(
+            current.as_ref().map(|v| v.deref()),
+            Some(&self.clear_framebuffer),
+        ) {
+            gl.bind_framebuffer(Gl::DRAW_FRAMEBUFFER, Some(&self.clear_framebuffer));
+
+            self.bound_draw_framebuffer = Some((&self.clear_framebuffer).into_abi());
+        }
+    }
+
     pub fn max_draw_buffers(&self) -> usize {
         self.max_draw_buffers
     }
@@ -167,6 +324,8 @@ impl DynamicState {
         if !identical(program, current.as_ref().map(|v| v.deref())) {
             self.active_program = program.map(|p| p.into_abi());
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.use_program(program);
 
@@ -197,6 +356,8 @@ impl DynamicState {
         if !identical(buffer, current.as_ref().map(|v| v.deref())) {
             self.bound_array_buffer = buffer.map(|b| b.into_abi());
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.bind_buffer(Gl::ARRAY_BUFFER, buffer);
 
@@ -232,6 +393,8 @@ impl DynamicState {
         };
 
         if do_update {
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.bind_buffer(Gl::ELEMENT_ARRAY_BUFFER, buffer);
 
@@ -254,6 +417,8 @@ impl DynamicState {
         if !identical(buffer, current.as_ref().map(|v| v.deref())) {
             self.bound_copy_read_buffer = buffer.map(|b| b.into_abi());
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.bind_buffer(Gl::COPY_READ_BUFFER, buffer);
 
@@ -276,6 +441,8 @@ impl DynamicState {
         if !identical(buffer, current.as_ref().map(|v| v.deref())) {
             self.bound_copy_write_buffer = buffer.map(|b| b.into_abi());
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.bind_buffer(Gl::COPY_WRITE_BUFFER, buffer);
 
@@ -298,6 +465,8 @@ impl DynamicState {
         if !identical(buffer, current.as_ref().map(|v| v.deref())) {
             self.bound_pixel_pack_buffer = buffer.map(|b| b.into_abi());
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.bind_buffer(Gl::PIXEL_PACK_BUFFER, buffer);
 
@@ -320,6 +489,8 @@ impl DynamicState {
         if !identical(buffer, current.as_ref().map(|v| v.deref())) {
             self.bound_pixel_unpack_buffer = buffer.map(|b| b.into_abi());
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.bind_buffer(Gl::PIXEL_UNPACK_BUFFER, buffer);
 
@@ -344,6 +515,8 @@ impl DynamicState {
             self.bound_transform_feedback_buffers[index as usize] =
                 buffer_range.map(|b| b.into_abi());
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 match buffer_range {
                     BufferRange::None => {
@@ -395,6 +568,9 @@ impl DynamicState {
         if !buffer_range.identical(&current.as_ref().map(|b| b.deref())) {
             self.bound_uniform_buffers[index as usize] = buffer_range.map(|b| b.into_abi());
 
+            self.state_change_count += 1;
+            self.resource_bindings_generation += 1;
+
             Some(move |context: &Gl| {
                 match buffer_range {
                     BufferRange::None => context.bind_buffer_base(Gl::UNIFORM_BUFFER, index, None),
@@ -421,6 +597,8 @@ impl DynamicState {
     pub fn unref_buffer(&mut self, buffer: &WebGlBuffer) {
         let abi = buffer.into_abi();
 
+        self.resource_bindings_generation += 1;
+
         if Some(abi) == self.bound_array_buffer {
             self.bound_array_buffer = None
         }
@@ -470,6 +648,8 @@ impl DynamicState {
         if !identical(framebuffer, current.as_ref().map(|v| v.deref())) {
             self.bound_draw_framebuffer = framebuffer.map(|f| f.into_abi());
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.bind_framebuffer(Gl::DRAW_FRAMEBUFFER, framebuffer);
 
@@ -492,6 +672,8 @@ impl DynamicState {
         if !identical(framebuffer, current.as_ref().map(|v| v.deref())) {
             self.bound_read_framebuffer = framebuffer.map(|f| f.into_abi());
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.bind_framebuffer(Gl::READ_FRAMEBUFFER, framebuffer);
 
@@ -526,6 +708,8 @@ impl DynamicState {
         if !identical(renderbuffer, current.as_ref().map(|v| v.deref())) {
             self.bound_renderbuffer = renderbuffer.map(|r| r.into_abi());
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.bind_renderbuffer(Gl::RENDERBUFFER, renderbuffer);
 
@@ -560,6 +744,9 @@ impl DynamicState {
             self.bound_texture_2d = texture.map(|t| t.into_abi());
             *active_unit_texture = texture.map(|t| t.into_abi());
 
+            self.state_change_count += 1;
+            self.resource_bindings_generation += 1;
+
             Some(move |context: &Gl| {
                 context.bind_texture(Gl::TEXTURE_2D, texture);
 
@@ -589,6 +776,9 @@ impl DynamicState {
             self.bound_texture_2d_array = texture.map(|t| t.into_abi());
             *active_unit_texture = texture.map(|t| t.into_abi());
 
+            self.state_change_count += 1;
+            self.resource_bindings_generation += 1;
+
             Some(move |context: &Gl| {
                 context.bind_texture(Gl::TEXTURE_2D_ARRAY, texture);
 
@@ -615,6 +805,9 @@ impl DynamicState {
             self.bound_texture_3d = texture.map(|t| t.into_abi());
             *active_unit_texture = texture.map(|t| t.into_abi());
 
+            self.state_change_count += 1;
+            self.resource_bindings_generation += 1;
+
             Some(move |context: &Gl| {
                 context.bind_texture(Gl::TEXTURE_3D, texture);
 
@@ -644,6 +837,9 @@ impl DynamicState {
             self.bound_texture_cube_map = texture.map(|t| t.into_abi());
             *active_unit_texture = texture.map(|t| t.into_abi());
 
+            self.state_change_count += 1;
+            self.resource_bindings_generation += 1;
+
             Some(move |context: &Gl| {
                 context.bind_texture(Gl::TEXTURE_CUBE_MAP, texture);
 
@@ -657,6 +853,8 @@ impl DynamicState {
     pub fn unref_texture(&mut self, texture: &WebGlTexture) {
         let abi = texture.into_abi();
 
+        self.resource_bindings_generation += 1;
+
         if Some(abi) == self.bound_texture_2d {
             self.bound_texture_2d = None;
         }
@@ -692,6 +890,9 @@ impl DynamicState {
         if !identical(sampler, current.as_ref().map(|v| v.deref())) {
             self.bound_samplers[texture_unit as usize] = sampler.map(|v| v.into_abi());
 
+            self.state_change_count += 1;
+            self.resource_bindings_generation += 1;
+
             Some(move |context: &Gl| {
                 context.bind_sampler(texture_unit, sampler);
 
@@ -705,6 +906,8 @@ impl DynamicState {
     pub fn unref_sampler(&mut self, sampler: &WebGlSampler) {
         let abi = sampler.into_abi();
 
+        self.resource_bindings_generation += 1;
+
         for sampler in &mut self.bound_samplers {
             if Some(abi) == *sampler {
                 *sampler = None;
@@ -724,6 +927,8 @@ impl DynamicState {
         if !identical(vertex_array, current.as_ref().map(|v| v.deref())) {
             self.bound_vertex_array = vertex_array.map(|v| v.into_abi());
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.bind_vertex_array(vertex_array);
 
@@ -754,6 +959,8 @@ impl DynamicState {
         if !identical(transform_feedback, current.as_ref().map(|v| v.deref())) {
             self.bound_transform_feedback = transform_feedback.map(|tf| tf.into_abi());
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.bind_transform_feedback(Gl::TRANSFORM_FEEDBACK, transform_feedback);
 
@@ -781,6 +988,8 @@ impl DynamicState {
             self.active_texture = texture_unit;
             self.texture_units_lru.use_index(texture_unit as usize);
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.active_texture(Gl::TEXTURE0 + texture_unit);
 
@@ -795,6 +1004,8 @@ impl DynamicState {
         let texture_unit = self.texture_units_lru.use_lru_index();
         self.active_texture = texture_unit as u32;
 
+        self.state_change_count += 1;
+
         Some(move |context: &Gl| {
             context.active_texture(Gl::TEXTURE0 + texture_unit as u32);
 
@@ -810,6 +1021,8 @@ impl DynamicState {
         if color != self.clear_color {
             self.clear_color = color;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.clear_color(color[0], color[1], color[2], color[3]);
 
@@ -828,6 +1041,8 @@ impl DynamicState {
         if depth != self.clear_depth {
             self.clear_depth = depth;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.clear_depth(depth);
 
@@ -846,6 +1061,8 @@ impl DynamicState {
         if stencil != self.clear_stencil {
             self.clear_stencil = stencil;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.clear_stencil(stencil);
 
@@ -856,6 +1073,52 @@ impl DynamicState {
         }
     }
 
+    pub fn pixel_pack_alignment(&self) -> i32 {
+        self.pixel_pack_alignment
+    }
+
+    pub fn set_pixel_pack_alignment(
+        &mut self,
+        pixel_pack_alignment: i32,
+    ) -> impl ContextUpdate<'static, ()> {
+        if pixel_pack_alignment != self.pixel_pack_alignment {
+            self.pixel_pack_alignment = pixel_pack_alignment;
+
+            self.state_change_count += 1;
+
+            Some(move |context: &Gl| {
+                context.pixel_storei(Gl::PACK_ALIGNMENT, pixel_pack_alignment);
+
+                Ok(())
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn pixel_pack_row_length(&self) -> i32 {
+        self.pixel_pack_row_length
+    }
+
+    pub fn set_pixel_pack_row_length(
+        &mut self,
+        pixel_pack_row_length: i32,
+    ) -> impl ContextUpdate<'static, ()> {
+        if pixel_pack_row_length != self.pixel_pack_row_length {
+            self.pixel_pack_row_length = pixel_pack_row_length;
+
+            self.state_change_count += 1;
+
+            Some(move |context: &Gl| {
+                context.pixel_storei(Gl::PACK_ROW_LENGTH, pixel_pack_row_length);
+
+                Ok(())
+            })
+        } else {
+            None
+        }
+    }
+
     pub fn pixel_unpack_alignment(&self) -> i32 {
         self.pixel_unpack_alignment
     }
@@ -867,6 +1130,8 @@ impl DynamicState {
         if pixel_unpack_alignment != self.pixel_unpack_alignment {
             self.pixel_unpack_alignment = pixel_unpack_alignment;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.pixel_storei(Gl::UNPACK_ALIGNMENT, pixel_unpack_alignment);
 
@@ -877,6 +1142,55 @@ impl DynamicState {
         }
     }
 
+    pub fn pixel_unpack_flip_y(&self) -> bool {
+        self.pixel_unpack_flip_y
+    }
+
+    pub fn set_pixel_unpack_flip_y(
+        &mut self,
+        pixel_unpack_flip_y: bool,
+    ) -> impl ContextUpdate<'static, ()> {
+        if pixel_unpack_flip_y != self.pixel_unpack_flip_y {
+            self.pixel_unpack_flip_y = pixel_unpack_flip_y;
+
+            self.state_change_count += 1;
+
+            Some(move |context: &Gl| {
+                context.pixel_storei(Gl::UNPACK_FLIP_Y_WEBGL, pixel_unpack_flip_y as i32);
+
+                Ok(())
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn pixel_unpack_premultiply_alpha(&self) -> bool {
+        self.pixel_unpack_premultiply_alpha
+    }
+
+    pub fn set_pixel_unpack_premultiply_alpha(
+        &mut self,
+        pixel_unpack_premultiply_alpha: bool,
+    ) -> impl ContextUpdate<'static, ()> {
+        if pixel_unpack_premultiply_alpha != self.pixel_unpack_premultiply_alpha {
+            self.pixel_unpack_premultiply_alpha = pixel_unpack_premultiply_alpha;
+
+            self.state_change_count += 1;
+
+            Some(move |context: &Gl| {
+                context.pixel_storei(
+                    Gl::UNPACK_PREMULTIPLY_ALPHA_WEBGL,
+                    pixel_unpack_premultiply_alpha as i32,
+                );
+
+                Ok(())
+            })
+        } else {
+            None
+        }
+    }
+
     pub fn pixel_unpack_row_length(&self) -> i32 {
         self.pixel_unpack_row_length
     }
@@ -888,6 +1202,8 @@ impl DynamicState {
         if pixel_unpack_row_length != self.pixel_unpack_row_length {
             self.pixel_unpack_row_length = pixel_unpack_row_length;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.pixel_storei(Gl::UNPACK_ROW_LENGTH, pixel_unpack_row_length);
 
@@ -909,6 +1225,8 @@ impl DynamicState {
         if pixel_unpack_image_height != self.pixel_unpack_image_height {
             self.pixel_unpack_image_height = pixel_unpack_image_height;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.pixel_storei(Gl::UNPACK_IMAGE_HEIGHT, pixel_unpack_image_height);
 
@@ -919,6 +1237,52 @@ impl DynamicState {
         }
     }
 
+    pub fn pixel_unpack_skip_pixels(&self) -> i32 {
+        self.pixel_unpack_skip_pixels
+    }
+
+    pub fn set_pixel_unpack_skip_pixels(
+        &mut self,
+        pixel_unpack_skip_pixels: i32,
+    ) -> impl ContextUpdate<'static, ()> {
+        if pixel_unpack_skip_pixels != self.pixel_unpack_skip_pixels {
+            self.pixel_unpack_skip_pixels = pixel_unpack_skip_pixels;
+
+            self.state_change_count += 1;
+
+            Some(move |context: &Gl| {
+                context.pixel_storei(Gl::UNPACK_SKIP_PIXELS, pixel_unpack_skip_pixels);
+
+                Ok(())
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn pixel_unpack_skip_rows(&self) -> i32 {
+        self.pixel_unpack_skip_rows
+    }
+
+    pub fn set_pixel_unpack_skip_rows(
+        &mut self,
+        pixel_unpack_skip_rows: i32,
+    ) -> impl ContextUpdate<'static, ()> {
+        if pixel_unpack_skip_rows != self.pixel_unpack_skip_rows {
+            self.pixel_unpack_skip_rows = pixel_unpack_skip_rows;
+
+            self.state_change_count += 1;
+
+            Some(move |context: &Gl| {
+                context.pixel_storei(Gl::UNPACK_SKIP_ROWS, pixel_unpack_skip_rows);
+
+                Ok(())
+            })
+        } else {
+            None
+        }
+    }
+
     pub fn line_width(&self) -> f32 {
         self.line_width
     }
@@ -927,6 +1291,8 @@ impl DynamicState {
         if line_width != self.line_width {
             self.line_width = line_width;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.line_width(line_width);
 
@@ -948,6 +1314,8 @@ impl DynamicState {
         if depth_test_enabled != self.depth_test_enabled {
             self.depth_test_enabled = depth_test_enabled;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 if depth_test_enabled {
                     context.enable(Gl::DEPTH_TEST);
@@ -973,6 +1341,8 @@ impl DynamicState {
         if stencil_test_enabled != self.stencil_test_enabled {
             self.stencil_test_enabled = stencil_test_enabled;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 if stencil_test_enabled {
                     context.enable(Gl::STENCIL_TEST);
@@ -998,6 +1368,8 @@ impl DynamicState {
         if scissor_test_enabled != self.scissor_test_enabled {
             self.scissor_test_enabled = scissor_test_enabled;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 if scissor_test_enabled {
                     context.enable(Gl::SCISSOR_TEST);
@@ -1020,6 +1392,8 @@ impl DynamicState {
         if blend_enabled != self.blend_enabled {
             self.blend_enabled = blend_enabled;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 if blend_enabled {
                     context.enable(Gl::BLEND);
@@ -1042,6 +1416,8 @@ impl DynamicState {
         if dither_enabled != self.dither_enabled {
             self.dither_enabled = dither_enabled;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 if dither_enabled {
                     context.enable(Gl::DITHER);
@@ -1067,6 +1443,8 @@ impl DynamicState {
         if polygon_offset_fill_enabled != self.polygon_offset_fill_enabled {
             self.polygon_offset_fill_enabled = polygon_offset_fill_enabled;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 if polygon_offset_fill_enabled {
                     context.enable(Gl::POLYGON_OFFSET_FILL);
@@ -1092,6 +1470,8 @@ impl DynamicState {
         if sample_aplha_to_coverage_enabled != self.sample_alpha_to_coverage_enabled {
             self.sample_alpha_to_coverage_enabled = sample_aplha_to_coverage_enabled;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 if sample_aplha_to_coverage_enabled {
                     context.enable(Gl::SAMPLE_ALPHA_TO_COVERAGE);
@@ -1117,6 +1497,8 @@ impl DynamicState {
         if sample_coverage_enabled != self.sample_coverage_enabled {
             self.sample_coverage_enabled = sample_coverage_enabled;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 if sample_coverage_enabled {
                     context.enable(Gl::SAMPLE_COVERAGE);
@@ -1142,6 +1524,8 @@ impl DynamicState {
         if rasterizer_discard_enabled != self.rasterizer_discard_enabled {
             self.rasterizer_discard_enabled = rasterizer_discard_enabled;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 if rasterizer_discard_enabled {
                     context.enable(Gl::RASTERIZER_DISCARD);
@@ -1163,6 +1547,8 @@ impl DynamicState {
         if self.scissor != value {
             self.scissor = value;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 let (x, y, width, height) = value;
 
@@ -1183,6 +1569,8 @@ impl DynamicState {
         if self.depth_func != depth_func {
             self.depth_func = depth_func;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.depth_func(depth_func.id());
 
@@ -1201,6 +1589,8 @@ impl DynamicState {
         if self.depth_mask != depth_mask {
             self.depth_mask = depth_mask;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.depth_mask(depth_mask);
 
@@ -1211,6 +1601,28 @@ impl DynamicState {
         }
     }
 
+    pub fn color_mask(&self) -> [bool; 4] {
+        self.color_mask
+    }
+
+    pub fn set_color_mask(&mut self, color_mask: [bool; 4]) -> impl ContextUpdate<'static, ()> {
+        if self.color_mask != color_mask {
+            self.color_mask = color_mask;
+
+            self.state_change_count += 1;
+
+            Some(move |context: &Gl| {
+                let [r, g, b, a] = color_mask;
+
+                context.color_mask(r, g, b, a);
+
+                Ok(())
+            })
+        } else {
+            None
+        }
+    }
+
     pub fn depth_range(&self) -> &DepthRange {
         &self.depth_range
     }
@@ -1222,6 +1634,8 @@ impl DynamicState {
 
             self.depth_range = depth_range;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.depth_range(near, far);
 
@@ -1246,6 +1660,8 @@ impl DynamicState {
 
             self.polygon_offset = polygon_offset;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.polygon_offset(factor, units);
 
@@ -1282,6 +1698,8 @@ impl DynamicState {
             self.stencil_ref_front = reference;
             self.stencil_test_mask_front = mask;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.stencil_func_separate(Gl::FRONT, func.id(), reference, mask);
 
@@ -1318,6 +1736,8 @@ impl DynamicState {
             self.stencil_ref_back = reference;
             self.stencil_test_mask_back = mask;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.stencil_func_separate(Gl::BACK, func.id(), reference, mask);
 
@@ -1354,6 +1774,8 @@ impl DynamicState {
             self.stencil_op_zfail_front = zfail;
             self.stencil_op_zpass_front = zpass;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.stencil_op_separate(Gl::FRONT, fail.id(), zfail.id(), zpass.id());
 
@@ -1390,6 +1812,8 @@ impl DynamicState {
             self.stencil_op_zfail_back = zfail;
             self.stencil_op_zpass_back = zpass;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.stencil_op_separate(Gl::BACK, fail.id(), zfail.id(), zpass.id());
 
@@ -1408,6 +1832,8 @@ impl DynamicState {
         if self.stencil_write_mask_front != mask {
             self.stencil_write_mask_front = mask;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.stencil_mask_separate(Gl::FRONT, mask);
 
@@ -1426,6 +1852,8 @@ impl DynamicState {
         if self.stencil_write_mask_back != mask {
             self.stencil_write_mask_back = mask;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.stencil_mask_separate(Gl::BACK, mask);
 
@@ -1444,6 +1872,8 @@ impl DynamicState {
         if self.blend_color != blend_color {
             self.blend_color = blend_color;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 let [r, g, b, a] = blend_color;
 
@@ -1473,6 +1903,8 @@ impl DynamicState {
             self.blend_equation_rgb = rgb;
             self.blend_equation_alpha = alpha;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.blend_equation_separate(rgb.id(), alpha.id());
 
@@ -1516,6 +1948,8 @@ impl DynamicState {
             self.blend_func_destination_rgb = destination_rgb;
             self.blend_func_destination_alpha = destination_alpha;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.blend_func_separate(
                     source_rgb.id(),
@@ -1545,6 +1979,8 @@ impl DynamicState {
         if self.viewport != (x, y, width, height) {
             self.viewport = (x, y, width, height);
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 context.viewport(x, y, width, height);
 
@@ -1563,6 +1999,8 @@ impl DynamicState {
         if self.front_face != front_face {
             self.front_face = front_face;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 match front_face {
                     WindingOrder::CounterClockwise => context.front_face(Gl::CCW),
@@ -1586,6 +2024,8 @@ impl DynamicState {
 
             self.cull_face = cull_face;
 
+            self.state_change_count += 1;
+
             Some(move |context: &Gl| {
                 match cull_face {
                     CullingMode::None => context.disable(Gl::CULL_FACE),
@@ -1632,7 +2072,9 @@ impl DynamicState {
             framebuffer_cache: FnvHashMap::default(),
             vertex_array_cache: FnvHashMap::default(),
             program_cache: FnvHashMap::default(),
+            pending_programs: FnvHashMap::default(),
             read_framebuffer: context.create_framebuffer().unwrap(),
+            clear_framebuffer: context.create_framebuffer().unwrap(),
             max_draw_buffers: context
                 .get_parameter(Gl::MAX_DRAW_BUFFERS)
                 .unwrap()
@@ -1685,9 +2127,15 @@ impl DynamicState {
             clear_color: [0.0, 0.0, 0.0, 0.0],
             clear_depth: 1.0,
             clear_stencil: 0,
+            pixel_pack_alignment: 4,
+            pixel_pack_row_length: 0,
             pixel_unpack_alignment: 4,
+            pixel_unpack_flip_y: false,
+            pixel_unpack_premultiply_alpha: false,
             pixel_unpack_row_length: 0,
             pixel_unpack_image_height: 0,
+            pixel_unpack_skip_pixels: 0,
+            pixel_unpack_skip_rows: 0,
             depth_test_enabled: false,
             stencil_test_enabled: false,
             scissor_test_enabled: false,
@@ -1697,7 +2145,13 @@ impl DynamicState {
             sample_alpha_to_coverage_enabled: false,
             sample_coverage_enabled: false,
             rasterizer_discard_enabled: false,
-            scissor: (0, 0, 0, 0),
+            // The initial scissor box covers the drawing buffer, like the initial viewport.
+            scissor: (
+                0,
+                0,
+                context.drawing_buffer_width() as u32,
+                context.drawing_buffer_height() as u32,
+            ),
             viewport: (
                 0,
                 0,
@@ -1706,6 +2160,7 @@ impl DynamicState {
             ),
             depth_func: TestFunction::Less,
             depth_mask: true,
+            color_mask: [true; 4],
             depth_range: DepthRange::default(),
             polygon_offset: PolygonOffset::default(),
             stencil_func_front: TestFunction::AlwaysPass,
@@ -1732,6 +2187,14 @@ impl DynamicState {
             line_width: 1.0,
             front_face: WindingOrder::CounterClockwise,
             cull_face: CullingMode::None,
+            state_change_count: 0,
+            bound_bind_groups: Vec::new(),
+            resource_bindings_generation: 0,
+            skipped_bind_group_count: 0,
+            draw_call_count: 0,
+            drawn_vertex_count: 0,
+            drawn_triangle_count: 0,
+            buffer_upload_count: 0,
         }
     }
 }
@@ -2052,6 +2515,12 @@ pub(crate) enum DepthStencilAttachmentDescriptor {
     None,
 }
 
+/// Retains a vertex array object for every combination of a vertex input layout, a set of vertex
+/// buffers and (optionally) an index buffer, such that binding the same combination again only
+/// requires a `bind_vertex_array` call, rather than re-specifying all attribute pointers.
+///
+/// Entries that depend on a buffer are deleted when that buffer is dropped, see
+/// [remove_buffer_dependents].
 pub(crate) struct VertexArrayCache<'a> {
     state: &'a mut DynamicState,
 }
@@ -2248,36 +2717,17 @@ impl<'a> ProgramCache<'a> {
     pub(crate) fn get_or_create(
         &mut self,
         key: ProgramKey,
+        vertex_input_layout: &VertexInputLayoutDescriptor,
         transform_feedback_layout: &Option<TransformFeedbackLayoutDescriptor>,
         gl: &Gl,
     ) -> Result<&Program, CreateProgramError> {
         let program = match self.state.program_cache.entry(key) {
             Entry::Occupied(entry) => entry.into_mut(),
             Entry::Vacant(entry) => {
-                let program_object = gl.create_program().unwrap();
-
-                unsafe {
-                    key.vertex_shader_id.with_value_unchecked(|shader_object| {
-                        gl.attach_shader(&program_object, &shader_object);
-                    });
-
-                    key.fragment_shader_id
-                        .with_value_unchecked(|shader_object| {
-                            gl.attach_shader(&program_object, &shader_object);
-                        });
-                }
-
-                if let Some(layout) = transform_feedback_layout {
-                    let varyings = JsValue::from_serde(&TransformFeedbackVaryings(layout)).unwrap();
-
-                    gl.transform_feedback_varyings(
-                        &program_object,
-                        &varyings,
-                        Gl::INTERLEAVED_ATTRIBS,
-                    );
-                }
-
-                gl.link_program(&program_object);
+                let program_object = match self.state.pending_programs.remove(&key) {
+                    Some(program_object) => program_object,
+                    None => link_program(&key, vertex_input_layout, transform_feedback_layout, gl),
+                };
 
                 if !gl
                     .get_program_parameter(&program_object, Gl::LINK_STATUS)
@@ -2803,8 +3253,8 @@ impl<'a> ProgramCache<'a> {
 
                 entry.insert(Program {
                     gl_object: program_object,
-                    attribute_slot_descriptors,
-                    resource_slot_descriptors,
+                    attribute_slot_descriptors: attribute_slot_descriptors.into(),
+                    resource_slot_descriptors: resource_slot_descriptors.into(),
                 })
             }
         };
@@ -2812,37 +3262,75 @@ impl<'a> ProgramCache<'a> {
         Ok(program)
     }
 
-    pub(crate) fn remove_vertex_shader_dependent(&mut self, shader_id: JsId) {
+    /// Starts linking a program for the `key` without waiting for the result, if no program for
+    /// the `key` exists in the cache and no program for the `key` is currently being linked.
+    ///
+    /// The program may then be retrieved with [get_or_create] once [is_link_complete] returns
+    /// `true`.
+    pub(crate) fn begin_link(
+        &mut self,
+        key: ProgramKey,
+        vertex_input_layout: &VertexInputLayoutDescriptor,
+        transform_feedback_layout: &Option<TransformFeedbackLayoutDescriptor>,
+        gl: &Gl,
+    ) {
+        if !self.state.program_cache.contains_key(&key)
+            && !self.state.pending_programs.contains_key(&key)
+        {
+            let program_object =
+                link_program(&key, vertex_input_layout, transform_feedback_layout, gl);
+
+            self.state.pending_programs.insert(key, program_object);
+        }
+    }
+
+    /// Returns `false` if a program for the `key` is still being linked, `true` otherwise.
+    ///
+    /// Requires the `KHR_parallel_shader_compile` extension to have been enabled; without the
+    /// extension the status cannot be queried and the link is always reported as complete.
+    pub(crate) fn is_link_complete(&self, key: &ProgramKey, gl: &Gl) -> bool {
+        if let Some(program_object) = self.state.pending_programs.get(key) {
+            gl.get_program_parameter(program_object, COMPLETION_STATUS_KHR)
+                .as_bool()
+                .unwrap_or(true)
+        } else {
+            true
+        }
+    }
+
+    pub(crate) fn remove_vertex_shader_dependent(&mut self, gl: &Gl, shader_id: JsId) {
+        self.remove_dependent(gl, |key| key.vertex_shader_id == shader_id);
+    }
+
+    pub(crate) fn remove_fragment_shader_dependent(&mut self, gl: &Gl, shader_id: JsId) {
+        self.remove_dependent(gl, |key| key.fragment_shader_id == shader_id);
+    }
+
+    // Removes the programs (both linked and pending) for which `is_dependent` returns `true` and
+    // deletes their program objects, rather than leaving them to be garbage collected.
+    fn remove_dependent<F>(&mut self, gl: &Gl, is_dependent: F)
+    where
+        F: Fn(&ProgramKey) -> bool,
+    {
         let DynamicState {
             active_program,
             program_cache,
+            pending_programs,
             ..
         } = &mut self.state;
 
-        program_cache.retain(|key, program| {
-            let retain = key.vertex_shader_id != shader_id;
+        pending_programs.retain(|key, program_object| {
+            let retain = !is_dependent(key);
 
             if !retain {
-                let abi = program.gl_object().into_abi();
-
-                if Some(abi) == *active_program {
-                    *active_program = None;
-                }
+                gl.delete_program(Some(program_object));
             }
 
             retain
         });
-    }
-
-    pub(crate) fn remove_fragment_shader_dependent(&mut self, shader_id: JsId) {
-        let DynamicState {
-            active_program,
-            program_cache,
-            ..
-        } = &mut self.state;
 
         program_cache.retain(|key, program| {
-            let retain = key.fragment_shader_id != shader_id;
+            let retain = !is_dependent(key);
 
             if !retain {
                 let abi = program.gl_object().into_abi();
@@ -2850,6 +3338,8 @@ impl<'a> ProgramCache<'a> {
                 if Some(abi) == *active_program {
                     *active_program = None;
                 }
+
+                gl.delete_program(Some(program.gl_object()));
             }
 
             retain
@@ -2857,6 +3347,44 @@ impl<'a> ProgramCache<'a> {
     }
 }
 
+/// The `COMPLETION_STATUS_KHR` program parameter defined by the `KHR_parallel_shader_compile`
+/// extension.
+const COMPLETION_STATUS_KHR: u32 = 0x91B1;
+
+fn link_program(
+    key: &ProgramKey,
+    vertex_input_layout: &VertexInputLayoutDescriptor,
+    transform_feedback_layout: &Option<TransformFeedbackLayoutDescriptor>,
+    gl: &Gl,
+) -> WebGlProgram {
+    let program_object = gl.create_program().unwrap();
+
+    unsafe {
+        key.vertex_shader_id.with_value_unchecked(|shader_object| {
+            gl.attach_shader(&program_object, &shader_object);
+        });
+
+        key.fragment_shader_id
+            .with_value_unchecked(|shader_object| {
+                gl.attach_shader(&program_object, &shader_object);
+            });
+    }
+
+    for (name, location) in vertex_input_layout.named_attributes() {
+        gl.bind_attrib_location(&program_object, location, name);
+    }
+
+    if let Some(layout) = transform_feedback_layout {
+        let varyings = JsValue::from_serde(&TransformFeedbackVaryings(layout)).unwrap();
+
+        gl.transform_feedback_varyings(&program_object, &varyings, layout.buffer_mode().id());
+    }
+
+    gl.link_program(&program_object);
+
+    program_object
+}
+
 pub enum CreateProgramError {
     ShaderLinkingError(String),
     UnsupportedUniformType(ResourceSlotIdentifier, &'static str),
@@ -2864,8 +3392,8 @@ pub enum CreateProgramError {
 
 pub(crate) struct Program {
     gl_object: WebGlProgram,
-    attribute_slot_descriptors: Vec<VertexAttributeSlotDescriptor>,
-    resource_slot_descriptors: Vec<ShaderResourceSlotDescriptor>,
+    attribute_slot_descriptors: Rc<[VertexAttributeSlotDescriptor]>,
+    resource_slot_descriptors: Rc<[ShaderResourceSlotDescriptor]>,
 }
 
 impl !Send for Program {}
@@ -2875,11 +3403,11 @@ impl Program {
         &self.gl_object
     }
 
-    pub fn attribute_slot_descriptors(&self) -> &[VertexAttributeSlotDescriptor] {
+    pub fn attribute_slot_descriptors(&self) -> &Rc<[VertexAttributeSlotDescriptor]> {
         &self.attribute_slot_descriptors
     }
 
-    pub fn resource_slot_descriptors(&self) -> &[ShaderResourceSlotDescriptor] {
+    pub fn resource_slot_descriptors(&self) -> &Rc<[ShaderResourceSlotDescriptor]> {
         &self.resource_slot_descriptors
     }
 }
@@ -2889,5 +3417,6 @@ pub(crate) struct ProgramKey {
     pub(crate) vertex_shader_id: JsId,
     pub(crate) fragment_shader_id: JsId,
     pub(crate) transform_feedback_layout_key: Option<u64>,
+    pub(crate) attribute_names_key: Option<u64>,
     pub(crate) resource_bindings_layout: u64,
 }
@@ -0,0 +1,384 @@
+use std::fmt;
+
+use js_sys::{Array, Float32Array, Int32Array};
+use wasm_bindgen::convert::RefFromWasmAbi;
+use wasm_bindgen::JsValue;
+use web_sys::WebGl2RenderingContext as Gl;
+
+use crate::pipeline::graphics::{
+    BlendEquation, BlendFactor, CullingMode, TestFunction, WindingOrder,
+};
+
+/// A snapshot of the GL state tracked by a context.
+///
+/// Obtained with [SingleThreadedContext::debug_capture_state]. Capturing a snapshot only reads the
+/// context's state cache, it does not query the driver, and is therefore cheap. Intended for
+/// debugging, e.g. to log the state of the context when a submitted task fails or produces
+/// unexpected output. Use [SingleThreadedContext::debug_verify_state] to check the tracked state
+/// against the state actually reported by the driver.
+///
+/// Bound objects are represented by opaque handles (see [ObjectHandle]): two snapshots that report
+/// the same handle for an object binding refer to the same object, as long as that object has not
+/// been deleted in between.
+///
+/// [SingleThreadedContext::debug_capture_state]: crate::runtime::single_threaded::SingleThreadedContext::debug_capture_state
+/// [SingleThreadedContext::debug_verify_state]: crate::runtime::single_threaded::SingleThreadedContext::debug_verify_state
+#[derive(Clone, PartialEq, Debug)]
+pub struct StateSnapshot {
+    /// Handle for the program that is currently in use, or `None` if no program is in use.
+    pub program: Option<ObjectHandle>,
+
+    /// Handle for the vertex array that is currently bound, or `None` if no vertex array is bound.
+    pub vertex_array: Option<ObjectHandle>,
+
+    /// Handle for the buffer that is currently bound to the `ARRAY_BUFFER` target, or `None` if no
+    /// buffer is bound.
+    pub array_buffer: Option<ObjectHandle>,
+
+    /// Handle for the framebuffer that is currently bound as the draw framebuffer, or `None` if
+    /// the default framebuffer is bound.
+    pub draw_framebuffer: Option<ObjectHandle>,
+
+    /// Handle for the framebuffer that is currently bound as the read framebuffer, or `None` if
+    /// the default framebuffer is bound.
+    pub read_framebuffer: Option<ObjectHandle>,
+
+    /// The index of the active texture unit.
+    pub active_texture: u32,
+
+    /// Whether or not the depth test is enabled.
+    pub depth_test_enabled: bool,
+
+    /// Whether or not the stencil test is enabled.
+    pub stencil_test_enabled: bool,
+
+    /// Whether or not the scissor test is enabled.
+    pub scissor_test_enabled: bool,
+
+    /// Whether or not blending is enabled.
+    pub blend_enabled: bool,
+
+    /// Whether or not polygon offset is enabled.
+    pub polygon_offset_fill_enabled: bool,
+
+    /// Whether or not rasterizer discard is enabled.
+    pub rasterizer_discard_enabled: bool,
+
+    /// The depth test function.
+    pub depth_func: TestFunction,
+
+    /// Whether or not writing to the depth buffer is enabled.
+    pub depth_mask: bool,
+
+    /// Whether or not writing to the red, green, blue and alpha channels of the color buffers is
+    /// enabled.
+    pub color_mask: [bool; 4],
+
+    /// The constant blend color.
+    pub blend_color: [f32; 4],
+
+    /// The blend equation for the red, green and blue components.
+    pub blend_equation_rgb: BlendEquation,
+
+    /// The blend equation for the alpha component.
+    pub blend_equation_alpha: BlendEquation,
+
+    /// The source blend factor for the red, green and blue components.
+    pub blend_func_source_rgb: BlendFactor,
+
+    /// The source blend factor for the alpha component.
+    pub blend_func_source_alpha: BlendFactor,
+
+    /// The destination blend factor for the red, green and blue components.
+    pub blend_func_destination_rgb: BlendFactor,
+
+    /// The destination blend factor for the alpha component.
+    pub blend_func_destination_alpha: BlendFactor,
+
+    /// The face-culling mode.
+    pub cull_face: CullingMode,
+
+    /// The winding order that determines which faces are front-facing.
+    pub front_face: WindingOrder,
+
+    /// The viewport as `(x, y, width, height)`.
+    pub viewport: (i32, i32, i32, i32),
+
+    /// The scissor box as `(x, y, width, height)`.
+    pub scissor: (i32, i32, u32, u32),
+}
+
+/// An opaque handle for an object bound in a [StateSnapshot].
+///
+/// Two handles are equal if they refer to the same object, as long as that object has not been
+/// deleted in between.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct ObjectHandle(pub(crate) u32);
+
+impl StateSnapshot {
+    /// Compares this snapshot against the state reported by the driver, returning a
+    /// [StateMismatch] for each parameter for which the values differ.
+    ///
+    /// Every comparison requires a synchronous query to the driver, which may stall the GPU
+    /// pipeline.
+    pub(crate) fn verify(&self, gl: &Gl) -> Vec<StateMismatch> {
+        let mut mismatches = Vec::new();
+
+        let mut check = |parameter: &'static str, tracked: String, actual: String| {
+            if tracked != actual {
+                mismatches.push(StateMismatch {
+                    parameter,
+                    tracked,
+                    actual,
+                });
+            }
+        };
+
+        check(
+            "CURRENT_PROGRAM",
+            describe_object(self.program),
+            describe_bound_object(gl, Gl::CURRENT_PROGRAM, self.program),
+        );
+        check(
+            "VERTEX_ARRAY_BINDING",
+            describe_object(self.vertex_array),
+            describe_bound_object(gl, Gl::VERTEX_ARRAY_BINDING, self.vertex_array),
+        );
+        check(
+            "ARRAY_BUFFER_BINDING",
+            describe_object(self.array_buffer),
+            describe_bound_object(gl, Gl::ARRAY_BUFFER_BINDING, self.array_buffer),
+        );
+        check(
+            "DRAW_FRAMEBUFFER_BINDING",
+            describe_object(self.draw_framebuffer),
+            describe_bound_object(gl, Gl::DRAW_FRAMEBUFFER_BINDING, self.draw_framebuffer),
+        );
+        check(
+            "READ_FRAMEBUFFER_BINDING",
+            describe_object(self.read_framebuffer),
+            describe_bound_object(gl, Gl::READ_FRAMEBUFFER_BINDING, self.read_framebuffer),
+        );
+        check(
+            "ACTIVE_TEXTURE",
+            format!("{}", Gl::TEXTURE0 + self.active_texture),
+            format!("{}", get_u32(gl, Gl::ACTIVE_TEXTURE)),
+        );
+
+        let capabilities = [
+            ("DEPTH_TEST", Gl::DEPTH_TEST, self.depth_test_enabled),
+            ("STENCIL_TEST", Gl::STENCIL_TEST, self.stencil_test_enabled),
+            ("SCISSOR_TEST", Gl::SCISSOR_TEST, self.scissor_test_enabled),
+            ("BLEND", Gl::BLEND, self.blend_enabled),
+            (
+                "POLYGON_OFFSET_FILL",
+                Gl::POLYGON_OFFSET_FILL,
+                self.polygon_offset_fill_enabled,
+            ),
+            (
+                "RASTERIZER_DISCARD",
+                Gl::RASTERIZER_DISCARD,
+                self.rasterizer_discard_enabled,
+            ),
+            (
+                "CULL_FACE",
+                Gl::CULL_FACE,
+                self.cull_face != CullingMode::None,
+            ),
+        ];
+
+        for (parameter, capability, tracked) in capabilities.iter() {
+            check(
+                *parameter,
+                format!("{}", tracked),
+                format!("{}", gl.is_enabled(*capability)),
+            );
+        }
+
+        check(
+            "DEPTH_FUNC",
+            format!("{}", self.depth_func.id()),
+            format!("{}", get_u32(gl, Gl::DEPTH_FUNC)),
+        );
+        check(
+            "DEPTH_WRITEMASK",
+            format!("{}", self.depth_mask),
+            format!("{}", get_bool(gl, Gl::DEPTH_WRITEMASK)),
+        );
+        check(
+            "COLOR_WRITEMASK",
+            format!("{:?}", self.color_mask),
+            format!("{:?}", get_bool_vec(gl, Gl::COLOR_WRITEMASK)),
+        );
+        check(
+            "BLEND_COLOR",
+            format!("{:?}", self.blend_color.to_vec()),
+            format!("{:?}", get_f32_vec(gl, Gl::BLEND_COLOR)),
+        );
+        check(
+            "BLEND_EQUATION_RGB",
+            format!("{}", self.blend_equation_rgb.id()),
+            format!("{}", get_u32(gl, Gl::BLEND_EQUATION_RGB)),
+        );
+        check(
+            "BLEND_EQUATION_ALPHA",
+            format!("{}", self.blend_equation_alpha.id()),
+            format!("{}", get_u32(gl, Gl::BLEND_EQUATION_ALPHA)),
+        );
+        check(
+            "BLEND_SRC_RGB",
+            format!("{}", self.blend_func_source_rgb.id()),
+            format!("{}", get_u32(gl, Gl::BLEND_SRC_RGB)),
+        );
+        check(
+            "BLEND_SRC_ALPHA",
+            format!("{}", self.blend_func_source_alpha.id()),
+            format!("{}", get_u32(gl, Gl::BLEND_SRC_ALPHA)),
+        );
+        check(
+            "BLEND_DST_RGB",
+            format!("{}", self.blend_func_destination_rgb.id()),
+            format!("{}", get_u32(gl, Gl::BLEND_DST_RGB)),
+        );
+        check(
+            "BLEND_DST_ALPHA",
+            format!("{}", self.blend_func_destination_alpha.id()),
+            format!("{}", get_u32(gl, Gl::BLEND_DST_ALPHA)),
+        );
+
+        // The cull face mode is only tracked while face-culling is enabled.
+        let cull_face_mode = match self.cull_face {
+            CullingMode::None => None,
+            CullingMode::Front => Some(Gl::FRONT),
+            CullingMode::Back => Some(Gl::BACK),
+            CullingMode::Both => Some(Gl::FRONT_AND_BACK),
+        };
+
+        if let Some(cull_face_mode) = cull_face_mode {
+            check(
+                "CULL_FACE_MODE",
+                format!("{}", cull_face_mode),
+                format!("{}", get_u32(gl, Gl::CULL_FACE_MODE)),
+            );
+        }
+
+        let front_face = match self.front_face {
+            WindingOrder::CounterClockwise => Gl::CCW,
+            WindingOrder::Clockwise => Gl::CW,
+        };
+
+        check(
+            "FRONT_FACE",
+            format!("{}", front_face),
+            format!("{}", get_u32(gl, Gl::FRONT_FACE)),
+        );
+
+        let (x, y, width, height) = self.viewport;
+
+        check(
+            "VIEWPORT",
+            format!("{:?}", vec![x, y, width, height]),
+            format!("{:?}", get_i32_vec(gl, Gl::VIEWPORT)),
+        );
+
+        let (x, y, width, height) = self.scissor;
+
+        check(
+            "SCISSOR_BOX",
+            format!("{:?}", vec![x, y, width as i32, height as i32]),
+            format!("{:?}", get_i32_vec(gl, Gl::SCISSOR_BOX)),
+        );
+
+        mismatches
+    }
+}
+
+/// A parameter for which the value tracked by a context does not match the value reported by the
+/// driver.
+///
+/// See [SingleThreadedContext::debug_verify_state].
+///
+/// [SingleThreadedContext::debug_verify_state]: crate::runtime::single_threaded::SingleThreadedContext::debug_verify_state
+#[derive(Clone, PartialEq, Debug)]
+pub struct StateMismatch {
+    /// The name of the GL parameter (e.g. `"DEPTH_FUNC"`).
+    pub parameter: &'static str,
+
+    /// A description of the value tracked by the context.
+    pub tracked: String,
+
+    /// A description of the value reported by the driver.
+    pub actual: String,
+}
+
+impl fmt::Display for StateMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}: tracked `{}`, but the driver reports `{}`",
+            self.parameter, self.tracked, self.actual
+        )
+    }
+}
+
+fn describe_object(handle: Option<ObjectHandle>) -> String {
+    match handle {
+        Some(ObjectHandle(handle)) => format!("object {}", handle),
+        None => "null".to_string(),
+    }
+}
+
+fn describe_bound_object(gl: &Gl, parameter: u32, tracked: Option<ObjectHandle>) -> String {
+    let actual = gl.get_parameter(parameter).unwrap_or(JsValue::NULL);
+
+    if actual.is_null() {
+        return "null".to_string();
+    }
+
+    let is_tracked_object = tracked
+        .map(|ObjectHandle(abi)| unsafe { *JsValue::ref_from_abi(abi) == actual })
+        .unwrap_or(false);
+
+    if is_tracked_object {
+        describe_object(tracked)
+    } else {
+        "a different object".to_string()
+    }
+}
+
+fn get_u32(gl: &Gl, parameter: u32) -> u32 {
+    gl.get_parameter(parameter)
+        .ok()
+        .and_then(|value| value.as_f64())
+        .unwrap_or(0.0) as u32
+}
+
+fn get_bool(gl: &Gl, parameter: u32) -> bool {
+    gl.get_parameter(parameter)
+        .ok()
+        .and_then(|value| value.as_bool())
+        .unwrap_or(false)
+}
+
+fn get_bool_vec(gl: &Gl, parameter: u32) -> Vec<bool> {
+    gl.get_parameter(parameter)
+        .map(|value| {
+            Array::from(&value)
+                .iter()
+                .map(|value| value.as_bool().unwrap_or(false))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn get_i32_vec(gl: &Gl, parameter: u32) -> Vec<i32> {
+    gl.get_parameter(parameter)
+        .map(|value| Int32Array::from(value).to_vec())
+        .unwrap_or_default()
+}
+
+fn get_f32_vec(gl: &Gl, parameter: u32) -> Vec<f32> {
+    gl.get_parameter(parameter)
+        .map(|value| Float32Array::from(value).to_vec())
+        .unwrap_or_default()
+}
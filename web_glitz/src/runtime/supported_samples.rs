@@ -1,3 +1,5 @@
+use std::iter::FusedIterator;
+
 use bitflags::bitflags;
 
 bitflags! {
@@ -29,6 +31,82 @@ impl SupportedSamples {
             None
         }
     }
+
+    /// Returns `true` if a sampling grid size of `samples` is supported, `false` otherwise.
+    ///
+    /// Note that only multisample grid sizes (`2`, `4`, `8` and `16`) are described by a
+    /// [SupportedSamples] value; this returns `false` for any other value of `samples`, including
+    /// `1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use web_glitz::runtime::SupportedSamples;
+    ///
+    /// let supported_samples = SupportedSamples::SAMPLES_8 | SupportedSamples::SAMPLES_4;
+    ///
+    /// assert!(supported_samples.contains_samples(4));
+    /// assert!(!supported_samples.contains_samples(16));
+    /// assert!(!supported_samples.contains_samples(3));
+    /// ```
+    pub fn contains_samples(&self, samples: u8) -> bool {
+        match samples {
+            2 | 4 | 8 | 16 => self.bits() & samples != 0,
+            _ => false,
+        }
+    }
+
+    /// Returns an iterator over the supported sampling grid sizes, in descending order of size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use web_glitz::runtime::SupportedSamples;
+    ///
+    /// let supported_samples = SupportedSamples::SAMPLES_8 | SupportedSamples::SAMPLES_2;
+    ///
+    /// assert_eq!(supported_samples.iter().collect::<Vec<_>>(), vec![8, 2]);
+    /// ```
+    pub fn iter(&self) -> SupportedSamplesIter {
+        self.into_iter()
+    }
+
+    /// Returns the maximum sampling grid size that is supported by all of the `supported_samples`,
+    /// or `None` if there is no sampling grid size that is supported by all of them (or if
+    /// `supported_samples` is empty).
+    ///
+    /// All multisample attachments of a render target must use the same sampling grid size, but
+    /// different formats may support different sampling grid sizes. This may be used to pick a
+    /// sampling grid size that is valid for all attachments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # fn wrapper<Rc>(context: &Rc) where Rc: RenderingContext {
+    /// use web_glitz::image::format::{Depth24Stencil8, RGBA8};
+    /// use web_glitz::runtime::SupportedSamples;
+    ///
+    /// let samples = SupportedSamples::best_common_samples(&[
+    ///     context.supported_samples(RGBA8),
+    ///     context.supported_samples(Depth24Stencil8),
+    /// ]);
+    ///
+    /// if let Some(samples) = samples {
+    ///     println!("Using MSAAx{}", samples);
+    /// }
+    /// # }
+    /// ```
+    pub fn best_common_samples(supported_samples: &[SupportedSamples]) -> Option<u8> {
+        if supported_samples.is_empty() {
+            return None;
+        }
+
+        supported_samples
+            .iter()
+            .fold(SupportedSamples::all(), |common, s| common & *s)
+            .max_samples()
+    }
 }
 
 impl IntoIterator for SupportedSamples {
@@ -46,6 +124,11 @@ impl IntoIterator for SupportedSamples {
 }
 
 /// Iterator over the available sampling grid sizes in a [SupportedSamples] value.
+///
+/// The sampling grid sizes are always yielded in descending order of size.
+///
+/// See [SupportedSamples::iter].
+#[derive(Clone, Debug)]
 pub struct SupportedSamplesIter {
     supported_samples: SupportedSamples,
     current: u8,
@@ -71,8 +154,24 @@ impl Iterator for SupportedSamplesIter {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // `current` is always either `0` or the next supported size, so the remaining sizes are the
+        // supported sizes that are less than or equal to `current`.
+        let remaining = if self.current > 0 {
+            (self.supported_samples.bits() & ((self.current << 1) - 1)).count_ones() as usize
+        } else {
+            0
+        };
+
+        (remaining, Some(remaining))
+    }
 }
 
+impl ExactSizeIterator for SupportedSamplesIter {}
+
+impl FusedIterator for SupportedSamplesIter {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -88,6 +187,48 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_supported_samples_contains_samples() {
+        let supported_samples = SupportedSamples::SAMPLES_16 | SupportedSamples::SAMPLES_2;
+
+        assert!(supported_samples.contains_samples(16));
+        assert!(supported_samples.contains_samples(2));
+        assert!(!supported_samples.contains_samples(8));
+        assert!(!supported_samples.contains_samples(1));
+        assert!(!supported_samples.contains_samples(0));
+        assert!(!supported_samples.contains_samples(18));
+    }
+
+    #[test]
+    fn test_supported_samples_iter_len() {
+        let supported_samples = SupportedSamples::SAMPLES_16
+            | SupportedSamples::SAMPLES_4
+            | SupportedSamples::SAMPLES_2;
+        let mut iter = supported_samples.iter();
+
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some(16));
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_best_common_samples() {
+        let a = SupportedSamples::SAMPLES_8 | SupportedSamples::SAMPLES_4;
+        let b = SupportedSamples::SAMPLES_4 | SupportedSamples::SAMPLES_2;
+
+        assert_eq!(SupportedSamples::best_common_samples(&[a, b]), Some(4));
+        assert_eq!(SupportedSamples::best_common_samples(&[a]), Some(8));
+        assert_eq!(
+            SupportedSamples::best_common_samples(&[a, SupportedSamples::SAMPLES_2]),
+            None
+        );
+        assert_eq!(SupportedSamples::best_common_samples(&[]), None);
+    }
+
     #[test]
     fn test_supported_samples_none() {
         let supported_samples = SupportedSamples::NONE;
@@ -0,0 +1,67 @@
+use crate::runtime::Connection;
+use crate::task::{ContextId, GpuTask, Progress, ValidationContext};
+
+/// A type-erased [GpuTask] that outputs a value of type `O`.
+///
+/// Different tasks typically have different concrete types, which means they cannot be stored in
+/// the same collection. A [BoxedTask] erases the concrete type of a task (using dynamic dispatch),
+/// so that tasks of different types that produce the same output type can be stored together,
+/// e.g. in a [Vec]. A [BoxedTask] is typically created with [GpuTaskExt::boxed].
+///
+/// # Example
+///
+/// ```rust
+/// # use web_glitz::runtime::RenderingContext;
+/// # use web_glitz::buffer::Buffer;
+/// # fn wrapper<Rc>(context: &Rc, a: Buffer<[f32]>, b: Buffer<[f32]>) where Rc: RenderingContext {
+/// use web_glitz::task::{sequence_iter, BoxedTask, GpuTaskExt};
+///
+/// let mut tasks: Vec<BoxedTask<()>> = Vec::new();
+///
+/// tasks.push(a.upload_command([1.0, 2.0, 3.0]).boxed());
+/// tasks.push(b.upload_command(vec![4.0, 5.0, 6.0]).boxed());
+///
+/// // Execute the tasks in order:
+/// context.submit(sequence_iter(tasks));
+/// # }
+/// ```
+///
+/// A [BoxedTask] may also be submitted directly with [RenderingContext::submit].
+///
+/// [GpuTaskExt::boxed]: crate::task::GpuTaskExt::boxed
+/// [RenderingContext::submit]: crate::runtime::RenderingContext::submit
+pub struct BoxedTask<O, Ec = Connection> {
+    task: Box<dyn GpuTask<Ec, Output = O>>,
+}
+
+impl<O, Ec> BoxedTask<O, Ec> {
+    /// Erases the type of the given `task`.
+    pub fn new<T>(task: T) -> Self
+    where
+        T: GpuTask<Ec, Output = O> + 'static,
+    {
+        BoxedTask {
+            task: Box::new(task),
+        }
+    }
+}
+
+unsafe impl<O, Ec> GpuTask<Ec> for BoxedTask<O, Ec> {
+    type Output = O;
+
+    fn context_id(&self) -> ContextId {
+        self.task.context_id()
+    }
+
+    fn validate(&self, context: &mut ValidationContext) {
+        self.task.validate(context)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.task.is_cancelled()
+    }
+
+    fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
+        self.task.progress(execution_context)
+    }
+}
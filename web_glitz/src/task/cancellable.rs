@@ -0,0 +1,173 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use crate::task::{ContextId, GpuTask, Progress, ValidationContext};
+
+/// Wraps the `task` in a [Cancellable] task and returns it together with a [CancelHandle] that
+/// may be used to cancel the task.
+///
+/// Note that dropping the [Execution] future returned by [RenderingContext::submit] does not
+/// cancel a task: a submitted task always runs to completion, regardless of whether its future is
+/// polled or dropped. Use this wrapper if a task should be abandoned explicitly, e.g. a task that
+/// waits on a GPU fence to read back data for a UI component that has since been removed.
+///
+/// A cancelled task stops at the next point where the executor would make progress on it; if it
+/// is waiting on a fence, it is removed from the fenced task queue the next time the queue is
+/// checked, without waiting for its fence to become signalled. Note that this only applies if the
+/// [Cancellable] task is the task that was submitted (or is wrapped by [GpuTaskExt::map],
+/// [GpuTaskExt::inspect] or [GpuTaskExt::boxed]); a [Cancellable] task that is combined with other
+/// tasks (e.g. with [sequence]) is only cancelled once its fence is signalled. Any commands the
+/// task already issued to the GPU are not undone: cancelling a sequence of uploads halfway may
+/// leave a resource partially updated.
+///
+/// # Example
+///
+/// ```rust
+/// # use web_glitz::runtime::RenderingContext;
+/// # use web_glitz::buffer::Buffer;
+/// # fn wrapper<Rc>(context: &Rc, buffer: Buffer<[f32]>) where Rc: RenderingContext {
+/// use web_glitz::task::cancellable;
+///
+/// let (task, cancel_handle) = cancellable(buffer.download_command());
+///
+/// let future = context.submit(task);
+///
+/// // Later, e.g. when the component that requested the data is unmounted:
+/// cancel_handle.cancel();
+/// # }
+/// ```
+///
+/// Here `future` resolves to `None` if the task was cancelled before it finished, or to `Some`
+/// with the task's output otherwise.
+///
+/// [Execution]: crate::runtime::Execution
+/// [RenderingContext::submit]: crate::runtime::RenderingContext::submit
+/// [GpuTaskExt::map]: crate::task::GpuTaskExt::map
+/// [GpuTaskExt::inspect]: crate::task::GpuTaskExt::inspect
+/// [GpuTaskExt::boxed]: crate::task::GpuTaskExt::boxed
+/// [sequence]: crate::task::sequence
+pub fn cancellable<T>(task: T) -> (Cancellable<T>, CancelHandle) {
+    let cancelled = Rc::new(Cell::new(false));
+
+    (
+        Cancellable {
+            task,
+            cancelled: cancelled.clone(),
+        },
+        CancelHandle { cancelled },
+    )
+}
+
+/// A task that may be cancelled with its associated [CancelHandle].
+///
+/// See [cancellable] for details.
+pub struct Cancellable<T> {
+    task: T,
+    cancelled: Rc<Cell<bool>>,
+}
+
+unsafe impl<Ec, T> GpuTask<Ec> for Cancellable<T>
+where
+    T: GpuTask<Ec>,
+{
+    type Output = Option<T::Output>;
+
+    fn context_id(&self) -> ContextId {
+        self.task.context_id()
+    }
+
+    fn validate(&self, context: &mut ValidationContext) {
+        self.task.validate(context)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.get()
+    }
+
+    fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
+        if self.cancelled.get() {
+            Progress::Finished(None)
+        } else {
+            self.task.progress(execution_context).map(Some)
+        }
+    }
+}
+
+/// Handle that may be used to cancel a [Cancellable] task.
+///
+/// See [cancellable] for details.
+#[derive(Clone)]
+pub struct CancelHandle {
+    cancelled: Rc<Cell<bool>>,
+}
+
+impl CancelHandle {
+    /// Cancels the associated task.
+    ///
+    /// Has no effect if the task has already finished.
+    pub fn cancel(&self) {
+        self.cancelled.set(true);
+    }
+
+    /// Whether or not [cancel] has been called on this handle (or a clone of this handle).
+    ///
+    /// [cancel]: CancelHandle::cancel
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::task::GpuTaskExt;
+
+    struct Fenced;
+
+    unsafe impl GpuTask<()> for Fenced {
+        type Output = u32;
+
+        fn context_id(&self) -> ContextId {
+            ContextId::Any
+        }
+
+        fn progress(&mut self, _execution_context: &mut ()) -> Progress<Self::Output> {
+            Progress::ContinueFenced
+        }
+    }
+
+    #[test]
+    fn test_cancellable_cancel() {
+        let (mut task, cancel_handle) = cancellable(Fenced);
+
+        match task.progress(&mut ()) {
+            Progress::ContinueFenced => (),
+            Progress::Finished(_) => panic!("Expected task to continue."),
+        }
+
+        assert!(!GpuTask::<()>::is_cancelled(&task));
+
+        cancel_handle.cancel();
+
+        assert!(cancel_handle.is_cancelled());
+        assert!(GpuTask::<()>::is_cancelled(&task));
+
+        match task.progress(&mut ()) {
+            Progress::Finished(output) => assert_eq!(output, None),
+            Progress::ContinueFenced => panic!("Expected task to finish."),
+        }
+    }
+
+    #[test]
+    fn test_cancellable_cancelled_through_map() {
+        let (task, cancel_handle) = cancellable(Fenced);
+        let task = GpuTaskExt::<()>::map(task, |output: Option<u32>| output.is_none());
+
+        assert!(!GpuTask::<()>::is_cancelled(&task));
+
+        cancel_handle.clone().cancel();
+
+        assert!(GpuTask::<()>::is_cancelled(&task));
+    }
+}
@@ -1,5 +1,7 @@
-use super::{Join, Join3, Join4, Join5, Sequence, Sequence3, Sequence4, Sequence5};
-use crate::task::Map;
+use super::{
+    Join, Join3, Join4, Join5, Sequence, Sequence3, Sequence4, Sequence5, TryJoin, TrySequence,
+};
+use crate::task::{BoxedTask, Inspect, Map, Retry, TransientError, ValidationContext};
 
 /// Trait for types that represent a computational task is to be partly or completely executed on a
 /// GPU.
@@ -41,6 +43,28 @@ pub unsafe trait GpuTask<Ec> {
     /// executor will insert a GPU fence into the command stream. It will call this method again
     /// once that fence has become signalled.
     fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output>;
+
+    /// Reports likely mistakes in this [GpuTask] to the validation `context`, without executing the
+    /// task.
+    ///
+    /// Called by [RenderingContext::validate]. The default implementation reports nothing. Tasks
+    /// that combine other tasks forward the call to each of the tasks they combine, so that the
+    /// complete task graph is validated.
+    ///
+    /// [RenderingContext::validate]: crate::runtime::RenderingContext::validate
+    fn validate(&self, _context: &mut ValidationContext) {}
+
+    /// Returns `true` if this [GpuTask] was cancelled, `false` otherwise.
+    ///
+    /// A task executor checks this for tasks that are waiting on a GPU fence: a cancelled task is
+    /// progressed one final time right away, rather than once its fence has become signalled. The
+    /// task must then finish without issuing any further commands. The default implementation
+    /// returns `false`; see [cancellable] for a task that may be cancelled.
+    ///
+    /// [cancellable]: crate::task::cancellable
+    fn is_cancelled(&self) -> bool {
+        false
+    }
 }
 
 unsafe impl<T, Ec> GpuTask<Ec> for Box<T>
@@ -53,6 +77,14 @@ where
         self.as_ref().context_id()
     }
 
+    fn validate(&self, context: &mut ValidationContext) {
+        self.as_ref().validate(context)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.as_ref().is_cancelled()
+    }
+
     fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
         self.as_mut().progress(execution_context)
     }
@@ -64,6 +96,33 @@ pub trait GpuTaskExt<Ec>: GpuTask<Ec> {
         F: FnOnce(Self::Output) -> U,
         Self: Sized;
 
+    /// Calls `f` with a reference to this task's output when this task finishes, then passes the
+    /// output on unchanged.
+    ///
+    /// This is useful for debugging, e.g. to log intermediate outputs or to assert invariants
+    /// somewhere in a chain of combinators, without otherwise affecting the chain.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use web_glitz::runtime::{Connection, RenderingContext};
+    /// # use web_glitz::task::GpuTask;
+    /// # fn wrapper<Rc, T>(context: &Rc, download_task: T)
+    /// # where Rc: RenderingContext, T: GpuTask<Connection, Output=Box<[u8]>> + 'static {
+    /// use web_glitz::task::GpuTaskExt;
+    ///
+    /// let task = download_task.inspect(|data| {
+    ///     web_sys::console::log_1(&format!("Downloaded {} bytes", data.len()).into());
+    /// });
+    ///
+    /// context.submit(task);
+    /// # }
+    /// ```
+    fn inspect<F>(self, f: F) -> Inspect<Self, F>
+    where
+        F: FnOnce(&Self::Output),
+        Self: Sized;
+
     /// Combines this task with another task `b`, waiting for both tasks to complete in no
     /// particular order.
     ///
@@ -210,6 +269,62 @@ pub trait GpuTaskExt<Ec>: GpuTask<Ec> {
         D: GpuTask<Ec>,
         E: GpuTask<Ec>,
         Self: Sized;
+
+    /// Combines this fallible task with another fallible task `b`, waiting for both tasks to
+    /// complete in no particular order, or until one of the tasks finishes with an error.
+    ///
+    /// This returns a new "joined" task. If either sub-task finishes with an `Err`, then the joined
+    /// task immediately finishes with that error. When both sub-tasks finish successfully, it will
+    /// output `Ok((A, B))` where `A` is this task's success value and `B` is task `b`'s success
+    /// value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [ContextId] of `b` is not compatible with this task's [ContextId].
+    fn try_join<B, O, OB, E>(self, b: B) -> TryJoin<Self, B, Ec>
+    where
+        Self: GpuTask<Ec, Output = Result<O, E>> + Sized,
+        B: GpuTask<Ec, Output = Result<OB, E>>;
+
+    /// Combines this fallible task with another fallible task `b`, waiting for both tasks to
+    /// complete in order, or until one of the tasks finishes with an error.
+    ///
+    /// This returns a new "sequenced" task. If this task finishes with an `Err`, then the
+    /// sequenced task immediately finishes with that error and `b` is never progressed. When both
+    /// sub-tasks finish successfully, it will output `Ok((A, B))` where `A` is this task's success
+    /// value and `B` is task `b`'s success value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [ContextId] of `b` is not compatible with this task's [ContextId].
+    fn try_sequence<B, O, OB, E>(self, b: B) -> TrySequence<Self, B, Ec>
+    where
+        Self: GpuTask<Ec, Output = Result<O, E>> + Sized,
+        B: GpuTask<Ec, Output = Result<OB, E>>;
+
+    /// Wraps this fallible task such that it is executed again when it fails with a transient error
+    /// (see [TransientError]), up to a total of `max_attempts` executions.
+    ///
+    /// Each new attempt executes a fresh clone of this task, after backing off until the GPU has
+    /// processed all commands that were issued before the failure. If the task fails with an error
+    /// that is not transient, or if the final attempt fails, then the retrying task finishes with
+    /// that error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_attempts` is `0`.
+    fn retry<O, E>(self, max_attempts: usize) -> Retry<Self>
+    where
+        Self: GpuTask<Ec, Output = Result<O, E>> + Clone + Sized,
+        E: TransientError;
+
+    /// Erases the type of this task, returning a [BoxedTask].
+    ///
+    /// This allows tasks of different types that produce the same output type to be stored
+    /// together, e.g. in a [Vec]. See [BoxedTask] for details.
+    fn boxed(self) -> BoxedTask<Self::Output, Ec>
+    where
+        Self: Sized + 'static;
 }
 
 impl<T, Ec> GpuTaskExt<Ec> for T
@@ -223,6 +338,13 @@ where
         Map::new(self, f)
     }
 
+    fn inspect<F>(self, f: F) -> Inspect<Self, F>
+    where
+        F: FnOnce(&Self::Output),
+    {
+        Inspect::new(self, f)
+    }
+
     fn join<B>(self, b: B) -> Join<T, B, Ec>
     where
         B: GpuTask<Ec>,
@@ -290,6 +412,37 @@ where
     {
         Sequence5::new(self, b, c, d, e)
     }
+
+    fn try_join<B, O, OB, E>(self, b: B) -> TryJoin<T, B, Ec>
+    where
+        T: GpuTask<Ec, Output = Result<O, E>>,
+        B: GpuTask<Ec, Output = Result<OB, E>>,
+    {
+        TryJoin::new(self, b)
+    }
+
+    fn try_sequence<B, O, OB, E>(self, b: B) -> TrySequence<T, B, Ec>
+    where
+        T: GpuTask<Ec, Output = Result<O, E>>,
+        B: GpuTask<Ec, Output = Result<OB, E>>,
+    {
+        TrySequence::new(self, b)
+    }
+
+    fn retry<O, E>(self, max_attempts: usize) -> Retry<T>
+    where
+        T: GpuTask<Ec, Output = Result<O, E>> + Clone,
+        E: TransientError,
+    {
+        Retry::new(self, max_attempts)
+    }
+
+    fn boxed(self) -> BoxedTask<T::Output, Ec>
+    where
+        T: 'static,
+    {
+        BoxedTask::new(self)
+    }
 }
 
 /// Returned from [GpuTask::progress], signifies the current state of progress for the task.
@@ -0,0 +1,52 @@
+use crate::task::{ContextId, GpuTask, Progress, ValidationContext};
+
+/// Task for the `inspect` combinator, calling a function with a reference to a task's output when
+/// the task finishes, then passing the output on unchanged.
+///
+/// See [GpuTaskExt::inspect].
+///
+/// [GpuTaskExt::inspect]: crate::task::GpuTaskExt::inspect
+#[derive(Clone)]
+pub struct Inspect<T, F> {
+    task: T,
+    f: Option<F>,
+}
+
+impl<T, F> Inspect<T, F> {
+    pub(crate) fn new(task: T, f: F) -> Self {
+        Inspect { task, f: Some(f) }
+    }
+}
+
+unsafe impl<Ec, T, F> GpuTask<Ec> for Inspect<T, F>
+where
+    T: GpuTask<Ec>,
+    F: FnOnce(&T::Output),
+{
+    type Output = T::Output;
+
+    fn context_id(&self) -> ContextId {
+        self.task.context_id()
+    }
+
+    fn validate(&self, context: &mut ValidationContext) {
+        self.task.validate(context)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.task.is_cancelled()
+    }
+
+    fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
+        self.task.progress(execution_context).map(|output| {
+            let f = self
+                .f
+                .take()
+                .expect("Cannot progress Inspect after it has finished.");
+
+            f(&output);
+
+            output
+        })
+    }
+}
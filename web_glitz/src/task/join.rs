@@ -1,7 +1,7 @@
 #![allow(non_snake_case)]
 
 use super::maybe_done::{maybe_done, MaybeDone};
-use super::{ContextId, GpuTask, Progress};
+use super::{ContextId, GpuTask, Progress, ValidationContext};
 
 macro_rules! generate_join {
     ($(
@@ -43,6 +43,14 @@ macro_rules! generate_join {
                 self.id
             }
 
+            fn validate(&self, context: &mut ValidationContext) {
+                self.a.validate(context);
+
+                $(
+                    self.$B.validate(context);
+                )*
+            }
+
             fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
                 let mut all_done = self.a.progress(execution_context);
 
@@ -140,6 +148,14 @@ macro_rules! generate_join_left {
                 self.id
             }
 
+            fn validate(&self, context: &mut ValidationContext) {
+                self.a.validate(context);
+
+                $(
+                    self.$B.validate(context);
+                )*
+            }
+
             fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
                 let mut all_done = self.a.progress(execution_context);
 
@@ -243,6 +259,14 @@ macro_rules! generate_join_right {
                 self.id
             }
 
+            fn validate(&self, context: &mut ValidationContext) {
+                $(
+                    self.$A.validate(context);
+                )*
+
+                self.$B.validate(context);
+            }
+
             fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
                 let mut all_done = true;
 
@@ -401,6 +425,12 @@ where
         self.id
     }
 
+    fn validate(&self, context: &mut ValidationContext) {
+        for task in &self.vec {
+            task.validate(context);
+        }
+    }
+
     fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
         if self.vec.is_empty() {
             return Progress::Finished(());
@@ -1,4 +1,4 @@
-use crate::task::{ContextId, GpuTask, Progress};
+use crate::task::{ContextId, GpuTask, Progress, ValidationContext};
 
 #[derive(Clone)]
 pub struct Map<T, F> {
@@ -23,6 +23,14 @@ where
         self.task.context_id()
     }
 
+    fn validate(&self, context: &mut ValidationContext) {
+        self.task.validate(context)
+    }
+
+    fn is_cancelled(&self) -> bool {
+        self.task.is_cancelled()
+    }
+
     fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
         self.task.progress(execution_context).map(|output| {
             let f = self
@@ -1,7 +1,8 @@
 use std::marker::PhantomData;
 use std::mem;
 
-use super::{GpuTask, Progress};
+use super::{GpuTask, Progress, ValidationContext};
+use crate::runtime::debug;
 
 pub(crate) enum MaybeDone<T, O, Ec> {
     NotYet(T, PhantomData<Ec>),
@@ -16,7 +17,9 @@ where
     pub fn progress(&mut self, execution_context: &mut Ec) -> bool {
         let res = match self {
             MaybeDone::Done(_) => return true,
-            MaybeDone::NotYet(ref mut task, _) => task.progress(execution_context),
+            MaybeDone::NotYet(ref mut task, _) => {
+                debug::track::<T, Ec, _>(|| task.progress(execution_context))
+            }
             MaybeDone::Gone => panic!("Cannot progress a Join twice."),
         };
 
@@ -30,6 +33,12 @@ where
         }
     }
 
+    pub fn validate(&self, context: &mut ValidationContext) {
+        if let MaybeDone::NotYet(task, _) = self {
+            task.validate(context);
+        }
+    }
+
     pub fn take(&mut self) -> O {
         match mem::replace(self, MaybeDone::Gone) {
             MaybeDone::Done(a) => a,
@@ -38,6 +47,33 @@ where
     }
 }
 
+impl<T, O, E, Ec> MaybeDone<T, Result<O, E>, Ec>
+where
+    T: GpuTask<Ec, Output = Result<O, E>>,
+{
+    pub fn is_err(&self) -> bool {
+        if let MaybeDone::Done(Err(_)) = self {
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn take_ok(&mut self) -> O {
+        match self.take() {
+            Ok(output) => output,
+            Err(_) => unreachable!("`take_ok` called on a task that finished with an error."),
+        }
+    }
+
+    pub fn take_err(&mut self) -> E {
+        match self.take() {
+            Err(err) => err,
+            Ok(_) => unreachable!("`take_err` called on a task that finished successfully."),
+        }
+    }
+}
+
 impl<T, O, Ec> Clone for MaybeDone<T, O, Ec>
 where
     T: Clone,
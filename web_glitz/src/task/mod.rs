@@ -142,7 +142,8 @@
 //! faces finish, so we use the [join_all] macro to join them into a task. However, it is important
 //! that we do not begin generating the mipmap data for the remaining levels before all uploads
 //! have finished. We therefor use [sequence] to sequence our combined upload command with the
-//! "generate mipmap" command.
+//! "generate mipmap" command. This particular pattern is common enough that [TextureCube] provides
+//! it as a single command, see [TextureCube::upload_all_faces_and_generate_mipmap_command].
 //!
 //! Note that all sequence and join functions and macros mentioned above also come in "left" and
 //! "right" variants. For example, [sequence5] is accompanied by [sequence5_left] and
@@ -155,6 +156,10 @@
 //! right-most task. In all other aspects the behaviour of a task created with [sequence5_left] or
 //! [sequence5_right] is identical to the behaviour of a task created with [sequence5].
 //!
+//! When sub-tasks are fallible (their output is a [Result]), [try_sequence] and [try_join] may be
+//! used instead. These stop at the first sub-task that finishes with an `Err` and output that
+//! error; if all sub-tasks succeed, they output `Ok` with a tuple of the success values.
+//!
 //! # Submitting tasks
 //!
 //! A task merely describes work for the GPU, it does not actually do anything until it is submitted
@@ -185,11 +190,21 @@
 //! [Texture2D]: web_glitz::image::texture_2d::Texture2D
 //! [RenderingContext]: web_glitz::runtime::RenderingContext
 //! [TextureCube]: web_glitz::image::texture_cube::TextureCube
+//! [TextureCube::upload_all_faces_and_generate_mipmap_command]: web_glitz::image::texture_cube::TextureCube::upload_all_faces_and_generate_mipmap_command
 //! [Future]: std::future::Future
 
+mod boxed;
+pub use self::boxed::BoxedTask;
+
+mod cancellable;
+pub use self::cancellable::{cancellable, CancelHandle, Cancellable};
+
 mod gpu_task;
 pub use self::gpu_task::{ContextId, Empty, GpuTask, GpuTaskExt, Progress};
 
+mod inspect;
+pub use self::inspect::Inspect;
+
 mod join;
 pub use self::join::{
     join, join3, join3_left, join3_right, join4, join4_left, join4_right, join5, join5_left,
@@ -203,15 +218,30 @@ pub use self::map::Map;
 mod option_task;
 pub use self::option_task::OptionTask;
 
+mod retry;
+pub use self::retry::{retry, Retry, TransientError};
+
 mod sequence;
 pub use self::sequence::{
     sequence, sequence3, sequence3_left, sequence3_right, sequence4, sequence4_left,
     sequence4_right, sequence5, sequence5_left, sequence5_right, sequence_iter, sequence_left,
     sequence_right, Sequence, Sequence3, Sequence3Left, Sequence3Right, Sequence4, Sequence4Left,
-    Sequence4Right, Sequence5, Sequence5Left, Sequence5Right, SequenceIter, SequenceLeft,
-    SequenceRight,
+    Sequence4Right, Sequence5, Sequence5Left, Sequence5Right, SequenceIter,
+    SequenceIterWithProgress, SequenceLeft, SequenceRight,
 };
 
+mod try_join;
+pub use self::try_join::{try_join, TryJoin};
+
+mod try_sequence;
+pub use self::try_sequence::{try_sequence, TrySequence};
+
+mod validate;
+pub use self::validate::{ValidationContext, ValidationError};
+
+mod value;
+pub use self::value::{value, ValueTask};
+
 mod maybe_done;
 
 /// Macro that joins all tasks.
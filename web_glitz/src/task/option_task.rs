@@ -1,4 +1,4 @@
-use crate::task::{ContextId, GpuTask, Progress};
+use crate::task::{ContextId, GpuTask, Progress, ValidationContext};
 
 #[derive(Clone)]
 pub struct OptionTask<T> {
@@ -18,6 +18,12 @@ where
         }
     }
 
+    fn validate(&self, context: &mut ValidationContext) {
+        if let Some(task) = &self.option {
+            task.validate(context);
+        }
+    }
+
     fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
         match &mut self.option {
             Some(task) => task.progress(execution_context).map(Some),
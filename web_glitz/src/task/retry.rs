@@ -0,0 +1,198 @@
+use super::{ContextId, GpuTask, Progress, ValidationContext};
+
+/// Trait implemented for error types that may indicate a transient failure, where executing the
+/// task that failed again may succeed.
+///
+/// See [retry] and [GpuTaskExt::retry].
+///
+/// [GpuTaskExt::retry]: crate::task::GpuTaskExt::retry
+pub trait TransientError {
+    /// Returns `true` if this error indicates a transient failure, or `false` if the failure is
+    /// deterministic (e.g. the result of invalid arguments) and executing the task again is
+    /// expected to fail in the same way.
+    fn is_transient(&self) -> bool;
+}
+
+/// Task for the `retry` combinator, re-executing a fallible task when it fails with a transient
+/// error.
+///
+/// See [retry] and [GpuTaskExt::retry].
+///
+/// [GpuTaskExt::retry]: crate::task::GpuTaskExt::retry
+#[derive(Clone)]
+pub struct Retry<T> {
+    task: T,
+    attempt: T,
+    remaining_retries: usize,
+    retry_pending: bool,
+}
+
+impl<T> Retry<T>
+where
+    T: Clone,
+{
+    pub(crate) fn new(task: T, max_attempts: usize) -> Self {
+        if max_attempts == 0 {
+            panic!("`max_attempts` must be at least `1`.");
+        }
+
+        Retry {
+            attempt: task.clone(),
+            task,
+            remaining_retries: max_attempts - 1,
+            retry_pending: false,
+        }
+    }
+}
+
+unsafe impl<T, O, E, Ec> GpuTask<Ec> for Retry<T>
+where
+    T: GpuTask<Ec, Output = Result<O, E>> + Clone,
+    E: TransientError,
+{
+    type Output = Result<O, E>;
+
+    fn context_id(&self) -> ContextId {
+        self.task.context_id()
+    }
+
+    fn validate(&self, context: &mut ValidationContext) {
+        self.attempt.validate(context)
+    }
+
+    fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
+        if self.retry_pending {
+            self.attempt = self.task.clone();
+            self.retry_pending = false;
+        }
+
+        match self.attempt.progress(execution_context) {
+            Progress::Finished(Err(err)) if err.is_transient() && self.remaining_retries > 0 => {
+                self.remaining_retries -= 1;
+                self.retry_pending = true;
+
+                // Back off until the GPU has processed the commands that were issued so far.
+                Progress::ContinueFenced
+            }
+            progress => progress,
+        }
+    }
+}
+
+/// Wraps fallible `task` such that it is executed again when it fails with a transient error (see
+/// [TransientError]), up to a total of `max_attempts` executions.
+///
+/// Each new attempt executes a fresh clone of the original `task`. Before each new attempt, the
+/// retrying task backs off until the GPU has processed all commands that were issued before the
+/// failure (the task executor waits on a fence). If the task fails with an error that is not
+/// transient, or if the final attempt fails, then the retrying task finishes with that error.
+///
+/// Note that WebGlitz's own commands currently do not produce transient errors; this combinator is
+/// intended for custom tasks with error types that implement [TransientError].
+///
+/// # Panics
+///
+/// Panics if `max_attempts` is `0`.
+pub fn retry<T, O, E, Ec>(task: T, max_attempts: usize) -> Retry<T>
+where
+    T: GpuTask<Ec, Output = Result<O, E>> + Clone,
+    E: TransientError,
+{
+    Retry::new(task, max_attempts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    enum TestError {
+        Transient,
+        Deterministic,
+    }
+
+    impl TransientError for TestError {
+        fn is_transient(&self) -> bool {
+            *self == TestError::Transient
+        }
+    }
+
+    // Fails with the given errors in order, then succeeds. The failure count is shared between
+    // clones through the execution context.
+    #[derive(Clone)]
+    struct Flaky {
+        errors: &'static [TestError],
+    }
+
+    unsafe impl GpuTask<usize> for Flaky {
+        type Output = Result<usize, TestError>;
+
+        fn context_id(&self) -> ContextId {
+            ContextId::Any
+        }
+
+        fn progress(&mut self, attempts: &mut usize) -> Progress<Self::Output> {
+            let attempt = *attempts;
+
+            *attempts += 1;
+
+            if let Some(error) = self.errors.get(attempt) {
+                Progress::Finished(Err(*error))
+            } else {
+                Progress::Finished(Ok(attempt))
+            }
+        }
+    }
+
+    fn run<T>(task: &mut T, attempts: &mut usize) -> T::Output
+    where
+        T: GpuTask<usize>,
+    {
+        loop {
+            if let Progress::Finished(output) = task.progress(attempts) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn test_retry_transient() {
+        let mut attempts = 0;
+        let mut task = retry(
+            Flaky {
+                errors: &[TestError::Transient, TestError::Transient],
+            },
+            3,
+        );
+
+        assert_eq!(run(&mut task, &mut attempts), Ok(2));
+    }
+
+    #[test]
+    fn test_retry_exhausted() {
+        let mut attempts = 0;
+        let mut task = retry(
+            Flaky {
+                errors: &[TestError::Transient, TestError::Transient],
+            },
+            2,
+        );
+
+        assert_eq!(run(&mut task, &mut attempts), Err(TestError::Transient));
+        assert_eq!(attempts, 2);
+    }
+
+    #[test]
+    fn test_retry_deterministic() {
+        let mut attempts = 0;
+        let mut task = retry(
+            Flaky {
+                errors: &[TestError::Deterministic],
+            },
+            3,
+        );
+
+        assert_eq!(run(&mut task, &mut attempts), Err(TestError::Deterministic));
+        assert_eq!(attempts, 1);
+    }
+}
@@ -1,7 +1,7 @@
 #![allow(non_snake_case)]
 
 use super::maybe_done::{maybe_done, MaybeDone};
-use super::{ContextId, GpuTask, Progress};
+use super::{ContextId, GpuTask, Progress, ValidationContext};
 
 macro_rules! generate_sequence {
     ($(
@@ -43,6 +43,14 @@ macro_rules! generate_sequence {
                 self.id
             }
 
+            fn validate(&self, context: &mut ValidationContext) {
+                self.a.validate(context);
+
+                $(
+                    self.$B.validate(context);
+                )*
+            }
+
             fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
                 if !self.a.progress(execution_context) {
                     return Progress::ContinueFenced;
@@ -140,6 +148,14 @@ macro_rules! generate_sequence_left {
                 self.id
             }
 
+            fn validate(&self, context: &mut ValidationContext) {
+                self.a.validate(context);
+
+                $(
+                    self.$B.validate(context);
+                )*
+            }
+
             fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
                 if !self.a.progress(execution_context) {
                     return Progress::ContinueFenced;
@@ -243,6 +259,14 @@ macro_rules! generate_sequence_right {
                 self.id
             }
 
+            fn validate(&self, context: &mut ValidationContext) {
+                $(
+                    self.$A.validate(context);
+                )*
+
+                self.$B.validate(context);
+            }
+
             fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
                 $(
                     if !self.$A.progress(execution_context) {
@@ -388,6 +412,54 @@ where
 
         SequenceIter { id, vec }
     }
+
+    /// Attaches a progress callback `f` to this sequence, which is invoked with the number of
+    /// sub-tasks that have finished and the total number of sub-tasks whenever a sub-task finishes.
+    ///
+    /// The task returned by this method yields to the executor after every sub-task that finishes
+    /// (except for the last), as if the sub-task required a GPU fence. This gives the browser an
+    /// opportunity to update the page (e.g. a loading bar) in between the sub-tasks. This does not
+    /// change the task's output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use web_glitz::runtime::RenderingContext;
+    /// # use web_glitz::image::format::RGBA8;
+    /// # use web_glitz::image::texture_2d::Texture2D;
+    /// # fn wrapper<Rc>(context: &Rc, texture: Texture2D<RGBA8>, pixels: Vec<[u8; 4]>)
+    /// # where Rc: RenderingContext {
+    /// use web_glitz::image::{Image2DSource, Region2D};
+    /// use web_glitz::task::sequence_iter;
+    ///
+    /// // Upload a 4096x4096 image in bands of 256 rows.
+    /// let base_level = texture.base_level();
+    /// let band_size = 4096 * 256;
+    ///
+    /// let chunks = pixels.chunks(band_size).enumerate().map(|(i, band)| {
+    ///     let data = Image2DSource::from_pixels(band.to_vec(), 4096, 256).unwrap();
+    ///     let region = Region2D::Area((0, i as u32 * 256), 4096, 256);
+    ///
+    ///     base_level.sub_image(region).upload_command(data)
+    /// });
+    ///
+    /// let task = sequence_iter(chunks).with_progress(|done, total| {
+    ///     // Update a loading bar...
+    /// });
+    ///
+    /// context.submit(task);
+    /// # }
+    /// ```
+    pub fn with_progress<F>(self, f: F) -> SequenceIterWithProgress<T, F, Ec>
+    where
+        F: FnMut(usize, usize),
+    {
+        SequenceIterWithProgress {
+            sequence: self,
+            f,
+            reported: 0,
+        }
+    }
 }
 
 unsafe impl<T, Ec> GpuTask<Ec> for SequenceIter<T, Ec>
@@ -400,6 +472,12 @@ where
         self.id
     }
 
+    fn validate(&self, context: &mut ValidationContext) {
+        for task in &self.vec {
+            task.validate(context);
+        }
+    }
+
     fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
         for task in &mut self.vec {
             if !task.progress(execution_context) {
@@ -423,6 +501,71 @@ where
     }
 }
 
+/// Returned from [SequenceIter::with_progress], a [SequenceIter] that reports its progress to a
+/// callback.
+///
+/// See [SequenceIter::with_progress].
+pub struct SequenceIterWithProgress<T, F, Ec> {
+    sequence: SequenceIter<T, Ec>,
+    f: F,
+    reported: usize,
+}
+
+unsafe impl<T, F, Ec> GpuTask<Ec> for SequenceIterWithProgress<T, F, Ec>
+where
+    T: GpuTask<Ec, Output = ()>,
+    F: FnMut(usize, usize),
+{
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        self.sequence.context_id()
+    }
+
+    fn validate(&self, context: &mut ValidationContext) {
+        self.sequence.validate(context)
+    }
+
+    fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
+        let total = self.sequence.vec.len();
+
+        for (i, task) in self.sequence.vec.iter_mut().enumerate() {
+            if !task.progress(execution_context) {
+                return Progress::ContinueFenced;
+            }
+
+            let done = i + 1;
+
+            if done > self.reported {
+                self.reported = done;
+
+                (self.f)(done, total);
+
+                // Yield after every newly finished sub-task, so that the page may be updated.
+                if done < total {
+                    return Progress::ContinueFenced;
+                }
+            }
+        }
+
+        Progress::Finished(())
+    }
+}
+
+impl<T, F, Ec> Clone for SequenceIterWithProgress<T, F, Ec>
+where
+    T: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        SequenceIterWithProgress {
+            sequence: self.sequence.clone(),
+            f: self.f.clone(),
+            reported: self.reported,
+        }
+    }
+}
+
 /// Combines task `a` with another task `b`, waiting for both tasks to complete in order.
 ///
 /// This returns a new "sequenced" task. This sequenced task must progress its sub-tasks in order.
@@ -695,3 +838,28 @@ where
 {
     SequenceIter::new(iterator)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::RefCell;
+
+    use crate::task::value;
+
+    #[test]
+    fn test_sequence_iter_with_progress() {
+        let reports = RefCell::new(Vec::new());
+        let mut task = sequence_iter(vec![value(()), value(()), value(())])
+            .with_progress(|done, total| reports.borrow_mut().push((done, total)));
+
+        let mut progress_calls = 1;
+
+        while let Progress::ContinueFenced = task.progress(&mut ()) {
+            progress_calls += 1;
+        }
+
+        assert_eq!(progress_calls, 3);
+        assert_eq!(*reports.borrow(), vec![(1, 3), (2, 3), (3, 3)]);
+    }
+}
@@ -0,0 +1,211 @@
+use super::maybe_done::{maybe_done, MaybeDone};
+use super::{ContextId, GpuTask, Progress, ValidationContext};
+
+/// Task for the `try_join` combinator, waiting for two fallible tasks to complete in no specific
+/// order, stopping at the first error.
+///
+/// See [try_join] and [GpuTaskExt::try_join].
+pub struct TryJoin<A, B, Ec>
+where
+    A: GpuTask<Ec>,
+    B: GpuTask<Ec>,
+{
+    id: ContextId,
+    a: MaybeDone<A, A::Output, Ec>,
+    b: MaybeDone<B, B::Output, Ec>,
+}
+
+impl<A, B, Ec> TryJoin<A, B, Ec>
+where
+    A: GpuTask<Ec>,
+    B: GpuTask<Ec>,
+{
+    pub(crate) fn new(a: A, b: B) -> Self {
+        let id = a.context_id().combine(b.context_id()).unwrap();
+
+        TryJoin {
+            id,
+            a: maybe_done(a),
+            b: maybe_done(b),
+        }
+    }
+}
+
+unsafe impl<A, B, OA, OB, E, Ec> GpuTask<Ec> for TryJoin<A, B, Ec>
+where
+    A: GpuTask<Ec, Output = Result<OA, E>>,
+    B: GpuTask<Ec, Output = Result<OB, E>>,
+{
+    type Output = Result<(OA, OB), E>;
+
+    fn context_id(&self) -> ContextId {
+        self.id
+    }
+
+    fn validate(&self, context: &mut ValidationContext) {
+        self.a.validate(context);
+        self.b.validate(context);
+    }
+
+    fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
+        let a_done = self.a.progress(execution_context);
+
+        if self.a.is_err() {
+            return Progress::Finished(Err(self.a.take_err()));
+        }
+
+        let b_done = self.b.progress(execution_context);
+
+        if self.b.is_err() {
+            return Progress::Finished(Err(self.b.take_err()));
+        }
+
+        if a_done && b_done {
+            Progress::Finished(Ok((self.a.take_ok(), self.b.take_ok())))
+        } else {
+            Progress::ContinueFenced
+        }
+    }
+}
+
+impl<A, B, Ec> Clone for TryJoin<A, B, Ec>
+where
+    A: GpuTask<Ec> + Clone,
+    A::Output: Clone,
+    B: GpuTask<Ec> + Clone,
+    B::Output: Clone,
+{
+    fn clone(&self) -> Self {
+        TryJoin {
+            id: self.id.clone(),
+            a: self.a.clone(),
+            b: self.b.clone(),
+        }
+    }
+}
+
+/// Combines fallible task `a` with another fallible task `b`, waiting for both tasks to complete in
+/// no specific order, or until one of the tasks finishes with an error.
+///
+/// This returns a new "joined" task. This joined task may progress its sub-tasks in any order. If
+/// either sub-task finishes with an `Err`, then the joined task immediately finishes with that
+/// error, without progressing the other sub-task any further. When both sub-tasks finish
+/// successfully, it will output `Ok((A, B))` where `A` is `a`'s success value and `B` is `b`'s
+/// success value.
+///
+/// # Panics
+///
+/// Panics if the [ContextId]s of `a` and `b` are not compatible.
+pub fn try_join<A, B, OA, OB, E, Ec>(a: A, b: B) -> TryJoin<A, B, Ec>
+where
+    A: GpuTask<Ec, Output = Result<OA, E>>,
+    B: GpuTask<Ec, Output = Result<OB, E>>,
+{
+    TryJoin::new(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::task::value;
+
+    struct Unreachable;
+
+    unsafe impl GpuTask<()> for Unreachable {
+        type Output = Result<(), u32>;
+
+        fn context_id(&self) -> ContextId {
+            ContextId::Any
+        }
+
+        fn progress(&mut self, _execution_context: &mut ()) -> Progress<Self::Output> {
+            panic!("Task should not have been progressed.")
+        }
+    }
+
+    // Waits on a fence once, then finishes with `output`.
+    struct FencedOnce {
+        output: Result<u32, u32>,
+        fenced: bool,
+    }
+
+    impl FencedOnce {
+        fn new(output: Result<u32, u32>) -> Self {
+            FencedOnce {
+                output,
+                fenced: false,
+            }
+        }
+    }
+
+    unsafe impl GpuTask<()> for FencedOnce {
+        type Output = Result<u32, u32>;
+
+        fn context_id(&self) -> ContextId {
+            ContextId::Any
+        }
+
+        fn progress(&mut self, _execution_context: &mut ()) -> Progress<Self::Output> {
+            if self.fenced {
+                Progress::Finished(self.output)
+            } else {
+                self.fenced = true;
+
+                Progress::ContinueFenced
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_join_ok() {
+        let mut task = try_join(FencedOnce::new(Ok(1)), value(Ok(2)));
+
+        match task.progress(&mut ()) {
+            Progress::Finished(_) => panic!("Expected task to wait on a fence."),
+            Progress::ContinueFenced => (),
+        }
+
+        match task.progress(&mut ()) {
+            Progress::Finished(output) => assert_eq!(output, Ok((1, 2))),
+            Progress::ContinueFenced => panic!("Expected task to finish."),
+        }
+    }
+
+    #[test]
+    fn test_try_join_short_circuits_first_task() {
+        let mut task = try_join(value(Err::<(), _>(3)), Unreachable);
+
+        match task.progress(&mut ()) {
+            Progress::Finished(output) => assert_eq!(output, Err(3)),
+            Progress::ContinueFenced => panic!("Expected task to finish."),
+        }
+    }
+
+    #[test]
+    fn test_try_join_short_circuits_second_task() {
+        // The first task is still waiting on a fence when the second task fails; the joined task
+        // finishes with the error right away rather than waiting for the first task.
+        let mut task = try_join(FencedOnce::new(Ok(1)), value(Err::<u32, _>(4)));
+
+        match task.progress(&mut ()) {
+            Progress::Finished(output) => assert_eq!(output, Err(4)),
+            Progress::ContinueFenced => panic!("Expected task to finish."),
+        }
+    }
+
+    #[test]
+    fn test_try_join_short_circuits_after_fence() {
+        let mut task = try_join(FencedOnce::new(Err(5)), value(Ok(2)));
+
+        match task.progress(&mut ()) {
+            Progress::Finished(_) => panic!("Expected task to wait on a fence."),
+            Progress::ContinueFenced => (),
+        }
+
+        match task.progress(&mut ()) {
+            Progress::Finished(output) => assert_eq!(output, Err(5)),
+            Progress::ContinueFenced => panic!("Expected task to finish."),
+        }
+    }
+}
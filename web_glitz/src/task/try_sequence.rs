@@ -0,0 +1,146 @@
+use super::maybe_done::{maybe_done, MaybeDone};
+use super::{ContextId, GpuTask, Progress, ValidationContext};
+
+/// Task for the `try_sequence` combinator, waiting for two fallible tasks to complete in order,
+/// stopping at the first error.
+///
+/// See [try_sequence] and [GpuTaskExt::try_sequence].
+pub struct TrySequence<A, B, Ec>
+where
+    A: GpuTask<Ec>,
+    B: GpuTask<Ec>,
+{
+    id: ContextId,
+    a: MaybeDone<A, A::Output, Ec>,
+    b: MaybeDone<B, B::Output, Ec>,
+}
+
+impl<A, B, Ec> TrySequence<A, B, Ec>
+where
+    A: GpuTask<Ec>,
+    B: GpuTask<Ec>,
+{
+    pub(crate) fn new(a: A, b: B) -> Self {
+        let id = a.context_id().combine(b.context_id()).unwrap();
+
+        TrySequence {
+            id,
+            a: maybe_done(a),
+            b: maybe_done(b),
+        }
+    }
+}
+
+unsafe impl<A, B, OA, OB, E, Ec> GpuTask<Ec> for TrySequence<A, B, Ec>
+where
+    A: GpuTask<Ec, Output = Result<OA, E>>,
+    B: GpuTask<Ec, Output = Result<OB, E>>,
+{
+    type Output = Result<(OA, OB), E>;
+
+    fn context_id(&self) -> ContextId {
+        self.id
+    }
+
+    fn validate(&self, context: &mut ValidationContext) {
+        self.a.validate(context);
+        self.b.validate(context);
+    }
+
+    fn progress(&mut self, execution_context: &mut Ec) -> Progress<Self::Output> {
+        if !self.a.progress(execution_context) {
+            return Progress::ContinueFenced;
+        }
+
+        if self.a.is_err() {
+            return Progress::Finished(Err(self.a.take_err()));
+        }
+
+        if !self.b.progress(execution_context) {
+            return Progress::ContinueFenced;
+        }
+
+        if self.b.is_err() {
+            return Progress::Finished(Err(self.b.take_err()));
+        }
+
+        Progress::Finished(Ok((self.a.take_ok(), self.b.take_ok())))
+    }
+}
+
+impl<A, B, Ec> Clone for TrySequence<A, B, Ec>
+where
+    A: GpuTask<Ec> + Clone,
+    A::Output: Clone,
+    B: GpuTask<Ec> + Clone,
+    B::Output: Clone,
+{
+    fn clone(&self) -> Self {
+        TrySequence {
+            id: self.id.clone(),
+            a: self.a.clone(),
+            b: self.b.clone(),
+        }
+    }
+}
+
+/// Combines fallible task `a` with another fallible task `b`, waiting for both tasks to complete in
+/// order, or until one of the tasks finishes with an error.
+///
+/// This returns a new "sequenced" task. This sequenced task must progress its sub-tasks in order.
+/// If `a` finishes with an `Err`, then the sequenced task immediately finishes with that error and
+/// `b` is never progressed. Otherwise, the sequenced task will finish when `b` has finished. When
+/// both sub-tasks finish successfully, it will output `Ok((A, B))` where `A` is `a`'s success
+/// value and `B` is `b`'s success value.
+///
+/// # Panics
+///
+/// Panics if the [ContextId]s of `a` and `b` are not compatible.
+pub fn try_sequence<A, B, OA, OB, E, Ec>(a: A, b: B) -> TrySequence<A, B, Ec>
+where
+    A: GpuTask<Ec, Output = Result<OA, E>>,
+    B: GpuTask<Ec, Output = Result<OB, E>>,
+{
+    TrySequence::new(a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::task::value;
+
+    struct Unreachable;
+
+    unsafe impl GpuTask<()> for Unreachable {
+        type Output = Result<(), u32>;
+
+        fn context_id(&self) -> ContextId {
+            ContextId::Any
+        }
+
+        fn progress(&mut self, _execution_context: &mut ()) -> Progress<Self::Output> {
+            panic!("Task should not have been progressed.")
+        }
+    }
+
+    #[test]
+    fn test_try_sequence_ok() {
+        let mut task = try_sequence(value(Ok::<_, u32>(1)), value(Ok(2)));
+
+        match task.progress(&mut ()) {
+            Progress::Finished(output) => assert_eq!(output, Ok((1, 2))),
+            Progress::ContinueFenced => panic!("Expected task to finish."),
+        }
+    }
+
+    #[test]
+    fn test_try_sequence_short_circuits() {
+        let mut task = try_sequence(value(Err::<(), _>(3)), Unreachable);
+
+        match task.progress(&mut ()) {
+            Progress::Finished(output) => assert_eq!(output, Err(3)),
+            Progress::ContinueFenced => panic!("Expected task to finish."),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt;
+
+use crate::pipeline::graphics::util::BufferDescriptor;
+
+/// A likely mistake in a task graph, found by [RenderingContext::validate].
+///
+/// Pipeline tasks are identified by their index in the order in which they are encountered in the
+/// task graph, starting at `0` for the first pipeline task.
+///
+/// [RenderingContext::validate]: crate::runtime::RenderingContext::validate
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ValidationError {
+    /// A pipeline task does not contain any draw commands.
+    ///
+    /// Such a pipeline task binds its pipeline, buffers and resources, but does not invoke the
+    /// pipeline; it therefore does not affect the framebuffer.
+    PipelineTaskWithoutDraw {
+        /// The index of the pipeline task.
+        pipeline_task: usize,
+    },
+
+    /// A pipeline task binds a buffer as a vertex buffer that is also one of the pipeline task's
+    /// transform feedback buffers.
+    ///
+    /// WebGL does not allow a buffer to be read as vertex input while it is being written to by
+    /// transform feedback; draw commands in the pipeline task would fail.
+    VertexBufferIsTransformFeedbackBuffer {
+        /// The index of the pipeline task.
+        pipeline_task: usize,
+
+        /// The bind slot of the vertex buffer.
+        bind_slot: u32,
+    },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationError::PipelineTaskWithoutDraw { pipeline_task } => write!(
+                f,
+                "Pipeline task {} does not contain any draw commands.",
+                pipeline_task
+            ),
+            ValidationError::VertexBufferIsTransformFeedbackBuffer {
+                pipeline_task,
+                bind_slot,
+            } => write!(
+                f,
+                "The vertex buffer bound to slot {} in pipeline task {} is also bound as a \
+                 transform feedback buffer.",
+                bind_slot, pipeline_task
+            ),
+        }
+    }
+}
+
+/// Collects the [ValidationError]s found while validating a task graph.
+///
+/// See [GpuTask::validate] and [RenderingContext::validate].
+///
+/// [GpuTask::validate]: crate::task::GpuTask::validate
+/// [RenderingContext::validate]: crate::runtime::RenderingContext::validate
+pub struct ValidationContext {
+    errors: Vec<ValidationError>,
+    pipeline_task_count: usize,
+    pipeline_task: Option<PipelineTaskState>,
+}
+
+struct PipelineTaskState {
+    index: usize,
+    draw_count: usize,
+    transform_feedback_buffers: Vec<usize>,
+}
+
+impl ValidationContext {
+    pub(crate) fn new() -> Self {
+        ValidationContext {
+            errors: Vec::new(),
+            pipeline_task_count: 0,
+            pipeline_task: None,
+        }
+    }
+
+    pub(crate) fn begin_pipeline_task(&mut self, transform_feedback_buffers: &[BufferDescriptor]) {
+        let index = self.pipeline_task_count;
+
+        self.pipeline_task_count += 1;
+        self.pipeline_task = Some(PipelineTaskState {
+            index,
+            draw_count: 0,
+            transform_feedback_buffers: transform_feedback_buffers
+                .iter()
+                .map(buffer_address)
+                .collect(),
+        });
+    }
+
+    pub(crate) fn end_pipeline_task(&mut self) {
+        if let Some(pipeline_task) = self.pipeline_task.take() {
+            if pipeline_task.draw_count == 0 {
+                self.errors.push(ValidationError::PipelineTaskWithoutDraw {
+                    pipeline_task: pipeline_task.index,
+                });
+            }
+        }
+    }
+
+    pub(crate) fn record_vertex_buffers(&mut self, vertex_buffers: &[BufferDescriptor]) {
+        if let Some(pipeline_task) = &self.pipeline_task {
+            for (i, buffer) in vertex_buffers.iter().enumerate() {
+                if pipeline_task
+                    .transform_feedback_buffers
+                    .contains(&buffer_address(buffer))
+                {
+                    self.errors
+                        .push(ValidationError::VertexBufferIsTransformFeedbackBuffer {
+                            pipeline_task: pipeline_task.index,
+                            bind_slot: i as u32,
+                        });
+                }
+            }
+        }
+    }
+
+    pub(crate) fn record_draw(&mut self) {
+        if let Some(pipeline_task) = &mut self.pipeline_task {
+            pipeline_task.draw_count += 1;
+        }
+    }
+
+    pub(crate) fn finish(self) -> Result<(), Vec<ValidationError>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+// Identifies a buffer by the address of its shared data, which is unique for as long as the buffer
+// is alive.
+fn buffer_address(descriptor: &BufferDescriptor) -> usize {
+    &*descriptor.buffer_data as *const _ as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pipeline_task_without_draw() {
+        let mut context = ValidationContext::new();
+
+        context.begin_pipeline_task(&[]);
+        context.record_draw();
+        context.end_pipeline_task();
+
+        context.begin_pipeline_task(&[]);
+        context.end_pipeline_task();
+
+        assert_eq!(
+            context.finish(),
+            Err(vec![ValidationError::PipelineTaskWithoutDraw {
+                pipeline_task: 1
+            }])
+        );
+    }
+
+    #[test]
+    fn test_draw_outside_pipeline_task() {
+        let mut context = ValidationContext::new();
+
+        context.record_draw();
+
+        assert_eq!(context.finish(), Ok(()));
+    }
+}
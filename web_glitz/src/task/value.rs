@@ -0,0 +1,50 @@
+use crate::task::{ContextId, GpuTask, Progress};
+
+/// Creates a task that immediately finishes with the given `value` as its output, without
+/// performing any work on the GPU.
+///
+/// This can be useful for injecting CPU-computed values into the output of a combined task, or
+/// for making several branches of a conditional produce tasks with the same output type.
+///
+/// # Example
+///
+/// ```rust
+/// # use web_glitz::runtime::RenderingContext;
+/// # use web_glitz::buffer::{Buffer, UsageHint};
+/// # fn wrapper<Rc>(context: &Rc, buffer: Buffer<[f32]>) where Rc: RenderingContext {
+/// use web_glitz::task::{sequence, value};
+///
+/// let task = sequence(buffer.upload_command([1.0, 2.0, 3.0]), value(3));
+///
+/// let future = context.submit(task);
+/// # }
+/// ```
+///
+/// Here `future` will resolve to `((), 3)` once the upload command has finished executing.
+pub fn value<T>(value: T) -> ValueTask<T> {
+    ValueTask { value: Some(value) }
+}
+
+/// A task that immediately finishes with a pre-determined value as its output.
+///
+/// See [value] for details.
+#[derive(Clone)]
+pub struct ValueTask<T> {
+    value: Option<T>,
+}
+
+unsafe impl<Ec, T> GpuTask<Ec> for ValueTask<T> {
+    type Output = T;
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Any
+    }
+
+    fn progress(&mut self, _execution_context: &mut Ec) -> Progress<Self::Output> {
+        Progress::Finished(
+            self.value
+                .take()
+                .expect("Cannot progress ValueTask after it has finished."),
+        )
+    }
+}
@@ -1,3 +1,5 @@
+use std::cell::RefCell;
+use std::fmt;
 use std::mem;
 
 use wasm_bindgen::convert::{FromWasmAbi, IntoWasmAbi};
@@ -50,3 +52,35 @@ where
 {
     a.map(|t| t.as_ref()) == b.map(|t| t.as_ref())
 }
+
+/// A label that may be assigned to a GPU object to help identify the object in `Debug` output and
+/// in error messages.
+#[derive(Default)]
+pub(crate) struct DebugLabel {
+    label: RefCell<Option<String>>,
+}
+
+impl DebugLabel {
+    pub(crate) fn set(&self, label: &str) {
+        *self.label.borrow_mut() = Some(label.to_string());
+    }
+
+    pub(crate) fn get(&self) -> Option<String> {
+        self.label.borrow().clone()
+    }
+
+    /// Describes an object of the given `kind` for use in error messages, e.g. "Buffer `vertices`"
+    /// if the label is set, or just "Buffer" if it is not.
+    pub(crate) fn describe(&self, kind: &str) -> String {
+        match &*self.label.borrow() {
+            Some(label) => format!("{} `{}`", kind, label),
+            None => kind.to_string(),
+        }
+    }
+}
+
+impl fmt::Debug for DebugLabel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.label.borrow().fmt(f)
+    }
+}
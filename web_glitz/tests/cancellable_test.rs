@@ -0,0 +1,42 @@
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+use web_glitz::runtime::{Connection, RenderingContext};
+use web_glitz::task::{cancellable, ContextId, GpuTask, Progress};
+
+use crate::common::create_context;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+// A task that waits on a fence indefinitely.
+struct NeverFinishes;
+
+unsafe impl GpuTask<Connection> for NeverFinishes {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Any
+    }
+
+    fn progress(&mut self, _connection: &mut Connection) -> Progress<Self::Output> {
+        Progress::ContinueFenced
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_cancel_fenced_task() {
+    let context = create_context();
+    let (task, cancel_handle) = cancellable(NeverFinishes);
+
+    let mut handle = context.submit_poll(task);
+
+    assert_eq!(handle.poll(), None);
+
+    cancel_handle.cancel();
+
+    // Polling checks the fenced task queue, which removes the cancelled task right away.
+    assert_eq!(handle.poll(), Some(None));
+}
@@ -0,0 +1,99 @@
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::WebGl2RenderingContext as Gl;
+
+use web_glitz::image::format::RGBA8;
+use web_glitz::image::renderbuffer::RenderbufferDescriptor;
+use web_glitz::image::texture_2d::Texture2DDescriptor;
+use web_glitz::image::MipmapLevels;
+use web_glitz::runtime::state::ContextUpdate;
+use web_glitz::runtime::{Connection, RenderingContext};
+use web_glitz::task::{ContextId, GpuTask, Progress};
+
+use crate::common::{assert_image_matches, create_context, read_image, solid_image};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+// Enables rasterizer discard, as a transform feedback task that does not rasterize would.
+struct EnableRasterizerDiscard;
+
+unsafe impl GpuTask<Connection> for EnableRasterizerDiscard {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Any
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        let (gl, state) = unsafe { connection.unpack_mut() };
+
+        state
+            .set_rasterizer_discard_enabled(true)
+            .apply(gl)
+            .unwrap();
+
+        Progress::Finished(())
+    }
+}
+
+// Outputs the current GL error.
+struct GetError;
+
+unsafe impl GpuTask<Connection> for GetError {
+    type Output = u32;
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Any
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        let (gl, _) = unsafe { connection.unpack() };
+
+        Progress::Finished(gl.get_error())
+    }
+}
+
+#[wasm_bindgen_test]
+async fn test_clear_with_rasterizer_discard_enabled() {
+    let context = create_context();
+
+    let renderbuffer = context.create_renderbuffer(&RenderbufferDescriptor {
+        format: RGBA8,
+        width: 4,
+        height: 4,
+    });
+
+    context.submit(EnableRasterizerDiscard);
+    context.submit(renderbuffer.clear_command([0.0, 0.0, 1.0, 1.0]));
+
+    let image = read_image(&context, &renderbuffer).await;
+
+    assert_image_matches(&image, &solid_image(4, 4, [0, 0, 255, 255]), 0);
+    assert_eq!(context.debug_verify_state(), Vec::new());
+}
+
+#[wasm_bindgen_test]
+async fn test_clear_empty_texture_level() {
+    let context = create_context();
+
+    let texture = context
+        .try_create_texture_2d(&Texture2DDescriptor {
+            format: RGBA8,
+            width: 0,
+            height: 0,
+            levels: MipmapLevels::Partial(1),
+        })
+        .unwrap();
+
+    // No storage is allocated for an empty texture.
+    assert_eq!(context.submit(GetError).await, Gl::NO_ERROR);
+
+    context.submit(texture.base_level().clear_command([0.0, 0.0, 1.0, 1.0]));
+
+    // The texture has no storage; clearing it must not attach it to the clear framebuffer, which
+    // would leave the framebuffer incomplete.
+    assert_eq!(context.submit(GetError).await, Gl::NO_ERROR);
+}
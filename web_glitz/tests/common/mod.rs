@@ -0,0 +1,180 @@
+//! Helpers for pixel-comparison tests that render into an offscreen render target.
+//!
+//! These tests need a WebGL 2.0 implementation and therefore only run in a browser, e.g. with
+//! `wasm-pack test --headless --firefox` (or `--chrome`).
+//!
+//! A test renders into an RGBA8 renderbuffer of a fixed size with [render_to_image], which returns
+//! the resulting pixel data, and compares that data against a reference image with
+//! [assert_image_matches]. A reference image may be stored alongside the tests as raw RGBA8 data in
+//! the same row order as the output of [render_to_image] (bottom row first) and be loaded with
+//! `include_bytes!`, or it may be generated by the test for simple images.
+
+#![allow(dead_code)]
+
+use wasm_bindgen::JsCast;
+use web_sys::{window, HtmlCanvasElement};
+
+use web_glitz::buffer::{Buffer, BufferView, UsageHint};
+use web_glitz::image::format::RGBA8;
+use web_glitz::image::renderbuffer::{Renderbuffer, RenderbufferDescriptor};
+use web_glitz::rendering::{
+    FloatAttachment, LoadOp, RenderPass, RenderPassContext, RenderTarget, RenderTargetDescriptor,
+    StoreOp,
+};
+use web_glitz::runtime::single_threaded::{self, SingleThreadedContext};
+use web_glitz::runtime::{ContextOptions, RenderingContext};
+use web_glitz::task::{sequence3_right, sequence_right, GpuTask};
+
+/// Creates a new [SingleThreadedContext] for a canvas that is not attached to the document.
+pub fn create_context() -> SingleThreadedContext {
+    let canvas: HtmlCanvasElement = window()
+        .unwrap()
+        .document()
+        .unwrap()
+        .create_element("canvas")
+        .unwrap()
+        .dyn_into()
+        .unwrap();
+
+    let (context, _) =
+        unsafe { single_threaded::init(&canvas, &ContextOptions::default()).unwrap() };
+
+    context
+}
+
+/// The render target passed to the closure of [render_to_image].
+pub type OffscreenRenderTarget<'a> =
+    RenderTarget<(FloatAttachment<&'a mut Renderbuffer<RGBA8>>,), ()>;
+
+/// Renders into an offscreen RGBA8 render target of the given `width` and `height` and returns the
+/// resulting pixel data.
+///
+/// The `f` closure receives the rendering context and the render target, and returns the render
+/// pass that is to be rendered. The render target is cleared to transparent black when the render
+/// pass begins.
+///
+/// The pixel data is returned as 4 bytes per pixel (red, green, blue, alpha), row by row, starting
+/// with the bottom row.
+pub async fn render_to_image<F, T>(width: u32, height: u32, f: F) -> Vec<u8>
+where
+    F: FnOnce(&SingleThreadedContext, &mut OffscreenRenderTarget) -> RenderPass<T>,
+    T: GpuTask<RenderPassContext> + 'static,
+{
+    let context = create_context();
+
+    let mut renderbuffer = context.create_renderbuffer(&RenderbufferDescriptor {
+        format: RGBA8,
+        width,
+        height,
+    });
+
+    let render_pass = {
+        let mut render_target =
+            context.create_render_target(RenderTargetDescriptor::new().attach_color_float(
+                &mut renderbuffer,
+                LoadOp::Clear([0.0, 0.0, 0.0, 0.0]),
+                StoreOp::Store,
+            ));
+
+        f(&context, &mut render_target)
+    };
+
+    let buffer: Buffer<[[u8; 4]]> = context.create_buffer(
+        vec![[0; 4]; width as usize * height as usize],
+        UsageHint::StreamRead,
+    );
+
+    let pack_command = renderbuffer.pack_to_buffer_command(BufferView::from(&buffer));
+    let download_command = buffer.download_command();
+
+    let pixels = context
+        .submit(sequence3_right(render_pass, pack_command, download_command))
+        .await;
+
+    pixels
+        .iter()
+        .flat_map(|pixel| pixel.iter().copied())
+        .collect()
+}
+
+/// Returns the pixel data stored in the `renderbuffer`, in the same format as the pixel data
+/// returned by [render_to_image].
+///
+/// Any tasks that render to the `renderbuffer` must be submitted to the `context` first.
+pub async fn read_image(
+    context: &SingleThreadedContext,
+    renderbuffer: &Renderbuffer<RGBA8>,
+) -> Vec<u8> {
+    let buffer: Buffer<[[u8; 4]]> = context.create_buffer(
+        vec![[0; 4]; renderbuffer.width() as usize * renderbuffer.height() as usize],
+        UsageHint::StreamRead,
+    );
+
+    let pack_command = renderbuffer.pack_to_buffer_command(BufferView::from(&buffer));
+    let download_command = buffer.download_command();
+
+    let pixels = context
+        .submit(sequence_right(pack_command, download_command))
+        .await;
+
+    pixels
+        .iter()
+        .flat_map(|pixel| pixel.iter().copied())
+        .collect()
+}
+
+/// Asserts that the `actual` pixel data matches the `reference` pixel data.
+///
+/// Both are expected to store 4 bytes per pixel, as returned by [render_to_image]. Pixel data
+/// matches if every byte in `actual` differs by no more than `tolerance` from the corresponding
+/// byte in `reference`; a small tolerance allows for differences in rounding between WebGL
+/// implementations.
+///
+/// # Panics
+///
+/// Panics if `actual` and `reference` do not have the same length, or if any of the bytes differ
+/// by more than `tolerance`. The panic message reports the number of mismatched pixels and the
+/// first mismatched pixel.
+pub fn assert_image_matches(actual: &[u8], reference: &[u8], tolerance: u8) {
+    assert_eq!(
+        actual.len(),
+        reference.len(),
+        "The image is {} bytes, but the reference image is {} bytes.",
+        actual.len(),
+        reference.len()
+    );
+
+    let mismatches: Vec<(usize, &[u8], &[u8])> = actual
+        .chunks(4)
+        .zip(reference.chunks(4))
+        .enumerate()
+        .filter(|(_, (a, r))| {
+            a.iter()
+                .zip(r.iter())
+                .any(|(a, r)| (*a as i16 - *r as i16).abs() > tolerance as i16)
+        })
+        .map(|(i, (a, r))| (i, a, r))
+        .collect();
+
+    if let Some((index, actual_pixel, reference_pixel)) = mismatches.first() {
+        panic!(
+            "{} pixel(s) do not match the reference image (tolerance {}); the first mismatched \
+             pixel is pixel {}, which is {:?}, but the reference is {:?}.",
+            mismatches.len(),
+            tolerance,
+            index,
+            actual_pixel,
+            reference_pixel
+        );
+    }
+}
+
+/// Returns pixel data for an image of `width` by `height` pixels that all have the same `color`.
+pub fn solid_image(width: u32, height: u32, color: [u8; 4]) -> Vec<u8> {
+    color
+        .iter()
+        .copied()
+        .cycle()
+        .take(width as usize * height as usize * 4)
+        .collect()
+}
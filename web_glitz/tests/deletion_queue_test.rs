@@ -0,0 +1,82 @@
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use std::mem;
+
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+use web_glitz::buffer::UsageHint;
+use web_glitz::image::format::RGBA8;
+use web_glitz::image::texture_2d::Texture2DDescriptor;
+use web_glitz::image::MipmapLevels;
+use web_glitz::pipeline::graphics::query_primitives_written;
+use web_glitz::runtime::RenderingContext;
+use web_glitz::task::{cancellable, Empty};
+
+use crate::common::create_context;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn test_drop_resources() {
+    let context = create_context();
+
+    let buffer = context.create_buffer([1.0f32, 2.0, 3.0], UsageHint::StaticDraw);
+    let texture = context
+        .try_create_texture_2d(&Texture2DDescriptor {
+            format: RGBA8,
+            width: 4,
+            height: 4,
+            levels: MipmapLevels::Partial(1),
+        })
+        .unwrap();
+
+    assert_eq!(context.debug_pending_deletion_count(), 0);
+
+    mem::drop(buffer);
+    mem::drop(texture);
+
+    assert_eq!(context.debug_pending_deletion_count(), 2);
+
+    // Flushing the context submits the pending deletions.
+    context.flush();
+
+    assert_eq!(context.debug_pending_deletion_count(), 0);
+}
+
+#[wasm_bindgen_test]
+fn test_drop_resource_after_context() {
+    let context = create_context();
+    let buffer = context.create_buffer([1.0f32, 2.0, 3.0], UsageHint::StaticDraw);
+
+    mem::drop(context);
+
+    // The buffer's GPU object was released together with the context; dropping the buffer must
+    // not schedule a deletion.
+    mem::drop(buffer);
+}
+
+#[wasm_bindgen_test]
+fn test_drop_pending_query() {
+    let context = create_context();
+    let (task, cancel_handle) = cancellable(query_primitives_written(Empty, |_, _| Empty));
+
+    let mut handle = context.submit_poll(task);
+
+    // Query results only become available after control has returned to the event loop, so the
+    // task is still waiting on its query.
+    assert_eq!(handle.poll(), None);
+    assert_eq!(context.debug_pending_deletion_count(), 0);
+
+    cancel_handle.cancel();
+
+    assert_eq!(handle.poll(), Some(None));
+
+    // Dropping the cancelled task scheduled the deletion of its query object.
+    assert_eq!(context.debug_pending_deletion_count(), 1);
+
+    context.flush();
+
+    assert_eq!(context.debug_pending_deletion_count(), 0);
+}
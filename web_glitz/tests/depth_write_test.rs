@@ -0,0 +1,211 @@
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+use web_glitz::image::format::{DepthComponent24, RGBA8};
+use web_glitz::image::renderbuffer::{Renderbuffer, RenderbufferDescriptor};
+use web_glitz::pipeline::graphics::{
+    CullingMode, DepthTest, GraphicsPipeline, GraphicsPipelineDescriptor, PrimitiveAssembly,
+    WindingOrder,
+};
+use web_glitz::pipeline::resources::BindGroup;
+use web_glitz::rendering::{
+    LoadOp, RenderPass, RenderPassContext, RenderTargetDescriptor, StoreOp,
+};
+use web_glitz::runtime::single_threaded::SingleThreadedContext;
+use web_glitz::runtime::RenderingContext;
+use web_glitz::task::{sequence_iter, GpuTask};
+
+use crate::common::{assert_image_matches, create_context, read_image, solid_image};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const WIDTH: u32 = 16;
+const HEIGHT: u32 = 16;
+
+// Covers the entire render target with a single triangle.
+const FULLSCREEN_VERTEX_SHADER: &str = "#version 300 es
+void main() {
+    vec2 uv = vec2((gl_VertexID << 1) & 2, gl_VertexID & 2);
+    gl_Position = vec4(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+";
+
+const RED_NEAR_FRAGMENT_SHADER: &str = "#version 300 es
+precision mediump float;
+out vec4 color;
+void main() {
+    color = vec4(1.0, 0.0, 0.0, 1.0);
+    gl_FragDepth = 0.5;
+}
+";
+
+const GREEN_FAR_FRAGMENT_SHADER: &str = "#version 300 es
+precision mediump float;
+out vec4 color;
+void main() {
+    color = vec4(0.0, 1.0, 0.0, 1.0);
+    gl_FragDepth = 0.75;
+}
+";
+
+struct Images {
+    color: Renderbuffer<RGBA8>,
+    depth: Renderbuffer<DepthComponent24>,
+}
+
+fn create_images(context: &SingleThreadedContext) -> Images {
+    Images {
+        color: context.create_renderbuffer(&RenderbufferDescriptor {
+            format: RGBA8,
+            width: WIDTH,
+            height: HEIGHT,
+        }),
+        depth: context.create_renderbuffer(&RenderbufferDescriptor {
+            format: DepthComponent24,
+            width: WIDTH,
+            height: HEIGHT,
+        }),
+    }
+}
+
+fn depth_test_pipeline(
+    context: &SingleThreadedContext,
+    fragment_shader_source: &str,
+    write: bool,
+) -> GraphicsPipeline<(), ((), ()), ()> {
+    let vertex_shader = context
+        .try_create_vertex_shader(FULLSCREEN_VERTEX_SHADER)
+        .unwrap();
+    let fragment_shader = context
+        .try_create_fragment_shader(fragment_shader_source)
+        .unwrap();
+
+    context
+        .try_create_graphics_pipeline(
+            &GraphicsPipelineDescriptor::begin()
+                .vertex_shader(&vertex_shader)
+                .primitive_assembly(PrimitiveAssembly::Triangles {
+                    winding_order: WindingOrder::CounterClockwise,
+                    face_culling: CullingMode::None,
+                })
+                .fragment_shader(&fragment_shader)
+                .typed_resource_bindings_layout::<((), ())>()
+                .enable_depth_test(DepthTest {
+                    write,
+                    ..Default::default()
+                })
+                .finish(),
+        )
+        .unwrap()
+}
+
+// Renders a pass that loads or clears the color and depth images and then draws with each of the
+// `pipelines` in order.
+fn render_pass(
+    context: &SingleThreadedContext,
+    images: &mut Images,
+    color_load_op: LoadOp<[f32; 4]>,
+    depth_load_op: LoadOp<f32>,
+    pipelines: &[&GraphicsPipeline<(), ((), ()), ()>],
+) -> RenderPass<impl GpuTask<RenderPassContext>> {
+    let mut render_target = context.create_render_target(
+        RenderTargetDescriptor::new()
+            .attach_color_float(&mut images.color, color_load_op, StoreOp::Store)
+            .attach_depth(&mut images.depth, depth_load_op, StoreOp::Store),
+    );
+
+    render_target.create_render_pass(|framebuffer| {
+        sequence_iter(pipelines.iter().map(|pipeline| {
+            framebuffer.pipeline_task(pipeline, |active_pipeline| {
+                active_pipeline
+                    .task_builder()
+                    .bind_resources((&BindGroup::empty(), &BindGroup::empty()))
+                    .draw_without_vertices(3)
+                    .finish()
+            })
+        }))
+    })
+}
+
+#[wasm_bindgen_test]
+async fn test_depth_test_without_write() {
+    let context = create_context();
+    let mut images = create_images(&context);
+
+    let red = depth_test_pipeline(&context, RED_NEAR_FRAGMENT_SHADER, false);
+    let green = depth_test_pipeline(&context, GREEN_FAR_FRAGMENT_SHADER, true);
+
+    context.submit(render_pass(
+        &context,
+        &mut images,
+        LoadOp::Clear([0.0, 0.0, 0.0, 0.0]),
+        LoadOp::Clear(1.0),
+        &[&red],
+    ));
+
+    // The red pipeline passes the depth test, but must leave the depth buffer untouched.
+    assert!(!context.debug_capture_state().depth_mask);
+
+    context.submit(render_pass(
+        &context,
+        &mut images,
+        LoadOp::Load,
+        LoadOp::Load,
+        &[&green],
+    ));
+
+    // Had the red pipeline written its depth (`0.5`), the green fragments (`0.75`) would fail the
+    // depth test.
+    let image = read_image(&context, &images.color).await;
+
+    assert_image_matches(&image, &solid_image(WIDTH, HEIGHT, [0, 255, 0, 255]), 0);
+    assert_eq!(context.debug_verify_state(), Vec::new());
+}
+
+#[wasm_bindgen_test]
+async fn test_depth_clear_restores_depth_mask() {
+    let context = create_context();
+    let mut images = create_images(&context);
+
+    let red = depth_test_pipeline(&context, RED_NEAR_FRAGMENT_SHADER, false);
+    let green = depth_test_pipeline(&context, GREEN_FAR_FRAGMENT_SHADER, true);
+
+    context.submit(render_pass(
+        &context,
+        &mut images,
+        LoadOp::Clear([0.0, 0.0, 0.0, 0.0]),
+        LoadOp::Clear(1.0),
+        &[&red],
+    ));
+
+    assert!(!context.debug_capture_state().depth_mask);
+
+    context.submit(render_pass(
+        &context,
+        &mut images,
+        LoadOp::Load,
+        LoadOp::Clear(0.6),
+        &[],
+    ));
+
+    // Clearing the depth buffer re-enables depth writes.
+    assert!(context.debug_capture_state().depth_mask);
+
+    context.submit(render_pass(
+        &context,
+        &mut images,
+        LoadOp::Load,
+        LoadOp::Load,
+        &[&green],
+    ));
+
+    // Had the clear been masked out, the depth buffer would still be `1.0` and the green fragments
+    // (`0.75`) would pass the depth test.
+    let image = read_image(&context, &images.color).await;
+
+    assert_image_matches(&image, &solid_image(WIDTH, HEIGHT, [255, 0, 0, 255]), 0);
+    assert_eq!(context.debug_verify_state(), Vec::new());
+}
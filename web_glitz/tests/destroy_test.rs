@@ -0,0 +1,73 @@
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+use web_glitz::runtime::single_threaded::{ContextBusy, SingleThreadedContext};
+use web_glitz::runtime::{Connection, RenderingContext};
+use web_glitz::task::{ContextId, GpuTask, Progress};
+
+use crate::common::create_context;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+// Attempts to destroy the context while it is executing this task.
+struct DestroyCommand {
+    context: SingleThreadedContext,
+}
+
+unsafe impl GpuTask<Connection> for DestroyCommand {
+    type Output = Result<(), ContextBusy>;
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Any
+    }
+
+    fn progress(&mut self, _connection: &mut Connection) -> Progress<Self::Output> {
+        Progress::Finished(self.context.destroy())
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_destroy_cancels_fenced_task() {
+    let context = create_context();
+
+    // A finish task waits on a fence, so it does not finish immediately.
+    let mut execution = context.finish();
+
+    assert!(!execution.is_cancelled());
+
+    context.destroy().unwrap();
+
+    assert!(execution.is_cancelled());
+}
+
+#[wasm_bindgen_test]
+fn test_submit_after_destroy() {
+    let context = create_context();
+
+    context.destroy().unwrap();
+
+    assert!(context.finish().is_cancelled());
+    assert!(context.clone().finish().is_cancelled());
+
+    // Destroying the context again has no effect.
+    assert_eq!(context.destroy(), Ok(()));
+}
+
+#[wasm_bindgen_test]
+fn test_destroy_while_executing() {
+    let context = create_context();
+
+    let output = context
+        .submit(DestroyCommand {
+            context: context.clone(),
+        })
+        .assume_ready();
+
+    assert_eq!(output, Err(ContextBusy));
+
+    // The context was not destroyed.
+    assert!(!context.finish().is_cancelled());
+}
@@ -0,0 +1,256 @@
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use std::rc::Rc;
+
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+use web_glitz::image::Region2D;
+use web_glitz::pipeline::graphics::attribute_format::VertexAttributeFormat;
+use web_glitz::pipeline::graphics::{
+    CullingMode, FragmentShader, GraphicsPipelineDescriptor, InputRate, PrimitiveAssembly, Untyped,
+    VertexAttributeDescriptor, VertexInputLayoutDescriptor, VertexInputLayoutDescriptorBuilder,
+    VertexShader, WindingOrder,
+};
+use web_glitz::pipeline::resources::{
+    ResourceBindingsLayoutBuilder, ResourceBindingsLayoutDescriptor, ResourceSlotDescriptor,
+    ResourceSlotIdentifier, ResourceSlotKind,
+};
+use web_glitz::runtime::single_threaded::SingleThreadedContext;
+use web_glitz::runtime::RenderingContext;
+
+use crate::common::create_context;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const VERTEX_SHADER: &str = "#version 300 es
+layout(location=0) in vec4 position;
+void main() {
+    gl_Position = position;
+}
+";
+
+const FRAGMENT_SHADER: &str = "#version 300 es
+precision mediump float;
+out vec4 color;
+void main() {
+    color = vec4(1.0, 0.0, 0.0, 1.0);
+}
+";
+
+struct Shaders {
+    vertex_shader: VertexShader,
+    fragment_shader: FragmentShader,
+}
+
+fn create_shaders(context: &SingleThreadedContext) -> Shaders {
+    Shaders {
+        vertex_shader: context.try_create_vertex_shader(VERTEX_SHADER).unwrap(),
+        fragment_shader: context.try_create_fragment_shader(FRAGMENT_SHADER).unwrap(),
+    }
+}
+
+fn vertex_input_layout(stride: u8) -> VertexInputLayoutDescriptor {
+    let mut builder = VertexInputLayoutDescriptorBuilder::new(None);
+
+    builder
+        .add_buffer_slot(stride, InputRate::PerVertex)
+        .add_attribute(VertexAttributeDescriptor {
+            location: 0,
+            name: None,
+            offset_in_bytes: 0,
+            format: VertexAttributeFormat::Float4_f32,
+        });
+
+    builder.finish()
+}
+
+fn empty_resource_bindings_layout() -> ResourceBindingsLayoutDescriptor {
+    ResourceBindingsLayoutBuilder::new(None)
+        .add_empty_bind_group(0)
+        .unwrap()
+        .add_empty_bind_group(1)
+        .unwrap()
+        .finish()
+}
+
+// Declares a uniform buffer slot that is not used by the shaders.
+fn unused_slot_resource_bindings_layout() -> ResourceBindingsLayoutDescriptor {
+    ResourceBindingsLayoutBuilder::new(None)
+        .add_bind_group(0)
+        .unwrap()
+        .add_resource_slot(ResourceSlotDescriptor {
+            slot_index: 0,
+            slot_identifier: ResourceSlotIdentifier::Static("Unused"),
+            slot_kind: ResourceSlotKind::UniformBuffer,
+        })
+        .unwrap()
+        .finish()
+        .add_empty_bind_group(1)
+        .unwrap()
+        .finish()
+}
+
+fn descriptor(
+    shaders: &Shaders,
+    vertex_input_layout: VertexInputLayoutDescriptor,
+    resource_bindings_layout: ResourceBindingsLayoutDescriptor,
+    face_culling: CullingMode,
+    scissor_region: Region2D,
+) -> GraphicsPipelineDescriptor<Untyped, Untyped, ()> {
+    GraphicsPipelineDescriptor::begin()
+        .vertex_shader(&shaders.vertex_shader)
+        .primitive_assembly(PrimitiveAssembly::Triangles {
+            winding_order: WindingOrder::CounterClockwise,
+            face_culling,
+        })
+        .fragment_shader(&shaders.fragment_shader)
+        .untyped_vertex_attribute_layout(vertex_input_layout)
+        .untyped_resource_bindings_layout(resource_bindings_layout)
+        .scissor_region(scissor_region)
+        .finish()
+}
+
+fn default_descriptor(shaders: &Shaders) -> GraphicsPipelineDescriptor<Untyped, Untyped, ()> {
+    descriptor(
+        shaders,
+        vertex_input_layout(16),
+        empty_resource_bindings_layout(),
+        CullingMode::None,
+        Region2D::Fill,
+    )
+}
+
+#[wasm_bindgen_test]
+fn test_pipeline_cache_hit() {
+    let context = create_context();
+    let shaders = create_shaders(&context);
+
+    let pipeline = context
+        .try_get_or_create_graphics_pipeline(&default_descriptor(&shaders))
+        .unwrap();
+    let cached_pipeline = context
+        .try_get_or_create_graphics_pipeline(&default_descriptor(&shaders))
+        .unwrap();
+
+    assert!(Rc::ptr_eq(&pipeline, &cached_pipeline));
+}
+
+#[wasm_bindgen_test]
+fn test_pipeline_cache_miss_fixed_function_state() {
+    let context = create_context();
+    let shaders = create_shaders(&context);
+
+    let pipeline = context
+        .try_get_or_create_graphics_pipeline(&default_descriptor(&shaders))
+        .unwrap();
+    let other_pipeline = context
+        .try_get_or_create_graphics_pipeline(&descriptor(
+            &shaders,
+            vertex_input_layout(16),
+            empty_resource_bindings_layout(),
+            CullingMode::Back,
+            Region2D::Fill,
+        ))
+        .unwrap();
+
+    assert!(!Rc::ptr_eq(&pipeline, &other_pipeline));
+}
+
+#[wasm_bindgen_test]
+fn test_pipeline_cache_miss_shaders() {
+    let context = create_context();
+    let shaders = create_shaders(&context);
+    let other_shaders = create_shaders(&context);
+
+    let pipeline = context
+        .try_get_or_create_graphics_pipeline(&default_descriptor(&shaders))
+        .unwrap();
+    let other_pipeline = context
+        .try_get_or_create_graphics_pipeline(&default_descriptor(&other_shaders))
+        .unwrap();
+
+    assert!(!Rc::ptr_eq(&pipeline, &other_pipeline));
+}
+
+#[wasm_bindgen_test]
+fn test_pipeline_cache_miss_vertex_input_layout() {
+    let context = create_context();
+    let shaders = create_shaders(&context);
+
+    let pipeline = context
+        .try_get_or_create_graphics_pipeline(&default_descriptor(&shaders))
+        .unwrap();
+    let other_pipeline = context
+        .try_get_or_create_graphics_pipeline(&descriptor(
+            &shaders,
+            vertex_input_layout(32),
+            empty_resource_bindings_layout(),
+            CullingMode::None,
+            Region2D::Fill,
+        ))
+        .unwrap();
+
+    assert!(!Rc::ptr_eq(&pipeline, &other_pipeline));
+}
+
+#[wasm_bindgen_test]
+fn test_pipeline_cache_miss_resource_bindings_layout() {
+    let context = create_context();
+    let shaders = create_shaders(&context);
+
+    let pipeline = context
+        .try_get_or_create_graphics_pipeline(&default_descriptor(&shaders))
+        .unwrap();
+    let other_pipeline = context
+        .try_get_or_create_graphics_pipeline(&descriptor(
+            &shaders,
+            vertex_input_layout(16),
+            unused_slot_resource_bindings_layout(),
+            CullingMode::None,
+            Region2D::Fill,
+        ))
+        .unwrap();
+
+    assert!(!Rc::ptr_eq(&pipeline, &other_pipeline));
+}
+
+#[wasm_bindgen_test]
+fn test_pipeline_cache_miss_scissor_region() {
+    let context = create_context();
+    let shaders = create_shaders(&context);
+
+    let pipeline = context
+        .try_get_or_create_graphics_pipeline(&default_descriptor(&shaders))
+        .unwrap();
+    let other_pipeline = context
+        .try_get_or_create_graphics_pipeline(&descriptor(
+            &shaders,
+            vertex_input_layout(16),
+            empty_resource_bindings_layout(),
+            CullingMode::None,
+            Region2D::Area((0, 0), 8, 8),
+        ))
+        .unwrap();
+
+    assert!(!Rc::ptr_eq(&pipeline, &other_pipeline));
+}
+
+#[wasm_bindgen_test]
+fn test_pipeline_cache_clear() {
+    let context = create_context();
+    let shaders = create_shaders(&context);
+
+    let pipeline = context
+        .try_get_or_create_graphics_pipeline(&default_descriptor(&shaders))
+        .unwrap();
+
+    context.clear_graphics_pipeline_cache();
+
+    let new_pipeline = context
+        .try_get_or_create_graphics_pipeline(&default_descriptor(&shaders))
+        .unwrap();
+
+    assert!(!Rc::ptr_eq(&pipeline, &new_pipeline));
+}
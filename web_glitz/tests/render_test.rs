@@ -0,0 +1,459 @@
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+use web_glitz::image::format::{RGBA8, RGBA8UI};
+use web_glitz::image::texture_2d::{
+    FloatSampledTexture2D, Texture2DDescriptor, UnsignedIntegerSampledTexture2D,
+};
+use web_glitz::image::{Image2DSource, MipmapLevels};
+use web_glitz::pipeline::graphics::{
+    BlendFactor, Blending, CullingMode, GraphicsPipeline, GraphicsPipelineDescriptor,
+    PrimitiveAssembly, WindingOrder,
+};
+use web_glitz::pipeline::resources::{BindGroup, TypedResourceBindingsLayout};
+use web_glitz::runtime::single_threaded::SingleThreadedContext;
+use web_glitz::runtime::RenderingContext;
+use web_glitz::task::sequence;
+
+use crate::common::{assert_image_matches, render_to_image, solid_image};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const WIDTH: u32 = 16;
+const HEIGHT: u32 = 16;
+
+// Covers the entire render target with a single triangle.
+const FULLSCREEN_VERTEX_SHADER: &str = "#version 300 es
+void main() {
+    vec2 uv = vec2((gl_VertexID << 1) & 2, gl_VertexID & 2);
+    gl_Position = vec4(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+";
+
+const SOLID_COLOR_FRAGMENT_SHADER: &str = "#version 300 es
+precision mediump float;
+out vec4 color;
+void main() {
+    color = vec4(1.0, 0.0, 0.0, 1.0);
+}
+";
+
+const HALF_TRANSPARENT_FRAGMENT_SHADER: &str = "#version 300 es
+precision mediump float;
+out vec4 color;
+void main() {
+    color = vec4(1.0, 1.0, 1.0, 0.5);
+}
+";
+
+// Reads the base level of the level range of `level_texture`. The left and right variants only
+// cover the left and right halves of the render target respectively.
+const SAMPLE_BASE_LEVEL_FRAGMENT_SHADER: &str = "#version 300 es
+precision mediump float;
+uniform sampler2D level_texture;
+out vec4 color;
+void main() {
+    color = texelFetch(level_texture, ivec2(0, 0), 0);
+}
+";
+
+const SAMPLE_BASE_LEVEL_LEFT_FRAGMENT_SHADER: &str = "#version 300 es
+precision mediump float;
+uniform sampler2D level_texture;
+out vec4 color;
+void main() {
+    if (gl_FragCoord.x > 8.0) {
+        discard;
+    }
+
+    color = texelFetch(level_texture, ivec2(0, 0), 0);
+}
+";
+
+const SAMPLE_BASE_LEVEL_RIGHT_FRAGMENT_SHADER: &str = "#version 300 es
+precision mediump float;
+uniform sampler2D level_texture;
+out vec4 color;
+void main() {
+    if (gl_FragCoord.x < 8.0) {
+        discard;
+    }
+
+    color = texelFetch(level_texture, ivec2(0, 0), 0);
+}
+";
+
+// Reads the base level of an unsigned integer texture, scaled to the `0..255` range.
+const FETCH_UNSIGNED_INTEGER_FRAGMENT_SHADER: &str = "#version 300 es
+precision mediump float;
+precision mediump usampler2D;
+uniform usampler2D level_texture;
+out vec4 color;
+void main() {
+    color = vec4(texelFetch(level_texture, ivec2(0, 0), 0)) / 255.0;
+}
+";
+
+#[derive(web_glitz::derive::Resources)]
+struct LevelResources<'a> {
+    #[resource(binding = 0)]
+    level_texture: FloatSampledTexture2D<'a>,
+}
+
+#[derive(web_glitz::derive::Resources)]
+struct OptionalLevelResources<'a> {
+    #[resource(binding = 0)]
+    level_texture: Option<FloatSampledTexture2D<'a>>,
+}
+
+#[derive(web_glitz::derive::Resources)]
+struct UnsignedIntegerLevelResources<'a> {
+    #[resource(binding = 0)]
+    level_texture: UnsignedIntegerSampledTexture2D<'a>,
+}
+
+fn fullscreen_pipeline<R>(
+    context: &SingleThreadedContext,
+    fragment_shader_source: &str,
+    blending: Option<Blending>,
+) -> GraphicsPipeline<(), R, ()>
+where
+    R: TypedResourceBindingsLayout,
+{
+    let vertex_shader = context
+        .try_create_vertex_shader(FULLSCREEN_VERTEX_SHADER)
+        .unwrap();
+    let fragment_shader = context
+        .try_create_fragment_shader(fragment_shader_source)
+        .unwrap();
+
+    let builder = GraphicsPipelineDescriptor::begin()
+        .vertex_shader(&vertex_shader)
+        .primitive_assembly(PrimitiveAssembly::Triangles {
+            winding_order: WindingOrder::CounterClockwise,
+            face_culling: CullingMode::None,
+        })
+        .fragment_shader(&fragment_shader)
+        .typed_resource_bindings_layout::<R>();
+
+    let builder = match blending {
+        Some(blending) => builder.enable_blending(blending),
+        None => builder,
+    };
+
+    context
+        .try_create_graphics_pipeline(&builder.finish())
+        .unwrap()
+}
+
+#[wasm_bindgen_test]
+async fn test_render_clear() {
+    let image = render_to_image(WIDTH, HEIGHT, |_, render_target| {
+        render_target.create_render_pass(|_| web_glitz::task::Empty)
+    })
+    .await;
+
+    assert_image_matches(&image, &solid_image(WIDTH, HEIGHT, [0, 0, 0, 0]), 0);
+}
+
+#[wasm_bindgen_test]
+async fn test_render_solid_color() {
+    let image = render_to_image(WIDTH, HEIGHT, |context, render_target| {
+        let pipeline = fullscreen_pipeline::<((), ())>(context, SOLID_COLOR_FRAGMENT_SHADER, None);
+
+        render_target.create_render_pass(|framebuffer| {
+            framebuffer.pipeline_task(&pipeline, |active_pipeline| {
+                active_pipeline
+                    .task_builder()
+                    .bind_resources((&BindGroup::empty(), &BindGroup::empty()))
+                    .draw_without_vertices(3)
+                    .finish()
+            })
+        })
+    })
+    .await;
+
+    assert_image_matches(&image, &solid_image(WIDTH, HEIGHT, [255, 0, 0, 255]), 0);
+}
+
+#[wasm_bindgen_test]
+async fn test_render_blending() {
+    let image = render_to_image(WIDTH, HEIGHT, |context, render_target| {
+        let pipeline = fullscreen_pipeline::<((), ())>(
+            context,
+            HALF_TRANSPARENT_FRAGMENT_SHADER,
+            Some(Blending {
+                source_color_factor: BlendFactor::SourceAlpha,
+                destination_color_factor: BlendFactor::OneMinusSourceAlpha,
+                ..Default::default()
+            }),
+        );
+
+        render_target.create_render_pass(|framebuffer| {
+            framebuffer.pipeline_task(&pipeline, |active_pipeline| {
+                active_pipeline
+                    .task_builder()
+                    .bind_resources((&BindGroup::empty(), &BindGroup::empty()))
+                    .draw_without_vertices(3)
+                    .finish()
+            })
+        })
+    })
+    .await;
+
+    // Blended over transparent black: the color is scaled by the source alpha, the alpha uses the
+    // default factors (`One` and `Zero`) and is therefore simply the source alpha.
+    assert_image_matches(&image, &solid_image(WIDTH, HEIGHT, [128, 128, 128, 128]), 1);
+}
+
+#[wasm_bindgen_test]
+async fn test_render_sampled_level_range() {
+    let image = render_to_image(WIDTH, HEIGHT, |context, render_target| {
+        let texture = context
+            .try_create_texture_2d(&Texture2DDescriptor {
+                format: RGBA8,
+                width: 2,
+                height: 2,
+                levels: MipmapLevels::Partial(2),
+            })
+            .unwrap();
+
+        // Level 0 is red, level 1 is green; the texture's own level range only covers level 1.
+        let red = Image2DSource::from_pixels(vec![[255u8, 0, 0, 255]; 4], 2, 2).unwrap();
+        let green = Image2DSource::from_pixels(vec![[0u8, 255, 0, 255]], 1, 1).unwrap();
+
+        context.submit(sequence(
+            texture.base_level().upload_command(red),
+            texture.levels().get(1).unwrap().upload_command(green),
+        ));
+        context.submit(texture.set_level_range_command(1, 1));
+
+        let left_pipeline = fullscreen_pipeline::<((), LevelResources)>(
+            context,
+            SAMPLE_BASE_LEVEL_LEFT_FRAGMENT_SHADER,
+            None,
+        );
+        let right_pipeline = fullscreen_pipeline::<((), LevelResources)>(
+            context,
+            SAMPLE_BASE_LEVEL_RIGHT_FRAGMENT_SHADER,
+            None,
+        );
+
+        // The left half is drawn with a view of the texture that restricts the level range to
+        // level 0. The right half is drawn afterwards with a view without a level range, which
+        // must not be affected by the level range of the left view.
+        let level_0 = context.create_bind_group(LevelResources {
+            level_texture: texture.float_unsampled().with_level_range(0, 0),
+        });
+        let own_level_range = context.create_bind_group(LevelResources {
+            level_texture: texture.float_unsampled(),
+        });
+
+        render_target.create_render_pass(|framebuffer| {
+            sequence(
+                framebuffer.pipeline_task(&left_pipeline, |active_pipeline| {
+                    active_pipeline
+                        .task_builder()
+                        .bind_resources((&BindGroup::empty(), &level_0))
+                        .draw_without_vertices(3)
+                        .finish()
+                }),
+                framebuffer.pipeline_task(&right_pipeline, |active_pipeline| {
+                    active_pipeline
+                        .task_builder()
+                        .bind_resources((&BindGroup::empty(), &own_level_range))
+                        .draw_without_vertices(3)
+                        .finish()
+                }),
+            )
+        })
+    })
+    .await;
+
+    // The left half samples level 0 (red), the right half samples level 1 (green).
+    let mut row = solid_image(WIDTH / 2, 1, [255, 0, 0, 255]);
+
+    row.extend(solid_image(WIDTH / 2, 1, [0, 255, 0, 255]));
+
+    assert_image_matches(&image, &row.repeat(HEIGHT as usize), 0);
+}
+
+#[wasm_bindgen_test]
+async fn test_render_optional_resource_none() {
+    let image = render_to_image(WIDTH, HEIGHT, |context, render_target| {
+        let texture = context
+            .try_create_texture_2d(&Texture2DDescriptor {
+                format: RGBA8,
+                width: 1,
+                height: 1,
+                levels: MipmapLevels::Partial(1),
+            })
+            .unwrap();
+
+        let red = Image2DSource::from_pixels(vec![[255u8, 0, 0, 255]], 1, 1).unwrap();
+
+        context.submit(texture.base_level().upload_command(red));
+
+        let left_pipeline = fullscreen_pipeline::<((), OptionalLevelResources)>(
+            context,
+            SAMPLE_BASE_LEVEL_LEFT_FRAGMENT_SHADER,
+            None,
+        );
+        let right_pipeline = fullscreen_pipeline::<((), OptionalLevelResources)>(
+            context,
+            SAMPLE_BASE_LEVEL_RIGHT_FRAGMENT_SHADER,
+            None,
+        );
+
+        // The left half is drawn with the texture bound to the slot. The right half is drawn
+        // afterwards without a texture, which must unbind the texture bound for the left half.
+        let some = context.create_bind_group(OptionalLevelResources {
+            level_texture: Some(texture.float_unsampled()),
+        });
+        let none = context.create_bind_group(OptionalLevelResources {
+            level_texture: None,
+        });
+
+        render_target.create_render_pass(|framebuffer| {
+            sequence(
+                framebuffer.pipeline_task(&left_pipeline, |active_pipeline| {
+                    active_pipeline
+                        .task_builder()
+                        .bind_resources((&BindGroup::empty(), &some))
+                        .draw_without_vertices(3)
+                        .finish()
+                }),
+                framebuffer.pipeline_task(&right_pipeline, |active_pipeline| {
+                    active_pipeline
+                        .task_builder()
+                        .bind_resources((&BindGroup::empty(), &none))
+                        .draw_without_vertices(3)
+                        .finish()
+                }),
+            )
+        })
+    })
+    .await;
+
+    // The left half samples the texture (red), the right half samples no texture, which returns
+    // opaque black.
+    let mut row = solid_image(WIDTH / 2, 1, [255, 0, 0, 255]);
+
+    row.extend(solid_image(WIDTH / 2, 1, [0, 0, 0, 255]));
+
+    assert_image_matches(&image, &row.repeat(HEIGHT as usize), 0);
+}
+
+#[wasm_bindgen_test]
+async fn test_render_generate_mipmap_custom_restores_level_range() {
+    let pass_count = Rc::new(Cell::new(0));
+    let pass_count_in_f = pass_count.clone();
+
+    let image = render_to_image(WIDTH, HEIGHT, |context, render_target| {
+        let mut texture = context
+            .try_create_texture_2d(&Texture2DDescriptor {
+                format: RGBA8,
+                width: 2,
+                height: 2,
+                levels: MipmapLevels::Partial(2),
+            })
+            .unwrap();
+
+        // Level 0 is red, level 1 is green; the texture's own level range only covers level 1.
+        let red = Image2DSource::from_pixels(vec![[255u8, 0, 0, 255]; 4], 2, 2).unwrap();
+        let green = Image2DSource::from_pixels(vec![[0u8, 255, 0, 255]], 1, 1).unwrap();
+
+        context.submit(sequence(
+            texture.base_level().upload_command(red),
+            texture.levels().get(1).unwrap().upload_command(green),
+        ));
+        context.submit(texture.set_level_range_command(1, 1));
+
+        // Instead of downsampling level 0, the pass clears level 1 to blue.
+        let command = texture.generate_mipmap_custom_command(move |_, target| {
+            pass_count_in_f.set(pass_count_in_f.get() + 1);
+
+            target.clear_command([0.0, 0.0, 1.0, 1.0])
+        });
+
+        assert_eq!(pass_count.get(), 0);
+
+        context.submit(command);
+
+        let pipeline = fullscreen_pipeline::<((), LevelResources)>(
+            context,
+            SAMPLE_BASE_LEVEL_FRAGMENT_SHADER,
+            None,
+        );
+        let own_level_range = context.create_bind_group(LevelResources {
+            level_texture: texture.float_unsampled(),
+        });
+
+        render_target.create_render_pass(|framebuffer| {
+            framebuffer.pipeline_task(&pipeline, |active_pipeline| {
+                active_pipeline
+                    .task_builder()
+                    .bind_resources((&BindGroup::empty(), &own_level_range))
+                    .draw_without_vertices(3)
+                    .finish()
+            })
+        })
+    })
+    .await;
+
+    assert_eq!(pass_count.get(), 1);
+
+    // The command restored the level range to level 1, which it cleared to blue; had it reset the
+    // level range to all levels, the base level would be level 0 (red).
+    assert_image_matches(&image, &solid_image(WIDTH, HEIGHT, [0, 0, 255, 255]), 0);
+}
+
+#[wasm_bindgen_test]
+async fn test_render_unsampled_integer_texture() {
+    let image = render_to_image(WIDTH, HEIGHT, |context, render_target| {
+        let texture = context
+            .try_create_texture_2d(&Texture2DDescriptor {
+                format: RGBA8UI,
+                width: 1,
+                height: 1,
+                levels: MipmapLevels::Partial(1),
+            })
+            .unwrap();
+
+        let data = Image2DSource::from_pixels(vec![[10u8, 20, 30, 40]], 1, 1).unwrap();
+
+        context.submit(texture.base_level().upload_command(data));
+
+        let pipeline = fullscreen_pipeline::<((), UnsignedIntegerLevelResources)>(
+            context,
+            FETCH_UNSIGNED_INTEGER_FRAGMENT_SHADER,
+            None,
+        );
+
+        let resources = context.create_bind_group(UnsignedIntegerLevelResources {
+            level_texture: texture.unsigned_integer_unsampled(),
+        });
+
+        render_target.create_render_pass(|framebuffer| {
+            framebuffer.pipeline_task(&pipeline, |active_pipeline| {
+                active_pipeline
+                    .task_builder()
+                    .bind_resources((&BindGroup::empty(), &resources))
+                    .draw_without_vertices(3)
+                    .finish()
+            })
+        })
+    })
+    .await;
+
+    // No sampler object is bound, so the texture's own sampling parameters apply. With the
+    // default minification filter, an integer texture is incomplete and `texelFetch` returns
+    // `0`; the nearest filtering set at allocation keeps it complete.
+    assert_image_matches(&image, &solid_image(WIDTH, HEIGHT, [10, 20, 30, 40]), 1);
+}
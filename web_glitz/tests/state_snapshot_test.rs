@@ -0,0 +1,73 @@
+#![cfg(target_arch = "wasm32")]
+
+mod common;
+
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+use web_sys::WebGl2RenderingContext as Gl;
+
+use web_glitz::runtime::{Connection, RenderingContext, StateMismatch};
+use web_glitz::task::{ContextId, GpuTask, Progress};
+
+use crate::common::create_context;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+// Enables blending on the raw WebGL context without updating the context's state cache.
+struct EnableBlendingUntracked;
+
+unsafe impl GpuTask<Connection> for EnableBlendingUntracked {
+    type Output = ();
+
+    fn context_id(&self) -> ContextId {
+        ContextId::Any
+    }
+
+    fn progress(&mut self, connection: &mut Connection) -> Progress<Self::Output> {
+        let (gl, _) = unsafe { connection.unpack() };
+
+        gl.enable(Gl::BLEND);
+
+        Progress::Finished(())
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_capture_state_fresh_context() {
+    let context = create_context();
+    let snapshot = context.debug_capture_state();
+
+    assert_eq!(snapshot.program, None);
+    assert_eq!(snapshot.vertex_array, None);
+    assert_eq!(snapshot.draw_framebuffer, None);
+    assert_eq!(snapshot.read_framebuffer, None);
+    assert!(!snapshot.blend_enabled);
+
+    // The initial scissor box covers the drawing buffer, like the initial viewport.
+    let (x, y, width, height) = snapshot.viewport;
+
+    assert_eq!(snapshot.scissor, (x, y, width as u32, height as u32));
+    assert_eq!(snapshot, context.debug_capture_state());
+}
+
+#[wasm_bindgen_test]
+fn test_verify_state_fresh_context() {
+    let context = create_context();
+
+    assert_eq!(context.debug_verify_state(), Vec::new());
+}
+
+#[wasm_bindgen_test]
+fn test_verify_state_untracked_change() {
+    let context = create_context();
+
+    context.submit(EnableBlendingUntracked);
+
+    assert_eq!(
+        context.debug_verify_state(),
+        vec![StateMismatch {
+            parameter: "BLEND",
+            tracked: "false".to_string(),
+            actual: "true".to_string(),
+        }]
+    );
+}
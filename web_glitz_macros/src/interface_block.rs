@@ -7,6 +7,7 @@ pub fn expand_derive_interface_block(input: &DeriveInput) -> Result<TokenStream,
     if let Data::Struct(data) = &input.data {
         let mod_path = quote!(web_glitz::pipeline::interface_block);
         let struct_name = &input.ident;
+        let struct_name_str = struct_name.to_string().trim_start_matches("r#").to_owned();
 
         let recurse_len = data.fields.iter().map(|field| {
             let ty = &field.ty;
@@ -24,6 +25,11 @@ pub fn expand_derive_interface_block(input: &DeriveInput) -> Result<TokenStream,
                 .clone()
                 .map(|i| i.into_token_stream())
                 .unwrap_or(position.into_token_stream());
+            let field_name = field
+                .ident
+                .as_ref()
+                .map(|i| i.to_string().trim_start_matches("r#").to_owned())
+                .unwrap_or(position.to_string());
             let span = field.span();
 
             quote_spanned! {span=>
@@ -36,7 +42,14 @@ pub fn expand_derive_interface_block(input: &DeriveInput) -> Result<TokenStream,
 
                     array[i] = #mod_path::MemoryUnit {
                         offset: base_offset + memory_unit.offset,
-                        layout: memory_unit.layout
+                        layout: memory_unit.layout,
+                        field: match memory_unit.field {
+                            Some(field) => Some(field),
+                            None => Some(#mod_path::MemoryUnitField {
+                                struct_name: #struct_name_str,
+                                field_name: #field_name,
+                            }),
+                        },
                     };
 
                     i += 1;
@@ -45,6 +58,49 @@ pub fn expand_derive_interface_block(input: &DeriveInput) -> Result<TokenStream,
             }
         });
 
+        let recurse_offset_check = data.fields.iter().enumerate().map(|(position, field)| {
+            let ty = &field.ty;
+            let ident = field
+                .ident
+                .clone()
+                .map(|i| i.into_token_stream())
+                .unwrap_or(position.into_token_stream());
+            let field_name = field
+                .ident
+                .as_ref()
+                .map(|i| i.to_string().trim_start_matches("r#").to_owned())
+                .unwrap_or(position.to_string());
+            let message = format!(
+                "Field `{}::{}` is not at the offset required by the std140 layout rules (did you \
+                 forget to mark the struct with `#[std140::repr_std140]`?).",
+                struct_name_str, field_name
+            );
+            let span = field.span();
+
+            quote_spanned! {span=>
+                let actual_offset = web_glitz::offset_of!(#struct_name, #ident);
+
+                match <#ty as #mod_path::InterfaceBlockComponent>::STD140_BASE_ALIGNMENT {
+                    Some(alignment) => {
+                        let required_offset = (offset + alignment - 1) / alignment * alignment;
+
+                        assert!(actual_offset == required_offset, #message);
+                    }
+                    None => (),
+                }
+
+                offset = actual_offset + ::std::mem::size_of::<#ty>();
+            }
+        });
+
+        let offset_check = quote! {
+            let mut offset = 0usize;
+
+            #(#recurse_offset_check)*
+
+            let _ = offset;
+        };
+
         let suffix = struct_name.to_string().trim_start_matches("r#").to_owned();
         let dummy_const = Ident::new(
             &format!("_IMPL_INTERFACE_BLOCK_FOR_{}", suffix),
@@ -53,16 +109,27 @@ pub fn expand_derive_interface_block(input: &DeriveInput) -> Result<TokenStream,
 
         let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
+        // A free constant cannot refer to the struct's generic parameters; for generic structs the
+        // check is instead performed when the struct's memory units are evaluated.
+        let (free_offset_check, inline_offset_check) = if input.generics.params.is_empty() {
+            (quote!(const _: () = { #offset_check };), quote!())
+        } else {
+            (quote!(), offset_check)
+        };
+
         let impl_block = quote! {
             #[automatically_derived]
             unsafe impl #impl_generics #mod_path::InterfaceBlock for #struct_name #ty_generics #where_clause {
                 const MEMORY_UNITS: &'static [#mod_path::MemoryUnit] = &{
+                    #inline_offset_check
+
                     const LEN: usize = #(#recurse_len)+*;
 
                     // Initialize array with temporary values;
                     let mut array = [#mod_path::MemoryUnit {
                         offset: 0,
-                        layout: #mod_path::UnitLayout::Float
+                        layout: #mod_path::UnitLayout::Float,
+                        field: None,
                     }; LEN];
 
                     let mut i = 0;
@@ -81,6 +148,8 @@ pub fn expand_derive_interface_block(input: &DeriveInput) -> Result<TokenStream,
                 #[cfg_attr(feature = "cargo-clippy", allow(useless_attribute))]
                 #[allow(rust_2018_idioms)]
 
+                #free_offset_check
+
                 #impl_block
             };
         };
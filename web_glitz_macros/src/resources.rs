@@ -1,7 +1,9 @@
+use std::ops::RangeInclusive;
+
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use syn::spanned::Spanned;
-use syn::{Attribute, Data, DeriveInput, Field, Ident, Lit, Meta, NestedMeta, Type};
+use syn::{Attribute, Data, DeriveInput, Expr, ExprLit, Field, Ident, Lit, Meta, NestedMeta, Type};
 
 use crate::util::ErrorLog;
 
@@ -17,10 +19,17 @@ pub fn expand_derive_resources(input: &DeriveInput) -> Result<TokenStream, Strin
             match ResourcesField::from_ast(field, position, &mut log) {
                 ResourcesField::Resource(resource_field) => {
                     for field in resource_fields.iter() {
-                        if field.binding == resource_field.binding {
+                        let bindings = field.bindings();
+                        let other_bindings = resource_field.bindings();
+
+                        if bindings.start() <= other_bindings.end()
+                            && other_bindings.start() <= bindings.end()
+                        {
                             log.log_error(format!(
                                 "Fields `{}` and `{}` cannot both use binding `{}`.",
-                                field.name, resource_field.name, field.binding
+                                field.name,
+                                resource_field.name,
+                                bindings.start().max(other_bindings.start())
                             ));
                         }
                     }
@@ -31,27 +40,31 @@ pub fn expand_derive_resources(input: &DeriveInput) -> Result<TokenStream, Strin
             };
         }
 
-        let resource_slot_descriptors = resource_fields.iter().map(|field| {
-            let ty = &field.ty;
-            let slot_identifier = &field.name;
-            let slot_index = field.binding as u32;
+        let resource_slot_descriptors = resource_fields.iter().flat_map(|field| {
+            let mod_path = &mod_path;
+            let ty = field.element_ty();
             let span = field.span;
 
-            quote_spanned! {span=>
-                #mod_path::TypedResourceSlotDescriptor {
-                    slot_identifier: #mod_path::ResourceSlotIdentifier::Static(#slot_identifier),
-                    slot_index: #slot_index,
-                    slot_type: <#ty as #mod_path::Resource>::TYPE
+            field.slots().map(move |(slot_identifier, slot_index)| {
+                quote_spanned! {span=>
+                    #mod_path::TypedResourceSlotDescriptor {
+                        slot_identifier: #mod_path::ResourceSlotIdentifier::Static(#slot_identifier),
+                        slot_index: #slot_index,
+                        slot_type: <#ty as #mod_path::Resource>::TYPE
+                    }
                 }
-            }
+            })
         });
 
-        let resource_types = resource_fields.iter().map(|field| {
-            let ty = &field.ty;
+        let resource_types = resource_fields.iter().flat_map(|field| {
+            let mod_path = &mod_path;
+            let ty = field.element_ty();
 
-            quote! {
-                <#ty as #mod_path::Resource>::Encoding
-            }
+            field.slots().map(move |_| {
+                quote! {
+                    <#ty as #mod_path::Resource>::Encoding
+                }
+            })
         });
 
         let resource_encodings = resource_fields.iter().map(|field| {
@@ -61,15 +74,35 @@ pub fn expand_derive_resources(input: &DeriveInput) -> Result<TokenStream, Strin
                 .map(|i| i.into_token_stream())
                 .unwrap_or(field.position.into_token_stream());
 
-            let binding = field.binding as u32;
+            if field.len.is_some() {
+                let elements: Vec<Ident> = field
+                    .slots()
+                    .enumerate()
+                    .map(|(i, _)| {
+                        Ident::new(&format!("__resource_{}_{}", field.position, i), field.span)
+                    })
+                    .collect();
+                let bindings = field.slots().map(|(_, binding)| binding);
+                let elements_iter = elements.iter();
+
+                quote! {
+                    let [#(#elements,)*] = self.#field_name;
+                    #(let encoder = #elements_iter.encode(#bindings, encoder);)*
+                }
+            } else {
+                let binding = field.binding as u32;
 
-            quote! {
-                let encoder = self.#field_name.encode(#binding, encoder);
+                quote! {
+                    let encoder = self.#field_name.encode(#binding, encoder);
+                }
             }
         });
 
         let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
-        let len = resource_fields.len();
+        let len = resource_fields
+            .iter()
+            .map(|field| field.len.unwrap_or(1) as usize)
+            .sum::<usize>();
 
         let impl_block = quote! {
             #[automatically_derived]
@@ -157,6 +190,7 @@ impl ResourcesField {
             };
 
             let mut binding = None;
+            let mut len = None;
             let mut name = ast.ident.clone().map(|i| i.to_string());
 
             for meta_item in meta_items.into_iter() {
@@ -180,6 +214,24 @@ impl ResourcesField {
                             ));
                         };
                     }
+                    NestedMeta::Meta(Meta::NameValue(m)) if m.path.is_ident("len") => {
+                        if let Lit::Int(i) = &m.lit {
+                            match i.base10_parse::<u32>() {
+                                Ok(value) if value > 0 => len = Some(value),
+                                _ => log.log_error(format!(
+                                    "Malformed #[resource] attribute for field `{}`: \
+                                     expected `len` to be a non-zero u32.",
+                                    field_name
+                                )),
+                            }
+                        } else {
+                            log.log_error(format!(
+                                "Malformed #[resource] attribute for field `{}`: \
+                                 expected `len` to be a positive integer.",
+                                field_name
+                            ));
+                        };
+                    }
                     NestedMeta::Meta(Meta::NameValue(ref m)) if m.path.is_ident("name") => {
                         if let Lit::Str(n) = &m.lit {
                             name = Some(n.value());
@@ -215,6 +267,50 @@ impl ResourcesField {
                 ));
             }
 
+            if let Some(len) = len {
+                match &ast.ty {
+                    Type::Array(array) => {
+                        if let Expr::Lit(ExprLit {
+                            lit: Lit::Int(i), ..
+                        }) = &array.len
+                        {
+                            if i.base10_parse::<u32>().ok() != Some(len) {
+                                log.log_error(format!(
+                                    "Field `{}` declares a `len` of `{}`, but the length of its \
+                                     array type is `{}`.",
+                                    field_name, len, i
+                                ));
+
+                                return ResourcesField::Excluded;
+                            }
+                        }
+                    }
+                    _ => {
+                        log.log_error(format!(
+                            "Field `{}` declares a `len`, but its type is not an array type.",
+                            field_name
+                        ));
+
+                        return ResourcesField::Excluded;
+                    }
+                }
+
+                if let Some(binding) = binding {
+                    if binding.checked_add(len - 1).is_none() {
+                        log.log_error(format!(
+                            "Field `{}` declares a `binding` of `{}` and a `len` of `{}`, but \
+                             its last binding index exceeds the maximum binding index (`{}`).",
+                            field_name,
+                            binding,
+                            len,
+                            u32::MAX
+                        ));
+
+                        return ResourcesField::Excluded;
+                    }
+                }
+            }
+
             if binding.is_some() && name.is_some() {
                 let binding = binding.unwrap();
                 let name = name.unwrap();
@@ -224,6 +320,7 @@ impl ResourcesField {
                     ty: ast.ty.clone(),
                     position,
                     binding,
+                    len,
                     name,
                     span: ast.span(),
                 })
@@ -241,10 +338,44 @@ struct ResourceField {
     ty: Type,
     position: usize,
     binding: u32,
+    len: Option<u32>,
     name: String,
     span: Span,
 }
 
+impl ResourceField {
+    /// The range of binding indices occupied by this field.
+    fn bindings(&self) -> RangeInclusive<u32> {
+        // `from_ast` verified that the last binding index does not overflow.
+        self.binding..=self.binding + (self.len.unwrap_or(1) - 1)
+    }
+
+    /// The resource type for each of the field's slots: the array element type if the field
+    /// declares a `len`, or the field type otherwise.
+    fn element_ty(&self) -> &Type {
+        match (&self.ty, self.len) {
+            (Type::Array(array), Some(_)) => &array.elem,
+            (ty, _) => ty,
+        }
+    }
+
+    /// The slot identifier and binding index for each of the field's slots.
+    ///
+    /// If the field declares a `len`, then the slot identifiers use GLSL array element notation
+    /// (e.g. `Bones[0]`, `Bones[1]`, ...).
+    fn slots(&self) -> impl Iterator<Item = (String, u32)> + '_ {
+        self.bindings().enumerate().map(move |(i, binding)| {
+            let identifier = if self.len.is_some() {
+                format!("{}[{}]", self.name, i)
+            } else {
+                self.name.clone()
+            };
+
+            (identifier, binding)
+        })
+    }
+}
+
 fn is_resource_attribute(attribute: &Attribute) -> bool {
     attribute.path.segments[0].ident == "resource"
 }
@@ -22,6 +22,23 @@ pub fn expand_derive_vertex(input: &DeriveInput) -> Result<TokenStream, String>
             position += 1;
         }
 
+        for (i, a) in vertex_attributes.iter().enumerate() {
+            for b in vertex_attributes[i + 1..].iter() {
+                if a.locations().any(|l| b.locations().any(|o| l == o)) {
+                    log.log_error(format!(
+                        "Vertex attribute `{}` (locations {}..{}) collides with vertex attribute \
+                         `{}` (locations {}..{}).",
+                        a.name(),
+                        a.location,
+                        a.location + a.location_count(),
+                        b.name(),
+                        b.location,
+                        b.location + b.location_count()
+                    ));
+                }
+            }
+        }
+
         let recurse = vertex_attributes.iter().map(|a| {
             let field_name = a
                 .ident
@@ -29,6 +46,10 @@ pub fn expand_derive_vertex(input: &DeriveInput) -> Result<TokenStream, String>
                 .map(|i| i.into_token_stream())
                 .unwrap_or(a.position.into_token_stream());
             let location = a.location as u32;
+            let name = match &a.shader_name {
+                Some(name) => quote!(Some(#name)),
+                None => quote!(None),
+            };
             let ty = &a.ty;
             let span = a.span;
             let format_kind = {
@@ -44,6 +65,7 @@ pub fn expand_derive_vertex(input: &DeriveInput) -> Result<TokenStream, String>
             quote! {
                 #mod_path::VertexAttributeDescriptor {
                     location: #location,
+                    name: #name,
                     format: #format_kind,
                     offset_in_bytes: web_glitz::offset_of!(#struct_name, #field_name) as u8
                 }
@@ -127,6 +149,7 @@ impl VertexField {
                 };
 
                 let mut location = None;
+                let mut shader_name = None;
                 let mut format = None;
 
                 for meta_item in meta_items.into_iter() {
@@ -150,6 +173,30 @@ impl VertexField {
                                 ));
                             };
                         }
+                        NestedMeta::Meta(Meta::NameValue(m)) if m.path.is_ident("name") => {
+                            if let Lit::Str(n) = &m.lit {
+                                shader_name = Some(n.value());
+                            } else {
+                                log.log_error(format!(
+                                    "Malformed #[vertex_attribute] attribute for field `{}`: \
+                                     expected `name` to be a string.",
+                                    field_name
+                                ));
+                            };
+                        }
+                        NestedMeta::Meta(Meta::Path(p)) if p.is_ident("name") => {
+                            if let Some(ident) = &ast.ident {
+                                shader_name =
+                                    Some(ident.to_string().trim_start_matches("r#").to_owned());
+                            } else {
+                                log.log_error(format!(
+                                    "Malformed #[vertex_attribute] attribute for field `{}`: \
+                                     `name` without a value can only be used on named fields, \
+                                     use `name = \"...\"` instead.",
+                                    field_name
+                                ));
+                            }
+                        }
                         NestedMeta::Meta(Meta::NameValue(m)) if m.path.is_ident("format") => {
                             if let Lit::Str(f) = &m.lit {
                                 format = Some(f.value());
@@ -194,6 +241,7 @@ impl VertexField {
                         ty: ast.ty.clone(),
                         position,
                         location,
+                        shader_name,
                         format,
                         span: ast.span(),
                     })
@@ -218,10 +266,38 @@ struct AttributeField {
     ty: Type,
     position: usize,
     location: u32,
+    shader_name: Option<String>,
     format: String,
     span: Span,
 }
 
+impl AttributeField {
+    fn name(&self) -> String {
+        self.ident
+            .as_ref()
+            .map(|i| i.to_string())
+            .unwrap_or(self.position.to_string())
+    }
+
+    /// The number of consecutive attribute locations the attribute consumes, starting at its
+    /// declared location.
+    ///
+    /// Matrix formats (e.g. `Float4x4_f32`) consume one location for each column; all other
+    /// formats consume a single location.
+    fn location_count(&self) -> u32 {
+        let dimensions = self.format.trim_start_matches("Float").as_bytes();
+
+        match dimensions {
+            [columns @ b'2'..=b'4', b'x', b'2'..=b'4', ..] => (columns - b'0') as u32,
+            _ => 1,
+        }
+    }
+
+    fn locations(&self) -> std::ops::Range<u32> {
+        self.location..self.location + self.location_count()
+    }
+}
+
 fn is_vertex_attribute(attribute: &Attribute) -> bool {
     attribute.path.segments[0].ident == "vertex_attribute"
 }
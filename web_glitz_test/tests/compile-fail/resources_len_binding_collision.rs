@@ -0,0 +1,13 @@
+extern crate web_glitz;
+
+use web_glitz::image::texture_2d::FloatSampledTexture2D;
+
+#[derive(web_glitz::derive::Resources)] //~ ERROR: cannot both use binding `2`
+struct ResourcesA<'a> {
+    #[resource(binding = 0, len = 3, name = "Layers")]
+    layers: [FloatSampledTexture2D<'a>; 3],
+    #[resource(binding = 2, name = "Normals")]
+    normals: FloatSampledTexture2D<'a>,
+}
+
+fn main() {}
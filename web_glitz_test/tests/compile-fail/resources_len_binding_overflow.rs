@@ -0,0 +1,11 @@
+extern crate web_glitz;
+
+use web_glitz::image::texture_2d::FloatSampledTexture2D;
+
+#[derive(web_glitz::derive::Resources)] //~ ERROR: exceeds the maximum binding index
+struct ResourcesA<'a> {
+    #[resource(binding = 4294967295, len = 2, name = "Layers")]
+    layers: [FloatSampledTexture2D<'a>; 2],
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![feature(const_fn, const_ptr_offset_from, const_transmute, ptr_offset_from)]
+extern crate web_glitz;
+
+#[derive(web_glitz::derive::Vertex)] //~ ERROR: collides with vertex attribute `color`
+struct VertexA {
+    #[vertex_attribute(location = 0, format = "Float4x4_f32")]
+    model: [[f32; 4]; 4],
+    #[vertex_attribute(location = 3, format = "Float4_f32")]
+    color: [f32; 4],
+}
+
+fn main() {}
@@ -0,0 +1,54 @@
+use web_glitz::image::texture_2d::FloatSampledTexture2D;
+use web_glitz::pipeline::resources::{
+    ResourceSlotIdentifier, ResourceSlotType, Resources, SampledTextureType,
+    TypedResourceSlotDescriptor,
+};
+
+#[derive(web_glitz::derive::Resources)]
+struct ArrayResources<'a> {
+    #[resource(binding = 0, name = "Albedo")]
+    albedo: FloatSampledTexture2D<'a>,
+    #[resource(binding = 1, len = 3, name = "Layers")]
+    layers: [FloatSampledTexture2D<'a>; 3],
+    #[resource(binding = 4, name = "Normals")]
+    normals: FloatSampledTexture2D<'a>,
+}
+
+#[derive(web_glitz::derive::Resources)]
+struct MaxBindingResources<'a> {
+    #[resource(binding = 4294967294, len = 2, name = "Layers")]
+    layers: [FloatSampledTexture2D<'a>; 2],
+}
+
+fn float_sampler_2d_slot(identifier: &'static str, index: u32) -> TypedResourceSlotDescriptor {
+    TypedResourceSlotDescriptor {
+        slot_identifier: ResourceSlotIdentifier::Static(identifier),
+        slot_index: index,
+        slot_type: ResourceSlotType::SampledTexture(SampledTextureType::FloatSampler2D),
+    }
+}
+
+#[test]
+fn test_array_field_layout() {
+    assert_eq!(
+        ArrayResources::LAYOUT,
+        &[
+            float_sampler_2d_slot("Albedo", 0),
+            float_sampler_2d_slot("Layers[0]", 1),
+            float_sampler_2d_slot("Layers[1]", 2),
+            float_sampler_2d_slot("Layers[2]", 3),
+            float_sampler_2d_slot("Normals", 4),
+        ]
+    );
+}
+
+#[test]
+fn test_array_field_layout_max_binding() {
+    assert_eq!(
+        MaxBindingResources::LAYOUT,
+        &[
+            float_sampler_2d_slot("Layers[0]", 4294967294),
+            float_sampler_2d_slot("Layers[1]", 4294967295),
+        ]
+    );
+}
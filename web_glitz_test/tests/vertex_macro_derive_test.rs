@@ -16,6 +16,15 @@ struct VertexA {
     #[vertex_attribute(location = 6, format = "Integer_i32")]
     integer: i32,
 }
+
+#[derive(web_glitz::derive::Vertex)]
+#[repr(C)]
+struct VertexNamed {
+    #[vertex_attribute(location = 0, name, format = "Float4_f32")]
+    position: [f32; 4],
+    #[vertex_attribute(location = 1, name = "a_normal", format = "Float3_i8_norm")]
+    normal: [i8; 3],
+}
 //
 //#[derive(web_glitz::derive::Vertex)]
 //#[repr(C)]
@@ -33,21 +42,25 @@ fn test_struct_attribute_descriptors() {
         &[
             VertexAttributeDescriptor {
                 location: 0,
+                name: None,
                 format: VertexAttributeFormat::Float4_f32,
                 offset_in_bytes: 0
             },
             VertexAttributeDescriptor {
                 location: 1,
+                name: None,
                 format: VertexAttributeFormat::Float3_i8_norm,
                 offset_in_bytes: 16
             },
             VertexAttributeDescriptor {
                 location: 2,
+                name: None,
                 format: VertexAttributeFormat::Float4x4_f32,
                 offset_in_bytes: 24
             },
             VertexAttributeDescriptor {
                 location: 6,
+                name: None,
                 format: VertexAttributeFormat::Integer_i32,
                 offset_in_bytes: 88,
             },
@@ -55,6 +68,29 @@ fn test_struct_attribute_descriptors() {
     );
 }
 
+#[test]
+fn test_struct_named_attribute_descriptors() {
+    let descriptors = VertexNamed::ATTRIBUTE_DESCRIPTORS;
+
+    assert_eq!(
+        descriptors,
+        &[
+            VertexAttributeDescriptor {
+                location: 0,
+                name: Some("position"),
+                format: VertexAttributeFormat::Float4_f32,
+                offset_in_bytes: 0
+            },
+            VertexAttributeDescriptor {
+                location: 1,
+                name: Some("a_normal"),
+                format: VertexAttributeFormat::Float3_i8_norm,
+                offset_in_bytes: 16
+            },
+        ]
+    );
+}
+
 //#[test]
 //fn test_tuple_struct_attribute_descriptors() {
 //    let descriptors = VertexB::ATTRIBUTE_DESCRIPTORS;
@@ -64,11 +100,13 @@ fn test_struct_attribute_descriptors() {
 //        &[
 //            VertexAttributeDescriptor {
 //                location: 0,
+//                name: None,
 //                format: VertexAttributeFormat::Integer2_i8,
 //                offset_in_bytes: 0
 //            },
 //            VertexAttributeDescriptor {
 //                location: 1,
+//                name: None,
 //                format: VertexAttributeFormat::Integer3_u8,
 //                offset_in_bytes: 2
 //            }